@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 use bevy::text::LetterSpacing;
-use bevy_archie::icons::ButtonIcon;
+use bevy_archie::icons::{ButtonIcon, ButtonLabels};
 use bevy_archie::prelude::*;
 
 fn main() {
@@ -17,9 +17,9 @@ fn main() {
 #[derive(Component)]
 struct LayoutText;
 
+/// Text showing a button's label, updated when the layout changes.
 #[derive(Component)]
 struct ButtonPrompt {
-    #[allow(dead_code)]
     icon: ButtonIcon,
 }
 
@@ -28,10 +28,9 @@ struct ButtonPrompt {
 struct ButtonPromptBundle {
     node: Node,
     background: BackgroundColor,
-    prompt: ButtonPrompt,
 }
 
-fn setup(mut commands: Commands) {
+fn setup(mut commands: Commands, labels: Res<ButtonLabels>) {
     commands.spawn(Camera2d);
 
     commands
@@ -81,10 +80,10 @@ fn setup(mut commands: Commands) {
                     ..default()
                 })
                 .with_children(|row| {
-                    spawn_button_prompt(row, ButtonIcon::FaceDown, "Confirm");
-                    spawn_button_prompt(row, ButtonIcon::FaceRight, "Cancel");
-                    spawn_button_prompt(row, ButtonIcon::FaceLeft, "Action");
-                    spawn_button_prompt(row, ButtonIcon::FaceUp, "Special");
+                    spawn_button_prompt(row, &labels, ButtonIcon::FaceDown, "Confirm");
+                    spawn_button_prompt(row, &labels, ButtonIcon::FaceRight, "Cancel");
+                    spawn_button_prompt(row, &labels, ButtonIcon::FaceLeft, "Action");
+                    spawn_button_prompt(row, &labels, ButtonIcon::FaceUp, "Special");
                 });
 
             // Shoulder buttons row
@@ -95,10 +94,10 @@ fn setup(mut commands: Commands) {
                     ..default()
                 })
                 .with_children(|row| {
-                    spawn_button_prompt(row, ButtonIcon::LeftBumper, "Page Left");
-                    spawn_button_prompt(row, ButtonIcon::RightBumper, "Page Right");
-                    spawn_button_prompt(row, ButtonIcon::LeftTrigger, "Aim");
-                    spawn_button_prompt(row, ButtonIcon::RightTrigger, "Fire");
+                    spawn_button_prompt(row, &labels, ButtonIcon::LeftBumper, "Page Left");
+                    spawn_button_prompt(row, &labels, ButtonIcon::RightBumper, "Page Right");
+                    spawn_button_prompt(row, &labels, ButtonIcon::LeftTrigger, "Aim");
+                    spawn_button_prompt(row, &labels, ButtonIcon::RightTrigger, "Fire");
                 });
 
             // Instructions
@@ -116,7 +115,12 @@ fn setup(mut commands: Commands) {
         });
 }
 
-fn spawn_button_prompt(parent: &mut ChildSpawnerCommands, icon: ButtonIcon, label: &str) {
+fn spawn_button_prompt(
+    parent: &mut ChildSpawnerCommands,
+    labels: &ButtonLabels,
+    icon: ButtonIcon,
+    label: &str,
+) {
     parent
         .spawn(Node {
             flex_direction: FlexDirection::Column,
@@ -136,12 +140,11 @@ fn spawn_button_prompt(parent: &mut ChildSpawnerCommands, icon: ButtonIcon, labe
                     ..default()
                 },
                 background: BackgroundColor(Color::srgb(0.3, 0.3, 0.4)),
-                prompt: ButtonPrompt { icon },
             })
             .with_children(|button: &mut ChildSpawnerCommands| {
                 // Text fallback for the icon
                 button.spawn((
-                    Text::new(icon.label(ControllerLayout::Xbox)),
+                    Text::new(labels.label(icon, ControllerLayout::Xbox)),
                     TextFont {
                         font: FontSource::Monospace,
                         font_size: FontSize::Px(20.0),
@@ -149,6 +152,7 @@ fn spawn_button_prompt(parent: &mut ChildSpawnerCommands, icon: ButtonIcon, labe
                         ..default()
                     },
                     TextColor(Color::WHITE),
+                    ButtonPrompt { icon },
                 ));
             });
 
@@ -169,7 +173,9 @@ fn spawn_button_prompt(parent: &mut ChildSpawnerCommands, icon: ButtonIcon, labe
 fn update_layout_display(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut config: ResMut<ControllerConfig>,
-    mut layout_query: Query<&mut Text, With<LayoutText>>,
+    labels: Res<ButtonLabels>,
+    mut layout_query: Query<&mut Text, (With<LayoutText>, Without<ButtonPrompt>)>,
+    mut prompt_query: Query<(&mut Text, &ButtonPrompt), Without<LayoutText>>,
 ) {
     let mut changed = false;
 
@@ -192,5 +198,8 @@ fn update_layout_display(
         for mut text in &mut layout_query {
             **text = format!("Layout: {layout:?}");
         }
+        for (mut text, prompt) in &mut prompt_query {
+            **text = labels.label(prompt.icon, layout);
+        }
     }
 }
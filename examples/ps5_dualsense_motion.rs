@@ -212,18 +212,14 @@ fn display_motion_data(
 
     // Display touchpad data
     for touchpad in &touchpad_query {
-        if touchpad.finger1.active {
-            let pos = touchpad.finger1.position();
-            let delta = touchpad.finger1_delta();
+        for finger in &touchpad.fingers {
+            let pos = finger.position();
+            let delta = touchpad.delta(finger.id);
             info!(
-                "Finger 1: pos=({:.3}, {:.3}), delta=({:.3}, {:.3})",
-                pos.x, pos.y, delta.x, delta.y
+                "Finger {}: pos=({:.3}, {:.3}), delta=({:.3}, {:.3})",
+                finger.id, pos.x, pos.y, delta.x, delta.y
             );
         }
-        if touchpad.finger2.active {
-            let pos = touchpad.finger2.position();
-            info!("Finger 2: pos=({:.3}, {:.3})", pos.x, pos.y);
-        }
     }
 
     // Display touchpad gestures
@@ -143,9 +143,9 @@ fn display_touchpad_gestures(
 ) {
     // Display raw touchpad data
     for touchpad in &touchpad_query {
-        if touchpad.finger1.active {
-            let pos = touchpad.finger1.position();
-            let delta = touchpad.finger1_delta();
+        if let Some(finger) = touchpad.primary() {
+            let pos = finger.position();
+            let delta = touchpad.delta(finger.id);
 
             if delta.length() > 0.01 {
                 debug!(
@@ -188,6 +188,27 @@ fn display_touchpad_gestures(
             TouchpadGesture::PinchOut => {
                 info!("  → Pinch out (zoom in)!");
             }
+            TouchpadGesture::Twist => {
+                info!(
+                    "  → Twist by {:.2} rad! Use for rotating maps or dials.",
+                    event.intensity
+                );
+            }
+            TouchpadGesture::EdgeSwipeFromLeft
+            | TouchpadGesture::EdgeSwipeFromRight
+            | TouchpadGesture::EdgeSwipeFromTop
+            | TouchpadGesture::EdgeSwipeFromBottom => {
+                info!("  → Edge swipe! Use for summoning overlays or quick menus.");
+            }
+            TouchpadGesture::ThreeFingerTap => {
+                info!("  → Three-finger tap detected!");
+            }
+            TouchpadGesture::ThreeFingerSwipeLeft
+            | TouchpadGesture::ThreeFingerSwipeRight
+            | TouchpadGesture::ThreeFingerSwipeUp
+            | TouchpadGesture::ThreeFingerSwipeDown => {
+                info!("  → Three-finger swipe! Use for system-level shortcuts.");
+            }
         }
     }
 }
@@ -0,0 +1,400 @@
+//! Saving and loading [`ActionMap`] bindings.
+//!
+//! Binding files can be JSON, RON, or TOML, picked from the file extension.
+//! Each file records its schema [`ACTION_MAP_VERSION`], and actions are
+//! stored by name, so a file still loads after actions are renamed or
+//! removed: bindings for actions the enum no longer has are skipped with a
+//! warning. Unversioned JSON written by serializing an [`ActionMap`]
+//! directly loads as version 0.
+
+use bevy::prelude::*;
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde::de::value::{Error as ValueError, StrDeserializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+
+use crate::actions::{ActionMap, Actionlike, AxisDirection, StickDirectionBinding};
+use crate::stick::{Stick, Stick8Way};
+
+/// Current version of the binding file schema.
+pub const ACTION_MAP_VERSION: u32 = 1;
+
+/// File format for saved bindings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BindingFormat {
+    /// JSON (`.json`).
+    #[default]
+    Json,
+    /// RON (`.ron`).
+    Ron,
+    /// TOML (`.toml`).
+    Toml,
+}
+
+impl BindingFormat {
+    /// Pick the format from a file extension, defaulting to JSON.
+    #[must_use]
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path
+            .as_ref()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("ron") => Self::Ron,
+            Some("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+
+    /// Name of the format for error messages.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Ron => "RON",
+            Self::Toml => "TOML",
+        }
+    }
+}
+
+/// On-disk layout of an [`ActionMap`], keyed by action name.
+#[derive(Debug, Serialize, Deserialize)]
+struct ActionMapFile {
+    /// Schema version; missing in files written before versioning.
+    #[serde(default)]
+    version: u32,
+    #[serde(default = "HashMap::new")]
+    gamepad_bindings: HashMap<String, Vec<GamepadButton>>,
+    #[serde(default = "HashMap::new")]
+    modifier_bindings: HashMap<String, Vec<(GamepadButton, GamepadButton)>>,
+    #[serde(default = "HashMap::new")]
+    axis_bindings: HashMap<String, Vec<(GamepadAxis, AxisDirection, f32)>>,
+    #[serde(default = "HashMap::new")]
+    key_bindings: HashMap<String, Vec<KeyCode>>,
+    #[serde(default = "HashMap::new")]
+    mouse_bindings: HashMap<String, Vec<MouseButton>>,
+    /// Missing in files written before stick directions were saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stick_direction_bindings: Option<HashMap<Stick, StickDirectionsFile>>,
+}
+
+/// On-disk layout of a [`StickDirectionBinding`], with actions stored by name.
+#[derive(Debug, Serialize, Deserialize)]
+struct StickDirectionsFile {
+    processor: Stick8Way,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    up: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    down: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    left: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    right: Option<String>,
+}
+
+impl StickDirectionsFile {
+    fn from_binding<A: Actionlike + Serialize>(binding: &StickDirectionBinding<A>) -> Self {
+        Self {
+            processor: binding.processor.clone(),
+            up: binding.up.as_ref().and_then(action_name),
+            down: binding.down.as_ref().and_then(action_name),
+            left: binding.left.as_ref().and_then(action_name),
+            right: binding.right.as_ref().and_then(action_name),
+        }
+    }
+
+    fn resolve<A: Actionlike + DeserializeOwned>(
+        self,
+        skipped: &mut Vec<String>,
+    ) -> StickDirectionBinding<A> {
+        let mut resolve =
+            |name: Option<String>| name.and_then(|name| resolve_action(name, skipped));
+        StickDirectionBinding {
+            processor: self.processor,
+            up: resolve(self.up),
+            down: resolve(self.down),
+            left: resolve(self.left),
+            right: resolve(self.right),
+        }
+    }
+}
+
+/// Get an action's name, if it serializes as one.
+fn action_name<A: Serialize>(action: &A) -> Option<String> {
+    match serde_json::to_value(action) {
+        Ok(serde_json::Value::String(name)) => Some(name),
+        _ => None,
+    }
+}
+
+/// Look up an action by name, recording names that aren't actions.
+fn resolve_action<A: DeserializeOwned>(name: String, skipped: &mut Vec<String>) -> Option<A> {
+    if let Ok(action) = A::deserialize(StrDeserializer::<ValueError>::new(&name)) {
+        Some(action)
+    } else {
+        skipped.push(name);
+        None
+    }
+}
+
+/// Re-key a map of bindings by action name.
+///
+/// Names are written as strings, so formats like RON that would otherwise
+/// write enum keys as bare identifiers load back into named maps.
+fn name_actions<A: Serialize, V: Clone>(bindings: &HashMap<A, V>) -> HashMap<String, V> {
+    bindings
+        .iter()
+        .filter_map(|(action, value)| Some((action_name(action)?, value.clone())))
+        .collect()
+}
+
+/// Re-key a map of named bindings, skipping names that aren't actions.
+fn resolve_actions<A: DeserializeOwned + Eq + Hash, V>(
+    named: HashMap<String, V>,
+    skipped: &mut Vec<String>,
+) -> HashMap<A, V> {
+    named
+        .into_iter()
+        .filter_map(|(name, value)| Some((resolve_action(name, skipped)?, value)))
+        .collect()
+}
+
+impl<A: Actionlike + Serialize + DeserializeOwned> ActionMap<A> {
+    /// Serialize the bindings in a format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bindings can't be represented in the format.
+    pub fn save_to_string(&self, format: BindingFormat) -> crate::Result<String> {
+        let file = ActionMapFile {
+            version: ACTION_MAP_VERSION,
+            gamepad_bindings: name_actions(&self.gamepad_bindings),
+            modifier_bindings: name_actions(&self.modifier_bindings),
+            axis_bindings: name_actions(&self.axis_bindings),
+            key_bindings: name_actions(&self.key_bindings),
+            mouse_bindings: name_actions(&self.mouse_bindings),
+            stick_direction_bindings: Some(
+                self.stick_direction_bindings
+                    .iter()
+                    .map(|(stick, binding)| (*stick, StickDirectionsFile::from_binding(binding)))
+                    .collect(),
+            ),
+        };
+        let error = |reason: String| crate::Error::Bindings {
+            format: format.name(),
+            reason,
+        };
+        match format {
+            BindingFormat::Json => {
+                serde_json::to_string_pretty(&file).map_err(|e| error(e.to_string()))
+            }
+            BindingFormat::Ron => {
+                ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+                    .map_err(|e| error(e.to_string()))
+            }
+            BindingFormat::Toml => toml::to_string_pretty(&file).map_err(|e| error(e.to_string())),
+        }
+    }
+
+    /// Parse bindings saved with [`Self::save_to_string`].
+    ///
+    /// Bindings for unknown actions are skipped with a warning. Files without
+    /// stick direction bindings get [`Actionlike::default_stick_directions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source isn't a valid binding file.
+    pub fn load_from_str(source: &str, format: BindingFormat) -> crate::Result<Self> {
+        let error = |reason: String| crate::Error::Bindings {
+            format: format.name(),
+            reason,
+        };
+        let file: ActionMapFile = match format {
+            BindingFormat::Json => {
+                serde_json::from_str(source).map_err(|e| error(e.to_string()))?
+            }
+            BindingFormat::Ron => ron::from_str(source).map_err(|e| error(e.to_string()))?,
+            BindingFormat::Toml => toml::from_str(source).map_err(|e| error(e.to_string()))?,
+        };
+
+        if file.version > ACTION_MAP_VERSION {
+            warn!(
+                "Binding file version {} is newer than supported version {ACTION_MAP_VERSION}; loading what is recognized",
+                file.version
+            );
+        }
+
+        let mut skipped = Vec::new();
+        let map = Self {
+            gamepad_bindings: resolve_actions(file.gamepad_bindings, &mut skipped),
+            modifier_bindings: resolve_actions(file.modifier_bindings, &mut skipped),
+            axis_bindings: resolve_actions(file.axis_bindings, &mut skipped),
+            stick_direction_bindings: match file.stick_direction_bindings {
+                Some(sticks) => sticks
+                    .into_iter()
+                    .map(|(stick, binding)| (stick, binding.resolve(&mut skipped)))
+                    .collect(),
+                None => A::default_stick_directions(),
+            },
+            key_bindings: resolve_actions(file.key_bindings, &mut skipped),
+            mouse_bindings: resolve_actions(file.mouse_bindings, &mut skipped),
+        };
+
+        if !skipped.is_empty() {
+            skipped.sort();
+            skipped.dedup();
+            warn!(
+                "Skipped bindings for unknown actions: {}",
+                skipped.join(", ")
+            );
+        }
+        Ok(map)
+    }
+
+    /// Save the bindings to a file, in the format given by its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let source = self.save_to_string(BindingFormat::from_path(path))?;
+        std::fs::write(path, source).map_err(|e| crate::Error::io(path, e))
+    }
+
+    /// Load bindings from a file, in the format given by its extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or isn't a valid binding
+    /// file.
+    pub fn load_from_file(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        Self::load_from_str(&source, BindingFormat::from_path(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::GameAction;
+    use crate::stick::DirectionMode;
+
+    fn custom_map() -> ActionMap {
+        let mut map = ActionMap::default();
+        map.bind_gamepad(GameAction::Custom1, GamepadButton::West);
+        map.bind_gamepad_with_modifier(
+            GameAction::Custom2,
+            GamepadButton::LeftTrigger,
+            GamepadButton::North,
+        );
+        map.bind_axis(
+            GameAction::Custom3,
+            GamepadAxis::LeftZ,
+            AxisDirection::Positive,
+            0.3,
+        );
+        map.bind_mouse(GameAction::Primary, MouseButton::Left);
+        map.stick_direction_bindings.remove(&Stick::Left);
+        let mut processor = Stick8Way::new(DirectionMode::FourWay);
+        processor.press_threshold = 0.7;
+        map.bind_stick_directions(
+            Stick::Right,
+            StickDirectionBinding::new(
+                processor,
+                GameAction::Custom1,
+                GameAction::Custom2,
+                GameAction::Custom3,
+                GameAction::Custom4,
+            ),
+        );
+        map
+    }
+
+    #[test]
+    fn test_binding_format_from_path() {
+        assert_eq!(BindingFormat::from_path("binds.ron"), BindingFormat::Ron);
+        assert_eq!(BindingFormat::from_path("binds.TOML"), BindingFormat::Toml);
+        assert_eq!(BindingFormat::from_path("binds.json"), BindingFormat::Json);
+        assert_eq!(BindingFormat::from_path("binds"), BindingFormat::Json);
+    }
+
+    #[test]
+    fn test_round_trip_every_format() {
+        let map = custom_map();
+        for format in [BindingFormat::Json, BindingFormat::Ron, BindingFormat::Toml] {
+            let source = map.save_to_string(format).unwrap();
+            let loaded = ActionMap::<GameAction>::load_from_str(&source, format).unwrap();
+            assert_eq!(loaded.gamepad_bindings, map.gamepad_bindings, "{format:?}");
+            assert_eq!(
+                loaded.modifier_bindings, map.modifier_bindings,
+                "{format:?}"
+            );
+            assert_eq!(loaded.axis_bindings, map.axis_bindings, "{format:?}");
+            assert_eq!(loaded.key_bindings, map.key_bindings, "{format:?}");
+            assert_eq!(loaded.mouse_bindings, map.mouse_bindings, "{format:?}");
+            assert_eq!(
+                loaded.stick_direction_bindings, map.stick_direction_bindings,
+                "{format:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_unversioned_json() {
+        let map = custom_map();
+        let legacy = serde_json::to_string(&map).unwrap();
+        let loaded = ActionMap::<GameAction>::load_from_str(&legacy, BindingFormat::Json).unwrap();
+        assert_eq!(
+            loaded.primary_gamepad_button(GameAction::Custom1),
+            Some(GamepadButton::West)
+        );
+        assert_eq!(
+            loaded.stick_direction_bindings,
+            map.stick_direction_bindings
+        );
+    }
+
+    #[test]
+    fn test_load_skips_unknown_actions() {
+        let source = r#"{
+            "version": 1,
+            "gamepad_bindings": {
+                "Confirm": ["South"],
+                "RemovedAction": ["North"]
+            }
+        }"#;
+        let loaded = ActionMap::<GameAction>::load_from_str(source, BindingFormat::Json).unwrap();
+        assert_eq!(loaded.gamepad_bindings.len(), 1);
+        assert!(loaded.stick_direction_bindings.contains_key(&Stick::Left));
+        assert_eq!(
+            loaded.primary_gamepad_button(GameAction::Confirm),
+            Some(GamepadButton::South)
+        );
+    }
+
+    #[test]
+    fn test_load_invalid_source_reports_format() {
+        let err =
+            ActionMap::<GameAction>::load_from_str("not = [toml", BindingFormat::Toml).unwrap_err();
+        assert!(err.to_string().contains("TOML"));
+    }
+
+    #[test]
+    fn test_save_and_load_file() {
+        let path =
+            std::env::temp_dir().join(format!("bevy_archie_bindings_{}.ron", std::process::id()));
+        let map = custom_map();
+        map.save_to_file(&path).unwrap();
+        let loaded = ActionMap::<GameAction>::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.gamepad_bindings, map.gamepad_bindings);
+    }
+}
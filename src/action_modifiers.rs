@@ -6,6 +6,8 @@
 use bevy::prelude::*;
 
 use crate::actions::{ActionState, GameAction};
+use crate::arbitration::{Claim, InputArbiter};
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 /// Action modifier types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
@@ -138,11 +140,14 @@ impl ActionModifierState {
 }
 
 /// System to detect action modifiers.
+///
+/// Detected modifiers are submitted to the [`InputArbiter`], which writes
+/// [`ModifiedActionEvent`]s for the ones that win arbitration.
 pub fn detect_action_modifiers(
     mut modifier_state: ResMut<ActionModifierState>,
     action_state: Res<ActionState>,
     time: Res<Time>,
-    mut modifier_events: MessageWriter<ModifiedActionEvent>,
+    mut arbiter: ResMut<InputArbiter>,
 ) {
     let current_time = time.elapsed_secs_f64();
 
@@ -169,12 +174,7 @@ pub fn detect_action_modifiers(
                     .find(|(a, _)| *a == action)
                     .map_or(0.0, |(_, t)| (current_time - t) as f32);
 
-                modifier_events.write(ModifiedActionEvent {
-                    action,
-                    modifier,
-                    gamepad: None,
-                    duration,
-                });
+                arbiter.submit(Claim::modifier(action, modifier, duration));
             }
 
             // Remove from held actions
@@ -184,12 +184,11 @@ pub fn detect_action_modifiers(
 
     // Check for long presses on currently held actions
     for action in modifier_state.check_long_press(current_time) {
-        modifier_events.write(ModifiedActionEvent {
+        arbiter.submit(Claim::modifier(
             action,
-            modifier: ActionModifier::LongPress,
-            gamepad: None,
-            duration: modifier_state.config.long_press_duration,
-        });
+            ActionModifier::LongPress,
+            modifier_state.config.long_press_duration,
+        ));
     }
 }
 
@@ -202,8 +201,14 @@ pub(crate) fn register_action_modifier_types(app: &mut App) {
 }
 
 /// Add action modifier systems to the app.
-pub(crate) fn add_action_modifier_systems(app: &mut App) {
-    app.add_systems(Update, detect_action_modifiers);
+pub(crate) fn add_action_modifier_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        detect_action_modifiers.run_if(subsystem_enabled(Subsystem::ActionModifiers)),
+    );
 }
 
 #[cfg(test)]
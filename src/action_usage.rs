@@ -0,0 +1,216 @@
+//! Persistent action usage statistics.
+//!
+//! [`ActionUsageTracker`] counts every press of every action and remembers
+//! when each was first and last used, measured in play time. Save it with the
+//! player's progress so tutorials can ask "has the player ever sprinted?"
+//! across sessions; [`HintScheduler`](crate::hints::HintScheduler) already
+//! skips hints for actions the tracker has seen.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::action_usage::ActionUsageTracker;
+//! use bevy_archie::actions::GameAction;
+//!
+//! fn sprint_prompt(usage: Res<ActionUsageTracker>) {
+//!     if usage.count(GameAction::Secondary) < 3 {
+//!         info!("Hold Secondary to sprint");
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::actions::{ActionState, GameAction};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Usage statistics for one action.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ActionUsage {
+    /// Play time in seconds when the action was first pressed.
+    pub first_used: f64,
+    /// Play time in seconds when the action was last pressed.
+    pub last_used: f64,
+    /// Lifetime number of presses.
+    pub count: u64,
+}
+
+/// Resource recording lifetime action usage.
+///
+/// Times are seconds of play time tracked by this resource, which carries
+/// over when the tracker is saved and loaded.
+#[derive(Debug, Clone, Default, PartialEq, Resource, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionUsageTracker {
+    /// Total play time tracked, in seconds.
+    play_time: f64,
+    /// Statistics per used action.
+    actions: HashMap<GameAction, ActionUsage>,
+}
+
+impl ActionUsageTracker {
+    /// Advance play time by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        self.play_time += f64::from(delta);
+    }
+
+    /// Record one press of an action at the current play time.
+    pub fn record(&mut self, action: GameAction) {
+        let now = self.play_time;
+        self.actions
+            .entry(action)
+            .and_modify(|usage| {
+                usage.last_used = now;
+                usage.count += 1;
+            })
+            .or_insert(ActionUsage {
+                first_used: now,
+                last_used: now,
+                count: 1,
+            });
+    }
+
+    /// Total play time tracked, in seconds.
+    #[must_use]
+    pub const fn play_time(&self) -> f64 {
+        self.play_time
+    }
+
+    /// Whether the action has ever been pressed.
+    #[must_use]
+    pub fn has_used(&self, action: GameAction) -> bool {
+        self.actions.contains_key(&action)
+    }
+
+    /// Lifetime number of presses of an action.
+    #[must_use]
+    pub fn count(&self, action: GameAction) -> u64 {
+        self.actions.get(&action).map_or(0, |usage| usage.count)
+    }
+
+    /// Play time when the action was first pressed.
+    #[must_use]
+    pub fn first_used(&self, action: GameAction) -> Option<f64> {
+        self.actions.get(&action).map(|usage| usage.first_used)
+    }
+
+    /// Statistics for an action, if it has been pressed.
+    #[must_use]
+    pub fn usage(&self, action: GameAction) -> Option<&ActionUsage> {
+        self.actions.get(&action)
+    }
+
+    /// Forget all usage, e.g. when starting a new save.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Get the default usage file path for the current platform.
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        if let Some(config_dir) = dirs::config_dir() {
+            config_dir.join("bevy_archie").join("action_usage.json")
+        } else {
+            PathBuf::from("action_usage.json")
+        }
+    }
+
+    /// Save usage to a JSON file, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(crate::Error::ActionUsage)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| crate::Error::io(parent, e))?;
+        }
+        std::fs::write(path, json).map_err(|e| crate::Error::io(path, e))
+    }
+
+    /// Load usage from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or contains invalid JSON.
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        serde_json::from_str(&json).map_err(crate::Error::ActionUsage)
+    }
+}
+
+/// System to advance play time and count action presses.
+pub fn track_action_usage(
+    time: Res<Time>,
+    action_state: Res<ActionState>,
+    mut tracker: ResMut<ActionUsageTracker>,
+) {
+    tracker.tick(time.delta_secs());
+    for action in GameAction::all() {
+        if action_state.just_pressed(*action) {
+            tracker.record(*action);
+        }
+    }
+}
+
+/// Plugin for registering action usage types.
+pub(crate) fn register_action_usage_types(app: &mut App) {
+    app.init_resource::<ActionUsageTracker>();
+}
+
+/// Add action usage systems to the app.
+pub(crate) fn add_action_usage_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        track_action_usage.run_if(subsystem_enabled(Subsystem::ActionUsage)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_first_use_and_counts() {
+        let mut tracker = ActionUsageTracker::default();
+        assert!(!tracker.has_used(GameAction::Primary));
+        assert_eq!(tracker.count(GameAction::Primary), 0);
+
+        tracker.tick(2.0);
+        tracker.record(GameAction::Primary);
+        tracker.tick(3.0);
+        tracker.record(GameAction::Primary);
+
+        let usage = tracker.usage(GameAction::Primary).unwrap();
+        assert_eq!(usage.first_used, 2.0);
+        assert_eq!(usage.last_used, 5.0);
+        assert_eq!(tracker.count(GameAction::Primary), 2);
+        assert_eq!(tracker.first_used(GameAction::Secondary), None);
+    }
+
+    #[test]
+    fn test_usage_round_trips_through_file() {
+        let mut tracker = ActionUsageTracker::default();
+        tracker.tick(1.5);
+        tracker.record(GameAction::Confirm);
+
+        let path = std::env::temp_dir()
+            .join("bevy_archie_action_usage_test")
+            .join("usage.json");
+        tracker.save(&path).unwrap();
+        let loaded = ActionUsageTracker::load(&path).unwrap();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(loaded, tracker);
+        assert_eq!(loaded.play_time(), 1.5);
+    }
+}
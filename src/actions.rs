@@ -3,10 +3,27 @@
 //! This module provides an abstraction layer over raw input,
 //! allowing games to define logical actions that can be bound
 //! to various input sources.
+//!
+//! [`ActionMap`], [`ActionState`], and the action systems are generic over
+//! any [`Actionlike`] enum and default to the built-in [`GameAction`]. Games
+//! with their own verbs define an enum with [`action_enum!`](crate::action_enum)
+//! and add an [`ActionPlugin`] for it.
 
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::config::{ConfirmStyle, ControllerConfig};
+use crate::detection::InputDeviceState;
+use crate::multiplayer::{
+    ControllerOwnership, PlayerActionMaps, PlayerActionStates, update_player_action_states,
+};
+use crate::simulated_input::{SimulatedInput, advance_simulated_input, apply_simulated};
+use crate::stick::{DirectionMode, Stick, Stick8Way, StickDirection};
 
 /// Predefined game actions that can be mapped to inputs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
@@ -148,8 +165,136 @@ impl GameAction {
     }
 }
 
+/// A set of logical actions that inputs can be bound to.
+///
+/// Implemented by [`GameAction`] and by enums defined with
+/// [`action_enum!`](crate::action_enum). [`ActionMap::default`] binds each
+/// action's [`default_bindings`](Self::default_bindings).
+pub trait Actionlike: Copy + Eq + Hash + Debug + Send + Sync + 'static {
+    /// Every action, in display order.
+    fn all() -> &'static [Self];
+
+    /// Human-readable name for this action.
+    fn display_name(self) -> &'static str;
+
+    /// Whether this action can be remapped by the player.
+    fn is_remappable(self) -> bool {
+        true
+    }
+
+    /// Whether this action requires a binding (cannot be unbound).
+    fn is_required(self) -> bool {
+        false
+    }
+
+    /// Bindings a default [`ActionMap`] gives this action.
+    fn default_bindings(self) -> Vec<InputBinding> {
+        Vec::new()
+    }
+
+    /// Stick direction bindings a default [`ActionMap`] starts with.
+    fn default_stick_directions() -> HashMap<Stick, StickDirectionBinding<Self>> {
+        HashMap::new()
+    }
+}
+
+impl Actionlike for GameAction {
+    fn all() -> &'static [Self] {
+        Self::all()
+    }
+
+    fn display_name(self) -> &'static str {
+        Self::display_name(self)
+    }
+
+    fn is_remappable(self) -> bool {
+        Self::is_remappable(self)
+    }
+
+    fn is_required(self) -> bool {
+        Self::is_required(self)
+    }
+
+    fn default_bindings(self) -> Vec<InputBinding> {
+        use InputBinding::{GamepadButton as Button, Key};
+
+        match self {
+            Self::Confirm => vec![
+                Button(GamepadButton::South),
+                Key(KeyCode::Enter),
+                Key(KeyCode::Space),
+            ],
+            Self::Cancel => vec![Button(GamepadButton::East), Key(KeyCode::Escape)],
+            Self::Pause => vec![Button(GamepadButton::Start), Key(KeyCode::Escape)],
+            Self::Select => vec![Button(GamepadButton::Select)],
+            Self::Up => vec![
+                Button(GamepadButton::DPadUp),
+                Key(KeyCode::ArrowUp),
+                Key(KeyCode::KeyW),
+            ],
+            Self::Down => vec![
+                Button(GamepadButton::DPadDown),
+                Key(KeyCode::ArrowDown),
+                Key(KeyCode::KeyS),
+            ],
+            Self::Left => vec![
+                Button(GamepadButton::DPadLeft),
+                Key(KeyCode::ArrowLeft),
+                Key(KeyCode::KeyA),
+            ],
+            Self::Right => vec![
+                Button(GamepadButton::DPadRight),
+                Key(KeyCode::ArrowRight),
+                Key(KeyCode::KeyD),
+            ],
+            Self::Primary => vec![Button(GamepadButton::West)],
+            Self::Secondary => vec![Button(GamepadButton::North)],
+            Self::LeftShoulder => vec![Button(GamepadButton::LeftTrigger)],
+            Self::RightShoulder => vec![Button(GamepadButton::RightTrigger)],
+            Self::LeftTrigger => vec![Button(GamepadButton::LeftTrigger2)],
+            Self::RightTrigger => vec![Button(GamepadButton::RightTrigger2)],
+            Self::PageLeft => vec![Button(GamepadButton::LeftTrigger), Key(KeyCode::KeyQ)],
+            Self::PageRight => vec![Button(GamepadButton::RightTrigger), Key(KeyCode::KeyE)],
+            Self::LookUp
+            | Self::LookDown
+            | Self::LookLeft
+            | Self::LookRight
+            | Self::Custom1
+            | Self::Custom2
+            | Self::Custom3
+            | Self::Custom4 => Vec::new(),
+        }
+    }
+
+    /// The left stick drives movement and the right stick drives looking.
+    fn default_stick_directions() -> HashMap<Stick, StickDirectionBinding<Self>> {
+        HashMap::from([
+            (
+                Stick::Left,
+                StickDirectionBinding::new(
+                    Stick8Way::new(DirectionMode::EightWay),
+                    Self::Up,
+                    Self::Down,
+                    Self::Left,
+                    Self::Right,
+                ),
+            ),
+            (
+                Stick::Right,
+                StickDirectionBinding::new(
+                    Stick8Way::new(DirectionMode::EightWay),
+                    Self::LookUp,
+                    Self::LookDown,
+                    Self::LookLeft,
+                    Self::LookRight,
+                ),
+            ),
+        ])
+    }
+}
+
 /// A binding source for an action.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 pub enum InputBinding {
     /// A gamepad button
     GamepadButton(GamepadButton),
@@ -161,8 +306,12 @@ pub enum InputBinding {
     MouseButton(MouseButton),
 }
 
+/// Threshold used when an [`InputBinding::GamepadAxis`] is bound with
+/// [`ActionMap::bind`].
+pub const DEFAULT_AXIS_THRESHOLD: f32 = 0.5;
+
 /// Direction for axis bindings.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum AxisDirection {
     /// Positive direction (right, up)
     Positive,
@@ -170,145 +319,198 @@ pub enum AxisDirection {
     Negative,
 }
 
+/// Binds a stick's 4-way or 8-way directions to actions.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct StickDirectionBinding<A: Actionlike = GameAction> {
+    /// Processor settings used for every gamepad.
+    pub processor: Stick8Way,
+    /// Action for up.
+    pub up: Option<A>,
+    /// Action for down.
+    pub down: Option<A>,
+    /// Action for left.
+    pub left: Option<A>,
+    /// Action for right.
+    pub right: Option<A>,
+}
+
+impl<A: Actionlike> StickDirectionBinding<A> {
+    /// Create a binding for the four directional actions.
+    #[must_use]
+    pub const fn new(processor: Stick8Way, up: A, down: A, left: A, right: A) -> Self {
+        Self {
+            processor,
+            up: Some(up),
+            down: Some(down),
+            left: Some(left),
+            right: Some(right),
+        }
+    }
+
+    /// Actions active for a direction, with their analog values.
+    fn actions(&self, direction: StickDirection, position: Vec2) -> impl Iterator<Item = (A, f32)> {
+        [
+            (direction.is_up(), self.up, position.y),
+            (direction.is_down(), self.down, -position.y),
+            (direction.is_left(), self.left, -position.x),
+            (direction.is_right(), self.right, position.x),
+        ]
+        .into_iter()
+        .filter_map(|(active, action, value)| {
+            action
+                .filter(|_| active)
+                .map(|action| (action, value.max(0.0)))
+        })
+    }
+
+    /// Remove an action from this binding.
+    fn clear_action(&mut self, action: A) {
+        for slot in [
+            &mut self.up,
+            &mut self.down,
+            &mut self.left,
+            &mut self.right,
+        ] {
+            if *slot == Some(action) {
+                *slot = None;
+            }
+        }
+    }
+}
+
 /// Resource containing action-to-input mappings.
 #[derive(Debug, Clone, Resource, Serialize, Deserialize, Reflect)]
 #[reflect(Resource)]
-pub struct ActionMap {
+pub struct ActionMap<A: Actionlike = GameAction> {
+    // Path defaults keep serde from requiring `A: Default`.
     /// Gamepad button bindings
-    #[reflect(ignore)]
-    #[serde(skip)]
-    pub gamepad_bindings: HashMap<GameAction, Vec<GamepadButton>>,
+    #[serde(default = "HashMap::new")]
+    pub gamepad_bindings: HashMap<A, Vec<GamepadButton>>,
+
+    /// Gamepad bindings active only while a modifier button is held
+    /// (action -> (modifier, button))
+    #[serde(default = "HashMap::new")]
+    pub modifier_bindings: HashMap<A, Vec<(GamepadButton, GamepadButton)>>,
 
     /// Gamepad axis bindings (action -> (axis, direction, threshold))
-    #[reflect(ignore)]
-    #[serde(skip)]
-    pub axis_bindings: HashMap<GameAction, Vec<(GamepadAxis, AxisDirection, f32)>>,
+    #[serde(default = "HashMap::new")]
+    pub axis_bindings: HashMap<A, Vec<(GamepadAxis, AxisDirection, f32)>>,
+
+    /// Stick direction bindings (stick -> 4/8-way processor and actions)
+    #[serde(default = "A::default_stick_directions")]
+    pub stick_direction_bindings: HashMap<Stick, StickDirectionBinding<A>>,
 
     /// Keyboard bindings
-    #[reflect(ignore)]
-    #[serde(skip)]
-    pub key_bindings: HashMap<GameAction, Vec<KeyCode>>,
+    #[serde(default = "HashMap::new")]
+    pub key_bindings: HashMap<A, Vec<KeyCode>>,
 
     /// Mouse button bindings
-    #[reflect(ignore)]
-    #[serde(skip)]
-    pub mouse_bindings: HashMap<GameAction, Vec<MouseButton>>,
+    #[serde(default = "HashMap::new")]
+    pub mouse_bindings: HashMap<A, Vec<MouseButton>>,
 }
 
-impl Default for ActionMap {
+impl<A: Actionlike> Default for ActionMap<A> {
     fn default() -> Self {
-        let mut map = Self {
+        let mut map = Self::empty();
+        for &action in A::all() {
+            for binding in action.default_bindings() {
+                map.bind(action, binding);
+            }
+        }
+        map.stick_direction_bindings = A::default_stick_directions();
+        map
+    }
+}
+
+impl<A: Actionlike> ActionMap<A> {
+    /// Create a map with no bindings.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
             gamepad_bindings: HashMap::new(),
+            modifier_bindings: HashMap::new(),
             axis_bindings: HashMap::new(),
+            stick_direction_bindings: HashMap::new(),
             key_bindings: HashMap::new(),
             mouse_bindings: HashMap::new(),
-        };
-
-        // Default gamepad bindings
-        map.bind_gamepad(GameAction::Confirm, GamepadButton::South);
-        map.bind_gamepad(GameAction::Cancel, GamepadButton::East);
-        map.bind_gamepad(GameAction::Pause, GamepadButton::Start);
-        map.bind_gamepad(GameAction::Select, GamepadButton::Select);
-        map.bind_gamepad(GameAction::Primary, GamepadButton::West);
-        map.bind_gamepad(GameAction::Secondary, GamepadButton::North);
-        map.bind_gamepad(GameAction::LeftShoulder, GamepadButton::LeftTrigger);
-        map.bind_gamepad(GameAction::RightShoulder, GamepadButton::RightTrigger);
-        map.bind_gamepad(GameAction::LeftTrigger, GamepadButton::LeftTrigger2);
-        map.bind_gamepad(GameAction::RightTrigger, GamepadButton::RightTrigger2);
-        map.bind_gamepad(GameAction::PageLeft, GamepadButton::LeftTrigger);
-        map.bind_gamepad(GameAction::PageRight, GamepadButton::RightTrigger);
-
-        // D-pad bindings
-        map.bind_gamepad(GameAction::Up, GamepadButton::DPadUp);
-        map.bind_gamepad(GameAction::Down, GamepadButton::DPadDown);
-        map.bind_gamepad(GameAction::Left, GamepadButton::DPadLeft);
-        map.bind_gamepad(GameAction::Right, GamepadButton::DPadRight);
-
-        // Left stick axis bindings
-        map.bind_axis(
-            GameAction::Up,
-            GamepadAxis::LeftStickY,
-            AxisDirection::Positive,
-            0.5,
-        );
-        map.bind_axis(
-            GameAction::Down,
-            GamepadAxis::LeftStickY,
-            AxisDirection::Negative,
-            0.5,
-        );
-        map.bind_axis(
-            GameAction::Left,
-            GamepadAxis::LeftStickX,
-            AxisDirection::Negative,
-            0.5,
-        );
-        map.bind_axis(
-            GameAction::Right,
-            GamepadAxis::LeftStickX,
-            AxisDirection::Positive,
-            0.5,
-        );
-
-        // Right stick for looking
-        map.bind_axis(
-            GameAction::LookUp,
-            GamepadAxis::RightStickY,
-            AxisDirection::Positive,
-            0.5,
-        );
-        map.bind_axis(
-            GameAction::LookDown,
-            GamepadAxis::RightStickY,
-            AxisDirection::Negative,
-            0.5,
-        );
-        map.bind_axis(
-            GameAction::LookLeft,
-            GamepadAxis::RightStickX,
-            AxisDirection::Negative,
-            0.5,
-        );
-        map.bind_axis(
-            GameAction::LookRight,
-            GamepadAxis::RightStickX,
-            AxisDirection::Positive,
-            0.5,
-        );
-
-        // Default keyboard bindings
-        map.bind_key(GameAction::Confirm, KeyCode::Enter);
-        map.bind_key(GameAction::Confirm, KeyCode::Space);
-        map.bind_key(GameAction::Cancel, KeyCode::Escape);
-        map.bind_key(GameAction::Pause, KeyCode::Escape);
-        map.bind_key(GameAction::Up, KeyCode::ArrowUp);
-        map.bind_key(GameAction::Up, KeyCode::KeyW);
-        map.bind_key(GameAction::Down, KeyCode::ArrowDown);
-        map.bind_key(GameAction::Down, KeyCode::KeyS);
-        map.bind_key(GameAction::Left, KeyCode::ArrowLeft);
-        map.bind_key(GameAction::Left, KeyCode::KeyA);
-        map.bind_key(GameAction::Right, KeyCode::ArrowRight);
-        map.bind_key(GameAction::Right, KeyCode::KeyD);
-        map.bind_key(GameAction::PageLeft, KeyCode::KeyQ);
-        map.bind_key(GameAction::PageRight, KeyCode::KeyE);
+        }
+    }
 
-        map
+    /// Bind any input source to an action.
+    ///
+    /// Axis bindings use [`DEFAULT_AXIS_THRESHOLD`].
+    pub fn bind(&mut self, action: A, binding: InputBinding) {
+        match binding {
+            InputBinding::GamepadButton(button) => self.bind_gamepad(action, button),
+            InputBinding::GamepadAxis(axis, direction) => {
+                self.bind_axis(action, axis, direction, DEFAULT_AXIS_THRESHOLD);
+            }
+            InputBinding::Key(key) => self.bind_key(action, key),
+            InputBinding::MouseButton(button) => self.bind_mouse(action, button),
+        }
     }
-}
 
-impl ActionMap {
     /// Bind a gamepad button to an action.
-    pub fn bind_gamepad(&mut self, action: GameAction, button: GamepadButton) {
+    pub fn bind_gamepad(&mut self, action: A, button: GamepadButton) {
         self.gamepad_bindings
             .entry(action)
             .or_default()
             .push(button);
     }
 
+    /// Bind a button to an action on a modifier layer.
+    ///
+    /// While `modifier` is held, pressing `button` fires `action` instead of
+    /// whatever `button` is bound to on its own, like a "mode shift". The
+    /// modifier keeps its own bindings.
+    pub fn bind_gamepad_with_modifier(
+        &mut self,
+        action: A,
+        modifier: GamepadButton,
+        button: GamepadButton,
+    ) {
+        self.modifier_bindings
+            .entry(action)
+            .or_default()
+            .push((modifier, button));
+    }
+
+    /// Buttons used as modifiers by any binding.
+    #[must_use]
+    pub fn modifier_buttons(&self) -> HashSet<GamepadButton> {
+        self.modifier_bindings
+            .values()
+            .flatten()
+            .map(|(modifier, _)| *modifier)
+            .collect()
+    }
+
+    /// Move every modifier-layer binding from one modifier button to another.
+    pub fn rebind_modifier(&mut self, from: GamepadButton, to: GamepadButton) {
+        for (modifier, _) in self.modifier_bindings.values_mut().flatten() {
+            if *modifier == from {
+                *modifier = to;
+            }
+        }
+    }
+
+    /// Buttons shifted onto a modifier layer while `modifier_pressed` holds.
+    pub(crate) fn shifted_buttons(
+        &self,
+        modifier_pressed: impl Fn(GamepadButton) -> bool,
+    ) -> HashSet<GamepadButton> {
+        self.modifier_bindings
+            .values()
+            .flatten()
+            .filter(|(modifier, _)| modifier_pressed(*modifier))
+            .map(|(_, button)| *button)
+            .collect()
+    }
+
     /// Bind a gamepad axis to an action.
     pub fn bind_axis(
         &mut self,
-        action: GameAction,
+        action: A,
         axis: GamepadAxis,
         direction: AxisDirection,
         threshold: f32,
@@ -319,93 +521,174 @@ impl ActionMap {
             .push((axis, direction, threshold));
     }
 
+    /// Bind a stick's digital directions to actions, replacing any
+    /// existing binding for that stick.
+    pub fn bind_stick_directions(&mut self, stick: Stick, binding: StickDirectionBinding<A>) {
+        self.stick_direction_bindings.insert(stick, binding);
+    }
+
     /// Bind a keyboard key to an action.
-    pub fn bind_key(&mut self, action: GameAction, key: KeyCode) {
+    pub fn bind_key(&mut self, action: A, key: KeyCode) {
         self.key_bindings.entry(action).or_default().push(key);
     }
 
     /// Bind a mouse button to an action.
-    pub fn bind_mouse(&mut self, action: GameAction, button: MouseButton) {
+    pub fn bind_mouse(&mut self, action: A, button: MouseButton) {
         self.mouse_bindings.entry(action).or_default().push(button);
     }
 
     /// Clear all bindings for an action.
-    pub fn clear_bindings(&mut self, action: GameAction) {
-        self.gamepad_bindings.remove(&action);
-        self.axis_bindings.remove(&action);
+    pub fn clear_bindings(&mut self, action: A) {
+        self.clear_gamepad_bindings(action);
         self.key_bindings.remove(&action);
         self.mouse_bindings.remove(&action);
     }
 
     /// Clear only gamepad bindings for an action.
-    pub fn clear_gamepad_bindings(&mut self, action: GameAction) {
+    pub fn clear_gamepad_bindings(&mut self, action: A) {
         self.gamepad_bindings.remove(&action);
+        self.modifier_bindings.remove(&action);
         self.axis_bindings.remove(&action);
+        for binding in self.stick_direction_bindings.values_mut() {
+            binding.clear_action(action);
+        }
     }
 
     /// Get the primary gamepad button for an action (for icon display).
     #[must_use]
-    pub fn primary_gamepad_button(&self, action: GameAction) -> Option<GamepadButton> {
+    pub fn primary_gamepad_button(&self, action: A) -> Option<GamepadButton> {
         self.gamepad_bindings
             .get(&action)
             .and_then(|buttons| buttons.first().copied())
     }
+
+    /// Get the first modifier-layer binding for an action as
+    /// `(modifier, button)` (for icon display).
+    #[must_use]
+    pub fn primary_modifier_binding(&self, action: A) -> Option<(GamepadButton, GamepadButton)> {
+        self.modifier_bindings
+            .get(&action)
+            .and_then(|bindings| bindings.first().copied())
+    }
+}
+
+impl ActionMap<GameAction> {
+    /// Rebind Confirm/Cancel face buttons to match a confirm convention.
+    ///
+    /// Only the bottom and right face buttons are touched; any other
+    /// buttons bound to Confirm or Cancel are kept.
+    pub fn apply_confirm_style(&mut self, style: ConfirmStyle) {
+        let face = [GamepadButton::South, GamepadButton::East];
+        for (action, button) in [
+            (GameAction::Confirm, style.confirm_button()),
+            (GameAction::Cancel, style.cancel_button()),
+        ] {
+            let buttons = self.gamepad_bindings.entry(action).or_default();
+            buttons.retain(|b| !face.contains(b));
+            buttons.insert(0, button);
+        }
+    }
+
+    /// Move Confirm/Cancel from one confirm convention's face buttons to
+    /// another's.
+    ///
+    /// Only bindings still on `from`'s face button are moved, so Confirm and
+    /// Cancel rebinds survive a style change.
+    pub fn switch_confirm_style(&mut self, from: ConfirmStyle, to: ConfirmStyle) {
+        for (action, old, new) in [
+            (
+                GameAction::Confirm,
+                from.confirm_button(),
+                to.confirm_button(),
+            ),
+            (GameAction::Cancel, from.cancel_button(), to.cancel_button()),
+        ] {
+            let Some(buttons) = self.gamepad_bindings.get_mut(&action) else {
+                continue;
+            };
+            let Some(index) = buttons.iter().position(|b| *b == old) else {
+                continue;
+            };
+            buttons.remove(index);
+            buttons.retain(|b| *b != new);
+            buttons.insert(index.min(buttons.len()), new);
+        }
+    }
 }
 
 /// Resource tracking the current state of all actions.
-#[derive(Debug, Clone, Default, Resource, Reflect)]
+#[derive(Debug, Clone, Resource, Reflect)]
 #[reflect(Resource)]
-pub struct ActionState {
+pub struct ActionState<A: Actionlike = GameAction> {
     /// Actions that are currently pressed.
-    #[reflect(ignore)]
-    pressed: HashMap<GameAction, bool>,
+    pressed: HashMap<A, bool>,
 
     /// Actions that were just pressed this frame.
-    #[reflect(ignore)]
-    just_pressed: HashMap<GameAction, bool>,
+    just_pressed: HashMap<A, bool>,
 
     /// Actions that were just released this frame.
-    #[reflect(ignore)]
-    just_released: HashMap<GameAction, bool>,
+    just_released: HashMap<A, bool>,
 
     /// Analog values for actions (0.0 - 1.0).
+    values: HashMap<A, f32>,
+
+    /// Why actions were suppressed this frame.
     #[reflect(ignore)]
-    values: HashMap<GameAction, f32>,
+    suppressed: HashMap<A, &'static str>,
 }
 
-impl ActionState {
+impl<A: Actionlike> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            pressed: HashMap::new(),
+            just_pressed: HashMap::new(),
+            just_released: HashMap::new(),
+            values: HashMap::new(),
+            suppressed: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> ActionState<A> {
     /// Check if an action is currently pressed.
     #[must_use]
-    pub fn pressed(&self, action: GameAction) -> bool {
+    pub fn pressed(&self, action: A) -> bool {
         self.pressed.get(&action).copied().unwrap_or(false)
     }
 
     /// Check if an action was just pressed this frame.
     #[must_use]
-    pub fn just_pressed(&self, action: GameAction) -> bool {
+    pub fn just_pressed(&self, action: A) -> bool {
         self.just_pressed.get(&action).copied().unwrap_or(false)
     }
 
     /// Check if an action was just released this frame.
     #[must_use]
-    pub fn just_released(&self, action: GameAction) -> bool {
+    pub fn just_released(&self, action: A) -> bool {
         self.just_released.get(&action).copied().unwrap_or(false)
     }
 
     /// Get the analog value of an action (0.0 - 1.0).
     #[must_use]
-    pub fn value(&self, action: GameAction) -> f32 {
+    pub fn value(&self, action: A) -> f32 {
         self.values.get(&action).copied().unwrap_or(0.0)
     }
 
-    /// Reset `just_pressed` and `just_released` flags.
+    /// What suppressed an action this frame, if anything did.
+    #[must_use]
+    pub fn suppressed_by(&self, action: A) -> Option<&'static str> {
+        self.suppressed.get(&action).copied()
+    }
+
+    /// Reset per-frame flags and suppression reasons.
     pub(crate) fn reset_frame_state(&mut self) {
         self.just_pressed.clear();
         self.just_released.clear();
+        self.suppressed.clear();
     }
 
     /// Set an action's pressed state.
-    pub(crate) fn set_pressed(&mut self, action: GameAction, pressed: bool) {
+    pub(crate) fn set_pressed(&mut self, action: A, pressed: bool) {
         let was_pressed = self.pressed.get(&action).copied().unwrap_or(false);
 
         if pressed && !was_pressed {
@@ -417,104 +700,606 @@ impl ActionState {
         self.pressed.insert(action, pressed);
     }
 
+    /// Force an action inactive for this frame.
+    ///
+    /// A press that started this frame is swallowed rather than turned into
+    /// a release, so suppressed actions never flicker `just_pressed`. The
+    /// `reason` is reported by [`Self::suppressed_by`].
+    pub(crate) fn suppress(&mut self, action: A, reason: &'static str) {
+        let was_pressed = self.pressed(action);
+        let pressed_this_frame = self.just_pressed.remove(&action).is_some();
+
+        if was_pressed && !pressed_this_frame {
+            self.just_released.insert(action, true);
+        }
+
+        self.pressed.insert(action, false);
+        self.values.insert(action, 0.0);
+        self.suppressed.insert(action, reason);
+    }
+
     /// Set an action's analog value.
-    pub(crate) fn set_value(&mut self, action: GameAction, value: f32) {
+    pub(crate) fn set_value(&mut self, action: A, value: f32) {
         self.values.insert(action, value.clamp(0.0, 1.0));
     }
 }
 
-/// System to update action states from input.
-pub fn update_action_state(
-    mut state: ResMut<ActionState>,
-    action_map: Res<ActionMap>,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mouse_buttons: Res<ButtonInput<MouseButton>>,
-    gamepads: Query<&Gamepad>,
-) {
-    // Reset frame state
-    state.reset_frame_state();
+/// Resource for exclusive input capture.
+///
+/// While capture is active, [`update_action_state`] keeps every action
+/// released and routes new presses into a captured buffer instead, so a
+/// button pressed during remap listening doesn't also fire its gameplay
+/// action. Inputs still held when capture ends stay suppressed until they
+/// are released.
+#[derive(Debug, Clone, Resource)]
+pub struct InputCapture<A: Actionlike = GameAction> {
+    /// Whether capture is active.
+    active: bool,
+    /// Actions pressed during capture, in press order.
+    captured: Vec<A>,
+    /// Actions held since capture that are still suppressed.
+    held: HashSet<A>,
+}
 
-    // Check all actions
-    for action in GameAction::all() {
-        let mut pressed = false;
-        let mut value = 0.0f32;
-
-        // Check keyboard bindings
-        if let Some(keys) = action_map.key_bindings.get(action) {
-            for key in keys {
-                if keyboard.pressed(*key) {
-                    pressed = true;
-                    value = 1.0;
-                    break;
-                }
+impl<A: Actionlike> Default for InputCapture<A> {
+    fn default() -> Self {
+        Self {
+            active: false,
+            captured: Vec::new(),
+            held: HashSet::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> InputCapture<A> {
+    /// Start capturing, clearing the captured buffer.
+    pub fn begin(&mut self) {
+        self.active = true;
+        self.captured.clear();
+    }
+
+    /// Stop capturing and return the captured actions.
+    pub fn end(&mut self) -> Vec<A> {
+        self.active = false;
+        std::mem::take(&mut self.captured)
+    }
+
+    /// Check if capture is active.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Actions pressed during the current capture.
+    #[must_use]
+    pub fn captured(&self) -> &[A] {
+        &self.captured
+    }
+
+    /// Route an action's raw pressed state through the capture.
+    ///
+    /// Returns whether the action should be suppressed this frame.
+    pub fn intercept(&mut self, action: A, pressed: bool) -> bool {
+        if !pressed {
+            self.held.remove(&action);
+            return self.active;
+        }
+
+        if self.active {
+            if self.held.insert(action) {
+                self.captured.push(action);
             }
+            return true;
+        }
+
+        self.held.contains(&action)
+    }
+}
+
+/// Component holding the action state driven by a single gamepad.
+///
+/// Added to every gamepad entity and updated in parallel, so per-player code
+/// can read its own controller's actions without going through the global
+/// [`ActionState`]. Only this gamepad's bindings feed it; keyboard and mouse
+/// input and safety gates apply to the global state only.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct GamepadActionState<A: Actionlike = GameAction> {
+    /// Action state from this gamepad's bindings.
+    actions: ActionState<A>,
+    /// Stick direction processors for this gamepad.
+    sticks: HashMap<Stick, Stick8Way>,
+}
+
+impl<A: Actionlike> Default for GamepadActionState<A> {
+    fn default() -> Self {
+        Self {
+            actions: ActionState::default(),
+            sticks: HashMap::new(),
         }
+    }
+}
+
+impl<A: Actionlike> GamepadActionState<A> {
+    /// The action state from this gamepad.
+    #[must_use]
+    pub const fn actions(&self) -> &ActionState<A> {
+        &self.actions
+    }
+
+    /// Force an action inactive for this frame.
+    pub(crate) fn suppress(&mut self, action: A, reason: &'static str) {
+        self.actions.suppress(action, reason);
+    }
 
-        // Check mouse bindings
-        if !pressed && let Some(buttons) = action_map.mouse_bindings.get(action) {
-            for button in buttons {
-                if mouse_buttons.pressed(*button) {
-                    pressed = true;
-                    value = 1.0;
-                    break;
+    /// Update from a gamepad's current input.
+    pub fn update(&mut self, action_map: &ActionMap<A>, gamepad: &Gamepad) {
+        self.actions.reset_frame_state();
+
+        // Resolve stick directions once per update
+        let mut stick_values: HashMap<A, f32> = HashMap::new();
+        for (stick, binding) in &action_map.stick_direction_bindings {
+            let processor = self
+                .sticks
+                .entry(*stick)
+                .or_insert_with(|| binding.processor.clone());
+            processor.copy_settings_from(&binding.processor);
+
+            let position = stick.read(gamepad);
+            if let Some(direction) = processor.update(position) {
+                for (action, value) in binding.actions(direction, position) {
+                    let entry = stick_values.entry(action).or_default();
+                    *entry = entry.max(value);
                 }
             }
         }
 
-        // Check gamepad bindings
-        if !pressed {
-            for gamepad in gamepads.iter() {
-                // Check button bindings
-                if let Some(buttons) = action_map.gamepad_bindings.get(action) {
-                    for button_type in buttons {
-                        if gamepad.pressed(*button_type) {
-                            pressed = true;
-                            value = 1.0;
-                            break;
-                        }
-                    }
-                }
+        // Buttons on an active modifier layer don't fire their own bindings
+        let shifted = action_map.shifted_buttons(|modifier| gamepad.pressed(modifier));
+
+        for action in A::all() {
+            let mut pressed = false;
+            let mut value = 0.0f32;
+
+            // Check button bindings
+            if let Some(buttons) = action_map.gamepad_bindings.get(action)
+                && buttons
+                    .iter()
+                    .any(|button| gamepad.pressed(*button) && !shifted.contains(button))
+            {
+                pressed = true;
+                value = 1.0;
+            }
+
+            // Check modifier-layer bindings
+            if !pressed
+                && let Some(bindings) = action_map.modifier_bindings.get(action)
+                && bindings.iter().any(|(modifier, button)| {
+                    gamepad.pressed(*modifier) && gamepad.pressed(*button)
+                })
+            {
+                pressed = true;
+                value = 1.0;
+            }
 
-                // Check axis bindings
-                if !pressed && let Some(axes) = action_map.axis_bindings.get(action) {
-                    for (axis_type, direction, threshold) in axes {
-                        if let Some(axis_value) = gamepad.get(*axis_type) {
-                            let check_value = match direction {
-                                AxisDirection::Positive => axis_value,
-                                AxisDirection::Negative => -axis_value,
-                            };
-
-                            if check_value > *threshold {
-                                pressed = true;
-                                value = value.max(check_value);
-                            }
+            // Check axis bindings
+            if !pressed && let Some(axes) = action_map.axis_bindings.get(action) {
+                for (axis_type, direction, threshold) in axes {
+                    if let Some(axis_value) = gamepad.get(*axis_type) {
+                        let check_value = match direction {
+                            AxisDirection::Positive => axis_value,
+                            AxisDirection::Negative => -axis_value,
+                        };
+
+                        if check_value > *threshold {
+                            pressed = true;
+                            value = value.max(check_value);
                         }
                     }
                 }
+            }
 
-                if pressed {
-                    break;
-                }
+            // Check stick direction bindings
+            if let Some(stick_value) = stick_values.get(action) {
+                pressed = true;
+                value = value.max(*stick_value);
             }
+
+            self.actions.set_pressed(*action, pressed);
+            self.actions.set_value(*action, value);
+        }
+    }
+}
+
+/// System to give every gamepad a [`GamepadActionState`].
+pub fn insert_gamepad_action_states<A: Actionlike>(
+    mut commands: Commands,
+    gamepads: Query<Entity, (With<Gamepad>, Without<GamepadActionState<A>>)>,
+) {
+    for entity in &gamepads {
+        commands
+            .entity(entity)
+            .insert(GamepadActionState::<A>::default());
+    }
+}
+
+/// System to update each gamepad's action state in parallel.
+///
+/// Gamepads owned by a player with their own bindings in
+/// [`PlayerActionMaps`] use those instead of the global [`ActionMap`].
+pub fn update_gamepad_action_states<A: Actionlike>(
+    action_map: Res<ActionMap<A>>,
+    ownership: Res<ControllerOwnership>,
+    player_maps: Res<PlayerActionMaps<A>>,
+    mut gamepads: Query<(Entity, &Gamepad, &mut GamepadActionState<A>)>,
+) {
+    gamepads
+        .par_iter_mut()
+        .for_each(|(entity, gamepad, mut state)| {
+            let map = player_maps.resolve(ownership.get_owner(entity), &action_map);
+            state.update(map, gamepad);
+        });
+}
+
+/// Raw input for an action from keyboard, mouse, and gamepads, as
+/// `(pressed, value)`.
+pub(crate) fn sample_action_input<'a, A: Actionlike>(
+    action: A,
+    action_map: &ActionMap<A>,
+    accepts_mouse_keyboard: bool,
+    keyboard: &ButtonInput<KeyCode>,
+    mouse_buttons: &ButtonInput<MouseButton>,
+    gamepads: impl IntoIterator<Item = &'a GamepadActionState<A>>,
+) -> (bool, f32) {
+    let mut pressed = false;
+    let mut value = 0.0f32;
+
+    // Check keyboard bindings
+    if accepts_mouse_keyboard
+        && let Some(keys) = action_map.key_bindings.get(&action)
+        && keys.iter().any(|key| keyboard.pressed(*key))
+    {
+        pressed = true;
+        value = 1.0;
+    }
+
+    // Check mouse bindings
+    if !pressed
+        && accepts_mouse_keyboard
+        && let Some(buttons) = action_map.mouse_bindings.get(&action)
+        && buttons.iter().any(|button| mouse_buttons.pressed(*button))
+    {
+        pressed = true;
+        value = 1.0;
+    }
+
+    // Combine gamepads
+    for gamepad in gamepads {
+        if gamepad.actions.pressed(action) {
+            pressed = true;
+            value = value.max(gamepad.actions.value(action));
+        }
+    }
+
+    (pressed, value)
+}
+
+/// Apply an action's raw input to the action state, merging in
+/// [`SimulatedInput`] and routing it through [`InputCapture`].
+pub(crate) fn apply_action_input<A: Actionlike>(
+    state: &mut ActionState<A>,
+    action: A,
+    (mut pressed, mut value): (bool, f32),
+    simulated: &SimulatedInput<A>,
+    capture: &mut InputCapture<A>,
+    gamepads: &mut Query<&mut GamepadActionState<A>>,
+) {
+    // Scripted input
+    apply_simulated(simulated, action, &mut pressed, &mut value);
+
+    state.set_pressed(action, pressed);
+    state.set_value(action, value);
+    if capture.intercept(action, pressed) {
+        state.suppress(action, "input capture");
+        for mut gamepad in gamepads {
+            gamepad.actions.suppress(action, "input capture");
         }
+    }
+}
+
+/// System to update action states from input.
+///
+/// Combines keyboard and mouse input with every gamepad's
+/// [`GamepadActionState`] and any [`SimulatedInput`].
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn update_action_state<A: Actionlike>(
+    mut state: ResMut<ActionState<A>>,
+    action_map: Res<ActionMap<A>>,
+    device_state: Res<InputDeviceState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut gamepads: Query<&mut GamepadActionState<A>>,
+    mut capture: ResMut<InputCapture<A>>,
+    simulated: Res<SimulatedInput<A>>,
+) {
+    // Reset frame state
+    state.reset_frame_state();
+
+    let accepts_mouse_keyboard = device_state.accepts_mouse_keyboard();
 
-        state.set_pressed(*action, pressed);
-        state.set_value(*action, value);
+    // Check all actions
+    for action in A::all() {
+        let input = sample_action_input(
+            *action,
+            &action_map,
+            accepts_mouse_keyboard,
+            &keyboard,
+            &mouse_buttons,
+            &gamepads,
+        );
+        apply_action_input(
+            &mut state,
+            *action,
+            input,
+            &simulated,
+            &mut capture,
+            &mut gamepads,
+        );
     }
 }
 
+/// System to keep Confirm/Cancel bindings in sync with the confirm convention.
+///
+/// Confirm/Cancel bindings the player moved off the previous convention's
+/// face buttons are left alone.
+pub fn sync_confirm_style(
+    config: Res<ControllerConfig>,
+    mut action_map: ResMut<ActionMap>,
+    mut applied: Local<Option<ConfirmStyle>>,
+) {
+    let style = config.confirm_style();
+    // Default bindings follow the South convention.
+    let previous = applied.unwrap_or(ConfirmStyle::South);
+    if previous != style {
+        action_map.switch_confirm_style(previous, style);
+    }
+    *applied = Some(style);
+}
+
 /// Plugin for registering action types and systems.
 pub(crate) fn register_action_types(app: &mut App) {
     app.register_type::<GameAction>()
         .register_type::<ActionMap>()
         .register_type::<ActionState>()
+        .register_type::<InputBinding>()
+        .register_type::<AxisDirection>()
+        .register_type::<StickDirectionBinding>()
+        .register_type::<GamepadActionState>()
         .init_resource::<ActionMap>()
-        .init_resource::<ActionState>();
+        .init_resource::<ActionState>()
+        .init_resource::<InputCapture>();
 }
 
 /// Add action systems to the app.
-pub(crate) fn add_action_systems(app: &mut App) {
-    app.add_systems(PreUpdate, update_action_state);
+pub(crate) fn add_action_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        (
+            sync_confirm_style,
+            insert_gamepad_action_states::<GameAction>,
+            update_gamepad_action_states::<GameAction>,
+            update_action_state::<GameAction>.run_if(crate::debug::input_pipeline_running),
+        )
+            .chain(),
+    );
+}
+
+/// Plugin for a game-defined action enum.
+///
+/// Adds an [`ActionMap`], [`ActionState`], [`InputCapture`],
+/// [`SimulatedInput`], [`PlayerActionMaps`], and [`PlayerActionStates`] for
+/// `A`, a [`GamepadActionState<A>`] on every gamepad, and the systems that
+/// update them. Add it alongside
+/// [`ControllerPlugin`](crate::plugin::ControllerPlugin), which provides
+/// device detection and keeps running the built-in [`GameAction`] set.
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_archie::action_enum;
+/// use bevy_archie::prelude::*;
+///
+/// action_enum! {
+///     pub enum MyAction {
+///         Jump => "Jump" { remappable: true, required: true },
+///         Dash => "Dash" { remappable: true, required: false },
+///     }
+/// }
+///
+/// fn jump(actions: Res<ActionState<MyAction>>) {
+///     if actions.just_pressed(MyAction::Jump) {
+///         info!("Jump!");
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(ControllerPlugin::default())
+///     .add_plugins(ActionPlugin::<MyAction>::default())
+///     .add_systems(Update, jump)
+///     .run();
+/// ```
+pub struct ActionPlugin<A: Actionlike> {
+    /// Schedule the action systems run in (default: `PreUpdate`).
+    pub schedule: InternedScheduleLabel,
+    _actions: PhantomData<A>,
+}
+
+impl<A: Actionlike> Default for ActionPlugin<A> {
+    fn default() -> Self {
+        Self {
+            schedule: PreUpdate.intern(),
+            _actions: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ActionPlugin<A> {
+    /// Run the action systems in a custom schedule.
+    #[must_use]
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl<A: Actionlike> Plugin for ActionPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActionMap<A>>()
+            .init_resource::<ActionState<A>>()
+            .init_resource::<InputCapture<A>>()
+            .init_resource::<SimulatedInput<A>>()
+            .init_resource::<ControllerOwnership>()
+            .init_resource::<PlayerActionMaps<A>>()
+            .init_resource::<PlayerActionStates<A>>()
+            .init_resource::<crate::debug::InputStepper>()
+            .add_systems(
+                self.schedule,
+                (
+                    insert_gamepad_action_states::<A>,
+                    update_gamepad_action_states::<A>,
+                    update_action_state::<A>.run_if(crate::debug::input_pipeline_running),
+                    update_player_action_states::<A>,
+                    advance_simulated_input::<A>,
+                )
+                    .chain()
+                    .after(update_action_state::<GameAction>),
+            );
+    }
+}
+
+/// Define a custom action enum with the same helpers as [`GameAction`].
+///
+/// Generates the enum plus `all()`, `display_name()`, `is_remappable()`,
+/// `is_required()`, and a `default_bindings()` table, so adding an action
+/// can't leave icon or remapping code with an incomplete list. The enum
+/// implements [`Actionlike`], so it works with [`ActionMap`], [`ActionState`],
+/// and [`ActionPlugin`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_archie::action_enum;
+/// use bevy_archie::actions::InputBinding;
+///
+/// action_enum! {
+///     /// Actions for my game.
+///     pub enum MyAction {
+///         Jump => "Jump" {
+///             remappable: true,
+///             required: true,
+///             bindings: [
+///                 InputBinding::GamepadButton(GamepadButton::South),
+///                 InputBinding::Key(KeyCode::Space),
+///             ],
+///         },
+///         Menu => "Open Menu" { remappable: false, required: true },
+///     }
+/// }
+///
+/// assert_eq!(MyAction::all().len(), 2);
+/// assert_eq!(MyAction::Menu.display_name(), "Open Menu");
+/// assert!(MyAction::Menu.default_bindings().is_empty());
+/// ```
+#[macro_export]
+macro_rules! action_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $display:literal {
+                    remappable: $remappable:expr,
+                    required: $required:expr
+                    $(, bindings: [$($binding:expr),* $(,)?])?
+                    $(,)?
+                }
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Get all actions as a slice.
+            #[must_use]
+            pub fn all() -> &'static [$name] {
+                &[$(Self::$variant),*]
+            }
+
+            /// Get the display name for this action.
+            #[must_use]
+            pub const fn display_name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $display,)*
+                }
+            }
+
+            /// Whether this action can be remapped by the player.
+            #[must_use]
+            pub const fn is_remappable(self) -> bool {
+                // Variants have no explicit discriminants, so they index in order.
+                const REMAPPABLE: &[bool] = &[$($remappable),*];
+                REMAPPABLE[self as usize]
+            }
+
+            /// Whether this action requires a binding (cannot be unbound).
+            #[must_use]
+            pub const fn is_required(self) -> bool {
+                const REQUIRED: &[bool] = &[$($required),*];
+                REQUIRED[self as usize]
+            }
+
+            /// Get the default bindings for this action.
+            #[must_use]
+            pub fn default_bindings(self) -> Vec<$crate::actions::InputBinding> {
+                match self {
+                    $(Self::$variant => vec![$($($binding),*)?],)*
+                }
+            }
+        }
+
+        impl $crate::actions::Actionlike for $name {
+            fn all() -> &'static [Self] {
+                Self::all()
+            }
+
+            fn display_name(self) -> &'static str {
+                Self::display_name(self)
+            }
+
+            fn is_remappable(self) -> bool {
+                Self::is_remappable(self)
+            }
+
+            fn is_required(self) -> bool {
+                Self::is_required(self)
+            }
+
+            fn default_bindings(self) -> Vec<$crate::actions::InputBinding> {
+                Self::default_bindings(self)
+            }
+        }
+    };
 }
 
 #[cfg(test)]
@@ -530,6 +1315,93 @@ mod tests {
         assert_eq!(all_actions.len(), 24);
     }
 
+    crate::action_enum! {
+        enum TestAction {
+            Jump => "Jump" {
+                remappable: true,
+                required: true,
+                bindings: [InputBinding::GamepadButton(GamepadButton::South)],
+            },
+            Menu => "Open Menu" { remappable: false, required: false },
+        }
+    }
+
+    #[test]
+    fn test_action_enum_macro() {
+        assert_eq!(TestAction::all(), &[TestAction::Jump, TestAction::Menu]);
+        assert_eq!(TestAction::Menu.display_name(), "Open Menu");
+        assert!(TestAction::Jump.is_remappable());
+        assert!(!TestAction::Menu.is_remappable());
+        assert!(TestAction::Jump.is_required());
+        assert!(!TestAction::Menu.is_required());
+        assert_eq!(
+            TestAction::Jump.default_bindings(),
+            vec![InputBinding::GamepadButton(GamepadButton::South)]
+        );
+        assert!(TestAction::Menu.default_bindings().is_empty());
+    }
+
+    #[test]
+    fn test_custom_action_map_and_state() {
+        let map = ActionMap::<TestAction>::default();
+        assert_eq!(
+            map.primary_gamepad_button(TestAction::Jump),
+            Some(GamepadButton::South)
+        );
+        assert!(map.stick_direction_bindings.is_empty());
+
+        let mut gamepad = Gamepad::default();
+        let mut state = GamepadActionState::<TestAction>::default();
+        gamepad.digital_mut().press(GamepadButton::South);
+        state.update(&map, &gamepad);
+        assert!(state.actions().just_pressed(TestAction::Jump));
+        assert!(!state.actions().pressed(TestAction::Menu));
+    }
+
+    #[test]
+    fn test_action_plugin_updates_custom_actions() {
+        let mut app = App::new();
+        app.init_resource::<InputDeviceState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Time>()
+            .add_plugins(ActionPlugin::<TestAction>::default());
+        app.world_mut()
+            .resource_mut::<ActionMap<TestAction>>()
+            .bind_key(TestAction::Menu, KeyCode::Tab);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Tab);
+        app.update();
+        let state = app.world().resource::<ActionState<TestAction>>();
+        assert!(state.just_pressed(TestAction::Menu));
+        assert!(!state.pressed(TestAction::Jump));
+    }
+
+    #[test]
+    fn test_action_plugin_respects_frozen_pipeline() {
+        let mut app = App::new();
+        app.init_resource::<InputDeviceState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Time>()
+            .add_plugins(ActionPlugin::<TestAction>::default());
+        app.world_mut()
+            .resource_mut::<ActionMap<TestAction>>()
+            .bind_key(TestAction::Menu, KeyCode::Tab);
+        app.world_mut()
+            .resource_mut::<crate::debug::InputStepper>()
+            .freeze();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Tab);
+        app.update();
+        let state = app.world().resource::<ActionState<TestAction>>();
+        assert!(!state.pressed(TestAction::Menu));
+    }
+
     #[test]
     fn test_game_action_display_names() {
         assert_eq!(GameAction::Confirm.display_name(), "Confirm");
@@ -582,6 +1454,142 @@ mod tests {
         // Check that default bindings exist for core actions
         assert!(map.primary_gamepad_button(GameAction::Confirm).is_some());
         assert!(map.primary_gamepad_button(GameAction::Cancel).is_some());
+        assert_eq!(
+            map.key_bindings[&GameAction::Confirm],
+            vec![KeyCode::Enter, KeyCode::Space]
+        );
+        assert_eq!(
+            map.gamepad_bindings[&GameAction::PageLeft],
+            vec![GamepadButton::LeftTrigger]
+        );
+    }
+
+    #[test]
+    fn test_action_map_bind_input_binding() {
+        let mut map = ActionMap::<GameAction>::empty();
+        map.bind(
+            GameAction::Custom1,
+            InputBinding::GamepadAxis(GamepadAxis::LeftZ, AxisDirection::Positive),
+        );
+        map.bind(
+            GameAction::Custom1,
+            InputBinding::MouseButton(MouseButton::Left),
+        );
+
+        assert_eq!(
+            map.axis_bindings[&GameAction::Custom1],
+            vec![(
+                GamepadAxis::LeftZ,
+                AxisDirection::Positive,
+                DEFAULT_AXIS_THRESHOLD
+            )]
+        );
+        assert!(map.mouse_bindings.contains_key(&GameAction::Custom1));
+        assert!(map.gamepad_bindings.is_empty());
+    }
+
+    #[test]
+    fn test_action_map_default_stick_directions() {
+        let map = ActionMap::default();
+        let left = &map.stick_direction_bindings[&Stick::Left];
+        assert_eq!(left.up, Some(GameAction::Up));
+        assert!(!map.axis_bindings.contains_key(&GameAction::Up));
+
+        let actions: Vec<_> = left
+            .actions(StickDirection::UpRight, Vec2::new(0.7, 0.7))
+            .map(|(action, _)| action)
+            .collect();
+        assert_eq!(actions, vec![GameAction::Up, GameAction::Right]);
+    }
+
+    #[test]
+    fn test_clear_gamepad_bindings_clears_stick_direction() {
+        let mut map = ActionMap::default();
+        map.clear_gamepad_bindings(GameAction::Up);
+
+        let left = &map.stick_direction_bindings[&Stick::Left];
+        assert_eq!(left.up, None);
+        assert_eq!(left.down, Some(GameAction::Down));
+    }
+
+    #[test]
+    fn test_modifier_layer_replaces_base_binding_while_held() {
+        let mut map = ActionMap::default();
+        map.bind_gamepad_with_modifier(
+            GameAction::Custom1,
+            GamepadButton::LeftTrigger,
+            GamepadButton::West,
+        );
+        assert_eq!(
+            map.modifier_buttons(),
+            HashSet::from([GamepadButton::LeftTrigger])
+        );
+
+        let mut gamepad = Gamepad::default();
+        let mut state = GamepadActionState::default();
+        gamepad.digital_mut().press(GamepadButton::West);
+        state.update(&map, &gamepad);
+        assert!(state.actions().pressed(GameAction::Primary));
+        assert!(!state.actions().pressed(GameAction::Custom1));
+
+        gamepad.digital_mut().press(GamepadButton::LeftTrigger);
+        state.update(&map, &gamepad);
+        assert!(!state.actions().pressed(GameAction::Primary));
+        assert!(state.actions().pressed(GameAction::Custom1));
+        // The modifier keeps its own binding
+        assert!(state.actions().pressed(GameAction::LeftShoulder));
+
+        map.rebind_modifier(GamepadButton::LeftTrigger, GamepadButton::RightTrigger);
+        state.update(&map, &gamepad);
+        assert!(state.actions().pressed(GameAction::Primary));
+        assert_eq!(
+            map.primary_modifier_binding(GameAction::Custom1),
+            Some((GamepadButton::RightTrigger, GamepadButton::West))
+        );
+    }
+
+    #[test]
+    fn test_action_map_serde_round_trip() {
+        let mut map = ActionMap::default();
+        map.bind_gamepad(GameAction::Custom1, GamepadButton::West);
+
+        let json = serde_json::to_string(&map).unwrap();
+        let loaded: ActionMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            loaded.gamepad_bindings.get(&GameAction::Custom1),
+            Some(&vec![GamepadButton::West])
+        );
+        assert_eq!(loaded.key_bindings, map.key_bindings);
+        assert!(loaded.stick_direction_bindings.contains_key(&Stick::Left));
+
+        let empty: ActionMap = serde_json::from_str("{}").unwrap();
+        assert!(empty.gamepad_bindings.is_empty());
+        assert_eq!(
+            empty.stick_direction_bindings,
+            GameAction::default_stick_directions()
+        );
+    }
+
+    #[test]
+    fn test_stick_direction_bindings_round_trip() {
+        let mut map = ActionMap::default();
+        map.bind_stick_directions(
+            Stick::Right,
+            StickDirectionBinding::new(
+                Stick8Way::new(DirectionMode::FourWay).with_recenter(true),
+                GameAction::Custom1,
+                GameAction::Custom2,
+                GameAction::Custom3,
+                GameAction::Custom4,
+            ),
+        );
+
+        let json = serde_json::to_string(&map).unwrap();
+        let loaded: ActionMap = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            loaded.stick_direction_bindings,
+            map.stick_direction_bindings
+        );
     }
 
     #[test]
@@ -624,6 +1632,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_action_map_apply_confirm_style() {
+        let mut map = ActionMap::default();
+        map.bind_gamepad(GameAction::Confirm, GamepadButton::Start);
+
+        map.apply_confirm_style(ConfirmStyle::East);
+        assert_eq!(
+            map.primary_gamepad_button(GameAction::Confirm),
+            Some(GamepadButton::East)
+        );
+        assert_eq!(
+            map.primary_gamepad_button(GameAction::Cancel),
+            Some(GamepadButton::South)
+        );
+        assert!(map.gamepad_bindings[&GameAction::Confirm].contains(&GamepadButton::Start));
+        assert!(!map.gamepad_bindings[&GameAction::Confirm].contains(&GamepadButton::South));
+
+        map.apply_confirm_style(ConfirmStyle::South);
+        assert_eq!(
+            map.primary_gamepad_button(GameAction::Confirm),
+            Some(GamepadButton::South)
+        );
+        assert_eq!(map.gamepad_bindings[&GameAction::Cancel].len(), 1);
+    }
+
+    #[test]
+    fn test_switch_confirm_style_keeps_rebinds() {
+        let mut map = ActionMap::default();
+        map.gamepad_bindings
+            .insert(GameAction::Confirm, vec![GamepadButton::North]);
+
+        map.switch_confirm_style(ConfirmStyle::South, ConfirmStyle::East);
+        assert_eq!(
+            map.gamepad_bindings[&GameAction::Confirm],
+            vec![GamepadButton::North]
+        );
+        assert_eq!(
+            map.primary_gamepad_button(GameAction::Cancel),
+            Some(GamepadButton::South)
+        );
+    }
+
+    #[test]
+    fn test_custom_confirm_binding_survives_startup() {
+        let mut app = App::new();
+        let config = ControllerConfig {
+            confirm_style: ConfirmStyle::East,
+            ..default()
+        };
+        let mut map = ActionMap::default();
+        map.gamepad_bindings
+            .insert(GameAction::Confirm, vec![GamepadButton::North]);
+        app.insert_resource(config)
+            .insert_resource(map)
+            .add_systems(Update, sync_confirm_style);
+
+        app.update();
+        let map = app.world().resource::<ActionMap>();
+        assert_eq!(
+            map.gamepad_bindings[&GameAction::Confirm],
+            vec![GamepadButton::North]
+        );
+        assert_eq!(
+            map.primary_gamepad_button(GameAction::Cancel),
+            Some(GamepadButton::South)
+        );
+    }
+
     #[test]
     fn test_action_state_pressed() {
         let mut state = ActionState::default();
@@ -673,6 +1749,34 @@ mod tests {
         assert!(!state.pressed(GameAction::Confirm));
     }
 
+    #[test]
+    fn test_input_capture_buffers_presses() {
+        let mut capture = InputCapture::default();
+        capture.begin();
+
+        assert!(capture.intercept(GameAction::Primary, true));
+        assert!(capture.intercept(GameAction::Primary, true));
+        assert!(capture.intercept(GameAction::Secondary, false));
+        assert_eq!(capture.captured(), &[GameAction::Primary]);
+
+        assert_eq!(capture.end(), vec![GameAction::Primary]);
+        assert!(!capture.is_active());
+    }
+
+    #[test]
+    fn test_input_capture_suppresses_until_release() {
+        let mut capture = InputCapture::default();
+        capture.begin();
+        capture.intercept(GameAction::Primary, true);
+        capture.end();
+
+        // Still held from the capture
+        assert!(capture.intercept(GameAction::Primary, true));
+        assert!(!capture.intercept(GameAction::Primary, false));
+        assert!(!capture.intercept(GameAction::Primary, true));
+        assert!(!capture.intercept(GameAction::Secondary, true));
+    }
+
     #[test]
     fn test_axis_direction_variants() {
         let pos = AxisDirection::Positive;
@@ -0,0 +1,515 @@
+//! Arbitration between competing interpretations of the same presses.
+//!
+//! Double-tap and long-press modifiers and combos can all claim the same
+//! action presses. Instead of writing their events directly, the detectors
+//! submit [`Claim`]s to the [`InputArbiter`], which decides once per frame
+//! which interpretations fire and then writes the [`ModifiedActionEvent`]
+//! and [`ComboDetected`] messages for the winners.
+//!
+//! # Priority rules
+//!
+//! Claims compete when they cover at least one common action on the same
+//! gamepad. A claim without a gamepad competes with claims from every
+//! gamepad:
+//!
+//! 1. The higher priority wins. Defaults are combos 30, double tap and long
+//!    press 20, hold 10, and tap 0. [`InputArbiter::set_priority`] overrides
+//!    the priority of a single definition.
+//! 2. On equal priority, the claim covering more actions wins.
+//! 3. Remaining ties all fire.
+//! 4. A winner keeps its actions claimed for
+//!    [`claim_window`](InputArbiter::claim_window) seconds, so a later claim
+//!    with lower or equal priority on the same actions loses too (a double
+//!    tap that completes after a combo on the same presses).
+//! 5. [`ActionModifier::Released`] is a lifecycle event and always fires.
+//!
+//! Chords are settled earlier by their clash strategy and reach the arbiter
+//! as ordinary action presses.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::arbitration::{InputArbiter, Interpretation};
+//!
+//! fn setup_arbitration(mut arbiter: ResMut<InputArbiter>) {
+//!     // Let double-tap dodges win over the Fireball combo
+//!     arbiter.set_priority(Interpretation::combo("Fireball"), 15);
+//!     arbiter.trace = true;
+//! }
+//!
+//! fn explain(arbiter: Res<InputArbiter>) {
+//!     for decision in arbiter.last_trace() {
+//!         info!("{:?}: {}", decision.interpretation, decision.reason);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use log::debug;
+use std::collections::HashMap;
+
+use crate::action_modifiers::{ActionModifier, ModifiedActionEvent};
+use crate::actions::GameAction;
+use crate::input_buffer::ComboDetected;
+
+/// One way of interpreting a set of presses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Interpretation {
+    /// A modifier on an action (double tap, long press, ...).
+    Modifier {
+        /// The base action.
+        action: GameAction,
+        /// The modifier.
+        modifier: ActionModifier,
+    },
+    /// A named combo.
+    Combo {
+        /// Combo name.
+        name: String,
+    },
+}
+
+impl Interpretation {
+    /// A modifier interpretation.
+    #[must_use]
+    pub const fn modifier(action: GameAction, modifier: ActionModifier) -> Self {
+        Self::Modifier { action, modifier }
+    }
+
+    /// A combo interpretation.
+    #[must_use]
+    pub fn combo(name: impl Into<String>) -> Self {
+        Self::Combo { name: name.into() }
+    }
+
+    /// Priority used when no override is set.
+    #[must_use]
+    pub const fn default_priority(&self) -> i32 {
+        match self {
+            Self::Combo { .. } => 30,
+            Self::Modifier { modifier, .. } => match modifier {
+                ActionModifier::DoubleTap | ActionModifier::LongPress => 20,
+                ActionModifier::Hold => 10,
+                ActionModifier::Tap | ActionModifier::Released => 0,
+            },
+        }
+    }
+}
+
+/// A detector's claim on some action presses.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Claim {
+    /// What the presses would mean.
+    pub interpretation: Interpretation,
+    /// Actions whose presses are claimed.
+    pub actions: Vec<GameAction>,
+    /// Gamepad that produced the presses (if known).
+    pub gamepad: Option<Entity>,
+    /// Duration held (for Hold/LongPress).
+    pub duration: f32,
+}
+
+impl Claim {
+    /// Claim an action's press for a modifier.
+    #[must_use]
+    pub fn modifier(action: GameAction, modifier: ActionModifier, duration: f32) -> Self {
+        Self {
+            interpretation: Interpretation::modifier(action, modifier),
+            actions: vec![action],
+            gamepad: None,
+            duration,
+        }
+    }
+
+    /// Claim a combo's sequence.
+    #[must_use]
+    pub fn combo(name: impl Into<String>, sequence: &[GameAction]) -> Self {
+        Self {
+            interpretation: Interpretation::combo(name),
+            actions: sequence.to_vec(),
+            gamepad: None,
+            duration: 0.0,
+        }
+    }
+
+    /// Set the gamepad that produced the presses.
+    #[must_use]
+    pub const fn with_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+
+    /// Whether two claims cover a common action on the same gamepad.
+    ///
+    /// A claim without a gamepad matches every gamepad.
+    fn overlaps(&self, other: &Self) -> bool {
+        let same_gamepad = match (self.gamepad, other.gamepad) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        same_gamepad
+            && self
+                .actions
+                .iter()
+                .any(|action| other.actions.contains(action))
+    }
+}
+
+/// Why a claim was accepted or rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArbitrationDecision {
+    /// The interpretation that was judged.
+    pub interpretation: Interpretation,
+    /// Whether it fired.
+    pub accepted: bool,
+    /// Human-readable explanation.
+    pub reason: String,
+}
+
+/// Resource arbitrating claims from modifiers and combos.
+#[derive(Debug, Clone, Resource)]
+pub struct InputArbiter {
+    /// When false every claim fires, as if there were no arbitration.
+    pub enabled: bool,
+    /// Seconds a winning claim keeps its actions claimed.
+    pub claim_window: f32,
+    /// Log every decision at debug level.
+    pub trace: bool,
+    /// Per-definition priority overrides.
+    overrides: HashMap<Interpretation, i32>,
+    /// Claims submitted this frame.
+    pending: Vec<Claim>,
+    /// Recent winners with their priority and time.
+    recent: Vec<(Claim, i32, f64)>,
+    /// Decisions from the last frame with claims.
+    last_trace: Vec<ArbitrationDecision>,
+}
+
+impl Default for InputArbiter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            claim_window: 0.3,
+            trace: false,
+            overrides: HashMap::new(),
+            pending: Vec::new(),
+            recent: Vec::new(),
+            last_trace: Vec::new(),
+        }
+    }
+}
+
+impl InputArbiter {
+    /// Override the priority of one interpretation.
+    pub fn set_priority(&mut self, interpretation: Interpretation, priority: i32) {
+        self.overrides.insert(interpretation, priority);
+    }
+
+    /// Remove a priority override.
+    pub fn clear_priority(&mut self, interpretation: &Interpretation) {
+        self.overrides.remove(interpretation);
+    }
+
+    /// Effective priority of an interpretation.
+    #[must_use]
+    pub fn priority(&self, interpretation: &Interpretation) -> i32 {
+        self.overrides
+            .get(interpretation)
+            .copied()
+            .unwrap_or_else(|| interpretation.default_priority())
+    }
+
+    /// Submit a claim for this frame.
+    pub fn submit(&mut self, claim: Claim) {
+        self.pending.push(claim);
+    }
+
+    /// Decisions from the last frame that had claims.
+    #[must_use]
+    pub fn last_trace(&self) -> &[ArbitrationDecision] {
+        &self.last_trace
+    }
+
+    /// Decide this frame's claims, returning the ones that fire.
+    ///
+    /// `now` is the elapsed time in seconds.
+    pub fn resolve(&mut self, now: f64) -> Vec<Claim> {
+        let window = f64::from(self.claim_window);
+        self.recent.retain(|(_, _, time)| now - time <= window);
+
+        let pending = std::mem::take(&mut self.pending);
+        if pending.is_empty() {
+            return Vec::new();
+        }
+
+        let mut decisions = Vec::with_capacity(pending.len());
+        let mut accepted = Vec::new();
+        for (index, claim) in pending.iter().enumerate() {
+            let priority = self.priority(&claim.interpretation);
+            // Releases always go through so held modifiers can end
+            let release = matches!(
+                claim.interpretation,
+                Interpretation::Modifier {
+                    modifier: ActionModifier::Released,
+                    ..
+                }
+            );
+            let rejection = if !self.enabled || release {
+                None
+            } else {
+                self.rejection(claim, priority, index, &pending)
+            };
+
+            let accepted_claim = rejection.is_none();
+            let reason = rejection.unwrap_or_else(|| format!("accepted at priority {priority}"));
+            if self.trace {
+                debug!(
+                    "Input arbitration: {:?} {}: {reason}",
+                    claim.interpretation,
+                    if accepted_claim { "fired" } else { "dropped" }
+                );
+            }
+            decisions.push(ArbitrationDecision {
+                interpretation: claim.interpretation.clone(),
+                accepted: accepted_claim,
+                reason,
+            });
+            if accepted_claim {
+                accepted.push(claim.clone());
+            }
+        }
+
+        for claim in &accepted {
+            let priority = self.priority(&claim.interpretation);
+            self.recent.push((claim.clone(), priority, now));
+        }
+        self.last_trace = decisions;
+        accepted
+    }
+
+    /// Explain why a claim loses, or `None` when it fires.
+    fn rejection(
+        &self,
+        claim: &Claim,
+        priority: i32,
+        index: usize,
+        pending: &[Claim],
+    ) -> Option<String> {
+        for (other_index, other) in pending.iter().enumerate() {
+            if other_index == index || !claim.overlaps(other) {
+                continue;
+            }
+            let other_priority = self.priority(&other.interpretation);
+            if other_priority > priority {
+                return Some(format!(
+                    "lost to {:?} (priority {other_priority} > {priority})",
+                    other.interpretation
+                ));
+            }
+            if other_priority == priority && other.actions.len() > claim.actions.len() {
+                return Some(format!(
+                    "lost to {:?} (equal priority, covers more actions)",
+                    other.interpretation
+                ));
+            }
+        }
+
+        self.recent
+            .iter()
+            .find(|(recent, recent_priority, _)| {
+                recent.interpretation != claim.interpretation
+                    && *recent_priority >= priority
+                    && claim.overlaps(recent)
+            })
+            .map(|(recent, recent_priority, _)| {
+                format!(
+                    "presses already claimed by {:?} (priority {recent_priority} >= {priority})",
+                    recent.interpretation
+                )
+            })
+    }
+}
+
+/// System to resolve the frame's claims and write events for the winners.
+pub fn arbitrate_input(
+    mut arbiter: ResMut<InputArbiter>,
+    time: Res<Time>,
+    mut modifier_events: MessageWriter<ModifiedActionEvent>,
+    mut combo_events: MessageWriter<ComboDetected>,
+) {
+    for claim in arbiter.resolve(time.elapsed_secs_f64()) {
+        match claim.interpretation {
+            Interpretation::Modifier { action, modifier } => {
+                modifier_events.write(ModifiedActionEvent {
+                    action,
+                    modifier,
+                    gamepad: claim.gamepad,
+                    duration: claim.duration,
+                });
+            }
+            Interpretation::Combo { name } => {
+                combo_events.write(ComboDetected {
+                    combo: name,
+                    gamepad: claim.gamepad,
+                });
+            }
+        }
+    }
+}
+
+/// Plugin for registering arbitration types.
+pub(crate) fn register_arbitration_types(app: &mut App) {
+    app.init_resource::<InputArbiter>();
+}
+
+/// Add arbitration systems to the app.
+pub(crate) fn add_arbitration_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        arbitrate_input
+            .after(crate::action_modifiers::detect_action_modifiers)
+            .after(crate::input_buffer::detect_combos),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combo_beats_double_tap_on_same_action() {
+        let mut arbiter = InputArbiter::default();
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::DoubleTap,
+            0.0,
+        ));
+        arbiter.submit(Claim::combo(
+            "Flurry",
+            &[GameAction::Primary, GameAction::Primary],
+        ));
+
+        let accepted = arbiter.resolve(1.0);
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].interpretation, Interpretation::combo("Flurry"));
+        assert!(!arbiter.last_trace()[0].accepted);
+        assert!(arbiter.last_trace()[0].reason.contains("lost to"));
+    }
+
+    #[test]
+    fn test_override_changes_winner() {
+        let mut arbiter = InputArbiter::default();
+        let double_tap = Interpretation::modifier(GameAction::Primary, ActionModifier::DoubleTap);
+        arbiter.set_priority(double_tap.clone(), 50);
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::DoubleTap,
+            0.0,
+        ));
+        arbiter.submit(Claim::combo("Flurry", &[GameAction::Primary]));
+
+        let accepted = arbiter.resolve(1.0);
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].interpretation, double_tap);
+    }
+
+    #[test]
+    fn test_unrelated_claims_all_fire() {
+        let mut arbiter = InputArbiter::default();
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::Tap,
+            0.0,
+        ));
+        arbiter.submit(Claim::combo(
+            "Dash",
+            &[GameAction::Right, GameAction::Right],
+        ));
+        assert_eq!(arbiter.resolve(1.0).len(), 2);
+    }
+
+    #[test]
+    fn test_claims_from_different_gamepads_all_fire() {
+        let mut arbiter = InputArbiter::default();
+        let player_one = Entity::from_bits(1);
+        let player_two = Entity::from_bits(2);
+        arbiter.submit(
+            Claim::modifier(GameAction::Primary, ActionModifier::DoubleTap, 0.0)
+                .with_gamepad(player_one),
+        );
+        arbiter.submit(Claim::combo("Flurry", &[GameAction::Primary]).with_gamepad(player_two));
+        assert_eq!(arbiter.resolve(1.0).len(), 2);
+
+        // Player two's recent combo doesn't claim player one's presses
+        arbiter.submit(
+            Claim::modifier(GameAction::Primary, ActionModifier::DoubleTap, 0.0)
+                .with_gamepad(player_one),
+        );
+        assert_eq!(arbiter.resolve(1.1).len(), 1);
+
+        // A claim without a gamepad still competes with both
+        arbiter.submit(
+            Claim::modifier(GameAction::Primary, ActionModifier::DoubleTap, 0.0)
+                .with_gamepad(player_two),
+        );
+        arbiter.submit(Claim::combo("Flurry", &[GameAction::Primary]));
+        let accepted = arbiter.resolve(5.0);
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(accepted[0].interpretation, Interpretation::combo("Flurry"));
+    }
+
+    #[test]
+    fn test_recent_winner_claims_later_presses() {
+        let mut arbiter = InputArbiter::default();
+        arbiter.submit(Claim::combo(
+            "Flurry",
+            &[GameAction::Primary, GameAction::Primary],
+        ));
+        arbiter.resolve(1.0);
+
+        // Double tap completes on release a frame later
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::DoubleTap,
+            0.0,
+        ));
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::Released,
+            0.0,
+        ));
+        let accepted = arbiter.resolve(1.1);
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(
+            accepted[0].interpretation,
+            Interpretation::modifier(GameAction::Primary, ActionModifier::Released)
+        );
+
+        // The claim expires after the window
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::DoubleTap,
+            0.0,
+        ));
+        assert_eq!(arbiter.resolve(2.0).len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_arbiter_passes_everything() {
+        let mut arbiter = InputArbiter {
+            enabled: false,
+            ..default()
+        };
+        arbiter.submit(Claim::modifier(
+            GameAction::Primary,
+            ActionModifier::DoubleTap,
+            0.0,
+        ));
+        arbiter.submit(Claim::combo("Flurry", &[GameAction::Primary]));
+        assert_eq!(arbiter.resolve(1.0).len(), 2);
+    }
+}
@@ -0,0 +1,313 @@
+//! Attract mode: demo playback while the game sits idle.
+//!
+//! After [`AttractMode::idle_timeout`] seconds without real input, a recorded
+//! session is played back through [`SimulatedInput`], so the game reacts
+//! exactly as it did when it was recorded. Any real key, click, mouse motion,
+//! touch, gamepad button, or stick or trigger moved past
+//! [`ControllerConfig::deadzone`] cancels playback and hands control back
+//! immediately.
+//!
+//! Sessions are [`RecordedInput`] lists captured with
+//! [`InputRecorder`](crate::debug::InputRecorder), which can be bundled with
+//! the game as JSON.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::attract_mode::AttractMode;
+//!
+//! fn setup_attract(mut attract: ResMut<AttractMode>) {
+//!     let json = std::fs::read_to_string("assets/demo.json").unwrap();
+//!     let session = serde_json::from_str(&json).unwrap();
+//!     attract.set_session(session);
+//!     attract.idle_timeout = 45.0;
+//! }
+//! ```
+
+use bevy::input::mouse::MouseMotion;
+use bevy::input::touch::TouchInput;
+use bevy::prelude::*;
+
+use crate::actions::GameAction;
+use crate::config::ControllerConfig;
+use crate::debug::{InputPlayback, RecordedInput};
+use crate::simulated_input::SimulatedInput;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Resource configuring and tracking attract mode.
+#[derive(Debug, Clone, Resource)]
+pub struct AttractMode {
+    /// Seconds without real input before playback starts.
+    pub idle_timeout: f32,
+    /// Restart the session when it finishes.
+    pub looping: bool,
+    /// Session to play back.
+    session: Vec<RecordedInput>,
+    /// Playback cursor.
+    playback: InputPlayback,
+    /// Whether the demo is playing.
+    playing: bool,
+    /// Seconds since the last real input.
+    idle_time: f32,
+}
+
+impl Default for AttractMode {
+    fn default() -> Self {
+        Self {
+            idle_timeout: 30.0,
+            looping: true,
+            session: Vec::new(),
+            playback: InputPlayback::default(),
+            playing: false,
+            idle_time: 0.0,
+        }
+    }
+}
+
+impl AttractMode {
+    /// Set the session to play back.
+    pub fn set_session(&mut self, session: Vec<RecordedInput>) {
+        self.session = session;
+    }
+
+    /// The session played back when idle.
+    #[must_use]
+    pub fn session(&self) -> &[RecordedInput] {
+        &self.session
+    }
+
+    /// Whether the demo is playing.
+    #[must_use]
+    pub const fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Seconds since the last real input.
+    #[must_use]
+    pub const fn idle_time(&self) -> f32 {
+        self.idle_time
+    }
+
+    /// Advance attract mode by one frame.
+    ///
+    /// `now` is the elapsed time in seconds. Returns `Some(playing)` when
+    /// playback started or stopped.
+    pub fn step(
+        &mut self,
+        real_input: bool,
+        delta: f32,
+        now: f64,
+        simulated: &mut SimulatedInput,
+    ) -> Option<bool> {
+        if real_input {
+            self.idle_time = 0.0;
+            return self.playing.then(|| {
+                self.stop(simulated);
+                false
+            });
+        }
+
+        self.idle_time += delta;
+
+        let mut changed = None;
+        if !self.playing {
+            if self.idle_time < self.idle_timeout || self.session.is_empty() {
+                return None;
+            }
+            self.playing = true;
+            self.playback.start(self.session.clone(), now);
+            simulated.clear();
+            changed = Some(true);
+        }
+
+        for input in self.playback.get_next(now) {
+            if input.pressed {
+                simulated.set_value(input.action, input.analog_value.unwrap_or(1.0));
+            } else {
+                simulated.release(input.action);
+            }
+        }
+
+        if !self.playback.playing {
+            simulated.clear();
+            if self.looping {
+                self.playback.start(self.session.clone(), now);
+            } else {
+                self.playing = false;
+                self.idle_time = 0.0;
+                changed = Some(false);
+            }
+        }
+
+        changed
+    }
+
+    /// Stop playback, releasing anything the demo was holding.
+    pub fn stop(&mut self, simulated: &mut SimulatedInput) {
+        if self.playing {
+            self.playing = false;
+            self.playback.stop();
+            simulated.clear();
+        }
+    }
+}
+
+/// Event fired when attract mode starts or stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct AttractModeChanged {
+    /// Whether the demo is now playing.
+    pub playing: bool,
+}
+
+/// Check if a gamepad has a new press, or a stick or trigger past the
+/// deadzone.
+fn gamepad_active(gamepad: &Gamepad, deadzone: f32) -> bool {
+    gamepad.get_just_pressed().next().is_some()
+        || gamepad
+            .analog()
+            .all_axes_and_values()
+            .any(|(_, value)| value.abs() > deadzone)
+}
+
+/// System to start attract mode when idle and cancel it on real input.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn update_attract_mode(
+    mut attract: ResMut<AttractMode>,
+    mut simulated: ResMut<SimulatedInput>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: MessageReader<MouseMotion>,
+    mut touches: MessageReader<TouchInput>,
+    gamepads: Query<&Gamepad>,
+    config: Res<ControllerConfig>,
+    time: Res<Time<Real>>,
+    mut events: MessageWriter<AttractModeChanged>,
+) {
+    let mouse_moved = mouse_motion.read().count() > 0;
+    let touched = touches.read().count() > 0;
+    let real_input = mouse_moved
+        || touched
+        || keyboard.get_just_pressed().next().is_some()
+        || mouse_buttons.get_just_pressed().next().is_some()
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad_active(gamepad, config.deadzone));
+
+    if let Some(playing) = attract.step(
+        real_input,
+        time.delta_secs(),
+        time.elapsed_secs_f64(),
+        &mut simulated,
+    ) {
+        events.write(AttractModeChanged { playing });
+    }
+}
+
+/// Plugin for registering attract mode types.
+pub(crate) fn register_attract_mode_types(app: &mut App) {
+    app.init_resource::<AttractMode>()
+        .add_message::<AttractModeChanged>();
+}
+
+/// Add attract mode systems to the app.
+pub(crate) fn add_attract_mode_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.input,
+        update_attract_mode
+            .before(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::AttractMode)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Vec<RecordedInput> {
+        vec![
+            RecordedInput {
+                action: GameAction::Right,
+                timestamp: 0.0,
+                pressed: true,
+                analog_value: None,
+            },
+            RecordedInput {
+                action: GameAction::Right,
+                timestamp: 1.0,
+                pressed: false,
+                analog_value: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_starts_after_idle_timeout() {
+        let mut attract = AttractMode {
+            idle_timeout: 1.0,
+            looping: false,
+            ..default()
+        };
+        attract.set_session(session());
+        let mut simulated = SimulatedInput::default();
+
+        assert_eq!(attract.step(false, 0.5, 0.5, &mut simulated), None);
+        assert_eq!(attract.step(false, 0.5, 1.0, &mut simulated), Some(true));
+        assert!(simulated.get(GameAction::Right).is_some());
+
+        // Session finishes
+        assert_eq!(attract.step(false, 1.0, 2.0, &mut simulated), Some(false));
+        assert!(!simulated.is_active());
+    }
+
+    #[test]
+    fn test_real_input_cancels_playback() {
+        let mut attract = AttractMode {
+            idle_timeout: 0.0,
+            ..default()
+        };
+        attract.set_session(session());
+        let mut simulated = SimulatedInput::default();
+
+        assert_eq!(attract.step(false, 0.1, 0.1, &mut simulated), Some(true));
+        assert!(attract.is_playing());
+
+        assert_eq!(attract.step(true, 0.1, 0.2, &mut simulated), Some(false));
+        assert!(!attract.is_playing());
+        assert!(!simulated.is_active());
+        assert_eq!(attract.idle_time(), 0.0);
+    }
+
+    #[test]
+    fn test_no_session_never_plays() {
+        let mut attract = AttractMode {
+            idle_timeout: 0.0,
+            ..default()
+        };
+        let mut simulated = SimulatedInput::default();
+        assert_eq!(attract.step(false, 10.0, 10.0, &mut simulated), None);
+        assert!(!attract.is_playing());
+    }
+
+    #[test]
+    fn test_stick_past_deadzone_is_real_input() {
+        let mut gamepad = Gamepad::default();
+        assert!(!gamepad_active(&gamepad, 0.1));
+
+        gamepad.analog_mut().set(GamepadAxis::LeftStickX, 0.05);
+        assert!(!gamepad_active(&gamepad, 0.1));
+
+        gamepad.analog_mut().set(GamepadAxis::LeftStickX, -0.5);
+        assert!(gamepad_active(&gamepad, 0.1));
+
+        gamepad.analog_mut().set(GamepadAxis::LeftStickX, 0.0);
+        gamepad.analog_mut().set(GamepadButton::RightTrigger2, 0.8);
+        assert!(gamepad_active(&gamepad, 0.1));
+    }
+}
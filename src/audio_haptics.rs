@@ -0,0 +1,258 @@
+//! Rumble generated from audio.
+//!
+//! Like `DualSense` audio haptics, an [`AudioHaptics`] component on a gamepad
+//! turns sound into vibration: the game pushes PCM samples (a sound effect's
+//! buffer, or a copy of what it is playing), and each frame the samples that
+//! would have played are split at [`AudioHaptics::crossover`]. Bass drives
+//! the low-frequency motor and the rest drives the high-frequency motor, each
+//! following the loudness of its band.
+//!
+//! The component is opt-in and does nothing until samples are pushed. Its
+//! output is mixed with other rumble sources through
+//! [`RumbleOutput`] and scaled by the gamepad's [`RumbleScale`](crate::haptics::RumbleScale).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::audio_haptics::AudioHaptics;
+//!
+//! fn play_engine_sound(
+//!     mut commands: Commands,
+//!     gamepads: Query<Entity, With<Gamepad>>,
+//! ) {
+//!     // Mono samples decoded by the game's audio pipeline
+//!     let samples: Vec<f32> = vec![0.0; 48_000];
+//!     for gamepad in &gamepads {
+//!         let mut haptics = AudioHaptics::new(48_000);
+//!         haptics.push(&samples);
+//!         commands.entity(gamepad).insert(haptics);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use crate::haptics::{RumbleIntensity, RumbleOutput, send_rumble_output, update_rumble_scales};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Component that turns audio samples into rumble on its gamepad.
+#[derive(Debug, Clone, Component)]
+#[require(RumbleOutput)]
+pub struct AudioHaptics {
+    /// Sample rate of the pushed audio, in Hz.
+    pub sample_rate: u32,
+    /// Frequency splitting the low and high motor bands, in Hz.
+    pub crossover: f32,
+    /// Multiplier applied to each band's loudness.
+    pub gain: f32,
+    /// Seconds for the motors to fall after the sound gets quieter.
+    pub release: f32,
+    queue: VecDeque<f32>,
+    sample_debt: f32,
+    low_pass: f32,
+    envelope: RumbleIntensity,
+}
+
+impl AudioHaptics {
+    /// Create audio haptics for audio at a sample rate.
+    #[must_use]
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            crossover: 150.0,
+            gain: 1.5,
+            release: 0.08,
+            queue: VecDeque::new(),
+            sample_debt: 0.0,
+            low_pass: 0.0,
+            envelope: RumbleIntensity::none(),
+        }
+    }
+
+    /// Set the crossover frequency.
+    #[must_use]
+    pub const fn with_crossover(mut self, hz: f32) -> Self {
+        self.crossover = hz;
+        self
+    }
+
+    /// Set the gain.
+    #[must_use]
+    pub const fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Queue mono samples to play after those already queued.
+    pub fn push(&mut self, samples: &[f32]) {
+        self.queue.extend(samples);
+    }
+
+    /// Queue interleaved multi-channel samples, mixed down to mono.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "channel counts are tiny and exact in f32"
+    )]
+    pub fn push_interleaved(&mut self, samples: &[f32], channels: usize) {
+        let channels = channels.max(1);
+        self.queue.extend(
+            samples
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32),
+        );
+    }
+
+    /// Drop all queued samples.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.sample_debt = 0.0;
+    }
+
+    /// Length of the audio still queued.
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "queue lengths stay far below f32's exact integer range"
+    )]
+    pub fn queued(&self) -> Duration {
+        Duration::from_secs_f32(self.queue.len() as f32 / self.sample_rate.max(1) as f32)
+    }
+
+    /// Consume the samples for a frame and return the motor intensity.
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "per-frame sample counts are small and non-negative"
+    )]
+    pub fn process(&mut self, delta: f32) -> RumbleIntensity {
+        let sample_rate = self.sample_rate.max(1) as f32;
+        let wanted = delta.max(0.0).mul_add(sample_rate, self.sample_debt);
+        let count = (wanted.floor() as usize).min(self.queue.len());
+        self.sample_debt = if count < self.queue.len() {
+            wanted - wanted.floor()
+        } else {
+            0.0
+        };
+
+        // One-pole low-pass; the remainder is the high band
+        let alpha = 1.0 - (-TAU * self.crossover / sample_rate).exp();
+        let (mut low_energy, mut high_energy) = (0.0, 0.0);
+        for sample in self.queue.drain(..count) {
+            self.low_pass += alpha * (sample - self.low_pass);
+            let high = sample - self.low_pass;
+            low_energy += self.low_pass * self.low_pass;
+            high_energy += high * high;
+        }
+        let (low, high) = if count == 0 {
+            (0.0, 0.0)
+        } else {
+            let count = count as f32;
+            (
+                (low_energy / count).sqrt() * self.gain,
+                (high_energy / count).sqrt() * self.gain,
+            )
+        };
+
+        // Rise immediately, fall over the release time
+        let decay = if self.release > 0.0 {
+            (-delta / self.release).exp()
+        } else {
+            0.0
+        };
+        self.envelope = RumbleIntensity::new(
+            low.max(self.envelope.low_frequency * decay),
+            high.max(self.envelope.high_frequency * decay),
+        );
+        self.envelope
+    }
+}
+
+/// System to turn queued audio into rumble on each gamepad.
+pub fn update_audio_haptics(
+    mut gamepads: Query<(&mut AudioHaptics, &mut RumbleOutput)>,
+    time: Res<Time>,
+) {
+    for (mut haptics, mut output) in &mut gamepads {
+        let intensity = haptics.process(time.delta_secs());
+        output.request(intensity);
+    }
+}
+
+/// Add audio haptics systems to the app.
+pub(crate) fn add_audio_haptics_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        update_audio_haptics
+            .after(update_rumble_scales)
+            .before(send_rumble_output)
+            .run_if(subsystem_enabled(Subsystem::Haptics)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn tone(hz: f32, sample_rate: u32, seconds: f32) -> Vec<f32> {
+        #[expect(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "test signal lengths are small"
+        )]
+        let len = (sample_rate as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| {
+                #[expect(clippy::cast_precision_loss, reason = "test sample index")]
+                let t = i as f32 / sample_rate as f32;
+                (TAU * hz * t).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bass_drives_low_motor() {
+        let mut haptics = AudioHaptics::new(8_000);
+        haptics.push(&tone(40.0, 8_000, 0.5));
+        haptics.process(0.1);
+        let bass = haptics.process(0.1);
+        assert!(bass.low_frequency > 0.5);
+        assert!(bass.high_frequency < bass.low_frequency * 0.5);
+
+        let mut haptics = AudioHaptics::new(8_000);
+        haptics.push(&tone(2_000.0, 8_000, 0.5));
+        haptics.process(0.1);
+        let treble = haptics.process(0.1);
+        assert!(treble.high_frequency > 0.5);
+        assert!(treble.low_frequency < treble.high_frequency * 0.5);
+    }
+
+    #[test]
+    fn test_consumes_samples_and_decays() {
+        let mut haptics = AudioHaptics::new(1_000);
+        haptics.push_interleaved(&[0.5; 400], 2);
+        assert_relative_eq!(haptics.queued().as_secs_f32(), 0.2, epsilon = 1e-4);
+
+        haptics.process(0.15);
+        assert_relative_eq!(haptics.queued().as_secs_f32(), 0.05, epsilon = 1e-4);
+        let playing = haptics.process(0.1);
+        assert_eq!(haptics.queued(), Duration::ZERO);
+        assert!(playing.low_frequency > 0.0);
+
+        let silent = haptics.process(0.1);
+        assert!(silent.low_frequency < playing.low_frequency);
+        for _ in 0..20 {
+            haptics.process(0.1);
+        }
+        assert!(haptics.process(0.1).low_frequency < 0.01);
+    }
+}
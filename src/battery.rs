@@ -0,0 +1,252 @@
+//! Controller battery level reporting.
+//!
+//! Backends that can read a controller's battery (`DualSense` via the
+//! `dualsense` feature, or your own) keep a [`ControllerBattery`] component
+//! on its gamepad entity up to date. [`BatteryLow`] is sent once when a
+//! discharging controller drops to [`BatterySettings::low_threshold`], and
+//! [`InputDeviceState::active_battery`] mirrors the battery of the active
+//! gamepad for UI.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::battery::BatteryLow;
+//!
+//! fn battery_toast(mut low: MessageReader<BatteryLow>) {
+//!     for event in low.read() {
+//!         info!("Controller battery low ({:.0}%)", event.level * 100.0);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::detection::InputDeviceState;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Whether a controller is running on or charging its battery.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum ChargingState {
+    /// The backend doesn't report charging.
+    #[default]
+    Unknown,
+    /// Running on battery.
+    Discharging,
+    /// Plugged in and charging.
+    Charging,
+    /// Plugged in and fully charged.
+    Full,
+    /// Wired controller without a battery.
+    Wired,
+}
+
+impl ChargingState {
+    /// Check if the controller is plugged in.
+    #[must_use]
+    pub const fn is_plugged_in(self) -> bool {
+        matches!(self, Self::Charging | Self::Full | Self::Wired)
+    }
+}
+
+/// Battery state of a gamepad, kept up to date by its backend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct ControllerBattery {
+    /// Charge level (0.0 - 1.0), if the backend reports one.
+    pub level: Option<f32>,
+    /// Charging state.
+    pub state: ChargingState,
+}
+
+impl ControllerBattery {
+    /// Create a battery state.
+    #[must_use]
+    pub fn new(level: Option<f32>, state: ChargingState) -> Self {
+        Self {
+            level: level.map(|level| level.clamp(0.0, 1.0)),
+            state,
+        }
+    }
+
+    /// Charge level as a whole percentage.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "level is clamped to 0.0 - 1.0"
+    )]
+    pub fn percent(&self) -> Option<u8> {
+        self.level.map(|level| (level * 100.0).round() as u8)
+    }
+
+    /// Check if the battery is discharging at or below a level.
+    #[must_use]
+    pub fn is_low(&self, threshold: f32) -> bool {
+        !self.state.is_plugged_in() && self.level.is_some_and(|level| level <= threshold)
+    }
+}
+
+/// Settings for low-battery warnings.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct BatterySettings {
+    /// Level at or below which [`BatteryLow`] is sent (0.0 - 1.0).
+    pub low_threshold: f32,
+    /// How far above the threshold the level must recover before another
+    /// warning can be sent, so a level hovering at the threshold warns once.
+    pub recover_margin: f32,
+}
+
+impl Default for BatterySettings {
+    fn default() -> Self {
+        Self {
+            low_threshold: 0.2,
+            recover_margin: 0.05,
+        }
+    }
+}
+
+impl BatterySettings {
+    /// Update a gamepad's warning flag, returning whether to warn now.
+    fn should_warn(
+        &self,
+        warned: &mut HashSet<Entity>,
+        gamepad: Entity,
+        battery: &ControllerBattery,
+    ) -> bool {
+        if battery.is_low(self.low_threshold) {
+            return warned.insert(gamepad);
+        }
+        let recovered = battery
+            .level
+            .is_none_or(|level| level > self.low_threshold + self.recover_margin);
+        if battery.state.is_plugged_in() || recovered {
+            warned.remove(&gamepad);
+        }
+        false
+    }
+}
+
+/// Event fired when a controller's battery runs low.
+#[derive(Debug, Clone, Message)]
+pub struct BatteryLow {
+    /// Gamepad with the low battery.
+    pub gamepad: Entity,
+    /// Charge level (0.0 - 1.0).
+    pub level: f32,
+}
+
+/// System to send [`BatteryLow`] when a battery drops below the threshold.
+pub fn detect_low_battery(
+    settings: Res<BatterySettings>,
+    batteries: Query<(Entity, &ControllerBattery), Changed<ControllerBattery>>,
+    mut removed: RemovedComponents<ControllerBattery>,
+    mut warned: Local<HashSet<Entity>>,
+    mut low_events: MessageWriter<BatteryLow>,
+) {
+    for gamepad in removed.read() {
+        warned.remove(&gamepad);
+    }
+
+    for (gamepad, battery) in &batteries {
+        if settings.should_warn(&mut warned, gamepad, battery) {
+            low_events.write(BatteryLow {
+                gamepad,
+                level: battery.level.unwrap_or(0.0),
+            });
+        }
+    }
+}
+
+/// System to mirror the active gamepad's battery into [`InputDeviceState`].
+pub fn update_active_battery(
+    mut state: ResMut<InputDeviceState>,
+    batteries: Query<&ControllerBattery>,
+) {
+    let battery = state
+        .active_gamepad()
+        .and_then(|gamepad| batteries.get(gamepad).ok().copied());
+    if state.active_battery != battery {
+        state.active_battery = battery;
+    }
+}
+
+/// Plugin for registering battery types.
+pub(crate) fn register_battery_types(app: &mut App) {
+    app.register_type::<ChargingState>()
+        .register_type::<ControllerBattery>()
+        .register_type::<BatterySettings>()
+        .init_resource::<BatterySettings>()
+        .add_message::<BatteryLow>();
+}
+
+/// Add battery systems to the app.
+pub(crate) fn add_battery_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        (detect_low_battery, update_active_battery).run_if(subsystem_enabled(Subsystem::Battery)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_battery_is_low_only_when_discharging() {
+        let low = ControllerBattery::new(Some(0.1), ChargingState::Discharging);
+        assert!(low.is_low(0.2));
+        assert_eq!(low.percent(), Some(10));
+
+        let charging = ControllerBattery::new(Some(0.1), ChargingState::Charging);
+        assert!(!charging.is_low(0.2));
+
+        let unknown = ControllerBattery::new(None, ChargingState::Discharging);
+        assert!(!unknown.is_low(0.2));
+    }
+
+    #[test]
+    fn test_low_battery_warns_once_until_recovered() {
+        let settings = BatterySettings::default();
+        let gamepad = Entity::from_bits(1);
+        let mut warned = HashSet::new();
+        let at = |level| ControllerBattery::new(Some(level), ChargingState::Discharging);
+
+        assert!(!settings.should_warn(&mut warned, gamepad, &at(0.5)));
+        assert!(settings.should_warn(&mut warned, gamepad, &at(0.2)));
+        assert!(!settings.should_warn(&mut warned, gamepad, &at(0.19)));
+        // Hovering just above the threshold doesn't re-arm the warning
+        assert!(!settings.should_warn(&mut warned, gamepad, &at(0.22)));
+        assert!(!settings.should_warn(&mut warned, gamepad, &at(0.2)));
+
+        let charging = ControllerBattery::new(Some(0.2), ChargingState::Charging);
+        assert!(!settings.should_warn(&mut warned, gamepad, &charging));
+        assert!(settings.should_warn(&mut warned, gamepad, &at(0.2)));
+    }
+
+    #[test]
+    fn test_active_battery_follows_active_gamepad() {
+        let mut app = App::new();
+        app.init_resource::<InputDeviceState>()
+            .add_systems(Update, update_active_battery);
+        let battery = ControllerBattery::new(Some(0.8), ChargingState::Discharging);
+        let gamepad = app.world_mut().spawn(battery).id();
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<InputDeviceState>().active_battery,
+            None
+        );
+
+        app.world_mut()
+            .resource_mut::<InputDeviceState>()
+            .active_device = crate::detection::InputDevice::Gamepad(gamepad);
+        app.update();
+        assert_eq!(
+            app.world().resource::<InputDeviceState>().active_battery,
+            Some(battery)
+        );
+    }
+}
@@ -0,0 +1,485 @@
+//! Binding coverage checks for [`ActionMap`]s.
+//!
+//! [`BindingLint`] inspects an action map and returns a [`BindingReport`]
+//! listing required actions without bindings, actions bound on only one of
+//! gamepad or keyboard/mouse, bindings that can never fire because a chord
+//! always wins over them, and inputs bound more than once. Run it in a test
+//! to catch mistakes in default bindings, or after a remap to show warnings
+//! next to the affected rows of an options menu.
+//!
+//! Menu and gameplay actions often share inputs on purpose (Escape for both
+//! Cancel and Pause); list those with [`BindingLint::allow_shared`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy_archie::actions::{ActionMap, GameAction};
+//! use bevy_archie::binding_lint::BindingLint;
+//!
+//! let mut lint = BindingLint::default();
+//! lint.allow_shared([GameAction::Cancel, GameAction::Pause]);
+//! let report = lint.check(&ActionMap::default());
+//! for issue in &report.issues {
+//!     println!("{issue}");
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::actions::{ActionMap, Actionlike, GameAction, InputBinding};
+use crate::chords::{ButtonChord, ChordBinding, ChordBindings, resolve_clashes};
+use crate::virtual_input::VirtualButton;
+
+/// A problem found in an action map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingIssue<A: Actionlike = GameAction> {
+    /// A required action has no bindings.
+    UnboundRequired {
+        /// The unbound action.
+        action: A,
+    },
+    /// An action is bound on a gamepad but not on keyboard or mouse.
+    MissingKeyboardMouse {
+        /// The action.
+        action: A,
+    },
+    /// An action is bound on keyboard or mouse but not on a gamepad.
+    MissingGamepad {
+        /// The action.
+        action: A,
+    },
+    /// A binding never fires because a chord pressed with it always wins
+    /// the clash.
+    ShadowedByChord {
+        /// The action that never fires from this binding.
+        action: A,
+        /// Inputs of the shadowed binding.
+        buttons: Vec<VirtualButton>,
+        /// The chord's action.
+        by: A,
+    },
+    /// The same input is bound more than once.
+    Duplicate {
+        /// The input.
+        binding: InputBinding,
+        /// Modifier held with the input, for modifier-layer bindings.
+        modifier: Option<GamepadButton>,
+        /// Actions the input is bound to, repeated if an action lists it
+        /// twice.
+        actions: Vec<A>,
+    },
+}
+
+impl<A: Actionlike> BindingIssue<A> {
+    /// Check if the issue concerns an action.
+    #[must_use]
+    pub fn involves(&self, action: A) -> bool {
+        match self {
+            Self::UnboundRequired { action: a }
+            | Self::MissingKeyboardMouse { action: a }
+            | Self::MissingGamepad { action: a } => *a == action,
+            Self::ShadowedByChord { action: a, by, .. } => *a == action || *by == action,
+            Self::Duplicate { actions, .. } => actions.contains(&action),
+        }
+    }
+}
+
+impl<A: Actionlike> fmt::Display for BindingIssue<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnboundRequired { action } => {
+                write!(
+                    f,
+                    "{} is required but has no bindings",
+                    action.display_name()
+                )
+            }
+            Self::MissingKeyboardMouse { action } => write!(
+                f,
+                "{} has no keyboard or mouse binding",
+                action.display_name()
+            ),
+            Self::MissingGamepad { action } => {
+                write!(f, "{} has no gamepad binding", action.display_name())
+            }
+            Self::ShadowedByChord {
+                action,
+                buttons,
+                by,
+            } => write!(
+                f,
+                "{} never fires from {buttons:?}; {} always wins",
+                action.display_name(),
+                by.display_name()
+            ),
+            Self::Duplicate {
+                binding,
+                modifier,
+                actions,
+            } => {
+                let names: Vec<&str> = actions.iter().map(|a| a.display_name()).collect();
+                match modifier {
+                    Some(modifier) => write!(f, "{modifier:?}+{binding:?}")?,
+                    None => write!(f, "{binding:?}")?,
+                }
+                write!(f, " is bound more than once: {}", names.join(", "))
+            }
+        }
+    }
+}
+
+/// Result of checking an action map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingReport<A: Actionlike = GameAction> {
+    /// Problems found, in action order.
+    pub issues: Vec<BindingIssue<A>>,
+}
+
+impl<A: Actionlike> Default for BindingReport<A> {
+    fn default() -> Self {
+        Self { issues: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> BindingReport<A> {
+    /// Whether no problems were found.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Problems concerning an action, for per-row menu warnings.
+    pub fn for_action(&self, action: A) -> impl Iterator<Item = &BindingIssue<A>> {
+        self.issues
+            .iter()
+            .filter(move |issue| issue.involves(action))
+    }
+}
+
+/// Settings for checking action maps.
+#[derive(Debug, Clone)]
+pub struct BindingLint<A: Actionlike = GameAction> {
+    /// Report actions bound on only one of gamepad or keyboard/mouse.
+    pub check_device_parity: bool,
+    /// Actions allowed to be bound on only one device family, like
+    /// mouse-only camera or gamepad-only gyro actions.
+    pub parity_exempt: HashSet<A>,
+    /// Groups of actions allowed to share inputs.
+    shared: Vec<HashSet<A>>,
+}
+
+impl<A: Actionlike> Default for BindingLint<A> {
+    fn default() -> Self {
+        Self {
+            check_device_parity: true,
+            parity_exempt: HashSet::new(),
+            shared: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> BindingLint<A> {
+    /// Exempt an action from the device parity check.
+    pub fn exempt_parity(&mut self, action: A) -> &mut Self {
+        self.parity_exempt.insert(action);
+        self
+    }
+
+    /// Allow a group of actions to share inputs.
+    ///
+    /// A duplicate is still reported if any action bound to the input is
+    /// outside the group, or one action lists the input twice.
+    pub fn allow_shared(&mut self, actions: impl IntoIterator<Item = A>) -> &mut Self {
+        self.shared.push(actions.into_iter().collect());
+        self
+    }
+
+    /// Check an action map.
+    #[must_use]
+    pub fn check(&self, map: &ActionMap<A>) -> BindingReport<A> {
+        self.check_coverage(map, &[])
+    }
+
+    /// Check required actions, device parity, and duplicates, counting
+    /// chord bindings as coverage.
+    fn check_coverage(&self, map: &ActionMap<A>, chords: &[ChordBinding<A>]) -> BindingReport<A> {
+        let mut report = BindingReport::default();
+        let stick_actions: HashSet<A> = map
+            .stick_direction_bindings
+            .values()
+            .flat_map(|binding| [binding.up, binding.down, binding.left, binding.right])
+            .flatten()
+            .collect();
+        let chorded = |action: A, gamepad: bool| {
+            chords.iter().any(|binding| {
+                binding.action == action
+                    && binding
+                        .chord
+                        .buttons()
+                        .iter()
+                        .any(|button| matches!(button, VirtualButton::Gamepad(_)) == gamepad)
+            })
+        };
+
+        for action in A::all() {
+            let action = *action;
+            let gamepad = bindings_for(&map.gamepad_bindings, action).next().is_some()
+                || bindings_for(&map.modifier_bindings, action)
+                    .next()
+                    .is_some()
+                || bindings_for(&map.axis_bindings, action).next().is_some()
+                || stick_actions.contains(&action)
+                || chorded(action, true);
+            let keyboard_mouse = bindings_for(&map.key_bindings, action).next().is_some()
+                || bindings_for(&map.mouse_bindings, action).next().is_some()
+                || chorded(action, false);
+
+            if !gamepad && !keyboard_mouse {
+                if action.is_required() {
+                    report.issues.push(BindingIssue::UnboundRequired { action });
+                }
+            } else if self.check_device_parity && !self.parity_exempt.contains(&action) {
+                if !keyboard_mouse {
+                    report
+                        .issues
+                        .push(BindingIssue::MissingKeyboardMouse { action });
+                } else if !gamepad {
+                    report.issues.push(BindingIssue::MissingGamepad { action });
+                }
+            }
+        }
+
+        report.issues.extend(self.duplicates(map));
+        report
+    }
+
+    /// Inputs bound more than once, in order of first binding.
+    fn duplicates(&self, map: &ActionMap<A>) -> Vec<BindingIssue<A>> {
+        let mut order: Vec<(InputBinding, Option<GamepadButton>)> = Vec::new();
+        let mut uses: HashMap<(InputBinding, Option<GamepadButton>), Vec<A>> = HashMap::new();
+        let mut add = |binding: InputBinding, modifier: Option<GamepadButton>, action: A| {
+            let key = (binding, modifier);
+            let actions = uses.entry(key).or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            });
+            actions.push(action);
+        };
+
+        for action in A::all() {
+            let action = *action;
+            for button in bindings_for(&map.gamepad_bindings, action) {
+                add(InputBinding::GamepadButton(button), None, action);
+            }
+            for (modifier, button) in bindings_for(&map.modifier_bindings, action) {
+                add(InputBinding::GamepadButton(button), Some(modifier), action);
+            }
+            for (axis, direction, _) in bindings_for(&map.axis_bindings, action) {
+                add(InputBinding::GamepadAxis(axis, direction), None, action);
+            }
+            for key in bindings_for(&map.key_bindings, action) {
+                add(InputBinding::Key(key), None, action);
+            }
+            for button in bindings_for(&map.mouse_bindings, action) {
+                add(InputBinding::MouseButton(button), None, action);
+            }
+        }
+
+        order
+            .into_iter()
+            .filter_map(|key| {
+                let actions = uses.remove(&key)?;
+                let distinct: HashSet<A> = actions.iter().copied().collect();
+                let allowed = distinct.len() == actions.len()
+                    && self.shared.iter().any(|group| distinct.is_subset(group));
+                (actions.len() > 1 && !allowed).then_some(BindingIssue::Duplicate {
+                    binding: key.0,
+                    modifier: key.1,
+                    actions,
+                })
+            })
+            .collect()
+    }
+}
+
+/// An action's bindings from one of the [`ActionMap`] tables.
+fn bindings_for<A: Actionlike, T: Copy>(
+    bindings: &HashMap<A, Vec<T>>,
+    action: A,
+) -> impl Iterator<Item = T> + '_ {
+    bindings.get(&action).into_iter().flatten().copied()
+}
+
+impl BindingLint<GameAction> {
+    /// Check an action map together with its chord bindings.
+    ///
+    /// Chords count as coverage, and bindings that always lose to a chord
+    /// under the chords' [`ClashStrategy`](crate::chords::ClashStrategy) are
+    /// reported as [`BindingIssue::ShadowedByChord`].
+    #[must_use]
+    pub fn check_with_chords(&self, map: &ActionMap, chords: &ChordBindings) -> BindingReport {
+        let mut report = self.check_coverage(map, chords.bindings());
+        report.issues.extend(shadowed_bindings(map, chords));
+        report
+    }
+}
+
+/// Bindings that lose every clash with a chord pressed along with them.
+///
+/// A chord is pressed along with a binding whenever its buttons are a
+/// subset of the binding's. Chords resolve before single buttons, in
+/// registration order, as in [`ChordBindings::resolve`].
+fn shadowed_bindings(map: &ActionMap, chords: &ChordBindings) -> Vec<BindingIssue> {
+    let singles = GameAction::all().iter().flat_map(|action| {
+        let buttons = bindings_for(&map.gamepad_bindings, *action)
+            .map(VirtualButton::Gamepad)
+            .chain(bindings_for(&map.key_bindings, *action).map(VirtualButton::Key))
+            .chain(bindings_for(&map.mouse_bindings, *action).map(VirtualButton::Mouse));
+        let priority = chords.priorities.get(action).copied().unwrap_or(0);
+        buttons.map(move |button| {
+            ChordBinding::with_priority(ButtonChord::from_buttons([button]), *action, priority)
+        })
+    });
+    let candidates: Vec<ChordBinding<GameAction>> = chords
+        .bindings()
+        .iter()
+        .filter(|binding| !binding.chord.is_empty())
+        .cloned()
+        .chain(singles)
+        .collect();
+
+    let mut issues = Vec::new();
+    for (index, binding) in candidates.iter().enumerate() {
+        let pressed: HashSet<&VirtualButton> = binding.chord.buttons().iter().collect();
+        let winner = chords
+            .bindings()
+            .iter()
+            .filter(|chord| !chord.chord.is_empty())
+            .enumerate()
+            .filter(|(chord_index, chord)| {
+                *chord_index != index
+                    && chord.action != binding.action
+                    && chord.chord.buttons().iter().all(|b| pressed.contains(b))
+            })
+            .find(|(chord_index, chord)| {
+                // Resolve the pair in the order the real resolution uses
+                let pair = if *chord_index < index {
+                    [(*chord).clone(), binding.clone()]
+                } else {
+                    [binding.clone(), (*chord).clone()]
+                };
+                !resolve_clashes(&pair, chords.strategy).contains(&binding.action)
+            });
+        if let Some((_, chord)) = winner {
+            issues.push(BindingIssue::ShadowedByChord {
+                action: binding.action,
+                buttons: binding.chord.decompose(),
+                by: chord.action,
+            });
+        }
+    }
+    issues
+}
+
+impl<A: Actionlike> ActionMap<A> {
+    /// Check the bindings with the default [`BindingLint`].
+    #[must_use]
+    pub fn lint(&self) -> BindingReport<A> {
+        BindingLint::default().check(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chords::ClashStrategy;
+
+    #[test]
+    fn test_unbound_required_action() {
+        let mut map = ActionMap::default();
+        map.clear_bindings(GameAction::Confirm);
+
+        let report = map.lint();
+        assert!(report.issues.contains(&BindingIssue::UnboundRequired {
+            action: GameAction::Confirm
+        }));
+        assert_eq!(report.for_action(GameAction::Confirm).count(), 1);
+    }
+
+    #[test]
+    fn test_device_parity() {
+        let mut map = ActionMap::default();
+        map.clear_bindings(GameAction::Custom1);
+        map.bind_gamepad(GameAction::Custom1, GamepadButton::C);
+
+        let mut lint = BindingLint::default();
+        assert!(
+            lint.check(&map)
+                .issues
+                .contains(&BindingIssue::MissingKeyboardMouse {
+                    action: GameAction::Custom1
+                })
+        );
+
+        lint.exempt_parity(GameAction::Custom1);
+        assert_eq!(lint.check(&map).for_action(GameAction::Custom1).count(), 0);
+    }
+
+    #[test]
+    fn test_duplicates_respect_shared_groups() {
+        let mut map = ActionMap::empty();
+        map.bind_key(GameAction::Confirm, KeyCode::Enter);
+        map.bind_key(GameAction::Cancel, KeyCode::Escape);
+        map.bind_key(GameAction::Pause, KeyCode::Escape);
+
+        let mut lint = BindingLint::default();
+        lint.check_device_parity = false;
+        assert_eq!(
+            lint.check(&map).issues,
+            vec![BindingIssue::Duplicate {
+                binding: InputBinding::Key(KeyCode::Escape),
+                modifier: None,
+                actions: vec![GameAction::Cancel, GameAction::Pause],
+            }]
+        );
+
+        lint.allow_shared([GameAction::Cancel, GameAction::Pause]);
+        assert!(lint.check(&map).is_clean());
+    }
+
+    #[test]
+    fn test_single_button_chord_shadows_binding() {
+        let mut map = ActionMap::empty();
+        for (action, key) in [
+            (GameAction::Confirm, KeyCode::Enter),
+            (GameAction::Cancel, KeyCode::Escape),
+            (GameAction::Pause, KeyCode::KeyP),
+        ] {
+            map.bind_key(action, key);
+        }
+        map.bind_gamepad(GameAction::Primary, GamepadButton::West);
+        let mut chords = ChordBindings::default().with_strategy(ClashStrategy::PrioritizeFirst);
+        chords.bind(
+            ButtonChord::from_gamepad_buttons(&[GamepadButton::West]),
+            GameAction::Secondary,
+        );
+
+        let mut lint = BindingLint::default();
+        lint.check_device_parity = false;
+        let report = lint.check_with_chords(&map, &chords);
+        assert_eq!(
+            report.issues,
+            vec![BindingIssue::ShadowedByChord {
+                action: GameAction::Primary,
+                buttons: vec![VirtualButton::Gamepad(GamepadButton::West)],
+                by: GameAction::Secondary,
+            }]
+        );
+
+        // Equal-length chords don't beat single buttons by length
+        chords.strategy = ClashStrategy::PrioritizeLongest;
+        assert!(lint.check_with_chords(&map, &chords).is_clean());
+    }
+}
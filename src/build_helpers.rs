@@ -12,10 +12,13 @@
 //! let _manifest = generate_icon_manifest("assets/icons", &config);
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::config::ControllerLayout;
+use crate::icons::{ButtonIcon, IconSize};
 
 /// Configuration for controller icon generation.
 #[derive(Debug, Clone)]
@@ -356,6 +359,73 @@ pub fn validate_icons(assets_dir: impl AsRef<Path>, config: &ControllerIconConfi
     generate_icon_manifest(assets_dir, &config).missing
 }
 
+/// A `ButtonIcon` asset that is missing for a layout and size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingButtonIcon {
+    /// The icon that would fail to load
+    pub icon: ButtonIcon,
+    /// The controller layout
+    pub layout: ControllerLayout,
+    /// The icon size
+    pub size: IconSize,
+    /// The expected file path
+    pub path: PathBuf,
+}
+
+/// Find every `ButtonIcon` × layout × size file missing from an icon directory.
+///
+/// `icon_dir` is the directory `ControllerIconAssets::base_path` points at,
+/// relative to the working directory. Icons that share a filename across
+/// layouts are reported once, under the first layout checked.
+///
+/// # Example build.rs
+///
+/// ```rust,no_run
+/// use bevy_archie::build_helpers::find_missing_button_icons;
+/// use bevy_archie::config::ControllerLayout;
+/// use bevy_archie::icons::IconSize;
+///
+/// let missing = find_missing_button_icons(
+///     "assets/icons",
+///     ControllerLayout::all(),
+///     IconSize::all(),
+/// );
+/// for icon in &missing {
+///     println!("cargo:warning=missing icon {}", icon.path.display());
+/// }
+/// ```
+#[must_use]
+pub fn find_missing_button_icons(
+    icon_dir: impl AsRef<Path>,
+    layouts: &[ControllerLayout],
+    sizes: &[IconSize],
+) -> Vec<MissingButtonIcon> {
+    let icon_dir = icon_dir.as_ref();
+    let mut checked = HashSet::new();
+    let mut missing = Vec::new();
+
+    for &layout in layouts {
+        for &icon in ButtonIcon::all() {
+            for &size in sizes {
+                let path = icon_dir.join(icon.filename(layout, size));
+                if !checked.insert(path.clone()) {
+                    continue;
+                }
+                if !path.exists() {
+                    missing.push(MissingButtonIcon {
+                        icon,
+                        layout,
+                        size,
+                        path,
+                    });
+                }
+            }
+        }
+    }
+
+    missing
+}
+
 /// Generate Rust code for icon constants.
 ///
 /// This can be used in build.rs to generate a constants file.
@@ -435,6 +505,29 @@ mod tests {
         assert!(config.validate_icons);
     }
 
+    #[test]
+    fn test_find_missing_button_icons() {
+        let dir = std::env::temp_dir().join("bevy_archie_missing_icons_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("xbox_a.png"), b"").unwrap();
+
+        let missing =
+            find_missing_button_icons(&dir, &[ControllerLayout::Xbox], &[IconSize::Medium]);
+        assert_eq!(missing.len(), ButtonIcon::all().len() - 1);
+        assert!(!missing.iter().any(|m| m.icon == ButtonIcon::FaceDown));
+
+        // Shared filenames are only reported once across layouts
+        let missing = find_missing_button_icons(
+            &dir,
+            &[ControllerLayout::Xbox, ControllerLayout::Generic],
+            &[IconSize::Medium],
+        );
+        assert_eq!(missing.len(), ButtonIcon::all().len() - 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_generate_constants() {
         let manifest = IconManifest {
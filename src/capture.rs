@@ -0,0 +1,271 @@
+//! Screenshot and clip capture from a gamepad chord.
+//!
+//! Like the share button on modern consoles, the [`CaptureBinding`] chord
+//! (Select+Y by default) sends a [`CaptureRequest`] that games hook to take a
+//! screenshot or save a replay clip. Tapping the chord asks for a
+//! [`CaptureKind::Screenshot`] when it is released; holding it for
+//! [`CaptureBinding::long_press`] seconds asks for a [`CaptureKind::Clip`]
+//! straight away. After a capture the chord is ignored for
+//! [`CaptureBinding::cooldown`] seconds, so a bouncy press can't fire twice.
+//!
+//! The binding is off until [`CaptureBinding::enabled`] is set, since the
+//! chord's buttons are usually bound to actions too.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::capture::{CaptureBinding, CaptureKind, CaptureRequest};
+//!
+//! fn enable_capture(mut binding: ResMut<CaptureBinding>) {
+//!     binding.enabled = true;
+//! }
+//!
+//! fn handle_capture(mut requests: MessageReader<CaptureRequest>) {
+//!     for request in requests.read() {
+//!         match request.kind {
+//!             CaptureKind::Screenshot => info!("Saving screenshot"),
+//!             CaptureKind::Clip => info!("Saving the last 30 seconds"),
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// What a capture request asks the game to save.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum CaptureKind {
+    /// A screenshot, from tapping the chord.
+    Screenshot,
+    /// A replay clip, from holding the chord.
+    Clip,
+}
+
+/// Event fired when the capture chord is tapped or held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct CaptureRequest {
+    /// The gamepad that pressed the chord.
+    pub gamepad: Entity,
+    /// What to capture.
+    pub kind: CaptureKind,
+}
+
+/// Progress of a held capture chord.
+#[derive(Debug, Clone, Copy)]
+struct ChordHold {
+    /// Seconds the chord has been held.
+    held: f32,
+    /// Whether this hold already captured, or started during the cooldown.
+    spent: bool,
+}
+
+/// Resource configuring the capture chord.
+#[derive(Debug, Clone, Resource)]
+pub struct CaptureBinding {
+    /// Whether the chord sends capture requests.
+    pub enabled: bool,
+    /// Buttons that must all be held.
+    pub buttons: Vec<GamepadButton>,
+    /// Seconds the chord must be held to capture a clip instead of a
+    /// screenshot.
+    pub long_press: f32,
+    /// Seconds after a capture during which the chord is ignored.
+    pub cooldown: f32,
+    /// Holds in progress, per gamepad.
+    holds: HashMap<Entity, ChordHold>,
+    /// Remaining cooldown, per gamepad.
+    cooldowns: HashMap<Entity, f32>,
+}
+
+impl Default for CaptureBinding {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            buttons: vec![GamepadButton::Select, GamepadButton::North],
+            long_press: 0.6,
+            cooldown: 1.0,
+            holds: HashMap::new(),
+            cooldowns: HashMap::new(),
+        }
+    }
+}
+
+impl CaptureBinding {
+    /// Create an enabled binding for a chord.
+    #[must_use]
+    pub fn new(buttons: impl IntoIterator<Item = GamepadButton>) -> Self {
+        Self {
+            enabled: true,
+            buttons: buttons.into_iter().collect(),
+            ..default()
+        }
+    }
+
+    /// Set the long-press time.
+    #[must_use]
+    pub const fn with_long_press(mut self, seconds: f32) -> Self {
+        self.long_press = seconds;
+        self
+    }
+
+    /// Set the cooldown.
+    #[must_use]
+    pub const fn with_cooldown(mut self, seconds: f32) -> Self {
+        self.cooldown = seconds;
+        self
+    }
+
+    /// Check if every chord button is held on a gamepad.
+    #[must_use]
+    pub fn is_held(&self, gamepad: &Gamepad) -> bool {
+        !self.buttons.is_empty() && self.buttons.iter().all(|&button| gamepad.pressed(button))
+    }
+
+    /// Check if a gamepad is holding the chord.
+    ///
+    /// Games can use this to ignore the chord's buttons while it is held.
+    #[must_use]
+    pub fn is_holding(&self, gamepad: Entity) -> bool {
+        self.holds.contains_key(&gamepad)
+    }
+
+    /// Advance one gamepad's chord by a frame.
+    ///
+    /// Returns the capture to request, if any.
+    pub fn step(&mut self, gamepad: Entity, held: bool, delta: f32) -> Option<CaptureKind> {
+        let cooling_down = match self.cooldowns.get_mut(&gamepad) {
+            Some(remaining) => {
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    self.cooldowns.remove(&gamepad);
+                    false
+                } else {
+                    true
+                }
+            }
+            None => false,
+        };
+
+        let kind = if held {
+            let hold = self.holds.entry(gamepad).or_insert(ChordHold {
+                held: 0.0,
+                spent: cooling_down,
+            });
+            hold.held += delta;
+            if hold.spent || hold.held < self.long_press {
+                return None;
+            }
+            hold.spent = true;
+            CaptureKind::Clip
+        } else {
+            match self.holds.remove(&gamepad) {
+                Some(hold) if !hold.spent => CaptureKind::Screenshot,
+                _ => return None,
+            }
+        };
+
+        self.cooldowns.insert(gamepad, self.cooldown);
+        Some(kind)
+    }
+
+    /// Forget a gamepad's hold and cooldown.
+    pub fn forget(&mut self, gamepad: Entity) {
+        self.holds.remove(&gamepad);
+        self.cooldowns.remove(&gamepad);
+    }
+}
+
+/// System to send capture requests from the capture chord.
+pub fn detect_capture_chord(
+    mut binding: ResMut<CaptureBinding>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    time: Res<Time<Real>>,
+    mut requests: MessageWriter<CaptureRequest>,
+    mut removed: RemovedComponents<Gamepad>,
+) {
+    for gamepad in removed.read() {
+        binding.forget(gamepad);
+    }
+
+    if !binding.enabled {
+        return;
+    }
+
+    for (entity, gamepad) in &gamepads {
+        let held = binding.is_held(gamepad);
+        if let Some(kind) = binding.step(entity, held, time.delta_secs()) {
+            requests.write(CaptureRequest {
+                gamepad: entity,
+                kind,
+            });
+        }
+    }
+}
+
+/// Plugin for registering capture types.
+pub(crate) fn register_capture_types(app: &mut App) {
+    app.register_type::<CaptureKind>()
+        .init_resource::<CaptureBinding>()
+        .add_message::<CaptureRequest>();
+}
+
+/// Add capture systems to the app.
+pub(crate) fn add_capture_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        detect_capture_chord.run_if(subsystem_enabled(Subsystem::Capture)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_screenshots_and_hold_clips() {
+        let mut binding = CaptureBinding::new([GamepadButton::Select, GamepadButton::North])
+            .with_long_press(0.5)
+            .with_cooldown(0.0);
+        let gamepad = Entity::from_bits(1);
+
+        assert_eq!(binding.step(gamepad, true, 0.1), None);
+        assert!(binding.is_holding(gamepad));
+        assert_eq!(
+            binding.step(gamepad, false, 0.1),
+            Some(CaptureKind::Screenshot)
+        );
+
+        assert_eq!(binding.step(gamepad, true, 0.3), None);
+        assert_eq!(binding.step(gamepad, true, 0.3), Some(CaptureKind::Clip));
+        // Still held, and releasing after a clip doesn't also screenshot
+        assert_eq!(binding.step(gamepad, true, 0.3), None);
+        assert_eq!(binding.step(gamepad, false, 0.1), None);
+    }
+
+    #[test]
+    fn test_cooldown_debounces_presses() {
+        let mut binding = CaptureBinding::new([GamepadButton::Select]).with_cooldown(0.5);
+        let gamepad = Entity::from_bits(1);
+
+        binding.step(gamepad, true, 0.0);
+        assert_eq!(
+            binding.step(gamepad, false, 0.0),
+            Some(CaptureKind::Screenshot)
+        );
+
+        // A bounce inside the cooldown is ignored for its whole hold
+        binding.step(gamepad, true, 0.1);
+        assert_eq!(binding.step(gamepad, false, 0.1), None);
+
+        binding.step(gamepad, false, 0.4);
+        binding.step(gamepad, true, 0.1);
+        assert_eq!(
+            binding.step(gamepad, false, 0.1),
+            Some(CaptureKind::Screenshot)
+        );
+    }
+}
@@ -0,0 +1,530 @@
+//! Per-controller characterization reports.
+//!
+//! [`ControllerCharacterizer`] measures a connected controller while a tester
+//! works its sticks: axis resolution, polling interval, input latency, resting
+//! drift, and how far each axis reaches in both directions. The resulting
+//! [`CharacterizationReport`] is saved as JSON in the calibration directory,
+//! keyed by vendor and product ID, so QA labs can compare units and games can
+//! tune processing with [`CharacterizationReport::apply`].
+//!
+//! Latency needs an external stimulus with a known time, such as a button
+//! pressing rig. Call [`ControllerCharacterizer::mark_stimulus`] when it
+//! fires; the next button press is timed against it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::characterization::{CharacterizationReport, ControllerCharacterizer};
+//!
+//! fn finish_characterization(
+//!     mut characterizer: ResMut<ControllerCharacterizer>,
+//!     gamepads: Query<Entity, With<Gamepad>>,
+//! ) {
+//!     for gamepad in &gamepads {
+//!         if let Some(report) = characterizer.finish(gamepad) {
+//!             let _ = report.save(&CharacterizationReport::default_directory());
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::config::ControllerConfig;
+use crate::profiles::DetectedController;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Stick axes measured, in report order.
+const AXES: [GamepadAxis; 4] = [
+    GamepadAxis::LeftStickX,
+    GamepadAxis::LeftStickY,
+    GamepadAxis::RightStickX,
+    GamepadAxis::RightStickY,
+];
+
+/// Stick magnitude up to which the stick counts as resting.
+const REST_WINDOW: f32 = 0.25;
+
+/// Gaps between value changes longer than this are pauses, not polls.
+const MAX_POLL_GAP: f32 = 0.25;
+
+/// Axis values are stored in millionths to find the smallest step.
+const VALUE_SCALE: f32 = 1_000_000.0;
+
+/// Whether a stick at this position counts as resting.
+///
+/// The magnitude is rounded to [`VALUE_SCALE`] first, so a stick on the edge
+/// of [`REST_WINDOW`] counts the same on either side of center whatever
+/// float error its axis values picked up.
+fn is_resting(x: f32, y: f32) -> bool {
+    (Vec2::new(x, y).length() * VALUE_SCALE).round() <= REST_WINDOW * VALUE_SCALE
+}
+
+/// Samples collected for one axis.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct AxisSamples {
+    values: BTreeSet<i32>,
+    min: f32,
+    max: f32,
+    rest_sum: f32,
+    rest_count: u32,
+}
+
+impl AxisSamples {
+    fn record(&mut self, value: f32, resting: bool) {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "axis values are within -1.0 - 1.0"
+        )]
+        let scaled = (value * VALUE_SCALE).round() as i32;
+        self.values.insert(scaled);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        if resting {
+            self.rest_sum += value;
+            self.rest_count += 1;
+        }
+    }
+
+    fn report(&self, axis: GamepadAxis) -> AxisReport {
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "steps and counts stay far below f32 precision limits"
+        )]
+        let resolution = self
+            .values
+            .iter()
+            .zip(self.values.iter().skip(1))
+            .map(|(low, high)| high - low)
+            .min()
+            .map(|step| step as f32 / VALUE_SCALE);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "sample counts stay far below f32 precision limits"
+        )]
+        let drift = if self.rest_count == 0 {
+            0.0
+        } else {
+            self.rest_sum / self.rest_count as f32
+        };
+        AxisReport {
+            axis: format!("{axis:?}"),
+            resolution,
+            drift,
+            min: self.min,
+            max: self.max,
+            symmetric_range: self.max.min(-self.min),
+        }
+    }
+}
+
+/// An in-progress measurement of one controller.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CharacterizationSession {
+    name: String,
+    vendor_id: Option<u16>,
+    product_id: Option<u16>,
+    started: Option<f64>,
+    last_time: f64,
+    frames: u32,
+    axes: [AxisSamples; 4],
+    previous: Option<[f32; 4]>,
+    last_change: Option<f64>,
+    change_intervals: Vec<f32>,
+    stimulus: Option<f64>,
+    latencies: Vec<f32>,
+}
+
+impl CharacterizationSession {
+    /// Record one frame of stick axes and whether a button was just pressed.
+    pub fn record(&mut self, now: f64, axes: [f32; 4], button_pressed: bool) {
+        self.started.get_or_insert(now);
+        self.last_time = now;
+        self.frames += 1;
+
+        let left_resting = is_resting(axes[0], axes[1]);
+        let right_resting = is_resting(axes[2], axes[3]);
+        for (index, value) in axes.into_iter().enumerate() {
+            let resting = if index < 2 {
+                left_resting
+            } else {
+                right_resting
+            };
+            self.axes[index].record(value, resting);
+        }
+
+        if self.previous.is_some_and(|previous| previous != axes) {
+            if let Some(last) = self.last_change {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    reason = "frame gaps are a fraction of a second"
+                )]
+                let interval = (now - last) as f32;
+                if interval > 0.0 && interval <= MAX_POLL_GAP {
+                    self.change_intervals.push(interval);
+                }
+            }
+            self.last_change = Some(now);
+        }
+        self.previous = Some(axes);
+
+        if button_pressed && let Some(stimulus) = self.stimulus.take() {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "latencies are a fraction of a second"
+            )]
+            self.latencies.push((now - stimulus).max(0.0) as f32);
+        }
+    }
+
+    /// Build the report from everything recorded so far.
+    #[must_use]
+    pub fn report(&self) -> CharacterizationReport {
+        let mut intervals = self.change_intervals.clone();
+        intervals.sort_by(f32::total_cmp);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "latency sample counts are small"
+        )]
+        let latency = (!self.latencies.is_empty())
+            .then(|| self.latencies.iter().sum::<f32>() / self.latencies.len() as f32);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "sessions last minutes, not years"
+        )]
+        let duration = self
+            .started
+            .map_or(0.0, |started| (self.last_time - started) as f32);
+
+        CharacterizationReport {
+            name: self.name.clone(),
+            vendor_id: self.vendor_id,
+            product_id: self.product_id,
+            duration,
+            frames: self.frames,
+            polling_interval: intervals.get(intervals.len() / 2).copied(),
+            latency,
+            latency_samples: u32::try_from(self.latencies.len()).unwrap_or(u32::MAX),
+            axes: AXES
+                .iter()
+                .zip(&self.axes)
+                .map(|(axis, samples)| samples.report(*axis))
+                .collect(),
+        }
+    }
+}
+
+/// Measurements for one stick axis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisReport {
+    /// Axis name, e.g. `"LeftStickX"`.
+    pub axis: String,
+    /// Smallest step between distinct values, if the axis moved.
+    pub resolution: Option<f32>,
+    /// Average value while the stick rested.
+    pub drift: f32,
+    /// Lowest value reached.
+    pub min: f32,
+    /// Highest value reached.
+    pub max: f32,
+    /// Deflection reached in both directions.
+    pub symmetric_range: f32,
+}
+
+/// Characterization of one controller.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterizationReport {
+    /// Controller name reported by the OS.
+    pub name: String,
+    /// Vendor ID, if detected.
+    pub vendor_id: Option<u16>,
+    /// Product ID, if detected.
+    pub product_id: Option<u16>,
+    /// Length of the session in seconds.
+    pub duration: f32,
+    /// Frames recorded.
+    pub frames: u32,
+    /// Median time between input changes while moving, in seconds.
+    ///
+    /// Never reads below the frame time; run at a high frame rate for
+    /// controllers that poll faster.
+    pub polling_interval: Option<f32>,
+    /// Average stimulus-to-press latency in seconds.
+    pub latency: Option<f32>,
+    /// Stimuli timed for [`Self::latency`].
+    pub latency_samples: u32,
+    /// Per-axis measurements.
+    pub axes: Vec<AxisReport>,
+}
+
+impl CharacterizationReport {
+    /// Get the default calibration directory for the current platform.
+    #[must_use]
+    pub fn default_directory() -> PathBuf {
+        if let Some(config_dir) = dirs::config_dir() {
+            config_dir.join("bevy_archie").join("calibration")
+        } else {
+            PathBuf::from("calibration")
+        }
+    }
+
+    /// File name for this controller's report.
+    ///
+    /// Uses the vendor and product ID when known, otherwise the name.
+    #[must_use]
+    pub fn file_name(&self) -> String {
+        let stem = match (self.vendor_id, self.product_id) {
+            (Some(vendor_id), Some(product_id)) => format!("{vendor_id:04x}-{product_id:04x}"),
+            _ => self
+                .name
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect(),
+        };
+        format!("{stem}.characterization.json")
+    }
+
+    /// Largest resting drift across all axes.
+    #[must_use]
+    pub fn max_drift(&self) -> f32 {
+        self.axes
+            .iter()
+            .map(|axis| axis.drift.abs())
+            .fold(0.0, f32::max)
+    }
+
+    /// Deadzone that covers the measured drift.
+    #[must_use]
+    pub fn suggested_deadzone(&self) -> f32 {
+        (self.max_drift() * 2.0 + 0.02).clamp(0.05, 0.5)
+    }
+
+    /// Outer threshold matching the shortest measured reach.
+    #[must_use]
+    pub fn suggested_outer_threshold(&self) -> f32 {
+        self.axes
+            .iter()
+            .map(|axis| axis.symmetric_range)
+            .filter(|range| *range > 0.5)
+            .fold(1.0, f32::min)
+    }
+
+    /// Tune a configuration from this report.
+    pub fn apply(&self, config: &mut ControllerConfig) {
+        config.deadzone = self.suggested_deadzone();
+        config.outer_threshold = self.suggested_outer_threshold();
+    }
+
+    /// Save the report into a directory, creating it if needed.
+    ///
+    /// Returns the path written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save(&self, directory: &Path) -> crate::Result<PathBuf> {
+        let path = directory.join(self.file_name());
+        let json = serde_json::to_string_pretty(self).map_err(crate::Error::Characterization)?;
+        std::fs::create_dir_all(directory).map_err(|e| crate::Error::io(directory, e))?;
+        std::fs::write(&path, json).map_err(|e| crate::Error::io(&path, e))?;
+        Ok(path)
+    }
+
+    /// Load a report from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or contains invalid JSON.
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        serde_json::from_str(&json).map_err(crate::Error::Characterization)
+    }
+}
+
+/// Resource running characterization sessions.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ControllerCharacterizer {
+    sessions: HashMap<Entity, CharacterizationSession>,
+}
+
+impl ControllerCharacterizer {
+    /// Start measuring a gamepad, discarding any previous session.
+    pub fn start(&mut self, gamepad: Entity) {
+        self.sessions
+            .insert(gamepad, CharacterizationSession::default());
+    }
+
+    /// Whether a gamepad is being measured.
+    #[must_use]
+    pub fn is_running(&self, gamepad: Entity) -> bool {
+        self.sessions.contains_key(&gamepad)
+    }
+
+    /// The session for a gamepad.
+    #[must_use]
+    pub fn session(&self, gamepad: Entity) -> Option<&CharacterizationSession> {
+        self.sessions.get(&gamepad)
+    }
+
+    /// Record that a latency stimulus fired at `time` (seconds of real time).
+    pub fn mark_stimulus(&mut self, gamepad: Entity, time: f64) {
+        if let Some(session) = self.sessions.get_mut(&gamepad) {
+            session.stimulus = Some(time);
+        }
+    }
+
+    /// Stop measuring a gamepad and return its report.
+    pub fn finish(&mut self, gamepad: Entity) -> Option<CharacterizationReport> {
+        self.sessions
+            .remove(&gamepad)
+            .map(|session| session.report())
+    }
+}
+
+/// System to sample gamepads being characterized.
+pub fn record_characterization(
+    time: Res<Time<Real>>,
+    gamepads: Query<(Entity, &Gamepad, Option<&Name>, Option<&DetectedController>)>,
+    mut characterizer: ResMut<ControllerCharacterizer>,
+) {
+    if characterizer.sessions.is_empty() {
+        return;
+    }
+    let now = time.elapsed_secs_f64();
+    for (entity, gamepad, name, detected) in &gamepads {
+        let Some(session) = characterizer.sessions.get_mut(&entity) else {
+            continue;
+        };
+        if let Some(name) = name {
+            session.name = name.to_string();
+        }
+        if let Some(detected) = detected {
+            session.vendor_id = Some(detected.vendor_id);
+            session.product_id = Some(detected.product_id);
+        }
+        let axes = AXES.map(|axis| gamepad.get(axis).unwrap_or(0.0));
+        session.record(now, axes, gamepad.get_just_pressed().next().is_some());
+    }
+}
+
+/// Plugin for registering characterization types.
+pub(crate) fn register_characterization_types(app: &mut App) {
+    app.init_resource::<ControllerCharacterizer>();
+}
+
+/// Add characterization systems to the app.
+pub(crate) fn add_characterization_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        record_characterization.run_if(subsystem_enabled(Subsystem::Characterization)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measured_session() -> CharacterizationSession {
+        let mut session = CharacterizationSession {
+            name: "Test Pad".into(),
+            ..default()
+        };
+        let mut now = 0.0;
+        // Resting with a slight rightward drift on the left stick
+        for _ in 0..10 {
+            session.record(now, [0.04, 0.0, 0.0, 0.0], false);
+            now += 0.01;
+        }
+        // Sweep the left stick, changing every other 10ms frame
+        for step in 0..20_u8 {
+            let x = f32::from(step / 2) * 0.1 - 0.45;
+            session.record(now, [x, 0.0, 0.0, 0.0], false);
+            now += 0.01;
+        }
+        session.stimulus = Some(now);
+        now += 0.03;
+        session.record(now, [0.04, 0.0, 0.0, 0.0], true);
+        session
+    }
+
+    #[test]
+    fn test_report_measurements() {
+        let report = measured_session().report();
+        assert_eq!(report.frames, 31);
+        assert!((report.polling_interval.unwrap() - 0.02).abs() < 1e-4);
+        assert!((report.latency.unwrap() - 0.03).abs() < 1e-4);
+        assert_eq!(report.latency_samples, 1);
+
+        let left_x = &report.axes[0];
+        assert_eq!(left_x.axis, "LeftStickX");
+        assert!((left_x.resolution.unwrap() - 0.01).abs() < 1e-4);
+        assert!((left_x.min + 0.45).abs() < 1e-4);
+        assert!((left_x.max - 0.45).abs() < 1e-4);
+        assert!(left_x.drift > 0.0);
+        assert_eq!(report.axes[3].resolution, None);
+    }
+
+    #[test]
+    fn test_rest_window_edge_is_symmetric() {
+        let mut session = CharacterizationSession::default();
+        // Both land on the window edge, with different float error
+        let below = f32::from(2_u8) * 0.1 - 0.45;
+        let above = f32::from(7_u8) * 0.1 - 0.45;
+        session.record(0.0, [below, 0.0, 0.0, 0.0], false);
+        session.record(0.01, [above, 0.0, 0.0, 0.0], false);
+        session.record(0.02, [0.26, 0.0, 0.0, 0.0], false);
+
+        assert_eq!(session.axes[0].rest_count, 2);
+        assert!(session.report().axes[0].drift.abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_report_suggestions() {
+        let mut report = measured_session().report();
+        report.axes[0].drift = 0.1;
+        report.axes[1].symmetric_range = 0.9;
+        assert!((report.suggested_deadzone() - 0.22).abs() < 1e-5);
+        assert!((report.suggested_outer_threshold() - 0.9).abs() < 1e-5);
+
+        let mut config = ControllerConfig::default();
+        report.apply(&mut config);
+        assert!((config.deadzone - 0.22).abs() < 1e-5);
+        assert!((config.outer_threshold - 0.9).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_report_file_name_and_round_trip() {
+        let mut report = measured_session().report();
+        assert_eq!(report.file_name(), "Test_Pad.characterization.json");
+        report.vendor_id = Some(0x054c);
+        report.product_id = Some(0x0ce6);
+        assert_eq!(report.file_name(), "054c-0ce6.characterization.json");
+
+        let directory = std::env::temp_dir().join("bevy_archie_characterization_test");
+        let path = report.save(&directory).unwrap();
+        assert_eq!(CharacterizationReport::load(&path).unwrap(), report);
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn test_characterizer_sessions() {
+        let mut characterizer = ControllerCharacterizer::default();
+        let gamepad = Entity::from_bits(1);
+        assert!(characterizer.finish(gamepad).is_none());
+
+        characterizer.start(gamepad);
+        assert!(characterizer.is_running(gamepad));
+        characterizer.mark_stimulus(gamepad, 1.0);
+        assert_eq!(characterizer.session(gamepad).unwrap().stimulus, Some(1.0));
+        assert_eq!(characterizer.finish(gamepad).unwrap().frames, 0);
+        assert!(!characterizer.is_running(gamepad));
+    }
+}
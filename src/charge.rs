@@ -0,0 +1,305 @@
+//! Multi-stage charge actions.
+//!
+//! Charge shots, charged jumps, and wind-up attacks all measure how long an
+//! action is held against a list of stage thresholds. A [`ChargeAction`]
+//! tracks the hold, fires [`ChargeStageReached`] (with a haptic tick on the
+//! active gamepad) as each stage is crossed, and reports the final stage in
+//! [`ChargeReleased`] when the action is let go.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::charge::{ChargeAction, ChargeReleased};
+//!
+//! fn spawn_blaster(mut commands: Commands) {
+//!     commands.spawn(ChargeAction::new(GameAction::Primary, [0.5, 1.5]));
+//! }
+//!
+//! fn fire(mut released: MessageReader<ChargeReleased>) {
+//!     for shot in released.read() {
+//!         match shot.stage {
+//!             0 => info!("Pea shot"),
+//!             1 => info!("Charged shot"),
+//!             _ => info!("Full charge!"),
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::actions::{ActionState, GameAction};
+use crate::detection::InputDeviceState;
+use crate::haptics::RumbleRequest;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// What changed during a [`ChargeAction::update`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChargeTransition {
+    /// A new stage was reached while holding.
+    StageReached(usize),
+    /// The action was released.
+    Released {
+        /// Stage reached before release (0 = uncharged).
+        stage: usize,
+        /// Seconds the action was held.
+        held: f32,
+    },
+}
+
+/// Component tracking a held action against charge stages.
+#[derive(Debug, Clone, Component)]
+pub struct ChargeAction {
+    /// The action being charged.
+    pub action: GameAction,
+    /// Hold durations in seconds at which each stage is reached, ascending.
+    pub stages: Vec<f32>,
+    /// Rumble strength of the tick played per stage (0.0 disables).
+    pub stage_rumble: f32,
+    /// Seconds the action has been held.
+    held: f32,
+    /// Current stage (0 = uncharged).
+    stage: usize,
+    /// Whether the action is being held.
+    charging: bool,
+}
+
+impl ChargeAction {
+    /// Create a charge tracker with stage thresholds in seconds.
+    #[must_use]
+    pub fn new(action: GameAction, stages: impl Into<Vec<f32>>) -> Self {
+        let mut stages = stages.into();
+        stages.sort_by(f32::total_cmp);
+        Self {
+            action,
+            stages,
+            stage_rumble: 0.4,
+            held: 0.0,
+            stage: 0,
+            charging: false,
+        }
+    }
+
+    /// Set the per-stage rumble strength.
+    #[must_use]
+    pub const fn with_stage_rumble(mut self, strength: f32) -> Self {
+        self.stage_rumble = strength;
+        self
+    }
+
+    /// Seconds the action has been held.
+    #[must_use]
+    pub const fn held(&self) -> f32 {
+        self.held
+    }
+
+    /// Current stage (0 = uncharged).
+    #[must_use]
+    pub const fn stage(&self) -> usize {
+        self.stage
+    }
+
+    /// Highest reachable stage.
+    #[must_use]
+    pub fn max_stage(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether the action is being held.
+    #[must_use]
+    pub const fn is_charging(&self) -> bool {
+        self.charging
+    }
+
+    /// Progress from the current stage to the next (0.0-1.0).
+    ///
+    /// Stays at 1.0 once fully charged.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        let Some(next) = self.stages.get(self.stage) else {
+            return 1.0;
+        };
+        let start = self
+            .stage
+            .checked_sub(1)
+            .and_then(|index| self.stages.get(index))
+            .copied()
+            .unwrap_or(0.0);
+        ((self.held - start) / (next - start).max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+
+    /// Advance the charge by `delta` seconds with the action's held state.
+    pub fn update(&mut self, pressed: bool, delta: f32) -> Option<ChargeTransition> {
+        if !pressed {
+            if !self.charging {
+                return None;
+            }
+            let released = ChargeTransition::Released {
+                stage: self.stage,
+                held: self.held,
+            };
+            self.cancel();
+            return Some(released);
+        }
+
+        self.charging = true;
+        self.held += delta;
+
+        let stage = self
+            .stages
+            .iter()
+            .take_while(|threshold| self.held >= **threshold)
+            .count();
+        if stage > self.stage {
+            self.stage = stage;
+            return Some(ChargeTransition::StageReached(stage));
+        }
+        None
+    }
+
+    /// Drop the charge without reporting a release.
+    pub fn cancel(&mut self) {
+        self.held = 0.0;
+        self.stage = 0;
+        self.charging = false;
+    }
+}
+
+/// Event fired when a charge reaches a new stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct ChargeStageReached {
+    /// Entity holding the [`ChargeAction`].
+    pub entity: Entity,
+    /// The charged action.
+    pub action: GameAction,
+    /// Stage reached (1-based).
+    pub stage: usize,
+}
+
+/// Event fired when a charged action is released.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct ChargeReleased {
+    /// Entity holding the [`ChargeAction`].
+    pub entity: Entity,
+    /// The charged action.
+    pub action: GameAction,
+    /// Stage reached before release (0 = uncharged).
+    pub stage: usize,
+    /// Seconds the action was held.
+    pub held: f32,
+}
+
+/// System to update charge actions and report stages and releases.
+pub fn update_charge_actions(
+    time: Res<Time>,
+    action_state: Res<ActionState>,
+    device_state: Res<InputDeviceState>,
+    mut charges: Query<(Entity, &mut ChargeAction)>,
+    mut stage_events: MessageWriter<ChargeStageReached>,
+    mut release_events: MessageWriter<ChargeReleased>,
+    mut rumble_requests: MessageWriter<RumbleRequest>,
+) {
+    for (entity, mut charge) in &mut charges {
+        let action = charge.action;
+        match charge.update(action_state.pressed(action), time.delta_secs()) {
+            Some(ChargeTransition::StageReached(stage)) => {
+                stage_events.write(ChargeStageReached {
+                    entity,
+                    action,
+                    stage,
+                });
+                if charge.stage_rumble > 0.0
+                    && let Some(gamepad) = device_state.active_gamepad()
+                {
+                    rumble_requests.write(RumbleRequest::new(
+                        gamepad,
+                        charge.stage_rumble,
+                        Duration::from_millis(50),
+                    ));
+                }
+            }
+            Some(ChargeTransition::Released { stage, held }) => {
+                release_events.write(ChargeReleased {
+                    entity,
+                    action,
+                    stage,
+                    held,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+/// Plugin for registering charge types.
+pub(crate) fn register_charge_types(app: &mut App) {
+    app.add_message::<ChargeStageReached>()
+        .add_message::<ChargeReleased>();
+}
+
+/// Add charge systems to the app.
+pub(crate) fn add_charge_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        update_charge_actions.run_if(subsystem_enabled(Subsystem::Charge)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_stages_reached_while_holding() {
+        let mut charge = ChargeAction::new(GameAction::Primary, [1.5, 0.5]);
+        assert_eq!(charge.stages, vec![0.5, 1.5]);
+
+        assert_eq!(charge.update(true, 0.25), None);
+        assert_relative_eq!(charge.progress(), 0.5);
+        assert_eq!(
+            charge.update(true, 0.25),
+            Some(ChargeTransition::StageReached(1))
+        );
+        assert_eq!(charge.update(true, 0.5), None);
+        assert_relative_eq!(charge.progress(), 0.5);
+        assert_eq!(
+            charge.update(true, 0.5),
+            Some(ChargeTransition::StageReached(2))
+        );
+        assert_eq!(charge.update(true, 1.0), None);
+        assert_relative_eq!(charge.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_release_reports_final_stage() {
+        let mut charge = ChargeAction::new(GameAction::Primary, [0.5, 1.5]);
+        charge.update(true, 0.75);
+        assert_eq!(
+            charge.update(false, 0.1),
+            Some(ChargeTransition::Released {
+                stage: 1,
+                held: 0.75
+            })
+        );
+        assert!(!charge.is_charging());
+        assert_eq!(charge.stage(), 0);
+        assert_eq!(charge.update(false, 0.1), None);
+    }
+
+    #[test]
+    fn test_quick_tap_releases_uncharged() {
+        let mut charge = ChargeAction::new(GameAction::Primary, [0.5]);
+        charge.update(true, 0.1);
+        assert_eq!(
+            charge.update(false, 0.1),
+            Some(ChargeTransition::Released {
+                stage: 0,
+                held: 0.1
+            })
+        );
+    }
+}
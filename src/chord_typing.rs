@@ -0,0 +1,430 @@
+//! Stenography-style chord typing for the virtual keyboard.
+//!
+//! Chord typing is an alternative to the key grid for experienced players:
+//! hold a combination of face buttons, d-pad directions and shoulder buttons,
+//! and when every button is released the whole stroke types the letter or
+//! letter group bound to it in the [`ChordChart`]. Press the right stick on
+//! the keyboard to switch between grid and chord typing, or open the keyboard
+//! with [`VirtualKeyboard::with_chord_typing`].
+//!
+//! While chord typing, Start confirms and Select cancels. Rendering is left
+//! to the game: draw [`ChordChart::candidates`] for the buttons in
+//! [`ChordTypingState::held`] as a chart overlay, so new players can see
+//! which strokes are still reachable from what they are holding.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::chord_typing::{ChordChart, ChordOutput};
+//!
+//! fn add_word_chords(mut chart: ResMut<ChordChart>) {
+//!     chart.bind(
+//!         &[GamepadButton::North, GamepadButton::West, GamepadButton::South],
+//!         ChordOutput::text("you"),
+//!     );
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::ui_sound::{UiNavigationSound, UiNavigationSoundKind, UiNavigationSource};
+use crate::virtual_keyboard::{VirtualKeyboard, VirtualKeyboardState, handle_keyboard_input};
+
+/// What a chord types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChordOutput {
+    /// A letter or letter group.
+    Text(String),
+    /// A space.
+    Space,
+    /// Delete the character before the cursor.
+    Backspace,
+    /// Capitalize the next character.
+    Shift,
+}
+
+impl ChordOutput {
+    /// Type a letter or letter group.
+    #[must_use]
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Label for the chord chart.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Text(text) => text,
+            Self::Space => "space",
+            Self::Backspace => "backspace",
+            Self::Shift => "shift",
+        }
+    }
+
+    /// Apply this output to a keyboard.
+    pub fn apply(&self, keyboard: &mut VirtualKeyboard) {
+        match self {
+            Self::Text(text) => {
+                for c in text.chars() {
+                    keyboard.add_char(c);
+                }
+            }
+            Self::Space => keyboard.add_space(),
+            Self::Backspace => keyboard.backspace(),
+            Self::Shift => keyboard.toggle_shift(),
+        }
+    }
+}
+
+/// A chord and what it types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordEntry {
+    /// Buttons pressed together for this chord.
+    pub buttons: Vec<GamepadButton>,
+    /// What the chord types.
+    pub output: ChordOutput,
+}
+
+impl ChordEntry {
+    /// Check if this chord is exactly a set of buttons.
+    #[must_use]
+    pub fn is_chord(&self, buttons: &[GamepadButton]) -> bool {
+        self.buttons.len() == buttons.len() && self.contains_all(buttons)
+    }
+
+    /// Check if this chord includes every one of some buttons.
+    #[must_use]
+    pub fn contains_all(&self, buttons: &[GamepadButton]) -> bool {
+        buttons.iter().all(|button| self.buttons.contains(button))
+    }
+}
+
+/// Resource mapping button chords to text.
+///
+/// The default chart puts the eight most common English letters on single
+/// buttons, the rest of the alphabet and common letter groups on two-button
+/// chords, and space, backspace and shift on the shoulder buttons.
+#[derive(Debug, Clone, Resource)]
+pub struct ChordChart {
+    entries: Vec<ChordEntry>,
+}
+
+impl Default for ChordChart {
+    fn default() -> Self {
+        use bevy::input::gamepad::GamepadButton::{
+            DPadDown as Down, DPadLeft as Left, DPadRight as Right, DPadUp as Up, East,
+            LeftTrigger, LeftTrigger2, North, RightTrigger, South, West,
+        };
+
+        let letters: &[(&[GamepadButton], &str)] = &[
+            (&[South], "e"),
+            (&[East], "t"),
+            (&[West], "a"),
+            (&[North], "o"),
+            (&[Up], "i"),
+            (&[Down], "n"),
+            (&[Left], "s"),
+            (&[Right], "r"),
+            (&[South, East], "h"),
+            (&[South, West], "l"),
+            (&[South, North], "d"),
+            (&[East, West], "c"),
+            (&[East, North], "u"),
+            (&[West, North], "m"),
+            (&[Up, Left], "w"),
+            (&[Up, Right], "f"),
+            (&[Down, Left], "g"),
+            (&[Down, Right], "y"),
+            (&[South, Up], "p"),
+            (&[South, Down], "b"),
+            (&[South, Left], "v"),
+            (&[South, Right], "k"),
+            (&[East, Up], "j"),
+            (&[East, Down], "x"),
+            (&[East, Left], "q"),
+            (&[East, Right], "z"),
+            (&[West, Up], "th"),
+            (&[West, Down], "ing"),
+            (&[West, Left], "er"),
+            (&[West, Right], "and"),
+            (&[North, Up], "the"),
+            (&[North, Down], "ed"),
+            (&[North, Left], "on"),
+            (&[North, Right], "an"),
+        ];
+
+        let mut chart = Self::empty();
+        for (buttons, text) in letters {
+            chart.bind(buttons, ChordOutput::text(*text));
+        }
+        chart.bind(&[RightTrigger], ChordOutput::Space);
+        chart.bind(&[LeftTrigger], ChordOutput::Backspace);
+        chart.bind(&[LeftTrigger2], ChordOutput::Shift);
+        chart
+    }
+}
+
+impl ChordChart {
+    /// Create a chart with no chords.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Bind a chord, replacing whatever it typed before.
+    pub fn bind(&mut self, buttons: &[GamepadButton], output: ChordOutput) {
+        self.unbind(buttons);
+        let mut chord = Vec::with_capacity(buttons.len());
+        for button in buttons {
+            if !chord.contains(button) {
+                chord.push(*button);
+            }
+        }
+        if !chord.is_empty() {
+            self.entries.push(ChordEntry {
+                buttons: chord,
+                output,
+            });
+        }
+    }
+
+    /// Remove a chord.
+    pub fn unbind(&mut self, buttons: &[GamepadButton]) {
+        self.entries.retain(|entry| !entry.is_chord(buttons));
+    }
+
+    /// All chords, in the order they were bound.
+    #[must_use]
+    pub fn entries(&self) -> &[ChordEntry] {
+        &self.entries
+    }
+
+    /// What a stroke types, if it is bound.
+    #[must_use]
+    pub fn lookup(&self, buttons: &[GamepadButton]) -> Option<&ChordOutput> {
+        self.entries
+            .iter()
+            .find(|entry| entry.is_chord(buttons))
+            .map(|entry| &entry.output)
+    }
+
+    /// The chord that types some text, for teaching it.
+    #[must_use]
+    pub fn chord_for(&self, text: &str) -> Option<&[GamepadButton]> {
+        self.entries
+            .iter()
+            .find(|entry| matches!(&entry.output, ChordOutput::Text(t) if t == text))
+            .map(|entry| entry.buttons.as_slice())
+    }
+
+    /// Chords still reachable while holding some buttons.
+    ///
+    /// With nothing held this is the whole chart.
+    pub fn candidates<'a>(
+        &'a self,
+        held: &'a [GamepadButton],
+    ) -> impl Iterator<Item = &'a ChordEntry> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.contains_all(held))
+    }
+}
+
+/// Resource tracking the stroke being typed.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ChordTypingState {
+    /// Buttons pressed since the stroke started.
+    held: Vec<GamepadButton>,
+}
+
+impl ChordTypingState {
+    /// Buttons pressed so far in the current stroke.
+    #[must_use]
+    pub fn held(&self) -> &[GamepadButton] {
+        &self.held
+    }
+
+    /// Check if a stroke is in progress.
+    #[must_use]
+    pub fn is_stroking(&self) -> bool {
+        !self.held.is_empty()
+    }
+
+    /// Drop the current stroke without typing it.
+    pub fn reset(&mut self) {
+        self.held.clear();
+    }
+
+    /// Add pressed chart buttons to the stroke, returning the finished
+    /// stroke once every chart button is released.
+    fn update(
+        &mut self,
+        chart: &ChordChart,
+        pressed: impl Fn(GamepadButton) -> bool,
+    ) -> Option<Vec<GamepadButton>> {
+        let mut any_pressed = false;
+        for entry in &chart.entries {
+            for button in &entry.buttons {
+                if pressed(*button) {
+                    any_pressed = true;
+                    if !self.held.contains(button) {
+                        self.held.push(*button);
+                    }
+                }
+            }
+        }
+
+        if any_pressed || self.held.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.held))
+        }
+    }
+}
+
+/// Event fired when a chord stroke finishes.
+#[derive(Debug, Clone, Message)]
+pub struct ChordTyped {
+    /// Buttons in the stroke.
+    pub buttons: Vec<GamepadButton>,
+    /// What was typed, or `None` if the stroke isn't in the chart.
+    pub output: Option<ChordOutput>,
+}
+
+/// System to type chord strokes into the virtual keyboard.
+pub fn type_chords(
+    mut keyboard: ResMut<VirtualKeyboard>,
+    mut state: ResMut<ChordTypingState>,
+    chart: Res<ChordChart>,
+    gamepads: Query<&Gamepad>,
+    mut typed_events: MessageWriter<ChordTyped>,
+    mut sounds: MessageWriter<UiNavigationSound>,
+) {
+    if !keyboard.chord_typing {
+        state.reset();
+        return;
+    }
+
+    let Some(buttons) = state.update(&chart, |button| {
+        gamepads.iter().any(|gamepad| gamepad.pressed(button))
+    }) else {
+        return;
+    };
+
+    let output = chart.lookup(&buttons).cloned();
+    let kind = if let Some(output) = &output {
+        output.apply(&mut keyboard);
+        UiNavigationSoundKind::Confirmed
+    } else {
+        UiNavigationSoundKind::Blocked
+    };
+    sounds.write(UiNavigationSound::new(
+        kind,
+        UiNavigationSource::VirtualKeyboard,
+    ));
+    typed_events.write(ChordTyped { buttons, output });
+}
+
+/// Add chord typing systems to the app.
+pub(crate) fn add_chord_typing_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.init_resource::<ChordChart>()
+        .init_resource::<ChordTypingState>()
+        .add_message::<ChordTyped>()
+        .add_systems(
+            schedules.update,
+            type_chords
+                .after(handle_keyboard_input)
+                .run_if(in_state(VirtualKeyboardState::Visible))
+                .run_if(subsystem_enabled(Subsystem::VirtualKeyboard)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_chart_covers_alphabet_once() {
+        let chart = ChordChart::default();
+        for letter in 'a'..='z' {
+            assert!(
+                chart.chord_for(&letter.to_string()).is_some(),
+                "no chord for {letter}"
+            );
+        }
+        for (i, entry) in chart.entries().iter().enumerate() {
+            assert!(
+                chart.entries()[i + 1..]
+                    .iter()
+                    .all(|other| !other.is_chord(&entry.buttons)),
+                "duplicate chord {:?}",
+                entry.buttons
+            );
+        }
+    }
+
+    #[test]
+    fn test_stroke_finishes_on_release() {
+        let chart = ChordChart::default();
+        let mut state = ChordTypingState::default();
+
+        let south_and_east =
+            |button: GamepadButton| matches!(button, GamepadButton::South | GamepadButton::East);
+        assert_eq!(state.update(&chart, south_and_east), None);
+        // Releasing one button keeps the stroke going
+        assert_eq!(
+            state.update(&chart, |button| button == GamepadButton::East),
+            None
+        );
+        assert_eq!(state.held(), &[GamepadButton::South, GamepadButton::East]);
+
+        let stroke = state.update(&chart, |_| false).unwrap();
+        assert_eq!(chart.lookup(&stroke), Some(&ChordOutput::text("h")));
+        assert!(!state.is_stroking());
+    }
+
+    #[test]
+    fn test_candidates_narrow_with_held_buttons() {
+        let chart = ChordChart::default();
+        assert_eq!(chart.candidates(&[]).count(), chart.entries().len());
+
+        let held = [GamepadButton::West, GamepadButton::DPadDown];
+        let labels: Vec<_> = chart
+            .candidates(&held)
+            .map(|entry| entry.output.label())
+            .collect();
+        assert_eq!(labels, ["ing"]);
+    }
+
+    #[test]
+    fn test_chord_output_applies_to_keyboard() {
+        let mut keyboard = VirtualKeyboard::new("Name");
+        ChordOutput::Shift.apply(&mut keyboard);
+        ChordOutput::text("the").apply(&mut keyboard);
+        ChordOutput::Space.apply(&mut keyboard);
+        ChordOutput::text("ed").apply(&mut keyboard);
+        ChordOutput::Backspace.apply(&mut keyboard);
+        assert_eq!(keyboard.value(), "The e");
+    }
+
+    #[test]
+    fn test_rebinding_replaces_chord() {
+        let mut chart = ChordChart::default();
+        chart.bind(
+            &[GamepadButton::East, GamepadButton::South],
+            ChordOutput::text("ch"),
+        );
+        assert_eq!(
+            chart.lookup(&[GamepadButton::South, GamepadButton::East]),
+            Some(&ChordOutput::text("ch"))
+        );
+        assert_eq!(chart.chord_for("h"), None);
+    }
+}
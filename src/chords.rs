@@ -12,11 +12,34 @@
 //! // Create a chord that requires Ctrl+Shift+C
 //! let chord = ButtonChord::from_keys(&[KeyCode::ControlLeft, KeyCode::ShiftLeft, KeyCode::KeyC]);
 //! ```
+//!
+//! # Chords in the action system
+//!
+//! [`ChordBindings`] binds chords to actions. Each frame the pressed chords
+//! and the pressed single-button bindings from the
+//! [`ActionMap`](crate::actions::ActionMap) are resolved together with the
+//! configured [`ClashStrategy`], so holding LB+A can fire a chord action
+//! without also firing the action bound to A alone:
+//!
+//! ```rust,no_run
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::chords::{ButtonChord, ChordBindings, ClashStrategy};
+//! use bevy::prelude::*;
+//!
+//! let mut chords = ChordBindings::default().with_strategy(ClashStrategy::PrioritizeLongest);
+//! chords.bind(
+//!     ButtonChord::from_gamepad_buttons(&[GamepadButton::LeftTrigger, GamepadButton::South]),
+//!     GameAction::Secondary,
+//! );
+//! ```
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use crate::actions::{ActionMap, ActionState, GameAction};
+use crate::simulated_input::SimulatedInput;
+use crate::subsystems::{Subsystem, subsystem_enabled};
 use crate::virtual_input::VirtualButton;
 
 /// A chord of buttons that must all be pressed simultaneously.
@@ -201,6 +224,11 @@ pub enum ClashStrategy {
     ///
     /// Only Ctrl+Shift+A would trigger; A and Ctrl+A would not.
     ExactOnly,
+
+    /// Prioritize the highest [`ChordBinding::priority`].
+    ///
+    /// Clashing bindings with equal priority all trigger.
+    PrioritizeHighest,
 }
 
 /// A chord binding that associates a chord with an action value.
@@ -235,51 +263,211 @@ impl<A> ChordBinding<A> {
 }
 
 /// Resolves clashes between multiple pressed chords.
+///
+/// Only bindings whose chords clash (one is a subset of the other) compete;
+/// unrelated bindings always trigger.
 pub fn resolve_clashes<A: Clone>(
     pressed_bindings: &[ChordBinding<A>],
     strategy: ClashStrategy,
 ) -> Vec<A> {
-    if pressed_bindings.is_empty() {
-        return Vec::new();
-    }
+    pressed_bindings
+        .iter()
+        .enumerate()
+        .filter(|(index, binding)| {
+            !pressed_bindings
+                .iter()
+                .enumerate()
+                .any(|(other_index, other)| {
+                    other_index != *index
+                        && binding.chord.clashes_with(&other.chord)
+                        && beats(other, other_index, binding, *index, strategy)
+                })
+        })
+        .map(|(_, binding)| binding.action.clone())
+        .collect()
+}
 
+/// Whether `other` wins a clash against `binding` under a strategy.
+fn beats<A>(
+    other: &ChordBinding<A>,
+    other_index: usize,
+    binding: &ChordBinding<A>,
+    index: usize,
+    strategy: ClashStrategy,
+) -> bool {
     match strategy {
-        ClashStrategy::UseAll => pressed_bindings.iter().map(|b| b.action.clone()).collect(),
-        ClashStrategy::PrioritizeLongest => {
-            // Group by chord length, take the longest
-            let max_len = pressed_bindings
-                .iter()
-                .map(|b| b.chord.len())
-                .max()
-                .unwrap_or(0);
-            pressed_bindings
-                .iter()
-                .filter(|b| b.chord.len() == max_len)
-                .map(|b| b.action.clone())
-                .collect()
-        }
-        ClashStrategy::PrioritizeFirst => {
-            // Just take the first one
-            pressed_bindings
-                .first()
-                .map(|b| b.action.clone())
-                .into_iter()
-                .collect()
+        ClashStrategy::UseAll => false,
+        ClashStrategy::PrioritizeLongest | ClashStrategy::ExactOnly => {
+            other.chord.len() > binding.chord.len()
         }
-        ClashStrategy::ExactOnly => {
-            // Only include chords that don't clash with longer chords
-            let mut result = Vec::new();
-            for binding in pressed_bindings {
-                let is_subset_of_longer = pressed_bindings.iter().any(|other| {
-                    other.chord.len() > binding.chord.len()
-                        && binding.chord.clashes_with(&other.chord)
-                });
-                if !is_subset_of_longer {
-                    result.push(binding.action.clone());
-                }
+        ClashStrategy::PrioritizeFirst => other_index < index,
+        ClashStrategy::PrioritizeHighest => other.priority > binding.priority,
+    }
+}
+
+/// Resource binding chords to actions and resolving their clashes.
+///
+/// Winning chords press their action through [`SimulatedInput`]; single-button
+/// actions that lose a clash are suppressed until their button is released,
+/// so letting go of the rest of the chord first doesn't fire them.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ChordBindings {
+    /// Chord bindings.
+    bindings: Vec<ChordBinding<GameAction>>,
+    /// How overlapping bindings are resolved.
+    pub strategy: ClashStrategy,
+    /// Per-action priorities for [`ClashStrategy::PrioritizeHighest`].
+    ///
+    /// Applies to single-button bindings from the action map; chords use
+    /// their own [`ChordBinding::priority`].
+    pub priorities: HashMap<GameAction, i32>,
+    /// Single-button actions that lost a clash and are still held.
+    held: HashSet<GameAction>,
+}
+
+impl ChordBindings {
+    /// Set the clash strategy.
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: ClashStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Bind a chord to an action.
+    pub fn bind(&mut self, chord: ButtonChord, action: GameAction) {
+        self.bindings.push(ChordBinding::new(chord, action));
+    }
+
+    /// Bind a chord to an action with a clash priority.
+    pub fn bind_with_priority(&mut self, chord: ButtonChord, action: GameAction, priority: i32) {
+        self.bindings
+            .push(ChordBinding::with_priority(chord, action, priority));
+    }
+
+    /// Set the priority of an action's single-button bindings.
+    pub fn set_priority(&mut self, action: GameAction, priority: i32) {
+        self.priorities.insert(action, priority);
+    }
+
+    /// Remove every chord bound to an action.
+    pub fn clear(&mut self, action: GameAction) {
+        self.bindings.retain(|binding| binding.action != action);
+    }
+
+    /// Chord bindings in registration order.
+    #[must_use]
+    pub fn bindings(&self) -> &[ChordBinding<GameAction>] {
+        &self.bindings
+    }
+
+    /// The first chord bound to an action (for icon display).
+    #[must_use]
+    pub fn chord_for(&self, action: GameAction) -> Option<&ButtonChord> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == action)
+            .map(|binding| &binding.chord)
+    }
+
+    /// Whether an action lost a clash and is still held.
+    #[must_use]
+    pub fn is_suppressed(&self, action: GameAction) -> bool {
+        self.held.contains(&action)
+    }
+
+    /// Resolve pressed chords against pressed single-button bindings.
+    ///
+    /// `is_pressed` reports whether a button is held. Returns the chord
+    /// actions that won and records the single-button actions that lost,
+    /// which stay suppressed until none of their buttons are held.
+    pub fn resolve(
+        &mut self,
+        action_map: &ActionMap,
+        is_pressed: impl Fn(VirtualButton) -> bool,
+    ) -> Vec<GameAction> {
+        let mut candidates: Vec<ChordBinding<GameAction>> = self
+            .bindings
+            .iter()
+            .filter(|binding| {
+                !binding.chord.is_empty() && binding.chord.buttons().iter().all(|b| is_pressed(*b))
+            })
+            .cloned()
+            .collect();
+        let chord_count = candidates.len();
+
+        let singles =
+            action_map
+                .gamepad_bindings
+                .iter()
+                .flat_map(|(action, buttons)| {
+                    buttons
+                        .iter()
+                        .map(|b| (*action, VirtualButton::Gamepad(*b)))
+                })
+                .chain(action_map.key_bindings.iter().flat_map(|(action, keys)| {
+                    keys.iter().map(|k| (*action, VirtualButton::Key(*k)))
+                }))
+                .chain(
+                    action_map
+                        .mouse_bindings
+                        .iter()
+                        .flat_map(|(action, buttons)| {
+                            buttons.iter().map(|b| (*action, VirtualButton::Mouse(*b)))
+                        }),
+                );
+        for (action, button) in singles {
+            if is_pressed(button) {
+                let priority = self.priorities.get(&action).copied().unwrap_or(0);
+                candidates.push(ChordBinding::with_priority(
+                    ButtonChord::from_buttons([button]),
+                    action,
+                    priority,
+                ));
             }
-            result
         }
+
+        let winners = resolve_clashes(&candidates, self.strategy);
+        let singles = &candidates[chord_count..];
+        self.held
+            .retain(|action| singles.iter().any(|binding| binding.action == *action));
+        self.held.extend(
+            singles
+                .iter()
+                .map(|binding| binding.action)
+                .filter(|action| !winners.contains(action)),
+        );
+
+        candidates[..chord_count]
+            .iter()
+            .map(|binding| binding.action)
+            .filter(|action| winners.contains(action))
+            .collect()
+    }
+}
+
+/// System to press winning chord actions and record clashed single buttons.
+pub fn update_chords(
+    mut chords: ResMut<ChordBindings>,
+    action_map: Res<ActionMap>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    mut simulated: ResMut<SimulatedInput>,
+) {
+    let winners = chords.resolve(&action_map, |button| {
+        button.is_pressed_with_mouse(&keyboard, &mouse, &gamepads)
+    });
+    for action in winners {
+        if simulated.get(action).is_none() {
+            simulated.tap(action);
+        }
+    }
+}
+
+/// System to suppress single-button actions that lost a chord clash.
+pub fn suppress_clashed_actions(chords: Res<ChordBindings>, mut action_state: ResMut<ActionState>) {
+    for action in &chords.held {
+        action_state.suppress(*action, "chord clash");
     }
 }
 
@@ -321,7 +509,20 @@ impl ModifierKey {
 pub(crate) fn register_chord_types(app: &mut App) {
     app.register_type::<ButtonChord>()
         .register_type::<ClashStrategy>()
-        .register_type::<ModifierKey>();
+        .register_type::<ModifierKey>()
+        .init_resource::<ChordBindings>();
+}
+
+/// Add chord systems to the app.
+pub(crate) fn add_chord_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        (
+            update_chords.before(crate::actions::update_action_state::<GameAction>),
+            suppress_clashed_actions.after(crate::actions::update_action_state::<GameAction>),
+        )
+            .run_if(subsystem_enabled(Subsystem::Chords)),
+    );
 }
 
 #[cfg(test)]
@@ -397,4 +598,69 @@ mod tests {
         assert_ne!(ModifierKey::Control, ModifierKey::Shift);
         assert_ne!(ModifierKey::Alt, ModifierKey::Super);
     }
+
+    #[test]
+    fn test_clash_strategy_ignores_unrelated_bindings() {
+        let bindings = vec![
+            ChordBinding::new(ButtonChord::from_keys(&[KeyCode::KeyW]), "W"),
+            ChordBinding::new(
+                ButtonChord::from_keys(&[KeyCode::ControlLeft, KeyCode::KeyA]),
+                "Ctrl+A",
+            ),
+        ];
+
+        let result = resolve_clashes(&bindings, ClashStrategy::PrioritizeLongest);
+        assert_eq!(result, vec!["W", "Ctrl+A"]);
+    }
+
+    #[test]
+    fn test_clash_strategy_prioritize_highest() {
+        let bindings = vec![
+            ChordBinding::with_priority(ButtonChord::from_keys(&[KeyCode::KeyA]), "A", 5),
+            ChordBinding::new(
+                ButtonChord::from_keys(&[KeyCode::ControlLeft, KeyCode::KeyA]),
+                "Ctrl+A",
+            ),
+        ];
+
+        let result = resolve_clashes(&bindings, ClashStrategy::PrioritizeHighest);
+        assert_eq!(result, vec!["A"]);
+    }
+
+    #[test]
+    fn test_chord_bindings_suppress_single_button_action() {
+        let map = ActionMap::default();
+        let mut chords = ChordBindings::default();
+        chords.bind(
+            ButtonChord::from_gamepad_buttons(&[GamepadButton::LeftTrigger, GamepadButton::South]),
+            GameAction::Secondary,
+        );
+        let held = [
+            VirtualButton::Gamepad(GamepadButton::LeftTrigger),
+            VirtualButton::Gamepad(GamepadButton::South),
+        ];
+
+        let winners = chords.resolve(&map, |button| held.contains(&button));
+        assert_eq!(winners, vec![GameAction::Secondary]);
+        assert!(chords.is_suppressed(GameAction::Confirm));
+        assert!(chords.is_suppressed(GameAction::LeftShoulder));
+        assert!(!chords.is_suppressed(GameAction::Secondary));
+
+        // Letting go of the trigger first doesn't fire A's action
+        let south = [VirtualButton::Gamepad(GamepadButton::South)];
+        assert!(
+            chords
+                .resolve(&map, |button| south.contains(&button))
+                .is_empty()
+        );
+        assert!(chords.is_suppressed(GameAction::Confirm));
+        assert!(!chords.is_suppressed(GameAction::LeftShoulder));
+
+        chords.resolve(&map, |_| false);
+        assert!(!chords.is_suppressed(GameAction::Confirm));
+
+        chords.strategy = ClashStrategy::UseAll;
+        chords.resolve(&map, |button| held.contains(&button));
+        assert!(!chords.is_suppressed(GameAction::Confirm));
+    }
 }
@@ -25,6 +25,19 @@ pub enum ControllerLayout {
 }
 
 impl ControllerLayout {
+    /// Get all layouts as a slice.
+    #[must_use]
+    pub fn all() -> &'static [ControllerLayout] {
+        &[
+            Self::Xbox,
+            Self::PlayStation,
+            Self::Nintendo,
+            Self::Steam,
+            Self::Stadia,
+            Self::Generic,
+        ]
+    }
+
     /// Detect controller layout from controller name.
     #[must_use]
     pub fn from_name(name: &str) -> Self {
@@ -154,6 +167,128 @@ impl ControllerLayout {
     }
 }
 
+/// Which face button confirms and which cancels in menus.
+///
+/// Western games confirm with the bottom face button (A / Cross), while
+/// Nintendo titles and older Japanese `PlayStation` titles confirm with the
+/// right face button (A on Nintendo / Circle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Reflect)]
+pub enum ConfirmStyle {
+    /// Pick a convention from the active controller layout.
+    #[default]
+    Auto,
+    /// Bottom face button confirms, right face button cancels.
+    South,
+    /// Right face button confirms, bottom face button cancels.
+    East,
+}
+
+impl ConfirmStyle {
+    /// Resolve `Auto` against a controller layout.
+    ///
+    /// Nintendo layouts use the right face button for confirm; everything
+    /// else follows the Western convention.
+    #[must_use]
+    pub const fn resolve(self, layout: ControllerLayout) -> Self {
+        match self {
+            Self::Auto => match layout {
+                ControllerLayout::Nintendo => Self::East,
+                _ => Self::South,
+            },
+            other => other,
+        }
+    }
+
+    /// Pick a convention from a region code (e.g. `"JP"` or `"ja-JP"`).
+    ///
+    /// Returns `Auto` for regions without a strong convention so that the
+    /// layout can still decide.
+    #[must_use]
+    pub fn from_region(region: &str) -> Self {
+        let region = region.to_ascii_uppercase();
+        if region == "JP" || region == "JA" || region.ends_with("-JP") || region.ends_with("_JP") {
+            Self::East
+        } else {
+            Self::Auto
+        }
+    }
+
+    /// The button that confirms under this convention.
+    ///
+    /// `Auto` behaves like `South`; call [`ConfirmStyle::resolve`] first.
+    #[must_use]
+    pub const fn confirm_button(self) -> GamepadButton {
+        match self {
+            Self::East => GamepadButton::East,
+            Self::Auto | Self::South => GamepadButton::South,
+        }
+    }
+
+    /// The button that cancels under this convention.
+    ///
+    /// `Auto` behaves like `South`; call [`ConfirmStyle::resolve`] first.
+    #[must_use]
+    pub const fn cancel_button(self) -> GamepadButton {
+        match self {
+            Self::East => GamepadButton::South,
+            Self::Auto | Self::South => GamepadButton::East,
+        }
+    }
+}
+
+/// Staged repeat timing for held actions.
+///
+/// The first repeat fires after `initial_delay`, then repeats every
+/// `slow_interval` until `fast_after` repeats have fired, after which they
+/// speed up to `fast_interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+pub struct RepeatStages {
+    /// Seconds held before the first repeat.
+    pub initial_delay: f32,
+    /// Seconds between repeats in the slow stage.
+    pub slow_interval: f32,
+    /// Seconds between repeats in the fast stage.
+    pub fast_interval: f32,
+    /// Repeats fired before switching to the fast stage.
+    pub fast_after: u32,
+}
+
+impl Default for RepeatStages {
+    fn default() -> Self {
+        Self {
+            initial_delay: 0.4,
+            slow_interval: 0.15,
+            fast_interval: 0.05,
+            fast_after: 5,
+        }
+    }
+}
+
+impl RepeatStages {
+    /// Single-stage timing with a fixed interval after the initial delay.
+    #[must_use]
+    pub const fn constant(initial_delay: f32, interval: f32) -> Self {
+        Self {
+            initial_delay,
+            slow_interval: interval,
+            fast_interval: interval,
+            fast_after: 0,
+        }
+    }
+
+    /// Seconds until the next repeat after `repeats` have already fired.
+    #[must_use]
+    pub const fn interval(&self, repeats: u32) -> f32 {
+        if repeats == 0 {
+            self.initial_delay
+        } else if repeats < self.fast_after {
+            self.slow_interval
+        } else {
+            self.fast_interval
+        }
+    }
+}
+
 /// Global controller configuration resource.
 #[derive(Debug, Clone, Resource, Serialize, Deserialize, Reflect)]
 #[reflect(Resource)]
@@ -172,6 +307,11 @@ pub struct ControllerConfig {
     /// Maximum configurable deadzone.
     pub max_deadzone: f32,
 
+    /// Stick magnitude treated as full deflection (0.5 - 1.0).
+    /// Worn sticks that no longer reach the edge still produce full output.
+    #[serde(default = "default_outer_threshold")]
+    pub outer_threshold: f32,
+
     /// Left stick sensitivity multiplier (0.2 - 3.0).
     pub left_stick_sensitivity: f32,
 
@@ -193,6 +333,10 @@ pub struct ControllerConfig {
     /// Current detected/forced controller layout.
     pub current_layout: ControllerLayout,
 
+    /// Confirm/cancel button convention.
+    #[serde(default)]
+    pub confirm_style: ConfirmStyle,
+
     /// Enable vibration/haptic feedback.
     pub vibration_enabled: bool,
 
@@ -222,6 +366,47 @@ pub struct ControllerConfig {
 
     /// Time in seconds between subsequent repeats.
     pub repeat_rate: f32,
+
+    /// Accelerating repeat timing used while navigating menus.
+    #[serde(default)]
+    pub menu_repeat: RepeatStages,
+}
+
+const fn default_outer_threshold() -> f32 {
+    1.0
+}
+
+/// Replace a non-finite value with `default` and clamp it into `min..=max`.
+fn repair_range(
+    value: &mut f32,
+    default: f32,
+    min: f32,
+    max: f32,
+    field: &'static str,
+    repaired: &mut Vec<&'static str>,
+) {
+    let fixed = if value.is_finite() {
+        value.clamp(min, max)
+    } else {
+        default
+    };
+    if fixed.to_bits() != value.to_bits() {
+        *value = fixed;
+        repaired.push(field);
+    }
+}
+
+/// Replace a non-finite, zero, or negative duration with `default`.
+fn repair_duration(
+    value: &mut f32,
+    default: f32,
+    field: &'static str,
+    repaired: &mut Vec<&'static str>,
+) {
+    if !value.is_finite() || *value <= 0.0 {
+        *value = default;
+        repaired.push(field);
+    }
 }
 
 impl Default for ControllerConfig {
@@ -230,6 +415,7 @@ impl Default for ControllerConfig {
             deadzone: 0.15,
             min_deadzone: 0.05,
             max_deadzone: 0.5,
+            outer_threshold: default_outer_threshold(),
             left_stick_sensitivity: 1.0,
             right_stick_sensitivity: 1.0,
             min_sensitivity: 0.2,
@@ -237,6 +423,7 @@ impl Default for ControllerConfig {
             auto_detect_layout: true,
             forced_layout: None,
             current_layout: ControllerLayout::default(),
+            confirm_style: ConfirmStyle::default(),
             vibration_enabled: true,
             vibration_intensity: 1.0,
             invert_left_x: false,
@@ -247,17 +434,156 @@ impl Default for ControllerConfig {
             hold_threshold: 0.5,
             repeat_delay: 0.5,
             repeat_rate: 0.1,
+            menu_repeat: RepeatStages::default(),
         }
     }
 }
 
 impl ControllerConfig {
+    /// Repair out-of-range values, e.g. from a hand-edited or corrupted file.
+    ///
+    /// Non-finite values are reset to their defaults, out-of-range values are
+    /// clamped, and zero or negative timings are reset to their defaults.
+    /// Returns the names of the repaired fields and logs them as a warning.
+    pub fn validate(&mut self) -> Vec<&'static str> {
+        let defaults = Self::default();
+        let mut repaired = Vec::new();
+
+        repair_range(
+            &mut self.min_deadzone,
+            defaults.min_deadzone,
+            0.0,
+            1.0,
+            "min_deadzone",
+            &mut repaired,
+        );
+        repair_range(
+            &mut self.max_deadzone,
+            defaults.max_deadzone,
+            self.min_deadzone,
+            1.0,
+            "max_deadzone",
+            &mut repaired,
+        );
+        repair_range(
+            &mut self.deadzone,
+            defaults
+                .deadzone
+                .clamp(self.min_deadzone, self.max_deadzone),
+            self.min_deadzone,
+            self.max_deadzone,
+            "deadzone",
+            &mut repaired,
+        );
+        repair_range(
+            &mut self.outer_threshold,
+            defaults.outer_threshold,
+            0.5,
+            1.0,
+            "outer_threshold",
+            &mut repaired,
+        );
+
+        repair_range(
+            &mut self.min_sensitivity,
+            defaults.min_sensitivity,
+            0.01,
+            defaults.max_sensitivity,
+            "min_sensitivity",
+            &mut repaired,
+        );
+        repair_range(
+            &mut self.max_sensitivity,
+            defaults.max_sensitivity,
+            self.min_sensitivity,
+            10.0,
+            "max_sensitivity",
+            &mut repaired,
+        );
+        let (min_sensitivity, max_sensitivity) = (self.min_sensitivity, self.max_sensitivity);
+        for (value, field) in [
+            (&mut self.left_stick_sensitivity, "left_stick_sensitivity"),
+            (&mut self.right_stick_sensitivity, "right_stick_sensitivity"),
+        ] {
+            repair_range(
+                value,
+                1.0_f32.clamp(min_sensitivity, max_sensitivity),
+                min_sensitivity,
+                max_sensitivity,
+                field,
+                &mut repaired,
+            );
+        }
+
+        repair_range(
+            &mut self.vibration_intensity,
+            defaults.vibration_intensity,
+            0.0,
+            1.0,
+            "vibration_intensity",
+            &mut repaired,
+        );
+
+        repair_duration(
+            &mut self.hold_threshold,
+            defaults.hold_threshold,
+            "hold_threshold",
+            &mut repaired,
+        );
+        repair_duration(
+            &mut self.repeat_delay,
+            defaults.repeat_delay,
+            "repeat_delay",
+            &mut repaired,
+        );
+        repair_duration(
+            &mut self.repeat_rate,
+            defaults.repeat_rate,
+            "repeat_rate",
+            &mut repaired,
+        );
+        repair_duration(
+            &mut self.menu_repeat.initial_delay,
+            defaults.menu_repeat.initial_delay,
+            "menu_repeat.initial_delay",
+            &mut repaired,
+        );
+        repair_duration(
+            &mut self.menu_repeat.slow_interval,
+            defaults.menu_repeat.slow_interval,
+            "menu_repeat.slow_interval",
+            &mut repaired,
+        );
+        repair_duration(
+            &mut self.menu_repeat.fast_interval,
+            defaults.menu_repeat.fast_interval,
+            "menu_repeat.fast_interval",
+            &mut repaired,
+        );
+
+        if !repaired.is_empty() {
+            log::warn!(
+                "repaired invalid controller config values: {}",
+                repaired.join(", ")
+            );
+        }
+        repaired
+    }
+
     /// Get the effective deadzone value clamped to valid range.
     #[must_use]
     pub fn effective_deadzone(&self) -> f32 {
         self.deadzone.clamp(self.min_deadzone, self.max_deadzone)
     }
 
+    /// Get the effective outer threshold, kept above the deadzone.
+    #[must_use]
+    pub fn effective_outer_threshold(&self) -> f32 {
+        self.outer_threshold
+            .clamp(0.5, 1.0)
+            .max(self.effective_deadzone() + 0.05)
+    }
+
     /// Get the effective left stick sensitivity value clamped to valid range.
     #[must_use]
     pub fn effective_left_sensitivity(&self) -> f32 {
@@ -272,12 +598,46 @@ impl ControllerConfig {
             .clamp(self.min_sensitivity, self.max_sensitivity)
     }
 
+    /// Get the effective vibration strength, 0.0 when vibration is disabled.
+    #[must_use]
+    pub fn effective_vibration(&self) -> f32 {
+        if self.vibration_enabled {
+            self.vibration_intensity.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Get the repeat timing for gameplay actions.
+    #[must_use]
+    pub const fn gameplay_repeat(&self) -> RepeatStages {
+        RepeatStages::constant(self.repeat_delay, self.repeat_rate)
+    }
+
     /// Get the current layout (forced or detected).
     #[must_use]
     pub fn layout(&self) -> ControllerLayout {
         self.forced_layout.unwrap_or(self.current_layout)
     }
 
+    /// Get the effective confirm/cancel convention for the current layout.
+    #[must_use]
+    pub fn confirm_style(&self) -> ConfirmStyle {
+        self.confirm_style.resolve(self.layout())
+    }
+
+    /// Get the button that confirms in menus.
+    #[must_use]
+    pub fn confirm_button(&self) -> GamepadButton {
+        self.confirm_style().confirm_button()
+    }
+
+    /// Get the button that cancels in menus.
+    #[must_use]
+    pub fn cancel_button(&self) -> GamepadButton {
+        self.confirm_style().cancel_button()
+    }
+
     /// Apply deadzone and sensitivity to an axis value for the left stick.
     #[must_use]
     pub fn apply_deadzone_left(&self, value: f32) -> f32 {
@@ -287,7 +647,8 @@ impl ControllerConfig {
         } else {
             // Remap the value to 0.0-1.0 range after deadzone
             let sign = value.signum();
-            let normalized = (value.abs() - deadzone) / (1.0 - deadzone);
+            let normalized =
+                ((value.abs() - deadzone) / (self.effective_outer_threshold() - deadzone)).min(1.0);
             sign * normalized * self.effective_left_sensitivity()
         }
     }
@@ -300,7 +661,8 @@ impl ControllerConfig {
             0.0
         } else {
             let sign = value.signum();
-            let normalized = (value.abs() - deadzone) / (1.0 - deadzone);
+            let normalized =
+                ((value.abs() - deadzone) / (self.effective_outer_threshold() - deadzone)).min(1.0);
             sign * normalized * self.effective_right_sensitivity()
         }
     }
@@ -309,6 +671,7 @@ impl ControllerConfig {
     #[must_use]
     pub fn apply_deadzone_2d(&self, x: f32, y: f32, is_left_stick: bool) -> Vec2 {
         let deadzone = self.effective_deadzone();
+        let outer_threshold = self.effective_outer_threshold();
         let magnitude = (x * x + y * y).sqrt();
 
         if magnitude < deadzone {
@@ -321,7 +684,7 @@ impl ControllerConfig {
                 self.effective_right_sensitivity()
             };
             let normalized_magnitude =
-                ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0) * sensitivity;
+                ((magnitude - deadzone) / (outer_threshold - deadzone)).min(1.0) * sensitivity;
             let direction = Vec2::new(x, y) / magnitude;
             direction * normalized_magnitude
         }
@@ -353,9 +716,10 @@ impl ControllerConfig {
     /// # Errors
     ///
     /// Returns an error if serialization fails or the file cannot be written.
-    pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
-        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
-        std::fs::write(path, json)
+    pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(crate::Error::Config)?;
+        std::fs::write(path, json).map_err(|e| crate::Error::io(path, e))
     }
 
     /// Load configuration from a JSON file.
@@ -363,10 +727,10 @@ impl ControllerConfig {
     /// # Errors
     ///
     /// Returns an error if the file cannot be read or contains invalid JSON.
-    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
-        let json = std::fs::read_to_string(path)?;
-        serde_json::from_str(&json)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        serde_json::from_str(&json).map_err(crate::Error::Config)
     }
 
     /// Get the default config file path for the current platform.
@@ -380,10 +744,27 @@ impl ControllerConfig {
     }
 
     /// Load configuration from the default path, or return default if not found.
+    ///
+    /// A config file that exists but fails to load is logged as a warning.
+    /// Loaded values are repaired with [`Self::validate`].
     #[must_use]
     pub fn load_or_default() -> Self {
         let path = Self::default_config_path();
-        Self::load_from_file(&path).unwrap_or_default()
+        match Self::load_from_file(&path) {
+            Ok(mut config) => {
+                config.validate();
+                config
+            }
+            Err(crate::Error::Io { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                Self::default()
+            }
+            Err(e) => {
+                log::warn!("{e}; using default controller config");
+                Self::default()
+            }
+        }
     }
 
     /// Save configuration to the default path, creating directories if needed.
@@ -391,10 +772,10 @@ impl ControllerConfig {
     /// # Errors
     ///
     /// Returns an error if directories cannot be created or the file cannot be written.
-    pub fn save_default(&self) -> std::io::Result<()> {
+    pub fn save_default(&self) -> crate::Result<()> {
         let path = Self::default_config_path();
         if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
+            std::fs::create_dir_all(parent).map_err(|e| crate::Error::io(parent, e))?;
         }
         self.save_to_file(&path)
     }
@@ -413,6 +794,7 @@ pub enum ConfigField {
     Deadzone,
     Sensitivity,
     Layout,
+    ConfirmStyle,
     Vibration,
     InvertAxis,
     SwapSticks,
@@ -423,6 +805,8 @@ pub enum ConfigField {
 pub(crate) fn register_config_types(app: &mut App) {
     app.register_type::<ControllerConfig>()
         .register_type::<ControllerLayout>()
+        .register_type::<ConfirmStyle>()
+        .register_type::<RepeatStages>()
         .init_resource::<ControllerConfig>()
         .add_message::<ControllerConfigChanged>();
 }
@@ -735,6 +1119,26 @@ mod tests {
         assert!(result.y > 0.0);
     }
 
+    #[test]
+    fn test_controller_config_outer_threshold() {
+        let config = ControllerConfig {
+            outer_threshold: 0.9,
+            ..default()
+        };
+
+        // Reaching the outer threshold gives full output
+        assert_relative_eq!(config.apply_deadzone_left(0.9), 1.0);
+        assert_relative_eq!(config.apply_deadzone_2d(0.95, 0.0, true).x, 1.0);
+
+        // Never set below the deadzone
+        let config = ControllerConfig {
+            deadzone: 0.5,
+            outer_threshold: 0.5,
+            ..default()
+        };
+        assert_relative_eq!(config.effective_outer_threshold(), 0.55);
+    }
+
     #[test]
     fn test_controller_config_apply_inversion_left() {
         let mut config = ControllerConfig::default();
@@ -778,6 +1182,63 @@ mod tests {
         assert_relative_eq!(result.y, -0.5);
     }
 
+    #[test]
+    fn test_confirm_style_resolve() {
+        assert_eq!(
+            ConfirmStyle::Auto.resolve(ControllerLayout::Xbox),
+            ConfirmStyle::South
+        );
+        assert_eq!(
+            ConfirmStyle::Auto.resolve(ControllerLayout::Nintendo),
+            ConfirmStyle::East
+        );
+        assert_eq!(
+            ConfirmStyle::South.resolve(ControllerLayout::Nintendo),
+            ConfirmStyle::South
+        );
+    }
+
+    #[test]
+    fn test_confirm_style_buttons() {
+        assert_eq!(ConfirmStyle::South.confirm_button(), GamepadButton::South);
+        assert_eq!(ConfirmStyle::South.cancel_button(), GamepadButton::East);
+        assert_eq!(ConfirmStyle::East.confirm_button(), GamepadButton::East);
+        assert_eq!(ConfirmStyle::East.cancel_button(), GamepadButton::South);
+    }
+
+    #[test]
+    fn test_confirm_style_from_region() {
+        assert_eq!(ConfirmStyle::from_region("JP"), ConfirmStyle::East);
+        assert_eq!(ConfirmStyle::from_region("ja-JP"), ConfirmStyle::East);
+        assert_eq!(ConfirmStyle::from_region("en-US"), ConfirmStyle::Auto);
+    }
+
+    #[test]
+    fn test_controller_config_confirm_button_follows_layout() {
+        let mut config = ControllerConfig::default();
+        assert_eq!(config.confirm_button(), GamepadButton::South);
+
+        config.forced_layout = Some(ControllerLayout::Nintendo);
+        assert_eq!(config.confirm_button(), GamepadButton::East);
+        assert_eq!(config.cancel_button(), GamepadButton::South);
+
+        config.confirm_style = ConfirmStyle::South;
+        assert_eq!(config.confirm_button(), GamepadButton::South);
+    }
+
+    #[test]
+    fn test_repeat_stages_interval() {
+        let stages = RepeatStages::default();
+        assert_relative_eq!(stages.interval(0), stages.initial_delay);
+        assert_relative_eq!(stages.interval(1), stages.slow_interval);
+        assert_relative_eq!(stages.interval(stages.fast_after), stages.fast_interval);
+
+        let gameplay = ControllerConfig::default().gameplay_repeat();
+        assert_relative_eq!(gameplay.interval(0), 0.5);
+        assert_relative_eq!(gameplay.interval(1), 0.1);
+        assert_relative_eq!(gameplay.interval(10), 0.1);
+    }
+
     #[test]
     fn test_controller_config_default_path() {
         let path = ControllerConfig::default_config_path();
@@ -798,12 +1259,54 @@ mod tests {
             ConfigField::Deadzone,
             ConfigField::Sensitivity,
             ConfigField::Layout,
+            ConfigField::ConfirmStyle,
             ConfigField::Vibration,
             ConfigField::InvertAxis,
             ConfigField::SwapSticks,
             ConfigField::Timing,
         ];
-        assert_eq!(fields.len(), 7);
+        assert_eq!(fields.len(), 8);
+    }
+
+    #[test]
+    fn test_validate_repairs_invalid_values() {
+        let mut config = ControllerConfig::default();
+        assert!(config.validate().is_empty());
+
+        config.deadzone = -0.2;
+        config.right_stick_sensitivity = f32::NAN;
+        config.repeat_rate = 0.0;
+        config.menu_repeat.fast_interval = f32::INFINITY;
+        config.vibration_intensity = 4.0;
+
+        let repaired = config.validate();
+        assert_eq!(
+            repaired,
+            vec![
+                "deadzone",
+                "right_stick_sensitivity",
+                "vibration_intensity",
+                "repeat_rate",
+                "menu_repeat.fast_interval",
+            ]
+        );
+        assert_relative_eq!(config.deadzone, config.min_deadzone);
+        assert_relative_eq!(config.right_stick_sensitivity, 1.0);
+        assert_relative_eq!(config.vibration_intensity, 1.0);
+        assert_relative_eq!(config.repeat_rate, 0.1);
+        assert_relative_eq!(config.menu_repeat.fast_interval, 0.05);
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_repairs_inverted_limits() {
+        let mut config = ControllerConfig::default();
+        config.min_deadzone = 0.6;
+        config.max_deadzone = 0.3;
+
+        assert_eq!(config.validate(), vec!["max_deadzone", "deadzone"]);
+        assert_relative_eq!(config.max_deadzone, 0.6);
+        assert_relative_eq!(config.deadzone, 0.6);
     }
 
     // ========== ControllerConfigChanged Event Tests ==========
@@ -0,0 +1,399 @@
+//! Deadzone recommendations from play-session statistics.
+//!
+//! [`DeadzoneAdvisor`] watches each stick while the game runs. It records how
+//! far the stick sits from center while untouched (resting noise), the peak of
+//! each deliberate movement, and how far full deflections reach. Once enough
+//! data is collected it fires [`DeadzoneRecommendation`] with a deadzone just
+//! above the noise and an outer threshold just below the stick's real reach.
+//!
+//! Recommendations are never applied automatically. Show them to the player
+//! and call [`DeadzoneRecommendation::apply`] once they confirm.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::config::ControllerConfig;
+//! use bevy_archie::deadzone_advisor::DeadzoneRecommendation;
+//!
+//! fn offer_deadzone(
+//!     mut recommendations: MessageReader<DeadzoneRecommendation>,
+//!     mut config: ResMut<ControllerConfig>,
+//! ) {
+//!     for recommendation in recommendations.read() {
+//!         // Ask the player first; accepted here for brevity
+//!         recommendation.apply(&mut config);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::config::ControllerConfig;
+use crate::stick::Stick;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Number of 0.01-wide magnitude bins.
+const BINS: usize = 100;
+
+/// Histogram of stick magnitudes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MagnitudeHistogram {
+    bins: [u32; BINS],
+    count: u32,
+}
+
+impl Default for MagnitudeHistogram {
+    fn default() -> Self {
+        Self {
+            bins: [0; BINS],
+            count: 0,
+        }
+    }
+}
+
+impl MagnitudeHistogram {
+    fn add(&mut self, magnitude: f32) {
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss,
+            reason = "magnitude is clamped to 0.0 - 1.0 before binning"
+        )]
+        let bin = ((magnitude.clamp(0.0, 1.0) * BINS as f32) as usize).min(BINS - 1);
+        self.bins[bin] += 1;
+        self.count += 1;
+    }
+
+    /// Upper edge of the bin containing the given fraction of samples.
+    fn percentile(&self, fraction: f32) -> Option<f32> {
+        if self.count == 0 {
+            return None;
+        }
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss,
+            reason = "sample counts stay far below f32 precision limits"
+        )]
+        let target = ((self.count as f32 * fraction.clamp(0.0, 1.0)).ceil() as u32).max(1);
+        let mut seen = 0;
+        self.bins.iter().enumerate().find_map(|(index, count)| {
+            seen += count;
+            #[expect(clippy::cast_precision_loss, reason = "bin indices are below 100")]
+            let edge = (index + 1) as f32 / BINS as f32;
+            (seen >= target).then_some(edge)
+        })
+    }
+}
+
+/// Usage statistics for one stick.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StickUsage {
+    rest: MagnitudeHistogram,
+    movements: MagnitudeHistogram,
+    full_deflections: MagnitudeHistogram,
+    previous: Vec2,
+    still_time: f32,
+    movement_peak: Option<f32>,
+}
+
+impl StickUsage {
+    /// Record one frame of stick position.
+    pub fn record(&mut self, position: Vec2, delta: f32, advisor: &DeadzoneAdvisor) {
+        let magnitude = position.length();
+        if position.distance(self.previous) > advisor.jitter {
+            self.still_time = 0.0;
+        } else {
+            self.still_time += delta;
+        }
+        self.previous = position;
+
+        if self.still_time >= advisor.rest_time && magnitude < advisor.rest_ceiling {
+            self.rest.add(magnitude);
+            self.movement_peak = None;
+            return;
+        }
+
+        let noise = self.rest.percentile(0.99).unwrap_or(advisor.rest_ceiling);
+        if magnitude > noise + advisor.margin {
+            let peak = self.movement_peak.get_or_insert(magnitude);
+            *peak = peak.max(magnitude);
+        } else if let Some(peak) = self.movement_peak.take() {
+            self.movements.add(peak);
+            if peak >= advisor.full_deflection {
+                self.full_deflections.add(peak);
+            }
+        }
+    }
+
+    /// Number of resting samples recorded.
+    #[must_use]
+    pub fn rest_samples(&self) -> u32 {
+        self.rest.count
+    }
+
+    /// Number of deliberate movements recorded.
+    #[must_use]
+    pub fn movements(&self) -> u32 {
+        self.movements.count
+    }
+
+    /// Recommended (deadzone, outer threshold), once enough data is recorded.
+    #[must_use]
+    pub fn recommend(&self, advisor: &DeadzoneAdvisor) -> Option<(f32, f32)> {
+        if self.rest.count < advisor.min_rest_samples
+            || self.movements.count < advisor.min_movements
+        {
+            return None;
+        }
+
+        let noise = self.rest.percentile(0.99)?;
+        let smallest_movement = self.movements.percentile(0.05)?;
+        // Stay above the noise, but below the player's finest movements
+        let deadzone = (noise + advisor.margin)
+            .min(smallest_movement * 0.9)
+            .max(noise);
+
+        let outer_threshold = if self.full_deflections.count >= advisor.min_movements / 4 {
+            self.full_deflections.percentile(0.5).map_or(1.0, |reach| {
+                (reach - 0.02).clamp(advisor.full_deflection, 1.0)
+            })
+        } else {
+            1.0
+        };
+        Some((deadzone, outer_threshold))
+    }
+}
+
+/// Resource collecting stick statistics and scheduling recommendations.
+#[derive(Debug, Clone, Resource)]
+pub struct DeadzoneAdvisor {
+    /// Movement per frame below which the stick counts as still.
+    pub jitter: f32,
+    /// Seconds the stick must be still before samples count as resting.
+    pub rest_time: f32,
+    /// Resting samples above this magnitude are ignored as held deflections.
+    pub rest_ceiling: f32,
+    /// Gap kept between resting noise and the deadzone.
+    pub margin: f32,
+    /// Peak magnitude counted as a full deflection.
+    pub full_deflection: f32,
+    /// Resting samples needed before recommending.
+    pub min_rest_samples: u32,
+    /// Deliberate movements needed before recommending.
+    pub min_movements: u32,
+    /// Seconds between recommendation checks.
+    pub report_interval: f32,
+    usage: HashMap<(Entity, Stick), StickUsage>,
+    reported: HashMap<(Entity, Stick), (f32, f32)>,
+    since_report: f32,
+}
+
+impl Default for DeadzoneAdvisor {
+    fn default() -> Self {
+        Self {
+            jitter: 0.01,
+            rest_time: 0.5,
+            rest_ceiling: 0.3,
+            margin: 0.03,
+            full_deflection: 0.7,
+            min_rest_samples: 300,
+            min_movements: 20,
+            report_interval: 60.0,
+            usage: HashMap::new(),
+            reported: HashMap::new(),
+            since_report: 0.0,
+        }
+    }
+}
+
+impl DeadzoneAdvisor {
+    /// Statistics for a gamepad's stick.
+    #[must_use]
+    pub fn usage(&self, gamepad: Entity, stick: Stick) -> Option<&StickUsage> {
+        self.usage.get(&(gamepad, stick))
+    }
+
+    /// Forget all statistics, e.g. after the player changes controllers.
+    pub fn reset(&mut self) {
+        self.usage.clear();
+        self.reported.clear();
+        self.since_report = 0.0;
+    }
+
+    /// Record a frame of stick position.
+    pub fn record(&mut self, gamepad: Entity, stick: Stick, position: Vec2, delta: f32) {
+        let mut usage = self.usage.remove(&(gamepad, stick)).unwrap_or_default();
+        usage.record(position, delta, self);
+        self.usage.insert((gamepad, stick), usage);
+    }
+
+    /// Recommendations that changed since they were last reported.
+    pub fn take_recommendations(&mut self) -> Vec<DeadzoneRecommendation> {
+        let mut recommendations = Vec::new();
+        for (&(gamepad, stick), usage) in &self.usage {
+            let Some((deadzone, outer_threshold)) = usage.recommend(self) else {
+                continue;
+            };
+            let unchanged = self.reported.get(&(gamepad, stick)).is_some_and(|last| {
+                (last.0 - deadzone).abs() < 0.01 && (last.1 - outer_threshold).abs() < 0.01
+            });
+            if !unchanged {
+                recommendations.push(DeadzoneRecommendation {
+                    gamepad,
+                    stick,
+                    deadzone,
+                    outer_threshold,
+                });
+            }
+        }
+        for recommendation in &recommendations {
+            self.reported.insert(
+                (recommendation.gamepad, recommendation.stick),
+                (recommendation.deadzone, recommendation.outer_threshold),
+            );
+        }
+        recommendations
+    }
+}
+
+/// Event carrying recommended stick settings.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct DeadzoneRecommendation {
+    /// The gamepad measured.
+    pub gamepad: Entity,
+    /// The stick measured.
+    pub stick: Stick,
+    /// Recommended deadzone.
+    pub deadzone: f32,
+    /// Recommended outer threshold.
+    pub outer_threshold: f32,
+}
+
+impl DeadzoneRecommendation {
+    /// Apply the recommendation to a configuration.
+    ///
+    /// [`ControllerConfig`] shares one deadzone and outer threshold between
+    /// both sticks, so apply the recommendation with the larger deadzone
+    /// when the sticks differ.
+    pub fn apply(&self, config: &mut ControllerConfig) {
+        config.deadzone = self
+            .deadzone
+            .clamp(config.min_deadzone, config.max_deadzone);
+        config.outer_threshold = self.outer_threshold;
+    }
+}
+
+/// System to record stick usage and fire recommendations.
+pub fn analyze_stick_usage(
+    time: Res<Time<Real>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut advisor: ResMut<DeadzoneAdvisor>,
+    mut recommendations: MessageWriter<DeadzoneRecommendation>,
+) {
+    let delta = time.delta_secs();
+    advisor
+        .usage
+        .retain(|(gamepad, _), _| gamepads.contains(*gamepad));
+
+    for (entity, gamepad) in &gamepads {
+        for stick in [Stick::Left, Stick::Right] {
+            advisor.record(entity, stick, stick.read(gamepad), delta);
+        }
+    }
+
+    advisor.since_report += delta;
+    if advisor.since_report < advisor.report_interval {
+        return;
+    }
+    advisor.since_report = 0.0;
+    recommendations.write_batch(advisor.take_recommendations());
+}
+
+/// Plugin for registering deadzone advisor types.
+pub(crate) fn register_deadzone_advisor_types(app: &mut App) {
+    app.init_resource::<DeadzoneAdvisor>()
+        .add_message::<DeadzoneRecommendation>();
+}
+
+/// Add deadzone advisor systems to the app.
+pub(crate) fn add_deadzone_advisor_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        analyze_stick_usage.run_if(subsystem_enabled(Subsystem::DeadzoneAdvisor)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advisor() -> DeadzoneAdvisor {
+        DeadzoneAdvisor {
+            min_rest_samples: 10,
+            min_movements: 4,
+            ..default()
+        }
+    }
+
+    /// Rest with slight drift, then flick out to `peak` and back.
+    fn session(usage: &mut StickUsage, advisor: &DeadzoneAdvisor, peaks: &[f32]) {
+        for &peak in peaks {
+            for _ in 0..40 {
+                usage.record(Vec2::new(0.04, 0.0), 0.016, advisor);
+            }
+            for step in 1..=5 {
+                #[expect(clippy::cast_precision_loss, reason = "small test step")]
+                let x = peak * step as f32 / 5.0;
+                usage.record(Vec2::new(x, 0.0), 0.016, advisor);
+            }
+        }
+        usage.record(Vec2::new(0.04, 0.0), 0.016, advisor);
+    }
+
+    #[test]
+    fn test_histogram_percentile() {
+        let mut histogram = MagnitudeHistogram::default();
+        for magnitude in [0.005, 0.015, 0.025, 0.035] {
+            histogram.add(magnitude);
+        }
+        assert_eq!(histogram.percentile(0.5), Some(0.02));
+        assert_eq!(histogram.percentile(1.0), Some(0.04));
+        assert_eq!(MagnitudeHistogram::default().percentile(0.5), None);
+    }
+
+    #[test]
+    fn test_recommends_deadzone_above_noise_and_outer_below_reach() {
+        let advisor = advisor();
+        let mut usage = StickUsage::default();
+        assert!(usage.recommend(&advisor).is_none());
+
+        session(&mut usage, &advisor, &[0.3, 0.5, 0.92, 0.92, 0.92, 0.4]);
+        let (deadzone, outer) = usage.recommend(&advisor).unwrap();
+        assert!(deadzone > 0.04 && deadzone < 0.1, "deadzone {deadzone}");
+        assert!((outer - 0.91).abs() < 0.011, "outer {outer}");
+    }
+
+    #[test]
+    fn test_recommendations_are_reported_once() {
+        let mut advisor = advisor();
+        let gamepad = Entity::from_bits(1);
+        let mut usage = StickUsage::default();
+        session(&mut usage, &advisor, &[0.3, 0.5, 0.9, 0.9, 0.9]);
+        advisor.usage.insert((gamepad, Stick::Left), usage);
+
+        let first = advisor.take_recommendations();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].stick, Stick::Left);
+        assert!(advisor.take_recommendations().is_empty());
+
+        let mut config = ControllerConfig::default();
+        first[0].apply(&mut config);
+        assert_eq!(config.deadzone, first[0].deadzone.max(config.min_deadzone));
+    }
+}
@@ -1,13 +1,23 @@
 //! Developer debugging tools for input visualization and testing.
 //!
 //! This module provides debugging utilities for visualizing controller
-//! input, recording/playback, and automated testing.
+//! input, recording/playback, and automated testing. [`InputStepper`]
+//! freezes the input pipeline and steps it one frame at a time.
 
 use bevy::prelude::*;
 use log::{debug, trace};
-use std::collections::VecDeque;
-
-use crate::actions::GameAction;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::actions::{
+    ActionMap, ActionState, GameAction, GamepadActionState, InputCapture, apply_action_input,
+    sample_action_input,
+};
+use crate::detection::InputDeviceState;
+use crate::mash::MashMeter;
+use crate::power::normal_power;
+use crate::simulated_input::SimulatedInput;
+use crate::subsystems::{EnabledSubsystems, Subsystem, subsystem_enabled};
 
 /// Debug overlay state.
 #[derive(Debug, Clone, Default, Resource)]
@@ -26,6 +36,8 @@ pub struct InputDebugger {
     pub show_buttons: bool,
     /// Show gyro data.
     pub show_gyro: bool,
+    /// Show mash meter rates.
+    pub show_mash: bool,
     /// Input history size.
     pub history_size: usize,
 }
@@ -40,6 +52,7 @@ impl InputDebugger {
             show_sticks: true,
             show_buttons: true,
             show_gyro: false,
+            show_mash: true,
             history_size: 20,
         }
     }
@@ -51,11 +64,69 @@ impl InputDebugger {
         self.show_sticks = true;
         self.show_buttons = true;
         self.show_gyro = true;
+        self.show_mash = true;
+    }
+}
+
+/// Streamer mode: hides sensitive input from recordings and the overlay.
+///
+/// While a sensitive window is open (the virtual keyboard is up, or the game
+/// called [`StreamerMode::begin_sensitive`]), the configured sensitive
+/// actions are left out of [`InputRecorder`] recordings and the debug overlay
+/// stops logging raw buttons and sticks, so spectators can't reconstruct
+/// passwords or names from the inputs.
+#[derive(Debug, Clone, Resource)]
+pub struct StreamerMode {
+    /// Whether streamer mode is enabled.
+    pub enabled: bool,
+    /// Open a sensitive window while the virtual keyboard is shown.
+    pub redact_virtual_keyboard: bool,
+    /// Actions redacted inside a sensitive window.
+    pub sensitive_actions: HashSet<GameAction>,
+    /// Window opened by the game.
+    manual_window: bool,
+    /// Window opened by the virtual keyboard.
+    keyboard_window: bool,
+}
+
+impl Default for StreamerMode {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redact_virtual_keyboard: true,
+            sensitive_actions: GameAction::all().iter().copied().collect(),
+            manual_window: false,
+            keyboard_window: false,
+        }
+    }
+}
+
+impl StreamerMode {
+    /// Open a sensitive window, e.g. while a custom password field has focus.
+    pub fn begin_sensitive(&mut self) {
+        self.manual_window = true;
+    }
+
+    /// Close the window opened by [`Self::begin_sensitive`].
+    pub fn end_sensitive(&mut self) {
+        self.manual_window = false;
+    }
+
+    /// Whether streamer mode is on and a sensitive window is open.
+    #[must_use]
+    pub const fn is_redacting(&self) -> bool {
+        self.enabled && (self.manual_window || self.keyboard_window)
+    }
+
+    /// Whether an action should be hidden right now.
+    #[must_use]
+    pub fn redacts(&self, action: GameAction) -> bool {
+        self.is_redacting() && self.sensitive_actions.contains(&action)
     }
 }
 
 /// Input event for recording.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecordedInput {
     /// Action performed.
     pub action: GameAction,
@@ -67,6 +138,36 @@ pub struct RecordedInput {
     pub analog_value: Option<f32>,
 }
 
+/// Limits that keep long recordings small.
+///
+/// Playback treats each input as the action's new state, so dropping inputs
+/// that repeat the previous state loses nothing. The analog epsilon, idle
+/// trimming, and memory budget trade fidelity for size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecordingLimits {
+    /// Drop inputs that repeat the action's previous state.
+    pub compress: bool,
+    /// Analog changes no larger than this count as repeats.
+    pub analog_epsilon: f32,
+    /// Longest gap between inputs, in seconds; longer gaps are shortened to
+    /// this (`None` = keep).
+    pub max_idle: Option<f64>,
+    /// Memory budget for recorded inputs, in bytes; past it the oldest
+    /// quarter is evicted (`None` = unbounded).
+    pub max_bytes: Option<usize>,
+}
+
+impl Default for RecordingLimits {
+    fn default() -> Self {
+        Self {
+            compress: true,
+            analog_epsilon: 0.0,
+            max_idle: None,
+            max_bytes: None,
+        }
+    }
+}
+
 /// Input recording system.
 #[derive(Debug, Clone, Default, Resource)]
 pub struct InputRecorder {
@@ -76,6 +177,14 @@ pub struct InputRecorder {
     pub recorded: Vec<RecordedInput>,
     /// Recording start time.
     pub start_time: f64,
+    /// Compression, trimming, and memory limits.
+    pub limits: RecordingLimits,
+    /// Last recorded state of each action, for compression.
+    last_state: HashMap<GameAction, (bool, Option<f32>)>,
+    /// Seconds removed from idle gaps so far.
+    trimmed: f64,
+    /// Evicted inputs since recording started.
+    evicted: usize,
 }
 
 impl InputRecorder {
@@ -84,6 +193,9 @@ impl InputRecorder {
         self.recording = true;
         self.recorded.clear();
         self.start_time = time;
+        self.last_state.clear();
+        self.trimmed = 0.0;
+        self.evicted = 0;
     }
 
     /// Stop recording.
@@ -92,10 +204,20 @@ impl InputRecorder {
     }
 
     /// Record an input.
-    pub fn record(&mut self, input: RecordedInput) {
-        if self.recording {
-            self.recorded.push(input);
+    ///
+    /// Returns whether the input was kept.
+    pub fn record(&mut self, input: RecordedInput) -> bool {
+        self.recording && self.push(input)
+    }
+
+    /// Record an input unless streamer mode redacts it.
+    ///
+    /// Returns whether the input was kept.
+    pub fn record_sanitized(&mut self, input: RecordedInput, streamer: &StreamerMode) -> bool {
+        if !self.recording || streamer.redacts(input.action) {
+            return false;
         }
+        self.push(input)
     }
 
     /// Get recording duration.
@@ -103,6 +225,61 @@ impl InputRecorder {
     pub fn duration(&self, current_time: f64) -> f64 {
         current_time - self.start_time
     }
+
+    /// Approximate memory used by the recorded inputs, in bytes.
+    #[must_use]
+    pub fn memory_usage(&self) -> usize {
+        self.recorded.len() * std::mem::size_of::<RecordedInput>()
+    }
+
+    /// Seconds removed from idle gaps since recording started.
+    #[must_use]
+    pub const fn trimmed_secs(&self) -> f64 {
+        self.trimmed
+    }
+
+    /// Inputs evicted to stay within the memory budget.
+    #[must_use]
+    pub const fn evicted(&self) -> usize {
+        self.evicted
+    }
+
+    /// Apply the limits to an input and store it.
+    fn push(&mut self, mut input: RecordedInput) -> bool {
+        let state = (input.pressed, input.analog_value);
+        if self.limits.compress
+            && let Some(&(pressed, value)) = self.last_state.get(&input.action)
+            && pressed == input.pressed
+            && match (value, input.analog_value) {
+                (Some(a), Some(b)) => (a - b).abs() <= self.limits.analog_epsilon,
+                (a, b) => a == b,
+            }
+        {
+            return false;
+        }
+        self.last_state.insert(input.action, state);
+
+        input.timestamp -= self.trimmed;
+        if let Some(max_idle) = self.limits.max_idle
+            && let Some(last) = self.recorded.last()
+        {
+            let gap = input.timestamp - last.timestamp;
+            if gap > max_idle {
+                self.trimmed += gap - max_idle;
+                input.timestamp = last.timestamp + max_idle;
+            }
+        }
+        self.recorded.push(input);
+
+        if let Some(max_bytes) = self.limits.max_bytes
+            && self.memory_usage() > max_bytes
+        {
+            let excess = self.recorded.len().div_ceil(4);
+            self.recorded.drain(..excess);
+            self.evicted += excess;
+        }
+        true
+    }
 }
 
 /// Input playback system.
@@ -163,6 +340,148 @@ impl InputPlayback {
     }
 }
 
+/// One frame of raw action input, queued while the pipeline is frozen.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputFrame {
+    /// Value of each action that was pressed.
+    pub actions: HashMap<GameAction, f32>,
+}
+
+impl InputFrame {
+    /// An action's input as `(pressed, value)`.
+    #[must_use]
+    pub fn input(&self, action: GameAction) -> (bool, f32) {
+        self.actions
+            .get(&action)
+            .map_or((false, 0.0), |value| (true, *value))
+    }
+}
+
+/// Frame-stepping controls for the input pipeline.
+///
+/// While frozen, [`ActionState`] stops updating and every subsystem but
+/// debug stops running, so buffering, modifiers, and state machines hold
+/// still. Real input keeps being sampled each frame into a pending queue,
+/// and each step runs the pipeline for one frame on the oldest pending
+/// frame. Per-gamepad [`GamepadActionState`]s keep tracking live input.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_archie::debug::FrameStepCommand;
+///
+/// fn step_keys(keys: Res<ButtonInput<KeyCode>>, mut commands: MessageWriter<FrameStepCommand>) {
+///     if keys.just_pressed(KeyCode::F9) {
+///         commands.write(FrameStepCommand::Freeze);
+///     } else if keys.just_pressed(KeyCode::F10) {
+///         commands.write(FrameStepCommand::Step(1));
+///     } else if keys.just_pressed(KeyCode::F11) {
+///         commands.write(FrameStepCommand::Resume);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Resource)]
+pub struct InputStepper {
+    /// Whether the pipeline is frozen.
+    frozen: bool,
+    /// Steps requested but not yet taken.
+    steps: u32,
+    /// Whether this frame is a step.
+    stepping: bool,
+    /// Input queued while frozen, oldest first.
+    pending: VecDeque<InputFrame>,
+    /// Most frames kept in the pending queue; older ones are dropped.
+    pub max_pending: usize,
+}
+
+impl Default for InputStepper {
+    fn default() -> Self {
+        Self {
+            frozen: false,
+            steps: 0,
+            stepping: false,
+            pending: VecDeque::new(),
+            max_pending: 600,
+        }
+    }
+}
+
+impl InputStepper {
+    /// Freeze the pipeline.
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Unfreeze the pipeline, dropping pending input and steps.
+    pub fn resume(&mut self) {
+        self.frozen = false;
+        self.steps = 0;
+        self.stepping = false;
+        self.pending.clear();
+    }
+
+    /// Advance a number of frames. Does nothing unless frozen.
+    pub fn step(&mut self, frames: u32) {
+        if self.frozen {
+            self.steps = self.steps.saturating_add(frames);
+        }
+    }
+
+    /// Check if the pipeline is frozen.
+    #[must_use]
+    pub const fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Check if this frame is a step.
+    #[must_use]
+    pub const fn is_stepping(&self) -> bool {
+        self.stepping
+    }
+
+    /// Input queued while frozen, oldest first.
+    #[must_use]
+    pub const fn pending(&self) -> &VecDeque<InputFrame> {
+        &self.pending
+    }
+
+    /// Queue a frame of input, dropping the oldest past `max_pending`.
+    pub fn queue(&mut self, frame: InputFrame) {
+        self.pending.push_back(frame);
+        while self.pending.len() > self.max_pending.max(1) {
+            self.pending.pop_front();
+        }
+    }
+
+    /// Start a frame, taking a requested step if frozen.
+    ///
+    /// Returns whether the pipeline runs this frame.
+    pub fn begin_frame(&mut self) -> bool {
+        self.stepping = self.frozen && self.steps > 0;
+        if self.stepping {
+            self.steps -= 1;
+        }
+        !self.frozen || self.stepping
+    }
+
+    /// Take the oldest pending frame.
+    pub fn next_frame(&mut self) -> Option<InputFrame> {
+        self.pending.pop_front()
+    }
+}
+
+/// Command to freeze, step, or resume the input pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub enum FrameStepCommand {
+    /// Freeze the pipeline, queueing real input.
+    Freeze,
+    /// Advance a number of frames from the pending queue.
+    Step(u32),
+    /// Unfreeze the pipeline, dropping pending input.
+    Resume,
+}
+
 /// Command to toggle debug overlay.
 #[derive(Debug, Clone, Message)]
 pub struct ToggleInputDebug {
@@ -211,18 +530,119 @@ pub fn handle_debug_commands(
     }
 }
 
+/// System to apply frame-step commands and start the frame.
+///
+/// Runs in `First` so every subsystem sees the same frozen state.
+pub fn handle_frame_step_commands(
+    mut commands: MessageReader<FrameStepCommand>,
+    mut stepper: ResMut<InputStepper>,
+    mut subsystems: ResMut<EnabledSubsystems>,
+) {
+    for command in commands.read() {
+        match command {
+            FrameStepCommand::Freeze => stepper.freeze(),
+            FrameStepCommand::Step(frames) => stepper.step(*frames),
+            FrameStepCommand::Resume => stepper.resume(),
+        }
+    }
+    let running = stepper.begin_frame();
+    if subsystems.is_paused() == running {
+        subsystems.set_paused(!running);
+    }
+}
+
+/// Run condition that passes unless the input pipeline is frozen.
+pub fn input_pipeline_running(stepper: Res<InputStepper>) -> bool {
+    !stepper.is_frozen()
+}
+
+/// System to queue real input while the pipeline is frozen.
+pub fn queue_frozen_input(
+    mut stepper: ResMut<InputStepper>,
+    action_map: Res<ActionMap>,
+    device_state: Res<InputDeviceState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&GamepadActionState>,
+) {
+    if !stepper.is_frozen() {
+        return;
+    }
+
+    let accepts_mouse_keyboard = device_state.accepts_mouse_keyboard();
+    let actions = GameAction::all()
+        .iter()
+        .filter_map(|action| {
+            let (pressed, value) = sample_action_input(
+                *action,
+                &action_map,
+                accepts_mouse_keyboard,
+                &keyboard,
+                &mouse_buttons,
+                gamepads,
+            );
+            pressed.then_some((*action, value))
+        })
+        .collect();
+    stepper.queue(InputFrame { actions });
+}
+
+/// System to update action state from the pending queue while frozen.
+///
+/// Each step applies the oldest pending frame. Between steps only the
+/// `just_pressed`/`just_released` edges are cleared, so held actions stay
+/// held without firing again every frozen frame.
+pub fn step_action_state(
+    mut stepper: ResMut<InputStepper>,
+    mut state: ResMut<ActionState>,
+    mut gamepads: Query<&mut GamepadActionState>,
+    mut capture: ResMut<InputCapture>,
+    simulated: Res<SimulatedInput>,
+) {
+    if !stepper.is_frozen() {
+        return;
+    }
+
+    state.reset_frame_state();
+    if !stepper.is_stepping() {
+        return;
+    }
+    let Some(frame) = stepper.next_frame() else {
+        return;
+    };
+    for action in GameAction::all() {
+        apply_action_input(
+            &mut state,
+            *action,
+            frame.input(*action),
+            &simulated,
+            &mut capture,
+            &mut gamepads,
+        );
+    }
+}
+
 /// System to render debug overlay.
 ///
 /// This outputs debug information to the log. For visual overlay, integrate with
 /// `bevy_egui` or a custom UI system.
-pub fn render_debug_overlay(debugger: Res<InputDebugger>, gamepads: Query<(Entity, &Gamepad)>) {
+///
+/// Raw buttons and sticks are not logged while [`StreamerMode`] is
+/// redacting.
+pub fn render_debug_overlay(
+    debugger: Res<InputDebugger>,
+    streamer: Res<StreamerMode>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    meters: Query<(Entity, &MashMeter)>,
+) {
     if !debugger.enabled {
         return;
     }
 
     // Log gamepad state when debug is enabled
+    let raw_input = !streamer.is_redacting();
     for (entity, gamepad) in &gamepads {
-        if debugger.show_buttons {
+        if debugger.show_buttons && raw_input {
             // Log digital button states
             for button in [
                 GamepadButton::South,
@@ -242,7 +662,7 @@ pub fn render_debug_overlay(debugger: Res<InputDebugger>, gamepads: Query<(Entit
             }
         }
 
-        if debugger.show_sticks {
+        if debugger.show_sticks && raw_input {
             // Log significant stick movement
             let left_x = gamepad.get(GamepadAxis::LeftStickX).unwrap_or(0.0);
             let left_y = gamepad.get(GamepadAxis::LeftStickY).unwrap_or(0.0);
@@ -258,21 +678,89 @@ pub fn render_debug_overlay(debugger: Res<InputDebugger>, gamepads: Query<(Entit
             }
         }
     }
+
+    if debugger.show_mash {
+        for (entity, meter) in &meters {
+            if meter.rate() > 0.0 && !streamer.redacts(meter.action) {
+                debug!(
+                    "Mash {entity:?}: {:?} {:.1}/s [{}]",
+                    meter.action,
+                    meter.rate(),
+                    mash_bar(meter.intensity(), 10)
+                );
+            }
+        }
+    }
+}
+
+/// Render a mash intensity (0.0-1.0) as a text bar of `width` cells.
+#[must_use]
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
+    reason = "bar widths are small and the intensity is clamped to 0.0-1.0"
+)]
+pub fn mash_bar(intensity: f32, width: usize) -> String {
+    let filled = (intensity.clamp(0.0, 1.0) * width as f32).round() as usize;
+    format!("{}{}", "#".repeat(filled), "-".repeat(width - filled))
+}
+
+/// System to open a sensitive window while the virtual keyboard is shown.
+#[cfg(feature = "virtual_keyboard")]
+pub fn track_keyboard_redaction(
+    keyboard_state: Res<State<crate::virtual_keyboard::VirtualKeyboardState>>,
+    mut streamer: ResMut<StreamerMode>,
+) {
+    let open = *keyboard_state.get() != crate::virtual_keyboard::VirtualKeyboardState::Hidden;
+    let window = streamer.redact_virtual_keyboard && open;
+    if streamer.keyboard_window != window {
+        streamer.keyboard_window = window;
+    }
 }
 
 /// Plugin for registering debug types.
 pub(crate) fn register_debug_types(app: &mut App) {
     app.init_resource::<InputDebugger>()
+        .init_resource::<StreamerMode>()
         .init_resource::<InputRecorder>()
         .init_resource::<InputPlayback>()
+        .init_resource::<InputStepper>()
         .add_message::<ToggleInputDebug>()
+        .add_message::<FrameStepCommand>()
         .add_message::<RecordingCommand>()
         .add_message::<PlaybackCommand>();
 }
 
 /// Add debug systems to the app.
-pub(crate) fn add_debug_systems(app: &mut App) {
-    app.add_systems(Update, (handle_debug_commands, render_debug_overlay));
+pub(crate) fn add_debug_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        (handle_debug_commands, render_debug_overlay)
+            .run_if(subsystem_enabled(Subsystem::Debug))
+            .run_if(normal_power),
+    );
+    app.add_systems(
+        First,
+        handle_frame_step_commands.after(crate::subsystems::handle_subsystem_toggles),
+    )
+    .add_systems(
+        schedules.input,
+        (queue_frozen_input, step_action_state)
+            .chain()
+            .after(crate::actions::update_gamepad_action_states::<GameAction>)
+            .before(crate::actions::update_action_state::<GameAction>),
+    );
+
+    #[cfg(feature = "virtual_keyboard")]
+    app.add_systems(
+        schedules.update,
+        track_keyboard_redaction
+            .before(render_debug_overlay)
+            .run_if(subsystem_enabled(Subsystem::Debug))
+            .run_if(normal_power)
+            .run_if(resource_exists::<State<crate::virtual_keyboard::VirtualKeyboardState>>),
+    );
 }
 
 #[cfg(test)]
@@ -287,6 +775,7 @@ mod tests {
         assert!(debugger.show_sticks);
         assert!(debugger.show_buttons);
         assert!(!debugger.show_gyro);
+        assert!(debugger.show_mash);
         assert_eq!(debugger.history_size, 20);
     }
 
@@ -306,6 +795,53 @@ mod tests {
         assert!(debugger.show_sticks);
         assert!(debugger.show_buttons);
         assert!(debugger.show_gyro);
+        assert!(debugger.show_mash);
+    }
+
+    #[test]
+    fn test_mash_bar() {
+        assert_eq!(mash_bar(0.0, 4), "----");
+        assert_eq!(mash_bar(0.5, 4), "##--");
+        assert_eq!(mash_bar(2.0, 4), "####");
+    }
+
+    fn press(action: GameAction) -> RecordedInput {
+        RecordedInput {
+            action,
+            timestamp: 0.0,
+            pressed: true,
+            analog_value: None,
+        }
+    }
+
+    #[test]
+    fn test_streamer_mode_redacts_only_in_window() {
+        let mut streamer = StreamerMode::default();
+        streamer.begin_sensitive();
+        assert!(!streamer.redacts(GameAction::Confirm));
+
+        streamer.enabled = true;
+        assert!(streamer.redacts(GameAction::Confirm));
+
+        streamer.end_sensitive();
+        assert!(!streamer.is_redacting());
+    }
+
+    #[test]
+    fn test_record_sanitized_drops_sensitive_actions() {
+        let mut streamer = StreamerMode {
+            enabled: true,
+            sensitive_actions: [GameAction::Confirm].into_iter().collect(),
+            ..default()
+        };
+        let mut recorder = InputRecorder::default();
+        recorder.start(0.0);
+
+        assert!(recorder.record_sanitized(press(GameAction::Confirm), &streamer));
+        streamer.begin_sensitive();
+        assert!(!recorder.record_sanitized(press(GameAction::Confirm), &streamer));
+        assert!(recorder.record_sanitized(press(GameAction::Pause), &streamer));
+        assert_eq!(recorder.recorded.len(), 2);
     }
 
     #[test]
@@ -454,6 +990,110 @@ mod tests {
         assert!(recorder.recorded.is_empty());
     }
 
+    fn analog(action: GameAction, timestamp: f64, value: f32) -> RecordedInput {
+        RecordedInput {
+            action,
+            timestamp,
+            pressed: true,
+            analog_value: Some(value),
+        }
+    }
+
+    #[test]
+    fn test_input_recorder_compresses_repeats() {
+        let mut recorder = InputRecorder::default();
+        recorder.limits.analog_epsilon = 0.05;
+        recorder.start(0.0);
+
+        assert!(recorder.record(analog(GameAction::Up, 0.0, 0.5)));
+        assert!(!recorder.record(analog(GameAction::Up, 0.1, 0.52)));
+        assert!(recorder.record(analog(GameAction::Down, 0.1, 0.52)));
+        assert!(recorder.record(analog(GameAction::Up, 0.2, 0.7)));
+        assert_eq!(recorder.recorded.len(), 3);
+
+        recorder.limits.compress = false;
+        assert!(recorder.record(analog(GameAction::Up, 0.3, 0.7)));
+    }
+
+    #[test]
+    fn test_input_recorder_trims_idle_gaps() {
+        let mut recorder = InputRecorder::default();
+        recorder.limits.max_idle = Some(1.0);
+        recorder.start(0.0);
+
+        recorder.record(analog(GameAction::Up, 0.5, 1.0));
+        recorder.record(analog(GameAction::Up, 10.5, 0.0));
+        recorder.record(analog(GameAction::Up, 11.0, 1.0));
+
+        let timestamps: Vec<f64> = recorder.recorded.iter().map(|i| i.timestamp).collect();
+        assert_eq!(timestamps, vec![0.5, 1.5, 2.0]);
+        assert_eq!(recorder.trimmed_secs(), 9.0);
+    }
+
+    #[test]
+    fn test_input_recorder_evicts_oldest_over_budget() {
+        let mut recorder = InputRecorder::default();
+        recorder.limits.max_bytes = Some(8 * std::mem::size_of::<RecordedInput>());
+        recorder.start(0.0);
+
+        for i in 0..9 {
+            recorder.record(analog(GameAction::Up, f64::from(i), i as f32));
+        }
+
+        assert!(recorder.memory_usage() <= 8 * std::mem::size_of::<RecordedInput>());
+        assert_eq!(recorder.evicted(), 3);
+        assert_eq!(recorder.recorded[0].timestamp, 3.0);
+
+        recorder.start(0.0);
+        assert_eq!(recorder.evicted(), 0);
+    }
+
+    // ========== InputStepper Tests ==========
+
+    #[test]
+    fn test_input_stepper_steps_only_while_frozen() {
+        let mut stepper = InputStepper::default();
+        stepper.step(1);
+        assert!(stepper.begin_frame());
+        assert!(!stepper.is_stepping());
+
+        stepper.freeze();
+        assert!(!stepper.begin_frame());
+
+        stepper.step(2);
+        assert!(stepper.begin_frame());
+        assert!(stepper.is_stepping());
+        assert!(stepper.begin_frame());
+        assert!(!stepper.begin_frame());
+        assert!(!stepper.is_stepping());
+    }
+
+    #[test]
+    fn test_input_stepper_queues_pending_frames() {
+        let mut stepper = InputStepper {
+            max_pending: 2,
+            ..default()
+        };
+        stepper.freeze();
+        for value in [0.25, 0.5, 0.75] {
+            stepper.queue(InputFrame {
+                actions: [(GameAction::Primary, value)].into_iter().collect(),
+            });
+        }
+
+        assert_eq!(stepper.pending().len(), 2);
+        let frame = stepper.next_frame().unwrap();
+        assert_eq!(frame.input(GameAction::Primary), (true, 0.5));
+        assert_eq!(frame.input(GameAction::Secondary), (false, 0.0));
+
+        stepper.step(3);
+        stepper.resume();
+        assert!(!stepper.is_frozen());
+        assert!(stepper.pending().is_empty());
+        stepper.freeze();
+        assert!(!stepper.begin_frame());
+    }
+
     // ========== InputPlayback Additional Tests ==========
 
     #[test]
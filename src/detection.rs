@@ -4,6 +4,9 @@
 //! (mouse, keyboard, or gamepad) the player is currently using.
 
 use bevy::prelude::*;
+use bevy::window::{CursorOptions, PrimaryWindow};
+
+use crate::battery::ControllerBattery;
 
 /// The type of input device currently being used.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
@@ -70,6 +73,15 @@ pub struct InputDeviceState {
 
     /// Whether to automatically switch devices based on input.
     pub auto_switch: bool,
+
+    /// Gamepad-only mode for kiosk and arcade builds.
+    ///
+    /// Mouse and keyboard input is ignored entirely, the OS cursor is
+    /// hidden, and the active device never switches away from a gamepad.
+    pub gamepad_only: bool,
+
+    /// Battery of the active gamepad, if it reports one.
+    pub active_battery: Option<ControllerBattery>,
 }
 
 impl Default for InputDeviceState {
@@ -82,6 +94,8 @@ impl Default for InputDeviceState {
             primary_gamepad: None,
             mouse_movement_threshold: 1.0,
             auto_switch: true,
+            gamepad_only: false,
+            active_battery: None,
         }
     }
 }
@@ -117,8 +131,30 @@ impl InputDeviceState {
         self.active_device.gamepad()
     }
 
+    /// Returns true if mouse and keyboard input should be processed.
+    #[must_use]
+    pub fn accepts_mouse_keyboard(&self) -> bool {
+        !self.gamepad_only
+    }
+
+    /// Enable or disable gamepad-only mode.
+    ///
+    /// Enabling it immediately switches to the primary gamepad, if any.
+    pub fn set_gamepad_only(&mut self, enabled: bool) {
+        self.gamepad_only = enabled;
+        if enabled
+            && !self.active_device.is_gamepad()
+            && let Some(gamepad) = self.primary_gamepad
+        {
+            self.set_active(InputDevice::Gamepad(gamepad));
+        }
+    }
+
     /// Set the active device and track changes.
     fn set_active(&mut self, device: InputDevice) {
+        if self.gamepad_only && !device.is_gamepad() {
+            return;
+        }
         if self.active_device != device {
             self.previous_device = self.active_device;
             self.active_device = device;
@@ -174,13 +210,15 @@ pub fn detect_input_device(
     let mouse_moved = mouse_motion.read().count() > 0;
     let mouse_clicked = mouse_buttons.get_just_pressed().next().is_some();
 
-    if mouse_moved || mouse_clicked {
-        state.set_active(InputDevice::Mouse);
-    }
+    if state.accepts_mouse_keyboard() {
+        if mouse_moved || mouse_clicked {
+            state.set_active(InputDevice::Mouse);
+        }
 
-    // Check for keyboard activity
-    if keyboard.get_just_pressed().next().is_some() {
-        state.set_active(InputDevice::Keyboard);
+        // Check for keyboard activity
+        if keyboard.get_just_pressed().next().is_some() {
+            state.set_active(InputDevice::Keyboard);
+        }
     }
 
     // Check for gamepad activity
@@ -226,6 +264,11 @@ pub fn track_gamepad_connections(
                 state.primary_gamepad = Some(entity);
             }
 
+            // Gamepad-only mode adopts the first available gamepad right away
+            if state.gamepad_only && !state.active_device.is_gamepad() {
+                state.set_active(InputDevice::Gamepad(entity));
+            }
+
             connected_events.write(GamepadConnected {
                 gamepad: entity,
                 name: name.map(std::string::ToString::to_string),
@@ -243,11 +286,15 @@ pub fn track_gamepad_connections(
                 state.primary_gamepad = state.connected_gamepads.first().copied();
             }
 
-            // Update active device if it was the disconnected gamepad
+            // Update active device if it was the disconnected gamepad.
+            // In gamepad-only mode the stale entity is kept until another
+            // gamepad connects, so prompts never fall back to keyboard.
             if state.active_device == InputDevice::Gamepad(entity) {
-                state.active_device = state
-                    .primary_gamepad
-                    .map_or(InputDevice::Keyboard, InputDevice::Gamepad);
+                if let Some(primary) = state.primary_gamepad {
+                    state.active_device = InputDevice::Gamepad(primary);
+                } else if !state.gamepad_only {
+                    state.active_device = InputDevice::Keyboard;
+                }
             }
 
             disconnected_events.write(GamepadDisconnected { gamepad: entity });
@@ -255,6 +302,22 @@ pub fn track_gamepad_connections(
     }
 }
 
+/// System to hide the OS cursor while gamepad-only mode is enabled.
+pub fn apply_gamepad_only_cursor(
+    state: Res<InputDeviceState>,
+    mut cursors: Query<&mut CursorOptions, With<PrimaryWindow>>,
+    mut applied: Local<Option<bool>>,
+) {
+    if applied.unwrap_or(false) == state.gamepad_only {
+        return;
+    }
+
+    for mut cursor in &mut cursors {
+        cursor.visible = !state.gamepad_only;
+        *applied = Some(state.gamepad_only);
+    }
+}
+
 /// Plugin for registering detection types and systems.
 pub(crate) fn register_detection_types(app: &mut App) {
     app.register_type::<InputDevice>()
@@ -266,11 +329,12 @@ pub(crate) fn register_detection_types(app: &mut App) {
 }
 
 /// Add detection systems to the app.
-pub(crate) fn add_detection_systems(app: &mut App) {
+pub(crate) fn add_detection_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
     app.add_systems(
-        PreUpdate,
+        schedules.input,
         (track_gamepad_connections, detect_input_device).chain(),
-    );
+    )
+    .add_systems(schedules.update, apply_gamepad_only_cursor);
 }
 
 #[cfg(test)]
@@ -344,6 +408,7 @@ mod tests {
         assert!(state.connected_gamepads.is_empty());
         assert!(state.primary_gamepad.is_none());
         assert!(state.auto_switch);
+        assert!(!state.gamepad_only);
     }
 
     #[test]
@@ -417,6 +482,30 @@ mod tests {
         assert!(!state.device_changed);
     }
 
+    #[test]
+    fn test_gamepad_only_blocks_mouse_and_keyboard() {
+        let mut state = InputDeviceState::default();
+        state.set_gamepad_only(true);
+        assert!(!state.accepts_mouse_keyboard());
+
+        state.active_device = InputDevice::Gamepad(Entity::PLACEHOLDER);
+        state.set_active(InputDevice::Keyboard);
+        assert!(state.using_gamepad());
+
+        state.set_active(InputDevice::Mouse);
+        assert!(state.using_gamepad());
+    }
+
+    #[test]
+    fn test_set_gamepad_only_switches_to_primary() {
+        let mut state = InputDeviceState::default();
+        state.primary_gamepad = Some(Entity::PLACEHOLDER);
+
+        state.set_gamepad_only(true);
+        assert_eq!(state.active_gamepad(), Some(Entity::PLACEHOLDER));
+        assert!(state.device_changed);
+    }
+
     #[test]
     fn test_input_device_state_mouse_movement_threshold() {
         let state = InputDeviceState::default();
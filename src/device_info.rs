@@ -0,0 +1,230 @@
+//! Controller IDs and power info read through gilrs (`gilrs` feature).
+//!
+//! Bevy reports vendor and product IDs only where its gilrs backend gets
+//! them at connection time, and never reports battery state. With the
+//! `gilrs` feature, [`GilrsDeviceInfo`] opens its own gilrs context, pairs
+//! each gilrs gamepad with its Bevy gamepad entity by name and IDs, and:
+//!
+//! - replaces a name-based [`DetectedController`] with one identified from
+//!   the real VID/PID, sending [`ControllerDetected`] again so profiles
+//!   reload, and
+//! - keeps a [`ControllerBattery`] on the gamepad up to date from gilrs
+//!   power info.
+
+use bevy::prelude::*;
+use gilrs::{GamepadId, Gilrs, PowerInfo};
+use log::warn;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::battery::{ChargingState, ControllerBattery};
+use crate::profiles::{ControllerDetected, DetectedController};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Resource holding a gilrs context for reading controller details.
+#[derive(Resource)]
+pub struct GilrsDeviceInfo {
+    gilrs: Mutex<Gilrs>,
+    /// gilrs gamepad paired with each Bevy gamepad entity.
+    assignments: HashMap<Entity, GamepadId>,
+    /// Seconds between battery reads.
+    pub poll_interval: f32,
+    since_poll: f32,
+}
+
+impl GilrsDeviceInfo {
+    /// Open a gilrs context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if gilrs is not supported on this platform.
+    pub fn new() -> crate::Result<Self> {
+        let gilrs = Gilrs::new().map_err(|e| crate::Error::BackendInit {
+            backend: "gilrs",
+            reason: e.to_string(),
+        })?;
+        Ok(Self {
+            gilrs: Mutex::new(gilrs),
+            assignments: HashMap::new(),
+            poll_interval: 1.0,
+            since_poll: f32::INFINITY,
+        })
+    }
+
+    /// The gilrs gamepad paired with a Bevy gamepad entity.
+    #[must_use]
+    pub fn gamepad_id(&self, gamepad: Entity) -> Option<GamepadId> {
+        self.assignments.get(&gamepad).copied()
+    }
+}
+
+/// Convert gilrs power info to a battery state.
+///
+/// Returns `None` when gilrs doesn't know.
+#[must_use]
+pub fn battery_from_power_info(power: PowerInfo) -> Option<ControllerBattery> {
+    let battery = match power {
+        PowerInfo::Unknown => return None,
+        PowerInfo::Wired => ControllerBattery::new(None, ChargingState::Wired),
+        PowerInfo::Discharging(percent) => {
+            ControllerBattery::new(Some(f32::from(percent) / 100.0), ChargingState::Discharging)
+        }
+        PowerInfo::Charging(percent) => {
+            ControllerBattery::new(Some(f32::from(percent) / 100.0), ChargingState::Charging)
+        }
+        PowerInfo::Charged => ControllerBattery::new(Some(1.0), ChargingState::Full),
+    };
+    Some(battery)
+}
+
+/// Whether a gilrs gamepad is the same controller as a Bevy gamepad.
+///
+/// Names must match; IDs must match where both sides report them.
+fn same_device(
+    name: Option<&str>,
+    ids: (Option<u16>, Option<u16>),
+    device_name: &str,
+    device_ids: (Option<u16>, Option<u16>),
+) -> bool {
+    let id_matches = |ours: Option<u16>, theirs: Option<u16>| {
+        ours.zip(theirs).is_none_or(|(ours, theirs)| ours == theirs)
+    };
+    name.is_none_or(|name| name == device_name)
+        && id_matches(ids.0, device_ids.0)
+        && id_matches(ids.1, device_ids.1)
+}
+
+/// System to pair gilrs gamepads with Bevy gamepads and copy their IDs and
+/// power info.
+pub fn sync_gilrs_devices(
+    time: Res<Time>,
+    mut info: ResMut<GilrsDeviceInfo>,
+    gamepads: Query<(Entity, &Gamepad, Option<&Name>, Option<&DetectedController>)>,
+    mut batteries: Query<&mut ControllerBattery>,
+    mut commands: Commands,
+    mut detected_events: MessageWriter<ControllerDetected>,
+) {
+    let info = &mut *info;
+    let Ok(gilrs) = info.gilrs.get_mut() else {
+        return;
+    };
+    // Events keep gilrs' device list current
+    while gilrs.next_event().is_some() {}
+
+    let connected: HashSet<GamepadId> = gilrs.gamepads().map(|(id, _)| id).collect();
+    info.assignments
+        .retain(|entity, id| gamepads.contains(*entity) && connected.contains(id));
+
+    for (entity, gamepad, name, current) in &gamepads {
+        if info.assignments.contains_key(&entity) {
+            continue;
+        }
+        let name = name.map(Name::as_str);
+        let ids = (gamepad.vendor_id(), gamepad.product_id());
+        let assigned: HashSet<GamepadId> = info.assignments.values().copied().collect();
+        let Some((id, device)) = gilrs.gamepads().find(|(id, device)| {
+            !assigned.contains(id)
+                && same_device(
+                    name,
+                    ids,
+                    device.name(),
+                    (device.vendor_id(), device.product_id()),
+                )
+        }) else {
+            continue;
+        };
+        info.assignments.insert(entity, id);
+
+        // Only replace detections that lacked real IDs
+        let detected = DetectedController::detect(device.vendor_id(), device.product_id(), name);
+        let had_ids = current.is_some_and(|c| c.vendor_id != 0 && c.product_id != 0);
+        if !had_ids && detected.vendor_id != 0 && detected.product_id != 0 {
+            commands.entity(entity).insert(detected);
+            if current.is_none_or(|c| c.model != detected.model) {
+                detected_events.write(ControllerDetected {
+                    gamepad: entity,
+                    model: detected.model,
+                });
+            }
+        }
+    }
+
+    info.since_poll += time.delta_secs();
+    if info.since_poll < info.poll_interval {
+        return;
+    }
+    info.since_poll = 0.0;
+
+    for (entity, id) in &info.assignments {
+        let Some(battery) = gilrs
+            .connected_gamepad(*id)
+            .and_then(|device| battery_from_power_info(device.power_info()))
+        else {
+            continue;
+        };
+        if let Ok(mut current) = batteries.get_mut(*entity) {
+            current.set_if_neq(battery);
+        } else {
+            commands.entity(*entity).insert(battery);
+        }
+    }
+}
+
+/// Plugin for registering gilrs device info.
+pub(crate) fn register_device_info_types(app: &mut App) {
+    match GilrsDeviceInfo::new() {
+        Ok(info) => {
+            app.insert_resource(info);
+        }
+        Err(e) => warn!("Controller IDs and power info unavailable: {e}"),
+    }
+}
+
+/// Add gilrs device info systems to the app.
+pub(crate) fn add_device_info_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        sync_gilrs_devices
+            .after(crate::profiles::detect_controller_models)
+            .before(crate::profiles::auto_load_profiles)
+            .before(crate::battery::detect_low_battery)
+            .run_if(resource_exists::<GilrsDeviceInfo>)
+            .run_if(subsystem_enabled(Subsystem::Profiles)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_info_conversion() {
+        assert_eq!(battery_from_power_info(PowerInfo::Unknown), None);
+
+        let battery = battery_from_power_info(PowerInfo::Discharging(15)).unwrap();
+        assert_eq!(battery.percent(), Some(15));
+        assert!(battery.is_low(0.2));
+
+        let battery = battery_from_power_info(PowerInfo::Wired).unwrap();
+        assert_eq!(battery.state, ChargingState::Wired);
+        assert_eq!(battery.level, None);
+    }
+
+    #[test]
+    fn test_device_pairing_rules() {
+        let ids = (Some(0x054c), Some(0x0ce6));
+        assert!(same_device(
+            Some("DualSense Wireless Controller"),
+            ids,
+            "DualSense Wireless Controller",
+            ids,
+        ));
+        // Unknown IDs on the Bevy side match on name alone
+        assert!(same_device(Some("Pad"), (None, None), "Pad", ids));
+        assert!(!same_device(Some("Pad"), ids, "Other", ids));
+        assert!(!same_device(Some("Pad"), (Some(0x045e), None), "Pad", ids));
+    }
+}
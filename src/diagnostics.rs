@@ -0,0 +1,289 @@
+//! Controller diagnostics for Bevy's diagnostics system.
+//!
+//! This module registers input-pipeline measurements with Bevy's
+//! [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore) so they show up
+//! in `LogDiagnosticsPlugin` output and third-party diagnostic overlays.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::diagnostic::LogDiagnosticsPlugin;
+//! use bevy::platform::collections::HashSet;
+//! use bevy::prelude::*;
+//! use bevy_archie::diagnostics;
+//! use bevy_archie::prelude::*;
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(ControllerPlugin::default())
+//!     .add_plugins(LogDiagnosticsPlugin {
+//!         filter: Some(HashSet::from([
+//!             diagnostics::ACTION_UPDATE_TIME,
+//!             diagnostics::INPUT_BUFFER_OCCUPANCY,
+//!             diagnostics::RUMBLE_REQUEST_BACKLOG,
+//!         ])),
+//!         ..default()
+//!     })
+//!     .run();
+//! ```
+//!
+//! Message channels that keep buffering many messages for a while log a
+//! warning through [`MessageBackpressure`], which usually means a writer is
+//! flooding the channel. Bevy drops messages after two frames whether or not
+//! they were read, so the counts can't tell a missing reader apart.
+
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use log::warn;
+use std::collections::HashMap;
+
+use crate::action_modifiers::ModifiedActionEvent;
+use crate::actions::{ActionState, GameAction};
+use crate::gyro::MotionGestureDetected;
+use crate::haptics::RumbleRequest;
+use crate::input_buffer::InputBuffer;
+use crate::plugin::ControllerSchedules;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::touchpad::TouchpadGestureEvent;
+
+/// Time spent updating the action state each frame, in milliseconds.
+pub const ACTION_UPDATE_TIME: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/action_update_time");
+
+/// Time spent polling motion and touchpad backends, in milliseconds.
+pub const BACKEND_POLL_TIME: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/backend_poll_time");
+
+/// Number of action press and release events emitted per frame.
+pub const ACTION_EVENTS: DiagnosticPath = DiagnosticPath::const_new("bevy_archie/action_events");
+
+/// Number of inputs held in the [`InputBuffer`].
+pub const INPUT_BUFFER_OCCUPANCY: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/input_buffer_occupancy");
+
+/// Number of buffered [`TouchpadGestureEvent`] messages.
+pub const TOUCHPAD_GESTURE_BACKLOG: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/messages/touchpad_gestures");
+
+/// Number of buffered [`MotionGestureDetected`] messages.
+pub const MOTION_GESTURE_BACKLOG: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/messages/motion_gestures");
+
+/// Number of buffered [`RumbleRequest`] messages.
+pub const RUMBLE_REQUEST_BACKLOG: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/messages/rumble_requests");
+
+/// Number of buffered [`ModifiedActionEvent`] messages.
+pub const MODIFIED_ACTION_BACKLOG: DiagnosticPath =
+    DiagnosticPath::const_new("bevy_archie/messages/modified_actions");
+
+/// Resource configuring message back-pressure warnings.
+///
+/// Bevy keeps a message for two frames whether or not anything reads it, so
+/// a channel that stays above [`Self::threshold`] buffered messages for
+/// [`Self::sustained_frames`] frames is logged once until it drains.
+#[derive(Debug, Clone, Resource)]
+pub struct MessageBackpressure {
+    /// Buffered messages above which a channel counts as backed up.
+    pub threshold: usize,
+    /// Consecutive backed-up frames before warning.
+    pub sustained_frames: u32,
+    /// Consecutive backed-up frames per channel.
+    backed_up: HashMap<DiagnosticPath, u32>,
+}
+
+impl Default for MessageBackpressure {
+    fn default() -> Self {
+        Self {
+            threshold: 64,
+            sustained_frames: 30,
+            backed_up: HashMap::new(),
+        }
+    }
+}
+
+impl MessageBackpressure {
+    /// Record a channel's buffered message count.
+    ///
+    /// Returns true on the frame the channel first needs a warning.
+    pub fn observe(&mut self, channel: &DiagnosticPath, buffered: usize) -> bool {
+        if buffered <= self.threshold {
+            self.backed_up.remove(channel);
+            return false;
+        }
+
+        let frames = self.backed_up.entry(channel.clone()).or_default();
+        *frames = frames.saturating_add(1);
+        *frames == self.sustained_frames.max(1)
+    }
+
+    /// Check if a channel is currently backed up.
+    #[must_use]
+    pub fn is_backed_up(&self, channel: &DiagnosticPath) -> bool {
+        self.backed_up.contains_key(channel)
+    }
+}
+
+/// System that records a message channel's backlog and warns on
+/// back-pressure.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "message counts are far below f64 precision limits"
+)]
+pub fn monitor_message_backlog<T: Message>(
+    channel: DiagnosticPath,
+) -> impl FnMut(Res<Messages<T>>, ResMut<MessageBackpressure>, Diagnostics) {
+    move |messages, mut backpressure, mut diagnostics| {
+        let buffered = messages.len();
+        diagnostics.add_measurement(&channel, || buffered as f64);
+
+        if backpressure.observe(&channel, buffered) {
+            warn!(
+                "{buffered} {} messages buffered for {} frames; a writer may be flooding the channel",
+                std::any::type_name::<T>(),
+                backpressure.sustained_frames
+            );
+        }
+    }
+}
+
+/// Monitor a message channel's backlog in the plugin's
+/// [`post_update`](ControllerSchedules::post_update) schedule.
+///
+/// Pass the same [`ControllerSchedules`] given to the plugin. Registers a
+/// diagnostic at `channel` and warns through [`MessageBackpressure`] like
+/// the built-in channels.
+pub fn add_message_backlog_monitor<T: Message>(
+    app: &mut App,
+    schedules: &ControllerSchedules,
+    channel: DiagnosticPath,
+) {
+    app.register_diagnostic(Diagnostic::new(channel.clone()))
+        .add_systems(
+            schedules.post_update,
+            monitor_message_backlog::<T>(channel).run_if(subsystem_enabled(Subsystem::Diagnostics)),
+        );
+}
+
+/// When the current action update started.
+#[derive(Debug, Default, Resource)]
+struct ActionUpdateTimer(Option<Instant>);
+
+/// Record the start of the action update.
+fn start_action_update_timer(mut timer: ResMut<ActionUpdateTimer>) {
+    timer.0 = Some(Instant::now());
+}
+
+/// Record how long the action update took.
+fn record_action_update_time(mut timer: ResMut<ActionUpdateTimer>, mut diagnostics: Diagnostics) {
+    if let Some(start) = timer.0.take() {
+        diagnostics.add_measurement(&ACTION_UPDATE_TIME, || {
+            start.elapsed().as_secs_f64() * 1000.0
+        });
+    }
+}
+
+/// Count the action press and release events emitted this frame.
+#[must_use]
+pub fn count_action_events(action_state: &ActionState) -> usize {
+    GameAction::all()
+        .iter()
+        .filter(|action| action_state.just_pressed(**action))
+        .count()
+        + GameAction::all()
+            .iter()
+            .filter(|action| action_state.just_released(**action))
+            .count()
+}
+
+/// System to record action events emitted this frame.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "event counts are far below f64 precision limits"
+)]
+pub fn record_action_events(action_state: Res<ActionState>, mut diagnostics: Diagnostics) {
+    diagnostics.add_measurement(&ACTION_EVENTS, || count_action_events(&action_state) as f64);
+}
+
+/// System to record input buffer occupancy.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "buffer length is capped well below f64 precision limits"
+)]
+pub fn record_input_buffer_occupancy(buffer: Res<InputBuffer>, mut diagnostics: Diagnostics) {
+    diagnostics.add_measurement(&INPUT_BUFFER_OCCUPANCY, || buffer.inputs.len() as f64);
+}
+
+/// Plugin for registering controller diagnostics.
+pub(crate) fn register_diagnostics_types(app: &mut App) {
+    app.init_resource::<ActionUpdateTimer>()
+        .init_resource::<MessageBackpressure>()
+        .register_diagnostic(Diagnostic::new(ACTION_UPDATE_TIME).with_suffix("ms"))
+        .register_diagnostic(Diagnostic::new(BACKEND_POLL_TIME).with_suffix("ms"))
+        .register_diagnostic(Diagnostic::new(ACTION_EVENTS))
+        .register_diagnostic(Diagnostic::new(INPUT_BUFFER_OCCUPANCY))
+        .register_diagnostic(Diagnostic::new(TOUCHPAD_GESTURE_BACKLOG))
+        .register_diagnostic(Diagnostic::new(MOTION_GESTURE_BACKLOG))
+        .register_diagnostic(Diagnostic::new(RUMBLE_REQUEST_BACKLOG))
+        .register_diagnostic(Diagnostic::new(MODIFIED_ACTION_BACKLOG));
+}
+
+/// Add diagnostics systems to the app.
+pub(crate) fn add_diagnostics_systems(app: &mut App, schedules: &ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        (
+            start_action_update_timer.before(crate::actions::sync_confirm_style),
+            (record_action_update_time, record_action_events)
+                .after(crate::actions::update_action_state::<GameAction>),
+        )
+            .run_if(subsystem_enabled(Subsystem::Diagnostics)),
+    )
+    .add_systems(
+        schedules.post_update,
+        (
+            record_input_buffer_occupancy,
+            monitor_message_backlog::<TouchpadGestureEvent>(TOUCHPAD_GESTURE_BACKLOG),
+            monitor_message_backlog::<MotionGestureDetected>(MOTION_GESTURE_BACKLOG),
+            monitor_message_backlog::<RumbleRequest>(RUMBLE_REQUEST_BACKLOG),
+            monitor_message_backlog::<ModifiedActionEvent>(MODIFIED_ACTION_BACKLOG),
+        )
+            .run_if(subsystem_enabled(Subsystem::Diagnostics)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_action_events() {
+        let mut state = ActionState::default();
+        assert_eq!(count_action_events(&state), 0);
+
+        state.set_pressed(GameAction::Confirm, true);
+        state.set_pressed(GameAction::Primary, true);
+        assert_eq!(count_action_events(&state), 2);
+    }
+
+    #[test]
+    fn test_backpressure_warns_once_when_sustained() {
+        let mut backpressure = MessageBackpressure {
+            threshold: 4,
+            sustained_frames: 3,
+            ..default()
+        };
+
+        assert!(!backpressure.observe(&RUMBLE_REQUEST_BACKLOG, 10));
+        assert!(!backpressure.observe(&RUMBLE_REQUEST_BACKLOG, 10));
+        assert!(backpressure.observe(&RUMBLE_REQUEST_BACKLOG, 10));
+        assert!(!backpressure.observe(&RUMBLE_REQUEST_BACKLOG, 10));
+        assert!(backpressure.is_backed_up(&RUMBLE_REQUEST_BACKLOG));
+        assert!(!backpressure.is_backed_up(&MOTION_GESTURE_BACKLOG));
+
+        // Draining resets the warning
+        assert!(!backpressure.observe(&RUMBLE_REQUEST_BACKLOG, 2));
+        assert!(!backpressure.is_backed_up(&RUMBLE_REQUEST_BACKLOG));
+    }
+}
@@ -0,0 +1,140 @@
+//! Crate-level error type.
+//!
+//! Fallible APIs across config persistence, profiles, motion backends, and
+//! remapping return [`Error`] so games can surface a single, actionable
+//! message type to players.
+
+use std::path::PathBuf;
+
+use crate::actions::GameAction;
+
+/// Errors returned by fallible `bevy_archie` APIs.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A config or profile file could not be read or written.
+    #[error("failed to access {}: {source}", .path.display())]
+    Io {
+        /// The file that was being accessed.
+        path: PathBuf,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+    /// A controller config could not be parsed or serialized.
+    #[error("invalid controller config: {0}")]
+    Config(#[source] serde_json::Error),
+    /// A controller profile could not be parsed or serialized.
+    #[error("invalid controller profile: {0}")]
+    Profile(#[source] serde_json::Error),
+    /// A controller profile contribution could not be parsed.
+    #[error("invalid profile contribution: {0}")]
+    Contribution(#[source] ron::error::SpannedError),
+    /// An action map binding file could not be parsed or serialized.
+    #[error("invalid {format} binding file: {reason}")]
+    Bindings {
+        /// The file format.
+        format: &'static str,
+        /// Why parsing or serialization failed.
+        reason: String,
+    },
+    /// Player settings could not be parsed or serialized.
+    #[error("invalid player settings: {0}")]
+    PlayerSettings(#[source] serde_json::Error),
+    /// Action usage statistics could not be parsed or serialized.
+    #[error("invalid action usage data: {0}")]
+    ActionUsage(#[source] serde_json::Error),
+    /// A controller characterization report could not be parsed or serialized.
+    #[error("invalid characterization report: {0}")]
+    Characterization(#[source] serde_json::Error),
+    /// An input replay or its expected snapshots could not be parsed or serialized.
+    #[error("invalid input replay: {0}")]
+    Replay(#[source] serde_json::Error),
+    /// A rumble asset could not be parsed.
+    #[error("invalid rumble asset: {0}")]
+    RumbleAsset(#[source] ron::error::SpannedError),
+    /// A player profile name can't be used as a file name.
+    #[error("invalid player profile name {name:?}")]
+    InvalidProfileName {
+        /// The rejected name.
+        name: String,
+    },
+    /// A motion backend failed to initialize.
+    #[error("{backend} backend failed to initialize: {reason}")]
+    BackendInit {
+        /// The backend name.
+        backend: &'static str,
+        /// Why initialization failed.
+        reason: String,
+    },
+    /// An input map from another engine could not be imported.
+    #[error("invalid {format} input map: {reason}")]
+    InputMapImport {
+        /// The source format.
+        format: &'static str,
+        /// Why the import failed.
+        reason: String,
+    },
+    /// The action cannot be remapped by the player.
+    #[error("{} cannot be remapped", .action.display_name())]
+    NotRemappable {
+        /// The action.
+        action: GameAction,
+    },
+    /// Remapping would leave a required action without a binding.
+    #[error("{} requires at least one binding", .action.display_name())]
+    RequiredBinding {
+        /// The action.
+        action: GameAction,
+    },
+}
+
+impl Error {
+    /// Wrap an I/O error with the path that caused it.
+    pub(crate) fn io(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+}
+
+/// Result type returned by fallible `bevy_archie` APIs.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        let err = Error::NotRemappable {
+            action: GameAction::Pause,
+        };
+        assert_eq!(err.to_string(), "Pause cannot be remapped");
+
+        let err = Error::RequiredBinding {
+            action: GameAction::Confirm,
+        };
+        assert_eq!(err.to_string(), "Confirm requires at least one binding");
+
+        let err = Error::BackendInit {
+            backend: "dualsense",
+            reason: "no controller".into(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "dualsense backend failed to initialize: no controller"
+        );
+    }
+
+    #[test]
+    fn test_io_error_includes_path() {
+        let err = Error::io(
+            "missing.json",
+            std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+        );
+        assert!(err.to_string().contains("missing.json"));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
@@ -0,0 +1,352 @@
+//! Thin FFI and scripting layer (`ffi` feature).
+//!
+//! [`ScriptInput`] is a per-frame snapshot of the action state plus a queue
+//! of rumble requests, shaped for scripting engines. Rust-hosted engines
+//! (Lua via `mlua`, Rhai, ...) can register closures over its safe methods;
+//! engines behind a C boundary can use the `archie_*` functions, which take a
+//! pointer to the resource.
+//!
+//! Actions are addressed by numeric ID, the index of the action in
+//! [`GameAction::all`]. Scripts can look IDs up by name with
+//! [`ScriptInput::action_id_by_name`] or [`archie_action_id`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::ffi::ScriptInput;
+//!
+//! fn run_scripts(world: &mut World) {
+//!     let mut input = world.resource_mut::<ScriptInput>();
+//!     let input: *mut ScriptInput = &mut *input;
+//!     // Hand `input` to the scripting runtime for the duration of this call.
+//!     # let _ = input;
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::ffi::{CStr, c_char};
+use std::time::Duration;
+
+use crate::actions::{ActionState, GameAction};
+use crate::detection::InputDeviceState;
+use crate::haptics::RumbleRequest;
+
+/// Snapshot of one action's state.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ActionSnapshot {
+    /// Whether the action is held.
+    pub pressed: bool,
+    /// Whether the action was pressed this frame.
+    pub just_pressed: bool,
+    /// Whether the action was released this frame.
+    pub just_released: bool,
+    /// Analog value (0.0 - 1.0).
+    pub value: f32,
+}
+
+/// A rumble request queued by a script.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScriptRumble {
+    /// Rumble intensity (0.0 - 1.0).
+    pub intensity: f32,
+    /// Duration in seconds.
+    pub duration: f32,
+}
+
+/// Resource exposing action state and rumble to scripts.
+#[derive(Debug, Clone, Resource)]
+pub struct ScriptInput {
+    /// Action snapshots indexed by action ID.
+    actions: Vec<ActionSnapshot>,
+    /// Rumble requests waiting to be sent.
+    rumble: Vec<ScriptRumble>,
+}
+
+impl Default for ScriptInput {
+    fn default() -> Self {
+        Self {
+            actions: vec![ActionSnapshot::default(); GameAction::all().len()],
+            rumble: Vec::new(),
+        }
+    }
+}
+
+impl ScriptInput {
+    /// Numeric ID of an action.
+    #[must_use]
+    pub fn action_id(action: GameAction) -> u32 {
+        GameAction::all()
+            .iter()
+            .position(|candidate| *candidate == action)
+            .and_then(|index| u32::try_from(index).ok())
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Look up an action ID by its variant name (e.g. `"Confirm"`).
+    #[must_use]
+    pub fn action_id_by_name(name: &str) -> Option<u32> {
+        GameAction::all()
+            .iter()
+            .position(|action| format!("{action:?}") == name)
+            .and_then(|index| u32::try_from(index).ok())
+    }
+
+    /// Snapshot of an action, if the ID is valid.
+    #[must_use]
+    pub fn get(&self, id: u32) -> Option<&ActionSnapshot> {
+        self.actions.get(usize::try_from(id).ok()?)
+    }
+
+    /// Whether an action is held.
+    #[must_use]
+    pub fn pressed(&self, id: u32) -> bool {
+        self.get(id).is_some_and(|action| action.pressed)
+    }
+
+    /// Whether an action was pressed this frame.
+    #[must_use]
+    pub fn just_pressed(&self, id: u32) -> bool {
+        self.get(id).is_some_and(|action| action.just_pressed)
+    }
+
+    /// Whether an action was released this frame.
+    #[must_use]
+    pub fn just_released(&self, id: u32) -> bool {
+        self.get(id).is_some_and(|action| action.just_released)
+    }
+
+    /// Analog value of an action (0.0 - 1.0).
+    #[must_use]
+    pub fn value(&self, id: u32) -> f32 {
+        self.get(id).map_or(0.0, |action| action.value)
+    }
+
+    /// Queue a rumble on the active gamepad.
+    ///
+    /// Requests with a NaN intensity or a duration too long to represent
+    /// are dropped.
+    pub fn request_rumble(&mut self, intensity: f32, duration: f32) {
+        let duration = duration.max(0.0);
+        if intensity.is_nan() || Duration::try_from_secs_f32(duration).is_err() {
+            return;
+        }
+        self.rumble.push(ScriptRumble {
+            intensity: intensity.clamp(0.0, 1.0),
+            duration,
+        });
+    }
+
+    /// Copy the current action state into the snapshot.
+    pub fn refresh(&mut self, state: &ActionState) {
+        for (snapshot, action) in self.actions.iter_mut().zip(GameAction::all()) {
+            *snapshot = ActionSnapshot {
+                pressed: state.pressed(*action),
+                just_pressed: state.just_pressed(*action),
+                just_released: state.just_released(*action),
+                value: state.value(*action),
+            };
+        }
+    }
+
+    /// Take the queued rumble requests.
+    pub fn drain_rumble(&mut self) -> Vec<ScriptRumble> {
+        std::mem::take(&mut self.rumble)
+    }
+}
+
+/// Number of actions addressable by ID.
+#[unsafe(no_mangle)]
+pub extern "C" fn archie_action_count() -> u32 {
+    u32::try_from(GameAction::all().len()).unwrap_or(u32::MAX)
+}
+
+/// Look up an action ID by name, returning -1 if unknown.
+///
+/// # Safety
+///
+/// `name` must be null or point to a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn archie_action_id(name: *const c_char) -> i64 {
+    if name.is_null() {
+        return -1;
+    }
+    // SAFETY: the caller guarantees `name` is a valid C string.
+    let name = unsafe { CStr::from_ptr(name) };
+    name.to_str()
+        .ok()
+        .and_then(ScriptInput::action_id_by_name)
+        .map_or(-1, i64::from)
+}
+
+/// Whether an action is held.
+///
+/// # Safety
+///
+/// `input` must be null or point to a live [`ScriptInput`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn archie_pressed(input: *const ScriptInput, action: u32) -> bool {
+    // SAFETY: the caller guarantees `input` is null or valid.
+    unsafe { input.as_ref() }.is_some_and(|input| input.pressed(action))
+}
+
+/// Whether an action was pressed this frame.
+///
+/// # Safety
+///
+/// `input` must be null or point to a live [`ScriptInput`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn archie_just_pressed(input: *const ScriptInput, action: u32) -> bool {
+    // SAFETY: the caller guarantees `input` is null or valid.
+    unsafe { input.as_ref() }.is_some_and(|input| input.just_pressed(action))
+}
+
+/// Whether an action was released this frame.
+///
+/// # Safety
+///
+/// `input` must be null or point to a live [`ScriptInput`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn archie_just_released(input: *const ScriptInput, action: u32) -> bool {
+    // SAFETY: the caller guarantees `input` is null or valid.
+    unsafe { input.as_ref() }.is_some_and(|input| input.just_released(action))
+}
+
+/// Analog value of an action (0.0 - 1.0).
+///
+/// # Safety
+///
+/// `input` must be null or point to a live [`ScriptInput`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn archie_value(input: *const ScriptInput, action: u32) -> f32 {
+    // SAFETY: the caller guarantees `input` is null or valid.
+    unsafe { input.as_ref() }.map_or(0.0, |input| input.value(action))
+}
+
+/// Queue a rumble on the active gamepad.
+///
+/// # Safety
+///
+/// `input` must be null or point to a live [`ScriptInput`] that is not
+/// aliased for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn archie_request_rumble(
+    input: *mut ScriptInput,
+    intensity: f32,
+    duration: f32,
+) {
+    // SAFETY: the caller guarantees `input` is null or valid and unaliased.
+    if let Some(input) = unsafe { input.as_mut() } {
+        input.request_rumble(intensity, duration);
+    }
+}
+
+/// System to refresh the script snapshot from the action state.
+pub fn update_script_input(mut input: ResMut<ScriptInput>, action_state: Res<ActionState>) {
+    input.refresh(&action_state);
+}
+
+/// System to send script rumble requests to the active gamepad.
+pub fn apply_script_rumble(
+    mut input: ResMut<ScriptInput>,
+    device_state: Res<InputDeviceState>,
+    mut rumble_requests: MessageWriter<RumbleRequest>,
+) {
+    let requests = input.drain_rumble();
+    let Some(gamepad) = device_state.active_gamepad() else {
+        return;
+    };
+    for request in requests {
+        let Ok(duration) = Duration::try_from_secs_f32(request.duration) else {
+            continue;
+        };
+        rumble_requests.write(RumbleRequest::new(gamepad, request.intensity, duration));
+    }
+}
+
+/// Plugin for registering FFI types.
+pub(crate) fn register_ffi_types(app: &mut App) {
+    app.init_resource::<ScriptInput>();
+}
+
+/// Add FFI systems to the app.
+pub(crate) fn add_ffi_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        update_script_input.after(crate::actions::update_action_state::<GameAction>),
+    )
+    .add_systems(schedules.update, apply_script_rumble);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_ids_round_trip() {
+        let id = ScriptInput::action_id(GameAction::Primary);
+        assert_eq!(ScriptInput::action_id_by_name("Primary"), Some(id));
+        assert_eq!(ScriptInput::action_id_by_name("Nope"), None);
+        assert_eq!(archie_action_count() as usize, GameAction::all().len());
+    }
+
+    #[test]
+    fn test_snapshot_queries() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Confirm, true);
+        state.set_value(GameAction::Confirm, 1.0);
+        let mut input = ScriptInput::default();
+        input.refresh(&state);
+
+        let confirm = ScriptInput::action_id(GameAction::Confirm);
+        assert!(input.pressed(confirm));
+        assert!(input.just_pressed(confirm));
+        assert_eq!(input.value(confirm), 1.0);
+        assert!(!input.pressed(u32::MAX));
+    }
+
+    #[test]
+    fn test_c_abi() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Cancel, true);
+        let mut input = ScriptInput::default();
+        input.refresh(&state);
+
+        let id = unsafe { archie_action_id(c"Cancel".as_ptr()) };
+        let cancel = u32::try_from(id).unwrap();
+        let input_ptr: *mut ScriptInput = &raw mut input;
+        unsafe {
+            assert!(archie_pressed(input_ptr, cancel));
+            assert!(!archie_just_released(input_ptr, cancel));
+            assert!(!archie_pressed(std::ptr::null(), cancel));
+            archie_request_rumble(input_ptr, 2.0, 0.25);
+        }
+
+        let rumble = input.drain_rumble();
+        assert_eq!(
+            rumble,
+            vec![ScriptRumble {
+                intensity: 1.0,
+                duration: 0.25,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rumble_is_dropped() {
+        let mut input = ScriptInput::default();
+        input.request_rumble(f32::NAN, 0.25);
+        input.request_rumble(0.5, f32::INFINITY);
+        input.request_rumble(0.5, 1e30);
+        input.request_rumble(0.5, -1.0);
+
+        assert_eq!(
+            input.drain_rumble(),
+            vec![ScriptRumble {
+                intensity: 0.5,
+                duration: 0.0,
+            }]
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//! Controller-navigable file and save-slot picker.
+//!
+//! Games that need filename entry or save management can open a
+//! [`FilePicker`] instead of falling back to mouse-only OS dialogs. The
+//! picker lists the files in a directory, moves its selection with the
+//! Up/Down actions (with held repeat), and in save mode offers a "new file"
+//! row that opens the virtual keyboard for the name. Rendering is left to the
+//! game: read [`FilePicker::rows`] and [`FilePicker::selected`] while
+//! [`FilePicker::is_open`] is true.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::file_picker::{FilePickerMode, FilePickerResult, OpenFilePicker};
+//!
+//! fn open_save_menu(mut open: MessageWriter<OpenFilePicker>) {
+//!     open.write(OpenFilePicker::new(FilePickerMode::Save, "saves").with_extension("sav"));
+//! }
+//!
+//! fn on_picked(mut results: MessageReader<FilePickerResult>) {
+//!     for result in results.read() {
+//!         if let FilePickerResult::Selected(path) = result {
+//!             info!("Saving to {}", path.display());
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use log::warn;
+use std::path::{Path, PathBuf};
+
+use crate::actions::{ActionState, GameAction};
+use crate::repeat::ActionRepeat;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::virtual_keyboard::{
+    ShowVirtualKeyboard, VirtualKeyboard, VirtualKeyboardEvent, VirtualKeyboardState,
+};
+
+/// Characters that can't appear in a file name.
+const INVALID_NAME_CHARS: &str = "/\\:*?\"<>|";
+
+/// Whether the picker loads or saves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilePickerMode {
+    /// Pick an existing file.
+    #[default]
+    Open,
+    /// Pick an existing file to overwrite or name a new one.
+    Save,
+}
+
+/// A file listed in the picker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PickerEntry {
+    /// Display name (file name without the extension).
+    pub name: String,
+    /// Full path to the file.
+    pub path: PathBuf,
+}
+
+/// A row shown in the picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerRow<'a> {
+    /// Create a new file (save mode only).
+    NewFile,
+    /// An existing file.
+    Entry(&'a PickerEntry),
+}
+
+/// List the files in a directory, optionally filtered by extension.
+///
+/// Entries are sorted by name.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn scan_directory(
+    directory: &Path,
+    extension: Option<&str>,
+) -> crate::Result<Vec<PickerEntry>> {
+    let read_dir = std::fs::read_dir(directory).map_err(|e| crate::Error::io(directory, e))?;
+
+    let mut entries: Vec<PickerEntry> = read_dir
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| extension.is_none_or(|ext| path.extension().is_some_and(|e| e == ext)))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().into_owned();
+            Some(PickerEntry { name, path })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// What confirming the selected row does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PickerConfirm {
+    /// A file was chosen.
+    Select(PathBuf),
+    /// The player needs to type a name.
+    EnterName,
+    /// Nothing to confirm.
+    Nothing,
+}
+
+/// Resource holding the open file picker.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct FilePicker {
+    /// Open or save.
+    pub mode: FilePickerMode,
+    /// Directory being listed.
+    pub directory: PathBuf,
+    /// Extension new files get and listed files must have.
+    pub extension: Option<String>,
+    /// Files in the directory.
+    pub entries: Vec<PickerEntry>,
+    /// Selected row index.
+    selected: usize,
+    /// Whether the picker is showing.
+    open: bool,
+    /// Whether the virtual keyboard is open for a new name.
+    naming: bool,
+}
+
+impl FilePicker {
+    /// Create a picker listing `entries`.
+    #[must_use]
+    pub fn new(
+        mode: FilePickerMode,
+        directory: impl Into<PathBuf>,
+        extension: Option<String>,
+        entries: Vec<PickerEntry>,
+    ) -> Self {
+        Self {
+            mode,
+            directory: directory.into(),
+            extension,
+            entries,
+            selected: 0,
+            open: true,
+            naming: false,
+        }
+    }
+
+    /// Whether the picker is showing.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Whether the player is typing a new file name.
+    #[must_use]
+    pub const fn is_naming(&self) -> bool {
+        self.naming
+    }
+
+    /// Selected row index.
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Rows to display, with the "new file" row first in save mode.
+    #[must_use]
+    pub fn rows(&self) -> Vec<PickerRow<'_>> {
+        let new_file = (self.mode == FilePickerMode::Save).then_some(PickerRow::NewFile);
+        new_file
+            .into_iter()
+            .chain(self.entries.iter().map(PickerRow::Entry))
+            .collect()
+    }
+
+    /// Move the selection by `delta` rows, clamped to the list.
+    ///
+    /// Returns false if the selection didn't move.
+    pub fn move_selection(&mut self, delta: isize) -> bool {
+        let last = self.rows().len().saturating_sub(1);
+        let target = self.selected.saturating_add_signed(delta).min(last);
+        let moved = target != self.selected;
+        self.selected = target;
+        moved
+    }
+
+    /// Confirm the selected row.
+    pub fn confirm(&mut self) -> PickerConfirm {
+        let result = match self.rows().get(self.selected) {
+            Some(PickerRow::NewFile) => PickerConfirm::EnterName,
+            Some(PickerRow::Entry(entry)) => PickerConfirm::Select(entry.path.clone()),
+            None => PickerConfirm::Nothing,
+        };
+        self.naming = result == PickerConfirm::EnterName;
+        result
+    }
+
+    /// Build the path for a typed file name.
+    ///
+    /// Returns `None` if the name is empty or contains path separators or
+    /// other characters that aren't valid in file names.
+    #[must_use]
+    pub fn path_for_name(&self, name: &str) -> Option<PathBuf> {
+        let name = name.trim();
+        if name.is_empty()
+            || name.starts_with('.')
+            || name.chars().any(|c| INVALID_NAME_CHARS.contains(c))
+        {
+            return None;
+        }
+
+        Some(match &self.extension {
+            Some(extension) => self.directory.join(format!("{name}.{extension}")),
+            None => self.directory.join(name),
+        })
+    }
+
+    /// Close the picker.
+    pub fn close(&mut self) {
+        self.open = false;
+        self.naming = false;
+    }
+}
+
+/// Event to open the file picker.
+#[derive(Debug, Clone, Message)]
+pub struct OpenFilePicker {
+    /// Open or save.
+    pub mode: FilePickerMode,
+    /// Directory to list.
+    pub directory: PathBuf,
+    /// Extension to filter by and give new files.
+    pub extension: Option<String>,
+}
+
+impl OpenFilePicker {
+    /// Open a picker on a directory.
+    #[must_use]
+    pub fn new(mode: FilePickerMode, directory: impl Into<PathBuf>) -> Self {
+        Self {
+            mode,
+            directory: directory.into(),
+            extension: None,
+        }
+    }
+
+    /// Only list files with this extension, and give new files it.
+    #[must_use]
+    pub fn with_extension(mut self, extension: impl Into<String>) -> Self {
+        self.extension = Some(extension.into());
+        self
+    }
+}
+
+/// Event fired when the picker closes.
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub enum FilePickerResult {
+    /// A file was chosen (it may not exist yet in save mode).
+    Selected(PathBuf),
+    /// The player backed out.
+    Cancelled,
+}
+
+/// System to open the file picker.
+pub fn handle_open_file_picker(
+    mut events: MessageReader<OpenFilePicker>,
+    mut picker: ResMut<FilePicker>,
+) {
+    for event in events.read() {
+        let entries =
+            scan_directory(&event.directory, event.extension.as_deref()).unwrap_or_else(|err| {
+                warn!("File picker could not list directory: {err}");
+                Vec::new()
+            });
+        *picker = FilePicker::new(
+            event.mode,
+            event.directory.clone(),
+            event.extension.clone(),
+            entries,
+        );
+    }
+}
+
+/// System to drive the file picker from actions.
+pub fn handle_file_picker_input(
+    mut picker: ResMut<FilePicker>,
+    action_state: Res<ActionState>,
+    repeat: Res<ActionRepeat>,
+    keyboard_state: Res<State<VirtualKeyboardState>>,
+    mut show_keyboard: MessageWriter<ShowVirtualKeyboard>,
+    mut results: MessageWriter<FilePickerResult>,
+) {
+    if !picker.is_open()
+        || picker.is_naming()
+        || *keyboard_state.get() != VirtualKeyboardState::Hidden
+    {
+        return;
+    }
+
+    if repeat.triggered(&action_state, GameAction::Up) {
+        picker.move_selection(-1);
+    }
+    if repeat.triggered(&action_state, GameAction::Down) {
+        picker.move_selection(1);
+    }
+
+    if action_state.just_pressed(GameAction::Confirm) {
+        match picker.confirm() {
+            PickerConfirm::Select(path) => {
+                picker.close();
+                results.write(FilePickerResult::Selected(path));
+            }
+            PickerConfirm::EnterName => {
+                show_keyboard.write(ShowVirtualKeyboard {
+                    keyboard: VirtualKeyboard::new("File name").with_exclude(INVALID_NAME_CHARS),
+                });
+            }
+            PickerConfirm::Nothing => {}
+        }
+    } else if action_state.just_pressed(GameAction::Cancel) {
+        picker.close();
+        results.write(FilePickerResult::Cancelled);
+    }
+}
+
+/// System to finish naming a new file from the virtual keyboard.
+pub fn handle_file_picker_naming(
+    mut picker: ResMut<FilePicker>,
+    mut keyboard_events: MessageReader<VirtualKeyboardEvent>,
+    mut results: MessageWriter<FilePickerResult>,
+) {
+    for event in keyboard_events.read() {
+        if !picker.is_naming() {
+            continue;
+        }
+
+        picker.naming = false;
+        if event.confirmed
+            && let Some(path) = picker.path_for_name(&event.value)
+        {
+            picker.close();
+            results.write(FilePickerResult::Selected(path));
+        }
+    }
+}
+
+/// Add file picker systems to the app.
+pub(crate) fn add_file_picker_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.init_resource::<FilePicker>()
+        .add_message::<OpenFilePicker>()
+        .add_message::<FilePickerResult>()
+        .add_systems(
+            schedules.update,
+            (
+                handle_open_file_picker,
+                handle_file_picker_input,
+                handle_file_picker_naming,
+            )
+                .chain()
+                .run_if(subsystem_enabled(Subsystem::FilePicker)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str) -> PickerEntry {
+        PickerEntry {
+            name: name.to_string(),
+            path: PathBuf::from(format!("saves/{name}.sav")),
+        }
+    }
+
+    #[test]
+    fn test_save_mode_has_new_file_row() {
+        let picker = FilePicker::new(FilePickerMode::Save, "saves", None, vec![entry("slot1")]);
+        let rows = picker.rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], PickerRow::NewFile);
+
+        let picker = FilePicker::new(FilePickerMode::Open, "saves", None, vec![entry("slot1")]);
+        assert_eq!(picker.rows().len(), 1);
+    }
+
+    #[test]
+    fn test_move_selection_clamps() {
+        let mut picker = FilePicker::new(
+            FilePickerMode::Open,
+            "saves",
+            None,
+            vec![entry("a"), entry("b")],
+        );
+        assert!(!picker.move_selection(-1));
+        assert!(picker.move_selection(1));
+        assert!(!picker.move_selection(1));
+        assert_eq!(picker.selected(), 1);
+    }
+
+    #[test]
+    fn test_confirm() {
+        let mut picker = FilePicker::new(FilePickerMode::Save, "saves", None, vec![entry("slot1")]);
+        assert_eq!(picker.confirm(), PickerConfirm::EnterName);
+        assert!(picker.is_naming());
+
+        picker.move_selection(1);
+        assert_eq!(
+            picker.confirm(),
+            PickerConfirm::Select(PathBuf::from("saves/slot1.sav"))
+        );
+
+        let mut empty = FilePicker::new(FilePickerMode::Open, "saves", None, Vec::new());
+        assert_eq!(empty.confirm(), PickerConfirm::Nothing);
+    }
+
+    #[test]
+    fn test_path_for_name() {
+        let picker = FilePicker::new(
+            FilePickerMode::Save,
+            "saves",
+            Some("sav".to_string()),
+            Vec::new(),
+        );
+        assert_eq!(
+            picker.path_for_name(" my game "),
+            Some(PathBuf::from("saves/my game.sav"))
+        );
+        assert_eq!(picker.path_for_name("   "), None);
+        assert_eq!(picker.path_for_name("../escape"), None);
+        assert_eq!(picker.path_for_name(".hidden"), None);
+        assert_eq!(
+            picker.path_for_name("v1.2"),
+            Some(PathBuf::from("saves/v1.2.sav"))
+        );
+    }
+
+    #[test]
+    fn test_scan_directory() {
+        let dir = std::env::temp_dir().join(format!("bevy_archie_picker_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.sav"), "").unwrap();
+        std::fs::write(dir.join("a.sav"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let entries = scan_directory(&dir, Some("sav")).unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+
+        assert_eq!(scan_directory(&dir, None).unwrap().len(), 3);
+        assert!(scan_directory(&dir.join("missing"), None).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
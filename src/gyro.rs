@@ -4,6 +4,10 @@
 //! like PS4/PS5 DualShock/DualSense and Switch Pro Controller.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::power::normal_power;
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 /// Gyroscope data from a gamepad.
 #[derive(Debug, Clone, Copy, Default, Component, Reflect)]
@@ -16,6 +20,12 @@ pub struct GyroData {
     pub roll: f32,
     /// Whether the data is valid/available.
     pub valid: bool,
+    /// Rotation (pitch, yaw, roll) in radians since the last frame,
+    /// integrated from every sensor sample.
+    ///
+    /// `None` when the source only provides rates; consumers then multiply
+    /// the rates by the frame time.
+    pub rotation: Option<Vec3>,
 }
 
 impl GyroData {
@@ -27,17 +37,36 @@ impl GyroData {
             yaw,
             roll,
             valid: true,
+            rotation: None,
         }
     }
 
     /// Set raw gyro values from a platform-specific source.
     ///
-    /// This marks the data as valid after setting values.
+    /// This marks the data as valid after setting values and clears any
+    /// integrated rotation.
     pub fn set_raw(&mut self, pitch: f32, yaw: f32, roll: f32) {
         self.pitch = pitch;
         self.yaw = yaw;
         self.roll = roll;
         self.valid = true;
+        self.rotation = None;
+    }
+
+    /// Set rates along with the rotation integrated over this frame's samples.
+    pub fn set_integrated(&mut self, pitch: f32, yaw: f32, roll: f32, rotation: Vec3) {
+        self.set_raw(pitch, yaw, roll);
+        self.rotation = Some(rotation);
+    }
+
+    /// Rotation (pitch, yaw, roll) in radians over a frame of `delta_secs`.
+    ///
+    /// Uses the integrated rotation when available, otherwise the current
+    /// rates times the frame time.
+    #[must_use]
+    pub fn frame_rotation(&self, delta_secs: f32) -> Vec3 {
+        self.rotation
+            .unwrap_or_else(|| Vec3::new(self.pitch, self.yaw, self.roll) * delta_secs)
     }
 
     /// Get the magnitude of rotation.
@@ -122,10 +151,31 @@ impl AccelData {
 }
 
 /// Configuration for gyro/accel calibration.
-#[derive(Debug, Clone, Resource)]
+///
+/// Gyro aim is tuned separately from stick sensitivity in
+/// [`ControllerConfig`](crate::config::ControllerConfig). Yaw and pitch
+/// sensitivities are percentages, so 100% turns the camera as far as the
+/// controller turned.
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MotionConfig {
     /// Gyro sensitivity multiplier.
     pub gyro_sensitivity: f32,
+    /// Horizontal (yaw) gyro sensitivity in percent.
+    pub yaw_sensitivity: f32,
+    /// Vertical (pitch) gyro sensitivity in percent.
+    pub pitch_sensitivity: f32,
+    /// Vertical sensitivity relative to horizontal (1.0 = same).
+    pub vertical_ratio: f32,
+    /// Invert horizontal gyro aim.
+    pub invert_yaw: bool,
+    /// Invert vertical gyro aim.
+    pub invert_pitch: bool,
+    /// Blend of roll into horizontal gyro aim (0.0 = yaw only, 1.0 = roll only).
+    ///
+    /// Roll steering turns by tilting the controller like a steering wheel,
+    /// with a positive roll turning the same way as a positive yaw.
+    pub roll_to_yaw: f32,
     /// Gyro deadzone (rad/s).
     pub gyro_deadzone: f32,
     /// Accelerometer sensitivity.
@@ -138,6 +188,12 @@ impl Default for MotionConfig {
     fn default() -> Self {
         Self {
             gyro_sensitivity: 1.0,
+            yaw_sensitivity: 100.0,
+            pitch_sensitivity: 100.0,
+            vertical_ratio: 1.0,
+            invert_yaw: false,
+            invert_pitch: false,
+            roll_to_yaw: 0.0,
             gyro_deadzone: 0.01,
             accel_sensitivity: 1.0,
             enabled: true,
@@ -145,6 +201,26 @@ impl Default for MotionConfig {
     }
 }
 
+impl MotionConfig {
+    /// Multipliers applied to gyro aim as (yaw, pitch), including inversion.
+    #[must_use]
+    pub fn aim_scale(&self) -> Vec2 {
+        let yaw = self.gyro_sensitivity * self.yaw_sensitivity / 100.0;
+        let pitch = self.gyro_sensitivity * self.pitch_sensitivity / 100.0 * self.vertical_ratio;
+        Vec2::new(
+            if self.invert_yaw { -yaw } else { yaw },
+            if self.invert_pitch { -pitch } else { pitch },
+        )
+    }
+
+    /// Horizontal gyro input blended from yaw and roll by [`Self::roll_to_yaw`].
+    #[must_use]
+    pub fn horizontal_rotation(&self, yaw: f32, roll: f32) -> f32 {
+        let blend = self.roll_to_yaw.clamp(0.0, 1.0);
+        yaw * (1.0 - blend) + roll * blend
+    }
+}
+
 /// Gesture detection thresholds.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MotionGesture {
@@ -169,6 +245,164 @@ pub struct MotionGestureDetected {
     pub intensity: f32,
 }
 
+/// Gyro aim output for a gamepad.
+///
+/// Add this to a gamepad entity to turn its [`GyroData`] into a per-frame
+/// aim rotation. When the game shakes the camera, report the shake through
+/// [`GyroAim::report_camera_rotation`] so the player's counter-motion isn't
+/// added on top of it.
+#[derive(Debug, Clone, Copy, Default, Component, Reflect)]
+pub struct GyroAim {
+    /// Aim rotation for this frame as (yaw, pitch) in radians.
+    pub delta: Vec2,
+    /// How much reported camera rotation to subtract (0.0 = off, 1.0 = full).
+    pub shake_compensation: f32,
+    /// Camera-driven rotation reported since the last update.
+    camera_rotation: Vec2,
+}
+
+impl GyroAim {
+    /// Create gyro aim with camera shake compensation.
+    #[must_use]
+    pub fn with_shake_compensation(strength: f32) -> Self {
+        Self {
+            shake_compensation: strength.clamp(0.0, 1.0),
+            ..Self::default()
+        }
+    }
+
+    /// Report camera rotation (yaw, pitch in radians) applied by the game.
+    ///
+    /// Reports accumulate until the next aim update consumes them.
+    pub fn report_camera_rotation(&mut self, yaw: f32, pitch: f32) {
+        self.camera_rotation += Vec2::new(yaw, pitch);
+    }
+
+    /// Camera rotation reported since the last update.
+    #[must_use]
+    pub const fn pending_camera_rotation(&self) -> Vec2 {
+        self.camera_rotation
+    }
+
+    /// Compute the aim rotation for a frame and consume reported camera rotation.
+    pub fn update(&mut self, gyro: &GyroData, config: &MotionConfig, delta_secs: f32) {
+        let raw = Vec2::new(config.horizontal_rotation(gyro.yaw, gyro.roll), gyro.pitch);
+        let gyro_delta = if !config.enabled || !gyro.valid || raw.length() < config.gyro_deadzone {
+            Vec2::ZERO
+        } else {
+            let rotation = gyro.frame_rotation(delta_secs);
+            Vec2::new(
+                config.horizontal_rotation(rotation.y, rotation.z),
+                rotation.x,
+            ) * config.aim_scale()
+        };
+
+        self.delta = gyro_delta - self.camera_rotation * self.shake_compensation;
+        self.camera_rotation = Vec2::ZERO;
+    }
+}
+
+/// How a controller is being held, judged from the direction of gravity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum ControllerGrip {
+    /// Held flat, face up; horizontal aim follows yaw.
+    #[default]
+    Flat,
+    /// Held upright, face toward the player; horizontal aim follows roll.
+    Upright,
+}
+
+impl ControllerGrip {
+    /// The [`MotionConfig::roll_to_yaw`] blend for this grip.
+    #[must_use]
+    pub const fn roll_to_yaw(self) -> f32 {
+        match self {
+            Self::Flat => 0.0,
+            Self::Upright => 1.0,
+        }
+    }
+}
+
+/// Event fired when a [`GripDetector`] switches grip.
+#[derive(Debug, Clone, Message)]
+pub struct ControllerGripChanged {
+    /// The gamepad whose grip changed.
+    pub gamepad: Entity,
+    /// The new grip.
+    pub grip: ControllerGrip,
+}
+
+/// Automatic gyro space selection from controller grip.
+///
+/// Add this next to [`GyroAim`] to pick yaw or roll for horizontal aim from
+/// how the controller is held, similar to `JoyShockMapper`'s world space. The
+/// tilt of smoothed gravity away from the controller's up axis decides the
+/// grip, and the gap between `upright_angle` and `flat_angle` keeps it from
+/// flickering near the boundary. While present it overrides
+/// [`MotionConfig::roll_to_yaw`] for this gamepad.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct GripDetector {
+    /// Current grip.
+    pub grip: ControllerGrip,
+    /// Tilt (degrees) above which a flat controller counts as upright.
+    pub upright_angle: f32,
+    /// Tilt (degrees) below which an upright controller counts as flat.
+    pub flat_angle: f32,
+    /// Time constant (seconds) of the gravity low-pass filter.
+    pub smoothing: f32,
+    /// Smoothed gravity direction.
+    gravity: Option<Vec3>,
+}
+
+impl Default for GripDetector {
+    fn default() -> Self {
+        Self {
+            grip: ControllerGrip::Flat,
+            upright_angle: 60.0,
+            flat_angle: 40.0,
+            smoothing: 0.2,
+            gravity: None,
+        }
+    }
+}
+
+impl GripDetector {
+    /// Smoothed tilt from flat in degrees, if any accelerometer data was seen.
+    #[must_use]
+    pub fn tilt_degrees(&self) -> Option<f32> {
+        let gravity = self.gravity?.try_normalize()?;
+        Some(gravity.y.abs().clamp(0.0, 1.0).acos().to_degrees())
+    }
+
+    /// Feed an accelerometer reading and return the new grip if it changed.
+    pub fn update(&mut self, accel: &AccelData, delta_secs: f32) -> Option<ControllerGrip> {
+        let reading = Vec3::new(accel.x, accel.y, accel.z);
+        if !accel.valid || reading.length_squared() <= f32::EPSILON {
+            return None;
+        }
+
+        self.gravity = Some(match self.gravity {
+            Some(gravity) if self.smoothing > 0.0 => {
+                let blend = 1.0 - (-delta_secs / self.smoothing).exp();
+                gravity.lerp(reading, blend)
+            }
+            _ => reading,
+        });
+
+        let tilt = self.tilt_degrees()?;
+        let grip = match self.grip {
+            ControllerGrip::Flat if tilt > self.upright_angle => ControllerGrip::Upright,
+            ControllerGrip::Upright if tilt < self.flat_angle => ControllerGrip::Flat,
+            grip => grip,
+        };
+
+        (grip != self.grip).then(|| {
+            self.grip = grip;
+            grip
+        })
+    }
+}
+
 /// System to update gyro data.
 ///
 /// # Platform Support
@@ -260,19 +494,66 @@ pub fn detect_motion_gestures(
     }
 }
 
+/// System to detect controller grip from accelerometer data.
+pub fn detect_controller_grip(
+    mut gamepads: Query<(Entity, &AccelData, &mut GripDetector)>,
+    time: Res<Time>,
+    mut grip_events: MessageWriter<ControllerGripChanged>,
+) {
+    for (entity, accel, mut detector) in &mut gamepads {
+        if let Some(grip) = detector.update(accel, time.delta_secs()) {
+            grip_events.write(ControllerGripChanged {
+                gamepad: entity,
+                grip,
+            });
+        }
+    }
+}
+
+/// System to update gyro aim from gyro data.
+pub fn update_gyro_aim(
+    mut gamepads: Query<(&GyroData, &mut GyroAim, Option<&GripDetector>)>,
+    config: Res<MotionConfig>,
+    time: Res<Time>,
+) {
+    for (gyro, mut aim, detector) in &mut gamepads {
+        if let Some(detector) = detector {
+            let config = MotionConfig {
+                roll_to_yaw: detector.grip.roll_to_yaw(),
+                ..config.clone()
+            };
+            aim.update(gyro, &config, time.delta_secs());
+        } else {
+            aim.update(gyro, &config, time.delta_secs());
+        }
+    }
+}
+
 /// Plugin for registering gyro types.
 pub(crate) fn register_gyro_types(app: &mut App) {
     app.register_type::<GyroData>()
         .register_type::<AccelData>()
+        .register_type::<GyroAim>()
+        .register_type::<GripDetector>()
         .init_resource::<MotionConfig>()
-        .add_message::<MotionGestureDetected>();
+        .add_message::<MotionGestureDetected>()
+        .add_message::<ControllerGripChanged>();
 }
 
 /// Add gyro systems to the app.
-pub(crate) fn add_gyro_systems(app: &mut App) {
+pub(crate) fn add_gyro_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
     app.add_systems(
-        Update,
-        (update_gyro_data, update_accel_data, detect_motion_gestures).chain(),
+        schedules.update,
+        (
+            update_gyro_data,
+            update_accel_data,
+            detect_motion_gestures,
+            detect_controller_grip,
+            update_gyro_aim,
+        )
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::Gyro))
+            .run_if(normal_power),
     );
 }
 
@@ -360,6 +641,62 @@ mod tests {
         assert!(config.enabled);
     }
 
+    #[test]
+    fn test_motion_config_per_axis_aim_scale() {
+        let mut config = MotionConfig::default();
+        assert_eq!(config.aim_scale(), Vec2::ONE);
+
+        config.gyro_sensitivity = 2.0;
+        config.yaw_sensitivity = 150.0;
+        config.pitch_sensitivity = 50.0;
+        config.vertical_ratio = 0.5;
+        config.invert_pitch = true;
+        let scale = config.aim_scale();
+        assert_relative_eq!(scale.x, 3.0);
+        assert_relative_eq!(scale.y, -0.5);
+    }
+
+    #[test]
+    fn test_motion_config_serde_round_trip() {
+        let config = MotionConfig {
+            yaw_sensitivity: 80.0,
+            invert_yaw: true,
+            ..MotionConfig::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let loaded: MotionConfig = serde_json::from_str(&json).unwrap();
+        assert_relative_eq!(loaded.yaw_sensitivity, 80.0);
+        assert!(loaded.invert_yaw);
+
+        let partial: MotionConfig = serde_json::from_str(r#"{"gyro_sensitivity":1.5}"#).unwrap();
+        assert_relative_eq!(partial.gyro_sensitivity, 1.5);
+        assert_relative_eq!(partial.pitch_sensitivity, 100.0);
+    }
+
+    #[test]
+    fn test_motion_config_roll_to_yaw_blend() {
+        let mut config = MotionConfig::default();
+        assert_relative_eq!(config.horizontal_rotation(1.0, 2.0), 1.0);
+
+        config.roll_to_yaw = 1.0;
+        assert_relative_eq!(config.horizontal_rotation(1.0, 2.0), 2.0);
+
+        config.roll_to_yaw = 0.25;
+        assert_relative_eq!(config.horizontal_rotation(1.0, 2.0), 1.25);
+    }
+
+    #[test]
+    fn test_gyro_aim_roll_steering() {
+        let config = MotionConfig {
+            roll_to_yaw: 1.0,
+            ..MotionConfig::default()
+        };
+        let mut aim = GyroAim::default();
+        aim.update(&GyroData::new(0.0, 1.0, 0.5), &config, 1.0);
+        assert_relative_eq!(aim.delta.x, 0.5);
+        assert_relative_eq!(aim.delta.y, 0.0);
+    }
+
     #[test]
     fn test_motion_gesture_detected_event() {
         let gamepad = Entity::from_bits(77);
@@ -428,6 +765,7 @@ mod tests {
             gyro_deadzone: 0.05,
             accel_sensitivity: 1.5,
             enabled: false,
+            ..MotionConfig::default()
         };
 
         assert_relative_eq!(config.gyro_sensitivity, 2.0);
@@ -473,4 +811,134 @@ mod tests {
 
         assert_ne!(flick.gesture, shake.gesture);
     }
+
+    #[test]
+    fn test_gyro_aim_follows_gyro() {
+        let mut aim = GyroAim::default();
+        let gyro = GyroData::new(0.5, 1.0, 0.0);
+        aim.update(&gyro, &MotionConfig::default(), 0.5);
+        assert_relative_eq!(aim.delta.x, 0.5);
+        assert_relative_eq!(aim.delta.y, 0.25);
+    }
+
+    #[test]
+    fn test_gyro_aim_subtracts_camera_shake() {
+        let mut aim = GyroAim::with_shake_compensation(1.0);
+        let gyro = GyroData::new(0.0, 1.0, 0.0);
+
+        aim.report_camera_rotation(0.2, 0.1);
+        aim.report_camera_rotation(0.1, 0.0);
+        aim.update(&gyro, &MotionConfig::default(), 0.5);
+        assert_relative_eq!(aim.delta.x, 0.2);
+        assert_relative_eq!(aim.delta.y, -0.1);
+        assert_eq!(aim.pending_camera_rotation(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_gyro_aim_without_compensation_ignores_shake() {
+        let mut aim = GyroAim::default();
+        let gyro = GyroData::new(0.0, 1.0, 0.0);
+
+        aim.report_camera_rotation(0.2, 0.1);
+        aim.update(&gyro, &MotionConfig::default(), 0.5);
+        assert_relative_eq!(aim.delta.x, 0.5);
+        assert_relative_eq!(aim.delta.y, 0.0);
+    }
+
+    #[test]
+    fn test_gyro_aim_deadzone() {
+        let mut aim = GyroAim::default();
+        let gyro = GyroData::new(0.001, 0.001, 0.0);
+        aim.update(&gyro, &MotionConfig::default(), 1.0);
+        assert_eq!(aim.delta, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_gyro_aim_prefers_integrated_rotation() {
+        let config = MotionConfig::default();
+        let mut gyro = GyroData::default();
+        gyro.set_integrated(0.0, 1.0, 0.0, Vec3::new(0.0, 0.05, 0.0));
+
+        let mut aim = GyroAim::default();
+        aim.update(&gyro, &config, 1.0);
+        assert_eq!(aim.delta, Vec2::new(0.05, 0.0));
+
+        gyro.set_raw(0.0, 1.0, 0.0);
+        assert!(gyro.rotation.is_none());
+        aim.update(&gyro, &config, 0.5);
+        assert_eq!(aim.delta, Vec2::new(0.5, 0.0));
+    }
+
+    #[test]
+    fn test_grip_detector_switches_with_hysteresis() {
+        let mut detector = GripDetector {
+            smoothing: 0.0,
+            ..GripDetector::default()
+        };
+        assert_eq!(detector.update(&AccelData::new(0.0, 9.8, 0.0), 0.1), None);
+        assert_eq!(detector.grip, ControllerGrip::Flat);
+
+        // 50 degrees of tilt stays flat.
+        let (sin, cos) = 50f32.to_radians().sin_cos();
+        assert_eq!(
+            detector.update(&AccelData::new(0.0, cos * 9.8, sin * 9.8), 0.1),
+            None
+        );
+
+        assert_eq!(
+            detector.update(&AccelData::new(0.0, 0.0, 9.8), 0.1),
+            Some(ControllerGrip::Upright)
+        );
+
+        // Back to 50 degrees stays upright until below the flat angle.
+        assert_eq!(
+            detector.update(&AccelData::new(0.0, cos * 9.8, sin * 9.8), 0.1),
+            None
+        );
+        assert_eq!(
+            detector.update(&AccelData::new(0.0, 9.8, 1.0), 0.1),
+            Some(ControllerGrip::Flat)
+        );
+    }
+
+    #[test]
+    fn test_grip_detector_smooths_brief_spikes() {
+        let mut detector = GripDetector::default();
+        detector.update(&AccelData::new(0.0, 9.8, 0.0), 0.016);
+        assert_eq!(detector.update(&AccelData::new(0.0, 0.0, 9.8), 0.016), None);
+        assert!(detector.tilt_degrees().unwrap() < 40.0);
+    }
+
+    #[test]
+    fn test_grip_detector_ignores_invalid_accel() {
+        let mut detector = GripDetector::default();
+        assert_eq!(detector.update(&AccelData::default(), 0.1), None);
+        assert!(detector.tilt_degrees().is_none());
+    }
+
+    #[test]
+    fn test_upright_grip_aims_with_roll() {
+        let mut app = App::new();
+        app.insert_resource(MotionConfig::default())
+            .init_resource::<Time>()
+            .add_systems(Update, update_gyro_aim);
+
+        let mut gyro = GyroData::default();
+        gyro.set_integrated(0.0, 0.2, 0.5, Vec3::new(0.0, 0.2, 0.5));
+        let gamepad = app
+            .world_mut()
+            .spawn((
+                gyro,
+                GyroAim::default(),
+                GripDetector {
+                    grip: ControllerGrip::Upright,
+                    ..GripDetector::default()
+                },
+            ))
+            .id();
+
+        app.update();
+        let aim = app.world().get::<GyroAim>(gamepad).unwrap();
+        assert_relative_eq!(aim.delta.x, 0.5);
+    }
 }
@@ -2,18 +2,56 @@
 //!
 //! This module provides vibration/rumble functionality for gamepads,
 //! including simple rumble, complex patterns, and `DualSense` advanced haptics.
+//!
+//! Common feedback can be wired as data with [`HapticBindings`], which maps
+//! actions and named [`HapticEvent`]s to rumble patterns.
+//!
+//! Every rumble source on a gamepad is mixed into its [`RumbleOutput`], which
+//! drives the motors through Bevy's [`GamepadRumbleRequest`] (played by
+//! `bevy_gilrs`).
 
 use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
+use crate::actions::{GameAction, GamepadActionState};
+use crate::config::ControllerConfig;
+use crate::detection::InputDeviceState;
+use crate::multiplayer::ControllerOwnership;
+use crate::player_settings::PlayerSettingsStore;
+use crate::rumble_asset::RumbleAsset;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
 /// Rumble intensity for motors.
+///
+/// Xbox One and Series controllers also have a motor in each trigger
+/// ("impulse triggers"). The trigger channels stay at 0.0 unless set with
+/// [`with_triggers`](Self::with_triggers), and only reach the hardware
+/// through a [`TriggerRumbleBackend`](crate::motion::TriggerRumbleBackend);
+/// other gamepads ignore them.
 #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
 pub struct RumbleIntensity {
     /// Low-frequency motor (0.0-1.0)
     pub low_frequency: f32,
     /// High-frequency motor (0.0-1.0)
     pub high_frequency: f32,
+    /// Left trigger motor (0.0-1.0)
+    pub left_trigger: f32,
+    /// Right trigger motor (0.0-1.0)
+    pub right_trigger: f32,
+}
+
+/// Clamp a motor value to 0.0-1.0.
+const fn clamp_motor(value: f32) -> f32 {
+    if value < 0.0 {
+        0.0
+    } else if value > 1.0 {
+        1.0
+    } else {
+        value
+    }
 }
 
 impl RumbleIntensity {
@@ -21,37 +59,17 @@ impl RumbleIntensity {
     #[must_use]
     pub const fn new(low: f32, high: f32) -> Self {
         Self {
-            low_frequency: if low < 0.0 {
-                0.0
-            } else if low > 1.0 {
-                1.0
-            } else {
-                low
-            },
-            high_frequency: if high < 0.0 {
-                0.0
-            } else if high > 1.0 {
-                1.0
-            } else {
-                high
-            },
+            low_frequency: clamp_motor(low),
+            high_frequency: clamp_motor(high),
+            left_trigger: 0.0,
+            right_trigger: 0.0,
         }
     }
 
     /// Create a uniform rumble (both motors same intensity).
     #[must_use]
     pub const fn uniform(intensity: f32) -> Self {
-        let clamped = if intensity < 0.0 {
-            0.0
-        } else if intensity > 1.0 {
-            1.0
-        } else {
-            intensity
-        };
-        Self {
-            low_frequency: clamped,
-            high_frequency: clamped,
-        }
+        Self::new(intensity, intensity)
     }
 
     /// No rumble.
@@ -59,10 +77,142 @@ impl RumbleIntensity {
     pub fn none() -> Self {
         Self::default()
     }
+
+    /// Set the trigger motors, clamping to 0.0-1.0.
+    #[must_use]
+    pub const fn with_triggers(mut self, left: f32, right: f32) -> Self {
+        self.left_trigger = clamp_motor(left);
+        self.right_trigger = clamp_motor(right);
+        self
+    }
+
+    /// Check if either trigger motor is running.
+    #[must_use]
+    pub const fn has_triggers(&self) -> bool {
+        self.left_trigger > 0.0 || self.right_trigger > 0.0
+    }
+
+    /// Scale every motor, clamping to 0.0-1.0.
+    #[must_use]
+    pub const fn scaled(self, scale: f32) -> Self {
+        Self::new(self.low_frequency * scale, self.high_frequency * scale)
+            .with_triggers(self.left_trigger * scale, self.right_trigger * scale)
+    }
+
+    /// Mix with another intensity, keeping the strongest value per motor.
+    #[must_use]
+    pub const fn mix(self, other: Self) -> Self {
+        Self {
+            low_frequency: self.low_frequency.max(other.low_frequency),
+            high_frequency: self.high_frequency.max(other.high_frequency),
+            left_trigger: self.left_trigger.max(other.left_trigger),
+            right_trigger: self.right_trigger.max(other.right_trigger),
+        }
+    }
+
+    /// Check if any motor differs from another intensity by more than
+    /// `epsilon`.
+    fn differs(&self, other: &Self, epsilon: f32) -> bool {
+        (self.low_frequency - other.low_frequency).abs() > epsilon
+            || (self.high_frequency - other.high_frequency).abs() > epsilon
+            || (self.left_trigger - other.left_trigger).abs() > epsilon
+            || (self.right_trigger - other.right_trigger).abs() > epsilon
+    }
+}
+
+/// Rumble strength multiplier for a gamepad.
+///
+/// Kept up to date from [`ControllerConfig`] vibration settings and the
+/// vibration preferences of the player who owns the gamepad. Every rumble
+/// sent to the gamepad is scaled by it.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[require(RumbleOutput)]
+pub struct RumbleScale(pub f32);
+
+impl Default for RumbleScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// How long each rumble sent to Bevy lasts before it must be refreshed.
+const RUMBLE_OUTPUT_DURATION: Duration = Duration::from_millis(250);
+
+/// How often unchanged rumble is re-sent, ahead of it running out.
+const RUMBLE_OUTPUT_REFRESH: f32 = 0.2;
+
+/// Smallest motor change worth re-sending.
+const RUMBLE_OUTPUT_EPSILON: f32 = 0.01;
+
+/// Motor output of a gamepad.
+///
+/// Rumble controllers, haptic timelines and vehicle haptics each
+/// [`request`](Self::request) an intensity every frame, and the strongest
+/// value per motor wins. Bevy adds overlapping rumble requests together, so
+/// [`send_rumble_output`] replaces the playing rumble only when the mixed
+/// output changes, rather than adding a new one each frame. Stop the motors
+/// with [`stop`](Self::stop) rather than a [`GamepadRumbleRequest::Stop`],
+/// so the output knows they stopped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct RumbleOutput {
+    requested: RumbleIntensity,
+    playing: RumbleIntensity,
+    since_sent: f32,
+    stopped: bool,
+}
+
+impl RumbleOutput {
+    /// Request an intensity for this frame, mixed with other sources.
+    pub const fn request(&mut self, intensity: RumbleIntensity) {
+        self.requested = self.requested.mix(intensity);
+    }
+
+    /// Intensity requested so far this frame, before scaling.
+    #[must_use]
+    pub const fn requested(&self) -> RumbleIntensity {
+        self.requested
+    }
+
+    /// Intensity the motors were last set to, after scaling.
+    #[must_use]
+    pub const fn playing(&self) -> RumbleIntensity {
+        self.playing
+    }
+
+    /// Stop the motors this frame, overriding every request.
+    pub const fn stop(&mut self) {
+        self.stopped = true;
+    }
+
+    /// Finish the frame, returning the intensity to send if the motors need
+    /// updating.
+    fn take_update(&mut self, scale: f32, delta: f32) -> Option<RumbleIntensity> {
+        let requested = std::mem::take(&mut self.requested);
+        let target = if std::mem::take(&mut self.stopped) {
+            RumbleIntensity::none()
+        } else {
+            requested.scaled(scale)
+        };
+        self.since_sent += delta;
+
+        let changed = target.differs(&self.playing, RUMBLE_OUTPUT_EPSILON)
+            || (target == RumbleIntensity::none()) != (self.playing == RumbleIntensity::none());
+        let expiring =
+            self.playing != RumbleIntensity::none() && self.since_sent >= RUMBLE_OUTPUT_REFRESH;
+        if !changed && !expiring {
+            return None;
+        }
+
+        self.playing = target;
+        self.since_sent = 0.0;
+        Some(target)
+    }
 }
 
 /// Predefined rumble patterns.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 pub enum RumblePattern {
     /// Constant rumble.
     Constant,
@@ -80,10 +230,177 @@ pub enum RumblePattern {
     Heartbeat,
 }
 
+/// Easing applied between two [`RumbleKeyframe`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Serialize, Deserialize)]
+pub enum RumbleEasing {
+    /// Straight line between the keyframes.
+    #[default]
+    Linear,
+    /// Hold the previous value, then jump at the keyframe.
+    Step,
+    /// Start slowly and speed up.
+    EaseIn,
+    /// Start quickly and slow down.
+    EaseOut,
+    /// Slow at both ends.
+    EaseInOut,
+}
+
+impl RumbleEasing {
+    /// Map progress between two keyframes (0.0-1.0) to a blend factor.
+    #[must_use]
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::Step => {
+                if t < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut => t * t * 2.0f32.mul_add(-t, 3.0),
+        }
+    }
+}
+
+/// Motor values at a point on a [`RumbleCurve`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct RumbleKeyframe {
+    /// Seconds from the start of the curve.
+    pub time: f32,
+    /// Low-frequency motor (0.0-1.0).
+    pub low: f32,
+    /// High-frequency motor (0.0-1.0).
+    pub high: f32,
+    /// Easing from the previous keyframe into this one.
+    #[serde(default)]
+    pub easing: RumbleEasing,
+}
+
+impl RumbleKeyframe {
+    /// Create a keyframe reached linearly from the previous one.
+    #[must_use]
+    pub const fn new(time: f32, low: f32, high: f32) -> Self {
+        Self {
+            time,
+            low,
+            high,
+            easing: RumbleEasing::Linear,
+        }
+    }
+
+    /// Set the easing from the previous keyframe.
+    #[must_use]
+    pub const fn with_easing(mut self, easing: RumbleEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The keyframe's motor values.
+    #[must_use]
+    pub const fn intensity(&self) -> RumbleIntensity {
+        RumbleIntensity::new(self.low, self.high)
+    }
+}
+
+/// A keyframed rumble envelope.
+///
+/// Unlike the fixed [`RumblePattern`]s, curves let designers author any
+/// shape: each keyframe sets both motors at a time, and the motors blend
+/// between keyframes using the later keyframe's easing.
+///
+/// ```rust
+/// use bevy_archie::haptics::{RumbleCurve, RumbleEasing, RumbleKeyframe};
+///
+/// // A sharp kick that fades out over half a second
+/// let kick = RumbleCurve::new()
+///     .with_key(0.0, 1.0, 0.6)
+///     .with_keyframe(RumbleKeyframe::new(0.5, 0.0, 0.0).with_easing(RumbleEasing::EaseOut));
+/// assert_eq!(kick.duration().as_secs_f32(), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Reflect, Serialize, Deserialize)]
+pub struct RumbleCurve {
+    /// Keyframes, sorted by time.
+    pub keyframes: Vec<RumbleKeyframe>,
+}
+
+impl RumbleCurve {
+    /// Create an empty curve.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+        }
+    }
+
+    /// Add a keyframe reached linearly from the previous one.
+    #[must_use]
+    pub fn with_key(self, time: f32, low: f32, high: f32) -> Self {
+        self.with_keyframe(RumbleKeyframe::new(time, low, high))
+    }
+
+    /// Add a keyframe (builder style).
+    #[must_use]
+    pub fn with_keyframe(mut self, keyframe: RumbleKeyframe) -> Self {
+        self.add(keyframe);
+        self
+    }
+
+    /// Add a keyframe, keeping keyframes sorted by time.
+    pub fn add(&mut self, keyframe: RumbleKeyframe) {
+        let index = self
+            .keyframes
+            .partition_point(|existing| existing.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+
+    /// Time of the last keyframe.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.keyframes.last().map_or(Duration::ZERO, |last| {
+            Duration::from_secs_f32(last.time.max(0.0))
+        })
+    }
+
+    /// Motor values at a time, holding the first and last keyframes outside
+    /// the curve.
+    #[must_use]
+    pub fn sample(&self, time: f32) -> RumbleIntensity {
+        let Some(first) = self.keyframes.first() else {
+            return RumbleIntensity::none();
+        };
+        if time <= first.time {
+            return first.intensity();
+        }
+
+        for pair in self.keyframes.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            if time < to.time {
+                let span = (to.time - from.time).max(f32::EPSILON);
+                let blend = to.easing.ease((time - from.time) / span);
+                return RumbleIntensity::new(
+                    (to.low - from.low).mul_add(blend, from.low),
+                    (to.high - from.high).mul_add(blend, from.high),
+                );
+            }
+        }
+
+        self.keyframes
+            .last()
+            .map_or_else(RumbleIntensity::none, RumbleKeyframe::intensity)
+    }
+}
+
 /// Component for controlling gamepad rumble.
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
 pub struct RumbleController {
     /// Target gamepad entity.
+    #[entities]
     pub gamepad: Entity,
     /// Current intensity.
     pub intensity: RumbleIntensity,
@@ -91,7 +408,13 @@ pub struct RumbleController {
     pub duration: Duration,
     /// Pattern being played.
     pub pattern: Option<RumblePattern>,
-    /// Pattern timer for pulse effects.
+    /// Curve being played; replaces the pattern when set.
+    ///
+    /// The curve repeats if the rumble lasts longer than it.
+    pub curve: Option<RumbleCurve>,
+    /// Rumble asset waiting to load; it starts playing once loaded.
+    pub asset: Option<Handle<RumbleAsset>>,
+    /// Time since the rumble started, for patterns and curves.
     pub pattern_timer: f32,
 }
 
@@ -104,27 +427,40 @@ impl RumbleController {
             intensity: RumbleIntensity::none(),
             duration: Duration::ZERO,
             pattern: None,
+            curve: None,
+            asset: None,
             pattern_timer: 0.0,
         }
     }
 
     /// Start a simple rumble.
-    pub const fn rumble(&mut self, intensity: RumbleIntensity, duration: Duration) {
+    pub fn rumble(&mut self, intensity: RumbleIntensity, duration: Duration) {
         self.intensity = intensity;
         self.duration = duration;
         self.pattern = Some(RumblePattern::Constant);
+        self.curve = None;
+        self.asset = None;
     }
 
     /// Start a rumble with pattern.
-    pub const fn rumble_pattern(
-        &mut self,
-        pattern: RumblePattern,
-        intensity: f32,
-        duration: Duration,
-    ) {
+    pub fn rumble_pattern(&mut self, pattern: RumblePattern, intensity: f32, duration: Duration) {
         self.intensity = RumbleIntensity::uniform(intensity);
         self.duration = duration;
         self.pattern = Some(pattern);
+        self.curve = None;
+        self.asset = None;
+        self.pattern_timer = 0.0;
+    }
+
+    /// Start a rumble that follows a curve, scaled by `intensity`.
+    ///
+    /// The rumble lasts as long as the curve.
+    pub fn rumble_curve(&mut self, curve: RumbleCurve, intensity: f32) {
+        self.intensity = RumbleIntensity::uniform(intensity);
+        self.duration = curve.duration();
+        self.pattern = None;
+        self.curve = Some(curve);
+        self.asset = None;
         self.pattern_timer = 0.0;
     }
 
@@ -133,6 +469,8 @@ impl RumbleController {
         self.intensity = RumbleIntensity::none();
         self.duration = Duration::ZERO;
         self.pattern = None;
+        self.curve = None;
+        self.asset = None;
     }
 }
 
@@ -147,6 +485,11 @@ pub struct RumbleRequest {
     pub duration: Duration,
     /// Optional pattern.
     pub pattern: Option<RumblePattern>,
+    /// Optional curve; replaces the pattern when set.
+    pub curve: Option<RumbleCurve>,
+    /// Optional rumble asset; replaces the intensity, duration, pattern and
+    /// curve once loaded. See [`RumbleAssetPlugin`](crate::rumble_asset::RumbleAssetPlugin).
+    pub asset: Option<Handle<RumbleAsset>>,
 }
 
 impl RumbleRequest {
@@ -158,6 +501,8 @@ impl RumbleRequest {
             intensity: RumbleIntensity::uniform(intensity),
             duration,
             pattern: Some(RumblePattern::Constant),
+            curve: None,
+            asset: None,
         }
     }
 
@@ -174,6 +519,184 @@ impl RumbleRequest {
             intensity: RumbleIntensity::uniform(intensity),
             duration,
             pattern: Some(pattern),
+            curve: None,
+            asset: None,
+        }
+    }
+
+    /// Create a rumble that follows a curve, scaled by `intensity`.
+    ///
+    /// The rumble lasts as long as the curve.
+    #[must_use]
+    pub fn with_curve(gamepad: Entity, curve: RumbleCurve, intensity: f32) -> Self {
+        Self {
+            gamepad,
+            intensity: RumbleIntensity::uniform(intensity),
+            duration: curve.duration(),
+            pattern: None,
+            curve: Some(curve),
+            asset: None,
+        }
+    }
+
+    /// Create a rumble that plays a [`RumbleAsset`] once it has loaded.
+    #[must_use]
+    pub const fn from_asset(gamepad: Entity, asset: Handle<RumbleAsset>) -> Self {
+        Self {
+            gamepad,
+            intensity: RumbleIntensity::uniform(1.0),
+            duration: Duration::ZERO,
+            pattern: None,
+            curve: None,
+            asset: Some(asset),
+        }
+    }
+}
+
+/// A rumble played in response to an action or event.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct HapticFeedback {
+    /// Pattern to play.
+    pub pattern: RumblePattern,
+    /// Intensity (0.0-1.0).
+    pub intensity: f32,
+    /// How long the rumble lasts.
+    pub duration: Duration,
+}
+
+impl HapticFeedback {
+    /// Create feedback from a pattern.
+    #[must_use]
+    pub const fn new(pattern: RumblePattern, intensity: f32, duration: Duration) -> Self {
+        Self {
+            pattern,
+            intensity,
+            duration,
+        }
+    }
+
+    /// A short, light tick (jumps, menu confirms).
+    #[must_use]
+    pub const fn light_tick() -> Self {
+        Self::new(RumblePattern::DamageTap, 0.3, Duration::from_millis(60))
+    }
+
+    /// A heavy hit (taking damage, landing hard).
+    #[must_use]
+    pub const fn heavy_impact() -> Self {
+        Self::new(RumblePattern::HeavyImpact, 1.0, Duration::from_millis(500))
+    }
+
+    /// Build a rumble request for a gamepad.
+    #[must_use]
+    pub const fn request(&self, gamepad: Entity) -> RumbleRequest {
+        RumbleRequest::with_pattern(gamepad, self.pattern, self.intensity, self.duration)
+    }
+}
+
+/// Resource mapping actions and named events to rumble.
+///
+/// Empty by default. Action feedback plays on the gamepad that pressed the
+/// action; event feedback plays on the event's gamepad, or the active one.
+#[derive(Debug, Clone, Default, Resource, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct HapticBindings {
+    /// Feedback played when an action is pressed.
+    pub actions: HashMap<GameAction, HapticFeedback>,
+    /// Feedback played when a [`HapticEvent`] with a name is sent.
+    pub events: HashMap<String, HapticFeedback>,
+}
+
+impl HapticBindings {
+    /// Play feedback when an action is pressed.
+    #[must_use]
+    pub fn with_action(mut self, action: GameAction, feedback: HapticFeedback) -> Self {
+        self.bind_action(action, feedback);
+        self
+    }
+
+    /// Play feedback when a named event is sent.
+    #[must_use]
+    pub fn with_event(mut self, name: impl Into<String>, feedback: HapticFeedback) -> Self {
+        self.bind_event(name, feedback);
+        self
+    }
+
+    /// Play feedback when an action is pressed.
+    pub fn bind_action(&mut self, action: GameAction, feedback: HapticFeedback) {
+        self.actions.insert(action, feedback);
+    }
+
+    /// Play feedback when a named event is sent.
+    pub fn bind_event(&mut self, name: impl Into<String>, feedback: HapticFeedback) {
+        self.events.insert(name.into(), feedback);
+    }
+
+    /// Feedback bound to an action.
+    #[must_use]
+    pub fn action_feedback(&self, action: GameAction) -> Option<&HapticFeedback> {
+        self.actions.get(&action)
+    }
+
+    /// Feedback bound to a named event.
+    #[must_use]
+    pub fn event_feedback(&self, name: &str) -> Option<&HapticFeedback> {
+        self.events.get(name)
+    }
+}
+
+/// Named game event that plays its [`HapticBindings`] feedback.
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct HapticEvent {
+    /// Binding name, e.g. `"TakeDamage"`.
+    pub name: String,
+    /// Gamepad to rumble; the active gamepad if `None`.
+    pub gamepad: Option<Entity>,
+}
+
+impl HapticEvent {
+    /// Create an event for the active gamepad.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            gamepad: None,
+        }
+    }
+
+    /// Target a specific gamepad.
+    #[must_use]
+    pub const fn for_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+}
+
+/// System to turn bound actions and events into rumble requests.
+pub fn play_haptic_bindings(
+    bindings: Res<HapticBindings>,
+    device_state: Res<InputDeviceState>,
+    gamepads: Query<(Entity, &GamepadActionState)>,
+    mut events: MessageReader<HapticEvent>,
+    mut requests: MessageWriter<RumbleRequest>,
+) {
+    for event in events.read() {
+        if let Some(feedback) = bindings.event_feedback(&event.name)
+            && let Some(gamepad) = event.gamepad.or_else(|| device_state.active_gamepad())
+        {
+            requests.write(feedback.request(gamepad));
+        }
+    }
+
+    if bindings.actions.is_empty() {
+        return;
+    }
+
+    for (gamepad, state) in &gamepads {
+        for (action, feedback) in &bindings.actions {
+            if state.actions().just_pressed(*action) {
+                requests.write(feedback.request(gamepad));
+            }
         }
     }
 }
@@ -196,21 +719,53 @@ pub fn handle_rumble_requests(
             controller.intensity = request.intensity;
             controller.duration = request.duration;
             controller.pattern = request.pattern;
+            controller.curve.clone_from(&request.curve);
+            controller.asset.clone_from(&request.asset);
             controller.pattern_timer = 0.0;
         } else {
             let mut controller = RumbleController::new(request.gamepad);
             controller.intensity = request.intensity;
             controller.duration = request.duration;
             controller.pattern = request.pattern;
+            controller.curve.clone_from(&request.curve);
+            controller.asset.clone_from(&request.asset);
             commands.entity(request.gamepad).insert(controller);
         }
     }
 }
 
+/// System to keep each gamepad's [`RumbleScale`] in line with the global
+/// and per-player vibration settings.
+pub fn update_rumble_scales(
+    mut commands: Commands,
+    config: Res<ControllerConfig>,
+    ownership: Res<ControllerOwnership>,
+    players: Res<PlayerSettingsStore>,
+    mut gamepads: Query<(Entity, Option<&mut RumbleScale>), With<Gamepad>>,
+) {
+    for (gamepad, current) in &mut gamepads {
+        let scale = ownership
+            .get_owner(gamepad)
+            .and_then(|player| players.settings(player))
+            .map_or_else(
+                || config.effective_vibration(),
+                |settings| settings.vibration_scale(&config),
+            );
+        match current {
+            Some(mut current) => {
+                current.set_if_neq(RumbleScale(scale));
+            }
+            None => {
+                commands.entity(gamepad).insert(RumbleScale(scale));
+            }
+        }
+    }
+}
+
 /// System to update rumble controllers and apply patterns.
 pub fn update_rumble(
     mut controllers: Query<&mut RumbleController>,
-    mut rumble_requests: MessageWriter<GamepadRumbleRequest>,
+    mut outputs: Query<&mut RumbleOutput>,
     time: Res<Time>,
 ) {
     for mut controller in &mut controllers {
@@ -222,11 +777,25 @@ pub fn update_rumble(
         let delta = time.delta();
         controller.duration = controller.duration.saturating_sub(delta);
 
-        // Apply pattern modulation
+        // Apply curve or pattern modulation
         let mut intensity = controller.intensity;
-        if let Some(pattern) = controller.pattern {
-            controller.pattern_timer += time.delta_secs();
-
+        controller.pattern_timer += time.delta_secs();
+        if let Some(curve) = &controller.curve {
+            // Loop the curve while the rumble lasts longer than it
+            let length = curve.duration().as_secs_f32();
+            let t = if length > 0.0 && !controller.duration.is_zero() {
+                controller.pattern_timer % length
+            } else {
+                controller.pattern_timer
+            };
+            let envelope = curve.sample(t);
+            intensity.low_frequency *= envelope.low_frequency;
+            intensity.high_frequency *= envelope.high_frequency;
+            // Curves have no trigger keys; triggers follow the stronger motor
+            let trigger_envelope = envelope.low_frequency.max(envelope.high_frequency);
+            intensity.left_trigger *= trigger_envelope;
+            intensity.right_trigger *= trigger_envelope;
+        } else if let Some(pattern) = controller.pattern {
             let modifier = match pattern {
                 RumblePattern::Constant => 1.0,
                 RumblePattern::Pulse => (controller.pattern_timer * 8.0).sin().abs(),
@@ -256,42 +825,262 @@ pub fn update_rumble(
                 }
             };
 
-            intensity.low_frequency *= modifier;
-            intensity.high_frequency *= modifier;
+            intensity = intensity.scaled(modifier);
+        }
+        if let Ok(mut output) = outputs.get_mut(controller.gamepad) {
+            output.request(intensity);
         }
-
-        // Send rumble request to Bevy's gamepad system
-        // We send a short duration since we're updating every frame
-        let frame_duration = Duration::from_millis(100);
-        rumble_requests.write(GamepadRumbleRequest::Add {
-            gamepad: controller.gamepad,
-            intensity: GamepadRumbleIntensity {
-                strong_motor: intensity.low_frequency,
-                weak_motor: intensity.high_frequency,
-            },
-            duration: frame_duration,
-        });
 
         // Stop if duration expired
         if controller.duration.is_zero() {
-            rumble_requests.write(GamepadRumbleRequest::Stop {
-                gamepad: controller.gamepad,
-            });
             controller.stop();
         }
     }
 }
 
+/// A single scheduled rumble on a [`HapticTimeline`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct HapticCue {
+    /// Offset from the start of the timeline.
+    pub start: Duration,
+    /// How long the cue plays.
+    pub duration: Duration,
+    /// Motor intensity while the cue plays.
+    pub intensity: RumbleIntensity,
+    /// Optional tag used to cancel related cues together.
+    pub tag: Option<String>,
+}
+
+impl HapticCue {
+    /// Create a new untagged cue.
+    #[must_use]
+    pub const fn new(start: Duration, duration: Duration, intensity: RumbleIntensity) -> Self {
+        Self {
+            start,
+            duration,
+            intensity,
+            tag: None,
+        }
+    }
+
+    /// Set the cue's tag.
+    #[must_use]
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    /// Offset at which the cue stops playing.
+    #[must_use]
+    pub fn end(&self) -> Duration {
+        self.start + self.duration
+    }
+
+    /// Check if the cue is playing at a timeline offset.
+    #[must_use]
+    pub fn is_active_at(&self, elapsed: Duration) -> bool {
+        elapsed >= self.start && elapsed < self.end()
+    }
+}
+
+/// Component that schedules multiple rumble cues over time.
+///
+/// Overlapping cues are mixed by taking the strongest value per motor. The
+/// component is removed once every cue has finished.
+#[derive(Debug, Clone, Component)]
+pub struct HapticTimeline {
+    /// Target gamepad entity.
+    pub gamepad: Entity,
+    /// Scheduled cues.
+    pub cues: Vec<HapticCue>,
+    /// Time since the timeline started.
+    pub elapsed: Duration,
+}
+
+impl HapticTimeline {
+    /// Create an empty timeline.
+    #[must_use]
+    pub fn new(gamepad: Entity) -> Self {
+        Self {
+            gamepad,
+            cues: Vec::new(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Add a cue (builder style).
+    #[must_use]
+    pub fn with_cue(mut self, cue: HapticCue) -> Self {
+        self.cues.push(cue);
+        self
+    }
+
+    /// Add a cue.
+    pub fn add(&mut self, cue: HapticCue) {
+        self.cues.push(cue);
+    }
+
+    /// Cancel every cue with the given tag.
+    ///
+    /// Returns the number of cues removed.
+    pub fn cancel_tag(&mut self, tag: &str) -> usize {
+        let before = self.cues.len();
+        self.cues.retain(|cue| cue.tag.as_deref() != Some(tag));
+        before - self.cues.len()
+    }
+
+    /// Remove all cues.
+    pub fn clear(&mut self) {
+        self.cues.clear();
+    }
+
+    /// Check if every cue has finished.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.cues.iter().all(|cue| cue.end() <= self.elapsed)
+    }
+
+    /// Get the mixed intensity at a timeline offset.
+    #[must_use]
+    pub fn intensity_at(&self, elapsed: Duration) -> RumbleIntensity {
+        self.cues
+            .iter()
+            .filter(|cue| cue.is_active_at(elapsed))
+            .fold(RumbleIntensity::none(), |mixed, cue| {
+                mixed.mix(cue.intensity)
+            })
+    }
+
+    /// Build a heartbeat whose rate ramps from `start_bpm` to `end_bpm`.
+    ///
+    /// Each beat is a strong "lub" followed by a softer "dub". All cues are
+    /// tagged `"heartbeat"`.
+    #[must_use]
+    pub fn heartbeat(
+        gamepad: Entity,
+        duration: Duration,
+        start_bpm: f32,
+        end_bpm: f32,
+        intensity: f32,
+    ) -> Self {
+        let mut timeline = Self::new(gamepad);
+        let total = duration.as_secs_f32();
+        let mut t = 0.0;
+
+        while t < total {
+            let progress = t / total.max(0.001);
+            let bpm = (end_bpm - start_bpm).mul_add(progress, start_bpm).max(1.0);
+
+            timeline.add(
+                HapticCue::new(
+                    Duration::from_secs_f32(t),
+                    Duration::from_millis(100),
+                    RumbleIntensity::uniform(intensity),
+                )
+                .with_tag("heartbeat"),
+            );
+            timeline.add(
+                HapticCue::new(
+                    Duration::from_secs_f32(t + 0.15),
+                    Duration::from_millis(80),
+                    RumbleIntensity::uniform(intensity * 0.6),
+                )
+                .with_tag("heartbeat"),
+            );
+
+            t += 60.0 / bpm;
+        }
+
+        timeline
+    }
+}
+
+/// System to play haptic timelines.
+pub fn update_haptic_timelines(
+    mut commands: Commands,
+    mut timelines: Query<(Entity, &mut HapticTimeline)>,
+    mut outputs: Query<&mut RumbleOutput>,
+    time: Res<Time>,
+) {
+    for (entity, mut timeline) in &mut timelines {
+        timeline.elapsed += time.delta();
+
+        if timeline.is_finished() {
+            commands.entity(entity).remove::<HapticTimeline>();
+            continue;
+        }
+
+        if let Ok(mut output) = outputs.get_mut(timeline.gamepad) {
+            output.request(timeline.intensity_at(timeline.elapsed));
+        }
+    }
+}
+
+/// System to send each gamepad's mixed [`RumbleOutput`] to its motors.
+pub fn send_rumble_output(
+    mut outputs: Query<(Entity, &mut RumbleOutput, Option<&RumbleScale>)>,
+    mut rumble_requests: MessageWriter<GamepadRumbleRequest>,
+    time: Res<Time>,
+) {
+    for (gamepad, mut output, scale) in &mut outputs {
+        let scale = scale.map_or(1.0, |scale| scale.0);
+        let Some(intensity) = output.take_update(scale, time.delta_secs()) else {
+            continue;
+        };
+
+        // Replace whatever is playing; Bevy sums rumbles that overlap. Bevy
+        // has no trigger motors, so trigger-only rumble leaves them stopped
+        rumble_requests.write(GamepadRumbleRequest::Stop { gamepad });
+        if intensity.low_frequency > 0.0 || intensity.high_frequency > 0.0 {
+            rumble_requests.write(GamepadRumbleRequest::Add {
+                gamepad,
+                intensity: GamepadRumbleIntensity {
+                    strong_motor: intensity.low_frequency,
+                    weak_motor: intensity.high_frequency,
+                },
+                duration: RUMBLE_OUTPUT_DURATION,
+            });
+        }
+    }
+}
+
 /// Plugin for registering haptics types and systems.
 pub(crate) fn register_haptics_types(app: &mut App) {
     app.register_type::<RumbleIntensity>()
         .register_type::<RumblePattern>()
-        .add_message::<RumbleRequest>();
+        .register_type::<RumbleEasing>()
+        .register_type::<RumbleKeyframe>()
+        .register_type::<RumbleCurve>()
+        .register_type::<HapticCue>()
+        .register_type::<RumbleController>()
+        .register_type::<RumbleScale>()
+        .register_type::<RumbleOutput>()
+        .register_type::<HapticFeedback>()
+        .register_type::<HapticBindings>()
+        .init_resource::<HapticBindings>()
+        .add_message::<RumbleRequest>()
+        .add_message::<HapticEvent>();
 }
 
 /// Add haptics systems to the app.
-pub(crate) fn add_haptics_systems(app: &mut App) {
-    app.add_systems(Update, (handle_rumble_requests, update_rumble).chain());
+pub(crate) fn add_haptics_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        (
+            (
+                update_rumble_scales,
+                play_haptic_bindings,
+                handle_rumble_requests,
+                update_rumble,
+            )
+                .chain(),
+            update_haptic_timelines.after(update_rumble_scales),
+            send_rumble_output
+                .after(update_rumble)
+                .after(update_haptic_timelines),
+        )
+            .run_if(subsystem_enabled(Subsystem::Haptics)),
+    );
 }
 
 #[cfg(test)]
@@ -299,8 +1088,136 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    // ========== RumbleCurve Tests ==========
+
+    #[test]
+    fn test_rumble_curve_sampling() {
+        let curve = RumbleCurve::new()
+            .with_key(1.0, 0.0, 1.0)
+            .with_key(0.0, 1.0, 0.0)
+            .with_keyframe(RumbleKeyframe::new(2.0, 1.0, 1.0).with_easing(RumbleEasing::Step));
+        assert_eq!(curve.duration(), Duration::from_secs(2));
+
+        assert_eq!(curve.sample(-1.0), RumbleIntensity::new(1.0, 0.0));
+        let mid = curve.sample(0.25);
+        assert_relative_eq!(mid.low_frequency, 0.75);
+        assert_relative_eq!(mid.high_frequency, 0.25);
+        // Step holds until the keyframe
+        assert_eq!(curve.sample(1.9), RumbleIntensity::new(0.0, 1.0));
+        assert_eq!(curve.sample(5.0), RumbleIntensity::uniform(1.0));
+        assert_eq!(RumbleCurve::new().sample(0.0), RumbleIntensity::none());
+    }
+
+    #[test]
+    fn test_rumble_easing() {
+        for easing in [
+            RumbleEasing::Linear,
+            RumbleEasing::EaseIn,
+            RumbleEasing::EaseOut,
+            RumbleEasing::EaseInOut,
+        ] {
+            assert_relative_eq!(easing.ease(0.0), 0.0);
+            assert_relative_eq!(easing.ease(1.0), 1.0);
+        }
+        assert!(RumbleEasing::EaseIn.ease(0.5) < 0.5);
+        assert!(RumbleEasing::EaseOut.ease(0.5) > 0.5);
+        assert_relative_eq!(RumbleEasing::EaseInOut.ease(0.5), 0.5);
+    }
+
+    #[test]
+    fn test_rumble_request_with_curve() {
+        let curve = RumbleCurve::new()
+            .with_key(0.0, 1.0, 1.0)
+            .with_key(0.3, 0.0, 0.0);
+        let request = RumbleRequest::with_curve(Entity::PLACEHOLDER, curve.clone(), 0.5);
+        assert_eq!(request.duration, curve.duration());
+        assert!(request.pattern.is_none());
+        assert_eq!(request.curve, Some(curve));
+    }
+
+    // ========== RumbleOutput Tests ==========
+
+    #[test]
+    fn test_rumble_output_mixes_and_sends_changes() {
+        let mut output = RumbleOutput::default();
+        assert_eq!(output.take_update(1.0, 0.016), None);
+
+        output.request(RumbleIntensity::new(0.8, 0.2));
+        output.request(RumbleIntensity::new(0.4, 0.6));
+        assert_eq!(
+            output.take_update(0.5, 0.016),
+            Some(RumbleIntensity::new(0.4, 0.3))
+        );
+
+        // Unchanged output isn't re-sent until it needs refreshing
+        output.request(RumbleIntensity::new(0.8, 0.6));
+        assert_eq!(output.take_update(0.5, 0.016), None);
+        output.request(RumbleIntensity::new(0.8, 0.6));
+        assert_eq!(
+            output.take_update(0.5, RUMBLE_OUTPUT_REFRESH),
+            Some(RumbleIntensity::new(0.4, 0.3))
+        );
+
+        // Nothing requested stops the motors once
+        assert_eq!(
+            output.take_update(0.5, 0.016),
+            Some(RumbleIntensity::none())
+        );
+        assert_eq!(output.take_update(0.5, 1.0), None);
+    }
+
+    #[test]
+    fn test_rumble_output_stop_overrides_requests() {
+        let mut output = RumbleOutput::default();
+        output.request(RumbleIntensity::uniform(0.5));
+        assert!(output.take_update(1.0, 0.016).is_some());
+
+        output.request(RumbleIntensity::uniform(0.5));
+        output.stop();
+        assert_eq!(
+            output.take_update(1.0, 0.016),
+            Some(RumbleIntensity::none())
+        );
+        assert_eq!(output.playing(), RumbleIntensity::none());
+
+        // Already stopped, so nothing more is sent
+        output.stop();
+        assert_eq!(output.take_update(1.0, 0.016), None);
+
+        // Requests play again on the next frame
+        output.request(RumbleIntensity::uniform(0.5));
+        assert!(output.take_update(1.0, 0.016).is_some());
+    }
+
+    #[test]
+    fn test_rumble_output_mixes_trigger_motors() {
+        let mut output = RumbleOutput::default();
+        output.request(RumbleIntensity::new(0.2, 0.2).with_triggers(0.6, 0.0));
+        output.request(RumbleIntensity::none().with_triggers(0.0, 0.4));
+        let sent = output.take_update(0.5, 0.016).unwrap();
+        assert_relative_eq!(sent.left_trigger, 0.3);
+        assert_relative_eq!(sent.right_trigger, 0.2);
+
+        // A trigger-only change is still sent
+        output.request(RumbleIntensity::new(0.2, 0.2).with_triggers(0.0, 0.4));
+        let sent = output.take_update(0.5, 0.016).unwrap();
+        assert_relative_eq!(sent.left_trigger, 0.0);
+        assert!(sent.has_triggers());
+    }
+
     // ========== RumbleIntensity Tests ==========
 
+    #[test]
+    fn test_rumble_intensity_scaled() {
+        let intensity = RumbleIntensity::new(0.5, 0.8).scaled(0.5);
+        assert_relative_eq!(intensity.low_frequency, 0.25);
+        assert_relative_eq!(intensity.high_frequency, 0.4);
+        assert_eq!(
+            RumbleIntensity::uniform(0.8).scaled(2.0),
+            RumbleIntensity::uniform(1.0)
+        );
+    }
+
     #[test]
     fn test_rumble_intensity_new() {
         let intensity = RumbleIntensity::new(0.5, 0.8);
@@ -461,6 +1378,98 @@ mod tests {
         assert_relative_eq!(request.intensity.high_frequency, 1.0);
     }
 
+    // ========== HapticTimeline Tests ==========
+
+    #[test]
+    fn test_haptic_timeline_mixes_overlapping_cues() {
+        let timeline = HapticTimeline::new(Entity::PLACEHOLDER)
+            .with_cue(HapticCue::new(
+                Duration::ZERO,
+                Duration::from_secs(1),
+                RumbleIntensity::new(0.5, 0.2),
+            ))
+            .with_cue(HapticCue::new(
+                Duration::from_millis(500),
+                Duration::from_secs(1),
+                RumbleIntensity::new(0.3, 0.8),
+            ));
+
+        let early = timeline.intensity_at(Duration::from_millis(100));
+        assert_relative_eq!(early.low_frequency, 0.5);
+        assert_relative_eq!(early.high_frequency, 0.2);
+
+        let overlap = timeline.intensity_at(Duration::from_millis(700));
+        assert_relative_eq!(overlap.low_frequency, 0.5);
+        assert_relative_eq!(overlap.high_frequency, 0.8);
+
+        let after = timeline.intensity_at(Duration::from_secs(2));
+        assert_eq!(after, RumbleIntensity::none());
+    }
+
+    #[test]
+    fn test_haptic_timeline_cancel_tag() {
+        let mut timeline = HapticTimeline::new(Entity::PLACEHOLDER)
+            .with_cue(
+                HapticCue::new(
+                    Duration::ZERO,
+                    Duration::from_secs(1),
+                    RumbleIntensity::uniform(1.0),
+                )
+                .with_tag("engine"),
+            )
+            .with_cue(HapticCue::new(
+                Duration::ZERO,
+                Duration::from_secs(1),
+                RumbleIntensity::uniform(0.2),
+            ));
+
+        assert_eq!(timeline.cancel_tag("engine"), 1);
+        assert_eq!(timeline.cues.len(), 1);
+        assert_eq!(timeline.cancel_tag("engine"), 0);
+    }
+
+    #[test]
+    fn test_haptic_timeline_is_finished() {
+        let mut timeline = HapticTimeline::new(Entity::PLACEHOLDER).with_cue(HapticCue::new(
+            Duration::from_millis(100),
+            Duration::from_millis(100),
+            RumbleIntensity::uniform(1.0),
+        ));
+        assert!(!timeline.is_finished());
+
+        timeline.elapsed = Duration::from_millis(200);
+        assert!(timeline.is_finished());
+    }
+
+    #[test]
+    fn test_haptic_timeline_heartbeat_accelerates() {
+        let timeline = HapticTimeline::heartbeat(
+            Entity::PLACEHOLDER,
+            Duration::from_secs(10),
+            60.0,
+            180.0,
+            1.0,
+        );
+
+        let lubs: Vec<Duration> = timeline
+            .cues
+            .iter()
+            .filter(|cue| cue.intensity == RumbleIntensity::uniform(1.0))
+            .map(|cue| cue.start)
+            .collect();
+        assert!(lubs.len() > 10);
+
+        let first_gap = lubs[1] - lubs[0];
+        let last_gap = lubs[lubs.len() - 1] - lubs[lubs.len() - 2];
+        assert!(last_gap < first_gap);
+        assert!(
+            timeline
+                .cues
+                .iter()
+                .all(|cue| cue.tag.as_deref() == Some("heartbeat"))
+        );
+    }
+
     // ========== Duration Tests ==========
 
     #[test]
@@ -475,4 +1484,42 @@ mod tests {
 
         assert_eq!(controller.duration, Duration::ZERO);
     }
+
+    // ========== HapticBindings Tests ==========
+
+    #[test]
+    fn test_haptic_bindings_lookup() {
+        let bindings = HapticBindings::default()
+            .with_action(GameAction::Primary, HapticFeedback::light_tick())
+            .with_event("TakeDamage", HapticFeedback::heavy_impact());
+
+        assert_eq!(
+            bindings.action_feedback(GameAction::Primary),
+            Some(&HapticFeedback::light_tick())
+        );
+        assert_eq!(bindings.action_feedback(GameAction::Secondary), None);
+        assert_eq!(
+            bindings.event_feedback("TakeDamage").map(|f| f.pattern),
+            Some(RumblePattern::HeavyImpact)
+        );
+    }
+
+    #[test]
+    fn test_haptic_feedback_request() {
+        let request = HapticFeedback::light_tick().request(Entity::PLACEHOLDER);
+        assert_eq!(request.pattern, Some(RumblePattern::DamageTap));
+        assert_relative_eq!(request.intensity.low_frequency, 0.3);
+        assert_eq!(request.duration, Duration::from_millis(60));
+    }
+
+    #[test]
+    fn test_haptic_bindings_roundtrip_json() {
+        let bindings = HapticBindings::default().with_event("Land", HapticFeedback::heavy_impact());
+        let json = serde_json::to_string(&bindings).unwrap();
+        let loaded: HapticBindings = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            loaded.event_feedback("Land"),
+            Some(&HapticFeedback::heavy_impact())
+        );
+    }
 }
@@ -0,0 +1,428 @@
+//! Controller-aware tutorial hints.
+//!
+//! [`HintScheduler`] watches which actions the player has never used and,
+//! once a [`HintRule`]'s conditions are met, fires [`ShowHintEvent`] with the
+//! [`HintPrompt`] for the player's current device: a button glyph for the
+//! active controller layout, or the bound key or mouse button.
+//!
+//! Rules trigger after a number of seconds since the scheduler's timers were
+//! last reset (call [`HintScheduler::reset_timers`] when entering a state to
+//! measure time in that state), or after the game reports failed attempts
+//! with [`HintScheduler::record_failure`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::hints::{HintPrompt, HintRule, HintScheduler, ShowHintEvent};
+//!
+//! fn setup_hints(mut hints: ResMut<HintScheduler>) {
+//!     hints.add_rule(HintRule::new(GameAction::Primary).after_seconds(10.0));
+//!     hints.add_rule(HintRule::new(GameAction::Secondary).after_failures(3));
+//! }
+//!
+//! fn show_hints(mut events: MessageReader<ShowHintEvent>) {
+//!     for hint in events.read() {
+//!         if let HintPrompt::Button { icon, layout } = hint.prompt {
+//!             info!("Press {:?} ({:?}) for {:?}", icon, layout, hint.action);
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::action_usage::ActionUsageTracker;
+use crate::actions::{ActionMap, ActionState, GameAction};
+use crate::config::{ControllerConfig, ControllerLayout};
+use crate::detection::InputDeviceState;
+use crate::icons::{ButtonIcon, ButtonLabels, CompositePrompt};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// The input prompt to show for a hinted action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HintPrompt {
+    /// A gamepad button glyph.
+    Button {
+        /// The button icon.
+        icon: ButtonIcon,
+        /// Controller layout to draw the icon for.
+        layout: ControllerLayout,
+    },
+    /// A gamepad button glyph held with a modifier button, e.g. "LB + X".
+    ModifiedButton {
+        /// The modifier button icon.
+        modifier: ButtonIcon,
+        /// The button icon.
+        icon: ButtonIcon,
+        /// Controller layout to draw the icons for.
+        layout: ControllerLayout,
+    },
+    /// A keyboard key.
+    Key(KeyCode),
+    /// A mouse button.
+    Mouse(MouseButton),
+    /// The action has no binding for the current device.
+    Unbound,
+}
+
+impl HintPrompt {
+    /// Resolve the prompt for an action on the player's current device.
+    #[must_use]
+    pub fn resolve(
+        action: GameAction,
+        action_map: &ActionMap,
+        device_state: &InputDeviceState,
+        config: &ControllerConfig,
+    ) -> Self {
+        if device_state.using_gamepad() {
+            let layout = config.layout();
+            if let Some(icon) = action_map
+                .primary_gamepad_button(action)
+                .and_then(ButtonIcon::from_button_type)
+            {
+                return Self::Button { icon, layout };
+            }
+            return action_map
+                .primary_modifier_binding(action)
+                .and_then(|(modifier, button)| {
+                    Some(Self::ModifiedButton {
+                        modifier: ButtonIcon::from_button_type(modifier)?,
+                        icon: ButtonIcon::from_button_type(button)?,
+                        layout,
+                    })
+                })
+                .unwrap_or(Self::Unbound);
+        }
+
+        let key = action_map
+            .key_bindings
+            .get(&action)
+            .and_then(|keys| keys.first().copied());
+        let mouse = action_map
+            .mouse_bindings
+            .get(&action)
+            .and_then(|buttons| buttons.first().copied());
+        match (key, mouse) {
+            (Some(key), _) => Self::Key(key),
+            (None, Some(button)) => Self::Mouse(button),
+            (None, None) => Self::Unbound,
+        }
+    }
+
+    /// Glyphs for a gamepad prompt, for a
+    /// [`CompositeIconDisplay`](crate::icons::CompositeIconDisplay).
+    #[must_use]
+    pub fn composite(&self) -> Option<CompositePrompt> {
+        match *self {
+            Self::Button { icon, .. } => Some(CompositePrompt::new([icon])),
+            Self::ModifiedButton { modifier, icon, .. } => {
+                Some(CompositePrompt::modified(modifier, icon))
+            }
+            Self::Key(_) | Self::Mouse(_) | Self::Unbound => None,
+        }
+    }
+
+    /// Text label for a gamepad prompt, e.g. "A" or "LB + X".
+    #[must_use]
+    pub fn button_label(&self, labels: &ButtonLabels) -> Option<String> {
+        match *self {
+            Self::Button { icon, layout } => Some(labels.label(icon, layout)),
+            Self::ModifiedButton {
+                modifier,
+                icon,
+                layout,
+            } => Some(labels.modified_label(modifier, icon, layout)),
+            Self::Key(_) | Self::Mouse(_) | Self::Unbound => None,
+        }
+    }
+}
+
+/// When to hint at an action the player hasn't used.
+///
+/// A rule fires when any of its configured conditions is met. A rule with no
+/// conditions never fires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HintRule {
+    /// The action to hint at.
+    pub action: GameAction,
+    /// Seconds since the timers were reset before hinting.
+    pub after_seconds: Option<f32>,
+    /// Failed attempts reported before hinting.
+    pub after_failures: Option<u32>,
+    /// Maximum number of times the hint is shown.
+    pub max_shows: u32,
+}
+
+impl HintRule {
+    /// Create a rule for an action with no conditions, shown at most once.
+    #[must_use]
+    pub const fn new(action: GameAction) -> Self {
+        Self {
+            action,
+            after_seconds: None,
+            after_failures: None,
+            max_shows: 1,
+        }
+    }
+
+    /// Hint after a number of seconds without using the action.
+    #[must_use]
+    pub const fn after_seconds(mut self, seconds: f32) -> Self {
+        self.after_seconds = Some(seconds);
+        self
+    }
+
+    /// Hint after a number of failed attempts.
+    #[must_use]
+    pub const fn after_failures(mut self, failures: u32) -> Self {
+        self.after_failures = Some(failures);
+        self
+    }
+
+    /// Set how many times the hint may be shown.
+    #[must_use]
+    pub const fn with_max_shows(mut self, max_shows: u32) -> Self {
+        self.max_shows = max_shows;
+        self
+    }
+}
+
+/// Progress toward a rule firing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct HintProgress {
+    elapsed: f32,
+    failures: u32,
+    shown: u32,
+}
+
+/// Resource scheduling hints for actions the player has never used.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct HintScheduler {
+    /// Registered rules.
+    rules: Vec<HintRule>,
+    /// Progress per hinted action.
+    progress: HashMap<GameAction, HintProgress>,
+    /// Actions the player has used.
+    used: HashSet<GameAction>,
+}
+
+impl HintScheduler {
+    /// Add a hint rule, replacing any existing rule for the same action.
+    pub fn add_rule(&mut self, rule: HintRule) {
+        self.rules.retain(|existing| existing.action != rule.action);
+        self.rules.push(rule);
+    }
+
+    /// Remove the rule for an action.
+    pub fn remove_rule(&mut self, action: GameAction) {
+        self.rules.retain(|rule| rule.action != action);
+        self.progress.remove(&action);
+    }
+
+    /// Report a failed attempt that the action would have helped with.
+    pub fn record_failure(&mut self, action: GameAction) {
+        self.progress.entry(action).or_default().failures += 1;
+    }
+
+    /// Mark an action as used, suppressing its hints.
+    pub fn mark_used(&mut self, action: GameAction) {
+        self.used.insert(action);
+    }
+
+    /// Whether the player has used an action.
+    #[must_use]
+    pub fn has_used(&self, action: GameAction) -> bool {
+        self.used.contains(&action)
+    }
+
+    /// Restart the time conditions, e.g. when entering a new state.
+    pub fn reset_timers(&mut self) {
+        for progress in self.progress.values_mut() {
+            progress.elapsed = 0.0;
+        }
+    }
+
+    /// Forget used actions and shown hints so every rule can fire again.
+    pub fn reset(&mut self) {
+        self.progress.clear();
+        self.used.clear();
+    }
+
+    /// Advance the timers by `delta` seconds.
+    ///
+    /// Returns the actions whose hint should be shown now. Showing a hint
+    /// restarts its conditions.
+    pub fn update(&mut self, delta: f32) -> Vec<GameAction> {
+        let mut due = Vec::new();
+        for rule in &self.rules {
+            if self.used.contains(&rule.action) {
+                continue;
+            }
+            let progress = self.progress.entry(rule.action).or_default();
+            if progress.shown >= rule.max_shows {
+                continue;
+            }
+            progress.elapsed += delta;
+
+            let timed_out = rule
+                .after_seconds
+                .is_some_and(|seconds| progress.elapsed >= seconds);
+            let failed = rule
+                .after_failures
+                .is_some_and(|failures| progress.failures >= failures);
+            if timed_out || failed {
+                *progress = HintProgress {
+                    shown: progress.shown + 1,
+                    ..default()
+                };
+                due.push(rule.action);
+            }
+        }
+        due
+    }
+}
+
+/// Event asking the game to show a hint for an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct ShowHintEvent {
+    /// The action to hint at.
+    pub action: GameAction,
+    /// The prompt for the player's current device.
+    pub prompt: HintPrompt,
+}
+
+/// System to track used actions and fire due hints.
+///
+/// Actions recorded by [`ActionUsageTracker`] in earlier sessions count as
+/// used.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn update_hints(
+    time: Res<Time>,
+    action_state: Res<ActionState>,
+    usage: Res<ActionUsageTracker>,
+    action_map: Res<ActionMap>,
+    device_state: Res<InputDeviceState>,
+    config: Res<ControllerConfig>,
+    mut hints: ResMut<HintScheduler>,
+    mut events: MessageWriter<ShowHintEvent>,
+) {
+    for action in GameAction::all() {
+        if action_state.just_pressed(*action) || usage.has_used(*action) {
+            hints.mark_used(*action);
+        }
+    }
+
+    for action in hints.update(time.delta_secs()) {
+        events.write(ShowHintEvent {
+            action,
+            prompt: HintPrompt::resolve(action, &action_map, &device_state, &config),
+        });
+    }
+}
+
+/// Plugin for registering hint types.
+pub(crate) fn register_hint_types(app: &mut App) {
+    app.init_resource::<HintScheduler>()
+        .add_message::<ShowHintEvent>();
+}
+
+/// Add hint systems to the app.
+pub(crate) fn add_hint_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        update_hints.run_if(subsystem_enabled(Subsystem::Hints)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timed_hint_fires_once() {
+        let mut hints = HintScheduler::default();
+        hints.add_rule(HintRule::new(GameAction::Primary).after_seconds(1.0));
+
+        assert!(hints.update(0.5).is_empty());
+        assert_eq!(hints.update(0.5), vec![GameAction::Primary]);
+        assert!(hints.update(5.0).is_empty());
+    }
+
+    #[test]
+    fn test_failures_trigger_hint() {
+        let mut hints = HintScheduler::default();
+        hints.add_rule(
+            HintRule::new(GameAction::Secondary)
+                .after_failures(2)
+                .with_max_shows(2),
+        );
+
+        hints.record_failure(GameAction::Secondary);
+        assert!(hints.update(0.1).is_empty());
+        hints.record_failure(GameAction::Secondary);
+        assert_eq!(hints.update(0.1), vec![GameAction::Secondary]);
+
+        // Failures restart after showing
+        hints.record_failure(GameAction::Secondary);
+        assert!(hints.update(0.1).is_empty());
+        hints.record_failure(GameAction::Secondary);
+        assert_eq!(hints.update(0.1), vec![GameAction::Secondary]);
+    }
+
+    #[test]
+    fn test_used_action_is_never_hinted() {
+        let mut hints = HintScheduler::default();
+        hints.add_rule(HintRule::new(GameAction::Primary).after_seconds(1.0));
+        hints.mark_used(GameAction::Primary);
+        assert!(hints.update(10.0).is_empty());
+    }
+
+    #[test]
+    fn test_reset_timers_measures_time_in_state() {
+        let mut hints = HintScheduler::default();
+        hints.add_rule(HintRule::new(GameAction::Primary).after_seconds(1.0));
+        hints.update(0.9);
+        hints.reset_timers();
+        assert!(hints.update(0.9).is_empty());
+    }
+
+    #[test]
+    fn test_prompt_follows_device() {
+        let map = ActionMap::default();
+        let config = ControllerConfig::default();
+        let mut device_state = InputDeviceState::default();
+
+        device_state.active_device = crate::detection::InputDevice::Keyboard;
+        let keyboard = HintPrompt::resolve(GameAction::Confirm, &map, &device_state, &config);
+        assert!(matches!(keyboard, HintPrompt::Key(_)));
+
+        device_state.active_device = crate::detection::InputDevice::Gamepad(Entity::PLACEHOLDER);
+        let gamepad = HintPrompt::resolve(GameAction::Confirm, &map, &device_state, &config);
+        assert!(matches!(gamepad, HintPrompt::Button { .. }));
+    }
+
+    #[test]
+    fn test_prompt_shows_modifier_binding() {
+        let mut map = ActionMap::default();
+        map.bind_gamepad_with_modifier(
+            GameAction::Custom1,
+            GamepadButton::LeftTrigger,
+            GamepadButton::West,
+        );
+        let config = ControllerConfig::default();
+        let mut device_state = InputDeviceState::default();
+        device_state.active_device = crate::detection::InputDevice::Gamepad(Entity::PLACEHOLDER);
+
+        let prompt = HintPrompt::resolve(GameAction::Custom1, &map, &device_state, &config);
+        assert_eq!(
+            prompt.button_label(&ButtonLabels::default()).as_deref(),
+            Some("LB + X")
+        );
+    }
+}
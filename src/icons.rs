@@ -2,10 +2,19 @@
 //!
 //! This module provides controller button icons that automatically
 //! adapt to the current controller layout (Xbox, `PlayStation`, etc.).
+//!
+//! Chords and modifier-layer bindings are shown with a
+//! [`CompositeIconDisplay`], which lays out one glyph per button joined by
+//! "+" separators, e.g. "L1 + △".
 
+use bevy::asset::LoadState;
 use bevy::prelude::*;
+use std::collections::HashSet;
 
-use crate::config::ControllerLayout;
+use crate::actions::{ActionMap, GameAction};
+use crate::chords::{ButtonChord, ChordBindings};
+use crate::config::{ConfirmStyle, ControllerLayout};
+use crate::virtual_input::VirtualButton;
 
 /// Icon size variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -20,6 +29,12 @@ pub enum IconSize {
 }
 
 impl IconSize {
+    /// Get all icon sizes as a slice.
+    #[must_use]
+    pub fn all() -> &'static [IconSize] {
+        &[Self::Small, Self::Medium, Self::Large]
+    }
+
     /// Get the pixel size for this icon size.
     #[must_use]
     pub const fn pixels(self) -> u32 {
@@ -30,6 +45,15 @@ impl IconSize {
         }
     }
 
+    /// Get the next smaller size, or this size if already the smallest.
+    #[must_use]
+    pub const fn smaller(self) -> Self {
+        match self {
+            Self::Small | Self::Medium => Self::Small,
+            Self::Large => Self::Medium,
+        }
+    }
+
     /// Get the suffix for asset paths.
     #[must_use]
     pub const fn suffix(self) -> &'static str {
@@ -76,6 +100,33 @@ pub enum ButtonIcon {
 }
 
 impl ButtonIcon {
+    /// Get all button icons as a slice.
+    #[must_use]
+    pub fn all() -> &'static [ButtonIcon] {
+        &[
+            Self::FaceDown,
+            Self::FaceRight,
+            Self::FaceLeft,
+            Self::FaceUp,
+            Self::LeftBumper,
+            Self::RightBumper,
+            Self::LeftTrigger,
+            Self::RightTrigger,
+            Self::LeftStick,
+            Self::RightStick,
+            Self::LeftStickPress,
+            Self::RightStickPress,
+            Self::DPadUp,
+            Self::DPadDown,
+            Self::DPadLeft,
+            Self::DPadRight,
+            Self::DPad,
+            Self::Start,
+            Self::Select,
+            Self::Home,
+        ]
+    }
+
     /// Get the icon for a gamepad button type.
     #[must_use]
     pub fn from_button_type(button: GamepadButton) -> Option<Self> {
@@ -101,6 +152,24 @@ impl ButtonIcon {
         }
     }
 
+    /// Get the icon for the confirm button under a confirm convention.
+    #[must_use]
+    pub const fn confirm(style: ConfirmStyle) -> Self {
+        match style {
+            ConfirmStyle::East => Self::FaceRight,
+            ConfirmStyle::Auto | ConfirmStyle::South => Self::FaceDown,
+        }
+    }
+
+    /// Get the icon for the cancel button under a confirm convention.
+    #[must_use]
+    pub const fn cancel(style: ConfirmStyle) -> Self {
+        match style {
+            ConfirmStyle::East => Self::FaceDown,
+            ConfirmStyle::Auto | ConfirmStyle::South => Self::FaceRight,
+        }
+    }
+
     /// Get the asset filename for this icon on a specific layout.
     #[must_use]
     pub fn filename(self, layout: ControllerLayout, size: IconSize) -> String {
@@ -250,6 +319,260 @@ impl ButtonIcon {
     }
 }
 
+/// Hook for translating button prompt labels.
+///
+/// Implement this to plug a localization backend into [`ButtonLabels`].
+/// Returning `None` falls back to the built-in English label.
+pub trait ButtonLabelLocalizer: Send + Sync + 'static {
+    /// Get the translated label for a button on a layout.
+    fn localize(&self, icon: ButtonIcon, layout: ControllerLayout) -> Option<String>;
+}
+
+/// Resource of translated button labels.
+///
+/// Lookups check, in order: an override for the exact icon and layout, an
+/// override for the icon on any layout, the installed localizer, and
+/// finally [`ButtonIcon::label`].
+#[derive(Default, Resource)]
+pub struct ButtonLabels {
+    /// Label overrides keyed by icon and optional layout.
+    overrides: std::collections::HashMap<(ButtonIcon, Option<ControllerLayout>), String>,
+    /// Optional localization backend.
+    localizer: Option<Box<dyn ButtonLabelLocalizer>>,
+}
+
+impl std::fmt::Debug for ButtonLabels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ButtonLabels")
+            .field("overrides", &self.overrides)
+            .field("has_localizer", &self.localizer.is_some())
+            .finish()
+    }
+}
+
+impl ButtonLabels {
+    /// Override the label for a button on a specific layout.
+    pub fn set(&mut self, icon: ButtonIcon, layout: ControllerLayout, label: impl Into<String>) {
+        self.overrides.insert((icon, Some(layout)), label.into());
+    }
+
+    /// Override the label for a button on every layout.
+    pub fn set_all_layouts(&mut self, icon: ButtonIcon, label: impl Into<String>) {
+        self.overrides.insert((icon, None), label.into());
+    }
+
+    /// Remove all overrides for a button.
+    pub fn clear(&mut self, icon: ButtonIcon) {
+        self.overrides.retain(|(i, _), _| *i != icon);
+    }
+
+    /// Install a localization backend.
+    pub fn set_localizer(&mut self, localizer: impl ButtonLabelLocalizer) {
+        self.localizer = Some(Box::new(localizer));
+    }
+
+    /// Get the label for a button, applying overrides and translations.
+    #[must_use]
+    pub fn label(&self, icon: ButtonIcon, layout: ControllerLayout) -> String {
+        if let Some(label) = self
+            .overrides
+            .get(&(icon, Some(layout)))
+            .or_else(|| self.overrides.get(&(icon, None)))
+        {
+            return label.clone();
+        }
+
+        self.localizer
+            .as_ref()
+            .and_then(|localizer| localizer.localize(icon, layout))
+            .unwrap_or_else(|| icon.label(layout).to_string())
+    }
+
+    /// Get the label for a button held with a modifier, e.g. "LB + X".
+    #[must_use]
+    pub fn modified_label(
+        &self,
+        modifier: ButtonIcon,
+        icon: ButtonIcon,
+        layout: ControllerLayout,
+    ) -> String {
+        CompositePrompt::modified(modifier, icon).label(self, layout)
+    }
+}
+
+/// A prompt made of several button glyphs, such as a chord or a button held
+/// with a modifier.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompositePrompt {
+    /// Modifier held before the other buttons, always shown first.
+    modifier: Option<ButtonIcon>,
+    /// Buttons pressed together.
+    buttons: Vec<ButtonIcon>,
+}
+
+impl CompositePrompt {
+    /// Create a prompt for buttons pressed together.
+    #[must_use]
+    pub fn new(buttons: impl IntoIterator<Item = ButtonIcon>) -> Self {
+        let mut prompt = Self::default();
+        for icon in buttons {
+            if !prompt.buttons.contains(&icon) {
+                prompt.buttons.push(icon);
+            }
+        }
+        prompt
+    }
+
+    /// Create a prompt for a button held with a modifier.
+    #[must_use]
+    pub fn modified(modifier: ButtonIcon, button: ButtonIcon) -> Self {
+        Self {
+            modifier: Some(modifier),
+            buttons: vec![button],
+        }
+    }
+
+    /// Create a prompt from gamepad buttons.
+    ///
+    /// Returns `None` if any button has no icon.
+    #[must_use]
+    pub fn from_buttons(buttons: &[GamepadButton]) -> Option<Self> {
+        buttons
+            .iter()
+            .map(|button| ButtonIcon::from_button_type(*button))
+            .collect::<Option<Vec<_>>>()
+            .map(Self::new)
+    }
+
+    /// Create a prompt from a chord.
+    ///
+    /// Returns `None` if the chord is empty or uses keys, mouse buttons, or
+    /// gamepad buttons without an icon.
+    #[must_use]
+    pub fn from_chord(chord: &ButtonChord) -> Option<Self> {
+        if chord.is_empty() {
+            return None;
+        }
+        chord
+            .buttons()
+            .iter()
+            .map(|button| match button {
+                VirtualButton::Gamepad(button) => ButtonIcon::from_button_type(*button),
+                VirtualButton::Key(_) | VirtualButton::Mouse(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(Self::new)
+    }
+
+    /// Resolve the gamepad prompt for an action.
+    ///
+    /// Prefers a plain button binding, then a modifier-layer binding, then a
+    /// chord.
+    #[must_use]
+    pub fn for_action(
+        action: GameAction,
+        action_map: &ActionMap,
+        chords: &ChordBindings,
+    ) -> Option<Self> {
+        if let Some(icon) = action_map
+            .primary_gamepad_button(action)
+            .and_then(ButtonIcon::from_button_type)
+        {
+            return Some(Self::new([icon]));
+        }
+        if let Some((modifier, button)) = action_map.primary_modifier_binding(action)
+            && let (Some(modifier), Some(button)) = (
+                ButtonIcon::from_button_type(modifier),
+                ButtonIcon::from_button_type(button),
+            )
+        {
+            return Some(Self::modified(modifier, button));
+        }
+        chords.chord_for(action).and_then(Self::from_chord)
+    }
+
+    /// Number of glyphs in the prompt.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buttons.len() + usize::from(self.modifier.is_some())
+    }
+
+    /// Whether the prompt has no glyphs.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Glyphs in display order for a layout.
+    ///
+    /// The modifier comes first, then shoulders and triggers, system
+    /// buttons, sticks, the D-pad, and face buttons, left hand before right.
+    /// Face buttons follow the layout's convention: alphabetical on lettered
+    /// layouts, △ ○ ✕ □ on `PlayStation`.
+    #[must_use]
+    pub fn ordered(&self, layout: ControllerLayout) -> Vec<ButtonIcon> {
+        let mut buttons = self.buttons.clone();
+        buttons.sort_by_key(|icon| icon.prompt_order(layout));
+        self.modifier.into_iter().chain(buttons).collect()
+    }
+
+    /// Icon size to draw each glyph at.
+    ///
+    /// Prompts of three or more glyphs step down one size to keep their
+    /// width close to a two-glyph prompt.
+    #[must_use]
+    pub fn icon_size(&self, size: IconSize) -> IconSize {
+        if self.len() > 2 { size.smaller() } else { size }
+    }
+
+    /// Text label for the prompt, e.g. "LB + X".
+    #[must_use]
+    pub fn label(&self, labels: &ButtonLabels, layout: ControllerLayout) -> String {
+        self.ordered(layout)
+            .into_iter()
+            .map(|icon| labels.label(icon, layout))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+impl ButtonIcon {
+    /// Sort key for placing this glyph in a composite prompt.
+    fn prompt_order(self, layout: ControllerLayout) -> (u8, u8) {
+        match self {
+            Self::LeftBumper => (0, 0),
+            Self::LeftTrigger => (0, 1),
+            Self::RightBumper => (0, 2),
+            Self::RightTrigger => (0, 3),
+            Self::Select => (1, 0),
+            Self::Home => (1, 1),
+            Self::Start => (1, 2),
+            Self::LeftStick => (2, 0),
+            Self::LeftStickPress => (2, 1),
+            Self::RightStick => (2, 2),
+            Self::RightStickPress => (2, 3),
+            Self::DPad => (3, 0),
+            Self::DPadUp => (3, 1),
+            Self::DPadRight => (3, 2),
+            Self::DPadDown => (3, 3),
+            Self::DPadLeft => (3, 4),
+            Self::FaceUp | Self::FaceRight | Self::FaceDown | Self::FaceLeft => {
+                let rank = if layout == ControllerLayout::PlayStation {
+                    match self {
+                        Self::FaceUp => 0,
+                        Self::FaceRight => 1,
+                        Self::FaceDown => 2,
+                        _ => 3,
+                    }
+                } else {
+                    self.label(layout).bytes().next().unwrap_or(0)
+                };
+                (4, rank)
+            }
+        }
+    }
+}
+
 /// Resource containing loaded controller icon assets.
 #[derive(Debug, Default, Resource)]
 pub struct ControllerIconAssets {
@@ -258,8 +581,48 @@ pub struct ControllerIconAssets {
 
     /// Cached icon handles.
     icons: std::collections::HashMap<(ButtonIcon, ControllerLayout, IconSize), Handle<Image>>,
+
+    /// Icons whose asset failed to load.
+    missing: HashSet<(ButtonIcon, ControllerLayout, IconSize)>,
+}
+
+/// Where an icon should be drawn from after applying the fallback chain.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IconSource {
+    /// An image asset.
+    Image(Handle<Image>),
+    /// No image is available; render this text label instead.
+    Label(ButtonIcon),
+}
+
+/// Which step of the fallback chain replaced a missing icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconFallback {
+    /// The generic layout icon is used instead.
+    GenericLayout,
+    /// A text label is rendered instead.
+    TextLabel,
 }
 
+/// Event fired when an icon asset fails to load.
+#[derive(Debug, Clone, Message)]
+pub struct MissingIconEvent {
+    /// The icon that is missing.
+    pub icon: ButtonIcon,
+    /// The layout that was requested.
+    pub layout: ControllerLayout,
+    /// The size that was requested.
+    pub size: IconSize,
+    /// The asset path that failed to load.
+    pub path: String,
+    /// The fallback that will be used instead.
+    pub fallback: IconFallback,
+}
+
+/// Marker for icon displays currently rendering a text label fallback.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct IconFallbackLabel;
+
 fn build_asset_path(base_path: &str, filename: &str) -> String {
     let normalized_base = base_path.replace('\\', "/");
     let trimmed_base = normalized_base.trim_end_matches('/');
@@ -281,9 +644,65 @@ impl ControllerIconAssets {
         Self {
             base_path: base_path.into(),
             icons: std::collections::HashMap::new(),
+            missing: HashSet::new(),
         }
     }
 
+    /// Get the asset path for an icon.
+    #[must_use]
+    pub fn icon_path(&self, icon: ButtonIcon, layout: ControllerLayout, size: IconSize) -> String {
+        build_asset_path(&self.base_path, &icon.filename(layout, size))
+    }
+
+    /// Record that an icon failed to load so it is skipped from now on.
+    pub fn mark_missing(&mut self, icon: ButtonIcon, layout: ControllerLayout, size: IconSize) {
+        let key = (icon, layout, size);
+        self.icons.remove(&key);
+        self.missing.insert(key);
+    }
+
+    /// Check if an icon is known to be missing.
+    #[must_use]
+    pub fn is_missing(&self, icon: ButtonIcon, layout: ControllerLayout, size: IconSize) -> bool {
+        self.missing.contains(&(icon, layout, size))
+    }
+
+    /// Resolve an icon through the fallback chain.
+    ///
+    /// Tries the requested layout, then the generic layout, and finally
+    /// falls back to a text label once both are known to be missing.
+    #[must_use]
+    pub fn resolve_icon(
+        &mut self,
+        icon: ButtonIcon,
+        layout: ControllerLayout,
+        size: IconSize,
+        asset_server: &AssetServer,
+    ) -> IconSource {
+        if !self.is_missing(icon, layout, size) {
+            return IconSource::Image(self.get_icon(icon, layout, size, asset_server));
+        }
+
+        let generic = ControllerLayout::Generic;
+        let same_file = icon.filename(layout, size) == icon.filename(generic, size);
+        if !same_file && !self.is_missing(icon, generic, size) {
+            return IconSource::Image(self.get_icon(icon, generic, size, asset_server));
+        }
+
+        IconSource::Label(icon)
+    }
+
+    /// Find the cache key for a loaded handle.
+    fn key_for_handle(
+        &self,
+        handle: &Handle<Image>,
+    ) -> Option<(ButtonIcon, ControllerLayout, IconSize)> {
+        self.icons
+            .iter()
+            .find(|(_, cached)| *cached == handle)
+            .map(|(key, _)| *key)
+    }
+
     /// Get or load an icon for a button.
     #[must_use]
     pub fn get_icon(
@@ -340,12 +759,146 @@ impl Default for ControllerIconDisplay {
     }
 }
 
+/// Component for displaying a multi-glyph prompt such as "LB + X".
+///
+/// The entity is laid out as a row and given one [`ControllerIconDisplay`]
+/// child per glyph, with [`CompositeIconSeparator`] text between them. The
+/// children are rebuilt when the prompt or the controller layout changes.
+#[derive(Debug, Clone, Default, Component)]
+pub struct CompositeIconDisplay {
+    /// The prompt to display.
+    pub prompt: CompositePrompt,
+    /// The icon size for prompts of up to two glyphs.
+    pub size: IconSize,
+}
+
+/// Marker for the "+" text between glyphs of a [`CompositeIconDisplay`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct CompositeIconSeparator;
+
+/// Marker for children spawned by a [`CompositeIconDisplay`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct CompositeIconPart;
+
+/// System to build the glyphs of composite icon displays.
+#[expect(
+    clippy::type_complexity,
+    reason = "Bevy queries combine several components with a change filter"
+)]
+pub fn update_composite_icon_displays(
+    mut commands: Commands,
+    config: Res<crate::config::ControllerConfig>,
+    displays: Query<(
+        Entity,
+        Ref<CompositeIconDisplay>,
+        Option<&Children>,
+        Has<Node>,
+    )>,
+    parts: Query<(), With<CompositeIconPart>>,
+) {
+    let layout = config.layout();
+
+    for (entity, display, children, has_node) in &displays {
+        if !display.is_changed() && !config.is_changed() {
+            continue;
+        }
+
+        for child in children.into_iter().flatten() {
+            if parts.contains(*child) {
+                commands.entity(*child).despawn();
+            }
+        }
+
+        let size = display.prompt.icon_size(display.size);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "icon sizes are small pixel counts"
+        )]
+        let pixels = size.pixels() as f32;
+        if !has_node {
+            commands.entity(entity).insert(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(pixels / 8.0),
+                ..default()
+            });
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            for (index, icon) in display.prompt.ordered(layout).into_iter().enumerate() {
+                if index > 0 {
+                    parent.spawn((
+                        Text::new("+"),
+                        TextFont::from_font_size(pixels / 2.0),
+                        CompositeIconSeparator,
+                        CompositeIconPart,
+                    ));
+                }
+                parent.spawn((
+                    ControllerIconDisplay {
+                        icon,
+                        size,
+                        auto_update: true,
+                    },
+                    CompositeIconPart,
+                ));
+            }
+        });
+    }
+}
+
+/// Apply a resolved icon source to a display entity.
+fn apply_icon_source(
+    commands: &mut Commands,
+    entity: Entity,
+    image: Option<Mut<ImageNode>>,
+    showing_label: bool,
+    source: IconSource,
+    layout: ControllerLayout,
+    labels: &ButtonLabels,
+) {
+    match source {
+        IconSource::Image(handle) => {
+            if let Some(mut image) = image {
+                image.image = handle;
+            } else {
+                commands.entity(entity).insert(ImageNode::new(handle));
+            }
+            if showing_label {
+                commands
+                    .entity(entity)
+                    .remove::<(Text, IconFallbackLabel)>();
+            }
+        }
+        IconSource::Label(icon) => {
+            commands
+                .entity(entity)
+                .remove::<ImageNode>()
+                .insert((Text::new(labels.label(icon, layout)), IconFallbackLabel));
+        }
+    }
+}
+
 /// System to update icon displays when layout changes.
+#[expect(
+    clippy::type_complexity,
+    reason = "Bevy queries combine several components with a change filter"
+)]
 pub fn update_icon_displays(
+    mut commands: Commands,
     mut icons: ResMut<ControllerIconAssets>,
+    labels: Res<ButtonLabels>,
     config: Res<crate::config::ControllerConfig>,
     asset_server: Option<Res<AssetServer>>,
-    mut query: Query<(&ControllerIconDisplay, &mut ImageNode), Changed<ControllerIconDisplay>>,
+    mut query: Query<
+        (
+            Entity,
+            &ControllerIconDisplay,
+            Option<&mut ImageNode>,
+            Has<IconFallbackLabel>,
+        ),
+        Changed<ControllerIconDisplay>,
+    >,
 ) {
     // Skip if asset server is not available (e.g., in tests without asset plugin)
     let Some(asset_server) = asset_server else {
@@ -354,27 +907,282 @@ pub fn update_icon_displays(
 
     let layout = config.layout();
 
-    for (display, mut image) in &mut query {
+    for (entity, display, image, showing_label) in &mut query {
         if display.auto_update {
-            let handle = icons.get_icon(display.icon, layout, display.size, &asset_server);
-            image.image = handle;
+            let source = icons.resolve_icon(display.icon, layout, display.size, &asset_server);
+            apply_icon_source(
+                &mut commands,
+                entity,
+                image,
+                showing_label,
+                source,
+                layout,
+                &labels,
+            );
+        }
+    }
+}
+
+/// System to replace icons whose asset failed to load.
+///
+/// Each failure is reported once through [`MissingIconEvent`] and the
+/// display moves to the next step of the fallback chain.
+pub fn handle_missing_icons(
+    mut commands: Commands,
+    mut icons: ResMut<ControllerIconAssets>,
+    labels: Res<ButtonLabels>,
+    asset_server: Option<Res<AssetServer>>,
+    mut missing_events: MessageWriter<MissingIconEvent>,
+    mut query: Query<(Entity, &ControllerIconDisplay, &mut ImageNode)>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+
+    for (entity, display, image) in &mut query {
+        if !matches!(
+            asset_server.load_state(image.image.id()),
+            LoadState::Failed(_)
+        ) {
+            continue;
+        }
+
+        let Some((icon, layout, size)) = icons.key_for_handle(&image.image) else {
+            continue;
+        };
+
+        let path = icons.icon_path(icon, layout, size);
+        icons.mark_missing(icon, layout, size);
+
+        let source = icons.resolve_icon(display.icon, layout, size, &asset_server);
+        let fallback = match source {
+            IconSource::Image(_) => IconFallback::GenericLayout,
+            IconSource::Label(_) => IconFallback::TextLabel,
+        };
+
+        missing_events.write(MissingIconEvent {
+            icon,
+            layout,
+            size,
+            path,
+            fallback,
+        });
+
+        apply_icon_source(
+            &mut commands,
+            entity,
+            Some(image),
+            false,
+            source,
+            layout,
+            &labels,
+        );
+    }
+}
+
+/// Animation style for a prompt icon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IconAnimationKind {
+    /// Scale up and down between two factors.
+    Pulse {
+        /// Smallest scale factor.
+        min_scale: f32,
+        /// Largest scale factor.
+        max_scale: f32,
+    },
+    /// Fade the icon's alpha in and out.
+    Fade {
+        /// Lowest alpha value.
+        min_alpha: f32,
+    },
+    /// Step through sprite-sheet frames on the icon's texture atlas.
+    Frames {
+        /// Index of the first frame in the atlas.
+        first: usize,
+        /// Number of frames in the animation.
+        count: usize,
+    },
+}
+
+/// A sampled animation frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconAnimationFrame {
+    /// Scale factor to apply.
+    pub scale: f32,
+    /// Alpha value to apply.
+    pub alpha: f32,
+    /// Texture atlas index to show, for frame animations.
+    pub atlas_index: Option<usize>,
+}
+
+/// Component for animating a [`ControllerIconDisplay`].
+///
+/// Useful for "press now" moments such as a pulsing A-button prompt.
+#[derive(Debug, Clone, Component)]
+pub struct IconAnimation {
+    /// The animation style.
+    pub kind: IconAnimationKind,
+    /// Cycles per second for pulse/fade, frames per second for frames.
+    pub speed: f32,
+    /// Whether the animation is playing.
+    pub playing: bool,
+    /// Time elapsed in seconds.
+    pub elapsed: f32,
+}
+
+impl IconAnimation {
+    /// Create a pulsing scale animation.
+    #[must_use]
+    pub const fn pulse(min_scale: f32, max_scale: f32, speed: f32) -> Self {
+        Self::new(
+            IconAnimationKind::Pulse {
+                min_scale,
+                max_scale,
+            },
+            speed,
+        )
+    }
+
+    /// Create an alpha fade animation.
+    #[must_use]
+    pub const fn fade(min_alpha: f32, speed: f32) -> Self {
+        Self::new(IconAnimationKind::Fade { min_alpha }, speed)
+    }
+
+    /// Create a sprite-sheet animation.
+    #[must_use]
+    pub const fn frames(first: usize, count: usize, fps: f32) -> Self {
+        Self::new(IconAnimationKind::Frames { first, count }, fps)
+    }
+
+    const fn new(kind: IconAnimationKind, speed: f32) -> Self {
+        Self {
+            kind,
+            speed,
+            playing: true,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Resume the animation.
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    /// Pause the animation on its current frame.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Toggle between playing and paused.
+    pub fn toggle(&mut self) {
+        self.playing = !self.playing;
+    }
+
+    /// Restart the animation from the beginning.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.playing = true;
+    }
+
+    /// Sample the animation at its current time.
+    #[must_use]
+    pub fn sample(&self) -> IconAnimationFrame {
+        // 0.0 at rest, rising to 1.0 at the peak of each cycle
+        let wave = 0.5 - 0.5 * (self.elapsed * self.speed * std::f32::consts::TAU).cos();
+
+        match self.kind {
+            IconAnimationKind::Pulse {
+                min_scale,
+                max_scale,
+            } => IconAnimationFrame {
+                scale: min_scale + (max_scale - min_scale) * wave,
+                alpha: 1.0,
+                atlas_index: None,
+            },
+            IconAnimationKind::Fade { min_alpha } => IconAnimationFrame {
+                scale: 1.0,
+                alpha: 1.0 - (1.0 - min_alpha) * wave,
+                atlas_index: None,
+            },
+            IconAnimationKind::Frames { first, count } => {
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    reason = "elapsed time and fps are non-negative and small"
+                )]
+                let frame = (self.elapsed * self.speed) as usize;
+                IconAnimationFrame {
+                    scale: 1.0,
+                    alpha: 1.0,
+                    atlas_index: Some(first + frame % count.max(1)),
+                }
+            }
+        }
+    }
+}
+
+/// System to advance icon animations.
+pub fn animate_icons(
+    time: Res<Time>,
+    mut query: Query<
+        (
+            &mut IconAnimation,
+            &mut ImageNode,
+            Option<&mut bevy::ui::UiTransform>,
+        ),
+        With<ControllerIconDisplay>,
+    >,
+) {
+    for (mut animation, mut image, transform) in &mut query {
+        if !animation.playing {
+            continue;
+        }
+
+        animation.elapsed += time.delta_secs();
+        let frame = animation.sample();
+
+        image.color.set_alpha(frame.alpha);
+        if let Some(index) = frame.atlas_index
+            && let Some(atlas) = image.texture_atlas.as_mut()
+        {
+            atlas.index = index;
+        }
+        if let Some(mut transform) = transform {
+            transform.scale = Vec2::splat(frame.scale);
         }
     }
 }
 
 /// Plugin for registering icon types.
 pub(crate) fn register_icon_types(app: &mut App) {
-    app.init_resource::<ControllerIconAssets>();
+    app.init_resource::<ControllerIconAssets>()
+        .init_resource::<ButtonLabels>()
+        .add_message::<MissingIconEvent>();
 }
 
 /// Add icon systems to the app.
 #[cfg(feature = "icons")]
-pub(crate) fn add_icon_systems(app: &mut App) {
-    app.add_systems(Update, update_icon_displays);
+pub(crate) fn add_icon_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        (
+            (
+                update_composite_icon_displays,
+                update_icon_displays,
+                handle_missing_icons,
+            )
+                .chain(),
+            animate_icons,
+        )
+            .run_if(crate::subsystems::subsystem_enabled(
+                crate::subsystems::Subsystem::Icons,
+            )),
+    );
 }
 
 #[cfg(not(feature = "icons"))]
-pub(crate) fn add_icon_systems(_app: &mut App) {}
+pub(crate) fn add_icon_systems(_app: &mut App, _schedules: &crate::plugin::ControllerSchedules) {}
 
 #[cfg(test)]
 mod tests {
@@ -427,6 +1235,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_button_icon_confirm_cancel() {
+        assert_eq!(
+            ButtonIcon::confirm(ConfirmStyle::South),
+            ButtonIcon::FaceDown
+        );
+        assert_eq!(
+            ButtonIcon::cancel(ConfirmStyle::South),
+            ButtonIcon::FaceRight
+        );
+        assert_eq!(
+            ButtonIcon::confirm(ConfirmStyle::East),
+            ButtonIcon::FaceRight
+        );
+        assert_eq!(ButtonIcon::cancel(ConfirmStyle::East), ButtonIcon::FaceDown);
+    }
+
     #[test]
     fn test_button_icon_filename_xbox() {
         assert_eq!(
@@ -538,12 +1363,270 @@ mod tests {
         assert_eq!(ButtonIcon::Select.label(ControllerLayout::Nintendo), "-");
     }
 
+    #[test]
+    fn test_button_labels_default_falls_back_to_label() {
+        let labels = ButtonLabels::default();
+        assert_eq!(
+            labels.label(ButtonIcon::Start, ControllerLayout::PlayStation),
+            "Options"
+        );
+    }
+
+    #[test]
+    fn test_button_labels_overrides() {
+        let mut labels = ButtonLabels::default();
+        labels.set_all_layouts(ButtonIcon::Start, "Menü");
+        labels.set(ButtonIcon::Start, ControllerLayout::PlayStation, "Optionen");
+
+        assert_eq!(
+            labels.label(ButtonIcon::Start, ControllerLayout::PlayStation),
+            "Optionen"
+        );
+        assert_eq!(
+            labels.label(ButtonIcon::Start, ControllerLayout::Xbox),
+            "Menü"
+        );
+
+        labels.clear(ButtonIcon::Start);
+        assert_eq!(
+            labels.label(ButtonIcon::Start, ControllerLayout::Xbox),
+            "Menu"
+        );
+    }
+
+    #[test]
+    fn test_button_labels_localizer() {
+        struct Spanish;
+
+        impl ButtonLabelLocalizer for Spanish {
+            fn localize(&self, icon: ButtonIcon, _layout: ControllerLayout) -> Option<String> {
+                (icon == ButtonIcon::Select).then(|| "Vista".to_string())
+            }
+        }
+
+        let mut labels = ButtonLabels::default();
+        labels.set_localizer(Spanish);
+
+        assert_eq!(
+            labels.label(ButtonIcon::Select, ControllerLayout::Xbox),
+            "Vista"
+        );
+        assert_eq!(
+            labels.label(ButtonIcon::Start, ControllerLayout::Xbox),
+            "Menu"
+        );
+    }
+
+    #[test]
+    fn test_composite_prompt_layout_ordering() {
+        let prompt = CompositePrompt::new([
+            ButtonIcon::FaceUp,
+            ButtonIcon::FaceDown,
+            ButtonIcon::LeftBumper,
+        ]);
+        assert_eq!(
+            prompt.ordered(ControllerLayout::Xbox),
+            vec![
+                ButtonIcon::LeftBumper,
+                ButtonIcon::FaceDown,
+                ButtonIcon::FaceUp
+            ]
+        );
+        assert_eq!(
+            prompt.ordered(ControllerLayout::PlayStation),
+            vec![
+                ButtonIcon::LeftBumper,
+                ButtonIcon::FaceUp,
+                ButtonIcon::FaceDown
+            ]
+        );
+
+        // Modifiers stay first even when they would sort later
+        let modified = CompositePrompt::modified(ButtonIcon::FaceUp, ButtonIcon::LeftBumper);
+        assert_eq!(
+            modified.label(&ButtonLabels::default(), ControllerLayout::PlayStation),
+            "△ + L1"
+        );
+    }
+
+    #[test]
+    fn test_composite_prompt_sizing() {
+        let pair = CompositePrompt::new([ButtonIcon::LeftBumper, ButtonIcon::FaceDown]);
+        assert_eq!(pair.icon_size(IconSize::Large), IconSize::Large);
+
+        let triple = CompositePrompt::new([
+            ButtonIcon::LeftBumper,
+            ButtonIcon::RightBumper,
+            ButtonIcon::FaceDown,
+        ]);
+        assert_eq!(triple.icon_size(IconSize::Large), IconSize::Medium);
+        assert_eq!(triple.icon_size(IconSize::Small), IconSize::Small);
+    }
+
+    #[test]
+    fn test_composite_prompt_for_chord() {
+        let mut chords = ChordBindings::default();
+        chords.bind(
+            ButtonChord::from_gamepad_buttons(&[GamepadButton::North, GamepadButton::LeftTrigger]),
+            GameAction::Custom2,
+        );
+        let mut action_map = ActionMap::default();
+        action_map.bind_gamepad_with_modifier(
+            GameAction::Custom1,
+            GamepadButton::LeftTrigger,
+            GamepadButton::West,
+        );
+        let labels = ButtonLabels::default();
+
+        let chord = CompositePrompt::for_action(GameAction::Custom2, &action_map, &chords).unwrap();
+        assert_eq!(
+            chord.label(&labels, ControllerLayout::PlayStation),
+            "L1 + △"
+        );
+
+        let modified =
+            CompositePrompt::for_action(GameAction::Custom1, &action_map, &chords).unwrap();
+        assert_eq!(modified.label(&labels, ControllerLayout::Xbox), "LB + X");
+
+        let keys = ButtonChord::from_keys(&[KeyCode::ControlLeft, KeyCode::KeyC]);
+        assert_eq!(CompositePrompt::from_chord(&keys), None);
+    }
+
+    #[test]
+    fn test_composite_icon_display_spawns_glyphs() {
+        let mut app = App::new();
+        app.init_resource::<crate::config::ControllerConfig>()
+            .add_systems(Update, update_composite_icon_displays);
+        let display = app
+            .world_mut()
+            .spawn(CompositeIconDisplay {
+                prompt: CompositePrompt::modified(ButtonIcon::LeftBumper, ButtonIcon::FaceLeft),
+                size: IconSize::Medium,
+            })
+            .id();
+        app.update();
+
+        let children: Vec<Entity> = app
+            .world()
+            .get::<Children>(display)
+            .unwrap()
+            .iter()
+            .collect();
+        assert_eq!(children.len(), 3);
+        let first = app
+            .world()
+            .get::<ControllerIconDisplay>(children[0])
+            .unwrap();
+        assert_eq!(first.icon, ButtonIcon::LeftBumper);
+        assert!(
+            app.world()
+                .get::<CompositeIconSeparator>(children[1])
+                .is_some()
+        );
+
+        // Rebuilt rather than duplicated on change
+        app.world_mut()
+            .get_mut::<CompositeIconDisplay>(display)
+            .unwrap()
+            .prompt = CompositePrompt::new([ButtonIcon::FaceDown]);
+        app.update();
+        assert_eq!(app.world().get::<Children>(display).unwrap().len(), 1);
+    }
+
     #[test]
     fn test_controller_icon_assets_new() {
         let assets = ControllerIconAssets::new("assets/icons");
         assert_eq!(assets.base_path, "assets/icons");
     }
 
+    #[test]
+    fn test_controller_icon_assets_mark_missing() {
+        let mut assets = ControllerIconAssets::new("icons");
+        assert!(!assets.is_missing(
+            ButtonIcon::FaceDown,
+            ControllerLayout::PlayStation,
+            IconSize::Medium
+        ));
+
+        assets.mark_missing(
+            ButtonIcon::FaceDown,
+            ControllerLayout::PlayStation,
+            IconSize::Medium,
+        );
+        assert!(assets.is_missing(
+            ButtonIcon::FaceDown,
+            ControllerLayout::PlayStation,
+            IconSize::Medium
+        ));
+        assert!(!assets.is_missing(
+            ButtonIcon::FaceDown,
+            ControllerLayout::PlayStation,
+            IconSize::Small
+        ));
+    }
+
+    #[test]
+    fn test_controller_icon_assets_icon_path() {
+        let assets = ControllerIconAssets::new("assets/icons/");
+        assert_eq!(
+            assets.icon_path(
+                ButtonIcon::FaceDown,
+                ControllerLayout::PlayStation,
+                IconSize::Small
+            ),
+            "assets/icons/ps_cross_small.png"
+        );
+    }
+
+    #[test]
+    fn test_icon_animation_pulse_sample() {
+        let mut animation = IconAnimation::pulse(1.0, 1.5, 1.0);
+        assert_eq!(animation.sample().scale, 1.0);
+
+        animation.elapsed = 0.5;
+        let frame = animation.sample();
+        assert!((frame.scale - 1.5).abs() < 1e-5);
+        assert_eq!(frame.alpha, 1.0);
+        assert!(frame.atlas_index.is_none());
+    }
+
+    #[test]
+    fn test_icon_animation_fade_sample() {
+        let mut animation = IconAnimation::fade(0.25, 2.0);
+        assert_eq!(animation.sample().alpha, 1.0);
+
+        animation.elapsed = 0.25;
+        assert!((animation.sample().alpha - 0.25).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_icon_animation_frames_wrap() {
+        let mut animation = IconAnimation::frames(4, 3, 10.0);
+        assert_eq!(animation.sample().atlas_index, Some(4));
+
+        animation.elapsed = 0.25;
+        assert_eq!(animation.sample().atlas_index, Some(6));
+
+        animation.elapsed = 0.35;
+        assert_eq!(animation.sample().atlas_index, Some(4));
+    }
+
+    #[test]
+    fn test_icon_animation_play_pause() {
+        let mut animation = IconAnimation::pulse(1.0, 1.2, 1.0);
+        assert!(animation.playing);
+
+        animation.pause();
+        assert!(!animation.playing);
+
+        animation.toggle();
+        assert!(animation.playing);
+
+        animation.elapsed = 3.0;
+        animation.restart();
+        assert_eq!(animation.elapsed, 0.0);
+    }
+
     #[test]
     fn test_controller_icon_display_default() {
         let display = ControllerIconDisplay::default();
@@ -7,12 +7,14 @@ use bevy::prelude::*;
 use std::time::Duration;
 
 use crate::actions::{ActionState, GameAction};
+use crate::arbitration::{Claim, InputArbiter};
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 /// Maximum size of input buffer.
 const MAX_BUFFER_SIZE: usize = 32;
 
 /// A buffered input entry.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect)]
 pub struct BufferedInput {
     /// The action that was pressed.
     pub action: GameAction,
@@ -23,7 +25,8 @@ pub struct BufferedInput {
 }
 
 /// Input buffer resource for storing recent inputs.
-#[derive(Debug, Clone, Default, Resource)]
+#[derive(Debug, Clone, Default, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct InputBuffer {
     /// Ring buffer of recent inputs.
     pub inputs: Vec<BufferedInput>,
@@ -126,7 +129,7 @@ impl InputBuffer {
 }
 
 /// Combo definition.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect)]
 pub struct Combo {
     /// Name of the combo.
     pub name: String,
@@ -168,7 +171,8 @@ impl Combo {
 }
 
 /// Resource for managing combo definitions.
-#[derive(Debug, Clone, Default, Resource)]
+#[derive(Debug, Clone, Default, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct ComboRegistry {
     /// Registered combos.
     pub combos: Vec<Combo>,
@@ -220,31 +224,43 @@ pub fn update_input_buffer(
 }
 
 /// System to detect combos.
+///
+/// Matched combos are submitted to the [`InputArbiter`], which writes
+/// [`ComboDetected`] for the ones that win arbitration.
 pub fn detect_combos(
     buffer: Res<InputBuffer>,
     registry: Res<ComboRegistry>,
-    mut combo_events: MessageWriter<ComboDetected>,
+    mut arbiter: ResMut<InputArbiter>,
 ) {
     if buffer.is_changed() {
-        for combo_name in registry.check_combos(&buffer) {
-            combo_events.write(ComboDetected {
-                combo: combo_name,
-                gamepad: None,
-            });
+        for combo in registry.combos.iter().filter(|combo| combo.check(&buffer)) {
+            arbiter.submit(Claim::combo(combo.name.clone(), &combo.sequence));
         }
     }
 }
 
 /// Plugin for registering input buffer types.
 pub(crate) fn register_input_buffer_types(app: &mut App) {
-    app.init_resource::<InputBuffer>()
+    app.register_type::<BufferedInput>()
+        .register_type::<InputBuffer>()
+        .register_type::<Combo>()
+        .register_type::<ComboRegistry>()
+        .init_resource::<InputBuffer>()
         .init_resource::<ComboRegistry>()
         .add_message::<ComboDetected>();
 }
 
 /// Add input buffer systems to the app.
-pub(crate) fn add_input_buffer_systems(app: &mut App) {
-    app.add_systems(Update, (update_input_buffer, detect_combos).chain());
+pub(crate) fn add_input_buffer_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        (update_input_buffer, detect_combos)
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::InputBuffer)),
+    );
 }
 
 #[cfg(test)]
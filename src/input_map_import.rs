@@ -0,0 +1,611 @@
+//! Importers for other engines' input map formats.
+//!
+//! Ports of existing games can bring their bindings along instead of
+//! re-entering them. [`InputMapImporter`] reads the `[input]` section of a
+//! Godot `project.godot` file or a Unity `.inputactions` JSON asset and adds
+//! the bindings to an [`ActionMap`].
+//!
+//! Source action names are mapped to [`GameAction`]s explicitly. Unity
+//! composite parts are named `"<action>/<part>"`, e.g. `"Move/up"`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy_archie::actions::{ActionMap, GameAction};
+//! use bevy_archie::input_map_import::InputMapImporter;
+//!
+//! let project = std::fs::read_to_string("project.godot").unwrap();
+//! let importer = InputMapImporter::new()
+//!     .map("ui_accept", GameAction::Confirm)
+//!     .map("jump", GameAction::Primary);
+//!
+//! let mut map = ActionMap::default();
+//! let report = importer.import_godot(&project, &mut map).unwrap();
+//! for name in &report.unmapped_actions {
+//!     println!("No GameAction for {name}");
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::actions::{ActionMap, AxisDirection, GameAction};
+use crate::error::{Error, Result};
+
+/// Axis threshold used for imported stick and trigger bindings.
+const IMPORTED_AXIS_THRESHOLD: f32 = 0.5;
+
+/// A binding read from a source file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ImportedBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+    Axis(GamepadAxis, AxisDirection),
+}
+
+/// Summary of an import.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Number of bindings added to the action map.
+    pub bound: usize,
+    /// Source actions with no [`GameAction`] mapping.
+    pub unmapped_actions: Vec<String>,
+    /// Source inputs that have no equivalent, as `"<action>: <input>"`.
+    pub unsupported: Vec<String>,
+}
+
+/// Converts other engines' input maps into [`ActionMap`] bindings.
+#[derive(Debug, Clone, Default)]
+pub struct InputMapImporter {
+    /// Source action name to game action.
+    actions: HashMap<String, GameAction>,
+}
+
+impl InputMapImporter {
+    /// Create an importer with no action mappings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map a source action name to a game action.
+    #[must_use]
+    pub fn map(mut self, name: impl Into<String>, action: GameAction) -> Self {
+        self.actions.insert(name.into(), action);
+        self
+    }
+
+    /// Import the `[input]` section of a Godot 4 `project.godot` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InputMapImport`] if an action entry is malformed.
+    pub fn import_godot(&self, source: &str, map: &mut ActionMap) -> Result<ImportReport> {
+        let mut report = ImportReport::default();
+        let mut lines = source.lines();
+
+        // Skip to the [input] section
+        if !lines.any(|line| line.trim() == "[input]") {
+            return Ok(report);
+        }
+
+        let mut current: Option<(String, String)> = None;
+        for line in lines {
+            let trimmed = line.trim();
+            if let Some((name, body)) = &mut current {
+                body.push_str(trimmed);
+                body.push('\n');
+                if trimmed == "}" {
+                    let name = std::mem::take(name);
+                    let body = std::mem::take(body);
+                    current = None;
+                    let bindings = parse_godot_events(&body);
+                    self.apply(&name, bindings, map, &mut report);
+                }
+                continue;
+            }
+
+            if trimmed.starts_with('[') {
+                break;
+            }
+            if trimmed.is_empty() || trimmed.starts_with(';') {
+                continue;
+            }
+            let Some((name, rest)) = trimmed.split_once('=') else {
+                return Err(godot_error(format!(
+                    "expected `name={{...}}`, got {trimmed:?}"
+                )));
+            };
+            if !rest.trim_start().starts_with('{') {
+                return Err(godot_error(format!("action {name:?} is not a dictionary")));
+            }
+            current = Some((name.trim().to_string(), String::new()));
+        }
+
+        if let Some((name, _)) = current {
+            return Err(godot_error(format!("action {name:?} is not closed")));
+        }
+        Ok(report)
+    }
+
+    /// Import a Unity Input System `.inputactions` JSON asset.
+    ///
+    /// Bindings from every action map are imported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InputMapImport`] if the JSON is invalid or lacks the
+    /// `maps` array.
+    pub fn import_unity(&self, source: &str, map: &mut ActionMap) -> Result<ImportReport> {
+        let root: serde_json::Value =
+            serde_json::from_str(source).map_err(|err| Error::InputMapImport {
+                format: "Unity",
+                reason: err.to_string(),
+            })?;
+        let maps = root
+            .get("maps")
+            .and_then(serde_json::Value::as_array)
+            .ok_or_else(|| Error::InputMapImport {
+                format: "Unity",
+                reason: "missing `maps` array".to_string(),
+            })?;
+
+        let mut report = ImportReport::default();
+        for binding in maps
+            .iter()
+            .filter_map(|action_map| action_map.get("bindings"))
+            .filter_map(serde_json::Value::as_array)
+            .flatten()
+        {
+            let field = |key| binding.get(key).and_then(serde_json::Value::as_str);
+            let (Some(action), Some(path)) = (field("action"), field("path")) else {
+                continue;
+            };
+            if binding
+                .get("isComposite")
+                .and_then(serde_json::Value::as_bool)
+                == Some(true)
+            {
+                continue;
+            }
+
+            let name = if binding
+                .get("isPartOfComposite")
+                .and_then(serde_json::Value::as_bool)
+                == Some(true)
+            {
+                format!("{action}/{}", field("name").unwrap_or_default())
+            } else {
+                action.to_string()
+            };
+            let parsed = parse_unity_path(path).ok_or_else(|| path.to_string());
+            self.apply(&name, vec![parsed], map, &mut report);
+        }
+        Ok(report)
+    }
+
+    /// Add parsed bindings for a source action.
+    fn apply(
+        &self,
+        name: &str,
+        bindings: Vec<std::result::Result<ImportedBinding, String>>,
+        map: &mut ActionMap,
+        report: &mut ImportReport,
+    ) {
+        let Some(action) = self.actions.get(name).copied() else {
+            if !report
+                .unmapped_actions
+                .iter()
+                .any(|unmapped| unmapped == name)
+            {
+                report.unmapped_actions.push(name.to_string());
+            }
+            return;
+        };
+
+        for binding in bindings {
+            match binding {
+                Ok(ImportedBinding::Key(key)) => map.bind_key(action, key),
+                Ok(ImportedBinding::Mouse(button)) => map.bind_mouse(action, button),
+                Ok(ImportedBinding::Gamepad(button)) => map.bind_gamepad(action, button),
+                Ok(ImportedBinding::Axis(axis, direction)) => {
+                    map.bind_axis(action, axis, direction, IMPORTED_AXIS_THRESHOLD);
+                }
+                Err(input) => {
+                    report.unsupported.push(format!("{name}: {input}"));
+                    continue;
+                }
+            }
+            report.bound += 1;
+        }
+    }
+}
+
+fn godot_error(reason: String) -> Error {
+    Error::InputMapImport {
+        format: "Godot",
+        reason,
+    }
+}
+
+/// Parse the `Object(InputEvent...)` entries of a Godot action.
+fn parse_godot_events(body: &str) -> Vec<std::result::Result<ImportedBinding, String>> {
+    body.split("Object(")
+        .skip(1)
+        .map(|event| {
+            let kind = event.split(',').next().unwrap_or_default().trim();
+            let int = |key| godot_field(event, key).and_then(|value| value.parse::<i64>().ok());
+            let parsed = match kind {
+                "InputEventKey" => int("physical_keycode")
+                    .filter(|code| *code != 0)
+                    .or_else(|| int("keycode"))
+                    .and_then(godot_key)
+                    .map(ImportedBinding::Key),
+                "InputEventMouseButton" => int("button_index")
+                    .and_then(godot_mouse_button)
+                    .map(ImportedBinding::Mouse),
+                "InputEventJoypadButton" => int("button_index")
+                    .and_then(godot_joy_button)
+                    .map(ImportedBinding::Gamepad),
+                "InputEventJoypadMotion" => {
+                    let value = godot_field(event, "axis_value")
+                        .and_then(|value| value.parse::<f32>().ok())
+                        .unwrap_or(0.0);
+                    int("axis").and_then(|axis| godot_joy_axis(axis, value))
+                }
+                _ => None,
+            };
+            parsed.ok_or_else(|| kind.to_string())
+        })
+        .collect()
+}
+
+/// Read the raw value of a `"key":value` field.
+fn godot_field<'a>(event: &'a str, key: &str) -> Option<&'a str> {
+    let start = event.find(&format!("\"{key}\":"))? + key.len() + 3;
+    let rest = &event[start..];
+    let end = rest.find([',', ')']).unwrap_or(rest.len());
+    Some(rest[..end].trim())
+}
+
+/// Convert a Godot 4 `Key` code.
+fn godot_key(code: i64) -> Option<KeyCode> {
+    const SPECIAL: i64 = 4_194_304;
+    let key = match code {
+        32 => KeyCode::Space,
+        48 => KeyCode::Digit0,
+        49 => KeyCode::Digit1,
+        50 => KeyCode::Digit2,
+        51 => KeyCode::Digit3,
+        52 => KeyCode::Digit4,
+        53 => KeyCode::Digit5,
+        54 => KeyCode::Digit6,
+        55 => KeyCode::Digit7,
+        56 => KeyCode::Digit8,
+        57 => KeyCode::Digit9,
+        65..=90 => return letter_key(u8::try_from(code).ok()?),
+        _ => match code - SPECIAL {
+            1 => KeyCode::Escape,
+            2 => KeyCode::Tab,
+            4 => KeyCode::Backspace,
+            5 => KeyCode::Enter,
+            6 => KeyCode::NumpadEnter,
+            8 => KeyCode::Delete,
+            15 => KeyCode::ArrowLeft,
+            16 => KeyCode::ArrowUp,
+            17 => KeyCode::ArrowRight,
+            18 => KeyCode::ArrowDown,
+            21 => KeyCode::ShiftLeft,
+            22 => KeyCode::ControlLeft,
+            24 => KeyCode::AltLeft,
+            _ => return None,
+        },
+    };
+    Some(key)
+}
+
+/// Convert an uppercase ASCII letter to its key.
+fn letter_key(letter: u8) -> Option<KeyCode> {
+    const LETTERS: [KeyCode; 26] = [
+        KeyCode::KeyA,
+        KeyCode::KeyB,
+        KeyCode::KeyC,
+        KeyCode::KeyD,
+        KeyCode::KeyE,
+        KeyCode::KeyF,
+        KeyCode::KeyG,
+        KeyCode::KeyH,
+        KeyCode::KeyI,
+        KeyCode::KeyJ,
+        KeyCode::KeyK,
+        KeyCode::KeyL,
+        KeyCode::KeyM,
+        KeyCode::KeyN,
+        KeyCode::KeyO,
+        KeyCode::KeyP,
+        KeyCode::KeyQ,
+        KeyCode::KeyR,
+        KeyCode::KeyS,
+        KeyCode::KeyT,
+        KeyCode::KeyU,
+        KeyCode::KeyV,
+        KeyCode::KeyW,
+        KeyCode::KeyX,
+        KeyCode::KeyY,
+        KeyCode::KeyZ,
+    ];
+    LETTERS
+        .get(usize::from(letter.to_ascii_uppercase().checked_sub(b'A')?))
+        .copied()
+}
+
+fn godot_mouse_button(index: i64) -> Option<MouseButton> {
+    match index {
+        1 => Some(MouseButton::Left),
+        2 => Some(MouseButton::Right),
+        3 => Some(MouseButton::Middle),
+        _ => None,
+    }
+}
+
+fn godot_joy_button(index: i64) -> Option<GamepadButton> {
+    let button = match index {
+        0 => GamepadButton::South,
+        1 => GamepadButton::East,
+        2 => GamepadButton::West,
+        3 => GamepadButton::North,
+        4 => GamepadButton::Select,
+        5 => GamepadButton::Mode,
+        6 => GamepadButton::Start,
+        7 => GamepadButton::LeftThumb,
+        8 => GamepadButton::RightThumb,
+        9 => GamepadButton::LeftTrigger,
+        10 => GamepadButton::RightTrigger,
+        11 => GamepadButton::DPadUp,
+        12 => GamepadButton::DPadDown,
+        13 => GamepadButton::DPadLeft,
+        14 => GamepadButton::DPadRight,
+        _ => return None,
+    };
+    Some(button)
+}
+
+/// Convert a Godot joypad axis; Godot's stick Y axes point down.
+fn godot_joy_axis(axis: i64, value: f32) -> Option<ImportedBinding> {
+    let positive = value >= 0.0;
+    let direction = |up_is_positive: bool| {
+        if positive == up_is_positive {
+            AxisDirection::Positive
+        } else {
+            AxisDirection::Negative
+        }
+    };
+    let binding = match axis {
+        0 => ImportedBinding::Axis(GamepadAxis::LeftStickX, direction(true)),
+        1 => ImportedBinding::Axis(GamepadAxis::LeftStickY, direction(false)),
+        2 => ImportedBinding::Axis(GamepadAxis::RightStickX, direction(true)),
+        3 => ImportedBinding::Axis(GamepadAxis::RightStickY, direction(false)),
+        4 => ImportedBinding::Gamepad(GamepadButton::LeftTrigger2),
+        5 => ImportedBinding::Gamepad(GamepadButton::RightTrigger2),
+        _ => return None,
+    };
+    Some(binding)
+}
+
+/// Convert a Unity control path such as `<Gamepad>/buttonSouth`.
+fn parse_unity_path(path: &str) -> Option<ImportedBinding> {
+    let (device, control) = path.strip_prefix('<')?.split_once(">/")?;
+    match device {
+        "Keyboard" => unity_key(control).map(ImportedBinding::Key),
+        "Mouse" => match control {
+            "leftButton" => Some(ImportedBinding::Mouse(MouseButton::Left)),
+            "rightButton" => Some(ImportedBinding::Mouse(MouseButton::Right)),
+            "middleButton" => Some(ImportedBinding::Mouse(MouseButton::Middle)),
+            _ => None,
+        },
+        _ => unity_gamepad_control(control),
+    }
+}
+
+fn unity_key(control: &str) -> Option<KeyCode> {
+    let key = match control {
+        "space" => KeyCode::Space,
+        "enter" => KeyCode::Enter,
+        "numpadEnter" => KeyCode::NumpadEnter,
+        "escape" => KeyCode::Escape,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "leftArrow" => KeyCode::ArrowLeft,
+        "rightArrow" => KeyCode::ArrowRight,
+        "upArrow" => KeyCode::ArrowUp,
+        "downArrow" => KeyCode::ArrowDown,
+        "leftShift" => KeyCode::ShiftLeft,
+        "rightShift" => KeyCode::ShiftRight,
+        "leftCtrl" => KeyCode::ControlLeft,
+        "rightCtrl" => KeyCode::ControlRight,
+        "leftAlt" => KeyCode::AltLeft,
+        "rightAlt" => KeyCode::AltRight,
+        "0" => KeyCode::Digit0,
+        "1" => KeyCode::Digit1,
+        "2" => KeyCode::Digit2,
+        "3" => KeyCode::Digit3,
+        "4" => KeyCode::Digit4,
+        "5" => KeyCode::Digit5,
+        "6" => KeyCode::Digit6,
+        "7" => KeyCode::Digit7,
+        "8" => KeyCode::Digit8,
+        "9" => KeyCode::Digit9,
+        letter if letter.len() == 1 => return letter_key(letter.as_bytes()[0]),
+        _ => return None,
+    };
+    Some(key)
+}
+
+fn unity_gamepad_control(control: &str) -> Option<ImportedBinding> {
+    let button = match control {
+        "buttonSouth" => GamepadButton::South,
+        "buttonEast" => GamepadButton::East,
+        "buttonWest" => GamepadButton::West,
+        "buttonNorth" => GamepadButton::North,
+        "leftShoulder" => GamepadButton::LeftTrigger,
+        "rightShoulder" => GamepadButton::RightTrigger,
+        "leftTrigger" => GamepadButton::LeftTrigger2,
+        "rightTrigger" => GamepadButton::RightTrigger2,
+        "start" => GamepadButton::Start,
+        "select" => GamepadButton::Select,
+        "leftStickPress" => GamepadButton::LeftThumb,
+        "rightStickPress" => GamepadButton::RightThumb,
+        "dpad/up" => GamepadButton::DPadUp,
+        "dpad/down" => GamepadButton::DPadDown,
+        "dpad/left" => GamepadButton::DPadLeft,
+        "dpad/right" => GamepadButton::DPadRight,
+        _ => {
+            let (stick, direction) = control.split_once('/')?;
+            let (x, y) = match stick {
+                "leftStick" => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+                "rightStick" => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+                _ => return None,
+            };
+            return match direction {
+                "up" => Some(ImportedBinding::Axis(y, AxisDirection::Positive)),
+                "down" => Some(ImportedBinding::Axis(y, AxisDirection::Negative)),
+                "left" => Some(ImportedBinding::Axis(x, AxisDirection::Negative)),
+                "right" => Some(ImportedBinding::Axis(x, AxisDirection::Positive)),
+                _ => None,
+            };
+        }
+    };
+    Some(ImportedBinding::Gamepad(button))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GODOT: &str = r#"
+[application]
+
+config/name="Demo"
+
+[input]
+
+jump={
+"deadzone": 0.5,
+"events": [Object(InputEventKey,"resource_local_to_scene":false,"device":-1,"keycode":0,"physical_keycode":32,"unicode":0,"echo":false,"script":null)
+, Object(InputEventJoypadButton,"resource_local_to_scene":false,"device":-1,"button_index":0,"pressure":0.0,"pressed":false,"script":null)
+]
+}
+look_up={
+"deadzone": 0.5,
+"events": [Object(InputEventJoypadMotion,"resource_local_to_scene":false,"device":-1,"axis":3,"axis_value":-1.0,"script":null)
+]
+}
+debug_menu={
+"deadzone": 0.5,
+"events": [Object(InputEventKey,"resource_local_to_scene":false,"device":-1,"keycode":0,"physical_keycode":4194332,"script":null)
+]
+}
+
+[rendering]
+"#;
+
+    #[test]
+    fn test_import_godot() {
+        let importer = InputMapImporter::new()
+            .map("jump", GameAction::Secondary)
+            .map("look_up", GameAction::LookUp);
+        let mut map = ActionMap::default();
+        map.clear_bindings(GameAction::Secondary);
+
+        let report = importer.import_godot(GODOT, &mut map).unwrap();
+        assert_eq!(report.bound, 3);
+        assert_eq!(report.unmapped_actions, vec!["debug_menu".to_string()]);
+        assert_eq!(
+            map.key_bindings.get(&GameAction::Secondary),
+            Some(&vec![KeyCode::Space])
+        );
+        assert_eq!(
+            map.gamepad_bindings.get(&GameAction::Secondary),
+            Some(&vec![GamepadButton::South])
+        );
+        assert!(map.axis_bindings[&GameAction::LookUp].contains(&(
+            GamepadAxis::RightStickY,
+            AxisDirection::Positive,
+            IMPORTED_AXIS_THRESHOLD
+        )));
+    }
+
+    #[test]
+    fn test_import_godot_reports_unsupported_events() {
+        let importer = InputMapImporter::new().map("debug_menu", GameAction::Select);
+        let mut map = ActionMap::default();
+        let report = importer.import_godot(GODOT, &mut map).unwrap();
+        assert_eq!(report.bound, 0);
+        assert_eq!(
+            report.unsupported,
+            vec!["debug_menu: InputEventKey".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_import_godot_unclosed_action_is_an_error() {
+        let importer = InputMapImporter::new();
+        let mut map = ActionMap::default();
+        let result = importer.import_godot("[input]\njump={\n\"events\": []\n", &mut map);
+        assert!(matches!(
+            result,
+            Err(Error::InputMapImport {
+                format: "Godot",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_import_unity() {
+        let json = r#"{
+            "name": "Controls",
+            "maps": [{
+                "name": "Player",
+                "actions": [{ "name": "Jump", "type": "Button" }],
+                "bindings": [
+                    { "path": "<Gamepad>/buttonSouth", "action": "Jump" },
+                    { "path": "<Keyboard>/space", "action": "Jump" },
+                    { "name": "WASD", "path": "2DVector", "action": "Move", "isComposite": true },
+                    { "name": "up", "path": "<Keyboard>/w", "action": "Move", "isPartOfComposite": true },
+                    { "path": "<Gamepad>/leftStick/up", "action": "Move/up" },
+                    { "path": "<Pen>/tip", "action": "Jump" }
+                ]
+            }]
+        }"#;
+        let importer = InputMapImporter::new()
+            .map("Jump", GameAction::Secondary)
+            .map("Move/up", GameAction::Up);
+        let mut map = ActionMap::default();
+        map.clear_bindings(GameAction::Secondary);
+        map.clear_bindings(GameAction::Up);
+
+        let report = importer.import_unity(json, &mut map).unwrap();
+        assert_eq!(report.bound, 4);
+        assert_eq!(report.unsupported, vec!["Jump: <Pen>/tip".to_string()]);
+        assert_eq!(map.key_bindings[&GameAction::Up], vec![KeyCode::KeyW]);
+        assert_eq!(
+            map.axis_bindings[&GameAction::Up],
+            vec![(
+                GamepadAxis::LeftStickY,
+                AxisDirection::Positive,
+                IMPORTED_AXIS_THRESHOLD
+            )]
+        );
+    }
+
+    #[test]
+    fn test_import_unity_rejects_invalid_json() {
+        let importer = InputMapImporter::new();
+        let mut map = ActionMap::default();
+        assert!(importer.import_unity("{", &mut map).is_err());
+        assert!(importer.import_unity("{}", &mut map).is_err());
+    }
+}
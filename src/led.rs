@@ -0,0 +1,213 @@
+//! Controller LED and lightbar control.
+//!
+//! A [`ControllerLed`] component on a gamepad entity holds the lightbar
+//! color (`DualShock` 4, `DualSense`) and player indicator LEDs (`DualSense`,
+//! Xbox 360 ring, Switch) the game wants. Send [`SetLedRequest`] to change
+//! them; the motion/HID backends write the component to the hardware.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::led::SetLedRequest;
+//! use bevy_archie::multiplayer::ControllerAssigned;
+//!
+//! const PLAYER_COLORS: [Color; 4] = [
+//!     Color::srgb(0.9, 0.2, 0.2),
+//!     Color::srgb(0.2, 0.4, 0.9),
+//!     Color::srgb(0.2, 0.8, 0.3),
+//!     Color::srgb(0.9, 0.8, 0.2),
+//! ];
+//!
+//! fn tint_lightbars(
+//!     mut assigned: MessageReader<ControllerAssigned>,
+//!     mut leds: MessageWriter<SetLedRequest>,
+//! ) {
+//!     for event in assigned.read() {
+//!         let color = PLAYER_COLORS[usize::from(event.player.id()) % PLAYER_COLORS.len()];
+//!         leds.write(SetLedRequest::lightbar(event.gamepad, color));
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::multiplayer::PlayerId;
+use crate::profiles::ControllerModel;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// LED state wanted for a gamepad.
+///
+/// `None` fields leave that LED as the controller has it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct ControllerLed {
+    /// Lightbar color (`DualShock` 4, `DualSense`).
+    pub lightbar: Option<Color>,
+    /// Player indicator LED bitmask, lowest bit first.
+    pub player_leds: Option<u8>,
+}
+
+impl ControllerLed {
+    /// Lightbar color as 8-bit sRGB.
+    #[must_use]
+    pub fn lightbar_rgb(&self) -> Option<[u8; 3]> {
+        self.lightbar
+            .map(|color| color.to_srgba().to_u8_array_no_alpha())
+    }
+
+    /// Apply a request's fields over this state.
+    pub fn apply(&mut self, request: &SetLedRequest) {
+        if let Some(color) = request.lightbar {
+            self.lightbar = Some(color);
+        }
+        if let Some(mask) = request.player_leds {
+            self.player_leds = Some(mask);
+        }
+    }
+}
+
+/// Player indicator bitmask showing a player number on a controller.
+///
+/// `DualSense` uses its five-LED patterns, the Xbox 360 ring lights one
+/// quadrant, and Switch controllers light one more LED per player. Returns
+/// 0 for controllers without player LEDs.
+#[must_use]
+pub fn player_led_mask(model: ControllerModel, player: PlayerId) -> u8 {
+    let index = usize::from(player.id() % 4);
+    match model {
+        ControllerModel::PS5 => [0b00100, 0b01010, 0b10101, 0b11011][index],
+        ControllerModel::Xbox360 => 1 << index,
+        ControllerModel::SwitchPro
+        | ControllerModel::SwitchJoyCon
+        | ControllerModel::Switch2Pro
+        | ControllerModel::Switch2GC => [0b0001, 0b0011, 0b0111, 0b1111][index],
+        _ => 0,
+    }
+}
+
+/// Request to change a gamepad's LEDs.
+///
+/// `None` fields keep the current setting.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct SetLedRequest {
+    /// Gamepad to change.
+    pub gamepad: Entity,
+    /// New lightbar color.
+    pub lightbar: Option<Color>,
+    /// New player indicator bitmask.
+    pub player_leds: Option<u8>,
+}
+
+impl SetLedRequest {
+    /// Set the lightbar color.
+    #[must_use]
+    pub const fn lightbar(gamepad: Entity, color: Color) -> Self {
+        Self {
+            gamepad,
+            lightbar: Some(color),
+            player_leds: None,
+        }
+    }
+
+    /// Set the player indicator bitmask.
+    #[must_use]
+    pub const fn player_leds(gamepad: Entity, mask: u8) -> Self {
+        Self {
+            gamepad,
+            lightbar: None,
+            player_leds: Some(mask),
+        }
+    }
+
+    /// Show a player number on the player indicator LEDs.
+    #[must_use]
+    pub fn player(gamepad: Entity, model: ControllerModel, player: PlayerId) -> Self {
+        Self::player_leds(gamepad, player_led_mask(model, player))
+    }
+}
+
+/// System to apply LED requests to [`ControllerLed`] components.
+pub fn handle_led_requests(
+    mut requests: MessageReader<SetLedRequest>,
+    mut leds: Query<&mut ControllerLed>,
+    gamepads: Query<(), With<Gamepad>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        if let Ok(mut led) = leds.get_mut(request.gamepad) {
+            led.apply(request);
+        } else if gamepads.contains(request.gamepad) {
+            let mut led = ControllerLed::default();
+            led.apply(request);
+            commands.entity(request.gamepad).insert(led);
+        }
+    }
+}
+
+/// Plugin for registering LED types.
+pub(crate) fn register_led_types(app: &mut App) {
+    app.register_type::<ControllerLed>()
+        .add_message::<SetLedRequest>();
+}
+
+/// Add LED systems to the app.
+pub(crate) fn add_led_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        handle_led_requests.run_if(subsystem_enabled(Subsystem::Leds)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_led_masks() {
+        assert_eq!(player_led_mask(ControllerModel::PS5, PlayerId(0)), 0b00100);
+        assert_eq!(player_led_mask(ControllerModel::PS5, PlayerId(3)), 0b11011);
+        assert_eq!(
+            player_led_mask(ControllerModel::Xbox360, PlayerId(2)),
+            0b0100
+        );
+        assert_eq!(
+            player_led_mask(ControllerModel::SwitchPro, PlayerId(1)),
+            0b0011
+        );
+        assert_eq!(
+            player_led_mask(ControllerModel::XboxSeriesXS, PlayerId(0)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_led_request_keeps_unset_fields() {
+        let gamepad = Entity::from_bits(1);
+        let mut led = ControllerLed::default();
+        led.apply(&SetLedRequest::lightbar(
+            gamepad,
+            Color::srgb(1.0, 0.0, 0.0),
+        ));
+        led.apply(&SetLedRequest::player_leds(gamepad, 0b101));
+
+        assert_eq!(led.lightbar_rgb(), Some([255, 0, 0]));
+        assert_eq!(led.player_leds, Some(0b101));
+    }
+
+    #[test]
+    fn test_led_requests_insert_component() {
+        let mut app = App::new();
+        app.add_message::<SetLedRequest>()
+            .add_systems(Update, handle_led_requests);
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+        app.world_mut()
+            .write_message(SetLedRequest::lightbar(gamepad, Color::BLACK));
+        app.update();
+
+        let led = app.world().get::<ControllerLed>(gamepad).unwrap();
+        assert_eq!(led.lightbar_rgb(), Some([0, 0, 0]));
+        assert_eq!(led.player_leds, None);
+    }
+}
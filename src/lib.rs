@@ -32,16 +32,23 @@
 //! - Controller icon system with automatic layout detection
 //! - Input action mapping with customizable bindings
 //! - Controller remapping at runtime
-//! - Virtual keyboard for controller text input
+//! - Slider, stepper and toggle widgets for options menus
+//! - Virtual keyboard and chord typing for controller text input
 //! - Configurable deadzones and sensitivity
 //! - Haptic feedback and rumble patterns
 //! - Input buffering and combo detection
 //! - Multiplayer controller ownership
+//! - Controller lightbar and player LED control
+//! - Controller battery level and low-battery warnings
+//! - Vendor/product ID and power info via gilrs (`gilrs` feature)
+//! - Steam Input action sets, glyph styles and binding panel (`steam-input` feature)
 //! - Gyroscope and accelerometer support
 //! - `PlayStation` touchpad support
 //! - Action modifiers (hold, double-tap, long-press)
 //! - Controller profiles and auto-detection
 //! - Debug tools and input visualization
+//! - Per-action input trace logging
+//! - Screenshot and clip capture from a gamepad chord
 //!
 //! ## Quick Start
 //!
@@ -55,53 +62,174 @@
 //!     .run();
 //! ```
 
+pub mod action_map_file;
 pub mod action_modifiers;
+pub mod action_usage;
 pub mod actions;
+pub mod arbitration;
+pub mod attract_mode;
+pub mod audio_haptics;
+pub mod battery;
+pub mod binding_lint;
+pub mod capture;
+pub mod characterization;
+pub mod charge;
+#[cfg(feature = "virtual_keyboard")]
+pub mod chord_typing;
+pub mod chords;
 pub mod config;
 pub mod constants;
+pub mod deadzone_advisor;
 pub mod debug;
 pub mod detection;
+#[cfg(feature = "gilrs")]
+pub mod device_info;
+pub mod diagnostics;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "virtual_keyboard")]
+pub mod file_picker;
 pub mod gyro;
 pub mod haptics;
+pub mod hints;
 pub mod icons;
 pub mod input_buffer;
+pub mod input_map_import;
+pub mod led;
+pub mod mash;
 pub mod motion;
 pub mod multiplayer;
+pub mod navigation;
+pub mod player_settings;
 pub mod plugin;
+pub mod power;
 pub mod profiles;
+pub mod qte;
 #[cfg(feature = "remapping")]
 pub mod remapping;
+pub mod repeat;
+pub mod replay_test;
+pub mod rumble_asset;
+pub mod safety;
+pub mod scroll;
+pub mod simulated_input;
+pub mod spatial_rumble;
+#[cfg(feature = "steam-input")]
+pub mod steam_input;
+pub mod stick;
+pub mod subsystems;
+pub mod suspend;
 pub mod touchpad;
+pub mod trace;
+pub mod ui_sound;
+pub mod vehicle_haptics;
+pub mod vehicle_input;
 pub mod virtual_cursor;
+pub mod virtual_input;
 #[cfg(feature = "virtual_keyboard")]
 pub mod virtual_keyboard;
+pub mod widgets;
+pub mod window_focus;
+
+pub use error::{Error, Result};
 
 pub mod prelude {
     //! Convenient imports for common use cases.
 
+    pub use crate::action_map_file::{ACTION_MAP_VERSION, BindingFormat};
     pub use crate::action_modifiers::{ActionModifier, ModifiedActionEvent, ModifierConfig};
-    pub use crate::actions::{ActionMap, ActionState, GameAction};
-    pub use crate::config::{ControllerConfig, ControllerLayout};
-    pub use crate::debug::{InputDebugger, InputPlayback, InputRecorder};
+    pub use crate::action_usage::ActionUsageTracker;
+    pub use crate::actions::{
+        ActionMap, ActionPlugin, ActionState, Actionlike, GameAction, GamepadActionState,
+        InputCapture,
+    };
+    pub use crate::arbitration::{InputArbiter, Interpretation};
+    pub use crate::attract_mode::{AttractMode, AttractModeChanged};
+    pub use crate::battery::{BatteryLow, BatterySettings, ChargingState, ControllerBattery};
+    pub use crate::binding_lint::{BindingIssue, BindingLint, BindingReport};
+    pub use crate::capture::{CaptureBinding, CaptureKind, CaptureRequest};
+    pub use crate::characterization::{CharacterizationReport, ControllerCharacterizer};
+    pub use crate::charge::{ChargeAction, ChargeReleased, ChargeStageReached};
+    pub use crate::config::{ConfirmStyle, ControllerConfig, ControllerLayout, RepeatStages};
+    pub use crate::deadzone_advisor::{DeadzoneAdvisor, DeadzoneRecommendation};
+    pub use crate::debug::{
+        FrameStepCommand, InputDebugger, InputPlayback, InputRecorder, InputStepper, StreamerMode,
+    };
     pub use crate::detection::{InputDevice, InputDeviceState};
-    pub use crate::gyro::{AccelData, GyroData, MotionConfig, MotionGesture};
-    pub use crate::haptics::{RumbleController, RumbleIntensity, RumblePattern, RumbleRequest};
-    pub use crate::icons::{ControllerIconAssets, IconSize};
+    pub use crate::gyro::{
+        AccelData, ControllerGrip, ControllerGripChanged, GripDetector, GyroAim, GyroData,
+        MotionConfig, MotionGesture,
+    };
+    pub use crate::haptics::{
+        HapticBindings, HapticEvent, HapticFeedback, HapticTimeline, RumbleController, RumbleCurve,
+        RumbleEasing, RumbleIntensity, RumbleKeyframe, RumblePattern, RumbleRequest, RumbleScale,
+    };
+    pub use crate::hints::{HintPrompt, HintRule, HintScheduler, ShowHintEvent};
+    pub use crate::icons::{CompositeIconDisplay, CompositePrompt, ControllerIconAssets, IconSize};
     pub use crate::input_buffer::{Combo, ComboRegistry, InputBuffer};
-    pub use crate::multiplayer::{ControllerOwnership, Player, PlayerId};
-    pub use crate::plugin::ControllerPlugin;
+    pub use crate::input_map_import::{ImportReport, InputMapImporter};
+    pub use crate::led::{ControllerLed, SetLedRequest};
+    pub use crate::mash::MashMeter;
+    pub use crate::multiplayer::{
+        ControllerOwnership, Player, PlayerActionMaps, PlayerActionStates, PlayerId,
+    };
+    pub use crate::navigation::{Focusable, NavDirection, NavEvent, NavRequest};
+    pub use crate::player_settings::{
+        AccessibilityOptions, PlayerProfileChanged, PlayerSettings, PlayerSettingsStore,
+        SwitchPlayerProfile, VibrationPreferences,
+    };
+    pub use crate::plugin::{ControllerPlugin, ControllerSchedules};
+    pub use crate::power::InputPowerProfile;
     pub use crate::profiles::{
-        ControllerModel, ControllerProfile, DetectedController, ProfileRegistry,
+        ContributionReport, ControllerModel, ControllerProfile, DetectedController,
+        ProfileContribution, ProfileRegistry,
     };
-    pub use crate::touchpad::{TouchpadConfig, TouchpadData, TouchpadGesture};
+    pub use crate::qte::{QteCompleted, QteOutcome, QtePrompt, QuickTimeEvent};
+    pub use crate::repeat::ActionRepeat;
+    pub use crate::safety::{HoldToEnable, SafetyGates};
+    pub use crate::scroll::{ScrollBounce, ScrollEdge, ScrollFling};
+    pub use crate::simulated_input::SimulatedInput;
+    pub use crate::spatial_rumble::{RumbleFalloff, SpatialRumble};
+    pub use crate::stick::{
+        DirectionMode, Stick, Stick8Way, StickDirection, StickTwirl, TwirlDirection,
+    };
+    pub use crate::subsystems::{EnabledSubsystems, SetSubsystemEnabled, Subsystem};
+    pub use crate::suspend::{InputSystemResumed, InputSystemSuspended, SuspendSettings};
+    pub use crate::touchpad::{
+        GestureEngine, TouchpadConfig, TouchpadData, TouchpadGesture, TouchpadLayout,
+        TouchpadRegions, TouchscreenGestureEvent, TouchscreenGesturePlugin,
+    };
+    pub use crate::trace::InputTrace;
+    pub use crate::ui_sound::{UiNavigationSound, UiNavigationSoundKind};
+    pub use crate::vehicle_haptics::VehicleHaptics;
+    pub use crate::vehicle_input::{Steering, Throttle, ThrottleDetentReached, ThrottleInput};
+    pub use crate::widgets::{
+        ActionPromptDisplay, SliderWidget, StepperWidget, ToggleWidget, WidgetPrompts, WidgetValue,
+        WidgetValueChanged,
+    };
+    pub use crate::window_focus::{InputFocusChanged, WindowFocusSettings, WindowFocusState};
 
     #[cfg(feature = "remapping")]
     pub use crate::remapping::{RemapButton, RemapEvent, RemappingState, StartRemapEvent};
 
-    pub use crate::virtual_cursor::{VirtualCursor, VirtualCursorClick, VirtualCursorState};
+    #[cfg(feature = "steam-input")]
+    pub use crate::steam_input::{SteamAnalogDirection, SteamInput, SteamInputBindings};
+
+    pub use crate::virtual_cursor::{
+        CursorDragEnd, CursorDragMove, CursorDragStart, CursorInputMode, CursorWindow, Draggable,
+        VirtualCursor, VirtualCursorClick, VirtualCursorState,
+    };
+
+    #[cfg(feature = "virtual_keyboard")]
+    pub use crate::chord_typing::{ChordChart, ChordOutput, ChordTyped, ChordTypingState};
+
+    #[cfg(feature = "virtual_keyboard")]
+    pub use crate::file_picker::{FilePicker, FilePickerMode, FilePickerResult, OpenFilePicker};
 
     #[cfg(feature = "virtual_keyboard")]
     pub use crate::virtual_keyboard::{
-        VirtualKeyboard, VirtualKeyboardEvent, VirtualKeyboardState,
+        VirtualKeyboard, VirtualKeyboardEvent, VirtualKeyboardSession, VirtualKeyboardSessionEvent,
+        VirtualKeyboardState,
     };
 }
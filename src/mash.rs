@@ -0,0 +1,239 @@
+//! Button-mash rate measurement.
+//!
+//! Mini-games and struggle mechanics often care about how fast the player is
+//! pressing rather than how many presses they have made. A [`MashMeter`]
+//! tracks presses of one action over a sliding window and exposes a smoothed
+//! presses-per-second rate and a 0.0-1.0 intensity.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::mash::MashMeter;
+//!
+//! fn spawn_struggle(mut commands: Commands) {
+//!     commands.spawn(MashMeter::new(GameAction::Confirm).with_max_rate(8.0));
+//! }
+//!
+//! fn struggle(meters: Query<&MashMeter>) {
+//!     for meter in &meters {
+//!         if meter.intensity() > 0.75 {
+//!             info!("Breaking free!");
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::actions::{ActionState, GameAction};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Component measuring how fast an action is being mashed.
+#[derive(Debug, Clone, Component)]
+pub struct MashMeter {
+    /// The action being measured.
+    pub action: GameAction,
+    /// Length of the sliding window in seconds.
+    pub window: f32,
+    /// Rate (presses per second) that maps to an intensity of 1.0.
+    pub max_rate: f32,
+    /// How quickly the smoothed rate follows the raw rate (per second).
+    ///
+    /// Higher values react faster; `0.0` disables smoothing.
+    pub smoothing: f32,
+    /// Timestamps of presses inside the window.
+    presses: VecDeque<f32>,
+    /// Seconds since the meter was created.
+    time: f32,
+    /// Smoothed rate in presses per second.
+    rate: f32,
+}
+
+impl MashMeter {
+    /// Create a meter for an action with a one second window.
+    #[must_use]
+    pub fn new(action: GameAction) -> Self {
+        Self {
+            action,
+            window: 1.0,
+            max_rate: 10.0,
+            smoothing: 8.0,
+            presses: VecDeque::new(),
+            time: 0.0,
+            rate: 0.0,
+        }
+    }
+
+    /// Set the sliding window length in seconds.
+    #[must_use]
+    pub const fn with_window(mut self, window: f32) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set the rate that maps to full intensity.
+    #[must_use]
+    pub const fn with_max_rate(mut self, max_rate: f32) -> Self {
+        self.max_rate = max_rate;
+        self
+    }
+
+    /// Set the smoothing speed.
+    #[must_use]
+    pub const fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Record a press at the current time.
+    pub fn record_press(&mut self) {
+        self.presses.push_back(self.time);
+    }
+
+    /// Advance the meter by `delta` seconds, dropping presses that have
+    /// left the window and updating the smoothed rate.
+    pub fn tick(&mut self, delta: f32) {
+        self.time += delta;
+
+        let cutoff = self.time - self.window;
+        while self.presses.front().is_some_and(|&press| press < cutoff) {
+            self.presses.pop_front();
+        }
+
+        let raw = self.raw_rate();
+        if self.smoothing <= 0.0 {
+            self.rate = raw;
+        } else {
+            let blend = 1.0 - (-self.smoothing * delta).exp();
+            self.rate += (raw - self.rate) * blend;
+        }
+    }
+
+    /// Update the meter from the action state for one frame.
+    pub fn update(&mut self, delta: f32, action_state: &ActionState) {
+        if action_state.just_pressed(self.action) {
+            self.record_press();
+        }
+        self.tick(delta);
+    }
+
+    /// Unsmoothed presses per second over the window.
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "press counts are far below f32 precision limits"
+    )]
+    pub fn raw_rate(&self) -> f32 {
+        self.presses.len() as f32 / self.window.max(f32::EPSILON)
+    }
+
+    /// Smoothed presses per second.
+    #[must_use]
+    pub const fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Smoothed rate normalized against [`Self::max_rate`] (0.0-1.0).
+    #[must_use]
+    pub fn intensity(&self) -> f32 {
+        (self.rate / self.max_rate.max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+
+    /// Clear all recorded presses.
+    pub fn reset(&mut self) {
+        self.presses.clear();
+        self.rate = 0.0;
+    }
+}
+
+/// System to update mash meters.
+pub fn update_mash_meters(
+    time: Res<Time>,
+    action_state: Res<ActionState>,
+    mut meters: Query<&mut MashMeter>,
+) {
+    for mut meter in &mut meters {
+        meter.update(time.delta_secs(), &action_state);
+    }
+}
+
+/// Add mash meter systems to the app.
+pub(crate) fn add_mash_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        update_mash_meters.run_if(subsystem_enabled(Subsystem::Mash)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_raw_rate_counts_window() {
+        let mut meter = MashMeter::new(GameAction::Confirm).with_smoothing(0.0);
+        for _ in 0..5 {
+            meter.record_press();
+            meter.tick(0.1);
+        }
+
+        assert_relative_eq!(meter.raw_rate(), 5.0);
+        assert_relative_eq!(meter.rate(), 5.0);
+        assert_relative_eq!(meter.intensity(), 0.5);
+    }
+
+    #[test]
+    fn test_presses_leave_window() {
+        let mut meter = MashMeter::new(GameAction::Confirm).with_window(0.5);
+        meter.record_press();
+        meter.tick(0.4);
+        assert_relative_eq!(meter.raw_rate(), 2.0);
+
+        meter.tick(0.2);
+        assert_relative_eq!(meter.raw_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_smoothing_lags_raw_rate() {
+        let mut meter = MashMeter::new(GameAction::Confirm);
+        for _ in 0..3 {
+            meter.record_press();
+        }
+        meter.tick(0.05);
+
+        assert!(meter.rate() > 0.0);
+        assert!(meter.rate() < meter.raw_rate());
+    }
+
+    #[test]
+    fn test_intensity_clamped() {
+        let mut meter = MashMeter::new(GameAction::Confirm)
+            .with_max_rate(2.0)
+            .with_smoothing(0.0);
+        for _ in 0..10 {
+            meter.record_press();
+        }
+        meter.tick(0.0);
+        assert_relative_eq!(meter.intensity(), 1.0);
+
+        meter.reset();
+        assert_relative_eq!(meter.intensity(), 0.0);
+    }
+
+    #[test]
+    fn test_update_reads_action_state() {
+        let mut meter = MashMeter::new(GameAction::Confirm).with_smoothing(0.0);
+        let mut state = ActionState::default();
+
+        state.set_pressed(GameAction::Confirm, true);
+        meter.update(0.1, &state);
+        state.reset_frame_state();
+        meter.update(0.1, &state);
+
+        assert_relative_eq!(meter.raw_rate(), 1.0);
+    }
+}
@@ -77,6 +77,8 @@ pub mod dualshock3_calibration {
 pub mod switch_calibration {
     /// Switch gyroscope sensitivity (from official SDK docs).
     pub const GYRO_SENSITIVITY: f32 = 13371.0;
+    /// Degrees per second read as [`GYRO_SENSITIVITY`].
+    pub const GYRO_SCALE_DPS: f32 = 936.0;
     /// Switch accelerometer sensitivity.
     pub const ACCEL_SENSITIVITY: f32 = 4096.0;
 
@@ -84,7 +86,7 @@ pub mod switch_calibration {
     #[must_use]
     pub fn gyro_to_rads(raw: i16) -> f32 {
         const DEG_TO_RAD: f32 = std::f32::consts::PI / 180.0;
-        (f32::from(raw) / GYRO_SENSITIVITY) * DEG_TO_RAD
+        (f32::from(raw) * GYRO_SCALE_DPS / GYRO_SENSITIVITY) * DEG_TO_RAD
     }
 
     /// Convert raw Switch accelerometer value to m/s².
@@ -112,6 +114,48 @@ pub struct MotionData {
     pub accel_y: f32,
     /// Accelerometer Z in m/s².
     pub accel_z: f32,
+    /// Sensor timestamp in seconds, if the device reports one.
+    ///
+    /// Only differences between timestamps are used, so any epoch works.
+    pub timestamp: Option<f64>,
+}
+
+/// Features a backend provides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackendCapabilities {
+    /// Gyroscope data.
+    pub gyro: bool,
+    /// Accelerometer data.
+    pub accelerometer: bool,
+    /// Touchpad data.
+    pub touchpad: bool,
+    /// Controllable LEDs (lightbar, player indicators).
+    pub leds: bool,
+    /// Adaptive trigger effects.
+    pub adaptive_triggers: bool,
+}
+
+impl BackendCapabilities {
+    /// Gyroscope and accelerometer.
+    pub const MOTION: Self = Self {
+        gyro: true,
+        accelerometer: true,
+        touchpad: false,
+        leds: false,
+        adaptive_triggers: false,
+    };
+
+    /// Combine the capabilities of two backends.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            gyro: self.gyro || other.gyro,
+            accelerometer: self.accelerometer || other.accelerometer,
+            touchpad: self.touchpad || other.touchpad,
+            leds: self.leds || other.leds,
+            adaptive_triggers: self.adaptive_triggers || other.adaptive_triggers,
+        }
+    }
 }
 
 /// Trait for motion control backends.
@@ -124,11 +168,31 @@ pub trait MotionBackend {
     /// Returns `Some(MotionData)` if new data is available, `None` otherwise.
     fn poll(&mut self) -> Option<MotionData>;
 
+    /// Poll every report received since the last call, oldest first.
+    ///
+    /// The default returns the single result of [`MotionBackend::poll`].
+    /// Backends that queue reports should override this so none are lost.
+    fn poll_batch(&mut self) -> Vec<MotionData> {
+        self.poll().into_iter().collect()
+    }
+
     /// Check if the backend is connected to a controller.
     fn is_connected(&self) -> bool;
 
     /// Get the name of this backend.
     fn name(&self) -> &'static str;
+
+    /// Try to reopen the controller after it disconnected.
+    ///
+    /// Implementations should drop any stale device handle and re-enumerate.
+    /// Returns `true` if the controller is connected again.
+    fn reconnect(&mut self) -> bool {
+        false
+    }
+    /// Features this backend provides.
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::MOTION
+    }
 }
 
 /// Touchpad finger data.
@@ -167,4 +231,53 @@ pub trait TouchpadBackend {
 
     /// Get the name of this backend.
     fn name(&self) -> &'static str;
+
+    /// Check if the backend is connected to a controller.
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    /// Try to reopen the controller after it disconnected.
+    ///
+    /// Returns `true` if the controller is connected again.
+    fn reconnect(&mut self) -> bool {
+        false
+    }
+    /// Features this backend provides.
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            touchpad: true,
+            ..BackendCapabilities::default()
+        }
+    }
+}
+
+/// Trait for backends that drive controller LEDs.
+///
+/// Register one per gamepad with
+/// [`LedBackends::insert`](super::leds::LedBackends::insert) to write its
+/// [`ControllerLed`](crate::led::ControllerLed) to the hardware.
+pub trait LedBackend {
+    /// Set the lightbar color (RGB).
+    fn set_lightbar(&mut self, rgb: [u8; 3]);
+
+    /// Set the player indicator LED bitmask.
+    fn set_player_leds(&mut self, mask: u8);
+
+    /// Get the name of this backend.
+    fn name(&self) -> &'static str;
+}
+
+/// Trait for backends that drive trigger motors (Xbox impulse triggers).
+///
+/// Register one per gamepad with
+/// [`TriggerRumbleBackends::insert`](super::trigger_rumble::TriggerRumbleBackends::insert)
+/// to write the trigger channels of its
+/// [`RumbleOutput`](crate::haptics::RumbleOutput) to the hardware.
+pub trait TriggerRumbleBackend {
+    /// Set the left and right trigger motor strengths (0.0-1.0).
+    fn set_trigger_rumble(&mut self, left: f32, right: f32);
+
+    /// Get the name of this backend.
+    fn name(&self) -> &'static str;
 }
@@ -23,10 +23,74 @@
 //!
 //! This is a reference implementation showing the intended architecture.
 //! See [`DualSenseBackend::new`] documentation for the implementation pattern.
+//!
+//! # Multiple Controllers
+//!
+//! Each [`DualSenseBackend`] is a handle to one controller, identified by its
+//! serial (the Bluetooth MAC address). [`DualSenseDevices`] holds every
+//! connected handle and pairs them with Bevy gamepad entities, so motion,
+//! touchpad, lightbar, and trigger state stay separate per player.
 
+use bevy::prelude::*;
+use log::{info, warn};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
-use super::backend::{MotionBackend, MotionData, TouchpadBackend, TouchpadData, TouchpadFinger};
+use super::backend::{
+    BackendCapabilities, MotionBackend, MotionData, TouchpadBackend, TouchpadData, TouchpadFinger,
+};
+use super::reconnect::{BackendDisconnected, BackendReconnected, Backoff, ReconnectPolicy};
+use crate::battery::{ChargingState, ControllerBattery};
+use crate::gyro::{AccelData, GyroData};
+use crate::led::ControllerLed;
+use crate::profiles::{ControllerModel, DetectedController};
+use crate::suspend::InputSystemResumed;
+
+/// Adaptive trigger effect.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TriggerEffect {
+    /// No resistance.
+    #[default]
+    Off,
+    /// Constant resistance past a point.
+    Resistance {
+        /// Trigger travel where resistance starts (0.0 - 1.0).
+        start: f32,
+        /// Resistance strength (0.0 - 1.0).
+        strength: f32,
+    },
+    /// Resistance over a section that gives way, like a gun trigger.
+    Weapon {
+        /// Trigger travel where resistance starts (0.0 - 1.0).
+        start: f32,
+        /// Trigger travel where the trigger gives way (0.0 - 1.0).
+        end: f32,
+        /// Resistance strength (0.0 - 1.0).
+        strength: f32,
+    },
+}
+
+/// Which adaptive trigger to configure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriggerSide {
+    /// L2.
+    Left,
+    /// R2.
+    Right,
+}
+
+/// Output state to send to one `DualSense`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DualSenseOutput {
+    /// Lightbar color (RGB).
+    pub lightbar: [u8; 3],
+    /// Player indicator LED bitmask (5 LEDs).
+    pub player_leds: u8,
+    /// L2 effect.
+    pub left_trigger: TriggerEffect,
+    /// R2 effect.
+    pub right_trigger: TriggerEffect,
+}
 
 /// Motion data received from `DualSense`, protected for thread-safe access.
 #[derive(Debug, Default)]
@@ -46,6 +110,10 @@ struct DualSenseState {
     touch2_y: f32,
     touch2_id: u8,
     connected: bool,
+    /// Raw battery status byte from the input report.
+    battery_status: Option<u8>,
+    output: DualSenseOutput,
+    output_dirty: bool,
 }
 
 /// PS5 `DualSense` motion backend.
@@ -67,13 +135,100 @@ struct DualSenseState {
 /// - `on_touchpad1_pressed`, `on_touchpoint2_changed` - Touch active state (bool)
 /// - `on_touchpoint1_id_changed`, `on_touchpoint2_id_changed` - Touch ID (u8)
 pub struct DualSenseBackend {
+    serial: String,
     state: Arc<Mutex<DualSenseState>>,
 }
 
 impl DualSenseBackend {
+    /// Open every connected `DualSense`.
+    ///
+    /// Each device gets its own handle and callback state, keyed by serial.
+    ///
+    /// # Errors
+    ///
+    /// Always returns an error until the callback bridge is implemented; see
+    /// [`DualSenseBackend::new`].
+    pub fn enumerate() -> crate::Result<Vec<Self>> {
+        // Per-device callbacks need one global state slot per serial, which
+        // the pattern in `new` extends with a map instead of a single value.
+        Self::new().map(|backend| vec![backend])
+    }
+
+    /// The device serial (Bluetooth MAC address).
+    #[must_use]
+    pub fn serial(&self) -> &str {
+        &self.serial
+    }
+
+    /// Set the lightbar color.
+    pub fn set_lightbar(&self, red: u8, green: u8, blue: u8) {
+        self.update_output(|output| output.lightbar = [red, green, blue]);
+    }
+
+    /// Set the player indicator LEDs (lower 5 bits).
+    pub fn set_player_leds(&self, mask: u8) {
+        self.update_output(|output| output.player_leds = mask & 0x1f);
+    }
+
+    /// Set an adaptive trigger effect.
+    pub fn set_trigger_effect(&self, side: TriggerSide, effect: TriggerEffect) {
+        self.update_output(|output| match side {
+            TriggerSide::Left => output.left_trigger = effect,
+            TriggerSide::Right => output.right_trigger = effect,
+        });
+    }
+
+    /// Battery state from the last input report.
+    #[must_use]
+    pub fn battery(&self) -> Option<ControllerBattery> {
+        self.state
+            .lock()
+            .ok()
+            .and_then(|s| s.battery_status)
+            .map(battery_from_status)
+    }
+
+    /// Current output state.
+    #[must_use]
+    pub fn output(&self) -> DualSenseOutput {
+        self.state.lock().map(|s| s.output).unwrap_or_default()
+    }
+
+    /// Take the output state if it changed since the last call.
+    ///
+    /// The HID writer calls this to send output reports only when needed.
+    pub fn take_output(&self) -> Option<DualSenseOutput> {
+        let mut state = self.state.lock().ok()?;
+        std::mem::take(&mut state.output_dirty).then_some(state.output)
+    }
+
+    /// Replace a stale handle with a freshly opened one for the same serial.
+    ///
+    /// The output state is carried over and marked for resending.
+    fn reopen(&mut self) -> bool {
+        let Some(fresh) = Self::enumerate().ok().and_then(|devices| {
+            devices
+                .into_iter()
+                .find(|device| device.serial == self.serial)
+        }) else {
+            return false;
+        };
+        let output = self.output();
+        *self = fresh;
+        self.update_output(|fresh_output| *fresh_output = output);
+        true
+    }
+
+    fn update_output(&self, change: impl FnOnce(&mut DualSenseOutput)) {
+        if let Ok(mut state) = self.state.lock() {
+            change(&mut state.output);
+            state.output_dirty = true;
+        }
+    }
+
     /// Try to create a new `DualSense` backend.
     ///
-    /// Returns `None` if no `DualSense` controller is connected or the feature is disabled.
+    /// Returns an error if no `DualSense` controller is connected or the feature is disabled.
     ///
     /// # Feature Requirements
     ///
@@ -95,7 +250,7 @@ impl DualSenseBackend {
     /// static DUALSENSE_STATE: Lazy<Arc<Mutex<DualSenseState>>> =
     ///     Lazy::new(|| Arc::new(Mutex::new(DualSenseState::default())));
     ///
-    /// pub fn new() -> Option<Self> {
+    /// pub fn new() -> bevy_archie::Result<Self> {
     ///     let mut controller = DualSense::default();
     ///     
     ///     // Static closures can reference global state
@@ -120,32 +275,45 @@ impl DualSenseBackend {
     ///     if let Ok(mut s) = DUALSENSE_STATE.lock() {
     ///         s.connected = true;
     ///     }
-    ///     Some(Self { state: DUALSENSE_STATE.clone() })
+    ///     Ok(Self {
+    ///         serial: controller.serial_number(),
+    ///         state: DUALSENSE_STATE.clone(),
+    ///     })
     /// }
     /// ```
     ///
     /// # Current Status
     ///
-    /// This placeholder returns `None` and logs a warning. To use `DualSense`
+    /// This placeholder returns [`crate::Error::BackendInit`]. To use `DualSense`
     /// motion controls, implement the pattern above using `once_cell` or
     /// `lazy_static` for global state management.
+    ///
+    /// # Errors
+    ///
+    /// Always returns an error until the callback bridge is implemented.
     #[cfg(feature = "dualsense")]
-    #[must_use]
-    pub fn new() -> Option<Self> {
+    pub fn new() -> crate::Result<Self> {
         // Full implementation requires 'static callbacks with global state.
         // See documentation above for the implementation pattern.
-        log::warn!(
-            "DualSense backend requires global state for 'static callbacks. \
-             See DualSenseBackend::new() docs for implementation pattern using once_cell."
-        );
-        None
+        Err(crate::Error::BackendInit {
+            backend: "dualsense",
+            reason: "'static callbacks require global state; see DualSenseBackend::new() docs \
+                     for the implementation pattern using once_cell"
+                .into(),
+        })
     }
 
-    /// Create a backend without the dualsense feature (always returns None).
+    /// Create a backend without the dualsense feature.
+    ///
+    /// # Errors
+    ///
+    /// Always returns an error because the `dualsense` feature is disabled.
     #[cfg(not(feature = "dualsense"))]
-    #[must_use]
-    pub fn new() -> Option<Self> {
-        None
+    pub fn new() -> crate::Result<Self> {
+        Err(crate::Error::BackendInit {
+            backend: "dualsense",
+            reason: "the `dualsense` feature is not enabled".into(),
+        })
     }
 }
 
@@ -158,6 +326,7 @@ impl MotionBackend for DualSenseBackend {
             accel_x: s.accel_x,
             accel_y: s.accel_y,
             accel_z: s.accel_z,
+            timestamp: None,
         })
     }
     fn is_connected(&self) -> bool {
@@ -167,6 +336,19 @@ impl MotionBackend for DualSenseBackend {
     fn name(&self) -> &'static str {
         "dualsense"
     }
+
+    fn reconnect(&mut self) -> bool {
+        self.reopen()
+    }
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            gyro: true,
+            accelerometer: true,
+            touchpad: true,
+            leds: true,
+            adaptive_triggers: true,
+        }
+    }
 }
 
 impl TouchpadBackend for DualSenseBackend {
@@ -195,4 +377,452 @@ impl TouchpadBackend for DualSenseBackend {
     fn name(&self) -> &'static str {
         "dualsense"
     }
+
+    fn is_connected(&self) -> bool {
+        MotionBackend::is_connected(self)
+    }
+
+    fn reconnect(&mut self) -> bool {
+        self.reopen()
+    }
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            gyro: true,
+            accelerometer: true,
+            touchpad: true,
+            leds: true,
+            adaptive_triggers: true,
+        }
+    }
+}
+
+/// Resource holding every connected `DualSense` and its gamepad entity.
+///
+/// Unassigned devices are paired with `PS5` gamepads in entity order by
+/// [`assign_dualsense_devices`]. Bevy does not expose controller serials, so
+/// call [`DualSenseDevices::assign`] when the game knows the exact pairing.
+#[derive(Default, Resource)]
+pub struct DualSenseDevices {
+    devices: Vec<DualSenseBackend>,
+    assignments: HashMap<Entity, String>,
+    /// Output state of dropped devices, by serial.
+    lost: HashMap<String, DualSenseOutput>,
+    backoff: Option<Backoff>,
+}
+
+impl DualSenseDevices {
+    /// Open every connected device not already held.
+    ///
+    /// Returns the number of new devices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if enumeration fails.
+    pub fn connect_all(&mut self) -> crate::Result<usize> {
+        let before = self.devices.len();
+        for backend in DualSenseBackend::enumerate()? {
+            if self.get(backend.serial()).is_none() {
+                self.devices.push(backend);
+            }
+        }
+        Ok(self.devices.len() - before)
+    }
+
+    /// Add a device, replacing any with the same serial.
+    pub fn insert(&mut self, backend: DualSenseBackend) {
+        self.devices
+            .retain(|device| device.serial != backend.serial);
+        self.devices.push(backend);
+    }
+
+    /// Get a device by serial.
+    #[must_use]
+    pub fn get(&self, serial: &str) -> Option<&DualSenseBackend> {
+        self.devices.iter().find(|device| device.serial == serial)
+    }
+
+    /// Iterate over all devices.
+    pub fn iter(&self) -> impl Iterator<Item = &DualSenseBackend> {
+        self.devices.iter()
+    }
+
+    /// Pair a device with a gamepad entity.
+    pub fn assign(&mut self, gamepad: Entity, serial: impl Into<String>) {
+        let serial = serial.into();
+        self.assignments.retain(|_, assigned| *assigned != serial);
+        self.assignments.insert(gamepad, serial);
+    }
+
+    /// Remove a gamepad's pairing.
+    pub fn unassign(&mut self, gamepad: Entity) {
+        self.assignments.remove(&gamepad);
+    }
+
+    /// The device paired with a gamepad entity.
+    #[must_use]
+    pub fn device(&self, gamepad: Entity) -> Option<&DualSenseBackend> {
+        self.get(self.assignments.get(&gamepad)?)
+    }
+
+    /// Drop disconnected device handles and return them.
+    ///
+    /// Pairings are kept so a returning device goes back to the same gamepad.
+    pub fn remove_disconnected(&mut self) -> Vec<DualSenseBackend> {
+        let (connected, dropped): (Vec<_>, Vec<_>) = std::mem::take(&mut self.devices)
+            .into_iter()
+            .partition(|device| MotionBackend::is_connected(device));
+        self.devices = connected;
+        dropped
+    }
+
+    /// Reopen every device, e.g. after the system wakes from sleep.
+    ///
+    /// Devices that fail to reopen are treated as dropped and reconnected
+    /// with backoff. Returns the number reopened.
+    pub fn rehandshake(&mut self) -> usize {
+        let mut reopened = 0;
+        for mut device in std::mem::take(&mut self.devices) {
+            if device.reopen() {
+                reopened += 1;
+                self.devices.push(device);
+            } else {
+                self.lost.insert(device.serial.clone(), device.output());
+            }
+        }
+        reopened
+    }
+
+    /// Serials that dropped and are waiting to reconnect.
+    pub fn lost(&self) -> impl Iterator<Item = &str> {
+        self.lost.keys().map(String::as_str)
+    }
+
+    fn next_unassigned(&self) -> Option<String> {
+        self.devices
+            .iter()
+            .find(|device| {
+                !self
+                    .assignments
+                    .values()
+                    .any(|serial| *serial == device.serial)
+            })
+            .map(|device| device.serial.clone())
+    }
+
+    fn device_mut(&mut self, serial: &str) -> Option<&mut DualSenseBackend> {
+        self.devices
+            .iter_mut()
+            .find(|device| device.serial == serial)
+    }
+}
+
+/// System to pair `DualSense` devices with `PS5` gamepad entities.
+pub fn assign_dualsense_devices(
+    mut devices: ResMut<DualSenseDevices>,
+    gamepads: Query<(Entity, &DetectedController)>,
+) {
+    let devices = &mut *devices;
+    devices
+        .assignments
+        .retain(|gamepad, _| gamepads.contains(*gamepad));
+
+    let mut unassigned: Vec<Entity> = gamepads
+        .iter()
+        .filter(|(entity, detected)| {
+            detected.model == ControllerModel::PS5 && !devices.assignments.contains_key(entity)
+        })
+        .map(|(entity, _)| entity)
+        .collect();
+    unassigned.sort();
+
+    for gamepad in unassigned {
+        let Some(serial) = devices.next_unassigned() else {
+            break;
+        };
+        devices.assign(gamepad, serial);
+    }
+}
+
+/// System to reopen every `DualSense` after the system resumes from sleep.
+pub fn rehandshake_dualsense_on_resume(
+    mut resumed: MessageReader<InputSystemResumed>,
+    mut devices: ResMut<DualSenseDevices>,
+) {
+    if resumed.read().count() == 0 {
+        return;
+    }
+    let reopened = devices.rehandshake();
+    info!("Reopened {reopened} DualSense device(s) after resume");
+}
+
+/// System to drop stale `DualSense` handles and re-enumerate with backoff.
+///
+/// Dropped devices keep their gamepad pairing; when they return, their
+/// lightbar and trigger output is resent and [`BackendReconnected`] fires.
+pub fn reconnect_dualsense_devices(
+    time: Res<Time<Real>>,
+    policy: Res<ReconnectPolicy>,
+    mut devices: ResMut<DualSenseDevices>,
+    mut disconnected: MessageWriter<BackendDisconnected>,
+    mut reconnected: MessageWriter<BackendReconnected>,
+) {
+    let devices = &mut *devices;
+    for device in devices.remove_disconnected() {
+        warn!("DualSense {} disconnected; reconnecting", device.serial);
+        disconnected.write(BackendDisconnected {
+            backend: "dualsense",
+        });
+        devices.lost.insert(device.serial.clone(), device.output());
+    }
+    if devices.lost.is_empty() || !policy.enabled {
+        devices.backoff = None;
+        return;
+    }
+
+    let backoff = devices.backoff.get_or_insert_with(|| Backoff::new(&policy));
+    if !backoff.tick(time.delta_secs()) {
+        return;
+    }
+    let attempts = backoff.attempts();
+
+    let fresh = DualSenseBackend::enumerate().unwrap_or_default();
+    for device in fresh {
+        if let Some(output) = devices.lost.remove(&device.serial) {
+            device.update_output(|fresh| *fresh = output);
+            info!(
+                "DualSense {} reconnected after {attempts} attempt(s)",
+                device.serial
+            );
+            reconnected.write(BackendReconnected {
+                backend: "dualsense",
+                attempts,
+            });
+        }
+        if devices.get(&device.serial).is_none() {
+            devices.devices.push(device);
+        }
+    }
+
+    if devices.lost.is_empty() {
+        devices.backoff = None;
+    } else if let Some(backoff) = &mut devices.backoff {
+        backoff.failed(&policy);
+    }
+}
+
+/// Decode the input report's battery status byte.
+///
+/// The low nibble is the level in tenths, the high nibble the charging
+/// state (0 discharging, 1 charging, 2 full).
+#[must_use]
+pub fn battery_from_status(status: u8) -> ControllerBattery {
+    let level = f32::from((status & 0x0f).min(10)) / 10.0;
+    let state = match status >> 4 {
+        0 => ChargingState::Discharging,
+        1 => ChargingState::Charging,
+        2 => ChargingState::Full,
+        _ => ChargingState::Unknown,
+    };
+    ControllerBattery::new(Some(level), state)
+}
+
+/// System to copy each `DualSense` battery to its gamepad's
+/// [`ControllerBattery`].
+pub fn sync_dualsense_battery(
+    mut commands: Commands,
+    devices: Res<DualSenseDevices>,
+    mut batteries: Query<&mut ControllerBattery>,
+    gamepads: Query<(), With<Gamepad>>,
+) {
+    for gamepad in devices.assignments.keys() {
+        let Some(battery) = devices.device(*gamepad).and_then(DualSenseBackend::battery) else {
+            continue;
+        };
+        if let Ok(mut current) = batteries.get_mut(*gamepad) {
+            current.set_if_neq(battery);
+        } else if gamepads.contains(*gamepad) {
+            commands.entity(*gamepad).insert(battery);
+        }
+    }
+}
+
+/// System to write each gamepad's [`ControllerLed`] to its `DualSense`.
+///
+/// Only LEDs that differ from the device's output state are sent.
+pub fn sync_dualsense_leds(devices: Res<DualSenseDevices>, leds: Query<(Entity, &ControllerLed)>) {
+    for (gamepad, led) in &leds {
+        let Some(device) = devices.device(gamepad) else {
+            continue;
+        };
+        let output = device.output();
+        if let Some([red, green, blue]) = led.lightbar_rgb()
+            && output.lightbar != [red, green, blue]
+        {
+            device.set_lightbar(red, green, blue);
+        }
+        if let Some(mask) = led.player_leds
+            && output.player_leds != mask & 0x1f
+        {
+            device.set_player_leds(mask);
+        }
+    }
+}
+
+/// System to copy each device's motion and touchpad data to its gamepad.
+pub fn update_dualsense_streams(
+    mut devices: ResMut<DualSenseDevices>,
+    mut gyro_query: Query<&mut GyroData>,
+    mut accel_query: Query<&mut AccelData>,
+    mut touchpad_query: Query<&mut crate::touchpad::TouchpadData>,
+) {
+    let assignments: Vec<(Entity, String)> = devices
+        .assignments
+        .iter()
+        .map(|(gamepad, serial)| (*gamepad, serial.clone()))
+        .collect();
+
+    for (gamepad, serial) in assignments {
+        let Some(device) = devices.device_mut(&serial) else {
+            continue;
+        };
+
+        if let Some(data) = MotionBackend::poll(device) {
+            if let Ok(mut gyro) = gyro_query.get_mut(gamepad) {
+                gyro.set_raw(data.gyro_pitch, data.gyro_yaw, data.gyro_roll);
+            }
+            if let Ok(mut accel) = accel_query.get_mut(gamepad) {
+                accel.set_raw(data.accel_x, data.accel_y, data.accel_z);
+            }
+        }
+
+        if let Some(data) = TouchpadBackend::poll(device)
+            && let Ok(mut touchpad) = touchpad_query.get_mut(gamepad)
+        {
+            touchpad.set_finger(0, data.finger1.x, data.finger1.y, data.finger1.active);
+            touchpad.set_finger(1, data.finger2.x, data.finger2.y, data.finger2.active);
+            touchpad.button_pressed = data.button_pressed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(serial: &str) -> DualSenseBackend {
+        DualSenseBackend {
+            serial: serial.to_string(),
+            state: Arc::new(Mutex::new(DualSenseState {
+                connected: true,
+                ..default()
+            })),
+        }
+    }
+
+    #[test]
+    fn test_battery_status_decoding() {
+        let battery = battery_from_status(0x03);
+        assert_eq!(battery.percent(), Some(30));
+        assert_eq!(battery.state, ChargingState::Discharging);
+
+        let battery = battery_from_status(0x2a);
+        assert_eq!(battery.percent(), Some(100));
+        assert_eq!(battery.state, ChargingState::Full);
+
+        let first = device("aa:aa");
+        assert_eq!(first.battery(), None);
+        first.state.lock().unwrap().battery_status = Some(0x15);
+        assert_eq!(first.battery().unwrap().state, ChargingState::Charging);
+    }
+
+    #[test]
+    fn test_output_is_per_device() {
+        let first = device("aa:aa");
+        let second = device("bb:bb");
+        first.set_lightbar(255, 0, 0);
+        second.set_trigger_effect(
+            TriggerSide::Right,
+            TriggerEffect::Resistance {
+                start: 0.2,
+                strength: 0.8,
+            },
+        );
+
+        assert_eq!(first.take_output().unwrap().lightbar, [255, 0, 0]);
+        assert!(first.take_output().is_none());
+        assert_eq!(second.output().lightbar, [0, 0, 0]);
+        assert_ne!(second.output().right_trigger, TriggerEffect::Off);
+    }
+
+    #[test]
+    fn test_devices_pair_with_ps5_gamepads() {
+        let mut app = App::new();
+        let mut devices = DualSenseDevices::default();
+        devices.insert(device("aa:aa"));
+        devices.insert(device("bb:bb"));
+        app.insert_resource(devices).add_systems(
+            Update,
+            (assign_dualsense_devices, update_dualsense_streams).chain(),
+        );
+
+        let ps5 = DetectedController::new(0x054c, 0x0ce6);
+        let first = app.world_mut().spawn((ps5, GyroData::default())).id();
+        let second = app.world_mut().spawn((ps5, GyroData::default())).id();
+        let xbox = app
+            .world_mut()
+            .spawn(DetectedController::new(0x045e, 0x0b12))
+            .id();
+
+        let state = app
+            .world()
+            .resource::<DualSenseDevices>()
+            .get("bb:bb")
+            .unwrap()
+            .state
+            .clone();
+        state.lock().unwrap().gyro_yaw = 1.5;
+        app.update();
+
+        let devices = app.world().resource::<DualSenseDevices>();
+        assert_eq!(devices.device(first).unwrap().serial(), "aa:aa");
+        assert_eq!(devices.device(second).unwrap().serial(), "bb:bb");
+        assert!(devices.device(xbox).is_none());
+        assert_eq!(app.world().get::<GyroData>(first).unwrap().yaw, 0.0);
+        assert_eq!(app.world().get::<GyroData>(second).unwrap().yaw, 1.5);
+    }
+
+    #[test]
+    fn test_explicit_assignment_moves_device() {
+        let mut devices = DualSenseDevices::default();
+        devices.insert(device("aa:aa"));
+        let first = Entity::from_bits(1);
+        let second = Entity::from_bits(2);
+
+        devices.assign(first, "aa:aa");
+        devices.assign(second, "aa:aa");
+        assert!(devices.device(first).is_none());
+        assert_eq!(devices.device(second).unwrap().serial(), "aa:aa");
+    }
+
+    #[test]
+    fn test_remove_disconnected_keeps_pairing() {
+        let mut devices = DualSenseDevices::default();
+        let dropped = device("aa:aa");
+        dropped.set_lightbar(0, 0, 255);
+        dropped.state.lock().unwrap().connected = false;
+        devices.insert(dropped);
+        devices.insert(device("bb:bb"));
+        let gamepad = Entity::from_bits(1);
+        devices.assign(gamepad, "aa:aa");
+
+        let removed = devices.remove_disconnected();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].output().lightbar, [0, 0, 255]);
+        assert!(devices.get("aa:aa").is_none());
+        assert!(devices.get("bb:bb").is_some());
+
+        // The pairing returns with the device
+        devices.insert(device("aa:aa"));
+        assert_eq!(devices.device(gamepad).unwrap().serial(), "aa:aa");
+    }
 }
@@ -0,0 +1,553 @@
+//! Raw HID motion and touchpad backend using `hidapi`.
+//!
+//! This backend reads `DualShock` 4 and `DualSense` input reports directly,
+//! so PS4 and PS5 gyro, accelerometer, and touchpad data work without a
+//! controller-specific crate.
+//!
+//! # Requirements
+//!
+//! - Enable the `hidapi` feature in Cargo.toml
+//! - On Linux, the user needs read access to the `hidraw` device (see the
+//!   udev rules in `docs/HARDWARE_INTEGRATION_GUIDE.md`)
+//!
+//! # Report Formats
+//!
+//! USB reports (ID `0x01`) start their data after the report ID. Over
+//! Bluetooth the `DualShock` 4 v2 sends extended reports (ID `0x11`) with two
+//! more header bytes, which [`HidReportLayout`] expects when the controller
+//! has [`ControllerQuirk::DS4BluetoothReportDiffers`]. `DualSense` Bluetooth
+//! reports (ID `0x31`) have one extra header byte.
+//!
+//! # Usage
+//!
+//! One [`HidBackend`] can feed both the motion and touchpad backends; the
+//! clones share a device handle.
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::motion::{ActiveMotionBackend, ActiveTouchpadBackend, HidBackend};
+//!
+//! fn use_hid_backend(mut commands: Commands) {
+//!     if let Ok(hid) = HidBackend::open() {
+//!         commands.insert_resource(ActiveMotionBackend::new(hid.clone()));
+//!         commands.insert_resource(ActiveTouchpadBackend::new(hid));
+//!     }
+//! }
+//! ```
+
+use hidapi::{DeviceInfo, HidApi, HidDevice};
+use std::sync::{Arc, Mutex};
+
+use super::backend::{
+    BackendCapabilities, MotionBackend, MotionData, TouchpadBackend, TouchpadData, TouchpadFinger,
+    dualsense_calibration, dualshock4_calibration,
+};
+use crate::profiles::{ControllerModel, ControllerQuirk, DetectedController};
+
+/// Most reports read from the device per poll.
+const MAX_REPORTS_PER_POLL: usize = 64;
+
+/// Most motion samples kept when nothing takes them.
+const MAX_QUEUED_SAMPLES: usize = 256;
+
+/// Byte offsets within a report's data, after the report header.
+struct PacketOffsets {
+    /// Gyro X, Y, Z (`i16` each).
+    gyro: usize,
+    /// Accelerometer X, Y, Z (`i16` each).
+    accel: usize,
+    /// Sensor timestamp.
+    timestamp: usize,
+    /// Byte holding the touchpad click bit (`0x02`).
+    touch_button: usize,
+    /// First touch point (4 bytes per point).
+    touch: usize,
+    /// Minimum data length.
+    len: usize,
+    /// Touchpad height in touch units.
+    touch_height: f32,
+}
+
+const DS4_OFFSETS: PacketOffsets = PacketOffsets {
+    gyro: 12,
+    accel: 18,
+    timestamp: 9,
+    touch_button: 6,
+    touch: 34,
+    len: 42,
+    touch_height: 942.0,
+};
+
+const DUALSENSE_OFFSETS: PacketOffsets = PacketOffsets {
+    gyro: 15,
+    accel: 21,
+    timestamp: 27,
+    touch_button: 9,
+    touch: 32,
+    len: 40,
+    touch_height: 1080.0,
+};
+
+/// Touchpad width in touch units (both controllers).
+const TOUCH_WIDTH: f32 = 1920.0;
+
+/// How to read input reports from one controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HidReportLayout {
+    model: ControllerModel,
+    /// Whether `DualShock` 4 extended Bluetooth reports are expected.
+    extended_bluetooth: bool,
+}
+
+impl HidReportLayout {
+    /// Layout for a detected controller.
+    ///
+    /// Returns `None` for controllers other than `DualShock` 4 and
+    /// `DualSense`.
+    #[must_use]
+    pub fn for_controller(controller: &DetectedController) -> Option<Self> {
+        match controller.model {
+            ControllerModel::PS4 | ControllerModel::PS5 => Some(Self {
+                model: controller.model,
+                extended_bluetooth: controller
+                    .quirks()
+                    .contains(&ControllerQuirk::DS4BluetoothReportDiffers),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The controller model this layout reads.
+    #[must_use]
+    pub const fn model(&self) -> ControllerModel {
+        self.model
+    }
+
+    /// Where a report's data starts, by report ID.
+    fn data_offset(&self, report_id: u8) -> Option<usize> {
+        match (self.model, report_id) {
+            (_, 0x01) => Some(1),
+            (ControllerModel::PS4, 0x11) if self.extended_bluetooth => Some(3),
+            (ControllerModel::PS5, 0x31) => Some(2),
+            _ => None,
+        }
+    }
+
+    const fn offsets(&self) -> &'static PacketOffsets {
+        match self.model {
+            ControllerModel::PS5 => &DUALSENSE_OFFSETS,
+            _ => &DS4_OFFSETS,
+        }
+    }
+
+    /// Seconds between sensor timestamp ticks.
+    fn tick_seconds(&self) -> f64 {
+        match self.model {
+            // 1/3 microsecond
+            ControllerModel::PS5 => 1.0 / 3_000_000.0,
+            // 16/3 microseconds
+            _ => 16.0 / 3_000_000.0,
+        }
+    }
+
+    /// Ticks between two sensor timestamps, allowing for wraparound.
+    fn tick_delta(&self, previous: u32, current: u32) -> u32 {
+        let delta = current.wrapping_sub(previous);
+        match self.model {
+            ControllerModel::PS5 => delta,
+            // The DualShock 4 timestamp is 16 bits
+            _ => delta & 0xffff,
+        }
+    }
+
+    /// Parse an input report, including its report ID byte.
+    ///
+    /// Returns `None` for other reports, such as the short reports a
+    /// `DualShock` 4 sends over Bluetooth before it switches to extended
+    /// reports.
+    #[must_use]
+    pub fn parse(&self, report: &[u8]) -> Option<HidInputReport> {
+        let data = report.get(self.data_offset(*report.first()?)?..)?;
+        let offsets = self.offsets();
+        if data.len() < offsets.len {
+            return None;
+        }
+
+        let read_i16 = |at: usize| i16::from_le_bytes([data[at], data[at + 1]]);
+        let (gyro, accel): (fn(i16) -> f32, fn(i16) -> f32) = match self.model {
+            ControllerModel::PS5 => (
+                dualsense_calibration::gyro_to_rads,
+                dualsense_calibration::accel_to_ms2,
+            ),
+            _ => (
+                dualshock4_calibration::gyro_to_rads,
+                dualshock4_calibration::accel_to_ms2,
+            ),
+        };
+        let motion = MotionData {
+            gyro_pitch: gyro(read_i16(offsets.gyro)),
+            gyro_yaw: gyro(read_i16(offsets.gyro + 2)),
+            gyro_roll: gyro(read_i16(offsets.gyro + 4)),
+            accel_x: accel(read_i16(offsets.accel)),
+            accel_y: accel(read_i16(offsets.accel + 2)),
+            accel_z: accel(read_i16(offsets.accel + 4)),
+            timestamp: None,
+        };
+
+        let timestamp = &data[offsets.timestamp..];
+        let sensor_ticks = match self.model {
+            ControllerModel::PS5 => {
+                u32::from_le_bytes([timestamp[0], timestamp[1], timestamp[2], timestamp[3]])
+            }
+            _ => u32::from(u16::from_le_bytes([timestamp[0], timestamp[1]])),
+        };
+
+        let finger = |at: usize| touch_finger(&data[at..at + 4], offsets.touch_height);
+        let touchpad = TouchpadData {
+            finger1: finger(offsets.touch),
+            finger2: finger(offsets.touch + 4),
+            button_pressed: data[offsets.touch_button] & 0x02 != 0,
+        };
+
+        Some(HidInputReport {
+            motion,
+            touchpad,
+            sensor_ticks,
+        })
+    }
+}
+
+/// Decode one touch point: a counter byte then 12-bit X and Y.
+fn touch_finger(bytes: &[u8], height: f32) -> TouchpadFinger {
+    let x = u16::from(bytes[1]) | (u16::from(bytes[2] & 0x0f) << 8);
+    let y = u16::from(bytes[2] >> 4) | (u16::from(bytes[3]) << 4);
+    TouchpadFinger {
+        // The high bit is set while the finger is lifted
+        active: bytes[0] & 0x80 == 0,
+        x: (f32::from(x) / TOUCH_WIDTH).min(1.0),
+        y: (f32::from(y) / height).min(1.0),
+        id: bytes[0] & 0x7f,
+    }
+}
+
+/// Data from one parsed input report.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HidInputReport {
+    /// Motion data, without a timestamp.
+    pub motion: MotionData,
+    /// Touchpad data.
+    pub touchpad: TouchpadData,
+    /// Raw sensor timestamp.
+    pub sensor_ticks: u32,
+}
+
+/// Running sensor clock built from wrapping report timestamps.
+#[derive(Debug, Default)]
+struct SensorClock {
+    last_ticks: Option<u32>,
+    seconds: f64,
+}
+
+impl SensorClock {
+    /// Advance to a report's timestamp and return the time in seconds.
+    fn advance(&mut self, layout: &HidReportLayout, ticks: u32) -> f64 {
+        if let Some(last) = self.last_ticks {
+            self.seconds += f64::from(layout.tick_delta(last, ticks)) * layout.tick_seconds();
+        }
+        self.last_ticks = Some(ticks);
+        self.seconds
+    }
+}
+
+/// Device handle and data shared by clones of a [`HidBackend`].
+struct HidShared {
+    device: Option<HidDevice>,
+    layout: HidReportLayout,
+    vendor_id: u16,
+    product_id: u16,
+    serial: Option<String>,
+    clock: SensorClock,
+    /// Motion samples not yet taken by the motion backend.
+    samples: Vec<MotionData>,
+    /// Latest touchpad state.
+    touchpad: Option<TouchpadData>,
+}
+
+impl HidShared {
+    /// Read every pending report.
+    fn pump(&mut self) {
+        let Some(device) = &self.device else {
+            return;
+        };
+        let mut buf = [0u8; 128];
+        let mut lost = false;
+        for _ in 0..MAX_REPORTS_PER_POLL {
+            match device.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => {
+                    if let Some(report) = self.layout.parse(&buf[..len]) {
+                        let time = self.clock.advance(&self.layout, report.sensor_ticks);
+                        self.samples.push(MotionData {
+                            timestamp: Some(time),
+                            ..report.motion
+                        });
+                        self.touchpad = Some(report.touchpad);
+                    }
+                }
+                Err(_) => {
+                    lost = true;
+                    break;
+                }
+            }
+        }
+
+        if lost {
+            self.device = None;
+        }
+        // Only the motion backend takes samples; drop the oldest if it isn't
+        let excess = self.samples.len().saturating_sub(MAX_QUEUED_SAMPLES);
+        self.samples.drain(..excess);
+    }
+}
+
+/// `DualShock` 4 and `DualSense` motion and touchpad backend over raw HID.
+///
+/// Clones share the same device, so one can be the motion backend and
+/// another the touchpad backend.
+#[derive(Clone)]
+pub struct HidBackend {
+    shared: Arc<Mutex<HidShared>>,
+}
+
+impl HidBackend {
+    /// Open the first connected `DualShock` 4 or `DualSense`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HID can't be initialized or no supported
+    /// controller is connected.
+    pub fn open() -> crate::Result<Self> {
+        let api = hid_api()?;
+        let (device, info, layout) = open_matching(&api, |_| true)?;
+        Ok(Self {
+            shared: Arc::new(Mutex::new(HidShared {
+                device: Some(device),
+                layout,
+                vendor_id: info.vendor_id(),
+                product_id: info.product_id(),
+                serial: info.serial_number().map(str::to_string),
+                clock: SensorClock::default(),
+                samples: Vec::new(),
+                touchpad: None,
+            })),
+        })
+    }
+
+    /// The report layout of the open controller.
+    #[must_use]
+    pub fn layout(&self) -> Option<HidReportLayout> {
+        self.shared.lock().ok().map(|shared| shared.layout)
+    }
+
+    /// Reopen the same controller by vendor, product, and serial.
+    fn reopen(&self) -> bool {
+        let Ok(mut shared) = self.shared.lock() else {
+            return false;
+        };
+        let Ok(api) = hid_api() else {
+            return false;
+        };
+        let (vendor_id, product_id) = (shared.vendor_id, shared.product_id);
+        let serial = shared.serial.clone();
+        let Ok((device, _, layout)) = open_matching(&api, |info| {
+            info.vendor_id() == vendor_id
+                && info.product_id() == product_id
+                && info.serial_number() == serial.as_deref()
+        }) else {
+            return false;
+        };
+        shared.device = Some(device);
+        shared.layout = layout;
+        // Timestamps restart with the new handle
+        shared.clock = SensorClock::default();
+        shared.samples.clear();
+        true
+    }
+}
+
+fn hid_api() -> crate::Result<HidApi> {
+    HidApi::new().map_err(|e| crate::Error::BackendInit {
+        backend: "hidapi",
+        reason: e.to_string(),
+    })
+}
+
+/// Open the first supported controller accepted by a filter.
+fn open_matching(
+    api: &HidApi,
+    filter: impl Fn(&DeviceInfo) -> bool,
+) -> crate::Result<(HidDevice, DeviceInfo, HidReportLayout)> {
+    for info in api.device_list() {
+        let detected = DetectedController::new(info.vendor_id(), info.product_id());
+        let Some(layout) = HidReportLayout::for_controller(&detected) else {
+            continue;
+        };
+        if !filter(info) {
+            continue;
+        }
+        let Ok(device) = info.open_device(api) else {
+            continue;
+        };
+        if device.set_blocking_mode(false).is_err() {
+            continue;
+        }
+        if layout.extended_bluetooth {
+            // Reading the calibration feature report switches a Bluetooth
+            // DualShock 4 from short reports to extended 0x11 reports
+            let mut calibration = [0u8; 37];
+            calibration[0] = 0x02;
+            let _ = device.get_feature_report(&mut calibration);
+        }
+        return Ok((device, info.clone(), layout));
+    }
+    Err(crate::Error::BackendInit {
+        backend: "hidapi",
+        reason: "no DualShock 4 or DualSense connected".into(),
+    })
+}
+
+impl MotionBackend for HidBackend {
+    fn poll(&mut self) -> Option<MotionData> {
+        self.poll_batch().pop()
+    }
+
+    fn poll_batch(&mut self) -> Vec<MotionData> {
+        let Ok(mut shared) = self.shared.lock() else {
+            return Vec::new();
+        };
+        shared.pump();
+        std::mem::take(&mut shared.samples)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.shared
+            .lock()
+            .is_ok_and(|shared| shared.device.is_some())
+    }
+
+    fn name(&self) -> &'static str {
+        "hidapi"
+    }
+
+    fn reconnect(&mut self) -> bool {
+        self.reopen()
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            touchpad: true,
+            ..BackendCapabilities::MOTION
+        }
+    }
+}
+
+impl TouchpadBackend for HidBackend {
+    fn poll(&mut self) -> Option<TouchpadData> {
+        let mut shared = self.shared.lock().ok()?;
+        shared.pump();
+        shared.touchpad
+    }
+
+    fn supports_multitouch(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "hidapi"
+    }
+
+    fn is_connected(&self) -> bool {
+        MotionBackend::is_connected(self)
+    }
+
+    fn reconnect(&mut self) -> bool {
+        self.reopen()
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        MotionBackend::capabilities(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(product_id: u16) -> HidReportLayout {
+        HidReportLayout::for_controller(&DetectedController::new(0x054c, product_id)).unwrap()
+    }
+
+    /// A report with gyro pitch 1024, a touch at (960, 471), and a click.
+    fn ds4_report(report_id: u8, header: usize, ticks: u16) -> Vec<u8> {
+        let mut report = vec![0u8; header + 64];
+        report[0] = report_id;
+        let data = &mut report[header..];
+        data[6] = 0x02;
+        data[9..11].copy_from_slice(&ticks.to_le_bytes());
+        data[12..14].copy_from_slice(&1024i16.to_le_bytes());
+        // Finger 1 touching with ID 5, finger 2 lifted
+        data[34..38].copy_from_slice(&[0x05, 0xc0, 0x73, 0x1d]);
+        data[38] = 0x80;
+        report
+    }
+
+    #[test]
+    fn test_ds4_usb_report() {
+        let report = layout(0x05c4).parse(&ds4_report(0x01, 1, 0)).unwrap();
+        assert!((report.motion.gyro_pitch - 2000f32.to_radians()).abs() < 1e-4);
+        assert!(report.touchpad.finger1.active);
+        assert_eq!(report.touchpad.finger1.id, 5);
+        assert!((report.touchpad.finger1.x - 0.5).abs() < 1e-3);
+        assert!((report.touchpad.finger1.y - 0.5).abs() < 1e-3);
+        assert!(!report.touchpad.finger2.active);
+        assert!(report.touchpad.button_pressed);
+    }
+
+    #[test]
+    fn test_ds4_bluetooth_report_needs_quirk() {
+        let bluetooth = ds4_report(0x11, 3, 0);
+
+        let v2 = layout(0x09cc);
+        let report = v2.parse(&bluetooth).unwrap();
+        assert_eq!(report.touchpad.finger1.id, 5);
+
+        // Short Bluetooth reports carry no motion data
+        assert!(v2.parse(&[0x01, 0x80, 0x80, 0x80, 0x80]).is_none());
+        assert!(layout(0x05c4).parse(&bluetooth).is_none());
+    }
+
+    #[test]
+    fn test_dualsense_bluetooth_report() {
+        let mut report = vec![0u8; 78];
+        report[0] = 0x31;
+        let data = &mut report[2..];
+        data[15..17].copy_from_slice(&(-512i16).to_le_bytes());
+        data[32] = 0x80;
+        data[36] = 0x80;
+
+        let report = layout(0x0ce6).parse(&report).unwrap();
+        assert!(report.motion.gyro_pitch < 0.0);
+        assert!(!report.touchpad.finger1.active);
+        assert!(!report.touchpad.button_pressed);
+    }
+
+    #[test]
+    fn test_sensor_clock_wraps() {
+        let ds4 = layout(0x05c4);
+        let mut clock = SensorClock::default();
+        assert_eq!(clock.advance(&ds4, 0xfff0), 0.0);
+        // 0x20 ticks across the 16-bit wrap
+        let seconds = clock.advance(&ds4, 0x0010);
+        assert!((seconds - 32.0 * 16.0 / 3_000_000.0).abs() < 1e-12);
+    }
+}
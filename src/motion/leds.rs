@@ -0,0 +1,115 @@
+//! Writing [`ControllerLed`] state to LED backends.
+//!
+//! `DualSense` lightbars and player LEDs are written through
+//! `DualSenseDevices` when the `dualsense` feature is on. Other controllers
+//! (`DualShock` 4, Xbox, Switch) need a [`LedBackend`] registered for their
+//! gamepad in [`LedBackends`].
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::backend::LedBackend;
+use crate::led::ControllerLed;
+
+/// Resource holding the LED backend for each gamepad.
+#[derive(Default, Resource)]
+pub struct LedBackends {
+    backends: HashMap<Entity, Box<dyn LedBackend + Send + Sync>>,
+    /// LED state last written to each backend.
+    sent: HashMap<Entity, ControllerLed>,
+}
+
+impl LedBackends {
+    /// Drive a gamepad's LEDs with a backend, replacing any it had.
+    pub fn insert<B: LedBackend + Send + Sync + 'static>(&mut self, gamepad: Entity, backend: B) {
+        self.backends.insert(gamepad, Box::new(backend));
+        self.sent.remove(&gamepad);
+    }
+
+    /// Stop driving a gamepad's LEDs.
+    pub fn remove(&mut self, gamepad: Entity) {
+        self.backends.remove(&gamepad);
+        self.sent.remove(&gamepad);
+    }
+
+    /// Name of the backend driving a gamepad's LEDs.
+    #[must_use]
+    pub fn name(&self, gamepad: Entity) -> Option<&'static str> {
+        self.backends.get(&gamepad).map(|backend| backend.name())
+    }
+
+    /// Write a gamepad's LED state if it changed since the last write.
+    ///
+    /// Returns whether anything was written.
+    pub fn write(&mut self, gamepad: Entity, led: &ControllerLed) -> bool {
+        let Some(backend) = self.backends.get_mut(&gamepad) else {
+            return false;
+        };
+        if self.sent.get(&gamepad) == Some(led) {
+            return false;
+        }
+        if let Some(rgb) = led.lightbar_rgb() {
+            backend.set_lightbar(rgb);
+        }
+        if let Some(mask) = led.player_leds {
+            backend.set_player_leds(mask);
+        }
+        self.sent.insert(gamepad, *led);
+        true
+    }
+}
+
+/// System to write every gamepad's [`ControllerLed`] to its backend.
+pub fn apply_led_backends(
+    mut backends: ResMut<LedBackends>,
+    leds: Query<(Entity, &ControllerLed)>,
+) {
+    for (gamepad, led) in &leds {
+        backends.write(gamepad, led);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct RecordingLeds(Arc<Mutex<Vec<String>>>);
+
+    impl LedBackend for RecordingLeds {
+        fn set_lightbar(&mut self, rgb: [u8; 3]) {
+            self.0.lock().unwrap().push(format!("lightbar {rgb:?}"));
+        }
+
+        fn set_player_leds(&mut self, mask: u8) {
+            self.0.lock().unwrap().push(format!("player {mask:#b}"));
+        }
+
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+    }
+
+    #[test]
+    fn test_led_backends_write_only_changes() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let gamepad = Entity::from_bits(7);
+        let mut backends = LedBackends::default();
+        backends.insert(gamepad, RecordingLeds(log.clone()));
+        assert_eq!(backends.name(gamepad), Some("recording"));
+
+        let led = ControllerLed {
+            lightbar: Some(Color::srgb(0.0, 1.0, 0.0)),
+            player_leds: Some(0b1),
+        };
+        assert!(backends.write(gamepad, &led));
+        assert!(!backends.write(gamepad, &led));
+        assert!(!backends.write(Entity::from_bits(8), &led));
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["lightbar [0, 255, 0]".to_string(), "player 0b1".to_string()]
+        );
+    }
+}
@@ -10,6 +10,8 @@
 //! - [`MotionBackend`] - Trait defining how to read gyro/accel/touchpad data
 //! - [`StubBackend`] - No-op fallback (always available)
 //! - [`DualSenseBackend`] - PS5 `DualSense` via `dualsense-rs` (feature: `dualsense`)
+//! - [`HidBackend`] - PS4 `DualShock` 4 and PS5 `DualSense` via raw HID (feature: `hidapi`)
+//! - [`SwitchBackend`] - Switch Pro Controller and Joy-Cons via raw HID (feature: `hidapi`)
 //!
 //! # Feature Flags
 //!
@@ -35,32 +37,68 @@
 //! ```
 
 pub mod backend;
+pub mod leds;
+pub mod polling;
+pub mod reconnect;
+pub mod status;
 mod stub;
+pub mod trigger_rumble;
 
 #[cfg(feature = "dualsense")]
 mod dualsense;
+#[cfg(feature = "hidapi")]
+mod hid;
+#[cfg(feature = "hidapi")]
+mod switch;
 
 pub use backend::{
-    MotionBackend, MotionData, TouchpadBackend, TouchpadData as BackendTouchpadData,
+    BackendCapabilities, LedBackend, MotionBackend, MotionData, TouchpadBackend,
+    TouchpadData as BackendTouchpadData, TriggerRumbleBackend,
 };
+pub use leds::LedBackends;
+pub use polling::{BackendPollSettings, GyroBatching};
+pub use reconnect::{BackendDisconnected, BackendReconnected, ReconnectPolicy};
+pub use status::MotionBackendStatus;
 pub use stub::StubBackend;
+pub use trigger_rumble::TriggerRumbleBackends;
 
 #[cfg(feature = "dualsense")]
-pub use dualsense::DualSenseBackend;
+pub use dualsense::{
+    DualSenseBackend, DualSenseDevices, DualSenseOutput, TriggerEffect, TriggerSide,
+    assign_dualsense_devices, battery_from_status, reconnect_dualsense_devices,
+    rehandshake_dualsense_on_resume, sync_dualsense_battery, sync_dualsense_leds,
+    update_dualsense_streams,
+};
+
+#[cfg(feature = "hidapi")]
+pub use hid::{HidBackend, HidInputReport, HidReportLayout};
+#[cfg(feature = "hidapi")]
+pub use switch::{SwitchBackend, SwitchInputReport};
 
+use bevy::diagnostic::Diagnostics;
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
+use log::{info, warn};
+
+use crate::power::InputPowerProfile;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::suspend::InputSystemResumed;
 
 /// Resource holding the active motion backend.
 #[derive(Resource)]
 pub struct ActiveMotionBackend {
     backend: Box<dyn MotionBackend + Send + Sync>,
+    reconnect: reconnect::ReconnectTracker,
+    timer: polling::PollTimer,
+    /// Seconds since the last poll.
+    since_poll: f32,
+    /// Timestamp of the newest sample received.
+    last_sample_time: Option<f64>,
 }
 
 impl Default for ActiveMotionBackend {
     fn default() -> Self {
-        Self {
-            backend: Box::new(StubBackend::new()),
-        }
+        Self::new(StubBackend::new())
     }
 }
 
@@ -69,32 +107,70 @@ impl ActiveMotionBackend {
     pub fn new<B: MotionBackend + Send + Sync + 'static>(backend: B) -> Self {
         Self {
             backend: Box::new(backend),
+            reconnect: default(),
+            timer: default(),
+            since_poll: 0.0,
+            last_sample_time: None,
         }
     }
 
+    /// Name of the backend.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.backend.name()
+    }
+
+    /// Whether the backend is waiting to reconnect.
+    #[must_use]
+    pub fn is_reconnecting(&self) -> bool {
+        self.reconnect.is_reconnecting()
+    }
+
+    /// Features the backend provides.
+    #[must_use]
+    pub fn capabilities(&self) -> BackendCapabilities {
+        self.backend.capabilities()
+    }
+
     /// Get motion data from the backend.
     pub fn poll(&mut self) -> Option<MotionData> {
         self.backend.poll()
     }
 
+    /// Get every report received since the last poll, oldest first.
+    pub fn poll_batch(&mut self) -> Vec<MotionData> {
+        self.backend.poll_batch()
+    }
+
     /// Check if the backend is connected.
     #[must_use]
     pub fn is_connected(&self) -> bool {
         self.backend.is_connected()
     }
+
+    /// Reopen the device, e.g. after the system wakes from sleep.
+    ///
+    /// Sample timing restarts so the sleep isn't integrated as rotation.
+    /// Returns whether the device is connected again.
+    pub fn rehandshake(&mut self) -> bool {
+        self.timer = default();
+        self.since_poll = 0.0;
+        self.last_sample_time = None;
+        self.backend.reconnect()
+    }
 }
 
 /// Resource holding the active touchpad backend.
 #[derive(Resource)]
 pub struct ActiveTouchpadBackend {
     backend: Box<dyn TouchpadBackend + Send + Sync>,
+    reconnect: reconnect::ReconnectTracker,
+    timer: polling::PollTimer,
 }
 
 impl Default for ActiveTouchpadBackend {
     fn default() -> Self {
-        Self {
-            backend: Box::new(StubBackend::new()),
-        }
+        Self::new(StubBackend::new())
     }
 }
 
@@ -103,25 +179,168 @@ impl ActiveTouchpadBackend {
     pub fn new<B: TouchpadBackend + Send + Sync + 'static>(backend: B) -> Self {
         Self {
             backend: Box::new(backend),
+            reconnect: default(),
+            timer: default(),
         }
     }
 
+    /// Name of the backend.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.backend.name()
+    }
+
     /// Get touchpad data from the backend.
     pub fn poll(&mut self) -> Option<BackendTouchpadData> {
         self.backend.poll()
     }
+
+    /// Check if the backend is connected.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.backend.is_connected()
+    }
+
+    /// Whether the backend is waiting to reconnect.
+    #[must_use]
+    pub fn is_reconnecting(&self) -> bool {
+        self.reconnect.is_reconnecting()
+    }
+
+    /// Features the backend provides.
+    #[must_use]
+    pub fn capabilities(&self) -> BackendCapabilities {
+        self.backend.capabilities()
+    }
+
+    /// Reopen the device, e.g. after the system wakes from sleep.
+    ///
+    /// Returns whether the device is connected again.
+    pub fn rehandshake(&mut self) -> bool {
+        self.timer = default();
+        self.backend.reconnect()
+    }
+}
+
+/// System to reopen backends after the system resumes from sleep.
+///
+/// Devices that fail to reopen are picked up by [`reconnect_backends`].
+pub fn rehandshake_on_resume(
+    mut resumed: MessageReader<InputSystemResumed>,
+    mut motion: ResMut<ActiveMotionBackend>,
+    mut touchpad: ResMut<ActiveTouchpadBackend>,
+) {
+    if resumed.read().count() == 0 {
+        return;
+    }
+    if !motion.rehandshake() {
+        warn!("{} backend did not reopen after resume", motion.name());
+    }
+    if !touchpad.rehandshake() {
+        warn!("{} backend did not reopen after resume", touchpad.name());
+    }
+}
+
+/// System to detect dropped backends and reconnect them with backoff.
+pub fn reconnect_backends(
+    time: Res<Time<Real>>,
+    policy: Res<ReconnectPolicy>,
+    mut motion: ResMut<ActiveMotionBackend>,
+    mut touchpad: ResMut<ActiveTouchpadBackend>,
+    mut disconnected: MessageWriter<BackendDisconnected>,
+    mut reconnected: MessageWriter<BackendReconnected>,
+) {
+    let delta = time.delta_secs();
+
+    let motion = &mut *motion;
+    let connected = motion.backend.is_connected();
+    let backend = &mut motion.backend;
+    let motion_change = motion
+        .reconnect
+        .update(connected, delta, &policy, || backend.reconnect());
+
+    let touchpad = &mut *touchpad;
+    let connected = touchpad.backend.is_connected();
+    let backend = &mut touchpad.backend;
+    let touchpad_change = touchpad
+        .reconnect
+        .update(connected, delta, &policy, || backend.reconnect());
+
+    for (name, change) in [
+        (motion.backend.name(), motion_change),
+        (touchpad.backend.name(), touchpad_change),
+    ] {
+        match change {
+            Some(reconnect::ConnectionChange::Disconnected) => {
+                warn!("{name} backend disconnected; reconnecting");
+                disconnected.write(BackendDisconnected { backend: name });
+            }
+            Some(reconnect::ConnectionChange::Reconnected { attempts }) => {
+                info!("{name} backend reconnected after {attempts} attempt(s)");
+                reconnected.write(BackendReconnected {
+                    backend: name,
+                    attempts,
+                });
+            }
+            None => {}
+        }
+    }
 }
 
 /// System to update gyro/accel data from the active backend.
+///
+/// Polls at most [`BackendPollSettings::motion_rate`] times per second
+/// (capped further in low-power mode, see [`InputPowerProfile`]) and
+/// combines every report received since the last poll with
+/// [`BackendPollSettings::gyro_batching`]. Gyro rotation is integrated over
+/// every report using sensor timestamps and stored in
+/// [`GyroData::rotation`](crate::gyro::GyroData::rotation), so total rotation
+/// does not depend on the frame rate.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
 pub fn update_motion_from_backend(
+    time: Res<Time<Real>>,
+    settings: Res<BackendPollSettings>,
+    power: Res<InputPowerProfile>,
     mut backend: ResMut<ActiveMotionBackend>,
+    mut status: ResMut<MotionBackendStatus>,
     mut gyro_query: Query<&mut crate::gyro::GyroData>,
     mut accel_query: Query<&mut crate::gyro::AccelData>,
+    mut diagnostics: Diagnostics,
 ) {
-    if let Some(data) = backend.poll() {
+    backend.since_poll += time.delta_secs();
+    if !backend
+        .timer
+        .ready(time.delta_secs(), power.poll_rate(settings.motion_rate))
+    {
+        // Rotation was reported on the polling frame; none happened since
+        for mut gyro in &mut gyro_query {
+            if gyro.rotation.is_some() {
+                gyro.rotation = Some(Vec3::ZERO);
+            }
+        }
+        return;
+    }
+
+    let start = Instant::now();
+    let samples = backend.poll_batch();
+    diagnostics.add_measurement(&crate::diagnostics::BACKEND_POLL_TIME, || {
+        start.elapsed().as_secs_f64() * 1000.0
+    });
+    let data = settings.gyro_batching.combine(&samples);
+    status.record_motion_poll(data.is_some(), time.elapsed_secs_f64());
+
+    let (rotation, last_sample_time) =
+        polling::integrate_gyro(&samples, backend.last_sample_time, backend.since_poll);
+    backend.last_sample_time = last_sample_time;
+    backend.since_poll = 0.0;
+
+    if let Some(data) = data {
         // Update all gyro components with the backend data
         for mut gyro in &mut gyro_query {
-            gyro.set_raw(data.gyro_pitch, data.gyro_yaw, data.gyro_roll);
+            gyro.set_integrated(data.gyro_pitch, data.gyro_yaw, data.gyro_roll, rotation);
         }
 
         // Update all accel components with the backend data
@@ -133,10 +352,29 @@ pub fn update_motion_from_backend(
 
 /// System to update touchpad data from the active backend.
 pub fn update_touchpad_from_backend(
+    time: Res<Time<Real>>,
+    settings: Res<BackendPollSettings>,
+    power: Res<InputPowerProfile>,
     mut backend: ResMut<ActiveTouchpadBackend>,
+    mut status: ResMut<MotionBackendStatus>,
     mut touchpad_query: Query<&mut crate::touchpad::TouchpadData>,
+    mut diagnostics: Diagnostics,
 ) {
-    if let Some(data) = backend.poll() {
+    if !backend
+        .timer
+        .ready(time.delta_secs(), power.poll_rate(settings.touchpad_rate))
+    {
+        return;
+    }
+
+    let start = Instant::now();
+    let data = backend.poll();
+    diagnostics.add_measurement(&crate::diagnostics::BACKEND_POLL_TIME, || {
+        start.elapsed().as_secs_f64() * 1000.0
+    });
+    status.record_touchpad_poll(data.is_some(), time.elapsed_secs_f64());
+
+    if let Some(data) = data {
         for mut touchpad in &mut touchpad_query {
             // Update finger 1
             touchpad.set_finger(0, data.finger1.x, data.finger1.y, data.finger1.active);
@@ -148,6 +386,24 @@ pub fn update_touchpad_from_backend(
     }
 }
 
+/// System to refresh [`MotionBackendStatus`] from the active backends.
+pub fn update_backend_status(
+    motion: Res<ActiveMotionBackend>,
+    touchpad: Res<ActiveTouchpadBackend>,
+    mut status: ResMut<MotionBackendStatus>,
+) {
+    let connected = motion.is_connected();
+    if status.connected && !connected {
+        status.disconnects += 1;
+    }
+
+    status.motion_backend = motion.name();
+    status.touchpad_backend = touchpad.name();
+    status.capabilities = motion.capabilities().union(touchpad.capabilities());
+    status.connected = connected;
+    status.reconnecting = motion.is_reconnecting() || touchpad.is_reconnecting();
+}
+
 /// Register motion backend resources and systems.
 #[expect(
     dead_code,
@@ -155,7 +411,17 @@ pub fn update_touchpad_from_backend(
 )]
 pub(crate) fn register_motion_backend(app: &mut App) {
     app.init_resource::<ActiveMotionBackend>()
-        .init_resource::<ActiveTouchpadBackend>();
+        .init_resource::<ActiveTouchpadBackend>()
+        .init_resource::<ReconnectPolicy>()
+        .init_resource::<MotionBackendStatus>()
+        .init_resource::<BackendPollSettings>()
+        .init_resource::<LedBackends>()
+        .init_resource::<TriggerRumbleBackends>()
+        .register_type::<BackendPollSettings>()
+        .add_message::<BackendDisconnected>()
+        .add_message::<BackendReconnected>();
+    #[cfg(feature = "dualsense")]
+    app.init_resource::<DualSenseDevices>();
 }
 
 /// Add motion backend systems.
@@ -163,9 +429,58 @@ pub(crate) fn register_motion_backend(app: &mut App) {
     dead_code,
     reason = "called from plugin when motion-backends feature is enabled"
 )]
-pub(crate) fn add_motion_backend_systems(app: &mut App) {
+pub(crate) fn add_motion_backend_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        (
+            rehandshake_on_resume,
+            reconnect_backends,
+            update_backend_status,
+            (update_motion_from_backend, update_touchpad_from_backend),
+        )
+            .chain(),
+    );
+    app.add_systems(
+        schedules.update,
+        leds::apply_led_backends
+            .after(crate::led::handle_led_requests)
+            .run_if(subsystem_enabled(Subsystem::Leds)),
+    );
+    app.add_systems(
+        schedules.update,
+        trigger_rumble::apply_trigger_rumble_backends
+            .after(crate::haptics::send_rumble_output)
+            .run_if(subsystem_enabled(Subsystem::Haptics)),
+    );
+    #[cfg(feature = "dualsense")]
+    app.add_systems(
+        schedules.update,
+        (
+            rehandshake_dualsense_on_resume,
+            reconnect_dualsense_devices,
+            assign_dualsense_devices,
+            update_dualsense_streams,
+        )
+            .chain()
+            .after(reconnect_backends),
+    );
+    #[cfg(feature = "dualsense")]
+    app.add_systems(
+        schedules.update,
+        sync_dualsense_leds
+            .after(assign_dualsense_devices)
+            .after(crate::led::handle_led_requests)
+            .run_if(subsystem_enabled(Subsystem::Leds)),
+    );
+    #[cfg(feature = "dualsense")]
     app.add_systems(
-        Update,
-        (update_motion_from_backend, update_touchpad_from_backend),
+        schedules.update,
+        sync_dualsense_battery
+            .after(assign_dualsense_devices)
+            .before(crate::battery::detect_low_battery)
+            .run_if(subsystem_enabled(Subsystem::Battery)),
     );
 }
@@ -0,0 +1,208 @@
+//! Polling rate limits and report batching for backends.
+//!
+//! Backends may deliver several reports per frame at low frame rates, or
+//! none at high ones. [`BackendPollSettings`] caps how often each backend is
+//! polled, and [`GyroBatching`] controls how the reports received since the
+//! last poll are combined into one reading.
+
+use bevy::prelude::*;
+
+use super::backend::MotionData;
+
+/// How to combine the motion reports received since the last poll.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum GyroBatching {
+    /// Use only the newest report.
+    Latest,
+    /// Average every report.
+    #[default]
+    Average,
+}
+
+impl GyroBatching {
+    /// Combine a batch of reports, oldest first.
+    #[must_use]
+    pub fn combine(self, samples: &[MotionData]) -> Option<MotionData> {
+        let latest = *samples.last()?;
+        if self == Self::Latest {
+            return Some(latest);
+        }
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "report batches are far smaller than f32 precision"
+        )]
+        let count = samples.len() as f32;
+        let sum = samples
+            .iter()
+            .fold(MotionData::default(), |sum, sample| MotionData {
+                gyro_pitch: sum.gyro_pitch + sample.gyro_pitch,
+                gyro_yaw: sum.gyro_yaw + sample.gyro_yaw,
+                gyro_roll: sum.gyro_roll + sample.gyro_roll,
+                accel_x: sum.accel_x + sample.accel_x,
+                accel_y: sum.accel_y + sample.accel_y,
+                accel_z: sum.accel_z + sample.accel_z,
+                timestamp: None,
+            });
+        Some(MotionData {
+            gyro_pitch: sum.gyro_pitch / count,
+            gyro_yaw: sum.gyro_yaw / count,
+            gyro_roll: sum.gyro_roll / count,
+            accel_x: sum.accel_x / count,
+            accel_y: sum.accel_y / count,
+            accel_z: sum.accel_z / count,
+            timestamp: latest.timestamp,
+        })
+    }
+}
+
+/// Longest gap between two samples counted toward rotation, in seconds.
+///
+/// Larger gaps (a stalled device or a paused game) would otherwise turn one
+/// sample into a large jump.
+pub const MAX_SAMPLE_GAP: f32 = 0.1;
+
+/// Integrate gyro rates over a batch of reports, oldest first.
+///
+/// Each sample's rate is multiplied by the time since the previous sample,
+/// taken from the sensor timestamps. `previous` is the timestamp of the last
+/// sample of the previous batch. Samples without a timestamp, and the first
+/// sample ever, share `elapsed` (seconds since the last poll) evenly.
+///
+/// Returns the rotation as (pitch, yaw, roll) in radians and the timestamp
+/// to pass as `previous` next time.
+#[must_use]
+pub fn integrate_gyro(
+    samples: &[MotionData],
+    previous: Option<f64>,
+    elapsed: f32,
+) -> (Vec3, Option<f64>) {
+    if samples.is_empty() {
+        return (Vec3::ZERO, previous);
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "report batches are far smaller than f32 precision"
+    )]
+    let shared_dt = elapsed / samples.len() as f32;
+    let mut last = previous;
+    let mut rotation = Vec3::ZERO;
+    for sample in samples {
+        let dt = match (sample.timestamp, last) {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "sample gaps are clamped to a fraction of a second"
+            )]
+            (Some(timestamp), Some(last)) => ((timestamp - last) as f32).clamp(0.0, MAX_SAMPLE_GAP),
+            _ => shared_dt,
+        };
+        if sample.timestamp.is_some() {
+            last = sample.timestamp;
+        }
+        rotation += Vec3::new(sample.gyro_pitch, sample.gyro_yaw, sample.gyro_roll) * dt;
+    }
+    (rotation, last)
+}
+
+/// Polling settings for the active backends.
+///
+/// Both backends are polled every frame by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct BackendPollSettings {
+    /// Maximum motion polls per second, or `None` to poll every frame.
+    pub motion_rate: Option<f32>,
+    /// Maximum touchpad polls per second, or `None` to poll every frame.
+    pub touchpad_rate: Option<f32>,
+    /// How batched motion reports are combined.
+    pub gyro_batching: GyroBatching,
+}
+
+/// Rate limiter for backend polls.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PollTimer {
+    elapsed: f32,
+}
+
+impl PollTimer {
+    /// Advance by `delta` seconds and return whether a poll is due.
+    pub fn ready(&mut self, delta: f32, rate: Option<f32>) -> bool {
+        let Some(rate) = rate.filter(|rate| *rate > 0.0) else {
+            return true;
+        };
+        let interval = rate.recip();
+        self.elapsed += delta;
+        if self.elapsed < interval {
+            return false;
+        }
+        // Drop whole missed intervals instead of polling repeatedly to catch up
+        self.elapsed %= interval;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(yaw: f32) -> MotionData {
+        MotionData {
+            gyro_yaw: yaw,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_batching_modes() {
+        let samples = [sample(1.0), sample(2.0), sample(6.0)];
+        assert_eq!(
+            GyroBatching::Latest.combine(&samples).unwrap().gyro_yaw,
+            6.0
+        );
+        assert_eq!(
+            GyroBatching::Average.combine(&samples).unwrap().gyro_yaw,
+            3.0
+        );
+        assert!(GyroBatching::Average.combine(&[]).is_none());
+    }
+
+    #[test]
+    fn test_poll_timer_limits_rate() {
+        let mut timer = PollTimer::default();
+        assert!(timer.ready(0.001, None));
+
+        // 10 Hz with 30ms frames polls on the 4th and 7th frames
+        let polls: Vec<usize> = (1..=9).filter(|_| timer.ready(0.03, Some(10.0))).collect();
+        assert_eq!(polls, vec![4, 7]);
+    }
+
+    #[test]
+    fn test_integrate_gyro_uses_timestamps() {
+        let samples = [0.01, 0.02, 0.04].map(|timestamp| MotionData {
+            gyro_yaw: 10.0,
+            timestamp: Some(timestamp),
+            ..default()
+        });
+        let (rotation, last) = integrate_gyro(&samples, Some(0.0), 0.5);
+        assert!((rotation.y - 0.4).abs() < 1e-5);
+        assert_eq!(last, Some(0.04));
+
+        // A stall is capped instead of producing a jump
+        let late = [MotionData {
+            gyro_yaw: 10.0,
+            timestamp: Some(5.0),
+            ..default()
+        }];
+        let (rotation, _) = integrate_gyro(&late, last, 5.0);
+        assert!((rotation.y - 10.0 * MAX_SAMPLE_GAP).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_integrate_gyro_without_timestamps_spreads_elapsed() {
+        let samples = [sample(2.0), sample(4.0)];
+        let (rotation, last) = integrate_gyro(&samples, None, 0.1);
+        assert!((rotation.y - 0.3).abs() < 1e-5);
+        assert_eq!(last, None);
+    }
+}
@@ -0,0 +1,232 @@
+//! Reconnection handling for backends whose controller drops out.
+//!
+//! Bluetooth controllers disconnect when they go to sleep, run out of range,
+//! or lose power. When a backend reports a drop, it is asked to
+//! [`reconnect`](super::MotionBackend::reconnect) with exponential backoff
+//! until the controller returns, and [`BackendReconnected`] lets dependent
+//! systems resync (recalibrate gyro, resend LED state, and so on).
+
+use bevy::prelude::*;
+
+/// Backoff settings for reconnection attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct ReconnectPolicy {
+    /// Whether dropped backends are reconnected automatically.
+    pub enabled: bool,
+    /// Seconds before the first attempt.
+    pub initial_delay: f32,
+    /// Longest wait between attempts in seconds.
+    pub max_delay: f32,
+    /// Delay growth factor after each failed attempt.
+    pub multiplier: f32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            initial_delay: 0.5,
+            max_delay: 30.0,
+            multiplier: 2.0,
+        }
+    }
+}
+
+/// Exponential backoff timer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    delay: f32,
+    remaining: f32,
+    attempts: u32,
+}
+
+impl Backoff {
+    /// Start waiting for the first attempt.
+    #[must_use]
+    pub fn new(policy: &ReconnectPolicy) -> Self {
+        Self {
+            delay: policy.initial_delay,
+            remaining: policy.initial_delay,
+            attempts: 0,
+        }
+    }
+
+    /// Advance the timer, returning `true` when an attempt is due.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.remaining -= delta;
+        if self.remaining > 0.0 {
+            return false;
+        }
+        self.attempts += 1;
+        true
+    }
+
+    /// Record a failed attempt and wait longer before the next.
+    pub fn failed(&mut self, policy: &ReconnectPolicy) {
+        self.delay = (self.delay * policy.multiplier).min(policy.max_delay);
+        self.remaining = self.delay;
+    }
+
+    /// Attempts made so far.
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Current wait between attempts in seconds.
+    #[must_use]
+    pub const fn delay(&self) -> f32 {
+        self.delay
+    }
+}
+
+/// Change in a backend's connection reported by [`ReconnectTracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionChange {
+    /// The controller dropped.
+    Disconnected,
+    /// The controller returned after a number of attempts.
+    Reconnected {
+        /// Attempts made.
+        attempts: u32,
+    },
+}
+
+/// Tracks one backend's connection and drives its reconnect attempts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReconnectTracker {
+    was_connected: bool,
+    backoff: Option<Backoff>,
+}
+
+impl ReconnectTracker {
+    /// Whether reconnection is in progress.
+    #[must_use]
+    pub const fn is_reconnecting(&self) -> bool {
+        self.backoff.is_some()
+    }
+
+    /// Update with the backend's connection state.
+    ///
+    /// `reconnect` is called when an attempt is due and returns whether the
+    /// controller is back.
+    pub fn update(
+        &mut self,
+        connected: bool,
+        delta: f32,
+        policy: &ReconnectPolicy,
+        reconnect: impl FnOnce() -> bool,
+    ) -> Option<ConnectionChange> {
+        if connected {
+            self.was_connected = true;
+            return self
+                .backoff
+                .take()
+                .map(|backoff| ConnectionChange::Reconnected {
+                    attempts: backoff.attempts(),
+                });
+        }
+
+        if self.was_connected {
+            self.was_connected = false;
+            if policy.enabled {
+                self.backoff = Some(Backoff::new(policy));
+            }
+            return Some(ConnectionChange::Disconnected);
+        }
+
+        let backoff = self.backoff.as_mut()?;
+        if !policy.enabled || !backoff.tick(delta) {
+            return None;
+        }
+        if reconnect() {
+            let attempts = backoff.attempts();
+            self.backoff = None;
+            self.was_connected = true;
+            return Some(ConnectionChange::Reconnected { attempts });
+        }
+        backoff.failed(policy);
+        None
+    }
+}
+
+/// Event fired when a backend's controller drops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct BackendDisconnected {
+    /// Backend name.
+    pub backend: &'static str,
+}
+
+/// Event fired when a backend's controller returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct BackendReconnected {
+    /// Backend name.
+    pub backend: &'static str,
+    /// Attempts made before the controller returned.
+    pub attempts: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_to_max() {
+        let policy = ReconnectPolicy {
+            initial_delay: 1.0,
+            max_delay: 3.0,
+            ..default()
+        };
+        let mut backoff = Backoff::new(&policy);
+        assert!(!backoff.tick(0.5));
+        assert!(backoff.tick(0.5));
+        backoff.failed(&policy);
+        assert_eq!(backoff.delay(), 2.0);
+        backoff.failed(&policy);
+        assert_eq!(backoff.delay(), 3.0);
+        assert_eq!(backoff.attempts(), 1);
+    }
+
+    #[test]
+    fn test_tracker_reconnects_after_drop() {
+        let policy = ReconnectPolicy {
+            initial_delay: 1.0,
+            ..default()
+        };
+        let mut tracker = ReconnectTracker::default();
+
+        // Never connected: nothing to recover
+        assert_eq!(tracker.update(false, 1.0, &policy, || true), None);
+
+        assert_eq!(tracker.update(true, 1.0, &policy, || true), None);
+        assert_eq!(
+            tracker.update(false, 1.0, &policy, || true),
+            Some(ConnectionChange::Disconnected)
+        );
+        assert!(tracker.is_reconnecting());
+
+        // First attempt fails, second waits twice as long
+        assert_eq!(tracker.update(false, 1.0, &policy, || false), None);
+        assert_eq!(tracker.update(false, 1.5, &policy, || true), None);
+        assert_eq!(
+            tracker.update(false, 0.5, &policy, || true),
+            Some(ConnectionChange::Reconnected { attempts: 2 })
+        );
+        assert!(!tracker.is_reconnecting());
+    }
+
+    #[test]
+    fn test_disabled_policy_does_not_retry() {
+        let policy = ReconnectPolicy {
+            enabled: false,
+            ..default()
+        };
+        let mut tracker = ReconnectTracker::default();
+        tracker.update(true, 0.0, &policy, || true);
+        assert_eq!(
+            tracker.update(false, 0.0, &policy, || true),
+            Some(ConnectionChange::Disconnected)
+        );
+        assert_eq!(tracker.update(false, 60.0, &policy, || true), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Health and capability reporting for the active backends.
+//!
+//! [`MotionBackendStatus`] is refreshed every frame so games can show
+//! "motion controls unavailable" UI when the backend is missing, dropped, or
+//! has stopped producing data.
+
+use bevy::prelude::*;
+
+use super::backend::BackendCapabilities;
+
+/// Resource describing the active motion and touchpad backends.
+#[derive(Debug, Clone, Default, PartialEq, Resource)]
+pub struct MotionBackendStatus {
+    /// Name of the active motion backend.
+    pub motion_backend: &'static str,
+    /// Name of the active touchpad backend.
+    pub touchpad_backend: &'static str,
+    /// Combined capabilities of both backends.
+    pub capabilities: BackendCapabilities,
+    /// Whether the motion backend has a controller.
+    pub connected: bool,
+    /// Whether a dropped backend is being reconnected.
+    pub reconnecting: bool,
+    /// Time of the last motion poll that returned data, in seconds of real time.
+    pub last_motion_poll: Option<f64>,
+    /// Time of the last touchpad poll that returned data, in seconds of real time.
+    pub last_touchpad_poll: Option<f64>,
+    /// Consecutive motion polls without data while connected.
+    pub empty_polls: u32,
+    /// Times a backend has disconnected.
+    pub disconnects: u32,
+}
+
+impl MotionBackendStatus {
+    /// Whether gyro data is currently available.
+    #[must_use]
+    pub const fn motion_available(&self) -> bool {
+        self.connected && !self.reconnecting && self.capabilities.gyro
+    }
+
+    /// Whether motion is unavailable or no data has arrived for `stale_after` seconds.
+    #[must_use]
+    pub fn is_degraded(&self, now: f64, stale_after: f64) -> bool {
+        !self.motion_available()
+            || self
+                .last_motion_poll
+                .is_none_or(|last| now - last > stale_after)
+    }
+
+    /// Record the result of a motion poll.
+    pub fn record_motion_poll(&mut self, received: bool, now: f64) {
+        if received {
+            self.last_motion_poll = Some(now);
+            self.empty_polls = 0;
+        } else if self.connected {
+            self.empty_polls += 1;
+        }
+    }
+
+    /// Record the result of a touchpad poll.
+    pub fn record_touchpad_poll(&mut self, received: bool, now: f64) {
+        if received {
+            self.last_touchpad_poll = Some(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_tracks_polls() {
+        let mut status = MotionBackendStatus {
+            connected: true,
+            capabilities: BackendCapabilities::MOTION,
+            ..default()
+        };
+        assert!(status.is_degraded(0.0, 1.0));
+
+        status.record_motion_poll(true, 1.0);
+        assert!(status.motion_available());
+        assert!(!status.is_degraded(1.5, 1.0));
+
+        status.record_motion_poll(false, 2.0);
+        status.record_motion_poll(false, 2.5);
+        assert_eq!(status.empty_polls, 2);
+        assert!(status.is_degraded(2.5, 1.0));
+    }
+
+    #[test]
+    fn test_reconnecting_backend_is_unavailable() {
+        let status = MotionBackendStatus {
+            connected: true,
+            reconnecting: true,
+            capabilities: BackendCapabilities::MOTION,
+            last_motion_poll: Some(0.0),
+            ..default()
+        };
+        assert!(!status.motion_available());
+        assert!(status.is_degraded(0.0, 1.0));
+    }
+}
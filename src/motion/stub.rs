@@ -3,7 +3,9 @@
 //! This is the fallback backend when no platform-specific backend is available.
 //! It always returns `None` from poll operations.
 
-use super::backend::{MotionBackend, MotionData, TouchpadBackend, TouchpadData};
+use super::backend::{
+    BackendCapabilities, MotionBackend, MotionData, TouchpadBackend, TouchpadData,
+};
 
 /// Stub backend that provides no data.
 ///
@@ -33,6 +35,10 @@ impl MotionBackend for StubBackend {
     fn name(&self) -> &'static str {
         "stub"
     }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
 }
 
 impl TouchpadBackend for StubBackend {
@@ -47,4 +53,12 @@ impl TouchpadBackend for StubBackend {
     fn name(&self) -> &'static str {
         "stub"
     }
+
+    fn is_connected(&self) -> bool {
+        false
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
 }
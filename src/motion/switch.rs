@@ -0,0 +1,465 @@
+//! Switch Pro Controller and Joy-Con motion backend using `hidapi`.
+//!
+//! Nintendo controllers only send motion data after they are told to: the
+//! backend switches them to full input reports (`0x30`) and enables the IMU
+//! with subcommands. A Switch Pro Controller on USB also needs the `0x80`
+//! handshake first, which is applied when the controller has
+//! [`ControllerQuirk::SwitchProUSBHandshake`].
+//!
+//! Each full report carries three IMU samples taken 5 ms apart, so the
+//! backend returns every sample with its own timestamp.
+//!
+//! # Requirements
+//!
+//! - Enable the `hidapi` feature in Cargo.toml
+//! - On Linux, the user needs read access to the `hidraw` device (see the
+//!   udev rules in `docs/HARDWARE_INTEGRATION_GUIDE.md`)
+//!
+//! A single Joy-Con reports motion in its own frame, so held sideways its
+//! axes are swapped relative to a Pro Controller.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::motion::{ActiveMotionBackend, SwitchBackend};
+//!
+//! fn use_switch_backend(mut commands: Commands) {
+//!     if let Ok(switch) = SwitchBackend::open() {
+//!         commands.insert_resource(ActiveMotionBackend::new(switch));
+//!     }
+//! }
+//! ```
+
+use hidapi::{BusType, DeviceInfo, HidApi, HidDevice};
+use std::sync::{Arc, Mutex};
+
+use super::backend::{BackendCapabilities, MotionBackend, MotionData, switch_calibration};
+use crate::profiles::{ConnectionType, ControllerModel, ControllerQuirk, DetectedController};
+
+/// Most reports read from the device per poll.
+const MAX_REPORTS_PER_POLL: usize = 64;
+
+/// Most motion samples kept when nothing takes them.
+const MAX_QUEUED_SAMPLES: usize = 256;
+
+/// Milliseconds to wait for a handshake or subcommand reply.
+const REPLY_TIMEOUT_MS: i32 = 100;
+
+/// Reports read while waiting for a reply.
+const MAX_REPLY_READS: usize = 16;
+
+/// Standard full input report with IMU data.
+const FULL_REPORT_ID: u8 = 0x30;
+
+/// Subcommand reply report.
+const SUBCOMMAND_REPLY_ID: u8 = 0x21;
+
+/// Offset of the first IMU sample in a full report.
+const IMU_OFFSET: usize = 13;
+
+/// Bytes per IMU sample: accelerometer then gyro, three `i16` each.
+const IMU_SAMPLE_LEN: usize = 12;
+
+/// IMU samples per full report.
+const IMU_SAMPLES: usize = 3;
+
+/// Seconds between IMU samples, and per tick of the report timer.
+const SAMPLE_SECONDS: f64 = 0.005;
+
+/// Rumble data that leaves the motors idle.
+const NEUTRAL_RUMBLE: [u8; 8] = [0x00, 0x01, 0x40, 0x40, 0x00, 0x01, 0x40, 0x40];
+
+/// USB handshake commands sent to a Switch Pro Controller, in order.
+///
+/// Handshake, switch to 3 Mbit, handshake again at the new speed, then
+/// force HID mode so the controller never times out.
+const USB_HANDSHAKE: [(u8, bool); 4] = [(0x02, true), (0x03, true), (0x02, true), (0x04, false)];
+
+/// Subcommand selecting the input report mode.
+const SET_REPORT_MODE: u8 = 0x03;
+
+/// Subcommand enabling or disabling the IMU.
+const ENABLE_IMU: u8 = 0x40;
+
+/// Whether a controller is supported by this backend.
+fn is_supported(controller: &DetectedController) -> bool {
+    matches!(
+        controller.model,
+        ControllerModel::SwitchPro | ControllerModel::SwitchJoyCon
+    )
+}
+
+/// Connection type of a HID device.
+const fn connection_type(bus: BusType) -> ConnectionType {
+    match bus {
+        BusType::Usb => ConnectionType::Usb,
+        BusType::Bluetooth => ConnectionType::Bluetooth,
+        _ => ConnectionType::Unknown,
+    }
+}
+
+/// Build a subcommand output report.
+fn subcommand_report(counter: u8, subcommand: u8, args: &[u8]) -> Vec<u8> {
+    let mut report = Vec::with_capacity(11 + args.len());
+    report.push(0x01);
+    report.push(counter & 0x0f);
+    report.extend_from_slice(&NEUTRAL_RUMBLE);
+    report.push(subcommand);
+    report.extend_from_slice(args);
+    report
+}
+
+/// Data from one parsed full input report.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwitchInputReport {
+    /// IMU samples, oldest first, without timestamps.
+    pub samples: [MotionData; IMU_SAMPLES],
+    /// Report timer, which ticks every 5 ms and wraps at 256.
+    pub timer: u8,
+}
+
+impl SwitchInputReport {
+    /// Parse a full input report, including its report ID byte.
+    ///
+    /// Returns `None` for other reports, such as subcommand replies.
+    #[must_use]
+    pub fn parse(report: &[u8]) -> Option<Self> {
+        if *report.first()? != FULL_REPORT_ID
+            || report.len() < IMU_OFFSET + IMU_SAMPLE_LEN * IMU_SAMPLES
+        {
+            return None;
+        }
+
+        let mut samples = [MotionData::default(); IMU_SAMPLES];
+        for (index, sample) in samples.iter_mut().enumerate() {
+            let at = IMU_OFFSET + index * IMU_SAMPLE_LEN;
+            let read_i16 =
+                |offset: usize| i16::from_le_bytes([report[at + offset], report[at + offset + 1]]);
+            // Nintendo axes are X forward, Y left, Z up
+            *sample = MotionData {
+                gyro_pitch: -switch_calibration::gyro_to_rads(read_i16(8)),
+                gyro_yaw: switch_calibration::gyro_to_rads(read_i16(10)),
+                gyro_roll: -switch_calibration::gyro_to_rads(read_i16(6)),
+                accel_x: -switch_calibration::accel_to_ms2(read_i16(2)),
+                accel_y: switch_calibration::accel_to_ms2(read_i16(4)),
+                accel_z: -switch_calibration::accel_to_ms2(read_i16(0)),
+                timestamp: None,
+            };
+        }
+
+        Some(Self {
+            samples,
+            timer: report[1],
+        })
+    }
+}
+
+/// Running clock built from the wrapping report timer.
+#[derive(Debug, Default)]
+struct ReportClock {
+    last_timer: Option<u8>,
+    seconds: f64,
+}
+
+impl ReportClock {
+    /// Advance to a report's timer and return the time in seconds.
+    fn advance(&mut self, timer: u8) -> f64 {
+        if let Some(last) = self.last_timer {
+            self.seconds += f64::from(timer.wrapping_sub(last)) * SAMPLE_SECONDS;
+        }
+        self.last_timer = Some(timer);
+        self.seconds
+    }
+}
+
+/// Device handle and data shared by clones of a [`SwitchBackend`].
+struct SwitchShared {
+    device: Option<HidDevice>,
+    model: ControllerModel,
+    vendor_id: u16,
+    product_id: u16,
+    serial: Option<String>,
+    clock: ReportClock,
+    /// Motion samples not yet taken.
+    samples: Vec<MotionData>,
+}
+
+impl SwitchShared {
+    /// Read every pending report.
+    fn pump(&mut self) {
+        let Some(device) = &self.device else {
+            return;
+        };
+        let mut buf = [0u8; 64];
+        let mut lost = false;
+        for _ in 0..MAX_REPORTS_PER_POLL {
+            match device.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => {
+                    if let Some(report) = SwitchInputReport::parse(&buf[..len]) {
+                        let time = self.clock.advance(report.timer);
+                        // The timer dates the newest sample
+                        for (index, sample) in report.samples.iter().enumerate() {
+                            #[expect(
+                                clippy::cast_precision_loss,
+                                reason = "index is below IMU_SAMPLES"
+                            )]
+                            let age = (IMU_SAMPLES - 1 - index) as f64;
+                            self.samples.push(MotionData {
+                                timestamp: Some(time - age * SAMPLE_SECONDS),
+                                ..*sample
+                            });
+                        }
+                    }
+                }
+                Err(_) => {
+                    lost = true;
+                    break;
+                }
+            }
+        }
+
+        if lost {
+            self.device = None;
+        }
+        let excess = self.samples.len().saturating_sub(MAX_QUEUED_SAMPLES);
+        self.samples.drain(..excess);
+    }
+}
+
+/// Switch Pro Controller and Joy-Con motion backend over raw HID.
+///
+/// Clones share the same device.
+#[derive(Clone)]
+pub struct SwitchBackend {
+    shared: Arc<Mutex<SwitchShared>>,
+}
+
+impl SwitchBackend {
+    /// Open and configure the first connected Switch Pro Controller or
+    /// Joy-Con.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if HID can't be initialized or no supported
+    /// controller is connected and accepts the setup commands.
+    pub fn open() -> crate::Result<Self> {
+        let api = hid_api()?;
+        let (device, info, model) = open_matching(&api, |_| true)?;
+        Ok(Self {
+            shared: Arc::new(Mutex::new(SwitchShared {
+                device: Some(device),
+                model,
+                vendor_id: info.vendor_id(),
+                product_id: info.product_id(),
+                serial: info.serial_number().map(str::to_string),
+                clock: ReportClock::default(),
+                samples: Vec::new(),
+            })),
+        })
+    }
+
+    /// The model of the open controller.
+    #[must_use]
+    pub fn model(&self) -> Option<ControllerModel> {
+        self.shared.lock().ok().map(|shared| shared.model)
+    }
+
+    /// Reopen the same controller by vendor, product, and serial.
+    fn reopen(&self) -> bool {
+        let Ok(mut shared) = self.shared.lock() else {
+            return false;
+        };
+        let Ok(api) = hid_api() else {
+            return false;
+        };
+        let (vendor_id, product_id) = (shared.vendor_id, shared.product_id);
+        let serial = shared.serial.clone();
+        let Ok((device, _, model)) = open_matching(&api, |info| {
+            info.vendor_id() == vendor_id
+                && info.product_id() == product_id
+                && info.serial_number() == serial.as_deref()
+        }) else {
+            return false;
+        };
+        shared.device = Some(device);
+        shared.model = model;
+        // The report timer restarts with the new handle
+        shared.clock = ReportClock::default();
+        shared.samples.clear();
+        true
+    }
+}
+
+fn hid_api() -> crate::Result<HidApi> {
+    HidApi::new().map_err(|e| crate::Error::BackendInit {
+        backend: "switch-hid",
+        reason: e.to_string(),
+    })
+}
+
+/// Write a report and wait for a reply accepted by a filter.
+fn send_and_wait(device: &HidDevice, report: &[u8], reply: impl Fn(&[u8]) -> bool) -> bool {
+    if device.write(report).is_err() {
+        return false;
+    }
+    let mut buf = [0u8; 64];
+    for _ in 0..MAX_REPLY_READS {
+        match device.read_timeout(&mut buf, REPLY_TIMEOUT_MS) {
+            Ok(len) if len > 0 && reply(&buf[..len]) => return true,
+            Ok(0) | Err(_) => return false,
+            Ok(_) => {}
+        }
+    }
+    false
+}
+
+/// Run the USB handshake and enable full reports with IMU data.
+fn configure(device: &HidDevice, quirks: &[ControllerQuirk]) -> bool {
+    if quirks.contains(&ControllerQuirk::SwitchProUSBHandshake) {
+        for (command, replies) in USB_HANDSHAKE {
+            let report = [0x80, command];
+            let acknowledged = if replies {
+                send_and_wait(device, &report, |reply| reply.starts_with(&[0x81, command]))
+            } else {
+                device.write(&report).is_ok()
+            };
+            if !acknowledged {
+                return false;
+            }
+        }
+    }
+
+    let setup = [(SET_REPORT_MODE, FULL_REPORT_ID), (ENABLE_IMU, 0x01)];
+    setup
+        .into_iter()
+        .zip(0u8..)
+        .all(|((subcommand, arg), counter)| {
+            send_and_wait(
+                device,
+                &subcommand_report(counter, subcommand, &[arg]),
+                |reply| {
+                    reply.first() == Some(&SUBCOMMAND_REPLY_ID)
+                        && reply.get(14) == Some(&subcommand)
+                },
+            )
+        })
+}
+
+/// Open the first supported controller accepted by a filter.
+fn open_matching(
+    api: &HidApi,
+    filter: impl Fn(&DeviceInfo) -> bool,
+) -> crate::Result<(HidDevice, DeviceInfo, ControllerModel)> {
+    for info in api.device_list() {
+        let detected = DetectedController::new(info.vendor_id(), info.product_id());
+        if !is_supported(&detected) || !filter(info) {
+            continue;
+        }
+        let Ok(device) = info.open_device(api) else {
+            continue;
+        };
+        let quirks = detected.quirks_on(connection_type(info.bus_type()));
+        if !configure(&device, &quirks) || device.set_blocking_mode(false).is_err() {
+            continue;
+        }
+        return Ok((device, info.clone(), detected.model));
+    }
+    Err(crate::Error::BackendInit {
+        backend: "switch-hid",
+        reason: "no Switch Pro Controller or Joy-Con connected".into(),
+    })
+}
+
+impl MotionBackend for SwitchBackend {
+    fn poll(&mut self) -> Option<MotionData> {
+        self.poll_batch().pop()
+    }
+
+    fn poll_batch(&mut self) -> Vec<MotionData> {
+        let Ok(mut shared) = self.shared.lock() else {
+            return Vec::new();
+        };
+        shared.pump();
+        std::mem::take(&mut shared.samples)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.shared
+            .lock()
+            .is_ok_and(|shared| shared.device.is_some())
+    }
+
+    fn name(&self) -> &'static str {
+        "switch-hid"
+    }
+
+    fn reconnect(&mut self) -> bool {
+        self.reopen()
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::MOTION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_report_axes() {
+        let mut report = vec![0u8; 49];
+        report[0] = FULL_REPORT_ID;
+        report[1] = 42;
+        // Newest sample: 4096 on accel X (1 g forward), 13371 on gyro Z
+        let newest = IMU_OFFSET + 2 * IMU_SAMPLE_LEN;
+        report[newest..newest + 2].copy_from_slice(&4096i16.to_le_bytes());
+        report[newest + 10..newest + 12].copy_from_slice(&13371i16.to_le_bytes());
+
+        let parsed = SwitchInputReport::parse(&report).unwrap();
+        assert_eq!(parsed.timer, 42);
+        assert_eq!(parsed.samples[0].gyro_yaw, 0.0);
+        let sample = parsed.samples[2];
+        assert!((sample.accel_z + 9.81).abs() < 1e-4);
+        assert!((sample.gyro_yaw - 936f32.to_radians()).abs() < 1e-4);
+
+        assert!(SwitchInputReport::parse(&report[..40]).is_none());
+        report[0] = SUBCOMMAND_REPLY_ID;
+        assert!(SwitchInputReport::parse(&report).is_none());
+    }
+
+    #[test]
+    fn test_subcommand_report_layout() {
+        let report = subcommand_report(17, ENABLE_IMU, &[0x01]);
+        assert_eq!(report[0], 0x01);
+        // The packet counter is 4 bits
+        assert_eq!(report[1], 1);
+        assert_eq!(report[2..10], NEUTRAL_RUMBLE);
+        assert_eq!(report[10..], [ENABLE_IMU, 0x01]);
+    }
+
+    #[test]
+    fn test_usb_handshake_only_over_usb() {
+        let pro = DetectedController::new(0x057e, 0x2009);
+        assert!(is_supported(&pro));
+        assert!(
+            pro.quirks_on(connection_type(BusType::Usb))
+                .contains(&ControllerQuirk::SwitchProUSBHandshake)
+        );
+        assert!(
+            !pro.quirks_on(connection_type(BusType::Bluetooth))
+                .contains(&ControllerQuirk::SwitchProUSBHandshake)
+        );
+        assert!(!is_supported(&DetectedController::new(0x054c, 0x0ce6)));
+    }
+
+    #[test]
+    fn test_report_clock_wraps() {
+        let mut clock = ReportClock::default();
+        assert_eq!(clock.advance(250), 0.0);
+        let seconds = clock.advance(2);
+        assert!((seconds - 8.0 * SAMPLE_SECONDS).abs() < 1e-12);
+    }
+}
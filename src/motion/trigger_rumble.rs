@@ -0,0 +1,107 @@
+//! Writing trigger rumble to trigger motor backends.
+//!
+//! Bevy's gamepad rumble only drives the two main motors, so the trigger
+//! channels of [`RumbleIntensity`](crate::haptics::RumbleIntensity) need a
+//! [`TriggerRumbleBackend`] registered for the gamepad in
+//! [`TriggerRumbleBackends`], e.g. one wrapping `Windows.Gaming.Input` or
+//! GameInput for Xbox controllers.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use super::backend::TriggerRumbleBackend;
+use crate::haptics::RumbleOutput;
+
+/// Resource holding the trigger motor backend for each gamepad.
+#[derive(Default, Resource)]
+pub struct TriggerRumbleBackends {
+    backends: HashMap<Entity, Box<dyn TriggerRumbleBackend + Send + Sync>>,
+    /// Trigger strengths last written to each backend.
+    sent: HashMap<Entity, (f32, f32)>,
+}
+
+impl TriggerRumbleBackends {
+    /// Drive a gamepad's trigger motors with a backend, replacing any it had.
+    pub fn insert<B: TriggerRumbleBackend + Send + Sync + 'static>(
+        &mut self,
+        gamepad: Entity,
+        backend: B,
+    ) {
+        self.backends.insert(gamepad, Box::new(backend));
+        self.sent.remove(&gamepad);
+    }
+
+    /// Stop driving a gamepad's trigger motors.
+    pub fn remove(&mut self, gamepad: Entity) {
+        self.backends.remove(&gamepad);
+        self.sent.remove(&gamepad);
+    }
+
+    /// Name of the backend driving a gamepad's trigger motors.
+    #[must_use]
+    pub fn name(&self, gamepad: Entity) -> Option<&'static str> {
+        self.backends.get(&gamepad).map(|backend| backend.name())
+    }
+
+    /// Write a gamepad's trigger strengths if they changed since the last
+    /// write.
+    ///
+    /// Returns whether anything was written.
+    pub fn write(&mut self, gamepad: Entity, left: f32, right: f32) -> bool {
+        let Some(backend) = self.backends.get_mut(&gamepad) else {
+            return false;
+        };
+        if self.sent.get(&gamepad) == Some(&(left, right)) {
+            return false;
+        }
+        backend.set_trigger_rumble(left, right);
+        self.sent.insert(gamepad, (left, right));
+        true
+    }
+}
+
+/// System to write every gamepad's trigger rumble to its backend.
+pub fn apply_trigger_rumble_backends(
+    mut backends: ResMut<TriggerRumbleBackends>,
+    outputs: Query<(Entity, &RumbleOutput)>,
+) {
+    for (gamepad, output) in &outputs {
+        let playing = output.playing();
+        backends.write(gamepad, playing.left_trigger, playing.right_trigger);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct RecordingTriggers(Arc<Mutex<Vec<(f32, f32)>>>);
+
+    impl TriggerRumbleBackend for RecordingTriggers {
+        fn set_trigger_rumble(&mut self, left: f32, right: f32) {
+            self.0.lock().unwrap().push((left, right));
+        }
+
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+    }
+
+    #[test]
+    fn test_trigger_backends_write_only_changes() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let gamepad = Entity::from_bits(7);
+        let mut backends = TriggerRumbleBackends::default();
+        backends.insert(gamepad, RecordingTriggers(log.clone()));
+        assert_eq!(backends.name(gamepad), Some("recording"));
+
+        assert!(backends.write(gamepad, 0.5, 0.0));
+        assert!(!backends.write(gamepad, 0.5, 0.0));
+        assert!(backends.write(gamepad, 0.0, 0.0));
+        assert!(!backends.write(Entity::from_bits(8), 1.0, 1.0));
+
+        assert_eq!(*log.lock().unwrap(), vec![(0.5, 0.0), (0.0, 0.0)]);
+    }
+}
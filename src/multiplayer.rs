@@ -1,11 +1,16 @@
 //! Multiplayer input management.
 //!
 //! This module provides per-player input isolation, controller ownership,
-//! and player assignment for local multiplayer games.
+//! and player assignment for local multiplayer games. Each player can have
+//! their own [`ActionMap`] in [`PlayerActionMaps`], and reads their
+//! controller's actions from [`PlayerActionStates`].
 
 use bevy::prelude::*;
 use std::collections::HashMap;
 
+use crate::actions::{ActionMap, ActionState, Actionlike, GameAction, GamepadActionState};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
 /// Player identifier (0-indexed).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
 pub struct PlayerId(pub u8);
@@ -130,6 +135,109 @@ impl ControllerOwnership {
     }
 }
 
+/// Per-player action bindings.
+///
+/// A gamepad owned by a player with a map here is evaluated with that map
+/// instead of the global [`ActionMap`], so local co-op players can each
+/// rebind. Players without an entry use the global map.
+#[derive(Debug, Clone, Resource)]
+pub struct PlayerActionMaps<A: Actionlike = GameAction> {
+    /// Bindings for each player.
+    maps: HashMap<PlayerId, ActionMap<A>>,
+}
+
+impl<A: Actionlike> Default for PlayerActionMaps<A> {
+    fn default() -> Self {
+        Self {
+            maps: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> PlayerActionMaps<A> {
+    /// Give a player their own bindings, returning any they had.
+    pub fn insert(&mut self, player: PlayerId, map: ActionMap<A>) -> Option<ActionMap<A>> {
+        self.maps.insert(player, map)
+    }
+
+    /// Return a player to the global bindings.
+    pub fn remove(&mut self, player: PlayerId) -> Option<ActionMap<A>> {
+        self.maps.remove(&player)
+    }
+
+    /// A player's own bindings.
+    #[must_use]
+    pub fn get(&self, player: PlayerId) -> Option<&ActionMap<A>> {
+        self.maps.get(&player)
+    }
+
+    /// A player's own bindings, for rebinding.
+    pub fn get_mut(&mut self, player: PlayerId) -> Option<&mut ActionMap<A>> {
+        self.maps.get_mut(&player)
+    }
+
+    /// Bindings used for a player: their own, or `global`.
+    #[must_use]
+    pub fn resolve<'a>(
+        &'a self,
+        player: Option<PlayerId>,
+        global: &'a ActionMap<A>,
+    ) -> &'a ActionMap<A> {
+        player.and_then(|player| self.get(player)).unwrap_or(global)
+    }
+}
+
+/// Action state for each player with an assigned gamepad.
+///
+/// Mirrors the [`GamepadActionState`] of the gamepad each player owns in
+/// [`ControllerOwnership`], after input capture is applied.
+#[derive(Debug, Clone, Resource)]
+pub struct PlayerActionStates<A: Actionlike = GameAction> {
+    /// State for each player.
+    states: HashMap<PlayerId, ActionState<A>>,
+}
+
+impl<A: Actionlike> Default for PlayerActionStates<A> {
+    fn default() -> Self {
+        Self {
+            states: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> PlayerActionStates<A> {
+    /// A player's action state, if they have a gamepad.
+    #[must_use]
+    pub fn get(&self, player: PlayerId) -> Option<&ActionState<A>> {
+        self.states.get(&player)
+    }
+
+    /// Players with an action state.
+    pub fn players(&self) -> impl Iterator<Item = PlayerId> + '_ {
+        self.states.keys().copied()
+    }
+}
+
+/// System to copy each owned gamepad's action state to its player.
+pub fn update_player_action_states<A: Actionlike>(
+    ownership: Res<ControllerOwnership>,
+    gamepads: Query<&GamepadActionState<A>>,
+    mut states: ResMut<PlayerActionStates<A>>,
+) {
+    states
+        .states
+        .retain(|player, _| ownership.get_gamepad(*player).is_some());
+    for (player, gamepad) in &ownership.assignments {
+        if let Ok(gamepad) = gamepads.get(*gamepad) {
+            states
+                .states
+                .entry(*player)
+                .or_default()
+                .clone_from(gamepad.actions());
+        }
+    }
+}
+
 /// Event fired when a controller is assigned to a player.
 #[derive(Debug, Clone, Message)]
 pub struct ControllerAssigned {
@@ -218,21 +326,32 @@ pub(crate) fn register_multiplayer_types(app: &mut App) {
     app.register_type::<PlayerId>()
         .register_type::<Player>()
         .init_resource::<ControllerOwnership>()
+        .init_resource::<PlayerActionMaps>()
+        .init_resource::<PlayerActionStates>()
         .add_message::<ControllerAssigned>()
         .add_message::<ControllerUnassigned>()
         .add_message::<AssignControllerRequest>();
 }
 
 /// Add multiplayer systems to the app.
-pub(crate) fn add_multiplayer_systems(app: &mut App) {
+pub(crate) fn add_multiplayer_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
     app.add_systems(
-        Update,
+        schedules.update,
         (
             handle_assignment_requests,
             auto_assign_controllers,
             handle_controller_disconnections,
         )
-            .chain(),
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::Multiplayer)),
+    );
+    app.add_systems(
+        schedules.input,
+        update_player_action_states::<GameAction>
+            .after(crate::actions::update_action_state::<GameAction>),
     );
 }
 
@@ -373,4 +492,81 @@ mod tests {
         assert_eq!(request.gamepad, gamepad);
         assert_eq!(request.player, player);
     }
+
+    #[test]
+    fn test_player_action_maps_resolve() {
+        let global = ActionMap::default();
+        let mut custom = ActionMap::empty();
+        custom.bind_gamepad(GameAction::Confirm, GamepadButton::North);
+
+        let mut maps = PlayerActionMaps::default();
+        assert!(maps.insert(PlayerId(1), custom).is_none());
+
+        assert!(std::ptr::eq(maps.resolve(None, &global), &global));
+        assert!(std::ptr::eq(
+            maps.resolve(Some(PlayerId(0)), &global),
+            &global
+        ));
+        assert_eq!(
+            maps.resolve(Some(PlayerId(1)), &global)
+                .primary_gamepad_button(GameAction::Confirm),
+            Some(GamepadButton::North)
+        );
+
+        assert!(maps.remove(PlayerId(1)).is_some());
+        assert!(maps.get(PlayerId(1)).is_none());
+    }
+
+    #[test]
+    fn test_players_use_their_own_bindings() {
+        let mut app = App::new();
+        app.init_resource::<ActionMap>()
+            .init_resource::<ControllerOwnership>()
+            .init_resource::<PlayerActionMaps>()
+            .init_resource::<PlayerActionStates>()
+            .add_systems(
+                Update,
+                (
+                    crate::actions::update_gamepad_action_states::<GameAction>,
+                    update_player_action_states::<GameAction>,
+                )
+                    .chain(),
+            );
+
+        let mut pads = [Entity::PLACEHOLDER; 2];
+        for pad in &mut pads {
+            let mut gamepad = Gamepad::default();
+            gamepad.digital_mut().press(GamepadButton::North);
+            *pad = app
+                .world_mut()
+                .spawn((gamepad, GamepadActionState::<GameAction>::default()))
+                .id();
+        }
+        let mut ownership = app.world_mut().resource_mut::<ControllerOwnership>();
+        ownership.assign(pads[0], PlayerId(0));
+        ownership.assign(pads[1], PlayerId(1));
+
+        let mut swapped = ActionMap::empty();
+        swapped.bind_gamepad(GameAction::Confirm, GamepadButton::North);
+        app.world_mut()
+            .resource_mut::<PlayerActionMaps>()
+            .insert(PlayerId(1), swapped);
+        app.update();
+
+        let states = app.world().resource::<PlayerActionStates>();
+        let one = states.get(PlayerId(0)).unwrap();
+        let two = states.get(PlayerId(1)).unwrap();
+        assert!(one.pressed(GameAction::Secondary));
+        assert!(!one.pressed(GameAction::Confirm));
+        assert!(two.just_pressed(GameAction::Confirm));
+        assert!(!two.pressed(GameAction::Secondary));
+        assert!(states.get(PlayerId(2)).is_none());
+
+        app.world_mut()
+            .resource_mut::<ControllerOwnership>()
+            .unassign_player(PlayerId(1));
+        app.update();
+        let states = app.world().resource::<PlayerActionStates>();
+        assert_eq!(states.players().collect::<Vec<_>>(), vec![PlayerId(0)]);
+    }
 }
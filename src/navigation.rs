@@ -0,0 +1,418 @@
+//! Focus-based UI navigation.
+//!
+//! Menus mark their widgets with [`Focusable`]; this module routes the
+//! directional, Confirm, and Cancel actions into [`NavRequest`]s, moves focus
+//! spatially between focusables, and reports what happened as [`NavEvent`]s.
+//! The protocol is modeled after `bevy-ui-navigation`, so any UI library can
+//! participate by reading [`NavEvent`] and, if it doesn't use `bevy_ui`
+//! nodes, setting [`Focusable::position`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::navigation::{Focusable, NavEvent};
+//!
+//! fn spawn_menu(mut commands: Commands) {
+//!     commands.spawn((Button, Node::default(), Focusable::default()));
+//!     commands.spawn((Button, Node::default(), Focusable::default()));
+//! }
+//!
+//! fn handle_nav(mut events: MessageReader<NavEvent>) {
+//!     for event in events.read() {
+//!         if let NavEvent::Activated(entity) = event {
+//!             info!("Activated {entity:?}");
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
+
+use crate::actions::{ActionState, GameAction};
+use crate::repeat::ActionRepeat;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Direction of a navigation move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum NavDirection {
+    /// Up.
+    Up,
+    /// Down.
+    Down,
+    /// Left.
+    Left,
+    /// Right.
+    Right,
+}
+
+impl NavDirection {
+    /// Unit vector in UI space (Y down).
+    #[must_use]
+    pub const fn ui_vector(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::NEG_Y,
+            Self::Down => Vec2::Y,
+            Self::Left => Vec2::NEG_X,
+            Self::Right => Vec2::X,
+        }
+    }
+
+    /// The direction an action navigates in, if any.
+    #[must_use]
+    pub const fn from_action(action: GameAction) -> Option<Self> {
+        match action {
+            GameAction::Up => Some(Self::Up),
+            GameAction::Down => Some(Self::Down),
+            GameAction::Left => Some(Self::Left),
+            GameAction::Right => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Component marking an entity as a navigation target.
+#[derive(Debug, Clone, Default, Component)]
+pub struct Focusable {
+    /// Skip this entity when moving focus.
+    pub blocked: bool,
+    /// Position in UI space (Y down) for entities without a
+    /// [`UiGlobalTransform`].
+    pub position: Option<Vec2>,
+    /// Left/Right adjust this entity (a slider or stepper) instead of
+    /// moving focus.
+    pub adjustable: bool,
+    /// Whether this entity currently has focus.
+    focused: bool,
+}
+
+impl Focusable {
+    /// Create a focusable at an explicit UI-space position.
+    #[must_use]
+    pub fn at(position: Vec2) -> Self {
+        Self {
+            position: Some(position),
+            ..default()
+        }
+    }
+
+    /// Create a focusable that keeps Left/Right for itself.
+    #[must_use]
+    pub fn adjustable() -> Self {
+        Self {
+            adjustable: true,
+            ..default()
+        }
+    }
+
+    /// Whether this entity currently has focus.
+    #[must_use]
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+}
+
+/// Request to the navigation system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub enum NavRequest {
+    /// Move focus in a direction.
+    Move(NavDirection),
+    /// Activate the focused entity.
+    Action,
+    /// Back out of the current menu.
+    Cancel,
+    /// Focus a specific entity.
+    FocusOn(Entity),
+}
+
+/// Result of a navigation request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub enum NavEvent {
+    /// Focus moved to another entity.
+    FocusChanged {
+        /// Previously focused entity.
+        from: Option<Entity>,
+        /// Newly focused entity.
+        to: Entity,
+    },
+    /// The request had nowhere to go.
+    NoChanges {
+        /// Focused entity when the request was made.
+        from: Option<Entity>,
+        /// The request that failed.
+        request: NavRequest,
+    },
+    /// The focused entity was activated.
+    Activated(Entity),
+    /// The player backed out.
+    Cancelled {
+        /// Focused entity when the player backed out.
+        from: Option<Entity>,
+    },
+}
+
+/// Settings for focus navigation.
+#[derive(Debug, Clone, Resource)]
+pub struct NavigationSettings {
+    /// Turn directional, Confirm, and Cancel actions into [`NavRequest`]s
+    /// while any [`Focusable`] exists.
+    pub route_actions: bool,
+}
+
+impl Default for NavigationSettings {
+    fn default() -> Self {
+        Self {
+            route_actions: true,
+        }
+    }
+}
+
+/// Resource holding the focused entity.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct NavFocus {
+    focused: Option<Entity>,
+}
+
+impl NavFocus {
+    /// The focused entity, if any.
+    #[must_use]
+    pub const fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+}
+
+/// Pick the nearest candidate in a direction.
+///
+/// Candidates behind `from` are ignored; off-axis candidates are penalized
+/// so moving right prefers the widget on the same row.
+#[must_use]
+pub fn pick_in_direction(
+    from: Vec2,
+    direction: NavDirection,
+    candidates: impl IntoIterator<Item = (Entity, Vec2)>,
+) -> Option<Entity> {
+    let axis = direction.ui_vector();
+
+    candidates
+        .into_iter()
+        .filter_map(|(entity, position)| {
+            let offset = position - from;
+            let along = offset.dot(axis);
+            if along <= f32::EPSILON {
+                return None;
+            }
+            let across = offset.perp_dot(axis).abs();
+            Some((entity, along + across * 2.0))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| entity)
+}
+
+/// Position of a focusable in UI space.
+fn focus_position(focusable: &Focusable, transform: Option<&UiGlobalTransform>) -> Vec2 {
+    focusable
+        .position
+        .or_else(|| transform.map(|transform| transform.translation))
+        .unwrap_or_default()
+}
+
+/// Pick the top-left candidate as the initial focus.
+fn pick_initial(candidates: impl IntoIterator<Item = (Entity, Vec2)>) -> Option<Entity> {
+    candidates
+        .into_iter()
+        .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+        .map(|(entity, _)| entity)
+}
+
+/// System to turn menu actions into navigation requests.
+pub fn route_navigation_actions(
+    settings: Res<NavigationSettings>,
+    action_state: Res<ActionState>,
+    repeat: Res<ActionRepeat>,
+    focus: Res<NavFocus>,
+    focusables: Query<&Focusable>,
+    mut requests: MessageWriter<NavRequest>,
+) {
+    if !settings.route_actions || focusables.is_empty() {
+        return;
+    }
+    let adjusting = focus
+        .focused()
+        .and_then(|entity| focusables.get(entity).ok())
+        .is_some_and(|focusable| focusable.adjustable);
+
+    for action in [
+        GameAction::Up,
+        GameAction::Down,
+        GameAction::Left,
+        GameAction::Right,
+    ] {
+        if adjusting && matches!(action, GameAction::Left | GameAction::Right) {
+            continue;
+        }
+        if repeat.triggered(&action_state, action)
+            && let Some(direction) = NavDirection::from_action(action)
+        {
+            requests.write(NavRequest::Move(direction));
+        }
+    }
+
+    if action_state.just_pressed(GameAction::Confirm) {
+        requests.write(NavRequest::Action);
+    }
+    if action_state.just_pressed(GameAction::Cancel) {
+        requests.write(NavRequest::Cancel);
+    }
+}
+
+/// System to resolve navigation requests and update focus.
+pub fn resolve_nav_requests(
+    mut requests: MessageReader<NavRequest>,
+    mut focus: ResMut<NavFocus>,
+    mut focusables: Query<(Entity, &mut Focusable, Option<&UiGlobalTransform>)>,
+    mut events: MessageWriter<NavEvent>,
+) {
+    // Drop focus on entities that were despawned or stopped being focusable.
+    if let Some(entity) = focus.focused
+        && !focusables.contains(entity)
+    {
+        focus.focused = None;
+    }
+
+    for request in requests.read() {
+        let from = focus.focused;
+        let candidates = || {
+            focusables
+                .iter()
+                .filter(|(entity, focusable, _)| !focusable.blocked && Some(*entity) != from)
+                .map(|(entity, focusable, transform)| {
+                    (entity, focus_position(focusable, transform))
+                })
+        };
+
+        let target = match *request {
+            NavRequest::Move(direction) => match from {
+                Some(current) => {
+                    let origin = focusables
+                        .get(current)
+                        .map(|(_, focusable, transform)| focus_position(focusable, transform))
+                        .unwrap_or_default();
+                    pick_in_direction(origin, direction, candidates())
+                }
+                None => pick_initial(candidates()),
+            },
+            NavRequest::FocusOn(entity) => focusables
+                .get(entity)
+                .ok()
+                .filter(|(_, focusable, _)| !focusable.blocked)
+                .map(|(entity, _, _)| entity),
+            NavRequest::Action => {
+                match from {
+                    Some(entity) => events.write(NavEvent::Activated(entity)),
+                    None => events.write(NavEvent::NoChanges {
+                        from,
+                        request: *request,
+                    }),
+                };
+                continue;
+            }
+            NavRequest::Cancel => {
+                events.write(NavEvent::Cancelled { from });
+                continue;
+            }
+        };
+
+        let Some(to) = target.filter(|to| Some(*to) != from) else {
+            events.write(NavEvent::NoChanges {
+                from,
+                request: *request,
+            });
+            continue;
+        };
+
+        if let Some(previous) = from
+            && let Ok((_, mut focusable, _)) = focusables.get_mut(previous)
+        {
+            focusable.focused = false;
+        }
+        if let Ok((_, mut focusable, _)) = focusables.get_mut(to) {
+            focusable.focused = true;
+        }
+        focus.focused = Some(to);
+        events.write(NavEvent::FocusChanged { from, to });
+    }
+}
+
+/// Plugin for registering navigation types.
+pub(crate) fn register_navigation_types(app: &mut App) {
+    app.register_type::<NavDirection>()
+        .init_resource::<NavigationSettings>()
+        .init_resource::<NavFocus>()
+        .add_message::<NavRequest>()
+        .add_message::<NavEvent>();
+}
+
+/// Add navigation systems to the app.
+pub(crate) fn add_navigation_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        (route_navigation_actions, resolve_nav_requests)
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::Navigation)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(world: &mut World) -> [(Entity, Vec2); 4] {
+        [
+            (world.spawn_empty().id(), Vec2::new(0.0, 0.0)),
+            (world.spawn_empty().id(), Vec2::new(100.0, 0.0)),
+            (world.spawn_empty().id(), Vec2::new(0.0, 100.0)),
+            (world.spawn_empty().id(), Vec2::new(100.0, 100.0)),
+        ]
+    }
+
+    #[test]
+    fn test_pick_in_direction() {
+        let mut world = World::new();
+        let [top_left, top_right, bottom_left, bottom_right] = grid(&mut world);
+        let cells = [top_left, top_right, bottom_left, bottom_right];
+
+        assert_eq!(
+            pick_in_direction(top_left.1, NavDirection::Right, cells),
+            Some(top_right.0)
+        );
+        assert_eq!(
+            pick_in_direction(top_left.1, NavDirection::Down, cells),
+            Some(bottom_left.0)
+        );
+        assert_eq!(pick_in_direction(top_left.1, NavDirection::Up, cells), None);
+        assert_eq!(
+            pick_in_direction(bottom_right.1, NavDirection::Up, cells),
+            Some(top_right.0)
+        );
+    }
+
+    #[test]
+    fn test_pick_initial_is_top_left() {
+        let mut world = World::new();
+        let cells = grid(&mut world);
+        assert_eq!(pick_initial(cells.into_iter().rev()), Some(cells[0].0));
+    }
+
+    #[test]
+    fn test_nav_direction_from_action() {
+        assert_eq!(
+            NavDirection::from_action(GameAction::Up),
+            Some(NavDirection::Up)
+        );
+        assert_eq!(NavDirection::from_action(GameAction::Confirm), None);
+    }
+}
@@ -0,0 +1,495 @@
+//! Per-player settings persistence.
+//!
+//! Each local player can have a named profile holding their own
+//! [`ControllerConfig`] overrides, [`ActionMap`], vibration preferences, and
+//! accessibility options.
+//! Profiles are saved as one JSON file per name, and
+//! [`PlayerSettingsStore::switch_profile`] swaps the profile attached to a
+//! [`PlayerId`] at runtime.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::multiplayer::PlayerId;
+//! use bevy_archie::player_settings::SwitchPlayerProfile;
+//!
+//! fn sign_in(mut switch: MessageWriter<SwitchPlayerProfile>) {
+//!     switch.write(SwitchPlayerProfile {
+//!         player: PlayerId::new(1),
+//!         profile: "alex".to_string(),
+//!     });
+//! }
+//! ```
+
+use bevy::prelude::*;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::actions::ActionMap;
+use crate::config::ControllerConfig;
+use crate::multiplayer::PlayerId;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Accessibility options stored with a player profile.
+///
+/// These are saved and restored with the profile. Apart from
+/// `rumble_scale`, the game decides how to apply them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilityOptions {
+    /// Treat hold inputs as toggles.
+    pub toggle_holds: bool,
+    /// Let a held button count as mashing.
+    pub auto_mash: bool,
+    /// Multiplier for quick-time event windows.
+    pub qte_time_scale: f32,
+    /// Scale for rumble strength on top of the controller config.
+    pub rumble_scale: f32,
+}
+
+impl Default for AccessibilityOptions {
+    fn default() -> Self {
+        Self {
+            toggle_holds: false,
+            auto_mash: false,
+            qte_time_scale: 1.0,
+            rumble_scale: 1.0,
+        }
+    }
+}
+
+/// Vibration preferences stored with a player profile.
+///
+/// These apply to the gamepads the player owns, on top of the controller
+/// config, so in couch co-op one player can turn rumble off while another
+/// keeps it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VibrationPreferences {
+    /// Enable rumble on this player's gamepads.
+    pub enabled: bool,
+    /// Rumble strength multiplier (0.0 - 1.0).
+    pub intensity: f32,
+}
+
+impl Default for VibrationPreferences {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            intensity: 1.0,
+        }
+    }
+}
+
+/// Settings saved for one player profile.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerSettings {
+    /// Profile name.
+    pub profile: String,
+    /// Controller config override; `None` uses the global config.
+    #[serde(default)]
+    pub config: Option<ControllerConfig>,
+    /// Action bindings override; `None` uses the global map.
+    #[serde(default)]
+    pub action_map: Option<ActionMap>,
+    /// Vibration preferences.
+    #[serde(default)]
+    pub vibration: VibrationPreferences,
+    /// Accessibility options.
+    #[serde(default)]
+    pub accessibility: AccessibilityOptions,
+}
+
+impl PlayerSettings {
+    /// Create empty settings for a profile.
+    #[must_use]
+    pub fn new(profile: impl Into<String>) -> Self {
+        Self {
+            profile: profile.into(),
+            ..default()
+        }
+    }
+
+    /// The controller config to use, falling back to the global one.
+    #[must_use]
+    pub fn effective_config<'a>(&'a self, global: &'a ControllerConfig) -> &'a ControllerConfig {
+        self.config.as_ref().unwrap_or(global)
+    }
+
+    /// The action map to use, falling back to the global one.
+    #[must_use]
+    pub fn effective_action_map<'a>(&'a self, global: &'a ActionMap) -> &'a ActionMap {
+        self.action_map.as_ref().unwrap_or(global)
+    }
+
+    /// Rumble strength for this player's gamepads.
+    ///
+    /// Combines the effective controller config, the player's vibration
+    /// preferences, and the accessibility rumble scale.
+    #[must_use]
+    pub fn vibration_scale(&self, global: &ControllerConfig) -> f32 {
+        if !self.vibration.enabled {
+            return 0.0;
+        }
+        self.effective_config(global).effective_vibration()
+            * self.vibration.intensity.clamp(0.0, 1.0)
+            * self.accessibility.rumble_scale.max(0.0)
+    }
+
+    /// Path of a profile's settings file inside a directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty or can't be used as a file name.
+    pub fn path_in(directory: &Path, profile: &str) -> crate::Result<PathBuf> {
+        let valid = !profile.trim().is_empty()
+            && !profile.starts_with('.')
+            && profile
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+        if !valid {
+            return Err(crate::Error::InvalidProfileName {
+                name: profile.to_string(),
+            });
+        }
+        Ok(directory.join(format!("{profile}.json")))
+    }
+
+    /// Load a profile's settings from a directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, the file cannot be read, or
+    /// it contains invalid JSON.
+    pub fn load(directory: &Path, profile: &str) -> crate::Result<Self> {
+        let path = Self::path_in(directory, profile)?;
+        let json = std::fs::read_to_string(&path).map_err(|e| crate::Error::io(&path, e))?;
+        let mut settings: Self =
+            serde_json::from_str(&json).map_err(crate::Error::PlayerSettings)?;
+        settings.profile = profile.to_string();
+        Ok(settings)
+    }
+
+    /// Save these settings into a directory, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid, serialization fails, or the
+    /// file cannot be written.
+    pub fn save(&self, directory: &Path) -> crate::Result<()> {
+        let path = Self::path_in(directory, &self.profile)?;
+        let json = serde_json::to_string_pretty(self).map_err(crate::Error::PlayerSettings)?;
+        std::fs::create_dir_all(directory).map_err(|e| crate::Error::io(directory, e))?;
+        std::fs::write(&path, json).map_err(|e| crate::Error::io(&path, e))
+    }
+}
+
+/// Resource holding the settings profile attached to each player.
+#[derive(Debug, Clone, Resource)]
+pub struct PlayerSettingsStore {
+    /// Directory profiles are saved in.
+    pub directory: PathBuf,
+    /// Settings attached to each player.
+    players: HashMap<PlayerId, PlayerSettings>,
+}
+
+impl Default for PlayerSettingsStore {
+    fn default() -> Self {
+        Self::new(Self::default_directory())
+    }
+}
+
+impl PlayerSettingsStore {
+    /// Create a store saving profiles in `directory`.
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            players: HashMap::new(),
+        }
+    }
+
+    /// Get the default profile directory for the current platform.
+    #[must_use]
+    pub fn default_directory() -> PathBuf {
+        if let Some(config_dir) = dirs::config_dir() {
+            config_dir.join("bevy_archie").join("players")
+        } else {
+            PathBuf::from("players")
+        }
+    }
+
+    /// Settings attached to a player.
+    #[must_use]
+    pub fn settings(&self, player: PlayerId) -> Option<&PlayerSettings> {
+        self.players.get(&player)
+    }
+
+    /// Mutable settings attached to a player.
+    pub fn settings_mut(&mut self, player: PlayerId) -> Option<&mut PlayerSettings> {
+        self.players.get_mut(&player)
+    }
+
+    /// Attach a profile to a player, saving the profile they had before.
+    ///
+    /// A profile that has never been saved starts with default settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previous profile cannot be saved or the new
+    /// one exists but cannot be loaded. The player keeps their previous
+    /// profile on error.
+    pub fn switch_profile(&mut self, player: PlayerId, profile: &str) -> crate::Result<()> {
+        // Save first, so switching to the same profile reloads the saved changes
+        if let Some(previous) = self.players.get(&player) {
+            previous.save(&self.directory)?;
+        }
+
+        let settings = match PlayerSettings::load(&self.directory, profile) {
+            Ok(settings) => settings,
+            Err(crate::Error::Io { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                PlayerSettings::new(profile)
+            }
+            Err(e) => return Err(e),
+        };
+        self.players.insert(player, settings);
+        Ok(())
+    }
+
+    /// Detach a player's profile, saving it first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile cannot be saved.
+    pub fn sign_out(&mut self, player: PlayerId) -> crate::Result<Option<PlayerSettings>> {
+        if let Some(settings) = self.players.get(&player) {
+            settings.save(&self.directory)?;
+        }
+        Ok(self.players.remove(&player))
+    }
+
+    /// Save every attached profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered.
+    pub fn save_all(&self) -> crate::Result<()> {
+        self.players
+            .values()
+            .try_for_each(|settings| settings.save(&self.directory))
+    }
+
+    /// Names of the profiles saved in the directory, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory exists but cannot be read.
+    pub fn saved_profiles(&self) -> crate::Result<Vec<String>> {
+        let read_dir = match std::fs::read_dir(&self.directory) {
+            Ok(read_dir) => read_dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(crate::Error::io(&self.directory, e)),
+        };
+
+        let mut profiles: Vec<String> = read_dir
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+            .collect();
+        profiles.sort();
+        Ok(profiles)
+    }
+}
+
+/// Request to attach a profile to a player.
+#[derive(Debug, Clone, Message)]
+pub struct SwitchPlayerProfile {
+    /// The player.
+    pub player: PlayerId,
+    /// Profile name to attach.
+    pub profile: String,
+}
+
+/// Event fired after a player's profile changes.
+#[derive(Debug, Clone, Message)]
+pub struct PlayerProfileChanged {
+    /// The player.
+    pub player: PlayerId,
+    /// The newly attached profile.
+    pub profile: String,
+}
+
+/// System to handle profile switch requests.
+pub fn handle_profile_switches(
+    mut requests: MessageReader<SwitchPlayerProfile>,
+    mut store: ResMut<PlayerSettingsStore>,
+    mut changed: MessageWriter<PlayerProfileChanged>,
+) {
+    for request in requests.read() {
+        match store.switch_profile(request.player, &request.profile) {
+            Ok(()) => {
+                changed.write(PlayerProfileChanged {
+                    player: request.player,
+                    profile: request.profile.clone(),
+                });
+            }
+            Err(e) => warn!(
+                "Could not switch player {} to profile {:?}: {e}",
+                request.player.id(),
+                request.profile
+            ),
+        }
+    }
+}
+
+/// Plugin for registering player settings types.
+pub(crate) fn register_player_settings_types(app: &mut App) {
+    app.init_resource::<PlayerSettingsStore>()
+        .add_message::<SwitchPlayerProfile>()
+        .add_message::<PlayerProfileChanged>();
+}
+
+/// Add player settings systems to the app.
+pub(crate) fn add_player_settings_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        handle_profile_switches.run_if(subsystem_enabled(Subsystem::PlayerSettings)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> PlayerSettingsStore {
+        let dir =
+            std::env::temp_dir().join(format!("bevy_archie_players_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        PlayerSettingsStore::new(dir)
+    }
+
+    #[test]
+    fn test_profile_name_validation() {
+        let dir = Path::new("players");
+        assert_eq!(
+            PlayerSettings::path_in(dir, "Player One").unwrap(),
+            dir.join("Player One.json")
+        );
+        assert!(PlayerSettings::path_in(dir, "").is_err());
+        assert!(PlayerSettings::path_in(dir, "../etc").is_err());
+        assert!(PlayerSettings::path_in(dir, ".hidden").is_err());
+    }
+
+    #[test]
+    fn test_effective_config_falls_back() {
+        let global = ControllerConfig::default();
+        let mut settings = PlayerSettings::new("alex");
+        assert_eq!(settings.effective_config(&global).deadzone, global.deadzone);
+
+        settings.config = Some(ControllerConfig {
+            deadzone: 0.3,
+            ..default()
+        });
+        assert_eq!(settings.effective_config(&global).deadzone, 0.3);
+    }
+
+    #[test]
+    fn test_vibration_scale_per_player() {
+        let mut global = ControllerConfig {
+            vibration_intensity: 0.5,
+            ..default()
+        };
+        let mut settings = PlayerSettings::new("alex");
+        assert_eq!(settings.vibration_scale(&global), 0.5);
+
+        settings.vibration.intensity = 0.5;
+        settings.accessibility.rumble_scale = 2.0;
+        assert_eq!(settings.vibration_scale(&global), 0.5);
+
+        settings.vibration.enabled = false;
+        assert_eq!(settings.vibration_scale(&global), 0.0);
+
+        settings.vibration.enabled = true;
+        global.vibration_enabled = false;
+        assert_eq!(settings.vibration_scale(&global), 0.0);
+    }
+
+    #[test]
+    fn test_switch_profile_saves_previous() {
+        let mut store = temp_store("switch");
+        let player = PlayerId::new(0);
+
+        store.switch_profile(player, "alex").unwrap();
+        store
+            .settings_mut(player)
+            .unwrap()
+            .accessibility
+            .toggle_holds = true;
+
+        store.switch_profile(player, "sam").unwrap();
+        assert_eq!(store.settings(player).unwrap().profile, "sam");
+        assert!(!store.settings(player).unwrap().accessibility.toggle_holds);
+        assert_eq!(store.saved_profiles().unwrap(), vec!["alex".to_string()]);
+
+        store.switch_profile(player, "alex").unwrap();
+        assert!(store.settings(player).unwrap().accessibility.toggle_holds);
+
+        std::fs::remove_dir_all(&store.directory).unwrap();
+    }
+
+    #[test]
+    fn test_switch_to_same_profile_keeps_changes() {
+        let mut store = temp_store("same");
+        let player = PlayerId::new(0);
+
+        store.switch_profile(player, "alex").unwrap();
+        store.settings_mut(player).unwrap().vibration.intensity = 0.25;
+
+        store.switch_profile(player, "alex").unwrap();
+        assert_eq!(store.settings(player).unwrap().vibration.intensity, 0.25);
+
+        std::fs::remove_dir_all(&store.directory).unwrap();
+    }
+
+    #[test]
+    fn test_switch_profile_rejects_invalid_name() {
+        let mut store = temp_store("invalid");
+        let player = PlayerId::new(0);
+        store.switch_profile(player, "alex").unwrap();
+
+        assert!(store.switch_profile(player, "a/b").is_err());
+        assert_eq!(store.settings(player).unwrap().profile, "alex");
+    }
+
+    #[test]
+    fn test_settings_round_trip_with_action_map() {
+        let store = temp_store("round_trip");
+        let mut settings = PlayerSettings::new("pat");
+        let mut map = ActionMap::default();
+        map.clear_gamepad_bindings(crate::actions::GameAction::Primary);
+        settings.action_map = Some(map);
+        settings.save(&store.directory).unwrap();
+
+        let loaded = PlayerSettings::load(&store.directory, "pat").unwrap();
+        let map = loaded.action_map.unwrap();
+        assert!(
+            !map.gamepad_bindings
+                .contains_key(&crate::actions::GameAction::Primary)
+        );
+        assert!(
+            map.gamepad_bindings
+                .contains_key(&crate::actions::GameAction::Confirm)
+        );
+
+        std::fs::remove_dir_all(&store.directory).unwrap();
+    }
+}
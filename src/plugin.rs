@@ -3,6 +3,7 @@
 //! This module provides the main plugin that ties together all
 //! controller support functionality.
 
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 
 /// The main controller support plugin.
@@ -22,6 +23,8 @@ use bevy::prelude::*;
 pub struct ControllerPlugin {
     /// Base path for controller icon assets.
     pub icon_base_path: Option<String>,
+    /// Schedules the plugin's system groups run in.
+    pub schedules: ControllerSchedules,
 }
 
 impl ControllerPlugin {
@@ -29,18 +32,92 @@ impl ControllerPlugin {
     pub fn with_icon_path(icon_path: impl Into<String>) -> Self {
         Self {
             icon_base_path: Some(icon_path.into()),
+            ..default()
+        }
+    }
+
+    /// Run the plugin's system groups in custom schedules.
+    #[must_use]
+    pub fn with_schedules(mut self, schedules: ControllerSchedules) -> Self {
+        self.schedules = schedules;
+        self
+    }
+}
+
+/// Schedules the controller plugin's system groups run in.
+///
+/// Games that process input in a fixed timestep or before a networking tick
+/// can move the input group (e.g. to `FixedPreUpdate` or a custom
+/// [`ScheduleLabel`]). Systems that read [`ActionState`](crate::actions::ActionState)
+/// should run in a schedule after the input group.
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_archie::prelude::*;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(ControllerPlugin::default().with_schedules(
+///         ControllerSchedules::default()
+///             .with_input(FixedPreUpdate)
+///             .with_update(FixedUpdate),
+///     ))
+///     .run();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControllerSchedules {
+    /// Device detection, action state updates, and the systems that adjust
+    /// action state (default: `PreUpdate`).
+    pub input: InternedScheduleLabel,
+    /// Feature systems that read action state (default: `Update`).
+    pub update: InternedScheduleLabel,
+    /// End-of-frame bookkeeping such as diagnostics (default: `PostUpdate`).
+    pub post_update: InternedScheduleLabel,
+}
+
+impl Default for ControllerSchedules {
+    fn default() -> Self {
+        Self {
+            input: PreUpdate.intern(),
+            update: Update.intern(),
+            post_update: PostUpdate.intern(),
         }
     }
 }
 
+impl ControllerSchedules {
+    /// Set the schedule for the input group.
+    #[must_use]
+    pub fn with_input(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.input = schedule.intern();
+        self
+    }
+
+    /// Set the schedule for the update group.
+    #[must_use]
+    pub fn with_update(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.update = schedule.intern();
+        self
+    }
+
+    /// Set the schedule for the post-update group.
+    #[must_use]
+    pub fn with_post_update(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.post_update = schedule.intern();
+        self
+    }
+}
+
 impl Plugin for ControllerPlugin {
     fn build(&self, app: &mut App) {
         // Register core types
+        crate::subsystems::register_subsystem_types(app);
         crate::config::register_config_types(app);
         crate::detection::register_detection_types(app);
         crate::actions::register_action_types(app);
         crate::icons::register_icon_types(app);
         crate::virtual_cursor::register_virtual_cursor_types(app);
+        crate::scroll::register_scroll_types(app);
 
         // Register new feature types
         crate::haptics::register_haptics_types(app);
@@ -49,8 +126,45 @@ impl Plugin for ControllerPlugin {
         crate::gyro::register_gyro_types(app);
         crate::touchpad::register_touchpad_types(app);
         crate::action_modifiers::register_action_modifier_types(app);
+        crate::arbitration::register_arbitration_types(app);
         crate::profiles::register_profile_types(app);
         crate::debug::register_debug_types(app);
+        crate::safety::register_safety_types(app);
+        crate::ui_sound::register_ui_sound_types(app);
+        crate::diagnostics::register_diagnostics_types(app);
+        crate::vehicle_haptics::register_vehicle_haptics_types(app);
+        crate::vehicle_input::register_vehicle_input_types(app);
+        crate::qte::register_qte_types(app);
+        crate::stick::register_stick_types(app);
+        crate::repeat::register_repeat_types(app);
+        crate::navigation::register_navigation_types(app);
+        crate::widgets::register_widget_types(app);
+        crate::player_settings::register_player_settings_types(app);
+        crate::window_focus::register_window_focus_types(app);
+        crate::simulated_input::register_simulated_input_types(app);
+        crate::chords::register_chord_types(app);
+        crate::virtual_input::register_virtual_input_types(app);
+        crate::attract_mode::register_attract_mode_types(app);
+        crate::charge::register_charge_types(app);
+        crate::hints::register_hint_types(app);
+        crate::deadzone_advisor::register_deadzone_advisor_types(app);
+        crate::characterization::register_characterization_types(app);
+        crate::power::register_power_types(app);
+        crate::suspend::register_suspend_types(app);
+        crate::action_usage::register_action_usage_types(app);
+        crate::led::register_led_types(app);
+        crate::trace::register_trace_types(app);
+        crate::battery::register_battery_types(app);
+        crate::capture::register_capture_types(app);
+
+        #[cfg(feature = "ffi")]
+        crate::ffi::register_ffi_types(app);
+
+        #[cfg(feature = "gilrs")]
+        crate::device_info::register_device_info_types(app);
+
+        #[cfg(feature = "steam-input")]
+        crate::steam_input::register_steam_input_types(app);
 
         // Set up icon path if provided
         if let Some(path) = &self.icon_base_path {
@@ -58,27 +172,75 @@ impl Plugin for ControllerPlugin {
         }
 
         // Add core systems
-        crate::detection::add_detection_systems(app);
-        crate::actions::add_action_systems(app);
-        crate::icons::add_icon_systems(app);
-        crate::virtual_cursor::add_virtual_cursor_systems(app);
+        let schedules = &self.schedules;
+        crate::subsystems::add_subsystem_systems(app);
+        crate::detection::add_detection_systems(app, schedules);
+        crate::actions::add_action_systems(app, schedules);
+        crate::icons::add_icon_systems(app, schedules);
+        crate::virtual_cursor::add_virtual_cursor_systems(app, schedules);
+        crate::scroll::add_scroll_systems(app, schedules);
 
         // Add new feature systems
-        crate::haptics::add_haptics_systems(app);
-        crate::input_buffer::add_input_buffer_systems(app);
-        crate::multiplayer::add_multiplayer_systems(app);
-        crate::gyro::add_gyro_systems(app);
-        crate::touchpad::add_touchpad_systems(app);
-        crate::action_modifiers::add_action_modifier_systems(app);
-        crate::profiles::add_profile_systems(app);
-        crate::debug::add_debug_systems(app);
+        crate::haptics::add_haptics_systems(app, schedules);
+        crate::audio_haptics::add_audio_haptics_systems(app, schedules);
+        crate::input_buffer::add_input_buffer_systems(app, schedules);
+        crate::multiplayer::add_multiplayer_systems(app, schedules);
+        crate::gyro::add_gyro_systems(app, schedules);
+        crate::touchpad::add_touchpad_systems(app, schedules);
+        crate::action_modifiers::add_action_modifier_systems(app, schedules);
+        crate::arbitration::add_arbitration_systems(app, schedules);
+        crate::profiles::add_profile_systems(app, schedules);
+        crate::debug::add_debug_systems(app, schedules);
+        crate::safety::add_safety_systems(app, schedules);
+        crate::ui_sound::add_ui_sound_systems(app, schedules);
+        crate::diagnostics::add_diagnostics_systems(app, schedules);
+        crate::vehicle_haptics::add_vehicle_haptics_systems(app, schedules);
+        crate::vehicle_input::add_vehicle_input_systems(app, schedules);
+        crate::qte::add_qte_systems(app, schedules);
+        crate::mash::add_mash_systems(app, schedules);
+        crate::charge::add_charge_systems(app, schedules);
+        crate::stick::add_stick_systems(app, schedules);
+        crate::repeat::add_repeat_systems(app, schedules);
+        crate::navigation::add_navigation_systems(app, schedules);
+        crate::widgets::add_widget_systems(app, schedules);
+        crate::player_settings::add_player_settings_systems(app, schedules);
+        crate::window_focus::add_window_focus_systems(app, schedules);
+        crate::simulated_input::add_simulated_input_systems(app, schedules);
+        crate::chords::add_chord_systems(app, schedules);
+        crate::virtual_input::add_virtual_input_systems(app, schedules);
+        crate::attract_mode::add_attract_mode_systems(app, schedules);
+        crate::hints::add_hint_systems(app, schedules);
+        crate::deadzone_advisor::add_deadzone_advisor_systems(app, schedules);
+        crate::characterization::add_characterization_systems(app, schedules);
+        crate::power::add_power_systems(app, schedules);
+        crate::suspend::add_suspend_systems(app, schedules);
+        crate::action_usage::add_action_usage_systems(app, schedules);
+        crate::led::add_led_systems(app, schedules);
+        crate::trace::add_trace_systems(app, schedules);
+        crate::battery::add_battery_systems(app, schedules);
+        crate::capture::add_capture_systems(app, schedules);
 
         // Add feature-gated systems
         #[cfg(feature = "remapping")]
-        crate::remapping::add_remapping_systems(app);
+        crate::remapping::add_remapping_systems(app, schedules);
 
         #[cfg(feature = "virtual_keyboard")]
-        crate::virtual_keyboard::add_virtual_keyboard_systems(app);
+        crate::virtual_keyboard::add_virtual_keyboard_systems(app, schedules);
+
+        #[cfg(feature = "virtual_keyboard")]
+        crate::file_picker::add_file_picker_systems(app, schedules);
+
+        #[cfg(feature = "virtual_keyboard")]
+        crate::chord_typing::add_chord_typing_systems(app, schedules);
+
+        #[cfg(feature = "ffi")]
+        crate::ffi::add_ffi_systems(app, schedules);
+
+        #[cfg(feature = "gilrs")]
+        crate::device_info::add_device_info_systems(app, schedules);
+
+        #[cfg(feature = "steam-input")]
+        crate::steam_input::add_steam_input_systems(app, schedules);
     }
 }
 
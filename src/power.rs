@@ -0,0 +1,131 @@
+//! Power-aware input processing for handhelds.
+//!
+//! When the game reports a battery-saver state, set
+//! [`InputPowerProfile::low_power`]. While it is set, motion and touchpad
+//! backends are polled at most [`InputPowerProfile::low_power_poll_rate`]
+//! times per second, gyro processing stops, and debug overlays are
+//! suspended. Clearing it restores normal processing.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::power::InputPowerProfile;
+//!
+//! fn follow_battery_saver(mut profile: ResMut<InputPowerProfile>) {
+//!     let battery_saver = true; // From the platform's power API
+//!     if profile.low_power != battery_saver {
+//!         profile.low_power = battery_saver;
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::gyro::GyroAim;
+
+/// Resource selecting normal or low-power input processing.
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct InputPowerProfile {
+    /// Whether the game is in a battery-saver state.
+    pub low_power: bool,
+    /// Maximum backend polls per second while in low-power mode.
+    pub low_power_poll_rate: f32,
+}
+
+impl Default for InputPowerProfile {
+    fn default() -> Self {
+        Self {
+            low_power: false,
+            low_power_poll_rate: 30.0,
+        }
+    }
+}
+
+impl InputPowerProfile {
+    /// Backend poll rate to use given the configured rate.
+    ///
+    /// In low-power mode the rate is capped at
+    /// [`Self::low_power_poll_rate`]; otherwise `rate` is returned as is.
+    #[must_use]
+    pub fn poll_rate(&self, rate: Option<f32>) -> Option<f32> {
+        if !self.low_power {
+            return rate;
+        }
+        Some(rate.map_or(self.low_power_poll_rate, |rate| {
+            rate.min(self.low_power_poll_rate)
+        }))
+    }
+}
+
+/// Run condition that passes unless low-power mode is on.
+pub fn normal_power(profile: Res<InputPowerProfile>) -> bool {
+    !profile.low_power
+}
+
+/// System to stop gyro aim when entering low-power mode.
+///
+/// Gyro systems stop running in low-power mode, so the last aim delta would
+/// otherwise keep being applied every frame.
+pub fn clear_gyro_on_low_power(profile: Res<InputPowerProfile>, mut aims: Query<&mut GyroAim>) {
+    if !profile.is_changed() || !profile.low_power {
+        return;
+    }
+    for mut aim in &mut aims {
+        aim.delta = Vec2::ZERO;
+    }
+}
+
+/// Plugin for registering power profile types.
+pub(crate) fn register_power_types(app: &mut App) {
+    app.init_resource::<InputPowerProfile>()
+        .register_type::<InputPowerProfile>();
+}
+
+/// Add power profile systems to the app.
+pub(crate) fn add_power_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(schedules.update, clear_gyro_on_low_power);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_rate_capped_in_low_power() {
+        let mut profile = InputPowerProfile::default();
+        assert_eq!(profile.poll_rate(None), None);
+        assert_eq!(profile.poll_rate(Some(120.0)), Some(120.0));
+
+        profile.low_power = true;
+        assert_eq!(profile.poll_rate(None), Some(30.0));
+        assert_eq!(profile.poll_rate(Some(120.0)), Some(30.0));
+        assert_eq!(profile.poll_rate(Some(10.0)), Some(10.0));
+    }
+
+    #[test]
+    fn test_low_power_clears_gyro_aim() {
+        let mut app = App::new();
+        app.init_resource::<InputPowerProfile>()
+            .add_systems(Update, clear_gyro_on_low_power);
+        let mut aim = GyroAim::default();
+        aim.delta = Vec2::ONE;
+        let gamepad = app.world_mut().spawn(aim).id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<GyroAim>(gamepad).unwrap().delta,
+            Vec2::ONE
+        );
+
+        app.world_mut()
+            .resource_mut::<InputPowerProfile>()
+            .low_power = true;
+        app.update();
+        assert_eq!(
+            app.world().get::<GyroAim>(gamepad).unwrap().delta,
+            Vec2::ZERO
+        );
+    }
+}
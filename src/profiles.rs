@@ -4,10 +4,13 @@
 //! automatically loaded based on detected hardware.
 
 use bevy::prelude::*;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 use crate::actions::ActionMap;
 use crate::config::ControllerLayout;
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 /// Controller connection type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
@@ -21,7 +24,7 @@ pub enum ConnectionType {
 }
 
 /// Controller-specific quirks or special handling requirements.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum ControllerQuirk {
     /// `DualShock` 4 over Bluetooth uses different HID report format than USB.
     DS4BluetoothReportDiffers,
@@ -34,7 +37,7 @@ pub enum ControllerQuirk {
 }
 
 /// Controller model/type identification.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum ControllerModel {
     /// Xbox 360 controller.
     Xbox360,
@@ -73,6 +76,35 @@ pub enum ControllerModel {
 }
 
 impl ControllerModel {
+    /// Guess the model from a controller's reported name.
+    ///
+    /// Used when the vendor and product IDs are unknown.
+    #[must_use]
+    pub fn from_name(name: &str) -> Self {
+        let name_lower = name.to_lowercase();
+        if name_lower.contains("xbox") {
+            if name_lower.contains("360") {
+                Self::Xbox360
+            } else if name_lower.contains("series") {
+                Self::XboxSeriesXS
+            } else {
+                Self::XboxOne
+            }
+        } else if name_lower.contains("playstation") || name_lower.contains("dualshock") {
+            Self::PS4
+        } else if name_lower.contains("dualsense") {
+            Self::PS5
+        } else if name_lower.contains("switch") {
+            if name_lower.contains("pro") {
+                Self::SwitchPro
+            } else {
+                Self::SwitchJoyCon
+            }
+        } else {
+            Self::Generic
+        }
+    }
+
     /// Get the default layout for this controller model.
     #[must_use]
     pub const fn default_layout(self) -> ControllerLayout {
@@ -150,6 +182,28 @@ impl DetectedController {
         }
     }
 
+    /// Identify a controller from whatever the platform reports.
+    ///
+    /// Uses the VID/PID database when both IDs are known, and the name when
+    /// they are missing or not in the database. Unknown IDs are stored as 0.
+    #[must_use]
+    pub fn detect(vendor_id: Option<u16>, product_id: Option<u16>, name: Option<&str>) -> Self {
+        let mut detected = match (vendor_id, product_id) {
+            (Some(vendor_id), Some(product_id)) => Self::new(vendor_id, product_id),
+            _ => Self {
+                model: ControllerModel::Generic,
+                vendor_id: vendor_id.unwrap_or(0),
+                product_id: product_id.unwrap_or(0),
+            },
+        };
+        if detected.model == ControllerModel::Generic
+            && let Some(name) = name
+        {
+            detected.model = ControllerModel::from_name(name);
+        }
+        detected
+    }
+
     /// Identify controller model from vendor/product IDs.
     ///
     /// VID/PID database compiled from:
@@ -216,6 +270,15 @@ impl DetectedController {
     /// Get quirks for this controller.
     #[must_use]
     pub fn quirks(self) -> Vec<ControllerQuirk> {
+        self.quirks_on(self.connection_type_hint())
+    }
+
+    /// Get quirks for this controller over a known connection.
+    ///
+    /// Some controllers use the same PID over USB and Bluetooth, so backends
+    /// that know the bus should use this instead of [`Self::quirks`].
+    #[must_use]
+    pub fn quirks_on(self, connection: ConnectionType) -> Vec<ControllerQuirk> {
         let mut quirks = Vec::new();
 
         match self.model {
@@ -225,7 +288,7 @@ impl DetectedController {
             ControllerModel::PS3 => {
                 quirks.push(ControllerQuirk::BigEndianValues);
             }
-            ControllerModel::SwitchPro if self.connection_type_hint() == ConnectionType::Usb => {
+            ControllerModel::SwitchPro if connection == ConnectionType::Usb => {
                 quirks.push(ControllerQuirk::SwitchProUSBHandshake);
             }
             ControllerModel::EightBitDoM30 | ControllerModel::EightBitDoSN30Pro => {
@@ -239,15 +302,19 @@ impl DetectedController {
 }
 
 /// A controller profile with custom settings.
-#[derive(Debug, Clone, Resource)]
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct ControllerProfile {
     /// Profile name.
     pub name: String,
     /// Target controller model.
     pub model: ControllerModel,
     /// Custom action map for this profile.
+    ///
+    /// Not persisted by [`ControllerProfile::to_json`].
+    #[serde(skip)]
     pub action_map: Option<ActionMap>,
     /// Layout override.
+    #[serde(default)]
     pub layout: Option<ControllerLayout>,
 }
 
@@ -276,6 +343,235 @@ impl ControllerProfile {
         self.layout = Some(layout);
         self
     }
+
+    /// Parse a profile from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is invalid or names an unknown model.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        serde_json::from_str(json).map_err(crate::Error::Profile)
+    }
+
+    /// Serialize the profile to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> crate::Result<String> {
+        serde_json::to_string_pretty(self).map_err(crate::Error::Profile)
+    }
+
+    /// Load a profile from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or contains an invalid profile.
+    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        Self::from_json(&json)
+    }
+
+    /// Save the profile to a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save_to_file(&self, path: impl AsRef<std::path::Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_json()?).map_err(|e| crate::Error::io(path, e))
+    }
+}
+
+/// A non-standard button described by a profile contribution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtraButton {
+    /// Display name, e.g. `"Back Paddle L"`.
+    pub name: String,
+    /// Raw HID button index.
+    pub index: u16,
+}
+
+/// Touchpad dimensions described by a profile contribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TouchpadSpec {
+    /// Horizontal resolution in device units.
+    pub width: u16,
+    /// Vertical resolution in device units.
+    pub height: u16,
+    /// Maximum simultaneous touches.
+    pub max_touches: u8,
+}
+
+/// A community-contributed controller description.
+///
+/// Contributions are RON files, one per controller:
+///
+/// ```ron
+/// (
+///     name: "Acme Pro Pad",
+///     vendor_id: 0x1234,
+///     product_id: 0x5678,
+///     layout: Xbox,
+///     extra_buttons: [(name: "Back Paddle L", index: 16)],
+///     quirks: [EightBitDoXInputMode],
+///     touchpad: Some((width: 1920, height: 1080, max_touches: 2)),
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileContribution {
+    /// Controller name.
+    pub name: String,
+    /// USB vendor ID.
+    pub vendor_id: u16,
+    /// USB product ID.
+    pub product_id: u16,
+    /// Controller model, if it matches a known one.
+    #[serde(default = "generic_model")]
+    pub model: ControllerModel,
+    /// Face button layout.
+    pub layout: ControllerLayout,
+    /// Buttons beyond the standard gamepad set.
+    #[serde(default)]
+    pub extra_buttons: Vec<ExtraButton>,
+    /// Special handling the controller needs.
+    #[serde(default)]
+    pub quirks: Vec<ControllerQuirk>,
+    /// Touchpad dimensions, if the controller has one.
+    #[serde(default)]
+    pub touchpad: Option<TouchpadSpec>,
+}
+
+const fn generic_model() -> ControllerModel {
+    ControllerModel::Generic
+}
+
+/// A problem that makes a contribution invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContributionIssue {
+    /// The file could not be parsed.
+    Parse(String),
+    /// The name is empty.
+    EmptyName,
+    /// The vendor or product ID is zero.
+    MissingDeviceId,
+    /// Two extra buttons share a name or HID index.
+    DuplicateExtraButton(String),
+    /// The touchpad has a zero dimension or no touches.
+    InvalidTouchpad,
+}
+
+impl std::fmt::Display for ContributionIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(reason) => write!(f, "parse error: {reason}"),
+            Self::EmptyName => write!(f, "name is empty"),
+            Self::MissingDeviceId => write!(f, "vendor and product IDs must be non-zero"),
+            Self::DuplicateExtraButton(button) => write!(f, "duplicate extra button {button:?}"),
+            Self::InvalidTouchpad => write!(f, "touchpad dimensions must be non-zero"),
+        }
+    }
+}
+
+impl ProfileContribution {
+    /// Parse a contribution from RON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the RON is invalid.
+    pub fn from_ron(source: &str) -> crate::Result<Self> {
+        ron::from_str(source).map_err(crate::Error::Contribution)
+    }
+
+    /// Check the contribution for problems.
+    ///
+    /// Returns an empty list if the contribution is valid.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ContributionIssue> {
+        let mut issues = Vec::new();
+        if self.name.trim().is_empty() {
+            issues.push(ContributionIssue::EmptyName);
+        }
+        if self.vendor_id == 0 || self.product_id == 0 {
+            issues.push(ContributionIssue::MissingDeviceId);
+        }
+
+        let mut names = HashSet::new();
+        let mut indices = HashSet::new();
+        for button in &self.extra_buttons {
+            if !names.insert(button.name.as_str()) || !indices.insert(button.index) {
+                issues.push(ContributionIssue::DuplicateExtraButton(button.name.clone()));
+            }
+        }
+
+        if self
+            .touchpad
+            .is_some_and(|pad| pad.width == 0 || pad.height == 0 || pad.max_touches == 0)
+        {
+            issues.push(ContributionIssue::InvalidTouchpad);
+        }
+        issues
+    }
+
+    /// Build a controller profile from the contribution.
+    #[must_use]
+    pub fn to_profile(&self) -> ControllerProfile {
+        ControllerProfile::new(self.name.clone(), self.model).with_layout(self.layout)
+    }
+}
+
+/// A contribution that was not merged because of another source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileConflict {
+    /// Another contribution already describes this device; the first is kept.
+    DuplicateDevice {
+        /// USB vendor ID.
+        vendor_id: u16,
+        /// USB product ID.
+        product_id: u16,
+        /// Name of the kept contribution.
+        kept: String,
+        /// Name of the ignored contribution.
+        ignored: String,
+    },
+    /// The contribution names a different model than the built-in database.
+    ModelMismatch {
+        /// Name of the ignored contribution.
+        name: String,
+        /// Model the contribution declares.
+        declared: ControllerModel,
+        /// Model the built-in database identifies.
+        known: ControllerModel,
+    },
+}
+
+/// A contribution rejected by validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidContribution {
+    /// File path or contribution name.
+    pub source: String,
+    /// Problems found.
+    pub issues: Vec<ContributionIssue>,
+}
+
+/// Summary of merging contributions into a [`ProfileRegistry`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContributionReport {
+    /// Names of the merged contributions.
+    pub loaded: Vec<String>,
+    /// Contributions rejected by validation.
+    pub invalid: Vec<InvalidContribution>,
+    /// Contributions skipped because of a conflict.
+    pub conflicts: Vec<ProfileConflict>,
+}
+
+impl ContributionReport {
+    /// Whether every contribution was merged.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.invalid.is_empty() && self.conflicts.is_empty()
+    }
 }
 
 /// Registry of controller profiles.
@@ -283,6 +579,8 @@ impl ControllerProfile {
 pub struct ProfileRegistry {
     /// Profiles mapped by controller model.
     pub profiles: HashMap<ControllerModel, ControllerProfile>,
+    /// Merged contributions mapped by vendor and product ID.
+    pub contributions: HashMap<(u16, u16), ProfileContribution>,
     /// Whether to auto-load profiles.
     pub auto_load: bool,
 }
@@ -298,6 +596,104 @@ impl ProfileRegistry {
     pub fn get(&self, model: ControllerModel) -> Option<&ControllerProfile> {
         self.profiles.get(&model)
     }
+
+    /// Get the merged contribution for a device.
+    #[must_use]
+    pub fn contribution(&self, vendor_id: u16, product_id: u16) -> Option<&ProfileContribution> {
+        self.contributions.get(&(vendor_id, product_id))
+    }
+
+    /// Validate and merge contributions.
+    ///
+    /// Invalid contributions and contributions conflicting with an already
+    /// merged one or with the built-in VID/PID database are skipped and
+    /// listed in the report.
+    pub fn merge_contributions(
+        &mut self,
+        contributions: impl IntoIterator<Item = ProfileContribution>,
+    ) -> ContributionReport {
+        let mut report = ContributionReport::default();
+        for contribution in contributions {
+            let source = contribution.name.clone();
+            self.merge_one(source, contribution, &mut report);
+        }
+        report
+    }
+
+    /// Validate and merge every `.ron` contribution in a directory.
+    ///
+    /// Files are merged in path order, so the first of two conflicting files
+    /// wins consistently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or one of its files cannot be read.
+    /// Unparseable files are reported, not returned as errors.
+    pub fn load_contributions(
+        &mut self,
+        dir: impl AsRef<Path>,
+    ) -> crate::Result<ContributionReport> {
+        let dir = dir.as_ref();
+        let mut paths = std::fs::read_dir(dir)
+            .map_err(|e| crate::Error::io(dir, e))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<PathBuf>>>()
+            .map_err(|e| crate::Error::io(dir, e))?;
+        paths.retain(|path| path.extension().is_some_and(|ext| ext == "ron"));
+        paths.sort();
+
+        let mut report = ContributionReport::default();
+        for path in paths {
+            let source = std::fs::read_to_string(&path).map_err(|e| crate::Error::io(&path, e))?;
+            match ProfileContribution::from_ron(&source) {
+                Ok(contribution) => {
+                    self.merge_one(path.display().to_string(), contribution, &mut report);
+                }
+                Err(err) => report.invalid.push(InvalidContribution {
+                    source: path.display().to_string(),
+                    issues: vec![ContributionIssue::Parse(err.to_string())],
+                }),
+            }
+        }
+        Ok(report)
+    }
+
+    fn merge_one(
+        &mut self,
+        source: String,
+        contribution: ProfileContribution,
+        report: &mut ContributionReport,
+    ) {
+        let issues = contribution.validate();
+        if !issues.is_empty() {
+            report.invalid.push(InvalidContribution { source, issues });
+            return;
+        }
+
+        let key = (contribution.vendor_id, contribution.product_id);
+        if let Some(existing) = self.contributions.get(&key) {
+            report.conflicts.push(ProfileConflict::DuplicateDevice {
+                vendor_id: key.0,
+                product_id: key.1,
+                kept: existing.name.clone(),
+                ignored: contribution.name,
+            });
+            return;
+        }
+
+        let known = DetectedController::identify(key.0, key.1);
+        if known != ControllerModel::Generic && known != contribution.model {
+            report.conflicts.push(ProfileConflict::ModelMismatch {
+                name: contribution.name,
+                declared: contribution.model,
+                known,
+            });
+            return;
+        }
+
+        report.loaded.push(contribution.name.clone());
+        self.contributions.insert(key, contribution);
+    }
 }
 
 /// Event fired when a controller model is detected.
@@ -315,42 +711,15 @@ pub fn detect_controller_models(
     mut commands: Commands,
     mut detected_events: MessageWriter<ControllerDetected>,
 ) {
-    for (entity, _gamepad, name) in &mut gamepads {
-        // Note: Bevy 0.17 doesn't expose vendor/product IDs directly
-        // This would need platform-specific implementation or gilrs integration
-        // For now, try to detect from name
-        let model = if let Some(name) = name {
-            let name_lower = name.to_string().to_lowercase();
-            if name_lower.contains("xbox") {
-                if name_lower.contains("360") {
-                    ControllerModel::Xbox360
-                } else if name_lower.contains("series") {
-                    ControllerModel::XboxSeriesXS
-                } else {
-                    ControllerModel::XboxOne
-                }
-            } else if name_lower.contains("playstation") || name_lower.contains("dualshock") {
-                ControllerModel::PS4
-            } else if name_lower.contains("dualsense") {
-                ControllerModel::PS5
-            } else if name_lower.contains("switch") {
-                if name_lower.contains("pro") {
-                    ControllerModel::SwitchPro
-                } else {
-                    ControllerModel::SwitchJoyCon
-                }
-            } else {
-                ControllerModel::Generic
-            }
-        } else {
-            ControllerModel::Generic
-        };
-
-        let detected = DetectedController {
-            model,
-            vendor_id: 0,
-            product_id: 0,
-        };
+    for (entity, gamepad, name) in &mut gamepads {
+        // Bevy reports IDs from gilrs where the platform provides them; the
+        // `gilrs` feature fills them in later for gamepads it doesn't
+        let detected = DetectedController::detect(
+            gamepad.vendor_id(),
+            gamepad.product_id(),
+            name.map(Name::as_str),
+        );
+        let model = detected.model;
 
         commands.entity(entity).insert(detected);
         detected_events.write(ControllerDetected {
@@ -390,10 +759,12 @@ pub(crate) fn register_profile_types(app: &mut App) {
 }
 
 /// Add profile systems to the app.
-pub(crate) fn add_profile_systems(app: &mut App) {
+pub(crate) fn add_profile_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
     app.add_systems(
-        Update,
-        (detect_controller_models, auto_load_profiles).chain(),
+        schedules.update,
+        (detect_controller_models, auto_load_profiles)
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::Profiles)),
     );
 }
 
@@ -462,6 +833,23 @@ mod tests {
         assert!(!ControllerModel::SwitchPro.supports_adaptive_triggers());
     }
 
+    #[test]
+    fn test_detect_prefers_ids_over_name() {
+        let detected = DetectedController::detect(Some(0x054c), Some(0x0ce6), Some("Xbox"));
+        assert_eq!(detected.model, ControllerModel::PS5);
+
+        let detected = DetectedController::detect(Some(0x1234), Some(0x5678), Some("Xbox 360"));
+        assert_eq!(detected.model, ControllerModel::Xbox360);
+        assert_eq!(detected.vendor_id, 0x1234);
+
+        let detected = DetectedController::detect(None, None, Some("Pro Controller Switch"));
+        assert_eq!(detected.model, ControllerModel::SwitchPro);
+        assert_eq!(detected.product_id, 0);
+
+        let detected = DetectedController::detect(None, None, None);
+        assert_eq!(detected.model, ControllerModel::Generic);
+    }
+
     #[test]
     fn test_detected_controller_creation() {
         let detected = DetectedController {
@@ -490,6 +878,23 @@ mod tests {
         assert_eq!(profile.layout, Some(ControllerLayout::Xbox));
     }
 
+    #[test]
+    fn test_controller_profile_json_roundtrip() {
+        let profile = ControllerProfile::new("Arcade", ControllerModel::PS5)
+            .with_layout(ControllerLayout::Xbox);
+        let json = profile.to_json().unwrap();
+        let loaded = ControllerProfile::from_json(&json).unwrap();
+        assert_eq!(loaded.name, "Arcade");
+        assert_eq!(loaded.model, ControllerModel::PS5);
+        assert_eq!(loaded.layout, Some(ControllerLayout::Xbox));
+    }
+
+    #[test]
+    fn test_controller_profile_invalid_json() {
+        let err = ControllerProfile::from_json(r#"{"name": "x", "model": "NotAPad"}"#);
+        assert!(matches!(err, Err(crate::Error::Profile(_))));
+    }
+
     #[test]
     fn test_profile_registry_default() {
         let registry = ProfileRegistry::default();
@@ -752,6 +1157,22 @@ mod tests {
         assert!(quirks.contains(&ControllerQuirk::BigEndianValues));
     }
 
+    #[test]
+    fn test_quirks_switch_pro_usb() {
+        let detected = DetectedController::new(0x057e, 0x2009);
+        assert!(
+            !detected
+                .quirks()
+                .contains(&ControllerQuirk::SwitchProUSBHandshake)
+        );
+        assert!(
+            detected
+                .quirks_on(ConnectionType::Usb)
+                .contains(&ControllerQuirk::SwitchProUSBHandshake)
+        );
+        assert!(detected.quirks_on(ConnectionType::Bluetooth).is_empty());
+    }
+
     #[test]
     fn test_quirks_8bitdo() {
         let detected = DetectedController::new(0x2dc8, 0x5006);
@@ -923,4 +1344,121 @@ mod tests {
         registry.auto_load = true;
         assert!(registry.auto_load);
     }
+
+    const CONTRIBUTION: &str = r#"(
+        name: "Acme Pro Pad",
+        vendor_id: 0x1234,
+        product_id: 0x5678,
+        layout: Xbox,
+        extra_buttons: [(name: "Back Paddle L", index: 16)],
+        quirks: [EightBitDoXInputMode],
+        touchpad: Some((width: 1920, height: 1080, max_touches: 2)),
+    )"#;
+
+    #[test]
+    fn test_profile_contribution_from_ron() {
+        let contribution = ProfileContribution::from_ron(CONTRIBUTION).unwrap();
+        assert_eq!(contribution.name, "Acme Pro Pad");
+        assert_eq!(contribution.model, ControllerModel::Generic);
+        assert_eq!(contribution.extra_buttons[0].index, 16);
+        assert_eq!(
+            contribution.quirks,
+            vec![ControllerQuirk::EightBitDoXInputMode]
+        );
+        assert!(contribution.validate().is_empty());
+
+        let err = ProfileContribution::from_ron("(name: \"x\")");
+        assert!(matches!(err, Err(crate::Error::Contribution(_))));
+    }
+
+    #[test]
+    fn test_profile_contribution_validation() {
+        let mut contribution = ProfileContribution::from_ron(CONTRIBUTION).unwrap();
+        contribution.name = String::new();
+        contribution.vendor_id = 0;
+        contribution.extra_buttons.push(ExtraButton {
+            name: "Back Paddle R".to_string(),
+            index: 16,
+        });
+        contribution.touchpad = Some(TouchpadSpec {
+            width: 0,
+            height: 1080,
+            max_touches: 2,
+        });
+
+        assert_eq!(
+            contribution.validate(),
+            vec![
+                ContributionIssue::EmptyName,
+                ContributionIssue::MissingDeviceId,
+                ContributionIssue::DuplicateExtraButton("Back Paddle R".to_string()),
+                ContributionIssue::InvalidTouchpad,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_contributions_reports_conflicts() {
+        let acme = ProfileContribution::from_ron(CONTRIBUTION).unwrap();
+        let mut clone = acme.clone();
+        clone.name = "Acme Clone".to_string();
+        let mut mislabeled = acme.clone();
+        mislabeled.name = "Not A DualSense".to_string();
+        mislabeled.vendor_id = 0x054c;
+        mislabeled.product_id = 0x0ce6;
+        let mut broken = acme.clone();
+        broken.name = String::new();
+
+        let mut registry = ProfileRegistry::default();
+        let report = registry.merge_contributions([acme, clone, mislabeled, broken]);
+
+        assert_eq!(report.loaded, vec!["Acme Pro Pad".to_string()]);
+        assert_eq!(report.invalid.len(), 1);
+        assert_eq!(
+            report.conflicts,
+            vec![
+                ProfileConflict::DuplicateDevice {
+                    vendor_id: 0x1234,
+                    product_id: 0x5678,
+                    kept: "Acme Pro Pad".to_string(),
+                    ignored: "Acme Clone".to_string(),
+                },
+                ProfileConflict::ModelMismatch {
+                    name: "Not A DualSense".to_string(),
+                    declared: ControllerModel::Generic,
+                    known: ControllerModel::PS5,
+                },
+            ]
+        );
+        assert!(!report.is_clean());
+        assert_eq!(
+            registry
+                .contribution(0x1234, 0x5678)
+                .unwrap()
+                .to_profile()
+                .layout,
+            Some(ControllerLayout::Xbox)
+        );
+    }
+
+    #[test]
+    fn test_load_contributions_from_dir() {
+        let dir = std::env::temp_dir().join(format!("archie_contrib_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("acme.ron"), CONTRIBUTION).unwrap();
+        std::fs::write(dir.join("broken.ron"), "(name: ").unwrap();
+        std::fs::write(dir.join("README.md"), "ignored").unwrap();
+
+        let mut registry = ProfileRegistry::default();
+        let report = registry.load_contributions(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.loaded, vec!["Acme Pro Pad".to_string()]);
+        assert_eq!(report.invalid.len(), 1);
+        assert!(matches!(
+            report.invalid[0].issues[..],
+            [ContributionIssue::Parse(_)]
+        ));
+        assert!(registry.load_contributions(&dir).is_err());
+    }
 }
@@ -0,0 +1,449 @@
+//! Quick-time events.
+//!
+//! This module provides reusable QTE prompts (single press, mash, hold,
+//! alternating presses, stick rotation) with a time window. Progress is
+//! tracked from the [`ActionState`], an attached [`ControllerIconDisplay`]
+//! is kept pointing at the button the player needs, and a [`QteCompleted`]
+//! event reports success or failure with the final fill percentage.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::icons::ControllerIconDisplay;
+//! use bevy_archie::qte::{QteCompleted, QteOutcome, QtePrompt, QuickTimeEvent};
+//!
+//! fn start_qte(mut commands: Commands) {
+//!     commands.spawn((
+//!         QuickTimeEvent::new(QtePrompt::Mash {
+//!             action: GameAction::Confirm,
+//!             presses: 10,
+//!         }, 3.0),
+//!         ControllerIconDisplay::default(),
+//!     ));
+//! }
+//!
+//! fn on_qte(mut events: MessageReader<QteCompleted>) {
+//!     for event in events.read() {
+//!         if event.outcome == QteOutcome::Success {
+//!             info!("QTE passed");
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+use crate::actions::{ActionMap, ActionState, GameAction};
+use crate::icons::{ButtonIcon, ControllerIconDisplay};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// What the player has to do to pass a QTE.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QtePrompt {
+    /// Press an action once.
+    Press {
+        /// The action to press.
+        action: GameAction,
+    },
+    /// Press an action repeatedly.
+    Mash {
+        /// The action to mash.
+        action: GameAction,
+        /// Presses required.
+        presses: u32,
+    },
+    /// Hold an action; releasing early resets progress.
+    Hold {
+        /// The action to hold.
+        action: GameAction,
+        /// Seconds the action must be held.
+        duration: f32,
+    },
+    /// Press two actions in turn, starting with `first`.
+    Alternate {
+        /// The action pressed first.
+        first: GameAction,
+        /// The action pressed second.
+        second: GameAction,
+        /// Total presses required across both actions.
+        presses: u32,
+    },
+    /// Rotate the movement stick (Up/Down/Left/Right actions) in either direction.
+    StickRotate {
+        /// Full rotations required.
+        rotations: f32,
+    },
+}
+
+/// Result of a finished QTE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QteOutcome {
+    /// The prompt was completed inside the window.
+    Success,
+    /// The window ran out first.
+    Failure,
+}
+
+/// Component running a quick-time event.
+#[derive(Debug, Clone, Component)]
+pub struct QuickTimeEvent {
+    /// The prompt.
+    pub prompt: QtePrompt,
+    /// Time allowed in seconds.
+    pub window: f32,
+    /// Seconds since the QTE started.
+    pub elapsed: f32,
+    /// Presses counted so far (mash/alternate).
+    presses: u32,
+    /// Seconds held so far (hold).
+    held: f32,
+    /// Radians rotated so far (stick rotate).
+    rotation: f32,
+    /// Stick angle at the last update.
+    last_angle: Option<f32>,
+    /// Set once the QTE finishes.
+    outcome: Option<QteOutcome>,
+}
+
+impl QuickTimeEvent {
+    /// Stick deflection required before rotation is tracked.
+    const STICK_THRESHOLD: f32 = 0.5;
+
+    /// Create a new QTE with a time window in seconds.
+    #[must_use]
+    pub const fn new(prompt: QtePrompt, window: f32) -> Self {
+        Self {
+            prompt,
+            window,
+            elapsed: 0.0,
+            presses: 0,
+            held: 0.0,
+            rotation: 0.0,
+            last_angle: None,
+            outcome: None,
+        }
+    }
+
+    /// Progress towards completion (0.0-1.0).
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "press counts are far below f32 precision limits"
+    )]
+    pub fn fill(&self) -> f32 {
+        let fill = match self.prompt {
+            QtePrompt::Press { .. } => {
+                if self.outcome == Some(QteOutcome::Success) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            QtePrompt::Mash { presses, .. } | QtePrompt::Alternate { presses, .. } => {
+                self.presses as f32 / presses.max(1) as f32
+            }
+            QtePrompt::Hold { duration, .. } => self.held / duration.max(f32::EPSILON),
+            QtePrompt::StickRotate { rotations } => {
+                self.rotation.abs() / (rotations * TAU).max(f32::EPSILON)
+            }
+        };
+        fill.clamp(0.0, 1.0)
+    }
+
+    /// Seconds left in the window.
+    #[must_use]
+    pub fn remaining(&self) -> f32 {
+        (self.window - self.elapsed).max(0.0)
+    }
+
+    /// The outcome, once the QTE has finished.
+    #[must_use]
+    pub const fn outcome(&self) -> Option<QteOutcome> {
+        self.outcome
+    }
+
+    /// The action the player should press next, if the prompt uses one.
+    #[must_use]
+    pub const fn expected_action(&self) -> Option<GameAction> {
+        match self.prompt {
+            QtePrompt::Press { action }
+            | QtePrompt::Mash { action, .. }
+            | QtePrompt::Hold { action, .. } => Some(action),
+            QtePrompt::Alternate { first, second, .. } => {
+                if self.presses.is_multiple_of(2) {
+                    Some(first)
+                } else {
+                    Some(second)
+                }
+            }
+            QtePrompt::StickRotate { .. } => None,
+        }
+    }
+
+    /// Advance the QTE by `delta` seconds.
+    ///
+    /// Returns the outcome on the frame the QTE finishes.
+    pub fn tick(&mut self, delta: f32, action_state: &ActionState) -> Option<QteOutcome> {
+        if self.outcome.is_some() {
+            return None;
+        }
+
+        self.elapsed += delta;
+
+        match self.prompt {
+            QtePrompt::Press { action } => {
+                if action_state.just_pressed(action) {
+                    self.outcome = Some(QteOutcome::Success);
+                }
+            }
+            QtePrompt::Mash { action, .. } => {
+                if action_state.just_pressed(action) {
+                    self.presses += 1;
+                }
+            }
+            QtePrompt::Hold { action, .. } => {
+                if action_state.pressed(action) {
+                    self.held += delta;
+                } else {
+                    self.held = 0.0;
+                }
+            }
+            QtePrompt::Alternate { .. } => {
+                if let Some(expected) = self.expected_action()
+                    && action_state.just_pressed(expected)
+                {
+                    self.presses += 1;
+                }
+            }
+            QtePrompt::StickRotate { .. } => {
+                let stick = Vec2::new(
+                    action_state.value(GameAction::Right) - action_state.value(GameAction::Left),
+                    action_state.value(GameAction::Up) - action_state.value(GameAction::Down),
+                );
+
+                if stick.length() >= Self::STICK_THRESHOLD {
+                    let angle = stick.y.atan2(stick.x);
+                    if let Some(last) = self.last_angle {
+                        // Wrap the step into (-PI, PI] so crossing the
+                        // -X axis doesn't count as a full turn.
+                        let step = (angle - last + PI).rem_euclid(TAU) - PI;
+                        self.rotation += step;
+                    }
+                    self.last_angle = Some(angle);
+                } else {
+                    self.last_angle = None;
+                }
+            }
+        }
+
+        if self.outcome.is_none() && self.fill() >= 1.0 {
+            self.outcome = Some(QteOutcome::Success);
+        } else if self.outcome.is_none() && self.elapsed >= self.window {
+            self.outcome = Some(QteOutcome::Failure);
+        }
+
+        self.outcome
+    }
+}
+
+/// Event fired when a QTE finishes.
+#[derive(Debug, Clone, Message)]
+pub struct QteCompleted {
+    /// The QTE entity.
+    pub entity: Entity,
+    /// Whether the player succeeded.
+    pub outcome: QteOutcome,
+    /// Final progress (0.0-1.0).
+    pub fill: f32,
+}
+
+/// Icon to show for a QTE prompt given the current bindings.
+fn prompt_icon(qte: &QuickTimeEvent, action_map: &ActionMap) -> Option<ButtonIcon> {
+    match qte.expected_action() {
+        Some(action) => action_map
+            .primary_gamepad_button(action)
+            .and_then(ButtonIcon::from_button_type),
+        None => Some(ButtonIcon::LeftStick),
+    }
+}
+
+/// System to advance QTEs, update their icons, and report results.
+pub fn update_quick_time_events(
+    time: Res<Time>,
+    action_state: Res<ActionState>,
+    action_map: Res<ActionMap>,
+    mut qtes: Query<(
+        Entity,
+        &mut QuickTimeEvent,
+        Option<&mut ControllerIconDisplay>,
+    )>,
+    mut completed: MessageWriter<QteCompleted>,
+) {
+    for (entity, mut qte, display) in &mut qtes {
+        if let Some(outcome) = qte.tick(time.delta_secs(), &action_state) {
+            completed.write(QteCompleted {
+                entity,
+                outcome,
+                fill: qte.fill(),
+            });
+        }
+
+        if let Some(mut display) = display
+            && let Some(icon) = prompt_icon(&qte, &action_map)
+            && display.icon != icon
+        {
+            display.icon = icon;
+        }
+    }
+}
+
+/// Plugin for registering QTE types.
+pub(crate) fn register_qte_types(app: &mut App) {
+    app.add_message::<QteCompleted>();
+}
+
+/// Add QTE systems to the app.
+pub(crate) fn add_qte_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        update_quick_time_events.run_if(subsystem_enabled(Subsystem::Qte)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(state: &mut ActionState, action: GameAction) {
+        state.set_pressed(action, false);
+        state.reset_frame_state();
+        state.set_pressed(action, true);
+    }
+
+    #[test]
+    fn test_press_succeeds() {
+        let mut qte = QuickTimeEvent::new(
+            QtePrompt::Press {
+                action: GameAction::Confirm,
+            },
+            1.0,
+        );
+        let mut state = ActionState::default();
+
+        assert_eq!(qte.tick(0.1, &state), None);
+        press(&mut state, GameAction::Confirm);
+        assert_eq!(qte.tick(0.1, &state), Some(QteOutcome::Success));
+        assert_eq!(qte.fill(), 1.0);
+        assert_eq!(qte.tick(0.1, &state), None);
+    }
+
+    #[test]
+    fn test_mash_fails_when_window_expires() {
+        let mut qte = QuickTimeEvent::new(
+            QtePrompt::Mash {
+                action: GameAction::Confirm,
+                presses: 4,
+            },
+            1.0,
+        );
+        let mut state = ActionState::default();
+
+        press(&mut state, GameAction::Confirm);
+        qte.tick(0.4, &state);
+        press(&mut state, GameAction::Confirm);
+        qte.tick(0.4, &state);
+
+        state.reset_frame_state();
+        assert_eq!(qte.tick(0.4, &state), Some(QteOutcome::Failure));
+        assert_eq!(qte.fill(), 0.5);
+    }
+
+    #[test]
+    fn test_hold_resets_on_release() {
+        let mut qte = QuickTimeEvent::new(
+            QtePrompt::Hold {
+                action: GameAction::Primary,
+                duration: 1.0,
+            },
+            5.0,
+        );
+        let mut state = ActionState::default();
+
+        state.set_pressed(GameAction::Primary, true);
+        qte.tick(0.6, &state);
+        assert!(qte.fill() > 0.5);
+
+        state.set_pressed(GameAction::Primary, false);
+        qte.tick(0.1, &state);
+        assert_eq!(qte.fill(), 0.0);
+
+        state.set_pressed(GameAction::Primary, true);
+        qte.tick(0.6, &state);
+        assert_eq!(qte.tick(0.6, &state), Some(QteOutcome::Success));
+    }
+
+    #[test]
+    fn test_alternate_requires_order() {
+        let mut qte = QuickTimeEvent::new(
+            QtePrompt::Alternate {
+                first: GameAction::LeftShoulder,
+                second: GameAction::RightShoulder,
+                presses: 2,
+            },
+            5.0,
+        );
+        let mut state = ActionState::default();
+
+        press(&mut state, GameAction::RightShoulder);
+        qte.tick(0.1, &state);
+        assert_eq!(qte.fill(), 0.0);
+        assert_eq!(qte.expected_action(), Some(GameAction::LeftShoulder));
+
+        press(&mut state, GameAction::LeftShoulder);
+        qte.tick(0.1, &state);
+        assert_eq!(qte.expected_action(), Some(GameAction::RightShoulder));
+
+        press(&mut state, GameAction::RightShoulder);
+        assert_eq!(qte.tick(0.1, &state), Some(QteOutcome::Success));
+    }
+
+    #[test]
+    fn test_stick_rotate_tracks_full_turn() {
+        let mut qte = QuickTimeEvent::new(QtePrompt::StickRotate { rotations: 1.0 }, 5.0);
+        let mut state = ActionState::default();
+
+        let directions = [
+            (GameAction::Right, GameAction::Up),
+            (GameAction::Up, GameAction::Left),
+            (GameAction::Left, GameAction::Down),
+            (GameAction::Down, GameAction::Right),
+        ];
+
+        let mut outcome = None;
+        for _ in 0..2 {
+            for (from, to) in directions {
+                for step in 0u8..=4 {
+                    let t = f32::from(step) / 4.0;
+                    for action in [
+                        GameAction::Up,
+                        GameAction::Down,
+                        GameAction::Left,
+                        GameAction::Right,
+                    ] {
+                        state.set_value(action, 0.0);
+                    }
+                    state.set_value(from, 1.0 - t);
+                    state.set_value(to, t);
+                    if let Some(result) = qte.tick(0.01, &state) {
+                        outcome = Some(result);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(outcome, Some(QteOutcome::Success));
+    }
+}
@@ -5,7 +5,9 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::actions::{ActionMap, GameAction};
+use crate::actions::{ActionMap, GameAction, InputCapture};
+use crate::config::ControllerConfig;
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 /// The current state of the remapping system.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, States, Hash)]
@@ -26,6 +28,11 @@ pub struct RemappingContext {
     pub timeout: f32,
     /// Maximum time to wait for input.
     pub max_timeout: f32,
+    /// Whether gameplay actions are captured while listening.
+    pub exclusive: bool,
+    /// Remapping is handled outside the game, e.g. by the Steam overlay;
+    /// [`StartRemapEvent`]s are ignored.
+    pub external: bool,
 }
 
 impl RemappingContext {
@@ -66,6 +73,9 @@ pub struct StartRemapEvent {
     pub action: GameAction,
     /// Timeout in seconds (default: 5.0).
     pub timeout: f32,
+    /// Capture gameplay actions while listening so the pressed button
+    /// doesn't also fire its current action (default: true).
+    pub exclusive: bool,
 }
 
 impl StartRemapEvent {
@@ -75,13 +85,25 @@ impl StartRemapEvent {
         Self {
             action,
             timeout: 5.0,
+            exclusive: true,
         }
     }
 
     /// Create a new remap event with custom timeout.
     #[must_use]
     pub fn with_timeout(action: GameAction, timeout: f32) -> Self {
-        Self { action, timeout }
+        Self {
+            action,
+            timeout,
+            exclusive: true,
+        }
+    }
+
+    /// Set whether gameplay actions are captured while listening.
+    #[must_use]
+    pub const fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
     }
 }
 
@@ -126,13 +148,30 @@ pub struct SavedBindings {
 
 impl SavedBindings {
     /// Apply saved bindings to an action map.
-    pub fn apply_to(&self, action_map: &mut ActionMap) {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the action map unchanged, if the saved
+    /// bindings change a non-remappable action or leave a required action
+    /// without a binding.
+    pub fn apply_to(&self, action_map: &mut ActionMap) -> crate::Result<()> {
+        for (action, buttons) in &self.gamepad {
+            if !action.is_remappable() && action_map.gamepad_bindings.get(action) != Some(buttons) {
+                return Err(crate::Error::NotRemappable { action: *action });
+            }
+            if action.is_required() && buttons.is_empty() {
+                return Err(crate::Error::RequiredBinding { action: *action });
+            }
+        }
+
         for (action, buttons) in &self.gamepad {
             action_map.clear_gamepad_bindings(*action);
             for button in buttons {
                 action_map.bind_gamepad(*action, *button);
             }
         }
+
+        Ok(())
     }
 
     /// Save current bindings from an action map.
@@ -155,22 +194,43 @@ pub fn handle_start_remap(
     mut next_state: ResMut<NextState<RemappingState>>,
 ) {
     for event in events.read() {
+        if context.external {
+            continue;
+        }
         context.start(event.action, event.timeout);
+        context.exclusive = event.exclusive;
         next_state.set(RemappingState::WaitingForInput);
     }
 }
 
+/// System to start exclusive input capture when remap listening begins.
+pub fn begin_remap_capture(context: Res<RemappingContext>, mut capture: ResMut<InputCapture>) {
+    if context.exclusive {
+        capture.begin();
+    }
+}
+
+/// System to restore normal action processing when remap listening ends.
+pub fn end_remap_capture(mut capture: ResMut<InputCapture>) {
+    capture.end();
+}
+
 /// System to handle input during remapping.
 ///
 /// # Panics
 ///
 /// Panics if the remapping context has no action set when active.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
 pub fn handle_remap_input(
     mut context: ResMut<RemappingContext>,
     mut action_map: ResMut<ActionMap>,
     mut remap_events: MessageWriter<RemapEvent>,
     mut next_state: ResMut<NextState<RemappingState>>,
     time: Res<Time>,
+    config: Res<ControllerConfig>,
     gamepads: Query<&Gamepad>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
@@ -179,8 +239,9 @@ pub fn handle_remap_input(
     }
 
     let action = context.action.unwrap();
+    let cancel_button = config.cancel_button();
 
-    // Check for cancel (Escape key or B button)
+    // Check for cancel (Escape key or the cancel face button)
     if keyboard.just_pressed(KeyCode::Escape) {
         remap_events.write(RemapEvent::Cancelled { action });
         context.cancel();
@@ -189,7 +250,7 @@ pub fn handle_remap_input(
     }
 
     for gamepad in gamepads.iter() {
-        if gamepad.just_pressed(GamepadButton::East) {
+        if gamepad.just_pressed(cancel_button) {
             remap_events.write(RemapEvent::Cancelled { action });
             context.cancel();
             next_state.set(RemappingState::Inactive);
@@ -201,6 +262,7 @@ pub fn handle_remap_input(
     for gamepad in gamepads.iter() {
         let buttons_to_check = [
             GamepadButton::South,
+            GamepadButton::East,
             GamepadButton::North,
             GamepadButton::West,
             GamepadButton::LeftTrigger,
@@ -217,7 +279,7 @@ pub fn handle_remap_input(
         ];
 
         for button in buttons_to_check {
-            if gamepad.just_pressed(button) {
+            if button != cancel_button && gamepad.just_pressed(button) {
                 // Check for conflicts
                 let mut conflict = None;
                 for other_action in GameAction::all() {
@@ -265,17 +327,75 @@ pub fn reset_bindings_to_default(mut action_map: ResMut<ActionMap>) {
 }
 
 /// Add remapping systems to the app.
-pub(crate) fn add_remapping_systems(app: &mut App) {
+pub(crate) fn add_remapping_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
     app.init_state::<RemappingState>()
         .init_resource::<RemappingContext>()
         .init_resource::<SavedBindings>()
         .add_message::<StartRemapEvent>()
         .add_message::<RemapEvent>()
         .add_systems(
-            Update,
+            schedules.update,
             (handle_start_remap, handle_remap_input)
                 .chain()
-                .run_if(in_state(RemappingState::WaitingForInput)),
+                .run_if(in_state(RemappingState::WaitingForInput))
+                .run_if(subsystem_enabled(Subsystem::Remapping)),
+        )
+        .add_systems(
+            schedules.update,
+            handle_start_remap.run_if(subsystem_enabled(Subsystem::Remapping)),
         )
-        .add_systems(Update, handle_start_remap);
+        .add_systems(
+            OnEnter(RemappingState::WaitingForInput),
+            begin_remap_capture,
+        )
+        .add_systems(OnExit(RemappingState::WaitingForInput), end_remap_capture);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saved_bindings_roundtrip() {
+        let mut action_map = ActionMap::default();
+        let mut saved = SavedBindings::default();
+        saved.save_from(&action_map);
+        assert!(saved.apply_to(&mut action_map).is_ok());
+    }
+
+    #[test]
+    fn test_saved_bindings_reject_fixed_action() {
+        let mut action_map = ActionMap::default();
+        let mut saved = SavedBindings::default();
+        saved
+            .gamepad
+            .insert(GameAction::Pause, vec![GamepadButton::North]);
+
+        let result = saved.apply_to(&mut action_map);
+        assert!(matches!(
+            result,
+            Err(crate::Error::NotRemappable {
+                action: GameAction::Pause
+            })
+        ));
+        assert_ne!(
+            action_map.gamepad_bindings.get(&GameAction::Pause),
+            Some(&vec![GamepadButton::North])
+        );
+    }
+
+    #[test]
+    fn test_saved_bindings_reject_unbound_required_action() {
+        let mut action_map = ActionMap::default();
+        let mut saved = SavedBindings::default();
+        saved.gamepad.insert(GameAction::Confirm, Vec::new());
+
+        let result = saved.apply_to(&mut action_map);
+        assert!(matches!(
+            result,
+            Err(crate::Error::RequiredBinding {
+                action: GameAction::Confirm
+            })
+        ));
+    }
 }
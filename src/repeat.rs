@@ -0,0 +1,216 @@
+//! Held-action repeat.
+//!
+//! Holding an action fires repeats after a delay, like key repeat on a
+//! keyboard. Gameplay uses the fixed [`ControllerConfig::repeat_delay`] and
+//! [`ControllerConfig::repeat_rate`]; while [`ActionRepeat::menu_mode`] is
+//! set, the staged [`ControllerConfig::menu_repeat`] timing is used instead
+//! so scrolling through long lists starts slow and accelerates.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::{ActionState, GameAction};
+//! use bevy_archie::repeat::ActionRepeat;
+//!
+//! fn scroll_list(actions: Res<ActionState>, repeat: Res<ActionRepeat>) {
+//!     if repeat.triggered(&actions, GameAction::Down) {
+//!         // Move the selection down one row
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::actions::{ActionState, GameAction};
+use crate::config::{ControllerConfig, RepeatStages};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Repeat progress for one held action.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HeldRepeat {
+    /// Seconds the action has been held.
+    held: f32,
+    /// Held time at which the next repeat fires.
+    next: f32,
+    /// Repeats fired so far.
+    repeats: u32,
+}
+
+impl HeldRepeat {
+    /// Start tracking a fresh press.
+    pub(crate) const fn new(stages: &RepeatStages) -> Self {
+        Self {
+            held: 0.0,
+            next: stages.initial_delay,
+            repeats: 0,
+        }
+    }
+
+    /// Advance by `delta` seconds, returning whether a repeat fired.
+    pub(crate) fn tick(&mut self, delta: f32, stages: &RepeatStages) -> bool {
+        self.held += delta;
+        if self.held < self.next {
+            return false;
+        }
+
+        self.repeats += 1;
+        self.next += stages.interval(self.repeats).max(f32::EPSILON);
+        true
+    }
+}
+
+/// Resource tracking repeats for held actions.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ActionRepeat {
+    /// Use the staged menu timing instead of the gameplay timing.
+    pub menu_mode: bool,
+    /// Progress for each held action.
+    held: HashMap<GameAction, HeldRepeat>,
+    /// Actions that repeated this frame.
+    repeated: HashSet<GameAction>,
+}
+
+impl ActionRepeat {
+    /// Check if an action fired a repeat this frame.
+    #[must_use]
+    pub fn just_repeated(&self, action: GameAction) -> bool {
+        self.repeated.contains(&action)
+    }
+
+    /// Check if an action was pressed or repeated this frame.
+    #[must_use]
+    pub fn triggered(&self, action_state: &ActionState, action: GameAction) -> bool {
+        action_state.just_pressed(action) || self.just_repeated(action)
+    }
+
+    /// Number of repeats an action has fired during the current hold.
+    #[must_use]
+    pub fn repeat_count(&self, action: GameAction) -> u32 {
+        self.held.get(&action).map_or(0, |held| held.repeats)
+    }
+
+    /// Advance repeat timers from the current action state.
+    pub fn update(&mut self, delta: f32, action_state: &ActionState, stages: &RepeatStages) {
+        self.repeated.clear();
+
+        for action in GameAction::all() {
+            if !action_state.pressed(*action) {
+                self.held.remove(action);
+                continue;
+            }
+
+            let held = self
+                .held
+                .entry(*action)
+                .or_insert_with(|| HeldRepeat::new(stages));
+            if !action_state.just_pressed(*action) && held.tick(delta, stages) {
+                self.repeated.insert(*action);
+            }
+        }
+    }
+}
+
+/// System to update action repeats.
+pub fn update_action_repeat(
+    time: Res<Time>,
+    config: Res<ControllerConfig>,
+    action_state: Res<ActionState>,
+    mut repeat: ResMut<ActionRepeat>,
+) {
+    let stages = if repeat.menu_mode {
+        config.menu_repeat
+    } else {
+        config.gameplay_repeat()
+    };
+    repeat.update(time.delta_secs(), &action_state, &stages);
+}
+
+/// Plugin for registering repeat types.
+pub(crate) fn register_repeat_types(app: &mut App) {
+    app.init_resource::<ActionRepeat>();
+}
+
+/// Add repeat systems to the app.
+pub(crate) fn add_repeat_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        update_action_repeat
+            .after(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::Repeat)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hold(repeat: &mut ActionRepeat, stages: &RepeatStages, frames: u32, delta: f32) -> u32 {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Down, true);
+        let mut fired = 0;
+        for _ in 0..frames {
+            repeat.update(delta, &state, stages);
+            if repeat.just_repeated(GameAction::Down) {
+                fired += 1;
+            }
+            state.reset_frame_state();
+        }
+        fired
+    }
+
+    #[test]
+    fn test_no_repeat_before_initial_delay() {
+        let mut repeat = ActionRepeat::default();
+        let stages = RepeatStages::constant(0.5, 0.25);
+        assert_eq!(hold(&mut repeat, &stages, 4, 0.125), 0);
+    }
+
+    #[test]
+    fn test_constant_repeat() {
+        let mut repeat = ActionRepeat::default();
+        let stages = RepeatStages::constant(0.5, 0.25);
+        // The first frame registers the press, then 1.0s of holding.
+        assert_eq!(hold(&mut repeat, &stages, 9, 0.125), 3);
+    }
+
+    #[test]
+    fn test_staged_repeat_accelerates() {
+        let stages = RepeatStages {
+            initial_delay: 0.5,
+            slow_interval: 0.25,
+            fast_interval: 0.0625,
+            fast_after: 2,
+        };
+
+        let mut repeat = ActionRepeat::default();
+        assert_eq!(hold(&mut repeat, &stages, 9, 0.0625), 1);
+
+        // Repeats at 0.5 and 0.75, then every 0.0625 until 1.0.
+        let mut repeat = ActionRepeat::default();
+        assert_eq!(hold(&mut repeat, &stages, 17, 0.0625), 6);
+        assert_eq!(repeat.repeat_count(GameAction::Down), 6);
+    }
+
+    #[test]
+    fn test_release_resets() {
+        let mut repeat = ActionRepeat::default();
+        let stages = RepeatStages::constant(0.125, 0.125);
+        hold(&mut repeat, &stages, 5, 0.125);
+        assert!(repeat.repeat_count(GameAction::Down) > 0);
+
+        repeat.update(0.125, &ActionState::default(), &stages);
+        assert_eq!(repeat.repeat_count(GameAction::Down), 0);
+        assert!(!repeat.just_repeated(GameAction::Down));
+    }
+
+    #[test]
+    fn test_triggered_includes_press() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Confirm, true);
+        let repeat = ActionRepeat::default();
+        assert!(repeat.triggered(&state, GameAction::Confirm));
+        assert!(!repeat.triggered(&state, GameAction::Cancel));
+    }
+}
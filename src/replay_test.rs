@@ -0,0 +1,533 @@
+//! Headless replay tests for control feel.
+//!
+//! A [`GamepadReplay`] lists raw gamepad button and axis changes by frame,
+//! and the frames at which to capture the action state. [`run_replay`]
+//! connects a virtual gamepad to an app and plays the replay through Bevy's
+//! gamepad events, so bindings, deadzones, modifiers and buffers all run as
+//! they would with real hardware, then returns an [`ActionSnapshot`] of the
+//! [`ActionState`] at each capture frame.
+//!
+//! [`ReplayExpectations`] stores snapshots as JSON next to the replay.
+//! [`assert_replay`] compares a run against them, giving downstream games
+//! regression tests for their control feel without a controller. Run the
+//! tests with `BEVY_ARCHIE_BLESS=1` to write the captured snapshots as the
+//! new expectations instead.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy::state::app::StatesPlugin;
+//! use bevy_archie::prelude::*;
+//! use bevy_archie::replay_test::{GamepadReplay, assert_replay};
+//!
+//! let mut app = App::new();
+//! app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+//!     .add_plugins(ControllerPlugin::default());
+//! // Spawn the scene under test here
+//!
+//! let replay = GamepadReplay::load("tests/replays/dash.json").unwrap();
+//! assert_replay(&mut app, &replay, "tests/replays/dash.expected.json");
+//! ```
+
+use bevy::input::gamepad::{
+    GamepadConnection, GamepadConnectionEvent, RawGamepadAxisChangedEvent,
+    RawGamepadButtonChangedEvent,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use crate::actions::{ActionState, GameAction};
+
+/// Current replay and expectation file version.
+pub const REPLAY_VERSION: u32 = 1;
+
+/// Environment variable that makes [`assert_replay`] write expectations.
+pub const BLESS_VAR: &str = "BEVY_ARCHIE_BLESS";
+
+/// One raw gamepad change in a replay.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReplayInput {
+    /// A button moved to a value (0.0 released, 1.0 fully pressed).
+    Button {
+        /// The button.
+        button: GamepadButton,
+        /// New value.
+        value: f32,
+    },
+    /// An axis moved to a value.
+    Axis {
+        /// The axis.
+        axis: GamepadAxis,
+        /// New value.
+        value: f32,
+    },
+}
+
+/// Inputs applied before one frame runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayFrame {
+    /// Frame number, counted from the first frame after connection.
+    pub frame: u32,
+    /// Changes applied before the frame.
+    pub inputs: Vec<ReplayInput>,
+}
+
+/// Raw gamepad input to replay, and the frames to capture.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GamepadReplay {
+    /// File format version.
+    #[serde(default = "replay_version")]
+    pub version: u32,
+    /// Name reported by the virtual gamepad, for controller detection.
+    #[serde(default)]
+    pub gamepad_name: Option<String>,
+    /// Input changes, in frame order.
+    #[serde(default)]
+    pub frames: Vec<ReplayFrame>,
+    /// Frames at which the action state is captured.
+    #[serde(default)]
+    pub capture: Vec<u32>,
+}
+
+const fn replay_version() -> u32 {
+    REPLAY_VERSION
+}
+
+impl GamepadReplay {
+    /// Create an empty replay.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            ..default()
+        }
+    }
+
+    /// Add an input change before a frame.
+    #[must_use]
+    pub fn with_input(mut self, frame: u32, input: ReplayInput) -> Self {
+        let index = self.frames.partition_point(|f| f.frame <= frame);
+        match self.frames.get_mut(index.wrapping_sub(1)) {
+            Some(existing) if existing.frame == frame => existing.inputs.push(input),
+            _ => self.frames.insert(
+                index,
+                ReplayFrame {
+                    frame,
+                    inputs: vec![input],
+                },
+            ),
+        }
+        self
+    }
+
+    /// Press a button before a frame.
+    #[must_use]
+    pub fn press(self, frame: u32, button: GamepadButton) -> Self {
+        self.with_input(frame, ReplayInput::Button { button, value: 1.0 })
+    }
+
+    /// Release a button before a frame.
+    #[must_use]
+    pub fn release(self, frame: u32, button: GamepadButton) -> Self {
+        self.with_input(frame, ReplayInput::Button { button, value: 0.0 })
+    }
+
+    /// Move an axis before a frame.
+    #[must_use]
+    pub fn axis(self, frame: u32, axis: GamepadAxis, value: f32) -> Self {
+        self.with_input(frame, ReplayInput::Axis { axis, value })
+    }
+
+    /// Capture the action state after a frame.
+    #[must_use]
+    pub fn capture_at(mut self, frame: u32) -> Self {
+        if let Err(index) = self.capture.binary_search(&frame) {
+            self.capture.insert(index, frame);
+        }
+        self
+    }
+
+    /// Number of frames the replay runs.
+    #[must_use]
+    pub fn len(&self) -> u32 {
+        let last_input = self.frames.iter().map(|f| f.frame).max();
+        let last_capture = self.capture.iter().copied().max();
+        last_input.max(last_capture).map_or(0, |frame| frame + 1)
+    }
+
+    /// Whether the replay runs no frames.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Load a replay from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or isn't a valid replay.
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        serde_json::from_str(&json).map_err(crate::Error::Replay)
+    }
+
+    /// Save the replay as a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(crate::Error::Replay)?;
+        std::fs::write(path, json).map_err(|e| crate::Error::io(path, e))
+    }
+}
+
+/// State of one action in a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ActionSample {
+    /// The action.
+    pub action: GameAction,
+    /// Whether it is held.
+    pub pressed: bool,
+    /// Whether it was pressed this frame.
+    pub just_pressed: bool,
+    /// Whether it was released this frame.
+    pub just_released: bool,
+    /// Analog value.
+    pub value: f32,
+}
+
+impl ActionSample {
+    /// Whether two samples match, with analog values within a tolerance.
+    #[must_use]
+    pub fn matches(&self, other: &Self, tolerance: f32) -> bool {
+        self.action == other.action
+            && self.pressed == other.pressed
+            && self.just_pressed == other.just_pressed
+            && self.just_released == other.just_released
+            && (self.value - other.value).abs() <= tolerance
+    }
+}
+
+/// The action state after one frame.
+///
+/// Only actions that are held, changed, or have a value are stored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionSnapshot {
+    /// Frame the snapshot was taken after.
+    pub frame: u32,
+    /// Active actions, in [`GameAction::all`] order.
+    pub actions: Vec<ActionSample>,
+}
+
+impl ActionSnapshot {
+    /// Capture the active actions in an action state.
+    #[must_use]
+    pub fn capture(frame: u32, state: &ActionState) -> Self {
+        let actions = GameAction::all()
+            .iter()
+            .map(|action| ActionSample {
+                action: *action,
+                pressed: state.pressed(*action),
+                just_pressed: state.just_pressed(*action),
+                just_released: state.just_released(*action),
+                value: state.value(*action),
+            })
+            .filter(|sample| {
+                sample.pressed || sample.just_pressed || sample.just_released || sample.value != 0.0
+            })
+            .collect();
+        Self { frame, actions }
+    }
+
+    /// The sample for an action, if it was active.
+    #[must_use]
+    pub fn get(&self, action: GameAction) -> Option<&ActionSample> {
+        self.actions.iter().find(|sample| sample.action == action)
+    }
+}
+
+/// A difference between an expected and a captured snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotMismatch {
+    /// A capture frame is missing from one side.
+    MissingFrame {
+        /// The frame.
+        frame: u32,
+        /// Whether the expectations have the frame (and the run doesn't).
+        expected: bool,
+    },
+    /// An action's state differs.
+    Action {
+        /// The frame.
+        frame: u32,
+        /// The action.
+        action: GameAction,
+        /// Expected sample, `None` if it should be inactive.
+        expected: Option<ActionSample>,
+        /// Captured sample, `None` if it was inactive.
+        actual: Option<ActionSample>,
+    },
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingFrame {
+                frame,
+                expected: true,
+            } => write!(f, "frame {frame}: expected a snapshot, none was captured"),
+            Self::MissingFrame {
+                frame,
+                expected: false,
+            } => write!(f, "frame {frame}: captured a snapshot with no expectation"),
+            Self::Action {
+                frame,
+                action,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "frame {frame}: {} expected {expected:?}, got {actual:?}",
+                action.display_name()
+            ),
+        }
+    }
+}
+
+/// Stored snapshots a replay is expected to produce.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayExpectations {
+    /// File format version.
+    #[serde(default = "replay_version")]
+    pub version: u32,
+    /// Expected snapshots, in frame order.
+    pub snapshots: Vec<ActionSnapshot>,
+}
+
+impl ReplayExpectations {
+    /// Expectations from captured snapshots.
+    #[must_use]
+    pub const fn new(snapshots: Vec<ActionSnapshot>) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            snapshots,
+        }
+    }
+
+    /// Compare captured snapshots against the expectations.
+    ///
+    /// Analog values may differ by up to `tolerance`.
+    #[must_use]
+    pub fn compare(&self, actual: &[ActionSnapshot], tolerance: f32) -> Vec<SnapshotMismatch> {
+        let mut mismatches = Vec::new();
+        for expected in &self.snapshots {
+            let Some(captured) = actual.iter().find(|s| s.frame == expected.frame) else {
+                mismatches.push(SnapshotMismatch::MissingFrame {
+                    frame: expected.frame,
+                    expected: true,
+                });
+                continue;
+            };
+            for action in GameAction::all() {
+                let (want, got) = (expected.get(*action), captured.get(*action));
+                let same = match (want, got) {
+                    (Some(want), Some(got)) => want.matches(got, tolerance),
+                    (None, None) => true,
+                    _ => false,
+                };
+                if !same {
+                    mismatches.push(SnapshotMismatch::Action {
+                        frame: expected.frame,
+                        action: *action,
+                        expected: want.copied(),
+                        actual: got.copied(),
+                    });
+                }
+            }
+        }
+        for captured in actual {
+            if !self.snapshots.iter().any(|s| s.frame == captured.frame) {
+                mismatches.push(SnapshotMismatch::MissingFrame {
+                    frame: captured.frame,
+                    expected: false,
+                });
+            }
+        }
+        mismatches
+    }
+
+    /// Load expectations from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn load(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| crate::Error::io(path, e))?;
+        serde_json::from_str(&json).map_err(crate::Error::Replay)
+    }
+
+    /// Save expectations as a JSON file, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> crate::Result<()> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(crate::Error::Replay)?;
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory).map_err(|e| crate::Error::io(directory, e))?;
+        }
+        std::fs::write(path, json).map_err(|e| crate::Error::io(path, e))
+    }
+}
+
+/// Connect a virtual gamepad to an app and play a replay through it.
+///
+/// Returns the snapshot captured after each of the replay's capture frames.
+/// The app needs Bevy's `InputPlugin` for the gamepad events to apply.
+pub fn run_replay(app: &mut App, replay: &GamepadReplay) -> Vec<ActionSnapshot> {
+    let gamepad = app.world_mut().spawn_empty().id();
+    app.world_mut().write_message(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Connected {
+            name: replay
+                .gamepad_name
+                .clone()
+                .unwrap_or_else(|| "Replay Gamepad".to_string()),
+            vendor_id: None,
+            product_id: None,
+        },
+    ));
+    app.update();
+
+    let mut inputs = replay.frames.iter().peekable();
+    let mut snapshots = Vec::new();
+    for frame in 0..replay.len() {
+        while let Some(frame_inputs) = inputs.next_if(|f| f.frame <= frame) {
+            for input in &frame_inputs.inputs {
+                match *input {
+                    ReplayInput::Button { button, value } => {
+                        app.world_mut()
+                            .write_message(RawGamepadButtonChangedEvent::new(
+                                gamepad, button, value,
+                            ));
+                    }
+                    ReplayInput::Axis { axis, value } => {
+                        app.world_mut()
+                            .write_message(RawGamepadAxisChangedEvent::new(gamepad, axis, value));
+                    }
+                }
+            }
+        }
+        app.update();
+        if replay.capture.contains(&frame) {
+            snapshots.push(ActionSnapshot::capture(
+                frame,
+                app.world().resource::<ActionState>(),
+            ));
+        }
+    }
+    snapshots
+}
+
+/// Play a replay and assert its snapshots match stored expectations.
+///
+/// With `BEVY_ARCHIE_BLESS` set, or when the expectations file doesn't exist
+/// yet, the captured snapshots are written as the expectations instead.
+///
+/// # Panics
+///
+/// Panics listing every mismatch if the snapshots differ, or if the
+/// expectations can't be read or written.
+pub fn assert_replay(app: &mut App, replay: &GamepadReplay, expected: impl AsRef<Path>) {
+    let expected = expected.as_ref();
+    let snapshots = run_replay(app, replay);
+
+    if std::env::var_os(BLESS_VAR).is_some() || !expected.exists() {
+        ReplayExpectations::new(snapshots)
+            .save(expected)
+            .unwrap_or_else(|e| panic!("could not write replay expectations: {e}"));
+        return;
+    }
+
+    let expectations = ReplayExpectations::load(expected)
+        .unwrap_or_else(|e| panic!("could not read replay expectations: {e}"));
+    let mismatches = expectations.compare(&snapshots, 1e-4);
+    assert!(
+        mismatches.is_empty(),
+        "replay does not match {}:\n{}\nRerun with {BLESS_VAR}=1 to accept the new behaviour.",
+        expected.display(),
+        mismatches
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_builder_orders_frames() {
+        let replay = GamepadReplay::new()
+            .press(5, GamepadButton::South)
+            .press(1, GamepadButton::East)
+            .release(5, GamepadButton::East)
+            .capture_at(8)
+            .capture_at(2)
+            .capture_at(8);
+        let frames: Vec<u32> = replay.frames.iter().map(|f| f.frame).collect();
+        assert_eq!(frames, vec![1, 5]);
+        assert_eq!(replay.frames[1].inputs.len(), 2);
+        assert_eq!(replay.capture, vec![2, 8]);
+        assert_eq!(replay.len(), 9);
+
+        let json = serde_json::to_string(&replay).unwrap();
+        assert_eq!(
+            serde_json::from_str::<GamepadReplay>(&json).unwrap(),
+            replay
+        );
+    }
+
+    #[test]
+    fn test_compare_reports_differences() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Confirm, true);
+        let expected = ReplayExpectations::new(vec![ActionSnapshot::capture(3, &state)]);
+        assert!(
+            expected
+                .compare(&[ActionSnapshot::capture(3, &state)], 0.0)
+                .is_empty()
+        );
+
+        let idle = ActionSnapshot::capture(3, &ActionState::default());
+        let extra = ActionSnapshot::capture(4, &ActionState::default());
+        let mismatches = expected.compare(&[idle, extra], 0.0);
+        assert_eq!(mismatches.len(), 2);
+        assert!(matches!(
+            mismatches[0],
+            SnapshotMismatch::Action {
+                action: GameAction::Confirm,
+                actual: None,
+                ..
+            }
+        ));
+        assert_eq!(
+            mismatches[1],
+            SnapshotMismatch::MissingFrame {
+                frame: 4,
+                expected: false
+            }
+        );
+    }
+}
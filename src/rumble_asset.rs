@@ -0,0 +1,234 @@
+//! Rumble effects loaded as assets.
+//!
+//! A `.rumble.ron` file describes a [`RumbleCurve`] plus how strongly and how
+//! many times to play it, so designers can iterate on haptics without
+//! recompiling. With [`RumbleAssetPlugin`] added, the files load through the
+//! [`AssetServer`] (and hot-reload with Bevy's `file_watcher` feature), and
+//! [`RumbleRequest::from_asset`](crate::haptics::RumbleRequest::from_asset)
+//! plays one on a gamepad.
+//!
+//! ```ron
+//! // assets/rumble/explosion.rumble.ron
+//! (
+//!     curve: (keyframes: [
+//!         (time: 0.0, low: 1.0, high: 0.8),
+//!         (time: 0.1, low: 0.9, high: 0.3),
+//!         (time: 0.6, low: 0.0, high: 0.0, easing: EaseOut),
+//!     ]),
+//!     intensity: 0.9,
+//!     loops: 1,
+//! )
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::prelude::*;
+//! use bevy_archie::rumble_asset::{RumbleAsset, RumbleAssetPlugin};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins((ControllerPlugin::default(), RumbleAssetPlugin))
+//!     .add_systems(Update, explode)
+//!     .run();
+//!
+//! fn explode(
+//!     keys: Res<ButtonInput<KeyCode>>,
+//!     asset_server: Res<AssetServer>,
+//!     gamepads: Query<Entity, With<Gamepad>>,
+//!     mut rumble: MessageWriter<RumbleRequest>,
+//! ) {
+//!     if keys.just_pressed(KeyCode::KeyE) {
+//!         let effect: Handle<RumbleAsset> = asset_server.load("rumble/explosion.rumble.ron");
+//!         for gamepad in &gamepads {
+//!             rumble.write(RumbleRequest::from_asset(gamepad, effect.clone()));
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::haptics::{RumbleController, RumbleCurve, handle_rumble_requests, update_rumble};
+
+/// A rumble effect loaded from a `.rumble.ron` file.
+#[derive(Debug, Clone, PartialEq, Asset, TypePath, Serialize, Deserialize)]
+pub struct RumbleAsset {
+    /// Envelope of the effect.
+    pub curve: RumbleCurve,
+    /// Strength multiplier for the curve (0.0-1.0).
+    #[serde(default = "full_intensity")]
+    pub intensity: f32,
+    /// Times the curve plays; 0 repeats it until the rumble is stopped or
+    /// replaced.
+    #[serde(default = "one_loop")]
+    pub loops: u32,
+}
+
+const fn full_intensity() -> f32 {
+    1.0
+}
+
+const fn one_loop() -> u32 {
+    1
+}
+
+impl RumbleAsset {
+    /// Create an asset that plays a curve once at full strength.
+    #[must_use]
+    pub const fn new(curve: RumbleCurve) -> Self {
+        Self {
+            curve,
+            intensity: 1.0,
+            loops: 1,
+        }
+    }
+
+    /// Parse an asset from RON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text isn't a valid rumble asset.
+    pub fn from_ron(ron: &str) -> crate::Result<Self> {
+        Self::from_ron_bytes(ron.as_bytes())
+    }
+
+    fn from_ron_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let mut asset: Self = ron::de::from_bytes(bytes).map_err(crate::Error::RumbleAsset)?;
+        asset
+            .curve
+            .keyframes
+            .sort_by(|a, b| a.time.total_cmp(&b.time));
+        Ok(asset)
+    }
+
+    /// How long the effect plays, or [`Duration::MAX`] if it loops forever.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        if self.loops == 0 {
+            Duration::MAX
+        } else {
+            self.curve.duration().saturating_mul(self.loops)
+        }
+    }
+}
+
+/// Errors from loading a [`RumbleAsset`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RumbleAssetLoaderError {
+    /// The file could not be read.
+    #[error("could not read rumble asset: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file isn't a valid rumble asset.
+    #[error(transparent)]
+    Parse(#[from] crate::Error),
+}
+
+/// Loads [`RumbleAsset`]s from `.rumble.ron` files.
+#[derive(Debug, Default, TypePath)]
+pub struct RumbleAssetLoader;
+
+impl AssetLoader for RumbleAssetLoader {
+    type Asset = RumbleAsset;
+    type Settings = ();
+    type Error = RumbleAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(RumbleAsset::from_ron_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rumble.ron"]
+    }
+}
+
+/// Plugin that loads [`RumbleAsset`]s and plays them on rumble controllers.
+///
+/// Needs Bevy's `AssetPlugin` (part of `DefaultPlugins`) and
+/// [`ControllerPlugin`](crate::plugin::ControllerPlugin).
+pub struct RumbleAssetPlugin;
+
+impl Plugin for RumbleAssetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<RumbleAsset>()
+            .init_asset_loader::<RumbleAssetLoader>()
+            .add_systems(
+                Update,
+                start_loaded_rumble_assets
+                    .after(handle_rumble_requests)
+                    .before(update_rumble),
+            );
+    }
+}
+
+/// System to start rumble controllers whose asset has finished loading.
+pub fn start_loaded_rumble_assets(
+    assets: Res<Assets<RumbleAsset>>,
+    mut controllers: Query<&mut RumbleController>,
+) {
+    for mut controller in &mut controllers {
+        let Some(asset) = controller
+            .asset
+            .as_ref()
+            .and_then(|handle| assets.get(handle))
+        else {
+            continue;
+        };
+
+        let (curve, intensity, duration) = (asset.curve.clone(), asset.intensity, asset.duration());
+        controller.rumble_curve(curve, intensity);
+        controller.duration = duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::haptics::RumbleEasing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_rumble_asset_from_ron() {
+        let asset = RumbleAsset::from_ron(
+            "(curve: (keyframes: [
+                (time: 0.4, low: 0.0, high: 0.0, easing: EaseOut),
+                (time: 0.0, low: 1.0, high: 0.5),
+            ]))",
+        )
+        .unwrap();
+        assert_relative_eq!(asset.curve.keyframes[0].time, 0.0);
+        assert_eq!(asset.curve.keyframes[1].easing, RumbleEasing::EaseOut);
+        assert_relative_eq!(asset.intensity, 1.0);
+        assert_eq!(asset.duration(), Duration::from_secs_f32(0.4));
+
+        assert!(matches!(
+            RumbleAsset::from_ron("(curve: 3)"),
+            Err(crate::Error::RumbleAsset(_))
+        ));
+    }
+
+    #[test]
+    fn test_rumble_asset_loops() {
+        let curve = RumbleCurve::new()
+            .with_key(0.0, 1.0, 1.0)
+            .with_key(0.5, 0.0, 0.0);
+        let mut asset = RumbleAsset::new(curve);
+        asset.loops = 3;
+        assert_eq!(asset.duration(), Duration::from_millis(1500));
+        asset.loops = 0;
+        assert_eq!(asset.duration(), Duration::MAX);
+    }
+}
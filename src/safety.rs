@@ -0,0 +1,248 @@
+//! Dead-man's switch safety inputs.
+//!
+//! This module lets an action stay active only while a separate safety
+//! input is continuously held. Releasing the safety input disables the
+//! guarded action after a short grace period, which is useful for VR-style
+//! controls, heavy machinery sims, and accessibility setups where accidental
+//! presses are dangerous.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::actions::{ActionState, GameAction};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// A guard that keeps `action` active only while `safety` is held.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct HoldToEnable {
+    /// The guarded action.
+    pub action: GameAction,
+    /// The safety action that must be held.
+    pub safety: GameAction,
+    /// Seconds the action stays enabled after the safety is released.
+    pub grace_period: f32,
+}
+
+impl HoldToEnable {
+    /// Create a new guard with no grace period.
+    #[must_use]
+    pub const fn new(action: GameAction, safety: GameAction) -> Self {
+        Self {
+            action,
+            safety,
+            grace_period: 0.0,
+        }
+    }
+
+    /// Set the grace period in seconds.
+    #[must_use]
+    pub const fn with_grace_period(mut self, seconds: f32) -> Self {
+        self.grace_period = seconds;
+        self
+    }
+}
+
+/// Resource holding all active safety guards.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct SafetyGates {
+    /// Registered guards.
+    pub gates: Vec<HoldToEnable>,
+    /// Remaining enabled time per guarded action.
+    remaining: HashMap<GameAction, f32>,
+}
+
+impl SafetyGates {
+    /// Register a guard, replacing any existing guard for the same action.
+    pub fn add(&mut self, gate: HoldToEnable) {
+        self.gates.retain(|g| g.action != gate.action);
+        self.gates.push(gate);
+    }
+
+    /// Remove the guard for an action.
+    pub fn remove(&mut self, action: GameAction) {
+        self.gates.retain(|g| g.action != action);
+        self.remaining.remove(&action);
+    }
+
+    /// Check if a guarded action is currently enabled.
+    ///
+    /// Actions without a guard are always enabled.
+    #[must_use]
+    pub fn is_enabled(&self, action: GameAction) -> bool {
+        if !self.gates.iter().any(|g| g.action == action) {
+            return true;
+        }
+        self.remaining.get(&action).copied().unwrap_or(0.0) > 0.0
+    }
+
+    /// Advance all guards by `delta` seconds.
+    ///
+    /// Returns the guards that were disabled during this step.
+    pub fn tick(
+        &mut self,
+        delta: f32,
+        safety_held: impl Fn(GameAction) -> bool,
+    ) -> Vec<HoldToEnable> {
+        let mut tripped = Vec::new();
+
+        for gate in &self.gates {
+            let remaining = self.remaining.entry(gate.action).or_insert(0.0);
+            let was_enabled = *remaining > 0.0;
+
+            if safety_held(gate.safety) {
+                // Keep a small positive value even with zero grace so the
+                // action is enabled on the frame the safety is held.
+                *remaining = gate.grace_period.max(f32::EPSILON);
+            } else {
+                *remaining = (*remaining - delta).max(0.0);
+                if gate.grace_period <= 0.0 {
+                    *remaining = 0.0;
+                }
+            }
+
+            if was_enabled && *remaining <= 0.0 {
+                tripped.push(*gate);
+            }
+        }
+
+        tripped
+    }
+}
+
+/// Event fired when a guarded action is disabled because its safety
+/// input was released.
+#[derive(Debug, Clone, Message)]
+pub struct SafetyReleased {
+    /// The guarded action.
+    pub action: GameAction,
+    /// The safety action that was released.
+    pub safety: GameAction,
+}
+
+/// System to apply safety guards to the action state.
+pub fn apply_safety_gates(
+    mut gates: ResMut<SafetyGates>,
+    mut action_state: ResMut<ActionState>,
+    time: Res<Time>,
+    mut released_events: MessageWriter<SafetyReleased>,
+) {
+    if gates.gates.is_empty() {
+        return;
+    }
+
+    let tripped = gates.tick(time.delta_secs(), |safety| action_state.pressed(safety));
+
+    for gate in tripped {
+        released_events.write(SafetyReleased {
+            action: gate.action,
+            safety: gate.safety,
+        });
+    }
+
+    let guarded: Vec<GameAction> = gates.gates.iter().map(|g| g.action).collect();
+    for action in guarded {
+        if !gates.is_enabled(action) {
+            action_state.suppress(action, "safety gate");
+        }
+    }
+}
+
+/// Plugin for registering safety types.
+pub(crate) fn register_safety_types(app: &mut App) {
+    app.register_type::<HoldToEnable>()
+        .init_resource::<SafetyGates>()
+        .add_message::<SafetyReleased>();
+}
+
+/// Add safety systems to the app.
+pub(crate) fn add_safety_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        apply_safety_gates
+            .after(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::Safety)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hold_to_enable_builder() {
+        let gate = HoldToEnable::new(GameAction::Primary, GameAction::LeftShoulder)
+            .with_grace_period(0.25);
+        assert_eq!(gate.action, GameAction::Primary);
+        assert_eq!(gate.safety, GameAction::LeftShoulder);
+        assert_eq!(gate.grace_period, 0.25);
+    }
+
+    #[test]
+    fn test_unguarded_action_is_enabled() {
+        let gates = SafetyGates::default();
+        assert!(gates.is_enabled(GameAction::Primary));
+    }
+
+    #[test]
+    fn test_guarded_action_requires_safety() {
+        let mut gates = SafetyGates::default();
+        gates.add(HoldToEnable::new(
+            GameAction::Primary,
+            GameAction::LeftShoulder,
+        ));
+
+        gates.tick(0.016, |_| false);
+        assert!(!gates.is_enabled(GameAction::Primary));
+
+        gates.tick(0.016, |a| a == GameAction::LeftShoulder);
+        assert!(gates.is_enabled(GameAction::Primary));
+    }
+
+    #[test]
+    fn test_grace_period_keeps_action_enabled() {
+        let mut gates = SafetyGates::default();
+        gates.add(
+            HoldToEnable::new(GameAction::Primary, GameAction::LeftShoulder).with_grace_period(0.1),
+        );
+
+        gates.tick(0.016, |_| true);
+        let tripped = gates.tick(0.05, |_| false);
+        assert!(tripped.is_empty());
+        assert!(gates.is_enabled(GameAction::Primary));
+
+        let tripped = gates.tick(0.06, |_| false);
+        assert_eq!(tripped.len(), 1);
+        assert!(!gates.is_enabled(GameAction::Primary));
+    }
+
+    #[test]
+    fn test_add_replaces_existing_gate() {
+        let mut gates = SafetyGates::default();
+        gates.add(HoldToEnable::new(
+            GameAction::Primary,
+            GameAction::LeftShoulder,
+        ));
+        gates.add(HoldToEnable::new(
+            GameAction::Primary,
+            GameAction::RightShoulder,
+        ));
+        assert_eq!(gates.gates.len(), 1);
+        assert_eq!(gates.gates[0].safety, GameAction::RightShoulder);
+
+        gates.remove(GameAction::Primary);
+        assert!(gates.gates.is_empty());
+    }
+
+    #[test]
+    fn test_suppress_clears_action_state() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Primary, true);
+        state.set_value(GameAction::Primary, 1.0);
+
+        state.suppress(GameAction::Primary, "safety gate");
+        assert!(!state.pressed(GameAction::Primary));
+        assert!(!state.just_pressed(GameAction::Primary));
+        assert!(!state.just_released(GameAction::Primary));
+        assert_eq!(state.value(GameAction::Primary), 0.0);
+    }
+}
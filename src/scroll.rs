@@ -0,0 +1,385 @@
+//! Momentum scrolling for stick-driven lists.
+//!
+//! A [`ScrollFling`] turns stick deflection into scroll velocity that keeps
+//! gliding after the stick is released and decays with friction, like a flick
+//! on a touchscreen. Scrolling past the content bounds stretches into a limited
+//! overscroll and springs back; [`ScrollBounce`] fires as an edge is hit so
+//! games can add a bounce sound, rumble, or stretch effect.
+//!
+//! The offset is copied to the entity's [`ScrollPosition`] when present, so a
+//! Bevy UI node with `Overflow::scroll_y()` scrolls directly. Bevy clamps
+//! `ScrollPosition` to the content, so draw any overscroll stretch from
+//! [`ScrollFling::overscroll`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::scroll::{ScrollBounce, ScrollFling};
+//!
+//! fn spawn_list(mut commands: Commands) {
+//!     commands.spawn((
+//!         Node {
+//!             overflow: Overflow::scroll_y(),
+//!             ..default()
+//!         },
+//!         // Content is 2400px taller than the viewport
+//!         ScrollFling::new(Vec2::new(0.0, 2400.0)),
+//!     ));
+//! }
+//!
+//! fn bounce(mut bounces: MessageReader<ScrollBounce>) {
+//!     for bounce in bounces.read() {
+//!         info!("Hit {:?} at {} px/s", bounce.edge, bounce.speed);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::detection::InputDeviceState;
+use crate::stick::Stick;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Speed in pixels per second below which a glide stops.
+const MIN_SPEED: f32 = 10.0;
+
+/// An edge of the scrollable content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ScrollEdge {
+    /// Start of the horizontal axis.
+    Left,
+    /// End of the horizontal axis.
+    Right,
+    /// Start of the vertical axis.
+    Top,
+    /// End of the vertical axis.
+    Bottom,
+}
+
+impl ScrollEdge {
+    /// The edge for an overscroll on an axis (0 = x, 1 = y).
+    fn from_overscroll(axis: usize, overscroll: f32) -> Self {
+        match (axis, overscroll > 0.0) {
+            (0, false) => Self::Left,
+            (0, true) => Self::Right,
+            (_, false) => Self::Top,
+            (_, true) => Self::Bottom,
+        }
+    }
+}
+
+/// Component adding stick-driven momentum scrolling to an entity.
+///
+/// Offsets and speeds are in logical pixels.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct ScrollFling {
+    /// Stick that drives scrolling.
+    pub stick: Stick,
+    /// Whether this list responds to the stick.
+    pub active: bool,
+    /// Stick deflection ignored as noise.
+    pub deadzone: f32,
+    /// Acceleration in pixels per second squared at full deflection.
+    pub acceleration: f32,
+    /// Maximum scroll speed in pixels per second.
+    pub max_speed: f32,
+    /// Velocity decay rate per second once the stick is released.
+    pub friction: f32,
+    /// Maximum distance the content can be pulled past its bounds.
+    pub overscroll: f32,
+    /// Spring rate per second braking and pulling back overscroll.
+    pub bounce: f32,
+    /// Largest scroll offset (content size minus viewport size).
+    pub max_offset: Vec2,
+    /// Current scroll offset.
+    offset: Vec2,
+    /// Current scroll velocity.
+    velocity: Vec2,
+}
+
+impl Default for ScrollFling {
+    fn default() -> Self {
+        Self {
+            stick: Stick::Right,
+            active: true,
+            deadzone: 0.15,
+            acceleration: 6000.0,
+            max_speed: 3000.0,
+            friction: 4.0,
+            overscroll: 80.0,
+            bounce: 12.0,
+            max_offset: Vec2::ZERO,
+            offset: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+        }
+    }
+}
+
+impl ScrollFling {
+    /// Create a scroller for content that scrolls up to `max_offset`.
+    #[must_use]
+    pub fn new(max_offset: Vec2) -> Self {
+        Self {
+            max_offset,
+            ..default()
+        }
+    }
+
+    /// Set the stick that drives scrolling.
+    #[must_use]
+    pub const fn with_stick(mut self, stick: Stick) -> Self {
+        self.stick = stick;
+        self
+    }
+
+    /// Set the velocity decay rate per second.
+    #[must_use]
+    pub const fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /// Set the maximum overscroll distance (0.0 disables bouncing).
+    #[must_use]
+    pub const fn with_overscroll(mut self, overscroll: f32) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// Current scroll offset, including any overscroll.
+    #[must_use]
+    pub const fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Current scroll velocity in pixels per second.
+    #[must_use]
+    pub const fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Signed distance past the content bounds on each axis.
+    ///
+    /// Negative values are past the top/left edge, positive past the
+    /// bottom/right edge.
+    #[must_use]
+    pub fn overscroll(&self) -> Vec2 {
+        self.offset
+            - self
+                .offset
+                .clamp(Vec2::ZERO, self.max_offset.max(Vec2::ZERO))
+    }
+
+    /// Whether the content is gliding or bouncing back.
+    #[must_use]
+    pub fn is_moving(&self) -> bool {
+        self.velocity != Vec2::ZERO || self.overscroll() != Vec2::ZERO
+    }
+
+    /// Add an impulse to the velocity, e.g. from a page-flip button.
+    pub fn fling(&mut self, impulse: Vec2) {
+        self.velocity = (self.velocity + impulse).clamp_length_max(self.max_speed);
+    }
+
+    /// Jump to an offset within the bounds and stop.
+    pub fn set_offset(&mut self, offset: Vec2) {
+        self.offset = offset.clamp(Vec2::ZERO, self.max_offset.max(Vec2::ZERO));
+        self.velocity = Vec2::ZERO;
+    }
+
+    /// Advance the scroll by `delta` seconds with the stick position.
+    ///
+    /// Pushing the stick up scrolls toward the top. Returns the edges hit
+    /// this frame with the speed they were hit at.
+    pub fn update(&mut self, stick: Vec2, delta: f32) -> Vec<(ScrollEdge, f32)> {
+        let drive = if stick.length() > self.deadzone {
+            Vec2::new(stick.x, -stick.y)
+        } else {
+            Vec2::ZERO
+        };
+        let before = self.overscroll();
+
+        if drive == Vec2::ZERO {
+            self.velocity *= (-self.friction * delta).exp();
+        } else {
+            self.velocity += drive * self.acceleration * delta;
+        }
+        self.velocity = self.velocity.clamp_length_max(self.max_speed);
+        let speed = self.velocity;
+        self.offset += self.velocity * delta;
+
+        let mut edges = Vec::new();
+        let over = self.overscroll();
+        for axis in 0..2 {
+            if over[axis] == 0.0 {
+                continue;
+            }
+            if before[axis] == 0.0 {
+                edges.push((
+                    ScrollEdge::from_overscroll(axis, over[axis]),
+                    speed[axis].abs(),
+                ));
+            }
+
+            let outward = self.velocity[axis] * over[axis] > 0.0;
+            let pushing = drive[axis] * over[axis] > 0.0;
+            if outward || pushing {
+                // Brake against the rubber band, stopping at the limit
+                self.velocity[axis] *= (-self.bounce * delta).exp();
+                let limit = self.overscroll.max(0.0);
+                let clamped =
+                    self.offset[axis].clamp(-limit, self.max_offset[axis].max(0.0) + limit);
+                if clamped != self.offset[axis] {
+                    self.offset[axis] = clamped;
+                    self.velocity[axis] = 0.0;
+                }
+            } else {
+                // Spring back toward the edge
+                self.velocity[axis] = 0.0;
+                self.offset[axis] -= over[axis] * (1.0 - (-self.bounce * delta).exp());
+                let remaining = self.overscroll()[axis];
+                if remaining.abs() < 0.5 {
+                    self.offset[axis] -= remaining;
+                }
+            }
+        }
+
+        if drive == Vec2::ZERO && self.velocity.length() < MIN_SPEED {
+            self.velocity = Vec2::ZERO;
+        }
+        edges
+    }
+}
+
+/// Event fired when a scrolling list hits an edge and starts to bounce.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct ScrollBounce {
+    /// Entity holding the [`ScrollFling`].
+    pub entity: Entity,
+    /// The edge that was hit.
+    pub edge: ScrollEdge,
+    /// Speed in pixels per second when the edge was hit.
+    pub speed: f32,
+}
+
+/// System to scroll active lists with the active gamepad's stick.
+pub fn update_scroll_flings(
+    time: Res<Time>,
+    device_state: Res<InputDeviceState>,
+    gamepads: Query<&Gamepad>,
+    mut flings: Query<(Entity, &mut ScrollFling, Option<&mut ScrollPosition>)>,
+    mut bounce_events: MessageWriter<ScrollBounce>,
+) {
+    let gamepad = device_state
+        .active_gamepad()
+        .and_then(|entity| gamepads.get(entity).ok());
+
+    for (entity, mut fling, position) in &mut flings {
+        let stick = match gamepad {
+            Some(gamepad) if fling.active => fling.stick.read(gamepad),
+            _ => Vec2::ZERO,
+        };
+        for (edge, speed) in fling.update(stick, time.delta_secs()) {
+            bounce_events.write(ScrollBounce {
+                entity,
+                edge,
+                speed,
+            });
+        }
+
+        if let Some(mut position) = position
+            && position.0 != fling.offset()
+        {
+            position.0 = fling.offset();
+        }
+    }
+}
+
+/// Plugin for registering scroll types.
+pub(crate) fn register_scroll_types(app: &mut App) {
+    app.register_type::<ScrollEdge>()
+        .register_type::<ScrollFling>()
+        .add_message::<ScrollBounce>();
+}
+
+/// Add scroll systems to the app.
+pub(crate) fn add_scroll_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        update_scroll_flings.run_if(subsystem_enabled(Subsystem::Scroll)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn list() -> ScrollFling {
+        ScrollFling::new(Vec2::new(0.0, 1000.0))
+    }
+
+    #[test]
+    fn test_stick_accelerates_and_glide_decays() {
+        let mut fling = list();
+        fling.set_offset(Vec2::new(0.0, 500.0));
+
+        // Stick down scrolls toward the bottom
+        fling.update(Vec2::new(0.0, -1.0), 0.1);
+        assert_relative_eq!(fling.velocity().y, 600.0);
+        assert!(fling.offset().y > 500.0);
+
+        let gliding = fling.velocity().y;
+        fling.update(Vec2::ZERO, 0.1);
+        assert!(fling.velocity().y > 0.0);
+        assert!(fling.velocity().y < gliding);
+
+        for _ in 0..100 {
+            fling.update(Vec2::ZERO, 0.1);
+        }
+        assert!(!fling.is_moving());
+    }
+
+    #[test]
+    fn test_speed_is_capped() {
+        let mut fling = list();
+        fling.fling(Vec2::new(0.0, 10_000.0));
+        assert_relative_eq!(fling.velocity().y, fling.max_speed);
+    }
+
+    #[test]
+    fn test_deadzone_ignores_drift() {
+        let mut fling = list();
+        fling.update(Vec2::new(0.0, -0.1), 0.1);
+        assert_eq!(fling.velocity(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_overscroll_bounces_back() {
+        let mut fling = list();
+        fling.fling(Vec2::new(0.0, -1000.0));
+
+        let edges = fling.update(Vec2::ZERO, 0.05);
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].0, ScrollEdge::Top);
+        assert!(fling.overscroll().y < 0.0);
+        assert!(fling.overscroll().y >= -fling.overscroll);
+
+        for _ in 0..100 {
+            assert!(fling.update(Vec2::ZERO, 0.05).is_empty());
+        }
+        assert_eq!(fling.offset(), Vec2::ZERO);
+        assert!(!fling.is_moving());
+    }
+
+    #[test]
+    fn test_overscroll_is_limited() {
+        let mut fling = list();
+        for _ in 0..50 {
+            fling.update(Vec2::new(0.0, 1.0), 0.05);
+        }
+        assert_relative_eq!(fling.overscroll().y, -fling.overscroll);
+    }
+}
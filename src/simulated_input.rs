@@ -0,0 +1,218 @@
+//! Scripted input for tutorials, demos, and attract modes.
+//!
+//! [`SimulatedInput`] injects action presses and axis values that are merged
+//! into [`ActionState`](crate::actions::ActionState) alongside real devices, so modifiers, input buffers,
+//! combos, and repeat see them exactly like a player's input. Unlike the
+//! test-only `MockInput`, it works on actions rather than raw devices and is
+//! safe to use in shipping builds.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::simulated_input::SimulatedInput;
+//!
+//! fn tutorial_jump(mut simulated: ResMut<SimulatedInput>) {
+//!     simulated.tap(GameAction::Primary);
+//!     simulated.hold_for(GameAction::Right, 1.5);
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::actions::{Actionlike, GameAction};
+
+/// How long a simulated action stays held.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum SimulatedHold {
+    /// Until released.
+    Indefinite,
+    /// For a number of frames.
+    Frames(u32),
+    /// For a number of seconds of virtual time.
+    Seconds(f32),
+}
+
+/// A simulated action press.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct SimulatedPress {
+    /// Action value, `0.0..=1.0`.
+    pub value: f32,
+    /// Remaining hold.
+    pub hold: SimulatedHold,
+}
+
+/// Resource for injecting synthetic action input.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct SimulatedInput<A: Actionlike = GameAction> {
+    /// Currently simulated actions.
+    presses: HashMap<A, SimulatedPress>,
+}
+
+impl<A: Actionlike> Default for SimulatedInput<A> {
+    fn default() -> Self {
+        Self {
+            presses: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> SimulatedInput<A> {
+    /// Hold an action until [`release`](Self::release) is called.
+    pub fn press(&mut self, action: A) {
+        self.set_value(action, 1.0);
+    }
+
+    /// Press an action for a single frame.
+    pub fn tap(&mut self, action: A) {
+        self.hold_frames(action, 1);
+    }
+
+    /// Hold an action for a number of frames.
+    pub fn hold_frames(&mut self, action: A, frames: u32) {
+        self.insert(action, 1.0, SimulatedHold::Frames(frames));
+    }
+
+    /// Hold an action for a number of seconds of virtual time.
+    pub fn hold_for(&mut self, action: A, seconds: f32) {
+        self.insert(action, 1.0, SimulatedHold::Seconds(seconds));
+    }
+
+    /// Hold an action at an analog value until released.
+    ///
+    /// A value of zero releases the action.
+    pub fn set_value(&mut self, action: A, value: f32) {
+        self.insert(action, value, SimulatedHold::Indefinite);
+    }
+
+    /// Release a simulated action.
+    pub fn release(&mut self, action: A) {
+        self.presses.remove(&action);
+    }
+
+    /// Release every simulated action.
+    pub fn clear(&mut self) {
+        self.presses.clear();
+    }
+
+    /// The simulated press for an action, if any.
+    #[must_use]
+    pub fn get(&self, action: A) -> Option<&SimulatedPress> {
+        self.presses.get(&action)
+    }
+
+    /// Whether any action is being simulated.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.presses.is_empty()
+    }
+
+    /// Advance timed holds, releasing expired ones.
+    pub fn tick(&mut self, delta: f32) {
+        self.presses.retain(|_, press| match &mut press.hold {
+            SimulatedHold::Indefinite => true,
+            SimulatedHold::Frames(frames) => {
+                *frames = frames.saturating_sub(1);
+                *frames > 0
+            }
+            SimulatedHold::Seconds(seconds) => {
+                *seconds -= delta;
+                *seconds > 0.0
+            }
+        });
+    }
+
+    fn insert(&mut self, action: A, value: f32, hold: SimulatedHold) {
+        let value = value.clamp(0.0, 1.0);
+        if value <= 0.0 {
+            self.presses.remove(&action);
+            return;
+        }
+        self.presses.insert(action, SimulatedPress { value, hold });
+    }
+}
+
+/// System to advance simulated holds after they were applied this frame.
+pub fn advance_simulated_input<A: Actionlike>(
+    mut simulated: ResMut<SimulatedInput<A>>,
+    time: Res<Time>,
+) {
+    if simulated.is_active() {
+        simulated.tick(time.delta_secs());
+    }
+}
+
+/// Merge simulated presses into the action state.
+pub(crate) fn apply_simulated<A: Actionlike>(
+    simulated: &SimulatedInput<A>,
+    action: A,
+    pressed: &mut bool,
+    value: &mut f32,
+) {
+    if let Some(press) = simulated.get(action) {
+        *pressed = true;
+        *value = value.max(press.value);
+    }
+}
+
+/// Plugin for registering simulated input types.
+pub(crate) fn register_simulated_input_types(app: &mut App) {
+    app.register_type::<SimulatedHold>()
+        .register_type::<SimulatedPress>()
+        .register_type::<SimulatedInput>()
+        .init_resource::<SimulatedInput>();
+}
+
+/// Add simulated input systems to the app.
+pub(crate) fn add_simulated_input_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.input,
+        advance_simulated_input::<GameAction>
+            .after(crate::actions::update_action_state::<GameAction>),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_lasts_one_frame() {
+        let mut simulated = SimulatedInput::default();
+        simulated.tap(GameAction::Primary);
+        assert!(simulated.get(GameAction::Primary).is_some());
+        simulated.tick(0.016);
+        assert!(simulated.get(GameAction::Primary).is_none());
+    }
+
+    #[test]
+    fn test_timed_and_indefinite_holds() {
+        let mut simulated = SimulatedInput::default();
+        simulated.hold_for(GameAction::Right, 0.5);
+        simulated.press(GameAction::Confirm);
+
+        simulated.tick(0.25);
+        assert!(simulated.get(GameAction::Right).is_some());
+        simulated.tick(0.25);
+        assert!(simulated.get(GameAction::Right).is_none());
+        assert!(simulated.get(GameAction::Confirm).is_some());
+
+        simulated.release(GameAction::Confirm);
+        assert!(!simulated.is_active());
+    }
+
+    #[test]
+    fn test_set_value_clamps_and_zero_releases() {
+        let mut simulated = SimulatedInput::default();
+        simulated.set_value(GameAction::Secondary, 1.5);
+        assert_eq!(simulated.get(GameAction::Secondary).unwrap().value, 1.0);
+        simulated.set_value(GameAction::Secondary, 0.0);
+        assert!(simulated.get(GameAction::Secondary).is_none());
+    }
+}
@@ -0,0 +1,184 @@
+//! Distance-attenuated rumble for world events.
+//!
+//! [`SpatialRumble`] scales a [`RumbleRequest`] by how far an event (an
+//! explosion, a landing, a passing train) is from the player, so nearby
+//! events shake the controller hard and distant ones barely at all. Events
+//! beyond [`SpatialRumble::max_distance`] produce no request.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::prelude::*;
+//! use bevy_archie::spatial_rumble::SpatialRumble;
+//! use std::time::Duration;
+//!
+//! #[derive(Component)]
+//! struct Player(Entity);
+//!
+//! fn explosion_rumble(
+//!     players: Query<(&Player, &GlobalTransform)>,
+//!     mut rumble: MessageWriter<RumbleRequest>,
+//! ) {
+//!     let explosion = Vec3::new(10.0, 0.0, 4.0);
+//!     let spatial = SpatialRumble::new(2.0, 40.0);
+//!     for (player, transform) in &players {
+//!         let request = RumbleRequest::with_pattern(
+//!             player.0,
+//!             RumblePattern::Explosion,
+//!             1.0,
+//!             Duration::from_millis(600),
+//!         );
+//!         if let Some(request) = spatial.request(request, explosion, transform) {
+//!             rumble.write(request);
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::haptics::{RumbleEasing, RumbleRequest};
+
+/// How rumble strength falls off between the near and far distances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RumbleFalloff {
+    /// Strength falls from full to nothing along an easing curve.
+    ///
+    /// [`RumbleEasing::Step`] keeps full strength up to the far distance.
+    Curve(RumbleEasing),
+    /// Strength falls with the inverse of the distance, like sound, and
+    /// fades to nothing at the far distance.
+    InverseDistance,
+}
+
+impl Default for RumbleFalloff {
+    fn default() -> Self {
+        Self::Curve(RumbleEasing::Linear)
+    }
+}
+
+/// Scales rumble by the distance between an event and a listener.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpatialRumble {
+    /// Distance within which the rumble plays at full strength.
+    pub min_distance: f32,
+    /// Distance at which the rumble fades to nothing.
+    pub max_distance: f32,
+    /// How strength falls off between the two distances.
+    pub falloff: RumbleFalloff,
+}
+
+impl Default for SpatialRumble {
+    fn default() -> Self {
+        Self::new(1.0, 30.0)
+    }
+}
+
+impl SpatialRumble {
+    /// Create a linear falloff between two distances.
+    #[must_use]
+    pub fn new(min_distance: f32, max_distance: f32) -> Self {
+        let min_distance = min_distance.max(0.0);
+        Self {
+            min_distance,
+            max_distance: max_distance.max(min_distance),
+            falloff: RumbleFalloff::default(),
+        }
+    }
+
+    /// Set the falloff.
+    #[must_use]
+    pub const fn with_falloff(mut self, falloff: RumbleFalloff) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Strength multiplier (0.0-1.0) at a distance.
+    #[must_use]
+    pub fn attenuation(&self, distance: f32) -> f32 {
+        if distance <= self.min_distance {
+            return 1.0;
+        }
+        if distance >= self.max_distance {
+            return 0.0;
+        }
+
+        let t = (distance - self.min_distance) / (self.max_distance - self.min_distance);
+        match self.falloff {
+            RumbleFalloff::Curve(easing) => 1.0 - easing.ease(t),
+            RumbleFalloff::InverseDistance => {
+                (self.min_distance.max(f32::EPSILON) / distance) * (1.0 - t)
+            }
+        }
+    }
+
+    /// Strength multiplier for an event heard by a listener.
+    #[must_use]
+    pub fn attenuation_at(&self, source: Vec3, listener: &GlobalTransform) -> f32 {
+        self.attenuation(source.distance(listener.translation()))
+    }
+
+    /// Scale a rumble request for an event heard by a listener.
+    ///
+    /// Returns `None` when the event is too far away to feel. The request's
+    /// intensity is scaled, so patterns and curves keep their shape; rumble
+    /// assets use their own intensity once loaded and aren't attenuated.
+    #[must_use]
+    pub fn request(
+        &self,
+        mut request: RumbleRequest,
+        source: Vec3,
+        listener: &GlobalTransform,
+    ) -> Option<RumbleRequest> {
+        let attenuation = self.attenuation_at(source, listener);
+        if attenuation <= 0.0 {
+            return None;
+        }
+        request.intensity = request.intensity.scaled(attenuation);
+        Some(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_falloff_curves() {
+        let linear = SpatialRumble::new(2.0, 12.0);
+        assert_relative_eq!(linear.attenuation(1.0), 1.0);
+        assert_relative_eq!(linear.attenuation(7.0), 0.5);
+        assert_relative_eq!(linear.attenuation(12.0), 0.0);
+
+        let step = linear.with_falloff(RumbleFalloff::Curve(RumbleEasing::Step));
+        assert_relative_eq!(step.attenuation(11.9), 1.0);
+        assert_relative_eq!(step.attenuation(12.0), 0.0);
+
+        let inverse = linear.with_falloff(RumbleFalloff::InverseDistance);
+        assert_relative_eq!(inverse.attenuation(4.0), 0.4);
+        assert!(inverse.attenuation(7.0) < linear.attenuation(7.0));
+    }
+
+    #[test]
+    fn test_request_scales_intensity() {
+        let spatial = SpatialRumble::new(0.0, 10.0);
+        let listener = GlobalTransform::from_translation(Vec3::new(0.0, 0.0, 5.0));
+        let request = RumbleRequest::new(Entity::from_bits(1), 0.8, Duration::from_millis(200));
+
+        let scaled = spatial
+            .request(request.clone(), Vec3::ZERO, &listener)
+            .unwrap();
+        assert_relative_eq!(scaled.intensity.low_frequency, 0.4);
+        assert_eq!(scaled.duration, request.duration);
+
+        assert!(
+            spatial
+                .request(request, Vec3::new(0.0, 0.0, 20.0), &listener)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! Steam Input API integration (`steam-input` feature).
+//!
+//! Under Steam, players configure controllers in the Steam overlay and
+//! expect games to follow that configuration. [`SteamInput`] reads the
+//! actions of a Steam Input action set each frame and feeds them into
+//! [`ActionState`](crate::actions::ActionState) through [`SimulatedInput`],
+//! so modifiers, buffers and repeat see them like any other input.
+//!
+//! - [`SteamInputBindings`] names the Steam digital and analog actions
+//!   behind each [`GameAction`], and the action set to activate.
+//! - The controller type Steam reports sets
+//!   [`ControllerConfig::current_layout`], so icons match the glyph style
+//!   Steam shows.
+//! - With the `remapping` feature, a [`StartRemapEvent`] opens the Steam
+//!   binding panel instead of the in-game remap flow when the game was
+//!   launched by Steam.
+//!
+//! The action names must match the game's Steam Input action manifest.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::steam_input::SteamInput;
+//!
+//! fn enable_steam_input(app: &mut App, client: steamworks::Client) {
+//!     match SteamInput::new(client) {
+//!         Ok(steam) => {
+//!             app.insert_resource(steam);
+//!         }
+//!         Err(e) => warn!("Steam Input unavailable: {e}"),
+//!     }
+//! }
+//! ```
+//!
+//! [`StartRemapEvent`]: crate::remapping::StartRemapEvent
+
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use steamworks::{Client, InputType};
+
+use crate::actions::GameAction;
+use crate::config::{ControllerConfig, ControllerLayout};
+use crate::simulated_input::SimulatedInput;
+
+/// Environment variable Steam sets for games it launches.
+const STEAM_GAME_ID_VAR: &str = "SteamGameId";
+
+/// Whether the game was launched by Steam.
+#[must_use]
+pub fn running_under_steam() -> bool {
+    std::env::var_os(STEAM_GAME_ID_VAR).is_some()
+}
+
+/// Direction of a Steam analog action that drives an action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SteamAnalogDirection {
+    /// Negative X.
+    Left,
+    /// Positive X.
+    Right,
+    /// Positive Y.
+    Up,
+    /// Negative Y.
+    Down,
+}
+
+impl SteamAnalogDirection {
+    /// Value of an analog action along this direction (0.0 - 1.0).
+    #[must_use]
+    pub fn value(self, x: f32, y: f32) -> f32 {
+        let value = match self {
+            Self::Left => -x,
+            Self::Right => x,
+            Self::Up => y,
+            Self::Down => -y,
+        };
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Steam analog action bound to an action.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct SteamAnalogBinding {
+    /// Analog action name in the manifest.
+    pub name: String,
+    /// Direction that drives the action.
+    pub direction: SteamAnalogDirection,
+}
+
+/// Resource naming the Steam Input actions behind each [`GameAction`].
+///
+/// The default uses an action set named `"InGameControls"`, analog actions
+/// `"move"` and `"camera"` for movement and look, and the snake-case action
+/// name (e.g. `"left_shoulder"`) as a digital action for everything else.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct SteamInputBindings {
+    /// Action set activated on every controller.
+    pub action_set: String,
+    digital: HashMap<GameAction, String>,
+    analog: HashMap<GameAction, SteamAnalogBinding>,
+}
+
+impl Default for SteamInputBindings {
+    fn default() -> Self {
+        let mut bindings = Self::empty("InGameControls");
+        for action in GameAction::all() {
+            let analog = match action {
+                GameAction::Up => Some(("move", SteamAnalogDirection::Up)),
+                GameAction::Down => Some(("move", SteamAnalogDirection::Down)),
+                GameAction::Left => Some(("move", SteamAnalogDirection::Left)),
+                GameAction::Right => Some(("move", SteamAnalogDirection::Right)),
+                GameAction::LookUp => Some(("camera", SteamAnalogDirection::Up)),
+                GameAction::LookDown => Some(("camera", SteamAnalogDirection::Down)),
+                GameAction::LookLeft => Some(("camera", SteamAnalogDirection::Left)),
+                GameAction::LookRight => Some(("camera", SteamAnalogDirection::Right)),
+                _ => None,
+            };
+            match analog {
+                Some((name, direction)) => bindings.bind_analog(*action, name, direction),
+                None => bindings.bind_digital(*action, snake_case(&format!("{action:?}"))),
+            }
+        }
+        bindings
+    }
+}
+
+impl SteamInputBindings {
+    /// Bindings with no actions for an action set.
+    #[must_use]
+    pub fn empty(action_set: impl Into<String>) -> Self {
+        Self {
+            action_set: action_set.into(),
+            digital: HashMap::new(),
+            analog: HashMap::new(),
+        }
+    }
+
+    /// Drive an action from a Steam digital action.
+    pub fn bind_digital(&mut self, action: GameAction, name: impl Into<String>) {
+        self.analog.remove(&action);
+        self.digital.insert(action, name.into());
+    }
+
+    /// Drive an action from one direction of a Steam analog action.
+    pub fn bind_analog(
+        &mut self,
+        action: GameAction,
+        name: impl Into<String>,
+        direction: SteamAnalogDirection,
+    ) {
+        self.digital.remove(&action);
+        self.analog.insert(
+            action,
+            SteamAnalogBinding {
+                name: name.into(),
+                direction,
+            },
+        );
+    }
+
+    /// Stop reading an action from Steam.
+    pub fn unbind(&mut self, action: GameAction) {
+        self.digital.remove(&action);
+        self.analog.remove(&action);
+    }
+
+    /// The Steam digital action behind an action.
+    #[must_use]
+    pub fn digital(&self, action: GameAction) -> Option<&str> {
+        self.digital.get(&action).map(String::as_str)
+    }
+
+    /// The Steam analog action behind an action.
+    #[must_use]
+    pub fn analog(&self, action: GameAction) -> Option<&SteamAnalogBinding> {
+        self.analog.get(&action)
+    }
+}
+
+/// Convert a `CamelCase` name to `snake_case`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_digit() && index > 0 {
+            snake.push('_');
+            snake.push(c);
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Icon layout matching the glyphs Steam shows for a controller type.
+#[must_use]
+pub fn layout_for_input_type(input_type: InputType) -> ControllerLayout {
+    match input_type {
+        InputType::XBox360Controller | InputType::XBoxOneController => ControllerLayout::Xbox,
+        InputType::PS3Controller | InputType::PS4Controller | InputType::PS5Controller => {
+            ControllerLayout::PlayStation
+        }
+        InputType::SwitchProController
+        | InputType::SwitchJoyConPair
+        | InputType::SwitchJoyConSingle => ControllerLayout::Nintendo,
+        InputType::SteamController | InputType::SteamDeckController => ControllerLayout::Steam,
+        _ => ControllerLayout::Generic,
+    }
+}
+
+/// Resource reading controllers through the Steam Input API.
+#[derive(Resource)]
+pub struct SteamInput {
+    client: Client,
+    /// Whether the game was launched by Steam.
+    under_steam: bool,
+    /// Connected Steam Input controller handles.
+    controllers: Vec<u64>,
+    action_set: Option<(String, u64)>,
+    digital_handles: HashMap<String, u64>,
+    analog_handles: HashMap<String, u64>,
+    /// Actions held in [`SimulatedInput`] by analog values.
+    held: HashSet<GameAction>,
+}
+
+impl SteamInput {
+    /// Initialize Steam Input on a Steam client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Steam Input fails to initialize.
+    pub fn new(client: Client) -> crate::Result<Self> {
+        if !client.input().init(true) {
+            return Err(crate::Error::BackendInit {
+                backend: "steam-input",
+                reason: "SteamInput initialization failed".into(),
+            });
+        }
+        Ok(Self {
+            client,
+            under_steam: running_under_steam(),
+            controllers: Vec::new(),
+            action_set: None,
+            digital_handles: HashMap::new(),
+            analog_handles: HashMap::new(),
+            held: HashSet::new(),
+        })
+    }
+
+    /// Whether the game was launched by Steam.
+    #[must_use]
+    pub const fn under_steam(&self) -> bool {
+        self.under_steam
+    }
+
+    /// Number of controllers Steam Input reports.
+    #[must_use]
+    pub fn controller_count(&self) -> usize {
+        self.controllers.len()
+    }
+
+    /// Icon layout for the first controller, if one is connected.
+    #[must_use]
+    pub fn layout(&self) -> Option<ControllerLayout> {
+        let controller = *self.controllers.first()?;
+        Some(layout_for_input_type(
+            self.client.input().get_input_type_for_handle(controller),
+        ))
+    }
+
+    /// Open the Steam binding panel for the first controller.
+    ///
+    /// Returns `false` if no controller is connected or the overlay is
+    /// unavailable.
+    pub fn show_binding_panel(&self) -> bool {
+        self.controllers
+            .first()
+            .is_some_and(|controller| self.client.input().show_binding_panel(*controller))
+    }
+
+    /// Refresh controllers and activate the action set.
+    fn run_frame(&mut self, action_set: &str) {
+        let input = self.client.input();
+        input.run_frame();
+        self.controllers = input.get_connected_controllers();
+
+        if self
+            .action_set
+            .as_ref()
+            .is_none_or(|(name, _)| name != action_set)
+        {
+            let handle = input.get_action_set_handle(action_set);
+            self.action_set = Some((action_set.to_string(), handle));
+        }
+        if let Some((_, handle)) = self.action_set {
+            for controller in &self.controllers {
+                input.activate_action_set_handle(*controller, handle);
+            }
+        }
+    }
+
+    /// Whether a digital action is held on any controller.
+    fn digital_pressed(&mut self, name: &str) -> bool {
+        let input = self.client.input();
+        let handle = *self
+            .digital_handles
+            .entry(name.to_string())
+            .or_insert_with(|| input.get_digital_action_handle(name));
+        handle != 0
+            && self.controllers.iter().any(|controller| {
+                let data = input.get_digital_action_data(*controller, handle);
+                data.bActive && data.bState
+            })
+    }
+
+    /// Strongest value of an analog action direction on any controller.
+    fn analog_value(&mut self, binding: &SteamAnalogBinding) -> f32 {
+        let input = self.client.input();
+        let handle = *self
+            .analog_handles
+            .entry(binding.name.clone())
+            .or_insert_with(|| input.get_analog_action_handle(&binding.name));
+        if handle == 0 {
+            return 0.0;
+        }
+        self.controllers
+            .iter()
+            .map(|controller| input.get_analog_action_data(*controller, handle))
+            .filter(|data| data.bActive)
+            .map(|data| binding.direction.value(data.x, data.y))
+            .fold(0.0, f32::max)
+    }
+}
+
+impl Drop for SteamInput {
+    fn drop(&mut self) {
+        self.client.input().shutdown();
+    }
+}
+
+/// System to feed Steam Input actions into the action state.
+pub fn read_steam_input(
+    mut steam: ResMut<SteamInput>,
+    bindings: Res<SteamInputBindings>,
+    mut simulated: ResMut<SimulatedInput>,
+) {
+    steam.run_frame(&bindings.action_set);
+
+    for (action, name) in &bindings.digital {
+        if steam.digital_pressed(name) && simulated.get(*action).is_none() {
+            simulated.tap(*action);
+        }
+    }
+
+    for (action, binding) in &bindings.analog {
+        let value = steam.analog_value(binding);
+        if value > 0.0 {
+            simulated.set_value(*action, value);
+            steam.held.insert(*action);
+        } else if steam.held.remove(action) {
+            simulated.release(*action);
+        }
+    }
+    // Release actions that were unbound while held
+    let stale: Vec<GameAction> = steam
+        .held
+        .iter()
+        .filter(|action| !bindings.analog.contains_key(action))
+        .copied()
+        .collect();
+    for action in stale {
+        steam.held.remove(&action);
+        simulated.release(action);
+    }
+}
+
+/// System to match the icon layout to the Steam controller type.
+pub fn sync_steam_layout(steam: Res<SteamInput>, mut config: ResMut<ControllerConfig>) {
+    if let Some(layout) = steam.layout()
+        && config.current_layout != layout
+    {
+        config.current_layout = layout;
+    }
+}
+
+/// System to open the Steam binding panel for remap requests when the game
+/// was launched by Steam.
+#[cfg(feature = "remapping")]
+pub fn defer_remapping_to_steam(
+    steam: Res<SteamInput>,
+    mut context: ResMut<crate::remapping::RemappingContext>,
+    mut requests: MessageReader<crate::remapping::StartRemapEvent>,
+) {
+    if context.external != steam.under_steam() {
+        context.external = steam.under_steam();
+    }
+    if steam.under_steam() && requests.read().count() > 0 {
+        steam.show_binding_panel();
+    }
+}
+
+/// Plugin for registering Steam Input types.
+pub(crate) fn register_steam_input_types(app: &mut App) {
+    app.register_type::<SteamInputBindings>()
+        .init_resource::<SteamInputBindings>();
+}
+
+/// Add Steam Input systems to the app.
+pub(crate) fn add_steam_input_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.input,
+        (read_steam_input, sync_steam_layout)
+            .before(crate::actions::update_action_state::<GameAction>)
+            .run_if(resource_exists::<SteamInput>),
+    );
+
+    #[cfg(feature = "remapping")]
+    app.add_systems(
+        schedules.update,
+        defer_remapping_to_steam
+            .before(crate::remapping::handle_start_remap)
+            .run_if(resource_exists::<SteamInput>),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_bindings() {
+        let bindings = SteamInputBindings::default();
+        assert_eq!(bindings.digital(GameAction::Confirm), Some("confirm"));
+        assert_eq!(
+            bindings.digital(GameAction::LeftShoulder),
+            Some("left_shoulder")
+        );
+        assert_eq!(bindings.digital(GameAction::Custom1), Some("custom_1"));
+        let look = bindings.analog(GameAction::LookLeft).unwrap();
+        assert_eq!(look.name, "camera");
+        assert_eq!(look.direction, SteamAnalogDirection::Left);
+        assert!(bindings.digital(GameAction::Up).is_none());
+    }
+
+    #[test]
+    fn test_rebinding_replaces_kind() {
+        let mut bindings = SteamInputBindings::empty("Menu");
+        bindings.bind_analog(GameAction::Confirm, "trigger", SteamAnalogDirection::Right);
+        bindings.bind_digital(GameAction::Confirm, "select");
+        assert!(bindings.analog(GameAction::Confirm).is_none());
+        bindings.unbind(GameAction::Confirm);
+        assert!(bindings.digital(GameAction::Confirm).is_none());
+    }
+
+    #[test]
+    fn test_analog_direction_values() {
+        assert_eq!(SteamAnalogDirection::Left.value(-0.5, 0.0), 0.5);
+        assert_eq!(SteamAnalogDirection::Right.value(-0.5, 0.0), 0.0);
+        assert_eq!(SteamAnalogDirection::Down.value(0.0, -2.0), 1.0);
+        assert_eq!(
+            layout_for_input_type(InputType::PS5Controller),
+            ControllerLayout::PlayStation
+        );
+        assert_eq!(
+            layout_for_input_type(InputType::SteamDeckController),
+            ControllerLayout::Steam
+        );
+    }
+}
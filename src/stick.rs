@@ -0,0 +1,584 @@
+//! Analog stick gesture processing.
+//!
+//! This module turns raw stick positions into higher-level inputs:
+//!
+//! - [`Stick8Way`] converts a stick into 4-way or 8-way digital directions
+//!   with angular hysteresis and an optional re-center requirement. The
+//!   default [`ActionMap`](crate::actions::ActionMap) uses it to drive the
+//!   movement and look actions.
+//! - [`StickTwirl`] tracks continuous rotation of a stick (throwing a hammer,
+//!   cranking a winch) and reports the accumulated angle, signed
+//!   rotations-per-second, and an analog charge that can be written to an
+//!   action.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::stick::{Stick, StickTwirl};
+//!
+//! fn add_twirl(mut commands: Commands, gamepads: Query<Entity, Added<Gamepad>>) {
+//!     for gamepad in &gamepads {
+//!         commands
+//!             .entity(gamepad)
+//!             .insert(StickTwirl::new(Stick::Left).with_action(GameAction::Custom1));
+//!     }
+//! }
+//!
+//! fn crank(twirls: Query<&StickTwirl>) {
+//!     for twirl in &twirls {
+//!         info!("{:.1} rotations/s", twirl.rotations_per_second());
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{PI, TAU};
+
+use crate::actions::{ActionState, GameAction};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Which analog stick to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize)]
+pub enum Stick {
+    /// The left stick.
+    #[default]
+    Left,
+    /// The right stick.
+    Right,
+}
+
+impl Stick {
+    /// Read the stick position from a gamepad.
+    #[must_use]
+    pub fn read(self, gamepad: &Gamepad) -> Vec2 {
+        match self {
+            Self::Left => gamepad.left_stick(),
+            Self::Right => gamepad.right_stick(),
+        }
+    }
+}
+
+/// How many directions a [`Stick8Way`] resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize)]
+pub enum DirectionMode {
+    /// Cardinal directions only.
+    FourWay,
+    /// Cardinal and diagonal directions.
+    #[default]
+    EightWay,
+}
+
+impl DirectionMode {
+    /// Number of sectors the stick is divided into.
+    #[must_use]
+    pub const fn sectors(self) -> usize {
+        match self {
+            Self::FourWay => 4,
+            Self::EightWay => 8,
+        }
+    }
+}
+
+/// A digital direction produced by [`Stick8Way`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum StickDirection {
+    /// Up.
+    Up,
+    /// Up and right.
+    UpRight,
+    /// Right.
+    Right,
+    /// Down and right.
+    DownRight,
+    /// Down.
+    Down,
+    /// Down and left.
+    DownLeft,
+    /// Left.
+    Left,
+    /// Up and left.
+    UpLeft,
+}
+
+impl StickDirection {
+    /// Directions in counter-clockwise order starting from the +X axis.
+    const EIGHT_WAY: [Self; 8] = [
+        Self::Right,
+        Self::UpRight,
+        Self::Up,
+        Self::UpLeft,
+        Self::Left,
+        Self::DownLeft,
+        Self::Down,
+        Self::DownRight,
+    ];
+
+    /// Cardinal directions in counter-clockwise order starting from the +X axis.
+    const FOUR_WAY: [Self; 4] = [Self::Right, Self::Up, Self::Left, Self::Down];
+
+    /// Resolve an angle (radians, counter-clockwise from +X) to a direction.
+    #[must_use]
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss,
+        reason = "the sector index is rounded and wrapped into 0..8"
+    )]
+    pub fn from_angle(angle: f32, mode: DirectionMode) -> Self {
+        let sectors = mode.sectors();
+        let sector = TAU / sectors as f32;
+        let index = (angle.rem_euclid(TAU) / sector).round() as usize % sectors;
+        match mode {
+            DirectionMode::FourWay => Self::FOUR_WAY[index],
+            DirectionMode::EightWay => Self::EIGHT_WAY[index],
+        }
+    }
+
+    /// Angle at the center of this direction's sector (radians).
+    #[must_use]
+    pub const fn angle(self) -> f32 {
+        match self {
+            Self::Right => 0.0,
+            Self::UpRight => PI * 0.25,
+            Self::Up => PI * 0.5,
+            Self::UpLeft => PI * 0.75,
+            Self::Left => PI,
+            Self::DownLeft => PI * 1.25,
+            Self::Down => PI * 1.5,
+            Self::DownRight => PI * 1.75,
+        }
+    }
+
+    /// Whether this direction includes up.
+    #[must_use]
+    pub const fn is_up(self) -> bool {
+        matches!(self, Self::Up | Self::UpLeft | Self::UpRight)
+    }
+
+    /// Whether this direction includes down.
+    #[must_use]
+    pub const fn is_down(self) -> bool {
+        matches!(self, Self::Down | Self::DownLeft | Self::DownRight)
+    }
+
+    /// Whether this direction includes left.
+    #[must_use]
+    pub const fn is_left(self) -> bool {
+        matches!(self, Self::Left | Self::UpLeft | Self::DownLeft)
+    }
+
+    /// Whether this direction includes right.
+    #[must_use]
+    pub const fn is_right(self) -> bool {
+        matches!(self, Self::Right | Self::UpRight | Self::DownRight)
+    }
+}
+
+/// Converts a stick position into 4-way or 8-way digital directions.
+///
+/// A direction activates once the stick passes [`Self::press_threshold`] and
+/// stays active until it falls below [`Self::release_threshold`]. While
+/// active, the stick has to move [`Self::hysteresis`] degrees past the
+/// sector edge before the direction changes, so holding a diagonal doesn't
+/// flicker between neighbours. With [`Self::require_recenter`], leaving a
+/// sector releases the direction until the stick returns to center.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Stick8Way {
+    /// Number of directions.
+    pub mode: DirectionMode,
+    /// Deflection needed to activate a direction.
+    pub press_threshold: f32,
+    /// Deflection below which an active direction releases.
+    pub release_threshold: f32,
+    /// Extra degrees past a sector edge before the direction changes.
+    pub hysteresis: f32,
+    /// Require the stick to return to center before changing direction.
+    pub require_recenter: bool,
+    /// Currently active direction.
+    #[serde(skip)]
+    current: Option<StickDirection>,
+    /// Set after leaving a sector while a re-center is required.
+    #[serde(skip)]
+    awaiting_recenter: bool,
+}
+
+impl Default for Stick8Way {
+    fn default() -> Self {
+        Self::new(DirectionMode::EightWay)
+    }
+}
+
+impl Stick8Way {
+    /// Create a processor with default thresholds.
+    #[must_use]
+    pub const fn new(mode: DirectionMode) -> Self {
+        Self {
+            mode,
+            press_threshold: 0.5,
+            release_threshold: 0.4,
+            hysteresis: 10.0,
+            require_recenter: false,
+            current: None,
+            awaiting_recenter: false,
+        }
+    }
+
+    /// Set the press and release thresholds.
+    #[must_use]
+    pub const fn with_thresholds(mut self, press: f32, release: f32) -> Self {
+        self.press_threshold = press;
+        self.release_threshold = release;
+        self
+    }
+
+    /// Set the angular hysteresis in degrees.
+    #[must_use]
+    pub const fn with_hysteresis(mut self, degrees: f32) -> Self {
+        self.hysteresis = degrees;
+        self
+    }
+
+    /// Require the stick to return to center before changing direction.
+    #[must_use]
+    pub const fn with_recenter(mut self, require: bool) -> Self {
+        self.require_recenter = require;
+        self
+    }
+
+    /// Currently active direction.
+    #[must_use]
+    pub const fn direction(&self) -> Option<StickDirection> {
+        self.current
+    }
+
+    /// Copy thresholds and mode from another processor, keeping state.
+    pub(crate) const fn copy_settings_from(&mut self, other: &Self) {
+        self.mode = other.mode;
+        self.press_threshold = other.press_threshold;
+        self.release_threshold = other.release_threshold;
+        self.hysteresis = other.hysteresis;
+        self.require_recenter = other.require_recenter;
+    }
+
+    /// Process a stick position and return the active direction.
+    #[expect(clippy::cast_precision_loss, reason = "sector counts are tiny")]
+    pub fn update(&mut self, position: Vec2) -> Option<StickDirection> {
+        let threshold = if self.current.is_some() || self.awaiting_recenter {
+            self.release_threshold
+        } else {
+            self.press_threshold
+        };
+
+        if position.length() < threshold {
+            self.current = None;
+            self.awaiting_recenter = false;
+            return None;
+        }
+
+        if self.awaiting_recenter {
+            return None;
+        }
+
+        let angle = position.y.atan2(position.x);
+
+        if let Some(current) = self.current {
+            let half_sector = PI / self.mode.sectors() as f32;
+            let hysteresis = self.hysteresis.to_radians().clamp(0.0, half_sector);
+            let offset = ((angle - current.angle() + PI).rem_euclid(TAU) - PI).abs();
+            if offset <= half_sector + hysteresis {
+                return self.current;
+            }
+
+            if self.require_recenter {
+                self.current = None;
+                self.awaiting_recenter = true;
+                return None;
+            }
+        }
+
+        self.current = Some(StickDirection::from_angle(angle, self.mode));
+        self.current
+    }
+}
+
+/// Direction of a stick rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum TwirlDirection {
+    /// Counter-clockwise (positive angle).
+    CounterClockwise,
+    /// Clockwise (negative angle).
+    Clockwise,
+}
+
+/// Component tracking continuous rotation of a stick on a gamepad entity.
+#[derive(Debug, Clone, Component)]
+pub struct StickTwirl {
+    /// The stick to track.
+    pub stick: Stick,
+    /// Deflection required before rotation is tracked.
+    pub threshold: f32,
+    /// Rotations per second that map to a charge of 1.0.
+    pub max_rotations_per_second: f32,
+    /// How quickly the measured speed follows the stick (per second).
+    pub smoothing: f32,
+    /// Action that receives the charge as its analog value.
+    pub action: Option<GameAction>,
+    /// Signed radians rotated since the stick left the center.
+    angle: f32,
+    /// Stick angle at the last update.
+    last_angle: Option<f32>,
+    /// Smoothed signed rotations per second.
+    speed: f32,
+}
+
+impl StickTwirl {
+    /// Create a twirl detector for a stick.
+    #[must_use]
+    pub const fn new(stick: Stick) -> Self {
+        Self {
+            stick,
+            threshold: 0.6,
+            max_rotations_per_second: 3.0,
+            smoothing: 10.0,
+            action: None,
+            angle: 0.0,
+            last_angle: None,
+            speed: 0.0,
+        }
+    }
+
+    /// Write the charge to an action's analog value.
+    #[must_use]
+    pub const fn with_action(mut self, action: GameAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Set the deflection threshold.
+    #[must_use]
+    pub const fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the rotation speed that maps to full charge.
+    #[must_use]
+    pub const fn with_max_rotations_per_second(mut self, max: f32) -> Self {
+        self.max_rotations_per_second = max;
+        self
+    }
+
+    /// Advance the detector with the current stick position.
+    pub fn update(&mut self, position: Vec2, delta: f32) {
+        let mut step = 0.0;
+
+        if position.length() >= self.threshold {
+            let angle = position.y.atan2(position.x);
+            if let Some(last) = self.last_angle {
+                // Wrap into (-PI, PI] so crossing the -X axis isn't a full turn.
+                step = (angle - last + PI).rem_euclid(TAU) - PI;
+                self.angle += step;
+            }
+            self.last_angle = Some(angle);
+        } else {
+            self.angle = 0.0;
+            self.last_angle = None;
+        }
+
+        if delta > 0.0 {
+            let raw = step / TAU / delta;
+            let blend = 1.0 - (-self.smoothing * delta).exp();
+            self.speed += (raw - self.speed) * blend;
+        }
+    }
+
+    /// Signed radians accumulated since the stick left the center.
+    #[must_use]
+    pub const fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Signed full rotations accumulated since the stick left the center.
+    #[must_use]
+    pub fn rotations(&self) -> f32 {
+        self.angle / TAU
+    }
+
+    /// Smoothed signed rotations per second (positive is counter-clockwise).
+    #[must_use]
+    pub const fn rotations_per_second(&self) -> f32 {
+        self.speed
+    }
+
+    /// Rotation speed normalized against the maximum (0.0-1.0).
+    #[must_use]
+    pub fn charge(&self) -> f32 {
+        (self.speed.abs() / self.max_rotations_per_second.max(f32::EPSILON)).clamp(0.0, 1.0)
+    }
+
+    /// Current rotation direction, if the stick is turning.
+    #[must_use]
+    pub fn direction(&self) -> Option<TwirlDirection> {
+        if self.charge() < 0.01 {
+            None
+        } else if self.speed > 0.0 {
+            Some(TwirlDirection::CounterClockwise)
+        } else {
+            Some(TwirlDirection::Clockwise)
+        }
+    }
+}
+
+/// System to update stick twirl detectors and write their charge to actions.
+pub fn update_stick_twirls(
+    time: Res<Time>,
+    mut action_state: ResMut<ActionState>,
+    mut twirls: Query<(&Gamepad, &mut StickTwirl)>,
+) {
+    for (gamepad, mut twirl) in &mut twirls {
+        let position = twirl.stick.read(gamepad);
+        twirl.update(position, time.delta_secs());
+
+        if let Some(action) = twirl.action {
+            let value = action_state.value(action).max(twirl.charge());
+            action_state.set_value(action, value);
+        }
+    }
+}
+
+/// Plugin for registering stick types.
+pub(crate) fn register_stick_types(app: &mut App) {
+    app.register_type::<Stick>()
+        .register_type::<DirectionMode>()
+        .register_type::<StickDirection>()
+        .register_type::<Stick8Way>()
+        .register_type::<TwirlDirection>();
+}
+
+/// Add stick systems to the app.
+pub(crate) fn add_stick_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        update_stick_twirls
+            .after(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::Stick)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_direction_from_angle() {
+        assert_eq!(
+            StickDirection::from_angle(0.0, DirectionMode::EightWay),
+            StickDirection::Right
+        );
+        assert_eq!(
+            StickDirection::from_angle(PI * 0.25, DirectionMode::EightWay),
+            StickDirection::UpRight
+        );
+        assert_eq!(
+            StickDirection::from_angle(-PI * 0.5, DirectionMode::EightWay),
+            StickDirection::Down
+        );
+        assert_eq!(
+            StickDirection::from_angle(PI * 0.3, DirectionMode::FourWay),
+            StickDirection::Up
+        );
+        assert!(StickDirection::UpLeft.is_up() && StickDirection::UpLeft.is_left());
+    }
+
+    #[test]
+    fn test_stick_8way_thresholds() {
+        let mut stick = Stick8Way::new(DirectionMode::EightWay);
+        assert_eq!(stick.update(Vec2::new(0.45, 0.0)), None);
+        assert_eq!(
+            stick.update(Vec2::new(0.6, 0.0)),
+            Some(StickDirection::Right)
+        );
+        // Stays active between the release and press thresholds.
+        assert_eq!(
+            stick.update(Vec2::new(0.45, 0.0)),
+            Some(StickDirection::Right)
+        );
+        assert_eq!(stick.update(Vec2::new(0.3, 0.0)), None);
+    }
+
+    #[test]
+    fn test_stick_8way_hysteresis() {
+        let mut stick = Stick8Way::new(DirectionMode::EightWay).with_hysteresis(10.0);
+        stick.update(Vec2::X);
+
+        // Just past the 22.5 degree edge: still Right.
+        let edge = Vec2::from_angle(25f32.to_radians());
+        assert_eq!(stick.update(edge), Some(StickDirection::Right));
+
+        // Past the edge plus hysteresis: switches to UpRight.
+        let past = Vec2::from_angle(35f32.to_radians());
+        assert_eq!(stick.update(past), Some(StickDirection::UpRight));
+    }
+
+    #[test]
+    fn test_stick_8way_recenter() {
+        let mut stick = Stick8Way::new(DirectionMode::FourWay).with_recenter(true);
+        assert_eq!(stick.update(Vec2::X), Some(StickDirection::Right));
+        assert_eq!(stick.update(Vec2::Y), None);
+        assert_eq!(stick.update(Vec2::Y), None);
+
+        stick.update(Vec2::ZERO);
+        assert_eq!(stick.update(Vec2::Y), Some(StickDirection::Up));
+    }
+
+    fn spin(twirl: &mut StickTwirl, steps: u16, direction: f32, delta: f32) {
+        for i in 0..=steps {
+            let angle = direction * TAU * f32::from(i) / f32::from(steps);
+            twirl.update(Vec2::from_angle(angle), delta);
+        }
+    }
+
+    #[test]
+    fn test_accumulates_rotation() {
+        let mut twirl = StickTwirl::new(Stick::Left);
+        spin(&mut twirl, 16, 1.0, 1.0 / 16.0);
+
+        assert_relative_eq!(twirl.rotations(), 1.0, epsilon = 1e-4);
+        assert!(twirl.rotations_per_second() > 0.0);
+        assert_eq!(twirl.direction(), Some(TwirlDirection::CounterClockwise));
+    }
+
+    #[test]
+    fn test_clockwise_direction() {
+        let mut twirl = StickTwirl::new(Stick::Left);
+        spin(&mut twirl, 16, -1.0, 1.0 / 16.0);
+
+        assert!(twirl.rotations() < 0.0);
+        assert_eq!(twirl.direction(), Some(TwirlDirection::Clockwise));
+    }
+
+    #[test]
+    fn test_recentering_resets_angle() {
+        let mut twirl = StickTwirl::new(Stick::Left);
+        spin(&mut twirl, 8, 1.0, 0.05);
+        assert!(twirl.angle() > 0.0);
+
+        twirl.update(Vec2::ZERO, 0.05);
+        assert_eq!(twirl.angle(), 0.0);
+    }
+
+    #[test]
+    fn test_charge_normalized() {
+        let mut twirl = StickTwirl::new(Stick::Left).with_max_rotations_per_second(1.0);
+        spin(&mut twirl, 16, 1.0, 1.0 / 64.0);
+        assert_relative_eq!(twirl.charge(), 1.0);
+
+        let idle = StickTwirl::new(Stick::Left);
+        assert_eq!(idle.charge(), 0.0);
+        assert_eq!(idle.direction(), None);
+    }
+}
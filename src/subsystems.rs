@@ -0,0 +1,227 @@
+//! Runtime subsystem toggles.
+//!
+//! Every optional subsystem's systems run only while the subsystem is enabled
+//! in [`EnabledSubsystems`]. Send [`SetSubsystemEnabled`] to switch one off,
+//! e.g. touchpad and gyro processing on the pause menu or during cutscenes.
+//! Device detection and action state updates are always on.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::subsystems::{SetSubsystemEnabled, Subsystem};
+//!
+//! fn enter_cutscene(mut toggles: MessageWriter<SetSubsystemEnabled>) {
+//!     for subsystem in [Subsystem::Gyro, Subsystem::Touchpad] {
+//!         toggles.write(SetSubsystemEnabled {
+//!             subsystem,
+//!             enabled: false,
+//!         });
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// An optional subsystem that can be toggled at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum Subsystem {
+    /// Controller icon updates and animation.
+    Icons,
+    /// Virtual cursor movement and clicks.
+    VirtualCursor,
+    /// Stick-driven momentum scrolling.
+    Scroll,
+    /// Rumble requests and haptic timelines.
+    Haptics,
+    /// Input buffering and combo detection.
+    InputBuffer,
+    /// Controller assignment for local multiplayer.
+    Multiplayer,
+    /// Gyro and accelerometer processing.
+    Gyro,
+    /// Touchpad processing and gestures.
+    Touchpad,
+    /// Action modifier detection (long press, double tap).
+    ActionModifiers,
+    /// Controller model detection and profile loading.
+    Profiles,
+    /// Debug commands and overlay.
+    Debug,
+    /// Hold-to-enable safety gates.
+    Safety,
+    /// UI navigation sounds.
+    UiSound,
+    /// Input diagnostics.
+    Diagnostics,
+    /// Vehicle haptics.
+    VehicleHaptics,
+    /// Throttle and steering controls.
+    VehicleInput,
+    /// Quick-time events.
+    Qte,
+    /// Mash meters.
+    Mash,
+    /// Multi-stage charge actions.
+    Charge,
+    /// Stick twirl detection.
+    Stick,
+    /// Held-action repeat.
+    Repeat,
+    /// Focus navigation.
+    Navigation,
+    /// Player settings profile switching.
+    PlayerSettings,
+    /// Releasing input and stopping rumble on window focus loss.
+    WindowFocus,
+    /// Demo playback while idle.
+    AttractMode,
+    /// Tutorial hints for unused actions.
+    Hints,
+    /// Deadzone recommendations from stick usage.
+    DeadzoneAdvisor,
+    /// Controller characterization sessions.
+    Characterization,
+    /// Releasing input and reopening devices around OS suspend.
+    Suspend,
+    /// Lifetime action usage statistics.
+    ActionUsage,
+    /// Controller lightbar and player LEDs.
+    Leds,
+    /// Battery level reporting and low-battery warnings.
+    Battery,
+    /// Screenshot and clip capture chord.
+    Capture,
+    /// Chord bindings and clash resolution.
+    Chords,
+    /// Expression virtual buttons.
+    VirtualInput,
+    /// Button remapping (`remapping` feature).
+    Remapping,
+    /// Virtual keyboard (`virtual_keyboard` feature).
+    VirtualKeyboard,
+    /// File picker (`virtual_keyboard` feature).
+    FilePicker,
+}
+
+/// Resource tracking which subsystems are enabled.
+///
+/// All subsystems start enabled. While the input pipeline is frozen by
+/// [`InputStepper`](crate::debug::InputStepper), only the debug subsystem
+/// runs.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct EnabledSubsystems {
+    disabled: HashSet<Subsystem>,
+    /// Whether the input pipeline is frozen this frame.
+    paused: bool,
+}
+
+impl EnabledSubsystems {
+    /// Check if a subsystem is enabled.
+    #[must_use]
+    pub fn is_enabled(&self, subsystem: Subsystem) -> bool {
+        !self.disabled.contains(&subsystem)
+    }
+
+    /// Check if the input pipeline is frozen this frame.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Check if a subsystem's systems run this frame.
+    #[must_use]
+    pub fn is_running(&self, subsystem: Subsystem) -> bool {
+        self.is_enabled(subsystem) && (!self.paused || subsystem == Subsystem::Debug)
+    }
+
+    /// Freeze or unfreeze every subsystem but debug.
+    pub(crate) fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Enable or disable a subsystem.
+    pub fn set_enabled(&mut self, subsystem: Subsystem, enabled: bool) {
+        if enabled {
+            self.disabled.remove(&subsystem);
+        } else {
+            self.disabled.insert(subsystem);
+        }
+    }
+}
+
+/// Request to enable or disable a subsystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct SetSubsystemEnabled {
+    /// The subsystem.
+    pub subsystem: Subsystem,
+    /// Whether it should run.
+    pub enabled: bool,
+}
+
+/// Run condition that passes while a subsystem is enabled and the input
+/// pipeline isn't frozen.
+pub fn subsystem_enabled(
+    subsystem: Subsystem,
+) -> impl FnMut(Res<EnabledSubsystems>) -> bool + Clone {
+    move |subsystems: Res<EnabledSubsystems>| subsystems.is_running(subsystem)
+}
+
+/// System to apply subsystem toggle requests.
+pub fn handle_subsystem_toggles(
+    mut requests: MessageReader<SetSubsystemEnabled>,
+    mut subsystems: ResMut<EnabledSubsystems>,
+) {
+    for request in requests.read() {
+        subsystems.set_enabled(request.subsystem, request.enabled);
+    }
+}
+
+/// Plugin for registering subsystem types.
+pub(crate) fn register_subsystem_types(app: &mut App) {
+    app.register_type::<Subsystem>()
+        .init_resource::<EnabledSubsystems>()
+        .add_message::<SetSubsystemEnabled>();
+}
+
+/// Add subsystem systems to the app.
+pub(crate) fn add_subsystem_systems(app: &mut App) {
+    // Apply toggles before any subsystem runs this frame.
+    app.add_systems(First, handle_subsystem_toggles);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsystems_start_enabled() {
+        let subsystems = EnabledSubsystems::default();
+        assert!(subsystems.is_enabled(Subsystem::Gyro));
+        assert!(subsystems.is_enabled(Subsystem::Touchpad));
+    }
+
+    #[test]
+    fn test_set_enabled() {
+        let mut subsystems = EnabledSubsystems::default();
+        subsystems.set_enabled(Subsystem::Gyro, false);
+        assert!(!subsystems.is_enabled(Subsystem::Gyro));
+        assert!(subsystems.is_enabled(Subsystem::Touchpad));
+
+        subsystems.set_enabled(Subsystem::Gyro, true);
+        assert!(subsystems.is_enabled(Subsystem::Gyro));
+    }
+
+    #[test]
+    fn test_paused_runs_only_debug() {
+        let mut subsystems = EnabledSubsystems::default();
+        subsystems.set_paused(true);
+        assert!(subsystems.is_enabled(Subsystem::Gyro));
+        assert!(!subsystems.is_running(Subsystem::Gyro));
+        assert!(subsystems.is_running(Subsystem::Debug));
+
+        subsystems.set_enabled(Subsystem::Debug, false);
+        assert!(!subsystems.is_running(Subsystem::Debug));
+    }
+}
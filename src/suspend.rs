@@ -0,0 +1,268 @@
+//! Input handling across OS suspend and resume.
+//!
+//! Handhelds sleep and wake constantly. When the app is suspended, either
+//! reported through [`AppLifecycle`] or detected as a long gap between frames,
+//! every action is released, the input buffer is flushed, and rumble stops.
+//! On resume, [`InputSystemResumed`] fires, input is ignored for a short
+//! grace period, and buttons held through the wake stay released until the
+//! player lets go. With the `motion-backends` feature, HID backends are also
+//! reopened, since their handles rarely survive sleep.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::suspend::InputSystemResumed;
+//!
+//! fn pause_on_wake(mut resumed: MessageReader<InputSystemResumed>) {
+//!     for event in resumed.read() {
+//!         info!("Woke after {:.0}s; showing pause menu", event.suspended_for);
+//!     }
+//! }
+//! ```
+
+use bevy::input::gamepad::GamepadRumbleRequest;
+use bevy::prelude::*;
+use bevy::window::AppLifecycle;
+use std::collections::HashSet;
+
+use crate::actions::{ActionState, GameAction};
+use crate::haptics::{RumbleController, RumbleOutput};
+use crate::input_buffer::InputBuffer;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Settings for suspend detection and resume behavior.
+#[derive(Debug, Clone, Resource)]
+pub struct SuspendSettings {
+    /// Frame gap in seconds treated as a suspend the app wasn't told about.
+    pub frame_gap: f32,
+    /// Seconds to ignore input after resuming.
+    pub resume_grace: f32,
+}
+
+impl Default for SuspendSettings {
+    fn default() -> Self {
+        Self {
+            frame_gap: 5.0,
+            resume_grace: 0.25,
+        }
+    }
+}
+
+/// Resource tracking whether the app is suspended.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct SuspendState {
+    /// Real time the suspend started, while suspended.
+    suspended_at: Option<f64>,
+    /// Seconds of grace left after resuming.
+    grace_remaining: f32,
+    /// Actions held through the wake, released until let go.
+    held: HashSet<GameAction>,
+}
+
+impl SuspendState {
+    /// Whether the app is suspended.
+    #[must_use]
+    pub const fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    /// Whether input is being ignored: suspended or within the grace period.
+    #[must_use]
+    pub fn is_ignoring_input(&self) -> bool {
+        self.is_suspended() || self.grace_remaining > 0.0
+    }
+
+    /// Record a suspend at `now`, returning whether the state changed.
+    pub fn suspend(&mut self, now: f64) -> bool {
+        if self.is_suspended() {
+            return false;
+        }
+        self.suspended_at = Some(now);
+        true
+    }
+
+    /// Record a resume at `now`, returning how long the app was suspended.
+    pub fn resume(&mut self, now: f64, settings: &SuspendSettings) -> Option<f32> {
+        let suspended_at = self.suspended_at.take()?;
+        self.grace_remaining = settings.resume_grace.max(0.0);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "suspend durations fit comfortably in f32"
+        )]
+        Some((now - suspended_at).max(0.0) as f32)
+    }
+
+    /// Advance the grace period by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        if !self.is_suspended() {
+            self.grace_remaining = (self.grace_remaining - delta).max(0.0);
+        }
+    }
+
+    /// Route an action's pressed state, returning whether to release it.
+    pub fn filter(&mut self, action: GameAction, pressed: bool) -> bool {
+        if !pressed {
+            self.held.remove(&action);
+            return false;
+        }
+
+        if self.is_ignoring_input() {
+            self.held.insert(action);
+            return true;
+        }
+
+        self.held.contains(&action)
+    }
+}
+
+/// Event fired when the app is suspended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct InputSystemSuspended;
+
+/// Event fired when the app resumes from suspend.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct InputSystemResumed {
+    /// Seconds the app was suspended.
+    pub suspended_for: f32,
+}
+
+/// System to track suspend and resume and release input around them.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn track_suspend(
+    settings: Res<SuspendSettings>,
+    mut state: ResMut<SuspendState>,
+    real_time: Res<Time<Real>>,
+    mut lifecycle: MessageReader<AppLifecycle>,
+    mut action_state: ResMut<ActionState>,
+    mut input_buffer: ResMut<InputBuffer>,
+    mut suspended: MessageWriter<InputSystemSuspended>,
+    mut resumed: MessageWriter<InputSystemResumed>,
+) {
+    let now = real_time.elapsed_secs_f64();
+    let delta = real_time.delta_secs();
+    let mut flush = false;
+
+    for event in lifecycle.read() {
+        match event {
+            AppLifecycle::WillSuspend | AppLifecycle::Suspended => {
+                if state.suspend(now) {
+                    suspended.write(InputSystemSuspended);
+                    flush = true;
+                }
+            }
+            AppLifecycle::WillResume | AppLifecycle::Running => {
+                if let Some(suspended_for) = state.resume(now, &settings) {
+                    resumed.write(InputSystemResumed { suspended_for });
+                    flush = true;
+                }
+            }
+            AppLifecycle::Idle => {}
+        }
+    }
+
+    // Desktop platforms rarely report sleep; a long frame means we missed it
+    if delta > settings.frame_gap && !state.is_suspended() {
+        state.suspend(now - f64::from(delta));
+        if let Some(suspended_for) = state.resume(now, &settings) {
+            resumed.write(InputSystemResumed { suspended_for });
+            flush = true;
+        }
+    } else {
+        state.tick(delta);
+    }
+
+    if flush {
+        input_buffer.clear();
+    }
+    for action in GameAction::all() {
+        if state.filter(*action, action_state.pressed(*action)) {
+            action_state.suppress(*action, "suspended");
+        }
+    }
+}
+
+/// System to stop rumble on suspend and resume.
+pub fn stop_rumble_on_suspend(
+    mut suspended: MessageReader<InputSystemSuspended>,
+    mut resumed: MessageReader<InputSystemResumed>,
+    mut controllers: Query<&mut RumbleController>,
+    mut gamepads: Query<(Entity, Option<&mut RumbleOutput>), With<Gamepad>>,
+    mut rumble_requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    let changed = suspended.read().count() + resumed.read().count() > 0;
+    if !changed {
+        return;
+    }
+
+    for mut controller in &mut controllers {
+        controller.stop();
+    }
+    for (gamepad, output) in &mut gamepads {
+        match output {
+            Some(mut output) => output.stop(),
+            None => {
+                rumble_requests.write(GamepadRumbleRequest::Stop { gamepad });
+            }
+        }
+    }
+}
+
+/// Plugin for registering suspend types.
+pub(crate) fn register_suspend_types(app: &mut App) {
+    app.init_resource::<SuspendSettings>()
+        .init_resource::<SuspendState>()
+        .add_message::<AppLifecycle>()
+        .add_message::<InputSystemSuspended>()
+        .add_message::<InputSystemResumed>();
+}
+
+/// Add suspend systems to the app.
+pub(crate) fn add_suspend_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.input,
+        track_suspend
+            .after(crate::actions::update_action_state::<GameAction>)
+            .before(crate::safety::apply_safety_gates)
+            .before(crate::stick::update_stick_twirls)
+            .before(crate::repeat::update_action_repeat)
+            .run_if(subsystem_enabled(Subsystem::Suspend)),
+    )
+    .add_systems(
+        schedules.update,
+        stop_rumble_on_suspend
+            .after(crate::haptics::update_rumble)
+            .after(crate::haptics::update_haptic_timelines)
+            .before(crate::haptics::send_rumble_output)
+            .run_if(subsystem_enabled(Subsystem::Suspend)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspend_and_resume() {
+        let settings = SuspendSettings::default();
+        let mut state = SuspendState::default();
+        assert_eq!(state.resume(1.0, &settings), None);
+
+        assert!(state.suspend(10.0));
+        assert!(!state.suspend(11.0));
+        assert!(state.filter(GameAction::Confirm, true));
+
+        assert_eq!(state.resume(40.0, &settings), Some(30.0));
+        assert!(state.is_ignoring_input());
+        state.tick(0.25);
+        assert!(!state.is_ignoring_input());
+
+        // Held through the wake until released
+        assert!(state.filter(GameAction::Confirm, true));
+        assert!(!state.filter(GameAction::Confirm, false));
+        assert!(!state.filter(GameAction::Confirm, true));
+    }
+}
@@ -399,6 +399,153 @@ impl MockInputSequence {
     pub fn frames(&self) -> &[MockInputFrame] {
         &self.frames
     }
+
+    /// Generate a reproducible random sequence for fuzzing.
+    ///
+    /// The same `seed`, `length`, and `constraints` always produce the same
+    /// frames, so a failing fuzz case can be replayed by logging its seed.
+    #[must_use]
+    pub fn random(seed: u64, length: usize, constraints: &RandomInputConstraints) -> Self {
+        let mut rng = SeededRng::new(seed);
+        let mut held: Vec<GamepadButton> = Vec::new();
+        let mut sequence = Self::new();
+
+        for _ in 0..length {
+            let mut frame = MockInputFrame::default();
+
+            // Release some held buttons
+            held.retain(|button| {
+                if rng.next_f32() < constraints.release_probability {
+                    frame.release_gamepad.push(*button);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            // Press new buttons up to the simultaneous limit
+            if !constraints.buttons.is_empty() {
+                while held.len() < constraints.max_simultaneous
+                    && rng.next_f32() < constraints.press_probability
+                {
+                    let button = constraints.buttons[rng.next_index(constraints.buttons.len())];
+                    if held.contains(&button) {
+                        break;
+                    }
+                    held.push(button);
+                    frame.press_gamepad.push(button);
+                }
+            }
+
+            // Move the left stick within the allowed angle range
+            if let Some((min_angle, max_angle)) = constraints.stick_angle_range {
+                let angle = (min_angle + (max_angle - min_angle) * rng.next_f32()).to_radians();
+                let magnitude = rng.next_f32();
+                frame
+                    .axis_values
+                    .push((GamepadAxis::LeftStickX, angle.cos() * magnitude));
+                frame
+                    .axis_values
+                    .push((GamepadAxis::LeftStickY, angle.sin() * magnitude));
+            }
+
+            sequence.frames.push(frame);
+        }
+
+        sequence
+    }
+}
+
+/// Constraints for [`MockInputSequence::random`].
+#[derive(Debug, Clone)]
+pub struct RandomInputConstraints {
+    /// Buttons the generator may press.
+    pub buttons: Vec<GamepadButton>,
+    /// Maximum number of buttons held at the same time.
+    pub max_simultaneous: usize,
+    /// Chance per frame of pressing another button (0.0 - 1.0).
+    pub press_probability: f32,
+    /// Chance per frame of releasing each held button (0.0 - 1.0).
+    pub release_probability: f32,
+    /// Allowed left stick angles in degrees, or `None` to leave the stick alone.
+    pub stick_angle_range: Option<(f32, f32)>,
+}
+
+impl Default for RandomInputConstraints {
+    fn default() -> Self {
+        Self {
+            buttons: vec![
+                GamepadButton::South,
+                GamepadButton::East,
+                GamepadButton::West,
+                GamepadButton::North,
+            ],
+            max_simultaneous: 2,
+            press_probability: 0.3,
+            release_probability: 0.5,
+            stick_angle_range: None,
+        }
+    }
+}
+
+impl RandomInputConstraints {
+    /// Restrict generation to a subset of buttons.
+    #[must_use]
+    pub fn with_buttons(mut self, buttons: impl IntoIterator<Item = GamepadButton>) -> Self {
+        self.buttons = buttons.into_iter().collect();
+        self
+    }
+
+    /// Set the maximum number of buttons held at once.
+    #[must_use]
+    pub fn with_max_simultaneous(mut self, max: usize) -> Self {
+        self.max_simultaneous = max;
+        self
+    }
+
+    /// Allow left stick movement within an angle range in degrees.
+    #[must_use]
+    pub fn with_stick_angles(mut self, min_degrees: f32, max_degrees: f32) -> Self {
+        self.stick_angle_range = Some((min_degrees, max_degrees));
+        self
+    }
+}
+
+/// Small deterministic PRNG (`SplitMix64`) so fuzzed sequences are
+/// reproducible without extra dependencies.
+#[derive(Debug, Clone)]
+struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "24 random bits fit exactly in an f32 mantissa"
+    )]
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "result is reduced modulo a usize length"
+    )]
+    fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
 }
 
 /// Helper for running mock input sequences in tests.
@@ -556,6 +703,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_random_sequence_is_reproducible() {
+        let constraints = RandomInputConstraints::default().with_stick_angles(0.0, 90.0);
+        let a = MockInputSequence::random(42, 50, &constraints);
+        let b = MockInputSequence::random(42, 50, &constraints);
+
+        assert_eq!(a.len(), 50);
+        for (fa, fb) in a.frames().iter().zip(b.frames()) {
+            assert_eq!(fa.press_gamepad, fb.press_gamepad);
+            assert_eq!(fa.release_gamepad, fb.release_gamepad);
+            assert_eq!(fa.axis_values, fb.axis_values);
+        }
+    }
+
+    #[test]
+    fn test_random_sequence_respects_constraints() {
+        let constraints = RandomInputConstraints::default()
+            .with_buttons([GamepadButton::South, GamepadButton::DPadUp])
+            .with_max_simultaneous(1)
+            .with_stick_angles(0.0, 90.0);
+        let sequence = MockInputSequence::random(7, 200, &constraints);
+
+        let mut held = 0usize;
+        for frame in sequence.frames() {
+            held -= frame.release_gamepad.len();
+            held += frame.press_gamepad.len();
+            assert!(held <= 1);
+
+            for button in &frame.press_gamepad {
+                assert!(constraints.buttons.contains(button));
+            }
+            for (_, value) in &frame.axis_values {
+                assert!(*value >= -1e-6);
+            }
+        }
+    }
+
     #[test]
     fn test_mock_input_mouse() {
         let mut mock = MockInput::new();
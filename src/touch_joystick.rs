@@ -30,6 +30,7 @@ impl Plugin for TouchJoystickPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<TouchJoystick>()
             .register_type::<TouchJoystickSettings>()
+            .register_type::<SafeAreaInsets>()
             .init_resource::<TouchJoystickSettings>()
             .add_message::<TouchJoystickEvent>()
             .add_systems(
@@ -40,7 +41,9 @@ impl Plugin for TouchJoystickPlugin {
 }
 
 /// A virtual joystick component for touch input.
-#[derive(Component, Debug, Clone, Reflect)]
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default)]
+#[serde(default)]
 pub struct TouchJoystick {
     /// Base position of the joystick (center of the base)
     pub base_position: Vec2,
@@ -58,8 +61,14 @@ pub struct TouchJoystick {
     pub side: JoystickSide,
     /// Whether the base follows the initial touch
     pub floating: bool,
+    /// Region a floating joystick must stay inside, in logical pixels
+    pub float_bounds: Option<Rect>,
+    /// Whether the base drifts after a finger dragged past the radius
+    pub follow: bool,
     /// Whether to snap back to center on release
     pub snap_to_center: bool,
+    /// Layout scale applied to `radius`, updated from the window each frame
+    pub scale: f32,
 }
 
 impl Default for TouchJoystick {
@@ -73,7 +82,10 @@ impl Default for TouchJoystick {
             touch_id: None,
             side: JoystickSide::Left,
             floating: true,
+            float_bounds: None,
+            follow: false,
             snap_to_center: true,
+            scale: 1.0,
         }
     }
 }
@@ -107,6 +119,28 @@ impl TouchJoystick {
         }
     }
 
+    /// Create a floating joystick confined to a screen region.
+    ///
+    /// The base appears under the initial touch, clamped so the joystick
+    /// stays inside `bounds`, and follows the finger when it drags past the
+    /// radius, like the movement stick in most mobile shooters.
+    #[must_use]
+    pub fn bounded_floating(bounds: Rect) -> Self {
+        Self {
+            floating: true,
+            float_bounds: Some(bounds),
+            follow: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set whether the base follows a finger dragged past the radius.
+    #[must_use]
+    pub fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
     /// Set the radius.
     #[must_use]
     pub fn with_radius(mut self, radius: f32) -> Self {
@@ -121,6 +155,31 @@ impl TouchJoystick {
         self
     }
 
+    /// Radius in logical pixels after layout scaling.
+    #[must_use]
+    pub fn effective_radius(&self) -> f32 {
+        self.radius * self.scale
+    }
+
+    /// Region the base may occupy within the playable area.
+    #[must_use]
+    pub fn base_bounds(&self, playable: Rect) -> Rect {
+        self.float_bounds
+            .map_or(playable, |bounds| bounds.intersect(playable))
+    }
+
+    /// Move the knob toward a touch, dragging the base along if following.
+    pub fn drag_to(&mut self, touch_pos: Vec2, playable: Rect) {
+        let radius = self.effective_radius();
+        let mut offset = touch_pos - self.base_position;
+        if self.follow && offset.length() > radius {
+            let target = touch_pos - offset.normalize() * radius;
+            self.base_position = clamp_base(target, self.base_bounds(playable), radius);
+            offset = touch_pos - self.base_position;
+        }
+        self.knob_offset = offset.clamp_length_max(radius);
+    }
+
     /// Get the normalized axis value (-1 to 1 for each component).
     #[must_use]
     pub fn axis(&self) -> Vec2 {
@@ -128,7 +187,7 @@ impl TouchJoystick {
             return Vec2::ZERO;
         }
 
-        let magnitude = self.knob_offset.length() / self.radius;
+        let magnitude = self.knob_offset.length() / self.effective_radius();
         if magnitude < self.deadzone {
             return Vec2::ZERO;
         }
@@ -145,13 +204,13 @@ impl TouchJoystick {
         if !self.active {
             return Vec2::ZERO;
         }
-        self.knob_offset / self.radius
+        self.knob_offset / self.effective_radius()
     }
 
     /// Get the distance from center as a percentage (0 to 1).
     #[must_use]
     pub fn magnitude(&self) -> f32 {
-        (self.knob_offset.length() / self.radius).min(1.0)
+        (self.knob_offset.length() / self.effective_radius()).min(1.0)
     }
 
     /// Get the angle of the joystick in radians (0 = right, PI/2 = up).
@@ -198,6 +257,16 @@ pub struct TouchJoystickSettings {
     pub knob_color: Color,
     /// Margin from screen edge for floating joysticks
     pub screen_margin: f32,
+    /// Scale radii, margins, and visuals to the screen
+    pub auto_scale: bool,
+    /// Logical pixels per inch that layouts were designed at
+    pub reference_dpi: f32,
+    /// Physical screen diagonal in inches, if the platform reports it
+    pub screen_diagonal_inches: Option<f32>,
+    /// Largest joystick radius as a fraction of the shorter window side
+    pub max_radius_fraction: f32,
+    /// Notch and system UI insets to keep joysticks out of
+    pub safe_area: SafeAreaInsets,
 }
 
 impl Default for TouchJoystickSettings {
@@ -210,10 +279,78 @@ impl Default for TouchJoystickSettings {
             base_color: Color::srgba(0.3, 0.3, 0.3, 0.5),
             knob_color: Color::srgba(0.8, 0.8, 0.8, 0.7),
             screen_margin: 50.0,
+            auto_scale: true,
+            reference_dpi: 96.0,
+            screen_diagonal_inches: None,
+            max_radius_fraction: 0.25,
+            safe_area: SafeAreaInsets::default(),
+        }
+    }
+}
+
+impl TouchJoystickSettings {
+    /// Layout scale for a joystick of `radius` in a window.
+    ///
+    /// With a screen size hint, radii keep the physical size they had at
+    /// [`Self::reference_dpi`]; the result is capped so a joystick never
+    /// exceeds [`Self::max_radius_fraction`] of the screen.
+    #[must_use]
+    pub fn layout_scale(&self, physical_size: Vec2, scale_factor: f32, radius: f32) -> f32 {
+        if !self.auto_scale || scale_factor <= 0.0 {
+            return 1.0;
+        }
+
+        let logical_size = physical_size / scale_factor;
+        let mut scale = 1.0;
+        if let Some(diagonal) = self.screen_diagonal_inches.filter(|inches| *inches > 0.0) {
+            let logical_dpi = logical_size.length() / diagonal;
+            scale = logical_dpi / self.reference_dpi;
         }
+
+        let max_radius = logical_size.min_element() * self.max_radius_fraction;
+        if radius > 0.0 && radius * scale > max_radius {
+            scale = max_radius / radius;
+        }
+        scale
+    }
+
+    /// Area floating joystick bases may occupy, in logical pixels.
+    #[must_use]
+    pub fn playable_rect(&self, window_size: Vec2, scale: f32) -> Rect {
+        let margin = self.screen_margin * scale;
+        let insets = self.safe_area;
+        Rect::new(
+            insets.left + margin,
+            insets.top + margin,
+            window_size.x - insets.right - margin,
+            window_size.y - insets.bottom - margin,
+        )
     }
 }
 
+/// Screen insets for notches, rounded corners, and system bars.
+///
+/// Values are in logical pixels and are usually copied from the platform's
+/// safe-area API at startup and on rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+pub struct SafeAreaInsets {
+    /// Inset from the top edge
+    pub top: f32,
+    /// Inset from the bottom edge
+    pub bottom: f32,
+    /// Inset from the left edge
+    pub left: f32,
+    /// Inset from the right edge
+    pub right: f32,
+}
+
+/// Clamp a base position so the whole joystick stays inside `rect`.
+fn clamp_base(position: Vec2, rect: Rect, radius: f32) -> Vec2 {
+    position
+        .max(rect.min + Vec2::splat(radius))
+        .min(rect.max - Vec2::splat(radius))
+}
+
 /// Event emitted when a joystick value changes.
 #[derive(Event, Message, Debug, Clone)]
 pub struct TouchJoystickEvent {
@@ -232,6 +369,7 @@ pub struct TouchJoystickEvent {
 /// System to update touch joysticks based on touch input.
 fn update_touch_joysticks(
     touches: Res<Touches>,
+    settings: Res<TouchJoystickSettings>,
     windows: Query<&Window>,
     mut joysticks: Query<&mut TouchJoystick>,
 ) {
@@ -239,21 +377,26 @@ fn update_touch_joysticks(
         return;
     };
     let window_size = Vec2::new(window.width(), window.height());
+    let physical_size = Vec2::new(
+        window.physical_width() as f32,
+        window.physical_height() as f32,
+    );
     let half_width = window_size.x / 2.0;
 
     for mut joystick in &mut joysticks {
+        // Follow window resizes and DPI changes
+        let scale = settings.layout_scale(physical_size, window.scale_factor(), joystick.radius);
+        if joystick.scale != scale {
+            joystick.scale = scale;
+        }
+        let radius = joystick.effective_radius();
+        let playable = settings.playable_rect(window_size, scale);
+
         // Check if our current touch is still active
         if let Some(touch_id) = joystick.touch_id {
             if let Some(touch) = touches.get_pressed(touch_id) {
                 // Update knob position
-                let touch_pos = touch.position();
-                let offset = touch_pos - joystick.base_position;
-                let clamped_offset = if offset.length() > joystick.radius {
-                    offset.normalize() * joystick.radius
-                } else {
-                    offset
-                };
-                joystick.knob_offset = clamped_offset;
+                joystick.drag_to(touch.position(), playable);
             } else {
                 // Touch released
                 joystick.active = false;
@@ -274,7 +417,7 @@ fn update_touch_joysticks(
                     JoystickSide::Full => true,
                     JoystickSide::Custom => {
                         let distance = (touch_pos - joystick.base_position).length();
-                        distance <= joystick.radius * 2.0
+                        distance <= radius * 2.0
                     }
                 };
 
@@ -282,10 +425,12 @@ fn update_touch_joysticks(
                     joystick.active = true;
                     joystick.touch_id = Some(touch.id());
 
+                    joystick.knob_offset = Vec2::ZERO;
                     if joystick.floating {
-                        joystick.base_position = touch_pos;
+                        let bounds = joystick.base_bounds(playable);
+                        joystick.base_position = clamp_base(touch_pos, bounds, radius);
+                        joystick.drag_to(touch_pos, playable);
                     }
-                    joystick.knob_offset = Vec2::ZERO;
                     break;
                 }
             }
@@ -327,7 +472,7 @@ pub fn spawn_joystick_visual(
         .spawn((
             Sprite {
                 color: settings.base_color.with_alpha(settings.opacity),
-                custom_size: Some(Vec2::splat(joystick.radius * 2.0)),
+                custom_size: Some(Vec2::splat(joystick.effective_radius() * 2.0)),
                 ..default()
             },
             Transform::from_translation(joystick.base_position.extend(0.0)),
@@ -340,7 +485,7 @@ pub fn spawn_joystick_visual(
         parent.spawn((
             Sprite {
                 color: settings.knob_color.with_alpha(settings.opacity),
-                custom_size: Some(Vec2::splat(joystick.radius * 0.6)),
+                custom_size: Some(Vec2::splat(joystick.effective_radius() * 0.6)),
                 ..default()
             },
             Transform::default(),
@@ -494,4 +639,85 @@ mod tests {
         joystick.knob_offset = Vec2::new(150.0, 0.0);
         assert!((joystick.magnitude() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_layout_scale_from_screen_hint() {
+        let settings = TouchJoystickSettings {
+            screen_diagonal_inches: Some(6.0),
+            ..Default::default()
+        };
+
+        // 1170x2532 phone at 3x: ~464 logical px over 6 inches
+        let scale = settings.layout_scale(Vec2::new(1170.0, 2532.0), 3.0, 50.0);
+        assert!(scale > 1.0);
+
+        // Capped to a quarter of the shorter side
+        let capped = settings.layout_scale(Vec2::new(1170.0, 2532.0), 3.0, 100.0);
+        assert!((100.0 * capped - 97.5).abs() < 0.01);
+
+        let fixed = TouchJoystickSettings {
+            auto_scale: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            fixed.layout_scale(Vec2::new(1170.0, 2532.0), 3.0, 100.0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_floating_base_respects_safe_area() {
+        let settings = TouchJoystickSettings {
+            screen_margin: 10.0,
+            safe_area: SafeAreaInsets {
+                left: 40.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let playable = settings.playable_rect(Vec2::new(800.0, 400.0), 1.0);
+        let base = clamp_base(Vec2::new(5.0, 390.0), playable, 50.0);
+        assert_eq!(base, Vec2::new(100.0, 340.0));
+    }
+
+    #[test]
+    fn test_effective_radius_scales_axis() {
+        let mut joystick = TouchJoystick::default().with_deadzone(0.0);
+        joystick.active = true;
+        joystick.scale = 2.0;
+        joystick.knob_offset = Vec2::new(100.0, 0.0);
+        assert!((joystick.magnitude() - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_bounded_floating_follows_and_clamps() {
+        let bounds = Rect::new(0.0, 200.0, 400.0, 600.0);
+        let playable = Rect::new(0.0, 0.0, 1000.0, 600.0);
+        let mut joystick = TouchJoystick::bounded_floating(bounds).with_radius(50.0);
+        joystick.active = true;
+
+        // Touch outside the region: base clamped inside, knob points at finger
+        joystick.base_position = clamp_base(Vec2::new(100.0, 100.0), bounds, 50.0);
+        joystick.drag_to(Vec2::new(100.0, 100.0), playable);
+        assert_eq!(joystick.base_position, Vec2::new(100.0, 250.0));
+        assert!((joystick.magnitude() - 1.0).abs() < 0.001);
+
+        // Drag past the radius: base drifts toward the finger
+        joystick.drag_to(Vec2::new(250.0, 250.0), playable);
+        assert_eq!(joystick.base_position, Vec2::new(200.0, 250.0));
+        assert_eq!(joystick.knob_offset, Vec2::new(50.0, 0.0));
+
+        // Drift stops at the region edge
+        joystick.drag_to(Vec2::new(900.0, 250.0), playable);
+        assert_eq!(joystick.base_position, Vec2::new(350.0, 250.0));
+        assert_eq!(joystick.knob_offset, Vec2::new(50.0, 0.0));
+    }
+
+    #[test]
+    fn test_fixed_joystick_does_not_follow() {
+        let mut joystick = TouchJoystick::fixed(Vec2::new(100.0, 100.0));
+        joystick.drag_to(Vec2::new(400.0, 100.0), Rect::new(0.0, 0.0, 800.0, 600.0));
+        assert_eq!(joystick.base_position, Vec2::new(100.0, 100.0));
+        assert_eq!(joystick.knob_offset, Vec2::new(100.0, 0.0));
+    }
 }
@@ -1,8 +1,20 @@
 //! `PlayStation` controller touchpad support.
 //!
 //! This module provides touchpad input for PS4 `DualShock` 4 and PS5 `DualSense` controllers.
+//! Any number of fingers are tracked by ID, and gestures include one-, two-,
+//! and three-finger taps, swipes, edge swipes, pinches, and two-finger twists. Detection lives in the
+//! source-agnostic [`GestureEngine`], which [`TouchscreenGesturePlugin`] also
+//! drives from a device touchscreen. [`TouchpadRegions`] turns the touchpad
+//! into a virtual d-pad or button grid.
 
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::HashMap;
+
+use crate::actions::GameAction;
+use crate::haptics::{HapticFeedback, RumbleRequest};
+use crate::simulated_input::SimulatedInput;
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 // ========== Touchpad Hardware Specifications ==========
 // Sourced from PS4/PS5 HID descriptors and verified against Joypad OS implementation
@@ -71,14 +83,14 @@ pub struct TouchFinger {
     pub y: f32,
     /// Whether this finger is currently touching.
     pub active: bool,
-    /// Unique finger ID.
-    pub id: u8,
+    /// Finger ID, stable for as long as the finger stays down.
+    pub id: u64,
 }
 
 impl TouchFinger {
     /// Create a new touch finger.
     #[must_use]
-    pub fn new(id: u8, x: f32, y: f32) -> Self {
+    pub fn new(id: u64, x: f32, y: f32) -> Self {
         Self {
             x: x.clamp(0.0, 1.0),
             y: y.clamp(0.0, 1.0),
@@ -94,67 +106,130 @@ impl TouchFinger {
     }
 }
 
+/// A contact in progress, from the first finger down to the last finger up.
+#[derive(Debug, Clone, Copy, Default, Reflect)]
+struct TouchSession {
+    /// Seconds since the first finger touched.
+    elapsed: f32,
+    /// Most fingers down at once.
+    max_fingers: usize,
+    /// Distance the fingers' centroid has traveled.
+    travel: f32,
+    /// Last centroid position.
+    position: Vec2,
+}
+
+/// A single-finger contact that started on a touchpad edge.
+#[derive(Debug, Clone, Copy, Reflect)]
+struct EdgeContact {
+    /// Finger that started on the edge.
+    finger: u64,
+    /// Edge swipe gesture this contact can produce.
+    gesture: TouchpadGesture,
+    /// Where the finger touched down.
+    origin: Vec2,
+    /// Whether the edge swipe already fired for this contact.
+    fired: bool,
+}
+
 /// Touchpad state for a gamepad.
-#[derive(Debug, Clone, Component, Reflect)]
+///
+/// Tracks any number of fingers by ID, so the same data drives controller
+/// touchpads and device touchscreens.
+#[derive(Debug, Clone, Default, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct TouchpadData {
-    /// First finger/touch point.
-    pub finger1: TouchFinger,
-    /// Second finger/touch point (for multi-touch).
-    pub finger2: TouchFinger,
+    /// Touching fingers, in the order they went down.
+    pub fingers: Vec<TouchFinger>,
     /// Whether the touchpad button is pressed.
     pub button_pressed: bool,
-    /// Previous frame's first finger position.
-    pub prev_finger1: Vec2,
-    /// Previous frame's second finger position.
-    pub prev_finger2: Vec2,
+    /// Finger positions at the last frame update, by finger ID.
+    pub previous: HashMap<u64, Vec2>,
+    /// Contact in progress, for tap detection.
+    session: Option<TouchSession>,
+    /// Contact that started on an edge, for edge swipe detection.
+    edge_contact: Option<EdgeContact>,
 }
 
-impl Default for TouchpadData {
-    fn default() -> Self {
-        Self {
-            finger1: TouchFinger::default(),
-            finger2: TouchFinger::default(),
-            button_pressed: false,
-            prev_finger1: Vec2::ZERO,
-            prev_finger2: Vec2::ZERO,
+impl TouchpadData {
+    /// Get a touching finger by ID.
+    #[must_use]
+    pub fn finger(&self, id: u64) -> Option<&TouchFinger> {
+        self.fingers.iter().find(|finger| finger.id == id)
+    }
+
+    /// Get the first finger that is still touching.
+    #[must_use]
+    pub fn primary(&self) -> Option<&TouchFinger> {
+        self.fingers.first()
+    }
+
+    /// Get the delta movement of a finger since the last frame.
+    #[must_use]
+    pub fn delta(&self, id: u64) -> Vec2 {
+        match (self.finger(id), self.previous.get(&id)) {
+            (Some(finger), Some(previous)) => finger.position() - *previous,
+            _ => Vec2::ZERO,
         }
     }
-}
 
-impl TouchpadData {
-    /// Get the delta movement of finger 1.
+    /// Get the delta movement of the primary finger.
     #[must_use]
-    pub fn finger1_delta(&self) -> Vec2 {
-        if !self.finger1.active {
-            return Vec2::ZERO;
+    pub fn primary_delta(&self) -> Vec2 {
+        self.primary()
+            .map_or(Vec2::ZERO, |finger| self.delta(finger.id))
+    }
+
+    /// Get the average position of all touching fingers.
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "finger counts are far below f32 precision limits"
+    )]
+    pub fn centroid(&self) -> Option<Vec2> {
+        if self.fingers.is_empty() {
+            return None;
         }
-        self.finger1.position() - self.prev_finger1
+        let sum: Vec2 = self.fingers.iter().map(TouchFinger::position).sum();
+        Some(sum / self.fingers.len() as f32)
     }
 
-    /// Get the delta movement of finger 2.
+    /// Get the average delta of fingers that were also down last frame.
     #[must_use]
-    pub fn finger2_delta(&self) -> Vec2 {
-        if !self.finger2.active {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "finger counts are far below f32 precision limits"
+    )]
+    pub fn centroid_delta(&self) -> Vec2 {
+        let deltas: Vec<Vec2> = self
+            .fingers
+            .iter()
+            .filter(|finger| self.previous.contains_key(&finger.id))
+            .map(|finger| self.delta(finger.id))
+            .collect();
+        if deltas.is_empty() {
             return Vec2::ZERO;
         }
-        self.finger2.position() - self.prev_finger2
+        deltas.iter().sum::<Vec2>() / deltas.len() as f32
     }
 
     /// Check if a swipe gesture is detected.
     #[must_use]
     pub fn is_swiping(&self, threshold: f32) -> bool {
-        self.finger1_delta().length() > threshold
+        self.primary_delta().length() > threshold
     }
 
     /// Check if a pinch gesture is detected (two fingers moving apart/together).
     #[must_use]
     pub fn is_pinching(&self) -> Option<f32> {
-        if !self.finger1.active || !self.finger2.active {
+        let [first, second] = self.fingers.get(..2)? else {
             return None;
-        }
+        };
+        let prev_first = self.previous.get(&first.id)?;
+        let prev_second = self.previous.get(&second.id)?;
 
-        let current_dist = self.finger1.position().distance(self.finger2.position());
-        let prev_dist = self.prev_finger1.distance(self.prev_finger2);
+        let current_dist = first.position().distance(second.position());
+        let prev_dist = prev_first.distance(*prev_second);
         let delta = current_dist - prev_dist;
 
         if delta.abs() > 0.01 {
@@ -164,35 +239,58 @@ impl TouchpadData {
         }
     }
 
-    /// Get the number of active fingers.
+    /// Check if a twist gesture is detected (two fingers rotating around
+    /// their midpoint).
+    ///
+    /// Returns the signed angle in radians the fingers turned since the last
+    /// frame. Positive angles are clockwise as seen on the touchpad, whose Y
+    /// axis points down.
     #[must_use]
-    pub fn active_fingers(&self) -> u8 {
-        let mut count = 0;
-        if self.finger1.active {
-            count += 1;
+    pub fn is_twisting(&self) -> Option<f32> {
+        let [first, second] = self.fingers.get(..2)? else {
+            return None;
+        };
+        let prev_first = self.previous.get(&first.id)?;
+        let prev_second = self.previous.get(&second.id)?;
+
+        let current = second.position() - first.position();
+        let prev = *prev_second - *prev_first;
+        if current.length() < 0.01 || prev.length() < 0.01 {
+            return None;
         }
-        if self.finger2.active {
-            count += 1;
+
+        let angle = prev.angle_to(current);
+        if angle.abs() > 0.02 {
+            Some(angle)
+        } else {
+            None
         }
-        count
+    }
+
+    /// Get the number of touching fingers.
+    #[must_use]
+    pub fn active_fingers(&self) -> usize {
+        self.fingers.len()
     }
 
     /// Set finger data from a platform-specific source.
     ///
     /// # Arguments
-    /// * `finger_index` - 0 for finger1, 1 for finger2
+    /// * `id` - Stable finger ID (e.g. 0 and 1 for a `DualSense`)
     /// * `x` - Normalized X position (0.0-1.0)
     /// * `y` - Normalized Y position (0.0-1.0)
-    /// * `active` - Whether the finger is touching
-    pub fn set_finger(&mut self, finger_index: usize, x: f32, y: f32, active: bool) {
-        let finger = if finger_index == 0 {
-            &mut self.finger1
-        } else {
-            &mut self.finger2
-        };
-        finger.x = x.clamp(0.0, 1.0);
-        finger.y = y.clamp(0.0, 1.0);
-        finger.active = active;
+    /// * `active` - Whether the finger is touching; `false` lifts it
+    pub fn set_finger(&mut self, id: u64, x: f32, y: f32, active: bool) {
+        if !active {
+            self.fingers.retain(|finger| finger.id != id);
+            return;
+        }
+
+        let finger = TouchFinger::new(id, x, y);
+        match self.fingers.iter_mut().find(|finger| finger.id == id) {
+            Some(existing) => *existing = finger,
+            None => self.fingers.push(finger),
+        }
     }
 
     /// Set finger data from raw hardware coordinates.
@@ -201,7 +299,7 @@ impl TouchpadData {
     /// using the provided maximum values.
     ///
     /// # Arguments
-    /// * `finger_index` - 0 for finger1, 1 for finger2
+    /// * `id` - Stable finger ID
     /// * `raw_x` - Raw X coordinate from hardware
     /// * `raw_y` - Raw Y coordinate from hardware
     /// * `max_x` - Maximum X value for this touchpad (e.g., 1920 for DS4)
@@ -209,7 +307,7 @@ impl TouchpadData {
     /// * `active` - Whether the finger is touching
     pub fn set_finger_raw(
         &mut self,
-        finger_index: usize,
+        id: u64,
         raw_x: u16,
         raw_y: u16,
         max_x: u16,
@@ -217,15 +315,117 @@ impl TouchpadData {
         active: bool,
     ) {
         let (x, y) = normalize_coords(raw_x, raw_y, max_x, max_y);
-        self.set_finger(finger_index, x, y, active);
+        self.set_finger(id, x, y, active);
+    }
+
+    /// Replace the fingers with a device touchscreen's touches.
+    ///
+    /// Positions are normalized against `area`, usually the window size in
+    /// logical pixels.
+    pub fn set_from_touches(&mut self, touches: &Touches, area: Vec2) {
+        let area = area.max(Vec2::ONE);
+        self.fingers
+            .retain(|finger| touches.get_pressed(finger.id).is_some());
+        for touch in touches.iter() {
+            let position = touch.position() / area;
+            self.set_finger(touch.id(), position.x, position.y, true);
+        }
     }
 
     /// Update frame state - call this at the end of your custom system.
     ///
-    /// This saves current finger positions to prev_ fields for delta calculation.
+    /// This saves current finger positions to `previous` for delta calculation.
     pub fn update_frame(&mut self) {
-        self.prev_finger1 = self.finger1.position();
-        self.prev_finger2 = self.finger2.position();
+        self.previous = self
+            .fingers
+            .iter()
+            .map(|finger| (finger.id, finger.position()))
+            .collect();
+    }
+
+    /// Track the current contact and report a tap when it ends.
+    ///
+    /// A contact is a tap when every finger lifts within `tap_time_window`
+    /// seconds and the fingers moved less than `slop`.
+    pub fn update_tap(
+        &mut self,
+        delta: f32,
+        tap_time_window: f32,
+        slop: f32,
+    ) -> Option<(TouchpadGesture, Vec2)> {
+        let Some(centroid) = self.centroid() else {
+            let session = self.session.take()?;
+            if session.elapsed > tap_time_window || session.travel >= slop {
+                return None;
+            }
+            let gesture = match session.max_fingers {
+                1 => TouchpadGesture::Tap,
+                2 => TouchpadGesture::TwoFingerTap,
+                _ => TouchpadGesture::ThreeFingerTap,
+            };
+            return Some((gesture, session.position));
+        };
+
+        let travel = self.centroid_delta().length();
+        let count = self.active_fingers();
+        let session = self.session.get_or_insert_default();
+        session.elapsed += delta;
+        session.max_fingers = session.max_fingers.max(count);
+        session.travel += travel;
+        session.position = centroid;
+        None
+    }
+
+    /// Track a contact that starts on an edge and report its edge swipe.
+    ///
+    /// A single finger touching down within `thickness` of a border fires
+    /// the matching edge swipe once it has moved `threshold` inward from
+    /// where it touched down. Call before [`Self::update_frame`].
+    pub fn update_edge_swipe(
+        &mut self,
+        thickness: f32,
+        threshold: f32,
+    ) -> Option<(TouchpadGesture, Vec2)> {
+        if self.active_fingers() != 1 {
+            self.edge_contact = None;
+            return None;
+        }
+        let finger = *self.primary()?;
+        let position = finger.position();
+
+        if self.previous.is_empty() {
+            self.edge_contact =
+                TouchpadGesture::edge_swipe(position, thickness).map(|gesture| EdgeContact {
+                    finger: finger.id,
+                    gesture,
+                    origin: position,
+                    fired: false,
+                });
+        }
+
+        let contact = self.edge_contact.as_mut()?;
+        if contact.fired || contact.finger != finger.id {
+            return None;
+        }
+        let travel = position - contact.origin;
+        let inward = match contact.gesture {
+            TouchpadGesture::EdgeSwipeFromLeft => travel.x,
+            TouchpadGesture::EdgeSwipeFromRight => -travel.x,
+            TouchpadGesture::EdgeSwipeFromTop => travel.y,
+            _ => -travel.y,
+        };
+        if inward > threshold {
+            contact.fired = true;
+            Some((contact.gesture, position))
+        } else {
+            None
+        }
+    }
+
+    /// Whether the current contact started on an edge.
+    #[must_use]
+    pub fn is_edge_contact(&self) -> bool {
+        self.edge_contact.is_some()
     }
 
     /// Update from a motion backend's touchpad data.
@@ -242,17 +442,8 @@ impl TouchpadData {
     /// ```
     #[cfg(feature = "motion-backends")]
     pub fn update_from_backend(&mut self, data: &crate::motion::backend::TouchpadData) {
-        // Update finger 1
-        self.finger1.x = data.finger1.x.clamp(0.0, 1.0);
-        self.finger1.y = data.finger1.y.clamp(0.0, 1.0);
-        self.finger1.active = data.finger1.active;
-        self.finger1.id = data.finger1.id;
-
-        // Update finger 2
-        self.finger2.x = data.finger2.x.clamp(0.0, 1.0);
-        self.finger2.y = data.finger2.y.clamp(0.0, 1.0);
-        self.finger2.active = data.finger2.active;
-        self.finger2.id = data.finger2.id;
+        self.set_finger(0, data.finger1.x, data.finger1.y, data.finger1.active);
+        self.set_finger(1, data.finger2.x, data.finger2.y, data.finger2.active);
 
         // Update button state
         self.button_pressed = data.button_pressed;
@@ -278,6 +469,68 @@ pub enum TouchpadGesture {
     PinchIn,
     /// Pinch out (zoom in).
     PinchOut,
+    /// Three (or more) finger tap.
+    ThreeFingerTap,
+    /// Three finger swipe left.
+    ThreeFingerSwipeLeft,
+    /// Three finger swipe right.
+    ThreeFingerSwipeRight,
+    /// Three finger swipe up.
+    ThreeFingerSwipeUp,
+    /// Three finger swipe down.
+    ThreeFingerSwipeDown,
+    /// Two fingers rotating around their midpoint.
+    ///
+    /// The event's intensity is the signed angle in radians turned this
+    /// frame, positive for clockwise.
+    Twist,
+    /// Swipe inward from the left edge.
+    EdgeSwipeFromLeft,
+    /// Swipe inward from the right edge.
+    EdgeSwipeFromRight,
+    /// Swipe inward from the top edge.
+    EdgeSwipeFromTop,
+    /// Swipe inward from the bottom edge.
+    EdgeSwipeFromBottom,
+}
+
+impl TouchpadGesture {
+    /// Get the swipe gesture for a movement.
+    #[must_use]
+    pub fn swipe(delta: Vec2, fingers: usize) -> Self {
+        let three = fingers >= 3;
+        if delta.x.abs() > delta.y.abs() {
+            match (delta.x > 0.0, three) {
+                (true, false) => Self::SwipeRight,
+                (false, false) => Self::SwipeLeft,
+                (true, true) => Self::ThreeFingerSwipeRight,
+                (false, true) => Self::ThreeFingerSwipeLeft,
+            }
+        } else {
+            match (delta.y > 0.0, three) {
+                (true, false) => Self::SwipeDown,
+                (false, false) => Self::SwipeUp,
+                (true, true) => Self::ThreeFingerSwipeDown,
+                (false, true) => Self::ThreeFingerSwipeUp,
+            }
+        }
+    }
+
+    /// Get the edge swipe for a touch-down position within `thickness` of a
+    /// border, using the nearest edge in corners.
+    #[must_use]
+    pub fn edge_swipe(position: Vec2, thickness: f32) -> Option<Self> {
+        [
+            (position.x, Self::EdgeSwipeFromLeft),
+            (1.0 - position.x, Self::EdgeSwipeFromRight),
+            (position.y, Self::EdgeSwipeFromTop),
+            (1.0 - position.y, Self::EdgeSwipeFromBottom),
+        ]
+        .into_iter()
+        .filter(|(distance, _)| *distance < thickness)
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, gesture)| gesture)
+    }
 }
 
 /// Event fired when a touchpad gesture is detected.
@@ -289,7 +542,8 @@ pub struct TouchpadGestureEvent {
     pub gesture: TouchpadGesture,
     /// Position where gesture occurred (if applicable).
     pub position: Vec2,
-    /// Intensity/magnitude of gesture.
+    /// Intensity/magnitude of gesture (the signed angle for
+    /// [`TouchpadGesture::Twist`]).
     pub intensity: f32,
 }
 
@@ -300,6 +554,8 @@ pub struct TouchpadConfig {
     pub swipe_threshold: f32,
     /// Tap detection time window.
     pub tap_time_window: f32,
+    /// Normalized width of the border where edge swipes start (0.0 = off).
+    pub edge_thickness: f32,
     /// Whether touchpad is enabled.
     pub enabled: bool,
 }
@@ -309,6 +565,7 @@ impl Default for TouchpadConfig {
         Self {
             swipe_threshold: 0.15,
             tap_time_window: 0.2,
+            edge_thickness: 0.1,
             enabled: true,
         }
     }
@@ -356,59 +613,469 @@ pub fn update_touchpad_data(
     }
 }
 
-/// System to detect touchpad gestures.
-pub fn detect_touchpad_gestures(
-    mut gamepads: Query<(Entity, &mut TouchpadData)>,
-    config: Res<TouchpadConfig>,
-    mut gesture_events: MessageWriter<TouchpadGestureEvent>,
-) {
-    if !config.enabled {
-        return;
+/// A gesture reported by a [`GestureEngine`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectedGesture {
+    /// The detected gesture.
+    pub gesture: TouchpadGesture,
+    /// Normalized position where the gesture occurred.
+    pub position: Vec2,
+    /// Intensity/magnitude of gesture.
+    pub intensity: f32,
+}
+
+/// Source-agnostic gesture detection.
+///
+/// The engine works on normalized [`TouchpadData`], so controller touchpads
+/// and device touchscreens share the same swipe, edge swipe, pinch, twist,
+/// and tap rules.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureEngine {
+    /// Swipe detection threshold, also used as the tap movement slop.
+    pub swipe_threshold: f32,
+    /// Tap detection time window in seconds.
+    pub tap_time_window: f32,
+    /// Normalized width of the border where edge swipes start (0.0 = off).
+    pub edge_thickness: f32,
+}
+
+impl Default for GestureEngine {
+    fn default() -> Self {
+        Self::from_config(&TouchpadConfig::default())
     }
+}
 
-    for (entity, mut touchpad) in &mut gamepads {
-        // Detect swipes
-        let delta = touchpad.finger1_delta();
-        if delta.length() > config.swipe_threshold {
-            let gesture = if delta.x.abs() > delta.y.abs() {
-                if delta.x > 0.0 {
-                    TouchpadGesture::SwipeRight
-                } else {
-                    TouchpadGesture::SwipeLeft
-                }
-            } else if delta.y > 0.0 {
-                TouchpadGesture::SwipeDown
-            } else {
-                TouchpadGesture::SwipeUp
-            };
+impl GestureEngine {
+    /// Create an engine using a touchpad configuration's thresholds.
+    #[must_use]
+    pub const fn from_config(config: &TouchpadConfig) -> Self {
+        Self {
+            swipe_threshold: config.swipe_threshold,
+            tap_time_window: config.tap_time_window,
+            edge_thickness: config.edge_thickness,
+        }
+    }
 
-            gesture_events.write(TouchpadGestureEvent {
-                gamepad: entity,
+    /// Detect this frame's gestures and advance the touch state.
+    ///
+    /// A contact counts as a tap when every finger lifts within
+    /// [`tap_time_window`](Self::tap_time_window) and moved less than
+    /// [`swipe_threshold`](Self::swipe_threshold). A contact that starts
+    /// within [`edge_thickness`](Self::edge_thickness) of a border reports
+    /// an edge swipe instead of regular swipes. Calls
+    /// [`TouchpadData::update_frame`] once detection is done.
+    pub fn detect(&self, touches: &mut TouchpadData, delta: f32) -> Vec<DetectedGesture> {
+        let mut gestures = Vec::new();
+        let fingers = touches.active_fingers();
+
+        // Detect edge swipes
+        if let Some((gesture, position)) =
+            touches.update_edge_swipe(self.edge_thickness, self.swipe_threshold)
+        {
+            gestures.push(DetectedGesture {
                 gesture,
-                position: touchpad.finger1.position(),
-                intensity: delta.length(),
+                position,
+                intensity: 1.0,
+            });
+        }
+
+        // Detect swipes: the primary finger, or the centroid for three or more
+        let (swipe, position) = if fingers >= 3 {
+            (touches.centroid_delta(), touches.centroid())
+        } else {
+            (
+                touches.primary_delta(),
+                touches.primary().map(TouchFinger::position),
+            )
+        };
+        if let Some(position) = position
+            && !touches.is_edge_contact()
+            && swipe.length() > self.swipe_threshold
+        {
+            gestures.push(DetectedGesture {
+                gesture: TouchpadGesture::swipe(swipe, fingers),
+                position,
+                intensity: swipe.length(),
             });
         }
 
         // Detect pinch
-        if let Some(pinch_delta) = touchpad.is_pinching() {
+        if fingers == 2
+            && let Some(pinch_delta) = touches.is_pinching()
+        {
             let gesture = if pinch_delta > 0.0 {
                 TouchpadGesture::PinchOut
             } else {
                 TouchpadGesture::PinchIn
             };
 
-            gesture_events.write(TouchpadGestureEvent {
-                gamepad: entity,
+            gestures.push(DetectedGesture {
                 gesture,
-                position: (touchpad.finger1.position() + touchpad.finger2.position()) / 2.0,
+                position: touches.centroid().unwrap_or_default(),
                 intensity: pinch_delta.abs(),
             });
         }
 
+        // Detect twist
+        if fingers == 2
+            && let Some(angle) = touches.is_twisting()
+        {
+            gestures.push(DetectedGesture {
+                gesture: TouchpadGesture::Twist,
+                position: touches.centroid().unwrap_or_default(),
+                intensity: angle,
+            });
+        }
+
+        // Detect taps
+        if let Some((gesture, position)) =
+            touches.update_tap(delta, self.tap_time_window, self.swipe_threshold)
+        {
+            gestures.push(DetectedGesture {
+                gesture,
+                position,
+                intensity: 1.0,
+            });
+        }
+
         // Update previous positions
-        touchpad.prev_finger1 = touchpad.finger1.position();
-        touchpad.prev_finger2 = touchpad.finger2.position();
+        touches.update_frame();
+        gestures
+    }
+}
+
+/// System to detect touchpad gestures.
+pub fn detect_touchpad_gestures(
+    mut gamepads: Query<(Entity, &mut TouchpadData)>,
+    config: Res<TouchpadConfig>,
+    time: Res<Time>,
+    mut gesture_events: MessageWriter<TouchpadGestureEvent>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let engine = GestureEngine::from_config(&config);
+    for (entity, mut touchpad) in &mut gamepads {
+        for detected in engine.detect(&mut touchpad, time.delta_secs()) {
+            gesture_events.write(TouchpadGestureEvent {
+                gamepad: entity,
+                gesture: detected.gesture,
+                position: detected.position,
+                intensity: detected.intensity,
+            });
+        }
+    }
+}
+
+// ========== Touchpad Regions ==========
+
+/// How [`TouchpadRegions`] divides the touchpad.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum TouchpadLayout {
+    /// Four directional regions around a center dead zone, indexed up,
+    /// right, down, left.
+    DPad {
+        /// Radius of the center dead zone, in normalized units.
+        deadzone: f32,
+    },
+    /// A grid of buttons, indexed row by row from the top left.
+    Grid {
+        /// Number of columns.
+        columns: u8,
+        /// Number of rows.
+        rows: u8,
+    },
+}
+
+impl TouchpadLayout {
+    /// Index of the up region in [`TouchpadLayout::DPad`].
+    pub const DPAD_UP: usize = 0;
+    /// Index of the right region in [`TouchpadLayout::DPad`].
+    pub const DPAD_RIGHT: usize = 1;
+    /// Index of the down region in [`TouchpadLayout::DPad`].
+    pub const DPAD_DOWN: usize = 2;
+    /// Index of the left region in [`TouchpadLayout::DPad`].
+    pub const DPAD_LEFT: usize = 3;
+
+    /// Number of regions in the layout.
+    #[must_use]
+    pub const fn region_count(self) -> usize {
+        match self {
+            Self::DPad { .. } => 4,
+            Self::Grid { columns, rows } => columns as usize * rows as usize,
+        }
+    }
+
+    /// Region containing a normalized touchpad position, if any.
+    #[must_use]
+    pub fn region(self, position: Vec2) -> Option<usize> {
+        match self {
+            Self::DPad { deadzone } => {
+                // Touchpad coordinates grow downward.
+                let offset = position - Vec2::splat(0.5);
+                if offset.length() < deadzone {
+                    None
+                } else if offset.x.abs() > offset.y.abs() {
+                    Some(if offset.x > 0.0 {
+                        Self::DPAD_RIGHT
+                    } else {
+                        Self::DPAD_LEFT
+                    })
+                } else if offset.y < 0.0 {
+                    Some(Self::DPAD_UP)
+                } else {
+                    Some(Self::DPAD_DOWN)
+                }
+            }
+            Self::Grid { columns, rows } => {
+                if columns == 0 || rows == 0 {
+                    return None;
+                }
+                let cell = |value: f32, count: u8| {
+                    ((value.clamp(0.0, 1.0) * f32::from(count)) as usize).min(count as usize - 1)
+                };
+                Some(cell(position.y, rows) * columns as usize + cell(position.x, columns))
+            }
+        }
+    }
+}
+
+/// Splits a gamepad's touchpad into a virtual d-pad or button grid.
+///
+/// Add to a gamepad entity alongside [`TouchpadData`]. The primary finger's
+/// region presses its action through [`SimulatedInput`], emulating extra
+/// buttons. With `require_click` set, the finger only selects a region and
+/// the touchpad must be clicked to press it. An optional `tick` rumbles
+/// each time the finger moves into a new region.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bevy_archie::actions::GameAction;
+/// use bevy_archie::haptics::HapticFeedback;
+/// use bevy_archie::touchpad::TouchpadRegions;
+///
+/// // Four buttons in a 2x2 grid, pressed by clicking the touchpad.
+/// let regions = TouchpadRegions::grid(2, 2)
+///     .with_action(0, GameAction::Custom1)
+///     .with_action(1, GameAction::Custom2)
+///     .with_action(2, GameAction::Custom3)
+///     .with_action(3, GameAction::Custom4)
+///     .with_click()
+///     .with_tick(HapticFeedback::light_tick());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct TouchpadRegions {
+    /// How the touchpad is divided.
+    pub layout: TouchpadLayout,
+    /// Action pressed by each region, by region index.
+    pub actions: Vec<Option<GameAction>>,
+    /// Only press a region's action while the touchpad is clicked.
+    pub require_click: bool,
+    /// Rumble played when the finger enters a different region.
+    pub tick: Option<HapticFeedback>,
+    /// Region under the primary finger.
+    current: Option<usize>,
+}
+
+impl TouchpadRegions {
+    /// Divide the touchpad with a layout, with no actions bound.
+    #[must_use]
+    pub fn new(layout: TouchpadLayout) -> Self {
+        Self {
+            layout,
+            actions: vec![None; layout.region_count()],
+            require_click: false,
+            tick: None,
+            current: None,
+        }
+    }
+
+    /// A virtual d-pad with a small center dead zone.
+    #[must_use]
+    pub fn dpad(up: GameAction, right: GameAction, down: GameAction, left: GameAction) -> Self {
+        let mut regions = Self::new(TouchpadLayout::DPad { deadzone: 0.1 });
+        regions.actions = vec![Some(up), Some(right), Some(down), Some(left)];
+        regions
+    }
+
+    /// A button grid with no actions bound.
+    #[must_use]
+    pub fn grid(columns: u8, rows: u8) -> Self {
+        Self::new(TouchpadLayout::Grid { columns, rows })
+    }
+
+    /// Bind an action to a region.
+    #[must_use]
+    pub fn with_action(mut self, region: usize, action: GameAction) -> Self {
+        self.bind(region, action);
+        self
+    }
+
+    /// Only press actions while the touchpad is clicked.
+    #[must_use]
+    pub const fn with_click(mut self) -> Self {
+        self.require_click = true;
+        self
+    }
+
+    /// Rumble when the finger enters a different region.
+    #[must_use]
+    pub const fn with_tick(mut self, tick: HapticFeedback) -> Self {
+        self.tick = Some(tick);
+        self
+    }
+
+    /// Bind an action to a region. Regions outside the layout are ignored.
+    pub fn bind(&mut self, region: usize, action: GameAction) {
+        if let Some(slot) = self.actions.get_mut(region) {
+            *slot = Some(action);
+        }
+    }
+
+    /// Action bound to a region.
+    #[must_use]
+    pub fn action(&self, region: usize) -> Option<GameAction> {
+        self.actions.get(region).copied().flatten()
+    }
+
+    /// Region under the primary finger.
+    #[must_use]
+    pub const fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Track the primary finger's region.
+    ///
+    /// Returns `true` when the finger entered a different region this frame.
+    pub fn update(&mut self, touchpad: &TouchpadData) -> bool {
+        let region = touchpad
+            .primary()
+            .and_then(|finger| self.layout.region(finger.position()));
+        let entered = region.is_some() && region != self.current;
+        self.current = region;
+        entered
+    }
+
+    /// Action the touchpad is pressing, if any.
+    #[must_use]
+    pub fn pressed_action(&self, touchpad: &TouchpadData) -> Option<GameAction> {
+        if self.require_click && !touchpad.button_pressed {
+            return None;
+        }
+        self.current.and_then(|region| self.action(region))
+    }
+}
+
+/// System to press actions from [`TouchpadRegions`].
+pub fn update_touchpad_regions(
+    mut gamepads: Query<(Entity, &TouchpadData, &mut TouchpadRegions)>,
+    config: Res<TouchpadConfig>,
+    mut simulated: ResMut<SimulatedInput>,
+    mut rumble: MessageWriter<RumbleRequest>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    for (entity, touchpad, mut regions) in &mut gamepads {
+        if regions.update(touchpad)
+            && let Some(tick) = regions.tick
+        {
+            rumble.write(tick.request(entity));
+        }
+        if let Some(action) = regions.pressed_action(touchpad)
+            && simulated.get(action).is_none()
+        {
+            simulated.tap(action);
+        }
+    }
+}
+
+// ========== Touchscreen Gestures ==========
+
+/// Plugin for gestures on a device touchscreen.
+///
+/// Feeds Bevy's [`Touches`] on the primary window into the same
+/// [`GestureEngine`] used for controller touchpads, emitting
+/// [`TouchscreenGestureEvent`]s. Thresholds come from [`TouchpadConfig`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_archie::touchpad::{TouchpadGesture, TouchscreenGestureEvent, TouchscreenGesturePlugin};
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(TouchscreenGesturePlugin)
+///     .add_systems(Update, on_gesture)
+///     .run();
+///
+/// fn on_gesture(mut gestures: MessageReader<TouchscreenGestureEvent>) {
+///     for event in gestures.read() {
+///         if event.gesture == TouchpadGesture::TwoFingerTap {
+///             info!("Two-finger tap at {}", event.position);
+///         }
+///     }
+/// }
+/// ```
+pub struct TouchscreenGesturePlugin;
+
+impl Plugin for TouchscreenGesturePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TouchpadConfig>()
+            .init_resource::<TouchscreenGestures>()
+            .add_message::<TouchscreenGestureEvent>()
+            .add_systems(Update, detect_touchscreen_gestures);
+    }
+}
+
+/// Resource tracking touchscreen contacts for gesture detection.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct TouchscreenGestures {
+    /// Touches normalized against the primary window.
+    pub touches: TouchpadData,
+}
+
+/// Event fired when a touchscreen gesture is detected.
+#[derive(Debug, Clone, Message)]
+pub struct TouchscreenGestureEvent {
+    /// The detected gesture.
+    pub gesture: TouchpadGesture,
+    /// Position where gesture occurred, in logical window pixels.
+    pub position: Vec2,
+    /// Intensity/magnitude of gesture, relative to the window size.
+    pub intensity: f32,
+}
+
+/// System to detect gestures on the primary window's touchscreen.
+pub fn detect_touchscreen_gestures(
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<TouchpadConfig>,
+    time: Res<Time>,
+    mut screen: ResMut<TouchscreenGestures>,
+    mut gesture_events: MessageWriter<TouchscreenGestureEvent>,
+) {
+    if !config.enabled {
+        return;
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    let size = Vec2::new(window.width(), window.height());
+    screen.touches.set_from_touches(&touches, size);
+    let engine = GestureEngine::from_config(&config);
+    for detected in engine.detect(&mut screen.touches, time.delta_secs()) {
+        gesture_events.write(TouchscreenGestureEvent {
+            gesture: detected.gesture,
+            position: detected.position * size,
+            intensity: detected.intensity,
+        });
     }
 }
 
@@ -417,15 +1084,25 @@ pub(crate) fn register_touchpad_types(app: &mut App) {
     app.register_type::<TouchFinger>()
         .register_type::<TouchpadData>()
         .register_type::<TouchpadGesture>()
+        .register_type::<TouchpadLayout>()
+        .register_type::<TouchpadRegions>()
         .init_resource::<TouchpadConfig>()
         .add_message::<TouchpadGestureEvent>();
 }
 
 /// Add touchpad systems to the app.
-pub(crate) fn add_touchpad_systems(app: &mut App) {
+pub(crate) fn add_touchpad_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
     app.add_systems(
-        Update,
-        (update_touchpad_data, detect_touchpad_gestures).chain(),
+        schedules.update,
+        (update_touchpad_data, detect_touchpad_gestures)
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::Touchpad)),
+    );
+    app.add_systems(
+        schedules.input,
+        update_touchpad_regions
+            .before(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::Touchpad)),
     );
 }
 
@@ -474,26 +1151,24 @@ mod tests {
     #[test]
     fn test_touchpad_data_default() {
         let data = TouchpadData::default();
-        assert!(!data.finger1.active);
-        assert!(!data.finger2.active);
+        assert!(data.fingers.is_empty());
         assert!(!data.button_pressed);
-        assert_eq!(data.prev_finger1, Vec2::ZERO);
-        assert_eq!(data.prev_finger2, Vec2::ZERO);
+        assert!(data.previous.is_empty());
     }
 
     #[test]
     fn test_touchpad_data_finger1_delta_inactive() {
         let data = TouchpadData::default();
-        assert_eq!(data.finger1_delta(), Vec2::ZERO);
+        assert_eq!(data.delta(0), Vec2::ZERO);
     }
 
     #[test]
     fn test_touchpad_data_finger1_delta_active() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.5, 0.5);
-        data.prev_finger1 = Vec2::new(0.3, 0.3);
+        data.set_finger(0, 0.5, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.3));
 
-        let delta = data.finger1_delta();
+        let delta = data.delta(0);
         assert_relative_eq!(delta.x, 0.2, epsilon = 0.001);
         assert_relative_eq!(delta.y, 0.2, epsilon = 0.001);
     }
@@ -501,16 +1176,16 @@ mod tests {
     #[test]
     fn test_touchpad_data_finger2_delta_inactive() {
         let data = TouchpadData::default();
-        assert_eq!(data.finger2_delta(), Vec2::ZERO);
+        assert_eq!(data.delta(1), Vec2::ZERO);
     }
 
     #[test]
     fn test_touchpad_data_finger2_delta_active() {
         let mut data = TouchpadData::default();
-        data.finger2 = TouchFinger::new(1, 0.8, 0.6);
-        data.prev_finger2 = Vec2::new(0.4, 0.2);
+        data.set_finger(1, 0.8, 0.6, true);
+        data.previous.insert(1, Vec2::new(0.4, 0.2));
 
-        let delta = data.finger2_delta();
+        let delta = data.delta(1);
         assert_relative_eq!(delta.x, 0.4, epsilon = 0.001);
         assert_relative_eq!(delta.y, 0.4, epsilon = 0.001);
     }
@@ -518,8 +1193,8 @@ mod tests {
     #[test]
     fn test_touchpad_data_is_swiping_true() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.8, 0.5);
-        data.prev_finger1 = Vec2::new(0.3, 0.5);
+        data.set_finger(0, 0.8, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.5));
 
         assert!(data.is_swiping(0.1));
     }
@@ -527,8 +1202,8 @@ mod tests {
     #[test]
     fn test_touchpad_data_is_swiping_false() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.35, 0.5);
-        data.prev_finger1 = Vec2::new(0.3, 0.5);
+        data.set_finger(0, 0.35, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.5));
 
         assert!(!data.is_swiping(0.1));
     }
@@ -536,7 +1211,7 @@ mod tests {
     #[test]
     fn test_touchpad_data_is_pinching_none_when_single_finger() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.5, 0.5);
+        data.set_finger(0, 0.5, 0.5, true);
         // finger2 not active
 
         assert!(data.is_pinching().is_none());
@@ -545,10 +1220,10 @@ mod tests {
     #[test]
     fn test_touchpad_data_is_pinching_out() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.2, 0.5);
-        data.finger2 = TouchFinger::new(1, 0.8, 0.5);
-        data.prev_finger1 = Vec2::new(0.3, 0.5);
-        data.prev_finger2 = Vec2::new(0.7, 0.5);
+        data.set_finger(0, 0.2, 0.5, true);
+        data.set_finger(1, 0.8, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.5));
+        data.previous.insert(1, Vec2::new(0.7, 0.5));
 
         let pinch = data.is_pinching();
         assert!(pinch.is_some());
@@ -558,10 +1233,10 @@ mod tests {
     #[test]
     fn test_touchpad_data_is_pinching_in() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.4, 0.5);
-        data.finger2 = TouchFinger::new(1, 0.6, 0.5);
-        data.prev_finger1 = Vec2::new(0.2, 0.5);
-        data.prev_finger2 = Vec2::new(0.8, 0.5);
+        data.set_finger(0, 0.4, 0.5, true);
+        data.set_finger(1, 0.6, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.2, 0.5));
+        data.previous.insert(1, Vec2::new(0.8, 0.5));
 
         let pinch = data.is_pinching();
         assert!(pinch.is_some());
@@ -571,14 +1246,39 @@ mod tests {
     #[test]
     fn test_touchpad_data_is_pinching_none_when_no_movement() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.3, 0.5);
-        data.finger2 = TouchFinger::new(1, 0.7, 0.5);
-        data.prev_finger1 = Vec2::new(0.3, 0.5);
-        data.prev_finger2 = Vec2::new(0.7, 0.5);
+        data.set_finger(0, 0.3, 0.5, true);
+        data.set_finger(1, 0.7, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.5));
+        data.previous.insert(1, Vec2::new(0.7, 0.5));
+
+        assert!(data.is_pinching().is_none());
+    }
 
+    #[test]
+    fn test_touchpad_data_is_twisting_clockwise() {
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.5, 0.3, true);
+        data.set_finger(1, 0.5, 0.7, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.5));
+        data.previous.insert(1, Vec2::new(0.7, 0.5));
+
+        // Right-pointing fingers turned to point down: a quarter turn clockwise
+        let angle = data.is_twisting().unwrap();
+        assert_relative_eq!(angle, std::f32::consts::FRAC_PI_2, epsilon = 0.001);
         assert!(data.is_pinching().is_none());
     }
 
+    #[test]
+    fn test_touchpad_data_is_twisting_none_when_parallel() {
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.4, 0.5, true);
+        data.set_finger(1, 0.8, 0.5, true);
+        data.previous.insert(0, Vec2::new(0.3, 0.5));
+        data.previous.insert(1, Vec2::new(0.7, 0.5));
+
+        assert!(data.is_twisting().is_none());
+    }
+
     #[test]
     fn test_touchpad_data_active_fingers_none() {
         let data = TouchpadData::default();
@@ -588,18 +1288,78 @@ mod tests {
     #[test]
     fn test_touchpad_data_active_fingers_one() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.5, 0.5);
+        data.set_finger(0, 0.5, 0.5, true);
         assert_eq!(data.active_fingers(), 1);
     }
 
     #[test]
     fn test_touchpad_data_active_fingers_two() {
         let mut data = TouchpadData::default();
-        data.finger1 = TouchFinger::new(0, 0.5, 0.5);
-        data.finger2 = TouchFinger::new(1, 0.7, 0.7);
+        data.set_finger(0, 0.5, 0.5, true);
+        data.set_finger(1, 0.7, 0.7, true);
         assert_eq!(data.active_fingers(), 2);
     }
 
+    #[test]
+    fn test_touchpad_data_tracks_many_fingers_by_id() {
+        let mut data = TouchpadData::default();
+        for (id, x) in [(0, 0.0), (1, 0.2), (2, 0.4), (3, 0.6)] {
+            data.set_finger(id, x, 0.5, true);
+        }
+        assert_eq!(data.active_fingers(), 4);
+        data.update_frame();
+
+        // Lifting one finger leaves the others' IDs and deltas intact
+        data.set_finger(0, 0.0, 0.0, false);
+        data.set_finger(2, 0.5, 0.5, true);
+        assert_eq!(data.active_fingers(), 3);
+        assert_eq!(data.primary().map(|finger| finger.id), Some(1));
+        assert_relative_eq!(data.delta(2).x, 0.1, epsilon = 0.001);
+        assert_eq!(data.delta(0), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_touchpad_data_centroid_delta() {
+        let mut data = TouchpadData::default();
+        for id in 0..3 {
+            data.set_finger(id, 0.3, 0.5, true);
+        }
+        data.update_frame();
+        for id in 0..3 {
+            data.set_finger(id, 0.6, 0.5, true);
+        }
+        // A finger that just touched doesn't pull the centroid delta
+        data.set_finger(7, 0.0, 0.0, true);
+
+        assert_relative_eq!(data.centroid_delta().x, 0.3, epsilon = 0.001);
+    }
+
+    #[test]
+    fn test_touchpad_data_tap_counts_fingers() {
+        let mut data = TouchpadData::default();
+        for id in 0..3 {
+            data.set_finger(id, 0.5, 0.5, true);
+        }
+        assert_eq!(data.update_tap(0.05, 0.2, 0.15), None);
+        data.update_frame();
+
+        for id in 0..3 {
+            data.set_finger(id, 0.0, 0.0, false);
+        }
+        let (gesture, position) = data.update_tap(0.05, 0.2, 0.15).unwrap();
+        assert_eq!(gesture, TouchpadGesture::ThreeFingerTap);
+        assert_relative_eq!(position.x, 0.5);
+    }
+
+    #[test]
+    fn test_touchpad_data_long_press_is_not_a_tap() {
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.5, 0.5, true);
+        data.update_tap(0.5, 0.2, 0.15);
+        data.set_finger(0, 0.0, 0.0, false);
+        assert_eq!(data.update_tap(0.016, 0.2, 0.15), None);
+    }
+
     // ========== TouchpadGesture Tests ==========
 
     #[test]
@@ -623,6 +1383,142 @@ mod tests {
         assert_eq!(gestures.len(), 8);
     }
 
+    #[test]
+    fn test_touchpad_gesture_swipe_direction() {
+        assert_eq!(
+            TouchpadGesture::swipe(Vec2::new(0.3, 0.0), 1),
+            TouchpadGesture::SwipeRight
+        );
+        assert_eq!(
+            TouchpadGesture::swipe(Vec2::new(0.0, -0.3), 3),
+            TouchpadGesture::ThreeFingerSwipeUp
+        );
+        assert_eq!(
+            TouchpadGesture::swipe(Vec2::new(-0.3, 0.1), 4),
+            TouchpadGesture::ThreeFingerSwipeLeft
+        );
+    }
+
+    // ========== GestureEngine Tests ==========
+
+    #[test]
+    fn test_gesture_engine_uses_config_thresholds() {
+        let engine = GestureEngine::from_config(&TouchpadConfig {
+            swipe_threshold: 0.3,
+            tap_time_window: 0.5,
+            edge_thickness: 0.05,
+            enabled: true,
+        });
+        assert_relative_eq!(engine.swipe_threshold, 0.3);
+        assert_relative_eq!(engine.tap_time_window, 0.5);
+        assert_relative_eq!(engine.edge_thickness, 0.05);
+    }
+
+    #[test]
+    fn test_gesture_engine_detects_swipe() {
+        let engine = GestureEngine::default();
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.2, 0.5, true);
+        assert!(engine.detect(&mut data, 0.016).is_empty());
+
+        data.set_finger(0, 0.6, 0.5, true);
+        let gestures = engine.detect(&mut data, 0.016);
+        assert_eq!(gestures.len(), 1);
+        assert_eq!(gestures[0].gesture, TouchpadGesture::SwipeRight);
+        assert_relative_eq!(gestures[0].intensity, 0.4, epsilon = 0.001);
+
+        // Previous positions were advanced by detect
+        assert_eq!(data.primary_delta(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_gesture_engine_detects_edge_swipe() {
+        let engine = GestureEngine::default();
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.95, 0.5, true);
+        assert!(engine.detect(&mut data, 0.016).is_empty());
+
+        // Moving inward past the threshold fires once, without a regular swipe
+        data.set_finger(0, 0.6, 0.5, true);
+        let gestures = engine.detect(&mut data, 0.016);
+        assert_eq!(gestures.len(), 1);
+        assert_eq!(gestures[0].gesture, TouchpadGesture::EdgeSwipeFromRight);
+
+        data.set_finger(0, 0.2, 0.5, true);
+        assert!(engine.detect(&mut data, 0.016).is_empty());
+    }
+
+    #[test]
+    fn test_gesture_engine_edge_swipe_disabled() {
+        let engine = GestureEngine {
+            edge_thickness: 0.0,
+            ..GestureEngine::default()
+        };
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.95, 0.5, true);
+        engine.detect(&mut data, 0.016);
+
+        data.set_finger(0, 0.6, 0.5, true);
+        let gestures = engine.detect(&mut data, 0.016);
+        assert_eq!(gestures.len(), 1);
+        assert_eq!(gestures[0].gesture, TouchpadGesture::SwipeLeft);
+    }
+
+    #[test]
+    fn test_touchpad_gesture_edge_swipe_nearest_edge() {
+        assert_eq!(
+            TouchpadGesture::edge_swipe(Vec2::new(0.02, 0.08), 0.1),
+            Some(TouchpadGesture::EdgeSwipeFromLeft)
+        );
+        assert_eq!(
+            TouchpadGesture::edge_swipe(Vec2::new(0.5, 0.97), 0.1),
+            Some(TouchpadGesture::EdgeSwipeFromBottom)
+        );
+        assert_eq!(TouchpadGesture::edge_swipe(Vec2::new(0.5, 0.5), 0.1), None);
+    }
+
+    #[test]
+    fn test_gesture_engine_detects_twist_with_pinch() {
+        let engine = GestureEngine::default();
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.4, 0.5, true);
+        data.set_finger(1, 0.6, 0.5, true);
+        engine.detect(&mut data, 0.016);
+
+        // Spread apart while turning counter-clockwise
+        data.set_finger(0, 0.3, 0.6, true);
+        data.set_finger(1, 0.7, 0.4, true);
+        let gestures = engine.detect(&mut data, 0.016);
+
+        let twist = gestures
+            .iter()
+            .find(|detected| detected.gesture == TouchpadGesture::Twist)
+            .unwrap();
+        assert!(twist.intensity < 0.0);
+        assert_relative_eq!(twist.position.x, 0.5);
+        assert!(
+            gestures
+                .iter()
+                .any(|detected| detected.gesture == TouchpadGesture::PinchOut)
+        );
+    }
+
+    #[test]
+    fn test_gesture_engine_detects_two_finger_tap() {
+        let engine = GestureEngine::default();
+        let mut data = TouchpadData::default();
+        data.set_finger(0, 0.4, 0.5, true);
+        data.set_finger(1, 0.6, 0.5, true);
+        assert!(engine.detect(&mut data, 0.05).is_empty());
+
+        data.set_finger(0, 0.0, 0.0, false);
+        data.set_finger(1, 0.0, 0.0, false);
+        let gestures = engine.detect(&mut data, 0.05);
+        assert_eq!(gestures.len(), 1);
+        assert_eq!(gestures[0].gesture, TouchpadGesture::TwoFingerTap);
+        assert_relative_eq!(gestures[0].position.x, 0.5);
+    }
+
     // ========== TouchpadConfig Tests ==========
 
     #[test]
@@ -638,6 +1534,7 @@ mod tests {
         let config = TouchpadConfig {
             swipe_threshold: 0.25,
             tap_time_window: 0.3,
+            edge_thickness: 0.0,
             enabled: false,
         };
         assert_relative_eq!(config.swipe_threshold, 0.25);
@@ -658,4 +1555,99 @@ mod tests {
         assert_eq!(event.gesture, TouchpadGesture::SwipeRight);
         assert_relative_eq!(event.intensity, 0.3);
     }
+
+    // ========== Touchpad Region Tests ==========
+
+    #[test]
+    fn test_dpad_layout_regions() {
+        let layout = TouchpadLayout::DPad { deadzone: 0.1 };
+        assert_eq!(
+            layout.region(Vec2::new(0.5, 0.1)),
+            Some(TouchpadLayout::DPAD_UP)
+        );
+        assert_eq!(
+            layout.region(Vec2::new(0.9, 0.5)),
+            Some(TouchpadLayout::DPAD_RIGHT)
+        );
+        assert_eq!(
+            layout.region(Vec2::new(0.5, 0.9)),
+            Some(TouchpadLayout::DPAD_DOWN)
+        );
+        assert_eq!(
+            layout.region(Vec2::new(0.1, 0.5)),
+            Some(TouchpadLayout::DPAD_LEFT)
+        );
+        assert_eq!(layout.region(Vec2::new(0.52, 0.48)), None);
+    }
+
+    #[test]
+    fn test_grid_layout_regions() {
+        let layout = TouchpadLayout::Grid {
+            columns: 3,
+            rows: 2,
+        };
+        assert_eq!(layout.region_count(), 6);
+        assert_eq!(layout.region(Vec2::new(0.1, 0.1)), Some(0));
+        assert_eq!(layout.region(Vec2::new(0.9, 0.1)), Some(2));
+        assert_eq!(layout.region(Vec2::new(0.5, 0.9)), Some(4));
+        assert_eq!(layout.region(Vec2::new(1.0, 1.0)), Some(5));
+        assert_eq!(
+            TouchpadLayout::Grid {
+                columns: 0,
+                rows: 2
+            }
+            .region(Vec2::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_touchpad_regions_track_finger() {
+        let mut regions = TouchpadRegions::grid(2, 1)
+            .with_action(0, GameAction::Custom1)
+            .with_action(1, GameAction::Custom2);
+        let mut touchpad = TouchpadData::default();
+
+        touchpad.set_finger(0, 0.2, 0.5, true);
+        assert!(regions.update(&touchpad));
+        assert_eq!(regions.pressed_action(&touchpad), Some(GameAction::Custom1));
+
+        touchpad.set_finger(0, 0.3, 0.5, true);
+        assert!(!regions.update(&touchpad));
+
+        touchpad.set_finger(0, 0.8, 0.5, true);
+        assert!(regions.update(&touchpad));
+        assert_eq!(regions.pressed_action(&touchpad), Some(GameAction::Custom2));
+
+        touchpad.set_finger(0, 0.8, 0.5, false);
+        assert!(!regions.update(&touchpad));
+        assert_eq!(regions.current(), None);
+        assert_eq!(regions.pressed_action(&touchpad), None);
+    }
+
+    #[test]
+    fn test_touchpad_regions_require_click() {
+        let mut regions = TouchpadRegions::dpad(
+            GameAction::Up,
+            GameAction::Right,
+            GameAction::Down,
+            GameAction::Left,
+        )
+        .with_click();
+        let mut touchpad = TouchpadData::default();
+        touchpad.set_finger(0, 0.5, 0.05, true);
+        regions.update(&touchpad);
+        assert_eq!(regions.pressed_action(&touchpad), None);
+
+        touchpad.button_pressed = true;
+        assert_eq!(regions.pressed_action(&touchpad), Some(GameAction::Up));
+    }
+
+    #[test]
+    fn test_touchpad_regions_bind_out_of_range() {
+        let mut regions = TouchpadRegions::grid(2, 2);
+        regions.bind(7, GameAction::Confirm);
+        assert_eq!(regions.actions.len(), 4);
+        assert_eq!(regions.action(7), None);
+    }
 }
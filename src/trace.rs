@@ -0,0 +1,334 @@
+//! Per-action input trace logging.
+//!
+//! [`InputTrace`] explains why chosen actions did or didn't fire. Each frame
+//! it rebuilds the decision chain behind a traced action's state and logs
+//! it whenever it changes:
+//!
+//! ```text
+//! Confirm: just pressed at 1.00 (South on gamepad 4v1 (player 0))
+//! Confirm: suppressed by chord clash (South on gamepad 4v1 (player 0))
+//! Confirm: released (South shifted by a modifier on gamepad 4v1 (player 0))
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::trace::InputTrace;
+//!
+//! fn trace_confirm(mut trace: ResMut<InputTrace>) {
+//!     trace.trace(GameAction::Confirm);
+//! }
+//! ```
+
+use bevy::prelude::*;
+use log::info;
+use std::collections::{HashMap, HashSet};
+
+use crate::actions::{ActionMap, ActionState, AxisDirection, GameAction, GamepadActionState};
+use crate::detection::InputDeviceState;
+use crate::multiplayer::ControllerOwnership;
+use crate::simulated_input::SimulatedInput;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Resource selecting actions whose input decisions are logged.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct InputTrace {
+    /// Actions being traced.
+    traced: HashSet<GameAction>,
+    /// Log every frame instead of only when an explanation changes.
+    pub every_frame: bool,
+    /// Latest explanation for each traced action.
+    explanations: HashMap<GameAction, String>,
+}
+
+impl InputTrace {
+    /// Start tracing an action.
+    pub fn trace(&mut self, action: GameAction) {
+        self.traced.insert(action);
+    }
+
+    /// Stop tracing an action.
+    pub fn untrace(&mut self, action: GameAction) {
+        self.traced.remove(&action);
+        self.explanations.remove(&action);
+    }
+
+    /// Stop tracing every action.
+    pub fn clear(&mut self) {
+        self.traced.clear();
+        self.explanations.clear();
+    }
+
+    /// Check if an action is traced.
+    #[must_use]
+    pub fn is_tracing(&self, action: GameAction) -> bool {
+        self.traced.contains(&action)
+    }
+
+    /// Check if any action is traced.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.traced.is_empty()
+    }
+
+    /// Latest explanation for a traced action.
+    #[must_use]
+    pub fn explanation(&self, action: GameAction) -> Option<&str> {
+        self.explanations.get(&action).map(String::as_str)
+    }
+
+    /// Store an explanation, returning whether it should be logged.
+    fn record(&mut self, action: GameAction, explanation: String) -> bool {
+        let changed = self.explanation(action) != Some(explanation.as_str());
+        self.explanations.insert(action, explanation);
+        changed || self.every_frame
+    }
+}
+
+/// A gamepad's view of an action for [`explain_action`].
+struct TracedGamepad<'a> {
+    /// Gamepad entity.
+    entity: Entity,
+    /// Owning player's number, if owned.
+    player: Option<u8>,
+    /// Raw gamepad input.
+    gamepad: &'a Gamepad,
+    /// Action state from this gamepad's bindings.
+    state: &'a GamepadActionState,
+}
+
+/// Inputs that are currently driving, or failing to drive, an action.
+fn gamepad_sources(action: GameAction, map: &ActionMap, pad: &TracedGamepad) -> Vec<String> {
+    let gamepad = pad.gamepad;
+    let shifted = map.shifted_buttons(|modifier| gamepad.pressed(modifier));
+    let mut sources = Vec::new();
+    let mut driving = false;
+
+    for button in map.gamepad_bindings.get(&action).into_iter().flatten() {
+        if gamepad.pressed(*button) {
+            if shifted.contains(button) {
+                sources.push(format!("{button:?} shifted by a modifier"));
+            } else {
+                sources.push(format!("{button:?}"));
+                driving = true;
+            }
+        }
+    }
+
+    for (modifier, button) in map.modifier_bindings.get(&action).into_iter().flatten() {
+        if gamepad.pressed(*modifier) && gamepad.pressed(*button) {
+            sources.push(format!("{modifier:?}+{button:?}"));
+            driving = true;
+        }
+    }
+
+    for (axis, direction, threshold) in map.axis_bindings.get(&action).into_iter().flatten() {
+        let value = gamepad.get(*axis).unwrap_or(0.0);
+        let value = match direction {
+            AxisDirection::Positive => value,
+            AxisDirection::Negative => -value,
+        };
+        if value > *threshold {
+            sources.push(format!("{axis:?} at {value:.2}"));
+            driving = true;
+        } else if value > 0.0 {
+            sources.push(format!("{axis:?} at {value:.2} below {threshold:.2}"));
+        }
+    }
+
+    // Anything else pressing this gamepad's state came from a stick direction
+    if pad.state.actions().pressed(action) && !driving {
+        sources.push("stick direction".to_string());
+    }
+
+    let device = match pad.player {
+        Some(player) => format!("gamepad {} (player {player})", pad.entity),
+        None => format!("gamepad {}", pad.entity),
+    };
+    sources
+        .into_iter()
+        .map(|source| format!("{source} on {device}"))
+        .collect()
+}
+
+/// Explain an action's state this frame as a single line.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "explains an action from every input source"
+)]
+fn explain_action<'a>(
+    action: GameAction,
+    state: &ActionState,
+    map: &ActionMap,
+    accepts_mouse_keyboard: bool,
+    keyboard: &ButtonInput<KeyCode>,
+    mouse_buttons: &ButtonInput<MouseButton>,
+    gamepads: impl IntoIterator<Item = TracedGamepad<'a>>,
+    simulated: &SimulatedInput,
+) -> String {
+    let ignored = if accepts_mouse_keyboard {
+        ""
+    } else {
+        " (mouse/keyboard ignored)"
+    };
+    let mut sources = Vec::new();
+
+    for key in map.key_bindings.get(&action).into_iter().flatten() {
+        if keyboard.pressed(*key) {
+            sources.push(format!("KeyCode::{key:?}{ignored}"));
+        }
+    }
+    for button in map.mouse_bindings.get(&action).into_iter().flatten() {
+        if mouse_buttons.pressed(*button) {
+            sources.push(format!("MouseButton::{button:?}{ignored}"));
+        }
+    }
+    for pad in gamepads {
+        sources.extend(gamepad_sources(action, map, &pad));
+    }
+    if simulated.get(action).is_some() {
+        sources.push("simulated input".to_string());
+    }
+
+    let status = if let Some(reason) = state.suppressed_by(action) {
+        format!("suppressed by {reason}")
+    } else if state.just_pressed(action) {
+        format!("just pressed at {:.2}", state.value(action))
+    } else if state.pressed(action) {
+        format!("pressed at {:.2}", state.value(action))
+    } else if state.just_released(action) {
+        "just released".to_string()
+    } else {
+        "released".to_string()
+    };
+
+    if sources.is_empty() {
+        format!("{action:?}: {status} (no input)")
+    } else {
+        format!("{action:?}: {status} ({})", sources.join("; "))
+    }
+}
+
+/// System to log the decision chain behind traced actions.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn log_input_trace(
+    mut trace: ResMut<InputTrace>,
+    state: Res<ActionState>,
+    action_map: Res<ActionMap>,
+    device_state: Res<InputDeviceState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    ownership: Res<ControllerOwnership>,
+    gamepads: Query<(Entity, &Gamepad, &GamepadActionState)>,
+    simulated: Res<SimulatedInput>,
+) {
+    for action in GameAction::all() {
+        let action = *action;
+        if !trace.is_tracing(action) {
+            continue;
+        }
+
+        let explanation = explain_action(
+            action,
+            &state,
+            &action_map,
+            device_state.accepts_mouse_keyboard(),
+            &keyboard,
+            &mouse_buttons,
+            gamepads
+                .iter()
+                .map(|(entity, gamepad, gamepad_state)| TracedGamepad {
+                    entity,
+                    player: ownership.get_owner(entity).map(|player| player.id()),
+                    gamepad,
+                    state: gamepad_state,
+                }),
+            &simulated,
+        );
+        if trace.record(action, explanation.clone()) {
+            info!("{explanation}");
+        }
+    }
+}
+
+/// Run condition for when any action is traced.
+fn trace_active(trace: Res<InputTrace>) -> bool {
+    trace.is_active()
+}
+
+/// Plugin for registering trace types.
+pub(crate) fn register_trace_types(app: &mut App) {
+    app.init_resource::<InputTrace>();
+}
+
+/// Add trace systems to the app.
+pub(crate) fn add_trace_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        log_input_trace
+            .run_if(subsystem_enabled(Subsystem::Debug))
+            .run_if(trace_active),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explain(state: &ActionState, keyboard: &ButtonInput<KeyCode>) -> String {
+        explain_action(
+            GameAction::Confirm,
+            state,
+            &ActionMap::default(),
+            true,
+            keyboard,
+            &ButtonInput::default(),
+            std::iter::empty(),
+            &SimulatedInput::default(),
+        )
+    }
+
+    #[test]
+    fn test_trace_selection() {
+        let mut trace = InputTrace::default();
+        assert!(!trace.is_active());
+
+        trace.trace(GameAction::Confirm);
+        assert!(trace.is_tracing(GameAction::Confirm));
+        assert!(trace.record(GameAction::Confirm, "a".to_string()));
+        assert!(!trace.record(GameAction::Confirm, "a".to_string()));
+        assert!(trace.record(GameAction::Confirm, "b".to_string()));
+
+        trace.untrace(GameAction::Confirm);
+        assert!(!trace.is_active());
+        assert_eq!(trace.explanation(GameAction::Confirm), None);
+    }
+
+    #[test]
+    fn test_explain_pressed_key() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Confirm, true);
+        state.set_value(GameAction::Confirm, 1.0);
+        let mut keyboard = ButtonInput::default();
+        keyboard.press(KeyCode::Enter);
+
+        let line = explain(&state, &keyboard);
+        assert!(line.starts_with("Confirm: just pressed"), "{line}");
+        assert!(line.contains("KeyCode::Enter"), "{line}");
+    }
+
+    #[test]
+    fn test_explain_suppressed() {
+        let mut state = ActionState::default();
+        state.set_pressed(GameAction::Confirm, true);
+        state.suppress(GameAction::Confirm, "chord clash");
+
+        let line = explain(&state, &ButtonInput::default());
+        assert_eq!(line, "Confirm: suppressed by chord clash (no input)");
+    }
+}
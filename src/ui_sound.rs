@@ -0,0 +1,168 @@
+//! UI navigation audio feedback hooks.
+//!
+//! This module provides a single event stream describing UI navigation
+//! moments (focus moved, confirmed, cancelled, blocked) so games can play
+//! menu sounds without writing observers for every widget.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::ui_sound::{UiNavigationSound, UiNavigationSoundKind};
+//!
+//! fn play_ui_sounds(mut sounds: MessageReader<UiNavigationSound>) {
+//!     for sound in sounds.read() {
+//!         match sound.kind {
+//!             UiNavigationSoundKind::FocusMoved => { /* play tick */ }
+//!             UiNavigationSoundKind::Confirmed => { /* play accept */ }
+//!             UiNavigationSoundKind::Cancelled => { /* play back */ }
+//!             UiNavigationSoundKind::Blocked => { /* play bump */ }
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::actions::{ActionState, GameAction};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// The kind of navigation moment that should play a sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum UiNavigationSoundKind {
+    /// Focus moved to another element.
+    FocusMoved,
+    /// The focused element was activated.
+    Confirmed,
+    /// The player backed out.
+    Cancelled,
+    /// Navigation was attempted but there was nowhere to go.
+    Blocked,
+}
+
+/// Which system produced a navigation sound event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum UiNavigationSource {
+    /// Generic action-driven menu navigation.
+    Actions,
+    /// The virtual cursor.
+    VirtualCursor,
+    /// The virtual keyboard.
+    VirtualKeyboard,
+}
+
+/// Event fired for every UI navigation moment that should play a sound.
+#[derive(Debug, Clone, Message)]
+pub struct UiNavigationSound {
+    /// What happened.
+    pub kind: UiNavigationSoundKind,
+    /// Which system it came from.
+    pub source: UiNavigationSource,
+}
+
+impl UiNavigationSound {
+    /// Create a new navigation sound event.
+    #[must_use]
+    pub const fn new(kind: UiNavigationSoundKind, source: UiNavigationSource) -> Self {
+        Self { kind, source }
+    }
+}
+
+/// Settings for navigation sound events.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct UiSoundSettings {
+    /// Emit events from the directional, Confirm, and Cancel actions.
+    ///
+    /// Off by default because actions also drive gameplay; enable it while
+    /// a menu is open.
+    pub emit_from_actions: bool,
+}
+
+/// Map an action press to a navigation sound, if it is a menu action.
+#[must_use]
+pub const fn sound_for_action(action: GameAction) -> Option<UiNavigationSoundKind> {
+    match action {
+        GameAction::Up
+        | GameAction::Down
+        | GameAction::Left
+        | GameAction::Right
+        | GameAction::PageLeft
+        | GameAction::PageRight => Some(UiNavigationSoundKind::FocusMoved),
+        GameAction::Confirm => Some(UiNavigationSoundKind::Confirmed),
+        GameAction::Cancel => Some(UiNavigationSoundKind::Cancelled),
+        _ => None,
+    }
+}
+
+/// System to emit navigation sounds from menu actions.
+pub fn emit_action_navigation_sounds(
+    settings: Res<UiSoundSettings>,
+    action_state: Res<ActionState>,
+    mut sounds: MessageWriter<UiNavigationSound>,
+) {
+    if !settings.emit_from_actions {
+        return;
+    }
+
+    for action in GameAction::all() {
+        if action_state.just_pressed(*action)
+            && let Some(kind) = sound_for_action(*action)
+        {
+            sounds.write(UiNavigationSound::new(kind, UiNavigationSource::Actions));
+        }
+    }
+}
+
+/// Plugin for registering UI sound types.
+pub(crate) fn register_ui_sound_types(app: &mut App) {
+    app.register_type::<UiNavigationSoundKind>()
+        .register_type::<UiNavigationSource>()
+        .init_resource::<UiSoundSettings>()
+        .add_message::<UiNavigationSound>();
+}
+
+/// Add UI sound systems to the app.
+pub(crate) fn add_ui_sound_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        emit_action_navigation_sounds.run_if(subsystem_enabled(Subsystem::UiSound)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_sound_settings_default() {
+        let settings = UiSoundSettings::default();
+        assert!(!settings.emit_from_actions);
+    }
+
+    #[test]
+    fn test_sound_for_action() {
+        assert_eq!(
+            sound_for_action(GameAction::Up),
+            Some(UiNavigationSoundKind::FocusMoved)
+        );
+        assert_eq!(
+            sound_for_action(GameAction::Confirm),
+            Some(UiNavigationSoundKind::Confirmed)
+        );
+        assert_eq!(
+            sound_for_action(GameAction::Cancel),
+            Some(UiNavigationSoundKind::Cancelled)
+        );
+        assert_eq!(sound_for_action(GameAction::Primary), None);
+    }
+
+    #[test]
+    fn test_ui_navigation_sound_new() {
+        let sound = UiNavigationSound::new(
+            UiNavigationSoundKind::Blocked,
+            UiNavigationSource::VirtualKeyboard,
+        );
+        assert_eq!(sound.kind, UiNavigationSoundKind::Blocked);
+        assert_eq!(sound.source, UiNavigationSource::VirtualKeyboard);
+    }
+}
@@ -0,0 +1,354 @@
+//! Continuous haptic generators for vehicles.
+//!
+//! Racing and driving games need rumble that follows the simulation every
+//! frame rather than one-shot patterns. The game writes floats (RPM, speed,
+//! surface roughness, wheel slip) into a [`VehicleHaptics`] component and
+//! this module turns them into motor intensities.
+//!
+//! Continuous rumble is mixed below one-shot effects: while a
+//! [`RumbleController`] on the same gamepad is playing, the vehicle output is
+//! scaled by [`VehicleHaptics::ducking`] so impacts and damage taps stay
+//! readable.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::vehicle_haptics::VehicleHaptics;
+//!
+//! fn drive(mut haptics: Query<&mut VehicleHaptics>) {
+//!     for mut haptics in &mut haptics {
+//!         haptics.engine.rpm = 4500.0;
+//!         haptics.surface.speed = 30.0;
+//!         haptics.surface.roughness = 0.4;
+//!         haptics.slip.slip = 0.1;
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::haptics::{RumbleController, RumbleIntensity, RumbleOutput};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Engine hum that follows an RPM value.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct EngineHum {
+    /// Current engine RPM.
+    pub rpm: f32,
+    /// RPM at idle.
+    pub idle_rpm: f32,
+    /// RPM at the redline.
+    pub max_rpm: f32,
+    /// Overall strength (0.0-1.0).
+    pub strength: f32,
+}
+
+impl Default for EngineHum {
+    fn default() -> Self {
+        Self {
+            rpm: 0.0,
+            idle_rpm: 800.0,
+            max_rpm: 7000.0,
+            strength: 0.4,
+        }
+    }
+}
+
+impl EngineHum {
+    /// Sample the hum at a point in time (seconds).
+    #[must_use]
+    pub fn sample(&self, time: f32) -> RumbleIntensity {
+        if self.rpm <= 0.0 {
+            return RumbleIntensity::none();
+        }
+
+        let load =
+            ((self.rpm - self.idle_rpm) / (self.max_rpm - self.idle_rpm).max(1.0)).clamp(0.0, 1.0);
+        let ripple = (time * TAU * self.rpm / 60.0).sin().mul_add(0.5, 0.5);
+
+        RumbleIntensity::new(
+            self.strength * load.mul_add(0.6, 0.2),
+            self.strength * load * ripple.mul_add(0.5, 0.5),
+        )
+    }
+}
+
+/// Road surface rumble that follows speed and roughness.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct SurfaceRumble {
+    /// Current speed (game units per second).
+    pub speed: f32,
+    /// Speed at which the rumble is strongest.
+    pub max_speed: f32,
+    /// Surface roughness (0.0 = smooth asphalt, 1.0 = gravel).
+    pub roughness: f32,
+    /// Overall strength (0.0-1.0).
+    pub strength: f32,
+}
+
+impl Default for SurfaceRumble {
+    fn default() -> Self {
+        Self {
+            speed: 0.0,
+            max_speed: 50.0,
+            roughness: 0.0,
+            strength: 0.6,
+        }
+    }
+}
+
+impl SurfaceRumble {
+    /// Sample the rumble at a point in time (seconds).
+    #[must_use]
+    pub fn sample(&self, time: f32) -> RumbleIntensity {
+        let level = (self.speed.abs() / self.max_speed.max(0.001)).clamp(0.0, 1.0)
+            * self.roughness.clamp(0.0, 1.0)
+            * self.strength;
+        let jitter = (time * 40.0).sin().mul_add(0.2, 0.8);
+
+        RumbleIntensity::new(level * 0.5, level * jitter)
+    }
+}
+
+/// High-frequency vibration while the tyres are slipping.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct SlipVibration {
+    /// Amount of slip (0.0 = full grip, 1.0 = sliding).
+    pub slip: f32,
+    /// Overall strength (0.0-1.0).
+    pub strength: f32,
+}
+
+impl Default for SlipVibration {
+    fn default() -> Self {
+        Self {
+            slip: 0.0,
+            strength: 0.8,
+        }
+    }
+}
+
+impl SlipVibration {
+    /// Sample the vibration.
+    #[must_use]
+    pub fn sample(&self) -> RumbleIntensity {
+        let level = self.slip.clamp(0.0, 1.0) * self.strength;
+        RumbleIntensity::new(level * 0.2, level)
+    }
+}
+
+/// Component that drives continuous vehicle rumble on a gamepad.
+#[derive(Debug, Clone, Component)]
+pub struct VehicleHaptics {
+    /// Target gamepad entity.
+    pub gamepad: Entity,
+    /// Engine hum generator.
+    pub engine: EngineHum,
+    /// Surface rumble generator.
+    pub surface: SurfaceRumble,
+    /// Tyre slip generator.
+    pub slip: SlipVibration,
+    /// Scale applied while a one-shot rumble is playing (0.0-1.0).
+    pub ducking: f32,
+    /// Whether the generators are active.
+    pub enabled: bool,
+    /// Current impact level, decaying over time.
+    impact: f32,
+    /// Time accumulator for oscillating generators.
+    time: f32,
+}
+
+impl VehicleHaptics {
+    /// Impact decay rate per second.
+    const IMPACT_DECAY: f32 = 8.0;
+
+    /// Create vehicle haptics for a gamepad with default generators.
+    #[must_use]
+    pub fn new(gamepad: Entity) -> Self {
+        Self {
+            gamepad,
+            engine: EngineHum::default(),
+            surface: SurfaceRumble::default(),
+            slip: SlipVibration::default(),
+            ducking: 0.3,
+            enabled: true,
+            impact: 0.0,
+            time: 0.0,
+        }
+    }
+
+    /// Trigger an impact (collision, landing) with a strength of 0.0-1.0.
+    ///
+    /// Stronger impacts replace weaker ones that are still fading out.
+    pub fn impact(&mut self, strength: f32) {
+        self.impact = self.impact.max(strength.clamp(0.0, 1.0));
+    }
+
+    /// Advance the generators by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        self.time += delta;
+        self.impact *= (-Self::IMPACT_DECAY * delta).exp();
+        if self.impact < 0.01 {
+            self.impact = 0.0;
+        }
+    }
+
+    /// Get the mixed output of every generator.
+    ///
+    /// `one_shot_playing` applies [`Self::ducking`] so one-shot effects take
+    /// priority over continuous rumble.
+    #[must_use]
+    pub fn output(&self, one_shot_playing: bool) -> RumbleIntensity {
+        if !self.enabled {
+            return RumbleIntensity::none();
+        }
+
+        let mixed = [
+            self.engine.sample(self.time),
+            self.surface.sample(self.time),
+            self.slip.sample(),
+            RumbleIntensity::uniform(self.impact),
+        ]
+        .into_iter()
+        .fold(RumbleIntensity::none(), |mixed, layer| {
+            RumbleIntensity::new(
+                mixed.low_frequency.max(layer.low_frequency),
+                mixed.high_frequency.max(layer.high_frequency),
+            )
+        });
+
+        if one_shot_playing {
+            RumbleIntensity::new(
+                mixed.low_frequency * self.ducking,
+                mixed.high_frequency * self.ducking,
+            )
+        } else {
+            mixed
+        }
+    }
+}
+
+/// System to play vehicle haptics.
+pub fn update_vehicle_haptics(
+    mut vehicles: Query<&mut VehicleHaptics>,
+    controllers: Query<&RumbleController>,
+    mut outputs: Query<&mut RumbleOutput>,
+    time: Res<Time>,
+) {
+    for mut vehicle in &mut vehicles {
+        vehicle.tick(time.delta_secs());
+
+        let one_shot_playing = controllers
+            .get(vehicle.gamepad)
+            .is_ok_and(|controller| !controller.duration.is_zero());
+        if let Ok(mut output) = outputs.get_mut(vehicle.gamepad) {
+            output.request(vehicle.output(one_shot_playing));
+        }
+    }
+}
+
+/// Plugin for registering vehicle haptics types.
+pub(crate) fn register_vehicle_haptics_types(app: &mut App) {
+    app.register_type::<EngineHum>()
+        .register_type::<SurfaceRumble>()
+        .register_type::<SlipVibration>();
+}
+
+/// Add vehicle haptics systems to the app.
+pub(crate) fn add_vehicle_haptics_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.update,
+        update_vehicle_haptics
+            .after(crate::haptics::update_rumble)
+            .before(crate::haptics::send_rumble_output)
+            .run_if(subsystem_enabled(Subsystem::VehicleHaptics)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_engine_hum_rises_with_rpm() {
+        let mut engine = EngineHum::default();
+        engine.rpm = engine.idle_rpm;
+        let idle = engine.sample(0.0);
+
+        engine.rpm = engine.max_rpm;
+        let redline = engine.sample(0.0);
+
+        assert!(redline.low_frequency > idle.low_frequency);
+        assert_relative_eq!(idle.low_frequency, engine.strength * 0.2);
+    }
+
+    #[test]
+    fn test_engine_off_is_silent() {
+        let engine = EngineHum::default();
+        assert_eq!(engine.sample(1.0), RumbleIntensity::none());
+    }
+
+    #[test]
+    fn test_surface_rumble_needs_speed_and_roughness() {
+        let mut surface = SurfaceRumble {
+            roughness: 1.0,
+            ..default()
+        };
+        assert_eq!(surface.sample(0.0), RumbleIntensity::none());
+
+        surface.speed = surface.max_speed;
+        assert!(surface.sample(0.0).high_frequency > 0.0);
+
+        surface.roughness = 0.0;
+        assert_eq!(surface.sample(0.0), RumbleIntensity::none());
+    }
+
+    #[test]
+    fn test_slip_vibration() {
+        let slip = SlipVibration {
+            slip: 1.0,
+            strength: 0.5,
+        };
+        let sample = slip.sample();
+        assert_relative_eq!(sample.high_frequency, 0.5);
+        assert_relative_eq!(sample.low_frequency, 0.1);
+    }
+
+    #[test]
+    fn test_impact_decays() {
+        let mut haptics = VehicleHaptics::new(Entity::PLACEHOLDER);
+        haptics.impact(1.0);
+        assert_relative_eq!(haptics.output(false).low_frequency, 1.0);
+
+        haptics.tick(0.1);
+        let faded = haptics.output(false).low_frequency;
+        assert!(faded < 1.0 && faded > 0.0);
+
+        haptics.tick(2.0);
+        assert_eq!(haptics.output(false), RumbleIntensity::none());
+    }
+
+    #[test]
+    fn test_one_shot_ducks_continuous_rumble() {
+        let mut haptics = VehicleHaptics::new(Entity::PLACEHOLDER);
+        haptics.slip.slip = 1.0;
+
+        let full = haptics.output(false);
+        let ducked = haptics.output(true);
+        assert_relative_eq!(ducked.high_frequency, full.high_frequency * haptics.ducking);
+    }
+
+    #[test]
+    fn test_disabled_is_silent() {
+        let mut haptics = VehicleHaptics::new(Entity::PLACEHOLDER);
+        haptics.slip.slip = 1.0;
+        haptics.enabled = false;
+        assert_eq!(haptics.output(false), RumbleIntensity::none());
+    }
+}
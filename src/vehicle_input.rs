@@ -0,0 +1,508 @@
+//! Persistent vehicle controls driven by gamepad axes.
+//!
+//! Flight and racing games need controls that remember where they were left
+//! rather than springing back with the trigger. [`Throttle`] turns a trigger
+//! pair or stick axis into a persistent 0.0-1.0 throttle that moves at a
+//! fixed rate, with optional detents (idle, cruise, full) that catch the
+//! throttle and play a short haptic tick. [`Steering`] turns stick X into a
+//! steering wheel angle with a configurable lock-to-lock range, shaped
+//! response, return-to-center, and countersteer assist.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::vehicle_input::{Steering, Throttle};
+//!
+//! fn add_throttle(mut commands: Commands, gamepads: Query<Entity, Added<Gamepad>>) {
+//!     for gamepad in &gamepads {
+//!         commands
+//!             .entity(gamepad)
+//!             .insert(Throttle::triggers().with_action(GameAction::Custom1));
+//!     }
+//! }
+//!
+//! fn fly(throttles: Query<&Throttle>) {
+//!     for throttle in &throttles {
+//!         info!("throttle {:.0}%", throttle.value() * 100.0);
+//!     }
+//! }
+//!
+//! fn drive(mut wheels: Query<&mut Steering>) {
+//!     for mut wheel in &mut wheels {
+//!         // Feed the car's slip so the assist can catch slides.
+//!         wheel.slip_angle = 12.0;
+//!         info!("wheel at {:.0} degrees", wheel.angle());
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::actions::{ActionState, GameAction};
+use crate::haptics::RumbleRequest;
+use crate::stick::Stick;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Where a throttle reads its input from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ThrottleInput {
+    /// One analog button raises the throttle, the other lowers it.
+    Triggers {
+        /// Button that raises the throttle.
+        increase: GamepadButton,
+        /// Button that lowers the throttle.
+        decrease: GamepadButton,
+    },
+    /// A stick's Y axis raises (up) or lowers (down) the throttle.
+    StickY(Stick),
+}
+
+impl ThrottleInput {
+    /// Read the signed input (-1.0 to 1.0) from a gamepad.
+    #[must_use]
+    pub fn read(self, gamepad: &Gamepad) -> f32 {
+        match self {
+            Self::Triggers { increase, decrease } => {
+                gamepad.get(increase).unwrap_or(0.0) - gamepad.get(decrease).unwrap_or(0.0)
+            }
+            Self::StickY(stick) => stick.read(gamepad).y,
+        }
+    }
+}
+
+/// Component holding a persistent throttle on a gamepad entity.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Throttle {
+    /// Input source.
+    pub input: ThrottleInput,
+    /// Throttle change per second at full input.
+    pub rate: f32,
+    /// Input below this magnitude is ignored.
+    pub deadzone: f32,
+    /// Throttle positions (0.0-1.0) that catch the throttle.
+    pub detents: Vec<f32>,
+    /// Seconds of continued input needed to push through a detent.
+    pub detent_hold: f32,
+    /// Rumble strength of the tick when a detent is reached (0.0 disables).
+    pub detent_rumble: f32,
+    /// Action that receives the throttle as its analog value.
+    pub action: Option<GameAction>,
+    /// Current throttle.
+    value: f32,
+    /// Detent the throttle is resting in.
+    detent: Option<f32>,
+    /// Seconds left before the throttle leaves its detent.
+    detent_timer: f32,
+}
+
+impl Throttle {
+    /// Create a throttle with idle, cruise, and full detents.
+    #[must_use]
+    pub fn new(input: ThrottleInput) -> Self {
+        Self {
+            input,
+            rate: 0.5,
+            deadzone: 0.1,
+            detents: vec![0.0, 0.5, 1.0],
+            detent_hold: 0.25,
+            detent_rumble: 0.3,
+            action: None,
+            value: 0.0,
+            detent: None,
+            detent_timer: 0.0,
+        }
+    }
+
+    /// Create a throttle on the right (raise) and left (lower) triggers.
+    #[must_use]
+    pub fn triggers() -> Self {
+        Self::new(ThrottleInput::Triggers {
+            increase: GamepadButton::RightTrigger2,
+            decrease: GamepadButton::LeftTrigger2,
+        })
+    }
+
+    /// Write the throttle to an action's analog value.
+    #[must_use]
+    pub const fn with_action(mut self, action: GameAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Set the change per second at full input.
+    #[must_use]
+    pub const fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Set the detent positions.
+    #[must_use]
+    pub fn with_detents(mut self, detents: impl Into<Vec<f32>>) -> Self {
+        self.detents = detents.into();
+        self
+    }
+
+    /// Current throttle (0.0-1.0).
+    #[must_use]
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Detent the throttle is resting in, if any.
+    #[must_use]
+    pub const fn detent(&self) -> Option<f32> {
+        self.detent
+    }
+
+    /// Set the throttle directly, e.g. when spawning mid-flight.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value.clamp(0.0, 1.0);
+        self.detent = None;
+    }
+
+    /// Advance the throttle with a signed input, returning a detent it
+    /// just reached.
+    pub fn update(&mut self, input: f32, delta: f32) -> Option<f32> {
+        let input = if input.abs() < self.deadzone {
+            0.0
+        } else {
+            input.clamp(-1.0, 1.0)
+        };
+
+        if input == 0.0 {
+            // Letting go re-arms the detent.
+            self.detent_timer = self.detent_hold;
+            return None;
+        }
+
+        if self.detent.is_some() {
+            self.detent_timer -= delta;
+            if self.detent_timer > 0.0 {
+                return None;
+            }
+            self.detent = None;
+        }
+
+        let previous = self.value;
+        let next = input.mul_add(self.rate * delta, previous).clamp(0.0, 1.0);
+        let crossed = self
+            .detents
+            .iter()
+            .copied()
+            .filter(|detent| {
+                if next > previous {
+                    *detent > previous && *detent <= next
+                } else {
+                    *detent < previous && *detent >= next
+                }
+            })
+            .min_by(|a, b| (a - previous).abs().total_cmp(&(b - previous).abs()));
+
+        if let Some(detent) = crossed {
+            self.value = detent;
+            self.detent = Some(detent);
+            self.detent_timer = self.detent_hold;
+        } else {
+            self.value = next;
+        }
+        crossed
+    }
+}
+
+/// Event fired when a throttle settles into a detent.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct ThrottleDetentReached {
+    /// Gamepad entity holding the throttle.
+    pub gamepad: Entity,
+    /// Detent position.
+    pub detent: f32,
+}
+
+/// System to update throttles and write them to actions.
+pub fn update_throttles(
+    time: Res<Time>,
+    mut action_state: ResMut<ActionState>,
+    mut throttles: Query<(Entity, &Gamepad, &mut Throttle)>,
+    mut detent_events: MessageWriter<ThrottleDetentReached>,
+    mut rumble_requests: MessageWriter<RumbleRequest>,
+) {
+    for (entity, gamepad, mut throttle) in &mut throttles {
+        let input = throttle.input.read(gamepad);
+        if let Some(detent) = throttle.update(input, time.delta_secs()) {
+            detent_events.write(ThrottleDetentReached {
+                gamepad: entity,
+                detent,
+            });
+            if throttle.detent_rumble > 0.0 {
+                rumble_requests.write(RumbleRequest::new(
+                    entity,
+                    throttle.detent_rumble,
+                    Duration::from_millis(40),
+                ));
+            }
+        }
+
+        if let Some(action) = throttle.action {
+            let value = action_state.value(action).max(throttle.value());
+            action_state.set_value(action, value);
+        }
+    }
+}
+
+/// Component emulating a steering wheel from a stick on a gamepad entity.
+///
+/// Angles are in degrees, positive to the right.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Steering {
+    /// Stick whose X axis steers.
+    pub stick: Stick,
+    /// Lock-to-lock rotation in degrees (900 for a road car wheel).
+    pub rotation_range: f32,
+    /// Degrees per second the wheel turns toward the stick.
+    pub steer_speed: f32,
+    /// Degrees per second the wheel returns with the stick centered.
+    pub return_speed: f32,
+    /// Response exponent: 1.0 is linear, higher is finer near center.
+    pub linearity: f32,
+    /// Stick deflection ignored around center.
+    pub deadzone: f32,
+    /// Multiplier on [`Self::steer_speed`] when steering back across center.
+    pub countersteer_rate: f32,
+    /// How much of [`Self::slip_angle`] is steered against (0.0-1.0).
+    pub countersteer_assist: f32,
+    /// Vehicle slip angle in degrees, written by the game each frame.
+    pub slip_angle: f32,
+    /// Current wheel angle.
+    angle: f32,
+}
+
+impl Default for Steering {
+    fn default() -> Self {
+        Self {
+            stick: Stick::Left,
+            rotation_range: 900.0,
+            steer_speed: 720.0,
+            return_speed: 540.0,
+            linearity: 1.5,
+            deadzone: 0.1,
+            countersteer_rate: 1.5,
+            countersteer_assist: 0.0,
+            slip_angle: 0.0,
+            angle: 0.0,
+        }
+    }
+}
+
+impl Steering {
+    /// Create steering with a lock-to-lock range in degrees.
+    #[must_use]
+    pub fn new(rotation_range: f32) -> Self {
+        Self {
+            rotation_range,
+            ..default()
+        }
+    }
+
+    /// Set the countersteer assist strength.
+    #[must_use]
+    pub const fn with_countersteer_assist(mut self, assist: f32) -> Self {
+        self.countersteer_assist = assist;
+        self
+    }
+
+    /// Set the response exponent.
+    #[must_use]
+    pub const fn with_linearity(mut self, linearity: f32) -> Self {
+        self.linearity = linearity;
+        self
+    }
+
+    /// Current wheel angle in degrees.
+    #[must_use]
+    pub const fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// Wheel angle relative to full lock (-1.0 to 1.0).
+    #[must_use]
+    pub fn normalized(&self) -> f32 {
+        let half = self.rotation_range * 0.5;
+        if half <= 0.0 {
+            0.0
+        } else {
+            (self.angle / half).clamp(-1.0, 1.0)
+        }
+    }
+
+    /// Angle the wheel is heading toward for a stick X value.
+    #[must_use]
+    pub fn target_angle(&self, stick_x: f32) -> f32 {
+        let half = self.rotation_range * 0.5;
+        let magnitude = stick_x.abs();
+        let shaped = if magnitude < self.deadzone {
+            0.0
+        } else {
+            let scaled = (magnitude - self.deadzone) / (1.0 - self.deadzone).max(f32::EPSILON);
+            scaled.min(1.0).powf(self.linearity) * stick_x.signum()
+        };
+
+        let assist = self.slip_angle * self.countersteer_assist.clamp(0.0, 1.0);
+        (shaped * half - assist).clamp(-half, half)
+    }
+
+    /// Advance the wheel toward the stick.
+    pub fn update(&mut self, stick_x: f32, delta: f32) {
+        let target = self.target_angle(stick_x);
+        let centered = stick_x.abs() < self.deadzone;
+        let crossing = self.angle != 0.0 && target.signum() != self.angle.signum();
+
+        let speed = if centered {
+            self.return_speed
+        } else if crossing {
+            self.steer_speed * self.countersteer_rate
+        } else {
+            self.steer_speed
+        };
+
+        let step = speed * delta;
+        let difference = target - self.angle;
+        self.angle = if difference.abs() <= step {
+            target
+        } else {
+            step.mul_add(difference.signum(), self.angle)
+        };
+    }
+}
+
+/// System to update steering wheels from their sticks.
+pub fn update_steering(time: Res<Time>, mut wheels: Query<(&Gamepad, &mut Steering)>) {
+    for (gamepad, mut steering) in &mut wheels {
+        let stick_x = steering.stick.read(gamepad).x;
+        steering.update(stick_x, time.delta_secs());
+    }
+}
+
+/// Plugin for registering vehicle input types.
+pub(crate) fn register_vehicle_input_types(app: &mut App) {
+    app.register_type::<ThrottleInput>()
+        .register_type::<Throttle>()
+        .register_type::<Steering>()
+        .add_message::<ThrottleDetentReached>();
+}
+
+/// Add vehicle input systems to the app.
+pub(crate) fn add_vehicle_input_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.input,
+        (update_throttles, update_steering)
+            .after(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::VehicleInput)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn throttle() -> Throttle {
+        Throttle::triggers().with_rate(1.0)
+    }
+
+    #[test]
+    fn test_throttle_persists_without_input() {
+        let mut throttle = throttle().with_detents(Vec::new());
+        throttle.update(1.0, 0.3);
+        assert_relative_eq!(throttle.value(), 0.3);
+
+        throttle.update(0.0, 1.0);
+        assert_relative_eq!(throttle.value(), 0.3);
+
+        throttle.update(-1.0, 0.1);
+        assert_relative_eq!(throttle.value(), 0.2);
+    }
+
+    #[test]
+    fn test_detent_catches_throttle() {
+        let mut throttle = throttle();
+        throttle.set_value(0.4);
+
+        assert_eq!(throttle.update(1.0, 0.2), Some(0.5));
+        assert_relative_eq!(throttle.value(), 0.5);
+
+        // Held at the detent until pushed through
+        assert_eq!(throttle.update(1.0, 0.1), None);
+        assert_relative_eq!(throttle.value(), 0.5);
+        throttle.update(1.0, 0.1);
+        assert_relative_eq!(throttle.value(), 0.5);
+        throttle.update(1.0, 0.1);
+        assert!(throttle.value() > 0.5);
+        assert_eq!(throttle.detent(), None);
+    }
+
+    #[test]
+    fn test_throttle_clamps_to_full() {
+        let mut throttle = throttle();
+        throttle.set_value(0.9);
+        assert_eq!(throttle.update(1.0, 1.0), Some(1.0));
+        assert_relative_eq!(throttle.value(), 1.0);
+    }
+
+    #[test]
+    fn test_deadzone_ignores_small_input() {
+        let mut throttle = throttle();
+        throttle.set_value(0.2);
+        throttle.update(0.05, 1.0);
+        assert_relative_eq!(throttle.value(), 0.2);
+    }
+
+    #[test]
+    fn test_steering_turns_toward_full_lock() {
+        let mut steering = Steering::new(900.0).with_linearity(1.0);
+        steering.deadzone = 0.0;
+
+        steering.update(1.0, 0.25);
+        assert_relative_eq!(steering.angle(), 180.0);
+
+        steering.update(1.0, 1.0);
+        assert_relative_eq!(steering.angle(), 450.0);
+        assert_relative_eq!(steering.normalized(), 1.0);
+    }
+
+    #[test]
+    fn test_steering_returns_to_center() {
+        let mut steering = Steering::default();
+        steering.update(1.0, 0.1);
+        let turned = steering.angle();
+        assert!(turned > 0.0);
+
+        steering.update(0.0, 0.05);
+        assert!(steering.angle() < turned);
+        steering.update(0.0, 1.0);
+        assert_relative_eq!(steering.angle(), 0.0);
+    }
+
+    #[test]
+    fn test_linearity_softens_center() {
+        let linear = Steering::default().with_linearity(1.0);
+        let curved = Steering::default().with_linearity(2.0);
+        assert!(curved.target_angle(0.5).abs() < linear.target_angle(0.5).abs());
+        assert_relative_eq!(curved.target_angle(1.0), linear.target_angle(1.0));
+    }
+
+    #[test]
+    fn test_countersteer_assist_opposes_slip() {
+        let mut steering = Steering::default().with_countersteer_assist(0.5);
+        steering.slip_angle = 20.0;
+        assert_relative_eq!(steering.target_angle(0.0), -10.0);
+    }
+}
@@ -2,15 +2,40 @@
 //!
 //! This module provides a virtual cursor that can be controlled with gamepad
 //! analog sticks, allowing gamepad users to interact with mouse-based UI.
+//!
+//! # Multiple windows
+//!
+//! A cursor moves in the primary window unless it has a [`CursorWindow`].
+//! Give each window (or editor-docked game view) its own cursor: only the
+//! cursor in the focused window moves and is shown, and clicks and drags go
+//! to that window. Cursor positions are centred on their window with Y up;
+//! [`VirtualCursorState::window_position`] converts to the window's
+//! top-left coordinates.
+//!
+//! # Gyro pointing
+//!
+//! With [`CursorInputMode::Gyro`], turning the controller moves the cursor
+//! like a gyro mouse: yaw moves it sideways and pitch moves it up and down,
+//! [`VirtualCursor::gyro_speed`] pixels per radian, following the gyro
+//! settings in [`MotionConfig`]. Pressing
+//! [`VirtualCursor::recenter_button`] snaps the cursor back to the middle of
+//! its window. Gamepads without gyro data keep using the stick.
 
 use bevy::prelude::*;
+use bevy::ui::{ComputedNode, ComputedStackIndex, UiGlobalTransform};
 use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
 
 use crate::config::ControllerConfig;
 use crate::detection::InputDeviceState;
+use crate::gyro::{GyroAim, GyroData, MotionConfig};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::ui_sound::{UiNavigationSound, UiNavigationSoundKind, UiNavigationSource};
 
 /// Component marking an entity as the virtual cursor.
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Default)]
+#[serde(default)]
 pub struct VirtualCursor {
     /// Current position of the cursor.
     pub position: Vec2,
@@ -20,6 +45,16 @@ pub struct VirtualCursor {
     pub visible: bool,
     /// Which stick controls the cursor (true = left, false = right).
     pub use_left_stick: bool,
+    /// Distance in pixels the cursor must move while held before a drag starts.
+    pub drag_threshold: f32,
+    /// Speed multiplier while dragging (1.0 = no slowdown).
+    pub drag_speed_scale: f32,
+    /// Whether the stick or the gyro moves the cursor.
+    pub input_mode: CursorInputMode,
+    /// Pixels the cursor moves per radian the controller turns in gyro mode.
+    pub gyro_speed: f32,
+    /// Button that recenters the cursor in gyro mode.
+    pub recenter_button: Option<GamepadButton>,
 }
 
 impl Default for VirtualCursor {
@@ -29,10 +64,52 @@ impl Default for VirtualCursor {
             speed: 600.0, // Pixels per second
             visible: false,
             use_left_stick: false, // Use right stick by default
+            drag_threshold: 8.0,
+            drag_speed_scale: 1.0,
+            input_mode: CursorInputMode::Stick,
+            gyro_speed: 1000.0,
+            recenter_button: Some(GamepadButton::RightThumb),
         }
     }
 }
 
+impl VirtualCursor {
+    /// Cursor movement for a frame of gyro input, before drag slowdown.
+    #[must_use]
+    pub fn gyro_delta(&self, gyro: &GyroData, config: &MotionConfig, delta_secs: f32) -> Vec2 {
+        let mut aim = GyroAim::default();
+        aim.update(gyro, config, delta_secs);
+        // Positive yaw turns left, the cursor's X grows to the right
+        Vec2::new(-aim.delta.x, aim.delta.y) * self.gyro_speed
+    }
+}
+
+/// What moves a [`VirtualCursor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum CursorInputMode {
+    /// The configured analog stick.
+    #[default]
+    Stick,
+    /// Turning the controller, for gamepads that report gyro data.
+    Gyro,
+}
+
+/// Window a [`VirtualCursor`] moves in.
+///
+/// Cursors without one use the primary window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct CursorWindow(pub Entity);
+
+/// Resolve the window entity a cursor belongs to.
+fn cursor_window_entity(
+    cursor_window: Option<&CursorWindow>,
+    primary: &Query<Entity, With<PrimaryWindow>>,
+) -> Option<Entity> {
+    cursor_window
+        .map(|window| window.0)
+        .or_else(|| primary.single().ok())
+}
+
 /// Click state for the virtual cursor.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ClickState {
@@ -52,12 +129,33 @@ pub enum ClickState {
 pub struct VirtualCursorState {
     /// Whether the virtual cursor is active.
     pub active: bool,
-    /// Current cursor position in screen space.
+    /// Current cursor position, centred on its window with Y up.
     pub position: Vec2,
+    /// Window of the cursor that moved last; clicks and drags go here.
+    pub window: Option<Entity>,
     /// Current click state.
     pub click_state: ClickState,
+    /// Press-and-hold drag in progress, if any.
+    pub drag: Option<CursorDrag>,
 }
 
+/// A press-and-hold drag tracked by the virtual cursor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CursorDrag {
+    /// Position where the click started.
+    pub origin: Vec2,
+    /// Position at the last update.
+    pub last: Vec2,
+    /// Draggable entity under the cursor when the click started.
+    pub entity: Option<Entity>,
+    /// Whether the cursor has moved far enough to count as a drag.
+    pub started: bool,
+}
+
+/// Marker for UI nodes that can be picked up by a virtual cursor drag.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct Draggable;
+
 impl VirtualCursorState {
     /// Reset frame state (call at start of frame).
     pub fn reset_frame_state(&mut self) {
@@ -100,17 +198,109 @@ impl VirtualCursorState {
     pub const fn just_released(&self) -> bool {
         matches!(self.click_state, ClickState::JustReleased)
     }
+
+    /// Check if a drag is in progress.
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some_and(|drag| drag.started)
+    }
+
+    /// Cursor position in a window's logical pixels from the top-left
+    /// corner, as [`Window::cursor_position`] reports it.
+    #[must_use]
+    pub fn window_position(&self, window: &Window) -> Vec2 {
+        Vec2::new(
+            self.position.x + window.width() / 2.0,
+            window.height() / 2.0 - self.position.y,
+        )
+    }
+
+    /// Advance drag tracking for this frame.
+    ///
+    /// `hovered` returns the draggable entity under a position.
+    fn step_drag(
+        &mut self,
+        threshold: f32,
+        hovered: impl Fn(Vec2) -> Option<Entity>,
+    ) -> Option<DragStep> {
+        let position = self.position;
+
+        if self.just_clicked() {
+            self.drag = Some(CursorDrag {
+                origin: position,
+                last: position,
+                entity: hovered(position),
+                started: false,
+            });
+        }
+
+        let mut drag = self.drag?;
+
+        if !self.is_clicking() {
+            self.drag = None;
+            return drag.started.then(|| {
+                DragStep::End(CursorDragEnd {
+                    entity: drag.entity,
+                    position,
+                    total_delta: position - drag.origin,
+                    target: hovered(position),
+                })
+            });
+        }
+
+        let step = if !drag.started {
+            if position.distance(drag.origin) >= threshold {
+                drag.started = true;
+                Some(DragStep::Start(CursorDragStart {
+                    entity: drag.entity,
+                    position: drag.origin,
+                }))
+            } else {
+                None
+            }
+        } else if position != drag.last {
+            Some(DragStep::Move(CursorDragMove {
+                entity: drag.entity,
+                position,
+                delta: position - drag.last,
+                total_delta: position - drag.origin,
+            }))
+        } else {
+            None
+        };
+
+        drag.last = position;
+        self.drag = Some(drag);
+        step
+    }
+}
+
+/// One frame's drag transition.
+#[derive(Debug, Clone, PartialEq)]
+enum DragStep {
+    Start(CursorDragStart),
+    Move(CursorDragMove),
+    End(CursorDragEnd),
 }
 
 /// System to update virtual cursor position based on gamepad input.
+///
+/// Only cursors in a focused window move. Switching windows drops any drag
+/// in progress, since its coordinates belong to the old window.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
 pub fn update_virtual_cursor(
     time: Res<Time>,
     config: Res<ControllerConfig>,
     input_state: Res<InputDeviceState>,
+    motion_config: Res<MotionConfig>,
     mut cursor_state: ResMut<VirtualCursorState>,
-    gamepads: Query<&Gamepad>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    mut cursor_query: Query<(&mut Transform, &VirtualCursor)>,
+    gamepads: Query<(&Gamepad, Option<&GyroData>)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    mut cursor_query: Query<(&mut Transform, &VirtualCursor, Option<&CursorWindow>)>,
 ) {
     // Only active when using gamepad
     if !input_state.using_gamepad() {
@@ -118,43 +308,64 @@ pub fn update_virtual_cursor(
         return;
     }
 
-    let Ok(window) = window_query.single() else {
-        return;
-    };
-
-    // Get gamepad input
-    let mut cursor_delta = Vec2::ZERO;
-    for gamepad in gamepads.iter() {
-        // Check if we should use this gamepad
-        if let Some(_active_gamepad) = input_state.active_gamepad() {
-            if cursor_query.is_empty() {
-                continue;
-            }
+    let (gamepad, gyro) = input_state
+        .active_gamepad()
+        .and_then(|entity| gamepads.get(entity).ok())
+        .map_or((None, None), |(gamepad, gyro)| {
+            (Some(gamepad), gyro.filter(|gyro| gyro.valid))
+        });
 
-            for (_transform, virtual_cursor) in &mut cursor_query {
-                // Get stick input based on configuration
-                let (x_axis, y_axis) = if virtual_cursor.use_left_stick {
-                    (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY)
-                } else {
-                    (GamepadAxis::RightStickX, GamepadAxis::RightStickY)
-                };
+    for (mut transform, virtual_cursor, cursor_window) in &mut cursor_query {
+        let Some(window_entity) = cursor_window_entity(cursor_window, &primary_window) else {
+            continue;
+        };
+        let Ok(window) = windows.get(window_entity) else {
+            continue;
+        };
+        if !window.focused {
+            continue;
+        }
 
-                if let (Some(x), Some(y)) = (gamepad.get(x_axis), gamepad.get(y_axis)) {
-                    // Apply deadzone and sensitivity
-                    let mut input = config.apply_deadzone_2d(x, y, virtual_cursor.use_left_stick);
+        // Get stick input based on configuration
+        let (x_axis, y_axis) = if virtual_cursor.use_left_stick {
+            (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY)
+        } else {
+            (GamepadAxis::RightStickX, GamepadAxis::RightStickY)
+        };
 
-                    // Apply inversion
-                    input = config.apply_inversion(input, virtual_cursor.use_left_stick);
+        let drag_scale = if cursor_state.is_dragging() {
+            virtual_cursor.drag_speed_scale
+        } else {
+            1.0
+        };
 
-                    cursor_delta = input * virtual_cursor.speed * time.delta_secs();
-                }
-            }
+        let mut cursor_delta = Vec2::ZERO;
+        let mut recenter = false;
+        if virtual_cursor.input_mode == CursorInputMode::Gyro
+            && let Some(gyro) = gyro
+        {
+            cursor_delta =
+                virtual_cursor.gyro_delta(gyro, &motion_config, time.delta_secs()) * drag_scale;
+            recenter = gamepad
+                .zip(virtual_cursor.recenter_button)
+                .is_some_and(|(gamepad, button)| gamepad.just_pressed(button));
+        } else if let Some(gamepad) = gamepad
+            && let (Some(x), Some(y)) = (gamepad.get(x_axis), gamepad.get(y_axis))
+        {
+            // Apply deadzone and sensitivity
+            let mut input = config.apply_deadzone_2d(x, y, virtual_cursor.use_left_stick);
+
+            // Apply inversion
+            input = config.apply_inversion(input, virtual_cursor.use_left_stick);
+
+            cursor_delta = input * virtual_cursor.speed * drag_scale * time.delta_secs();
         }
-    }
 
-    // Update cursor position
-    if let Ok((mut transform, _)) = cursor_query.single_mut() {
-        let new_pos = transform.translation.truncate() + cursor_delta;
+        let new_pos = if recenter {
+            Vec2::ZERO
+        } else {
+            transform.translation.truncate() + cursor_delta
+        };
 
         // Clamp to window bounds
         let half_width: f32 = window.width() / 2.0;
@@ -165,38 +376,49 @@ pub fn update_virtual_cursor(
         );
 
         transform.translation = clamped.extend(transform.translation.z);
+        if cursor_state.window != Some(window_entity) {
+            cursor_state.window = Some(window_entity);
+            cursor_state.drag = None;
+        }
         cursor_state.position = clamped;
-        cursor_state.active = cursor_delta.length() > 0.01;
+        cursor_state.active = recenter || cursor_delta.length() > 0.01;
     }
 }
 
 /// System to handle virtual cursor click input.
 pub fn handle_virtual_cursor_clicks(
     mut cursor_state: ResMut<VirtualCursorState>,
+    config: Res<ControllerConfig>,
     gamepads: Query<&Gamepad>,
 ) {
     cursor_state.reset_frame_state();
 
+    let click_button = config.confirm_button();
     for gamepad in gamepads.iter() {
-        // A button to click
-        if gamepad.just_pressed(GamepadButton::South) {
+        // Confirm button to click
+        if gamepad.just_pressed(click_button) {
             cursor_state.start_click();
         }
-        if gamepad.just_released(GamepadButton::South) {
+        if gamepad.just_released(click_button) {
             cursor_state.end_click();
         }
     }
 }
 
 /// System to show/hide virtual cursor based on input device.
+///
+/// Cursors are shown only in the focused window.
 pub fn toggle_virtual_cursor_visibility(
     input_state: Res<InputDeviceState>,
-    mut cursor_query: Query<&mut Visibility, With<VirtualCursor>>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    mut cursor_query: Query<(&mut Visibility, Option<&CursorWindow>), With<VirtualCursor>>,
 ) {
-    let should_show = input_state.using_gamepad();
-
-    for mut visibility in &mut cursor_query {
-        *visibility = if should_show {
+    for (mut visibility, cursor_window) in &mut cursor_query {
+        let focused = cursor_window_entity(cursor_window, &primary_window)
+            .and_then(|window| windows.get(window).ok())
+            .is_some_and(|window| window.focused);
+        *visibility = if input_state.using_gamepad() && focused {
             Visibility::Visible
         } else {
             Visibility::Hidden
@@ -209,17 +431,142 @@ pub fn toggle_virtual_cursor_visibility(
 pub struct VirtualCursorClick {
     /// Position where the click occurred.
     pub position: Vec2,
+    /// Window the click occurred in.
+    pub window: Option<Entity>,
 }
 
 /// System to fire click events.
 pub fn fire_virtual_cursor_events(
     cursor_state: Res<VirtualCursorState>,
     mut click_events: MessageWriter<VirtualCursorClick>,
+    mut sounds: MessageWriter<UiNavigationSound>,
 ) {
     if cursor_state.just_clicked() {
         click_events.write(VirtualCursorClick {
             position: cursor_state.position,
+            window: cursor_state.window,
         });
+        sounds.write(UiNavigationSound::new(
+            UiNavigationSoundKind::Confirmed,
+            UiNavigationSource::VirtualCursor,
+        ));
+    }
+}
+
+/// Event fired when a press-and-hold drag starts.
+#[derive(Debug, Clone, PartialEq, Message)]
+pub struct CursorDragStart {
+    /// Draggable entity under the cursor when the click started.
+    pub entity: Option<Entity>,
+    /// Position where the drag started.
+    pub position: Vec2,
+}
+
+/// Event fired each frame the cursor moves during a drag.
+#[derive(Debug, Clone, PartialEq, Message)]
+pub struct CursorDragMove {
+    /// Entity being dragged.
+    pub entity: Option<Entity>,
+    /// Current cursor position.
+    pub position: Vec2,
+    /// Movement since the previous drag event.
+    pub delta: Vec2,
+    /// Movement since the drag started.
+    pub total_delta: Vec2,
+}
+
+/// Event fired when a drag is released.
+#[derive(Debug, Clone, PartialEq, Message)]
+pub struct CursorDragEnd {
+    /// Entity that was dragged.
+    pub entity: Option<Entity>,
+    /// Position where the drag was released.
+    pub position: Vec2,
+    /// Movement since the drag started.
+    pub total_delta: Vec2,
+    /// Draggable entity under the cursor on release (the drop target).
+    pub target: Option<Entity>,
+}
+
+/// Find the topmost [`Draggable`] UI node under the virtual cursor.
+fn draggable_at(
+    cursor_state: &VirtualCursorState,
+    window: &Window,
+    nodes: &Query<
+        (
+            Entity,
+            &ComputedNode,
+            &ComputedStackIndex,
+            &UiGlobalTransform,
+        ),
+        With<Draggable>,
+    >,
+) -> Option<Entity> {
+    // UI nodes use physical pixels from the top-left corner
+    let point = cursor_state.window_position(window) * window.scale_factor();
+
+    nodes
+        .iter()
+        .filter(|(_, node, _, transform)| node.contains_point(**transform, point))
+        .max_by_key(|(_, _, stack_index, _)| stack_index.0)
+        .map(|(entity, ..)| entity)
+}
+
+/// System to track press-and-hold drags and fire drag events.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn update_virtual_cursor_drag(
+    mut cursor_state: ResMut<VirtualCursorState>,
+    cursor_query: Query<(&VirtualCursor, Option<&CursorWindow>)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    windows: Query<&Window>,
+    nodes: Query<
+        (
+            Entity,
+            &ComputedNode,
+            &ComputedStackIndex,
+            &UiGlobalTransform,
+        ),
+        With<Draggable>,
+    >,
+    mut start_events: MessageWriter<CursorDragStart>,
+    mut move_events: MessageWriter<CursorDragMove>,
+    mut end_events: MessageWriter<CursorDragEnd>,
+) {
+    let Some(window_entity) = cursor_state.window.or_else(|| primary_window.single().ok()) else {
+        return;
+    };
+    let Ok(window) = windows.get(window_entity) else {
+        return;
+    };
+    let threshold = cursor_query
+        .iter()
+        .find(|(_, cursor_window)| {
+            cursor_window_entity(*cursor_window, &primary_window) == Some(window_entity)
+        })
+        .map_or(VirtualCursor::default().drag_threshold, |(cursor, _)| {
+            cursor.drag_threshold
+        });
+
+    match cursor_state.step_drag(threshold, |position| {
+        let probe = VirtualCursorState {
+            position,
+            ..default()
+        };
+        draggable_at(&probe, window, &nodes)
+    }) {
+        Some(DragStep::Start(event)) => {
+            start_events.write(event);
+        }
+        Some(DragStep::Move(event)) => {
+            move_events.write(event);
+        }
+        Some(DragStep::End(event)) => {
+            end_events.write(event);
+        }
+        None => {}
     }
 }
 
@@ -248,21 +595,31 @@ pub fn spawn_virtual_cursor(
 
 /// Plugin for registering virtual cursor types and systems.
 pub(crate) fn register_virtual_cursor_types(app: &mut App) {
-    app.init_resource::<VirtualCursorState>()
-        .add_message::<VirtualCursorClick>();
+    app.register_type::<VirtualCursor>()
+        .register_type::<CursorInputMode>()
+        .init_resource::<VirtualCursorState>()
+        .add_message::<VirtualCursorClick>()
+        .add_message::<CursorDragStart>()
+        .add_message::<CursorDragMove>()
+        .add_message::<CursorDragEnd>();
 }
 
 /// Add virtual cursor systems to the app.
-pub(crate) fn add_virtual_cursor_systems(app: &mut App) {
+pub(crate) fn add_virtual_cursor_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
     app.add_systems(
-        Update,
+        schedules.update,
         (
             update_virtual_cursor,
             handle_virtual_cursor_clicks,
+            update_virtual_cursor_drag,
             toggle_virtual_cursor_visibility,
             fire_virtual_cursor_events,
         )
-            .chain(),
+            .chain()
+            .run_if(subsystem_enabled(Subsystem::VirtualCursor)),
     );
 }
 
@@ -279,6 +636,38 @@ mod tests {
         assert!(!cursor.use_left_stick);
     }
 
+    #[test]
+    fn test_virtual_cursor_serde_defaults_missing_fields() {
+        let cursor: VirtualCursor = serde_json::from_str(r#"{"speed": 900.0}"#).unwrap();
+        assert_eq!(cursor.speed, 900.0);
+        assert_eq!(cursor.drag_threshold, 8.0);
+    }
+
+    #[test]
+    fn test_gyro_delta_follows_turns() {
+        let cursor = VirtualCursor {
+            input_mode: CursorInputMode::Gyro,
+            gyro_speed: 100.0,
+            ..default()
+        };
+        let config = MotionConfig::default();
+
+        // Turning right (negative yaw) and tilting up
+        let gyro = GyroData::new(0.5, -1.0, 0.0);
+        let delta = cursor.gyro_delta(&gyro, &config, 0.1);
+        assert!((delta.x - 10.0).abs() < 1e-4);
+        assert!((delta.y - 5.0).abs() < 1e-4);
+
+        let still = GyroData::new(0.0, 0.001, 0.0);
+        assert_eq!(cursor.gyro_delta(&still, &config, 0.1), Vec2::ZERO);
+
+        let invert = MotionConfig {
+            invert_yaw: true,
+            ..default()
+        };
+        assert!(cursor.gyro_delta(&gyro, &invert, 0.1).x < 0.0);
+    }
+
     #[test]
     fn test_click_state_variants() {
         assert_ne!(ClickState::Idle, ClickState::JustPressed);
@@ -345,9 +734,136 @@ mod tests {
     fn test_virtual_cursor_click_event() {
         let event = VirtualCursorClick {
             position: Vec2::new(100.0, 200.0),
+            window: None,
         };
 
         assert_eq!(event.position.x, 100.0);
         assert_eq!(event.position.y, 200.0);
     }
+
+    #[test]
+    fn test_window_position_from_top_left() {
+        let window = Window::default();
+        let state = VirtualCursorState {
+            position: Vec2::new(-window.width() / 2.0, window.height() / 2.0),
+            ..default()
+        };
+        assert_eq!(state.window_position(&window), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_cursor_shown_only_in_focused_window() {
+        let mut app = App::new();
+        app.init_resource::<InputDeviceState>()
+            .add_systems(Update, toggle_virtual_cursor_visibility);
+        let focused = app.world_mut().spawn(Window::default()).id();
+        let unfocused = app
+            .world_mut()
+            .spawn(Window {
+                focused: false,
+                ..default()
+            })
+            .id();
+        let shown = app
+            .world_mut()
+            .spawn((
+                VirtualCursor::default(),
+                CursorWindow(focused),
+                Visibility::Hidden,
+            ))
+            .id();
+        let hidden = app
+            .world_mut()
+            .spawn((
+                VirtualCursor::default(),
+                CursorWindow(unfocused),
+                Visibility::Hidden,
+            ))
+            .id();
+        let gamepad = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<InputDeviceState>()
+            .active_device = crate::detection::InputDevice::Gamepad(gamepad);
+
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Visibility>(shown),
+            Some(&Visibility::Visible)
+        );
+        assert_eq!(
+            app.world().get::<Visibility>(hidden),
+            Some(&Visibility::Hidden)
+        );
+    }
+
+    #[test]
+    fn test_drag_starts_after_threshold() {
+        let mut world = World::new();
+        let mut state = VirtualCursorState::default();
+        let item = world.spawn_empty().id();
+
+        state.start_click();
+        assert_eq!(state.step_drag(8.0, |_| Some(item)), None);
+        assert!(!state.is_dragging());
+
+        state.reset_frame_state();
+        state.position = Vec2::new(10.0, 0.0);
+        assert_eq!(
+            state.step_drag(8.0, |_| Some(item)),
+            Some(DragStep::Start(CursorDragStart {
+                entity: Some(item),
+                position: Vec2::ZERO,
+            }))
+        );
+        assert!(state.is_dragging());
+
+        state.position = Vec2::new(15.0, 5.0);
+        assert_eq!(
+            state.step_drag(8.0, |_| Some(item)),
+            Some(DragStep::Move(CursorDragMove {
+                entity: Some(item),
+                position: Vec2::new(15.0, 5.0),
+                delta: Vec2::new(5.0, 5.0),
+                total_delta: Vec2::new(15.0, 5.0),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_drag_end_reports_drop_target() {
+        let mut world = World::new();
+        let mut state = VirtualCursorState::default();
+        let item = world.spawn_empty().id();
+        let slot = Entity::from_raw_u32(2).unwrap();
+
+        state.start_click();
+        state.step_drag(8.0, |_| Some(item));
+        state.reset_frame_state();
+        state.position = Vec2::new(50.0, 0.0);
+        state.step_drag(8.0, |_| Some(item));
+
+        state.end_click();
+        assert_eq!(
+            state.step_drag(8.0, |_| Some(slot)),
+            Some(DragStep::End(CursorDragEnd {
+                entity: Some(item),
+                position: Vec2::new(50.0, 0.0),
+                total_delta: Vec2::new(50.0, 0.0),
+                target: Some(slot),
+            }))
+        );
+        assert!(state.drag.is_none());
+    }
+
+    #[test]
+    fn test_click_without_movement_is_not_a_drag() {
+        let mut state = VirtualCursorState::default();
+
+        state.start_click();
+        state.step_drag(8.0, |_| None);
+        state.end_click();
+        assert_eq!(state.step_drag(8.0, |_| None), None);
+        assert!(state.drag.is_none());
+    }
 }
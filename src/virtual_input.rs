@@ -19,8 +19,35 @@
 //! // Create a virtual D-pad from arrow keys
 //! let arrows = VirtualDPad::arrow_keys();
 //! ```
+//!
+//! # Expressions
+//!
+//! [`InputExpr`] composes buttons and axis thresholds with `&`, `|`, and `!`
+//! into a virtual button, which [`VirtualButtonBindings`] can bind to an
+//! action:
+//!
+//! ```rust,no_run
+//! use bevy_archie::actions::GameAction;
+//! use bevy_archie::virtual_input::{InputExpr, VirtualButtonBindings};
+//! use bevy::prelude::*;
+//!
+//! // pressed(A) AND NOT pressed(LT) OR axis(RightStickY) > 0.8
+//! let expr = InputExpr::pressed(GamepadButton::South)
+//!     & !InputExpr::pressed(GamepadButton::LeftTrigger2)
+//!     | InputExpr::axis_above(GamepadAxis::RightStickY, 0.8);
+//!
+//! let mut bindings = VirtualButtonBindings::default();
+//! bindings.bind(GameAction::Primary, expr);
+//! ```
 
 use bevy::prelude::*;
+use std::collections::HashMap;
+use std::ops::{BitAnd, BitOr, Not};
+
+use crate::actions::GameAction;
+use crate::detection::InputDeviceState;
+use crate::simulated_input::SimulatedInput;
+use crate::subsystems::{Subsystem, subsystem_enabled};
 
 /// A virtual axis that combines two button inputs into a single axis value.
 ///
@@ -388,12 +415,231 @@ impl From<MouseButton> for VirtualButton {
     }
 }
 
+/// An expression composing inputs into a virtual button.
+///
+/// Build expressions with the constructors and combine them with `&`, `|`,
+/// and `!` (or [`and`](Self::and), [`or`](Self::or)). Button and axis terms
+/// on one side of an `&` are read from the same gamepad.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputExpr {
+    /// A button is held.
+    Pressed(VirtualButton),
+    /// A gamepad axis is above a threshold.
+    AxisAbove(GamepadAxis, f32),
+    /// A gamepad axis is below a threshold.
+    AxisBelow(GamepadAxis, f32),
+    /// The inner expression is false.
+    Not(Box<InputExpr>),
+    /// Every inner expression is true.
+    All(Vec<InputExpr>),
+    /// Any inner expression is true.
+    Any(Vec<InputExpr>),
+}
+
+impl InputExpr {
+    /// A button is held.
+    #[must_use]
+    pub fn pressed(button: impl Into<VirtualButton>) -> Self {
+        Self::Pressed(button.into())
+    }
+
+    /// A gamepad axis is above a threshold.
+    #[must_use]
+    pub const fn axis_above(axis: GamepadAxis, threshold: f32) -> Self {
+        Self::AxisAbove(axis, threshold)
+    }
+
+    /// A gamepad axis is below a threshold.
+    #[must_use]
+    pub const fn axis_below(axis: GamepadAxis, threshold: f32) -> Self {
+        Self::AxisBelow(axis, threshold)
+    }
+
+    /// Both expressions are true.
+    #[must_use]
+    pub fn and(self, other: Self) -> Self {
+        match self {
+            Self::All(mut terms) => {
+                terms.push(other);
+                Self::All(terms)
+            }
+            this => Self::All(vec![this, other]),
+        }
+    }
+
+    /// Either expression is true.
+    #[must_use]
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::Any(mut terms) => {
+                terms.push(other);
+                Self::Any(terms)
+            }
+            this => Self::Any(vec![this, other]),
+        }
+    }
+
+    /// Evaluate against one gamepad (or none) plus keyboard and mouse.
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        keyboard: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+        gamepad: Option<&Gamepad>,
+    ) -> bool {
+        match self {
+            Self::Pressed(VirtualButton::Key(key)) => keyboard.pressed(*key),
+            Self::Pressed(VirtualButton::Mouse(button)) => mouse.pressed(*button),
+            Self::Pressed(VirtualButton::Gamepad(button)) => {
+                gamepad.is_some_and(|gamepad| gamepad.pressed(*button))
+            }
+            Self::AxisAbove(axis, threshold) => gamepad
+                .and_then(|gamepad| gamepad.get(*axis))
+                .is_some_and(|value| value > *threshold),
+            Self::AxisBelow(axis, threshold) => gamepad
+                .and_then(|gamepad| gamepad.get(*axis))
+                .is_some_and(|value| value < *threshold),
+            Self::Not(inner) => !inner.evaluate(keyboard, mouse, gamepad),
+            Self::All(terms) => terms
+                .iter()
+                .all(|term| term.evaluate(keyboard, mouse, gamepad)),
+            Self::Any(terms) => terms
+                .iter()
+                .any(|term| term.evaluate(keyboard, mouse, gamepad)),
+        }
+    }
+
+    /// Check whether the expression holds for any connected gamepad.
+    ///
+    /// With no gamepads connected, only keyboard and mouse terms can match.
+    #[must_use]
+    pub fn is_active(
+        &self,
+        keyboard: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+        gamepads: &Query<&Gamepad>,
+    ) -> bool {
+        if gamepads.is_empty() {
+            return self.evaluate(keyboard, mouse, None);
+        }
+        gamepads
+            .iter()
+            .any(|gamepad| self.evaluate(keyboard, mouse, Some(gamepad)))
+    }
+}
+
+impl BitAnd for InputExpr {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.and(rhs)
+    }
+}
+
+impl BitOr for InputExpr {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.or(rhs)
+    }
+}
+
+impl Not for InputExpr {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Not(inner) => *inner,
+            this => Self::Not(Box::new(this)),
+        }
+    }
+}
+
+impl From<VirtualButton> for InputExpr {
+    fn from(button: VirtualButton) -> Self {
+        Self::Pressed(button)
+    }
+}
+
+/// Resource binding [`InputExpr`] virtual buttons to actions.
+///
+/// Active expressions press their action through [`SimulatedInput`], so
+/// they merge with the action's regular bindings.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct VirtualButtonBindings {
+    /// Expressions bound to each action.
+    bindings: HashMap<GameAction, Vec<InputExpr>>,
+}
+
+impl VirtualButtonBindings {
+    /// Bind an expression to an action.
+    pub fn bind(&mut self, action: GameAction, expr: impl Into<InputExpr>) {
+        self.bindings.entry(action).or_default().push(expr.into());
+    }
+
+    /// Remove every expression bound to an action.
+    pub fn clear(&mut self, action: GameAction) {
+        self.bindings.remove(&action);
+    }
+
+    /// Expressions bound to an action.
+    #[must_use]
+    pub fn get(&self, action: GameAction) -> &[InputExpr] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// System to press actions whose bound expressions are active.
+///
+/// Keys and mouse buttons count as released while mouse and keyboard input
+/// is off (see [`InputDeviceState::accepts_mouse_keyboard`]).
+pub fn update_virtual_buttons(
+    bindings: Res<VirtualButtonBindings>,
+    device_state: Res<InputDeviceState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    mut simulated: ResMut<SimulatedInput>,
+) {
+    let released_keyboard = ButtonInput::default();
+    let released_mouse = ButtonInput::default();
+    let (keyboard, mouse) = if device_state.accepts_mouse_keyboard() {
+        (&*keyboard, &*mouse)
+    } else {
+        (&released_keyboard, &released_mouse)
+    };
+
+    for (action, exprs) in &bindings.bindings {
+        if exprs
+            .iter()
+            .any(|expr| expr.is_active(keyboard, mouse, &gamepads))
+            && simulated.get(*action).is_none()
+        {
+            simulated.tap(*action);
+        }
+    }
+}
+
 /// Register virtual input types with the app.
 pub(crate) fn register_virtual_input_types(app: &mut App) {
     app.register_type::<VirtualAxis>()
         .register_type::<VirtualDPad>()
         .register_type::<VirtualDPad3D>()
-        .register_type::<VirtualButton>();
+        .register_type::<VirtualButton>()
+        .init_resource::<VirtualButtonBindings>();
+}
+
+/// Add virtual input systems to the app.
+pub(crate) fn add_virtual_input_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.input,
+        update_virtual_buttons
+            .before(crate::actions::update_action_state::<GameAction>)
+            .run_if(subsystem_enabled(Subsystem::VirtualInput)),
+    );
 }
 
 #[cfg(test)]
@@ -455,4 +701,90 @@ mod tests {
         let mouse: VirtualButton = MouseButton::Left.into();
         assert!(matches!(mouse, VirtualButton::Mouse(MouseButton::Left)));
     }
+
+    #[test]
+    fn test_input_expr_combinators() {
+        let expr = InputExpr::pressed(KeyCode::KeyA) & !InputExpr::pressed(KeyCode::ShiftLeft)
+            | InputExpr::pressed(MouseButton::Right);
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        assert!(!expr.evaluate(&keyboard, &mouse, None));
+
+        keyboard.press(KeyCode::KeyA);
+        assert!(expr.evaluate(&keyboard, &mouse, None));
+
+        keyboard.press(KeyCode::ShiftLeft);
+        assert!(!expr.evaluate(&keyboard, &mouse, None));
+
+        mouse.press(MouseButton::Right);
+        assert!(expr.evaluate(&keyboard, &mouse, None));
+    }
+
+    #[test]
+    fn test_input_expr_flattens_and_double_negation() {
+        let a = InputExpr::pressed(KeyCode::KeyA);
+        let b = InputExpr::pressed(KeyCode::KeyB);
+        let c = InputExpr::pressed(KeyCode::KeyC);
+        assert!(matches!(a.clone() & b.clone() & c, InputExpr::All(terms) if terms.len() == 3));
+        assert_eq!(!!a.clone(), a);
+        assert!(matches!(a | b, InputExpr::Any(terms) if terms.len() == 2));
+    }
+
+    #[test]
+    fn test_input_expr_gamepad_terms_without_gamepad() {
+        let keyboard = ButtonInput::<KeyCode>::default();
+        let mouse = ButtonInput::<MouseButton>::default();
+        let expr = InputExpr::axis_above(GamepadAxis::RightStickY, 0.8);
+        assert!(!expr.evaluate(&keyboard, &mouse, None));
+        assert!((!expr).evaluate(&keyboard, &mouse, None));
+    }
+
+    #[test]
+    fn test_virtual_button_bindings() {
+        let mut bindings = VirtualButtonBindings::default();
+        bindings.bind(GameAction::Primary, VirtualButton::Key(KeyCode::KeyJ));
+        bindings.bind(
+            GameAction::Primary,
+            InputExpr::axis_above(GamepadAxis::RightZ, 0.5),
+        );
+        assert_eq!(bindings.get(GameAction::Primary).len(), 2);
+        bindings.clear(GameAction::Primary);
+        assert!(bindings.get(GameAction::Primary).is_empty());
+    }
+
+    #[test]
+    fn test_gamepad_only_ignores_key_expressions() {
+        let mut app = App::new();
+        let mut bindings = VirtualButtonBindings::default();
+        bindings.bind(GameAction::Primary, InputExpr::pressed(KeyCode::Space));
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::Space);
+        let mut device_state = InputDeviceState::default();
+        device_state.set_gamepad_only(true);
+        app.insert_resource(bindings)
+            .insert_resource(device_state)
+            .insert_resource(keyboard)
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<SimulatedInput>()
+            .add_systems(Update, update_virtual_buttons);
+
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SimulatedInput>()
+                .get(GameAction::Primary)
+                .is_none()
+        );
+
+        app.world_mut()
+            .resource_mut::<InputDeviceState>()
+            .set_gamepad_only(false);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SimulatedInput>()
+                .get(GameAction::Primary)
+                .is_some()
+        );
+    }
 }
@@ -2,8 +2,44 @@
 //!
 //! This module provides a controller-friendly on-screen keyboard
 //! for entering text when a physical keyboard is not available.
+//!
+//! If the controller driving the keyboard disconnects, the keyboard moves to
+//! [`VirtualKeyboardState::Paused`] and keeps the buffer. Games can show a
+//! reconnect prompt while paused; reconnecting resumes the session, and the
+//! [`VirtualKeyboardSession::reconnect_timeout`] cancels it if nobody comes
+//! back.
+//!
+//! Experienced players can switch to chord typing with the right stick,
+//! typing letter groups as button chords instead of picking keys; see
+//! [`crate::chord_typing`].
+//!
+//! Accented letters can be typed two ways. The letters page has a row of
+//! [`DeadKey`]s that compose with the next letter typed (´ then e gives é),
+//! and holding Confirm on a letter with accented forms opens an
+//! [`AccentPicker`] to choose one.
+//!
+//! The symbols page has a paste key when [`VirtualKeyboardConfig::paste_key`]
+//! is set. Pressing it sends [`VirtualKeyboardPasteRequest`]; with the
+//! `clipboard` feature the request is answered from the OS clipboard, and
+//! games can answer it themselves (or paste at any time) by sending
+//! [`PasteIntoVirtualKeyboard`].
+//!
+//! The bumpers move the cursor, repeating while held with the
+//! [`ControllerConfig::menu_repeat`] timing, and jump by word while the
+//! right trigger is held. Select starts selecting text; while selecting, the
+//! cursor extends the selection, Y copies it and X cuts it, sending
+//! [`VirtualKeyboardCopied`].
 
 use bevy::prelude::*;
+#[cfg(feature = "clipboard")]
+use log::warn;
+use std::ops::Range;
+
+use crate::config::ControllerConfig;
+use crate::detection::{GamepadConnected, GamepadDisconnected, InputDeviceState};
+use crate::repeat::HeldRepeat;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::ui_sound::{UiNavigationSound, UiNavigationSoundKind, UiNavigationSource};
 
 /// The current state of the virtual keyboard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, States, Hash)]
@@ -13,6 +49,9 @@ pub enum VirtualKeyboardState {
     Hidden,
     /// Keyboard is visible and accepting input.
     Visible,
+    /// The controller disconnected; input is paused and the buffer is kept
+    /// until a controller reconnects.
+    Paused,
 }
 
 /// Configuration for the virtual keyboard.
@@ -30,6 +69,14 @@ pub struct VirtualKeyboardConfig {
     pub symbols1: String,
     pub symbols2: String,
     pub symbols3: String,
+    /// Dead keys shown as an extra row on the letters page, as accent
+    /// symbols (see [`DeadKey::from_symbol`]).
+    pub dead_keys: String,
+    /// Seconds Confirm is held on a letter before its accent picker opens.
+    pub accent_hold_time: f32,
+    /// Whether the symbols page ends with a paste key (on by default with
+    /// the `clipboard` feature).
+    pub paste_key: bool,
     /// Key width in pixels.
     pub key_width: f32,
     /// Key height in pixels.
@@ -58,6 +105,9 @@ impl Default for VirtualKeyboardConfig {
             symbols1: "!@#$%^&*()".to_string(),
             symbols2: "`~_-+=:;'\"".to_string(),
             symbols3: "<>,.?/\\|".to_string(),
+            dead_keys: "´`^¨~".to_string(),
+            accent_hold_time: 0.5,
+            paste_key: cfg!(feature = "clipboard"),
             key_width: 60.0,
             key_height: 50.0,
             key_spacing: 5.0,
@@ -70,14 +120,226 @@ impl Default for VirtualKeyboardConfig {
     }
 }
 
+impl VirtualKeyboardConfig {
+    /// Keys on a page (0 = letters, 1 = symbols), in focus order.
+    #[must_use]
+    pub fn page_keys(&self, page: usize) -> Vec<KeyboardKey> {
+        let rows = if page == 0 {
+            [&self.numbers, &self.row1, &self.row2, &self.row3]
+        } else {
+            [
+                &self.numbers,
+                &self.symbols1,
+                &self.symbols2,
+                &self.symbols3,
+            ]
+        };
+        let mut keys: Vec<KeyboardKey> = rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .map(KeyboardKey::Char)
+            .collect();
+        if page == 0 {
+            keys.extend(
+                self.dead_keys
+                    .chars()
+                    .filter_map(DeadKey::from_symbol)
+                    .map(KeyboardKey::Dead),
+            );
+        } else if self.paste_key {
+            keys.push(KeyboardKey::Paste);
+        }
+        keys
+    }
+}
+
+/// A key on the virtual keyboard grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardKey {
+    /// Types a character.
+    Char(char),
+    /// Accents the next letter typed.
+    Dead(DeadKey),
+    /// Requests a paste.
+    Paste,
+}
+
+/// An accent that composes with the next letter typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeadKey {
+    /// ´ (á é í ó ú ý)
+    Acute,
+    /// ` (à è ì ò ù)
+    Grave,
+    /// ^ (â ê î ô û)
+    Circumflex,
+    /// ¨ (ä ë ï ö ü ÿ)
+    Diaeresis,
+    /// ~ (ã õ ñ)
+    Tilde,
+}
+
+impl DeadKey {
+    /// All dead keys.
+    pub const ALL: [Self; 5] = [
+        Self::Acute,
+        Self::Grave,
+        Self::Circumflex,
+        Self::Diaeresis,
+        Self::Tilde,
+    ];
+
+    /// The accent on its own, typed when it has nothing to compose with.
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Acute => '´',
+            Self::Grave => '`',
+            Self::Circumflex => '^',
+            Self::Diaeresis => '¨',
+            Self::Tilde => '~',
+        }
+    }
+
+    /// The dead key for an accent symbol.
+    #[must_use]
+    pub fn from_symbol(symbol: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|dead| dead.symbol() == symbol)
+    }
+
+    /// Compose the accent with a letter, keeping its case.
+    ///
+    /// Returns `None` if the letter has no accented form.
+    #[must_use]
+    pub fn compose(self, base: char) -> Option<char> {
+        let composed = match (self, base.to_ascii_lowercase()) {
+            (Self::Acute, 'a') => 'á',
+            (Self::Acute, 'e') => 'é',
+            (Self::Acute, 'i') => 'í',
+            (Self::Acute, 'o') => 'ó',
+            (Self::Acute, 'u') => 'ú',
+            (Self::Acute, 'y') => 'ý',
+            (Self::Grave, 'a') => 'à',
+            (Self::Grave, 'e') => 'è',
+            (Self::Grave, 'i') => 'ì',
+            (Self::Grave, 'o') => 'ò',
+            (Self::Grave, 'u') => 'ù',
+            (Self::Circumflex, 'a') => 'â',
+            (Self::Circumflex, 'e') => 'ê',
+            (Self::Circumflex, 'i') => 'î',
+            (Self::Circumflex, 'o') => 'ô',
+            (Self::Circumflex, 'u') => 'û',
+            (Self::Diaeresis, 'a') => 'ä',
+            (Self::Diaeresis, 'e') => 'ë',
+            (Self::Diaeresis, 'i') => 'ï',
+            (Self::Diaeresis, 'o') => 'ö',
+            (Self::Diaeresis, 'u') => 'ü',
+            (Self::Diaeresis, 'y') => 'ÿ',
+            (Self::Tilde, 'a') => 'ã',
+            (Self::Tilde, 'o') => 'õ',
+            (Self::Tilde, 'n') => 'ñ',
+            _ => return None,
+        };
+        Some(if base.is_ascii_uppercase() {
+            to_upper(composed)
+        } else {
+            composed
+        })
+    }
+}
+
+/// Accented forms offered for a letter by the [`AccentPicker`].
+#[must_use]
+pub fn accent_variants(base: char) -> &'static [char] {
+    match base.to_ascii_lowercase() {
+        'a' => &['à', 'á', 'â', 'ä', 'ã', 'å', 'æ'],
+        'c' => &['ç'],
+        'e' => &['è', 'é', 'ê', 'ë'],
+        'i' => &['ì', 'í', 'î', 'ï'],
+        'n' => &['ñ'],
+        'o' => &['ò', 'ó', 'ô', 'ö', 'õ', 'ø', 'œ'],
+        's' => &['ß'],
+        'u' => &['ù', 'ú', 'û', 'ü'],
+        'y' => &['ý', 'ÿ'],
+        _ => &[],
+    }
+}
+
+/// Uppercase a character, leaving it alone if it has no single-character
+/// uppercase form (such as ß).
+fn to_upper(c: char) -> char {
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+/// Popup listing the accented forms of a letter, opened by holding Confirm.
+///
+/// Left and right move the selection, Confirm types it and Cancel closes the
+/// popup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccentPicker {
+    /// The letter the picker was opened on.
+    pub base: char,
+    /// The plain letter followed by its accented forms.
+    pub options: Vec<char>,
+    /// Index of the selected option.
+    pub selected: usize,
+}
+
+impl AccentPicker {
+    /// Open a picker for a letter, in uppercase if `uppercase` is set.
+    ///
+    /// Returns `None` if the letter has no accented forms.
+    #[must_use]
+    pub fn new(base: char, uppercase: bool) -> Option<Self> {
+        let variants = accent_variants(base);
+        if variants.is_empty() {
+            return None;
+        }
+        let case = |c: char| if uppercase { to_upper(c) } else { c };
+        Some(Self {
+            base,
+            options: std::iter::once(base)
+                .chain(variants.iter().copied())
+                .map(case)
+                .collect(),
+            // Start on the first accented form
+            selected: 1,
+        })
+    }
+
+    /// Move the selection; returns `false` at either end.
+    pub const fn move_selection(&mut self, forward: bool) -> bool {
+        if forward && self.selected + 1 < self.options.len() {
+            self.selected += 1;
+            true
+        } else if !forward && self.selected > 0 {
+            self.selected -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The selected character.
+    #[must_use]
+    pub fn selected_char(&self) -> char {
+        self.options[self.selected]
+    }
+}
+
 /// Resource tracking the virtual keyboard input state.
-#[derive(Debug, Clone, Default, Resource)]
+#[derive(Clone, Default, Resource)]
 pub struct VirtualKeyboard {
     /// Current input buffer.
     pub buffer: String,
     /// Maximum length of input.
     pub max_length: Option<usize>,
-    /// Cursor position in the buffer.
+    /// Cursor position in the buffer, as a byte index on a character
+    /// boundary.
     pub cursor: usize,
     /// Whether shift is active.
     pub shift_active: bool,
@@ -93,6 +355,44 @@ pub struct VirtualKeyboard {
     pub allow: Option<String>,
     /// Excluded characters.
     pub exclude: Option<String>,
+    /// Whether the text is sensitive (passwords, names); its `Debug` output
+    /// hides the buffer.
+    pub sensitive: bool,
+    /// Whether chords are typed instead of picking keys from the grid.
+    pub chord_typing: bool,
+    /// Dead key waiting to accent the next letter.
+    pub dead_key: Option<DeadKey>,
+    /// Open accent picker, if any.
+    pub accent_picker: Option<AccentPicker>,
+    /// Where the selection started, while selecting text.
+    pub selection_anchor: Option<usize>,
+}
+
+impl std::fmt::Debug for VirtualKeyboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let buffer: &dyn std::fmt::Debug = if self.sensitive {
+            &"[redacted]"
+        } else {
+            &self.buffer
+        };
+        f.debug_struct("VirtualKeyboard")
+            .field("buffer", buffer)
+            .field("max_length", &self.max_length)
+            .field("cursor", &self.cursor)
+            .field("shift_active", &self.shift_active)
+            .field("current_page", &self.current_page)
+            .field("focused_key", &self.focused_key)
+            .field("keys_per_row", &self.keys_per_row)
+            .field("prompt", &self.prompt)
+            .field("allow", &self.allow)
+            .field("exclude", &self.exclude)
+            .field("sensitive", &self.sensitive)
+            .field("chord_typing", &self.chord_typing)
+            .field("dead_key", &self.dead_key)
+            .field("accent_picker", &self.accent_picker)
+            .field("selection_anchor", &self.selection_anchor)
+            .finish()
+    }
 }
 
 impl VirtualKeyboard {
@@ -126,6 +426,20 @@ impl VirtualKeyboard {
         self
     }
 
+    /// Mark the text as sensitive so debug output hides it.
+    #[must_use]
+    pub const fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    /// Start in chord typing mode.
+    #[must_use]
+    pub const fn with_chord_typing(mut self, chord_typing: bool) -> Self {
+        self.chord_typing = chord_typing;
+        self
+    }
+
     /// Set initial value.
     #[must_use]
     pub fn with_value(mut self, value: impl Into<String>) -> Self {
@@ -151,57 +465,199 @@ impl VirtualKeyboard {
     }
 
     /// Add a character at the cursor position.
+    ///
+    /// A pending dead key composes with the character; if they don't
+    /// compose, the accent is typed on its own first.
     pub fn add_char(&mut self, c: char) {
-        if !self.is_char_allowed(c) {
-            return;
+        self.delete_selection();
+        let typed_char = if self.shift_active { to_upper(c) } else { c };
+
+        let typed = match self.dead_key.take() {
+            Some(dead) => match dead.compose(typed_char) {
+                Some(composed) => self.is_char_allowed(composed) && self.insert_char(composed),
+                None => {
+                    let accent =
+                        self.is_char_allowed(dead.symbol()) && self.insert_char(dead.symbol());
+                    // Space after a dead key types just the accent
+                    (c != ' ' && self.is_char_allowed(c) && self.insert_char(typed_char)) || accent
+                }
+            },
+            None => self.is_char_allowed(c) && self.insert_char(typed_char),
+        };
+
+        // Auto-disable shift after typing
+        if typed {
+            self.shift_active = false;
+        }
+    }
+
+    /// Press a dead key.
+    ///
+    /// Pressing the same dead key twice types the accent on its own.
+    pub fn press_dead_key(&mut self, dead: DeadKey) {
+        match self.dead_key.take() {
+            Some(pending) => {
+                if self.is_char_allowed(pending.symbol()) {
+                    self.insert_char(pending.symbol());
+                }
+                if pending != dead {
+                    self.dead_key = Some(dead);
+                }
+            }
+            None => self.dead_key = Some(dead),
+        }
+    }
+
+    /// Insert text at the cursor.
+    ///
+    /// Characters rejected by the allow and exclude lists, and control
+    /// characters such as newlines, are skipped, and text past the maximum
+    /// length is dropped. Returns the number of characters inserted.
+    pub fn paste(&mut self, text: &str) -> usize {
+        self.dead_key = None;
+        self.delete_selection();
+        let mut inserted = 0;
+        for c in text.chars() {
+            if c.is_control() || !self.is_char_allowed(c) {
+                continue;
+            }
+            if !self.insert_char(c) {
+                break;
+            }
+            inserted += 1;
         }
+        inserted
+    }
 
+    /// Insert a character at the cursor if there is room.
+    fn insert_char(&mut self, c: char) -> bool {
         if let Some(max) = self.max_length
-            && self.buffer.len() >= max
+            && self.buffer.chars().count() >= max
         {
-            return;
+            return false;
         }
 
-        let c = if self.shift_active {
-            c.to_uppercase().next().unwrap_or(c)
-        } else {
-            c
-        };
-
         self.buffer.insert(self.cursor, c);
-        self.cursor += 1;
-
-        // Auto-disable shift after typing
-        self.shift_active = false;
+        self.cursor += c.len_utf8();
+        true
     }
 
-    /// Remove the character before the cursor.
+    /// Remove the character before the cursor or the selected text, or
+    /// cancel a pending dead key.
     pub fn backspace(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        if self.dead_key.take().is_some() || self.delete_selection() {
+            return;
+        }
+        if let Some(c) = self.buffer[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
             self.buffer.remove(self.cursor);
         }
     }
 
     /// Move cursor left.
     pub fn cursor_left(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        if let Some(c) = self.buffer[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
         }
     }
 
     /// Move cursor right.
     pub fn cursor_right(&mut self) {
-        if self.cursor < self.buffer.len() {
-            self.cursor += 1;
+        if let Some(c) = self.buffer[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
         }
     }
 
+    /// Move the cursor to the start of the current or previous word.
+    pub fn word_left(&mut self) {
+        let before = self.buffer[..self.cursor].trim_end();
+        self.cursor = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+    }
+
+    /// Move the cursor to the start of the next word.
+    pub fn word_right(&mut self) {
+        let after = &self.buffer[self.cursor..];
+        let word_end = after.find(char::is_whitespace).unwrap_or(after.len());
+        let rest = &after[word_end..];
+        self.cursor += word_end + (rest.len() - rest.trim_start().len());
+    }
+
+    /// Move the cursor by a character, or by a word if `by_word` is set.
+    pub fn move_cursor(&mut self, forward: bool, by_word: bool) {
+        match (forward, by_word) {
+            (false, false) => self.cursor_left(),
+            (true, false) => self.cursor_right(),
+            (false, true) => self.word_left(),
+            (true, true) => self.word_right(),
+        }
+    }
+
+    /// Start or stop selecting text.
+    ///
+    /// While selecting, moving the cursor extends the selection from where
+    /// it started.
+    pub const fn toggle_selection(&mut self) {
+        self.selection_anchor = match self.selection_anchor {
+            Some(_) => None,
+            None => Some(self.cursor),
+        };
+    }
+
+    /// Byte range of the selected text, if any is selected.
+    #[must_use]
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+        (anchor != self.cursor).then(|| anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// The selected text, if any is selected.
+    #[must_use]
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.buffer[range])
+    }
+
+    /// Copy the selected text and stop selecting.
+    pub fn copy_selection(&mut self) -> Option<String> {
+        let text = self.selected_text()?.to_string();
+        self.selection_anchor = None;
+        Some(text)
+    }
+
+    /// Cut the selected text.
+    pub fn cut_selection(&mut self) -> Option<String> {
+        let text = self.selected_text()?.to_string();
+        self.delete_selection();
+        Some(text)
+    }
+
+    /// Delete the selected text and stop selecting.
+    ///
+    /// Returns whether any text was deleted.
+    pub fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.selection_anchor = None;
+        let Some(range) = selection else {
+            return false;
+        };
+        self.cursor = range.start;
+        self.buffer.replace_range(range, "");
+        true
+    }
+
     /// Toggle shift state.
     pub fn toggle_shift(&mut self) {
         self.shift_active = !self.shift_active;
     }
 
+    /// Toggle between grid and chord typing.
+    pub fn toggle_chord_typing(&mut self) {
+        self.chord_typing = !self.chord_typing;
+    }
+
     /// Toggle between letter and symbol pages.
     pub fn toggle_page(&mut self) {
         self.current_page = usize::from(self.current_page == 0);
@@ -216,6 +672,8 @@ impl VirtualKeyboard {
     pub fn clear(&mut self) {
         self.buffer.clear();
         self.cursor = 0;
+        self.dead_key = None;
+        self.selection_anchor = None;
     }
 
     /// Get the current value.
@@ -225,7 +683,10 @@ impl VirtualKeyboard {
     }
 
     /// Move focus to adjacent key.
-    pub fn move_focus(&mut self, direction: FocusDirection, total_keys: usize) {
+    ///
+    /// Returns `false` if focus was already at the edge in that direction.
+    pub fn move_focus(&mut self, direction: FocusDirection, total_keys: usize) -> bool {
+        let previous = self.focused_key;
         match direction {
             FocusDirection::Up => {
                 if self.focused_key >= self.keys_per_row {
@@ -248,9 +709,123 @@ impl VirtualKeyboard {
                 }
             }
         }
+        self.focused_key != previous
     }
 }
 
+/// Resource tracking which controller owns the open keyboard session.
+#[derive(Debug, Clone, Resource)]
+pub struct VirtualKeyboardSession {
+    /// Seconds to wait for a reconnect before cancelling the input.
+    ///
+    /// `None` waits forever.
+    pub reconnect_timeout: Option<f32>,
+    /// Resume when any controller connects, not just the one that left.
+    pub resume_with_any_gamepad: bool,
+    /// The gamepad driving the keyboard, if known.
+    gamepad: Option<Entity>,
+    /// Seconds spent paused, while paused.
+    paused_for: Option<f32>,
+}
+
+impl Default for VirtualKeyboardSession {
+    fn default() -> Self {
+        Self {
+            reconnect_timeout: Some(30.0),
+            resume_with_any_gamepad: true,
+            gamepad: None,
+            paused_for: None,
+        }
+    }
+}
+
+impl VirtualKeyboardSession {
+    /// Start a session driven by a gamepad.
+    pub fn begin(&mut self, gamepad: Option<Entity>) {
+        self.gamepad = gamepad;
+        self.paused_for = None;
+    }
+
+    /// The gamepad driving the keyboard, if known.
+    #[must_use]
+    pub const fn gamepad(&self) -> Option<Entity> {
+        self.gamepad
+    }
+
+    /// Whether the session is waiting for a reconnect.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.paused_for.is_some()
+    }
+
+    /// Seconds spent waiting for a reconnect.
+    #[must_use]
+    pub fn paused_for(&self) -> f32 {
+        self.paused_for.unwrap_or(0.0)
+    }
+
+    /// Handle a gamepad disconnecting. Returns true if the session paused.
+    ///
+    /// The session pauses when its gamepad leaves, or, if no gamepad owns
+    /// it, when the last connected gamepad leaves.
+    pub fn disconnect(&mut self, gamepad: Entity, remaining: &[Entity]) -> bool {
+        if self.is_paused() {
+            return false;
+        }
+
+        let lost = match self.gamepad {
+            Some(owner) => owner == gamepad,
+            None => remaining.is_empty(),
+        };
+        if lost {
+            self.paused_for = Some(0.0);
+        }
+        lost
+    }
+
+    /// Handle a gamepad connecting. Returns true if the session resumed.
+    pub fn reconnect(&mut self, gamepad: Entity) -> bool {
+        if !self.is_paused() {
+            return false;
+        }
+
+        if self.resume_with_any_gamepad || self.gamepad == Some(gamepad) {
+            self.begin(Some(gamepad));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Advance the pause timer. Returns true once the reconnect timeout
+    /// has elapsed.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        let Some(paused_for) = self.paused_for.as_mut() else {
+            return false;
+        };
+        *paused_for += delta;
+        self.reconnect_timeout
+            .is_some_and(|timeout| *paused_for >= timeout)
+    }
+}
+
+/// Event fired when a keyboard session pauses, resumes, or times out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub enum VirtualKeyboardSessionEvent {
+    /// The driving controller disconnected.
+    Paused {
+        /// The gamepad that disconnected.
+        gamepad: Entity,
+    },
+    /// A controller reconnected and input resumed.
+    Resumed {
+        /// The gamepad now driving the keyboard.
+        gamepad: Entity,
+    },
+    /// Nobody reconnected in time and the input was cancelled.
+    TimedOut,
+}
+
 /// Direction for focus movement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusDirection {
@@ -271,6 +846,44 @@ pub struct ShowVirtualKeyboard {
 #[derive(Debug, Clone, Message)]
 pub struct HideVirtualKeyboard;
 
+/// Event requesting text input from the player.
+///
+/// When the player is on a gamepad, or gamepad-only mode is enabled, the
+/// request is redirected to the virtual keyboard. Otherwise it is left for
+/// the game to satisfy with a physical keyboard.
+#[derive(Debug, Clone, Message)]
+pub struct TextInputRequest {
+    /// The keyboard configuration to use if the virtual keyboard is shown.
+    pub keyboard: VirtualKeyboard,
+}
+
+/// Event sent when the player presses the paste key.
+///
+/// Answered from the OS clipboard with the `clipboard` feature; otherwise the
+/// game can answer it with [`PasteIntoVirtualKeyboard`].
+#[derive(Debug, Clone, Message)]
+pub struct VirtualKeyboardPasteRequest;
+
+/// Event to insert text into the open virtual keyboard at its cursor.
+///
+/// The text is filtered like typed input; see [`VirtualKeyboard::paste`].
+#[derive(Debug, Clone, Message)]
+pub struct PasteIntoVirtualKeyboard {
+    /// Text to insert.
+    pub text: String,
+}
+
+/// Event fired when the player copies or cuts selected text.
+///
+/// Copied to the OS clipboard with the `clipboard` feature.
+#[derive(Debug, Clone, Message)]
+pub struct VirtualKeyboardCopied {
+    /// The copied text.
+    pub text: String,
+    /// Whether the text was cut from the buffer.
+    pub cut: bool,
+}
+
 /// Event fired when input is confirmed.
 #[derive(Debug, Clone, Message)]
 pub struct VirtualKeyboardEvent {
@@ -301,14 +914,95 @@ pub struct VirtualKeyboardInput;
 pub fn handle_show_keyboard(
     mut events: MessageReader<ShowVirtualKeyboard>,
     mut keyboard: ResMut<VirtualKeyboard>,
+    mut session: ResMut<VirtualKeyboardSession>,
+    device_state: Res<InputDeviceState>,
     mut next_state: ResMut<NextState<VirtualKeyboardState>>,
 ) {
     for event in events.read() {
         *keyboard = event.keyboard.clone();
+        session.begin(
+            device_state
+                .active_gamepad()
+                .or(device_state.primary_gamepad),
+        );
         next_state.set(VirtualKeyboardState::Visible);
     }
 }
 
+/// System to pause the keyboard when its controller disconnects and resume
+/// or cancel it on reconnect or timeout.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn manage_keyboard_session(
+    state: Res<State<VirtualKeyboardState>>,
+    mut next_state: ResMut<NextState<VirtualKeyboardState>>,
+    mut session: ResMut<VirtualKeyboardSession>,
+    device_state: Res<InputDeviceState>,
+    keyboard: Res<VirtualKeyboard>,
+    time: Res<Time>,
+    mut connected: MessageReader<GamepadConnected>,
+    mut disconnected: MessageReader<GamepadDisconnected>,
+    mut keyboard_events: MessageWriter<VirtualKeyboardEvent>,
+    mut session_events: MessageWriter<VirtualKeyboardSessionEvent>,
+) {
+    match state.get() {
+        VirtualKeyboardState::Hidden => {
+            connected.clear();
+            disconnected.clear();
+        }
+        VirtualKeyboardState::Visible => {
+            connected.clear();
+            for event in disconnected.read() {
+                if session.disconnect(event.gamepad, &device_state.connected_gamepads) {
+                    next_state.set(VirtualKeyboardState::Paused);
+                    session_events.write(VirtualKeyboardSessionEvent::Paused {
+                        gamepad: event.gamepad,
+                    });
+                }
+            }
+        }
+        VirtualKeyboardState::Paused => {
+            disconnected.clear();
+            for event in connected.read() {
+                if session.reconnect(event.gamepad) {
+                    next_state.set(VirtualKeyboardState::Visible);
+                    session_events.write(VirtualKeyboardSessionEvent::Resumed {
+                        gamepad: event.gamepad,
+                    });
+                    return;
+                }
+            }
+
+            if session.tick(time.delta_secs()) {
+                session.begin(None);
+                keyboard_events.write(VirtualKeyboardEvent {
+                    value: keyboard.buffer.clone(),
+                    confirmed: false,
+                });
+                next_state.set(VirtualKeyboardState::Hidden);
+                session_events.write(VirtualKeyboardSessionEvent::TimedOut);
+            }
+        }
+    }
+}
+
+/// System to route text input requests to the virtual keyboard.
+pub fn redirect_text_input_requests(
+    mut requests: MessageReader<TextInputRequest>,
+    mut show_events: MessageWriter<ShowVirtualKeyboard>,
+    device_state: Res<InputDeviceState>,
+) {
+    for request in requests.read() {
+        if device_state.gamepad_only || device_state.using_gamepad() {
+            show_events.write(ShowVirtualKeyboard {
+                keyboard: request.keyboard.clone(),
+            });
+        }
+    }
+}
+
 /// System to handle hiding the virtual keyboard.
 pub fn handle_hide_keyboard(
     mut events: MessageReader<HideVirtualKeyboard>,
@@ -319,67 +1013,188 @@ pub fn handle_hide_keyboard(
     }
 }
 
+/// Caret movement held on a gamepad, repeating while the button stays down.
+#[derive(Debug, Default)]
+pub struct HeldCaret(Option<(Entity, GamepadButton, HeldRepeat)>);
+
 /// System to handle keyboard input from controller.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
 pub fn handle_keyboard_input(
     mut keyboard: ResMut<VirtualKeyboard>,
     mut keyboard_events: MessageWriter<VirtualKeyboardEvent>,
     mut hide_events: MessageWriter<HideVirtualKeyboard>,
-    gamepads: Query<&Gamepad>,
+    mut paste_requests: MessageWriter<VirtualKeyboardPasteRequest>,
+    mut copies: MessageWriter<VirtualKeyboardCopied>,
+    gamepads: Query<(Entity, &Gamepad)>,
     config: Res<VirtualKeyboardConfig>,
+    controller_config: Res<ControllerConfig>,
+    time: Res<Time>,
+    mut held_letter: Local<Option<(Entity, char, f32)>>,
+    mut held_caret: Local<HeldCaret>,
+    mut sounds: MessageWriter<UiNavigationSound>,
 ) {
-    for gamepad in gamepads.iter() {
-        // D-pad navigation
-        if gamepad.just_pressed(GamepadButton::DPadUp) {
-            keyboard.move_focus(FocusDirection::Up, 40); // Approximate total keys
-        }
-        if gamepad.just_pressed(GamepadButton::DPadDown) {
-            keyboard.move_focus(FocusDirection::Down, 40);
+    let confirm_button = controller_config.confirm_button();
+    let cancel_button = controller_config.cancel_button();
+
+    for (entity, gamepad) in &gamepads {
+        // Switch between grid and chord typing (Right stick press)
+        if gamepad.just_pressed(GamepadButton::RightThumb) {
+            keyboard.toggle_chord_typing();
+            continue;
         }
-        if gamepad.just_pressed(GamepadButton::DPadLeft) {
-            keyboard.move_focus(FocusDirection::Left, 40);
-        }
-        if gamepad.just_pressed(GamepadButton::DPadRight) {
-            keyboard.move_focus(FocusDirection::Right, 40);
+
+        // Chord typing handles everything but confirm and cancel
+        if keyboard.chord_typing {
+            if gamepad.just_pressed(GamepadButton::Start) {
+                keyboard_events.write(VirtualKeyboardEvent {
+                    value: keyboard.buffer.clone(),
+                    confirmed: true,
+                });
+                hide_events.write(HideVirtualKeyboard);
+            } else if gamepad.just_pressed(GamepadButton::Select) {
+                sounds.write(UiNavigationSound::new(
+                    UiNavigationSoundKind::Cancelled,
+                    UiNavigationSource::VirtualKeyboard,
+                ));
+                keyboard_events.write(VirtualKeyboardEvent {
+                    value: keyboard.buffer.clone(),
+                    confirmed: false,
+                });
+                hide_events.write(HideVirtualKeyboard);
+            }
+            continue;
         }
 
-        // Confirm key press (A button)
-        if gamepad.just_pressed(GamepadButton::South) {
-            // Get the character at focused_key and add it
-            let rows = if keyboard.current_page == 0 {
-                vec![&config.numbers, &config.row1, &config.row2, &config.row3]
+        // The accent picker takes left/right, confirm and cancel while open
+        if let Some(mut picker) = keyboard.accent_picker.take() {
+            for (button, forward) in [
+                (GamepadButton::DPadLeft, false),
+                (GamepadButton::DPadRight, true),
+            ] {
+                if gamepad.just_pressed(button) {
+                    let kind = if picker.move_selection(forward) {
+                        UiNavigationSoundKind::FocusMoved
+                    } else {
+                        UiNavigationSoundKind::Blocked
+                    };
+                    sounds.write(UiNavigationSound::new(
+                        kind,
+                        UiNavigationSource::VirtualKeyboard,
+                    ));
+                }
+            }
+
+            if gamepad.just_pressed(confirm_button) {
+                sounds.write(UiNavigationSound::new(
+                    UiNavigationSoundKind::Confirmed,
+                    UiNavigationSource::VirtualKeyboard,
+                ));
+                keyboard.add_char(picker.selected_char());
+            } else if gamepad.just_pressed(cancel_button) {
+                sounds.write(UiNavigationSound::new(
+                    UiNavigationSoundKind::Cancelled,
+                    UiNavigationSource::VirtualKeyboard,
+                ));
             } else {
-                vec![
-                    &config.numbers,
-                    &config.symbols1,
-                    &config.symbols2,
-                    &config.symbols3,
-                ]
-            };
+                keyboard.accent_picker = Some(picker);
+            }
+            continue;
+        }
+
+        let keys = config.page_keys(keyboard.current_page);
 
-            let mut current_index = 0;
-            for row in rows {
-                for c in row.chars() {
-                    if current_index == keyboard.focused_key {
-                        keyboard.add_char(c);
-                        break;
-                    }
-                    current_index += 1;
+        // D-pad navigation
+        for (button, direction) in [
+            (GamepadButton::DPadUp, FocusDirection::Up),
+            (GamepadButton::DPadDown, FocusDirection::Down),
+            (GamepadButton::DPadLeft, FocusDirection::Left),
+            (GamepadButton::DPadRight, FocusDirection::Right),
+        ] {
+            if gamepad.just_pressed(button) {
+                let kind = if keyboard.move_focus(direction, keys.len()) {
+                    UiNavigationSoundKind::FocusMoved
+                } else {
+                    UiNavigationSoundKind::Blocked
+                };
+                sounds.write(UiNavigationSound::new(
+                    kind,
+                    UiNavigationSource::VirtualKeyboard,
+                ));
+            }
+        }
+
+        // Confirm key press (A button, or right face button in East style)
+        if gamepad.just_pressed(confirm_button) {
+            sounds.write(UiNavigationSound::new(
+                UiNavigationSoundKind::Confirmed,
+                UiNavigationSource::VirtualKeyboard,
+            ));
+
+            match keys.get(keyboard.focused_key) {
+                Some(KeyboardKey::Dead(dead)) => keyboard.press_dead_key(*dead),
+                // Letters with accents type on release, so holding can open
+                // the accent picker instead
+                Some(KeyboardKey::Char(c)) if !accent_variants(*c).is_empty() => {
+                    *held_letter = Some((entity, *c, 0.0));
+                }
+                Some(KeyboardKey::Char(c)) => keyboard.add_char(*c),
+                Some(KeyboardKey::Paste) => {
+                    paste_requests.write(VirtualKeyboardPasteRequest);
                 }
+                None => {}
             }
         }
 
-        // Backspace (X button)
+        if let Some((holder, c, held_for)) = *held_letter
+            && holder == entity
+        {
+            if !gamepad.pressed(confirm_button) {
+                keyboard.add_char(c);
+                *held_letter = None;
+            } else if held_for + time.delta_secs() >= config.accent_hold_time {
+                keyboard.accent_picker = AccentPicker::new(c, keyboard.shift_active);
+                *held_letter = None;
+            } else {
+                *held_letter = Some((entity, c, held_for + time.delta_secs()));
+            }
+        }
+
+        // Start or stop selecting (Select button)
+        if gamepad.just_pressed(GamepadButton::Select) {
+            keyboard.toggle_selection();
+        }
+
+        // Cut while selecting, otherwise backspace (X button)
         if gamepad.just_pressed(GamepadButton::West) {
-            keyboard.backspace();
+            if keyboard.selection_anchor.is_some() {
+                if let Some(text) = keyboard.cut_selection() {
+                    copies.write(VirtualKeyboardCopied { text, cut: true });
+                }
+            } else {
+                keyboard.backspace();
+            }
         }
 
-        // Space (Y button)
+        // Copy while selecting, otherwise space (Y button)
         if gamepad.just_pressed(GamepadButton::North) {
-            keyboard.add_space();
+            if keyboard.selection_anchor.is_some() {
+                if let Some(text) = keyboard.copy_selection() {
+                    copies.write(VirtualKeyboardCopied { text, cut: false });
+                }
+            } else {
+                keyboard.add_space();
+            }
         }
 
-        // Cancel (B button)
-        if gamepad.just_pressed(GamepadButton::East) {
+        // Cancel (B button, or bottom face button in East style)
+        if gamepad.just_pressed(cancel_button) {
+            sounds.write(UiNavigationSound::new(
+                UiNavigationSoundKind::Cancelled,
+                UiNavigationSource::VirtualKeyboard,
+            ));
             keyboard_events.write(VirtualKeyboardEvent {
                 value: keyboard.buffer.clone(),
                 confirmed: false,
@@ -406,28 +1221,124 @@ pub fn handle_keyboard_input(
             keyboard.toggle_page();
         }
 
-        // Cursor movement (bumpers)
-        if gamepad.just_pressed(GamepadButton::LeftTrigger) {
-            keyboard.cursor_left();
+        // Cursor movement (bumpers), repeating while held; holding the
+        // right trigger jumps by word
+        if let Some((holder, button, _)) = held_caret.0
+            && holder == entity
+            && !gamepad.pressed(button)
+        {
+            held_caret.0 = None;
         }
-        if gamepad.just_pressed(GamepadButton::RightTrigger) {
-            keyboard.cursor_right();
+        for (button, forward) in [
+            (GamepadButton::LeftTrigger, false),
+            (GamepadButton::RightTrigger, true),
+        ] {
+            let step = if gamepad.just_pressed(button) {
+                held_caret.0 = Some((
+                    entity,
+                    button,
+                    HeldRepeat::new(&controller_config.menu_repeat),
+                ));
+                true
+            } else if let Some((holder, held_button, repeat)) = held_caret.0.as_mut()
+                && *holder == entity
+                && *held_button == button
+            {
+                repeat.tick(time.delta_secs(), &controller_config.menu_repeat)
+            } else {
+                false
+            };
+
+            if step {
+                keyboard.move_cursor(forward, gamepad.pressed(GamepadButton::RightTrigger2));
+            }
         }
     }
 }
 
+/// System to insert pasted text into the open keyboard.
+pub fn handle_keyboard_paste(
+    mut events: MessageReader<PasteIntoVirtualKeyboard>,
+    mut keyboard: ResMut<VirtualKeyboard>,
+) {
+    for event in events.read() {
+        keyboard.paste(&event.text);
+    }
+}
+
+/// System to answer paste requests from the OS clipboard.
+#[cfg(feature = "clipboard")]
+pub fn paste_from_clipboard(
+    mut requests: MessageReader<VirtualKeyboardPasteRequest>,
+    mut pastes: MessageWriter<PasteIntoVirtualKeyboard>,
+) {
+    if requests.is_empty() {
+        return;
+    }
+    requests.clear();
+
+    match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+        Ok(text) => {
+            pastes.write(PasteIntoVirtualKeyboard { text });
+        }
+        Err(err) => warn!("Virtual keyboard could not read the clipboard: {err}"),
+    }
+}
+
+/// System to copy cut or copied text to the OS clipboard.
+#[cfg(feature = "clipboard")]
+pub fn copy_to_clipboard(mut copies: MessageReader<VirtualKeyboardCopied>) {
+    let Some(copied) = copies.read().last() else {
+        return;
+    };
+
+    if let Err(err) =
+        arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(copied.text.clone()))
+    {
+        warn!("Virtual keyboard could not write the clipboard: {err}");
+    }
+}
+
 /// Add virtual keyboard systems to the app.
-pub(crate) fn add_virtual_keyboard_systems(app: &mut App) {
+pub(crate) fn add_virtual_keyboard_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
     app.init_state::<VirtualKeyboardState>()
         .init_resource::<VirtualKeyboard>()
         .init_resource::<VirtualKeyboardConfig>()
+        .init_resource::<VirtualKeyboardSession>()
         .add_message::<ShowVirtualKeyboard>()
         .add_message::<HideVirtualKeyboard>()
+        .add_message::<TextInputRequest>()
         .add_message::<VirtualKeyboardEvent>()
-        .add_systems(Update, (handle_show_keyboard, handle_hide_keyboard))
+        .add_message::<VirtualKeyboardPasteRequest>()
+        .add_message::<PasteIntoVirtualKeyboard>()
+        .add_message::<VirtualKeyboardCopied>()
+        .add_message::<VirtualKeyboardSessionEvent>()
+        .add_systems(
+            schedules.update,
+            (
+                redirect_text_input_requests.before(handle_show_keyboard),
+                handle_show_keyboard,
+                handle_hide_keyboard,
+                manage_keyboard_session,
+            )
+                .run_if(subsystem_enabled(Subsystem::VirtualKeyboard)),
+        )
         .add_systems(
-            Update,
-            handle_keyboard_input.run_if(in_state(VirtualKeyboardState::Visible)),
+            schedules.update,
+            (
+                handle_keyboard_input,
+                #[cfg(feature = "clipboard")]
+                copy_to_clipboard,
+                #[cfg(feature = "clipboard")]
+                paste_from_clipboard,
+                handle_keyboard_paste,
+            )
+                .chain()
+                .run_if(in_state(VirtualKeyboardState::Visible))
+                .run_if(subsystem_enabled(Subsystem::VirtualKeyboard)),
         );
 }
 
@@ -442,6 +1353,65 @@ mod tests {
             VirtualKeyboardState::Hidden
         );
         assert_ne!(VirtualKeyboardState::Hidden, VirtualKeyboardState::Visible);
+        assert_ne!(VirtualKeyboardState::Visible, VirtualKeyboardState::Paused);
+    }
+
+    #[test]
+    fn test_session_pauses_when_owner_disconnects() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let other = world.spawn_empty().id();
+
+        let mut session = VirtualKeyboardSession::default();
+        session.begin(Some(owner));
+
+        assert!(!session.disconnect(other, &[owner]));
+        assert!(session.disconnect(owner, &[other]));
+        assert!(session.is_paused());
+    }
+
+    #[test]
+    fn test_session_without_owner_pauses_on_last_disconnect() {
+        let mut world = World::new();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+
+        let mut session = VirtualKeyboardSession::default();
+        assert!(!session.disconnect(first, &[second]));
+        assert!(session.disconnect(second, &[]));
+    }
+
+    #[test]
+    fn test_session_resumes_on_reconnect() {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let other = world.spawn_empty().id();
+
+        let mut session = VirtualKeyboardSession {
+            resume_with_any_gamepad: false,
+            ..default()
+        };
+        session.begin(Some(owner));
+        session.disconnect(owner, &[]);
+
+        assert!(!session.reconnect(other));
+        assert!(session.reconnect(owner));
+        assert!(!session.is_paused());
+        assert_eq!(session.gamepad(), Some(owner));
+    }
+
+    #[test]
+    fn test_session_times_out() {
+        let mut session = VirtualKeyboardSession {
+            reconnect_timeout: Some(1.0),
+            ..default()
+        };
+        assert!(!session.tick(5.0));
+
+        session.disconnect(Entity::PLACEHOLDER, &[]);
+        assert!(!session.tick(0.5));
+        assert!(session.tick(0.5));
+        assert_eq!(session.paused_for(), 1.0);
     }
 
     #[test]
@@ -492,6 +1462,26 @@ mod tests {
         assert_eq!(kb.cursor, 7);
     }
 
+    #[test]
+    fn test_sensitive_keyboard_debug_hides_buffer() {
+        let kb = VirtualKeyboard::new("Password").with_value("hunter2");
+        assert!(format!("{kb:?}").contains("hunter2"));
+
+        let kb = kb.with_sensitive(true);
+        let dump = format!("{kb:?}");
+        assert!(!dump.contains("hunter2"));
+        assert!(dump.contains("[redacted]"));
+    }
+
+    #[test]
+    fn test_virtual_keyboard_chord_typing_toggle() {
+        let mut kb = VirtualKeyboard::new("Test").with_chord_typing(true);
+        assert!(kb.chord_typing);
+
+        kb.toggle_chord_typing();
+        assert!(!kb.chord_typing);
+    }
+
     #[test]
     fn test_virtual_keyboard_is_char_allowed() {
         let mut kb = VirtualKeyboard::new("Test");
@@ -613,6 +1603,17 @@ mod tests {
         assert_eq!(kb.cursor, 0);
     }
 
+    #[test]
+    fn test_virtual_keyboard_move_focus_reports_blocked() {
+        let mut kb = VirtualKeyboard::new("Test");
+        kb.focused_key = 0;
+
+        assert!(!kb.move_focus(FocusDirection::Left, 40));
+        assert!(!kb.move_focus(FocusDirection::Up, 40));
+        assert!(kb.move_focus(FocusDirection::Right, 40));
+        assert_eq!(kb.focused_key, 1);
+    }
+
     #[test]
     fn test_virtual_keyboard_move_focus() {
         let mut kb = VirtualKeyboard::new("Test");
@@ -675,6 +1676,146 @@ mod tests {
         assert_eq!(kb.buffer, "Hello");
     }
 
+    #[test]
+    fn test_dead_key_composes_with_next_letter() {
+        let mut kb = VirtualKeyboard::new("Name");
+        kb.press_dead_key(DeadKey::Acute);
+        kb.add_char('e');
+        kb.shift_active = true;
+        kb.press_dead_key(DeadKey::Diaeresis);
+        kb.add_char('o');
+        assert_eq!(kb.buffer, "éÖ");
+        assert_eq!(kb.cursor, kb.buffer.len());
+        assert!(kb.dead_key.is_none());
+    }
+
+    #[test]
+    fn test_dead_key_without_composition_types_accent() {
+        let mut kb = VirtualKeyboard::new("Name");
+        kb.press_dead_key(DeadKey::Tilde);
+        kb.add_char('x');
+        kb.press_dead_key(DeadKey::Circumflex);
+        kb.press_dead_key(DeadKey::Circumflex);
+        kb.press_dead_key(DeadKey::Grave);
+        kb.add_space();
+        assert_eq!(kb.buffer, "~x^`");
+
+        // Backspace cancels a pending dead key before deleting
+        kb.press_dead_key(DeadKey::Acute);
+        kb.backspace();
+        assert_eq!(kb.buffer, "~x^`");
+        assert!(kb.dead_key.is_none());
+    }
+
+    #[test]
+    fn test_virtual_keyboard_multibyte_editing() {
+        let mut kb = VirtualKeyboard::new("Name")
+            .with_value("Zoë")
+            .with_max_length(4);
+        kb.cursor_left();
+        kb.cursor_left();
+        kb.cursor_right();
+        assert_eq!(kb.cursor, 2);
+        kb.add_char('ñ');
+        kb.add_char('a'); // At max length
+        assert_eq!(kb.buffer, "Zoñë");
+
+        kb.cursor = kb.buffer.len();
+        kb.backspace();
+        assert_eq!(kb.buffer, "Zoñ");
+        assert_eq!(kb.cursor, kb.buffer.len());
+    }
+
+    #[test]
+    fn test_accent_picker() {
+        assert!(AccentPicker::new('k', false).is_none());
+
+        let mut picker = AccentPicker::new('e', true).unwrap();
+        assert_eq!(picker.options, vec!['E', 'È', 'É', 'Ê', 'Ë']);
+        assert_eq!(picker.selected_char(), 'È');
+        assert!(picker.move_selection(true));
+        assert_eq!(picker.selected_char(), 'É');
+        assert!(picker.move_selection(false));
+        assert!(picker.move_selection(false));
+        assert!(!picker.move_selection(false));
+        assert_eq!(picker.selected_char(), 'E');
+
+        // ß has no single-character uppercase form
+        assert_eq!(
+            AccentPicker::new('s', true).unwrap().options,
+            vec!['S', 'ß']
+        );
+    }
+
+    #[test]
+    fn test_virtual_keyboard_word_jumps() {
+        let mut kb = VirtualKeyboard::new("Title").with_value("the  quick fox");
+        kb.word_left();
+        assert_eq!(kb.cursor, 11);
+        kb.word_left();
+        assert_eq!(kb.cursor, 5);
+        kb.move_cursor(false, true);
+        assert_eq!(kb.cursor, 0);
+        kb.move_cursor(false, true);
+        assert_eq!(kb.cursor, 0);
+
+        kb.word_right();
+        assert_eq!(kb.cursor, 5);
+        kb.word_right();
+        kb.word_right();
+        assert_eq!(kb.cursor, kb.buffer.len());
+    }
+
+    #[test]
+    fn test_virtual_keyboard_selection_cut_copy() {
+        let mut kb = VirtualKeyboard::new("Title").with_value("hello world");
+        kb.toggle_selection();
+        assert_eq!(kb.selected_text(), None);
+        kb.word_left();
+        assert_eq!(kb.selected_text(), Some("world"));
+        assert_eq!(kb.copy_selection().as_deref(), Some("world"));
+        assert!(kb.selection_anchor.is_none());
+
+        kb.toggle_selection();
+        kb.cursor_left();
+        assert_eq!(kb.cut_selection().as_deref(), Some(" "));
+        assert_eq!(kb.buffer, "helloworld");
+        assert_eq!(kb.cursor, 5);
+
+        // Typing replaces the selection
+        kb.toggle_selection();
+        kb.word_right();
+        kb.add_char('!');
+        assert_eq!(kb.buffer, "hello!");
+        assert!(kb.selection_anchor.is_none());
+    }
+
+    #[test]
+    fn test_virtual_keyboard_paste_filters_text() {
+        let mut kb = VirtualKeyboard::new("Code")
+            .with_allow("ABCDEF0123456789-")
+            .with_max_length(9);
+        kb.press_dead_key(DeadKey::Acute);
+        assert_eq!(kb.paste("ab-12\nCD-34-EF"), 9);
+        assert_eq!(kb.buffer, "-12CD-34-");
+        assert_eq!(kb.cursor, 9);
+        assert!(kb.dead_key.is_none());
+    }
+
+    #[test]
+    fn test_page_keys_include_dead_keys() {
+        let config = VirtualKeyboardConfig::default();
+        let letters = config.page_keys(0);
+        assert_eq!(letters.len(), 45);
+        assert_eq!(letters[40], KeyboardKey::Dead(DeadKey::Acute));
+
+        let config = VirtualKeyboardConfig {
+            paste_key: true,
+            ..default()
+        };
+        assert_eq!(config.page_keys(1).last(), Some(&KeyboardKey::Paste));
+    }
+
     #[test]
     fn test_virtual_keyboard_add_char_not_allowed() {
         let mut kb = VirtualKeyboard::new("Test").with_allow("abc");
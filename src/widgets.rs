@@ -0,0 +1,451 @@
+//! Controller-operable option widgets.
+//!
+//! [`SliderWidget`], [`StepperWidget`], and [`ToggleWidget`] are components
+//! for settings screens. Each is [`Focusable`]; while one has focus, the
+//! Left/Right actions adjust it instead of moving focus, and Confirm flips a
+//! toggle. Holding Left/Right repeats through [`ActionRepeat`], and sliders
+//! switch to a larger step after a few repeats. Every change sends a
+//! [`WidgetValueChanged`] event.
+//!
+//! Add [`WidgetPrompts`] to a widget to give it [`ActionPromptDisplay`]
+//! children showing the buttons currently bound to adjust it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::widgets::{SliderWidget, ToggleWidget, WidgetPrompts, WidgetValueChanged};
+//!
+//! fn spawn_options(mut commands: Commands) {
+//!     commands.spawn((Node::default(), SliderWidget::new(0.0, 1.0, 0.05), WidgetPrompts));
+//!     commands.spawn((Node::default(), ToggleWidget::new(true)));
+//! }
+//!
+//! fn apply_options(mut changes: MessageReader<WidgetValueChanged>) {
+//!     for change in changes.read() {
+//!         info!("{:?} is now {:?}", change.entity, change.value);
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::actions::{ActionMap, ActionState, GameAction};
+use crate::chords::ChordBindings;
+use crate::icons::{CompositeIconDisplay, CompositePrompt};
+use crate::navigation::{Focusable, NavEvent, NavFocus};
+use crate::repeat::ActionRepeat;
+use crate::subsystems::{Subsystem, subsystem_enabled};
+use crate::ui_sound::{UiNavigationSound, UiNavigationSoundKind, UiNavigationSource};
+
+/// A value adjusted with Left/Right between a minimum and maximum.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[require(Focusable = Focusable::adjustable())]
+pub struct SliderWidget {
+    /// Current value.
+    pub value: f32,
+    /// Lowest value.
+    pub min: f32,
+    /// Highest value.
+    pub max: f32,
+    /// Change per press or repeat.
+    pub step: f32,
+    /// Change per repeat once the hold has accelerated.
+    pub fast_step: f32,
+    /// Repeats before switching to `fast_step`.
+    pub fast_after: u32,
+}
+
+impl SliderWidget {
+    /// Create a slider starting at `min`.
+    ///
+    /// Holding accelerates to five times `step` after eight repeats.
+    #[must_use]
+    pub fn new(min: f32, max: f32, step: f32) -> Self {
+        Self {
+            value: min,
+            min,
+            max,
+            step,
+            fast_step: step * 5.0,
+            fast_after: 8,
+        }
+    }
+
+    /// Set the starting value.
+    #[must_use]
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = value.clamp(self.min, self.max);
+        self
+    }
+
+    /// Set how holding accelerates.
+    #[must_use]
+    pub const fn with_fast_step(mut self, fast_step: f32, fast_after: u32) -> Self {
+        self.fast_step = fast_step;
+        self.fast_after = fast_after;
+        self
+    }
+
+    /// Value as a fraction of the range (0.0 - 1.0), for drawing the bar.
+    #[must_use]
+    pub fn fraction(&self) -> f32 {
+        let range = self.max - self.min;
+        if range <= 0.0 {
+            return 0.0;
+        }
+        ((self.value - self.min) / range).clamp(0.0, 1.0)
+    }
+
+    /// Move one step in a direction (-1 or 1) after some repeats.
+    ///
+    /// Returns whether the value changed.
+    pub fn adjust(&mut self, direction: i32, repeats: u32) -> bool {
+        let step = if repeats >= self.fast_after {
+            self.fast_step
+        } else {
+            self.step
+        };
+        #[expect(clippy::cast_precision_loss, reason = "direction is -1, 0, or 1")]
+        let value = (self.value + step * direction as f32).clamp(self.min, self.max);
+        let changed = value != self.value;
+        self.value = value;
+        changed
+    }
+}
+
+/// A choice from a list of options, stepped through with Left/Right.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[require(Focusable = Focusable::adjustable())]
+pub struct StepperWidget {
+    /// Option labels.
+    pub options: Vec<String>,
+    /// Index of the selected option.
+    pub index: usize,
+    /// Step from the last option back to the first and vice versa.
+    pub wrap: bool,
+}
+
+impl StepperWidget {
+    /// Create a stepper on its first option.
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            index: 0,
+            wrap: false,
+        }
+    }
+
+    /// Select an option by index.
+    #[must_use]
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = index.min(self.options.len().saturating_sub(1));
+        self
+    }
+
+    /// Wrap around at either end.
+    #[must_use]
+    pub const fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Label of the selected option.
+    #[must_use]
+    pub fn selected(&self) -> Option<&str> {
+        self.options.get(self.index).map(String::as_str)
+    }
+
+    /// Move one option in a direction (-1 or 1).
+    ///
+    /// Returns whether the selection changed.
+    pub fn step(&mut self, direction: i32) -> bool {
+        let len = self.options.len();
+        if len == 0 || direction == 0 {
+            return false;
+        }
+        let index = if direction > 0 {
+            match self.index + 1 {
+                next if next < len => next,
+                _ if self.wrap => 0,
+                _ => self.index,
+            }
+        } else {
+            match self.index.checked_sub(1) {
+                Some(previous) => previous,
+                None if self.wrap => len - 1,
+                None => self.index,
+            }
+        };
+        let changed = index != self.index;
+        self.index = index;
+        changed
+    }
+}
+
+/// An on/off option, flipped with Confirm or set with Left (off) and
+/// Right (on).
+#[derive(Debug, Clone, Copy, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+#[require(Focusable = Focusable::adjustable())]
+pub struct ToggleWidget {
+    /// Whether the option is on.
+    pub on: bool,
+}
+
+impl ToggleWidget {
+    /// Create a toggle.
+    #[must_use]
+    pub const fn new(on: bool) -> Self {
+        Self { on }
+    }
+
+    /// Set the state, returning whether it changed.
+    pub fn set(&mut self, on: bool) -> bool {
+        let changed = self.on != on;
+        self.on = on;
+        changed
+    }
+}
+
+/// New value of a widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WidgetValue {
+    /// A slider's value.
+    Slider(f32),
+    /// A stepper's selected index.
+    Stepper(usize),
+    /// A toggle's state.
+    Toggle(bool),
+}
+
+/// Event fired when a widget's value changes from input.
+#[derive(Debug, Clone, Copy, PartialEq, Message)]
+pub struct WidgetValueChanged {
+    /// The widget entity.
+    pub entity: Entity,
+    /// Its new value.
+    pub value: WidgetValue,
+}
+
+/// Displays the gamepad prompt for an action's current binding.
+///
+/// The prompt is kept in sync with the [`ActionMap`], so rebinding an
+/// action updates every prompt that shows it.
+#[derive(Debug, Clone, Copy, Component)]
+#[require(CompositeIconDisplay)]
+pub struct ActionPromptDisplay {
+    /// The action whose binding is shown.
+    pub action: GameAction,
+}
+
+/// Marker giving a widget [`ActionPromptDisplay`] children for the actions
+/// that adjust it: Left and Right for sliders and steppers, Confirm for
+/// toggles.
+#[derive(Debug, Clone, Copy, Default, Component)]
+pub struct WidgetPrompts;
+
+/// System to adjust the focused widget with Left/Right.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn adjust_focused_widget(
+    focus: Res<NavFocus>,
+    action_state: Res<ActionState>,
+    repeat: Res<ActionRepeat>,
+    mut sliders: Query<&mut SliderWidget>,
+    mut steppers: Query<&mut StepperWidget>,
+    mut toggles: Query<&mut ToggleWidget>,
+    mut changed_events: MessageWriter<WidgetValueChanged>,
+    mut sounds: MessageWriter<UiNavigationSound>,
+) {
+    let Some(entity) = focus.focused() else {
+        return;
+    };
+    let (direction, action) = if repeat.triggered(&action_state, GameAction::Right) {
+        (1, GameAction::Right)
+    } else if repeat.triggered(&action_state, GameAction::Left) {
+        (-1, GameAction::Left)
+    } else {
+        return;
+    };
+
+    let value = if let Ok(mut slider) = sliders.get_mut(entity) {
+        slider
+            .adjust(direction, repeat.repeat_count(action))
+            .then(|| WidgetValue::Slider(slider.value))
+    } else if let Ok(mut stepper) = steppers.get_mut(entity) {
+        stepper
+            .step(direction)
+            .then(|| WidgetValue::Stepper(stepper.index))
+    } else if let Ok(mut toggle) = toggles.get_mut(entity) {
+        toggle
+            .set(direction > 0)
+            .then_some(WidgetValue::Toggle(direction > 0))
+    } else {
+        return;
+    };
+
+    let kind = if let Some(value) = value {
+        changed_events.write(WidgetValueChanged { entity, value });
+        UiNavigationSoundKind::FocusMoved
+    } else {
+        UiNavigationSoundKind::Blocked
+    };
+    sounds.write(UiNavigationSound::new(kind, UiNavigationSource::Actions));
+}
+
+/// System to flip toggles when they are activated.
+pub fn flip_activated_toggles(
+    mut nav_events: MessageReader<NavEvent>,
+    mut toggles: Query<&mut ToggleWidget>,
+    mut changed_events: MessageWriter<WidgetValueChanged>,
+) {
+    for event in nav_events.read() {
+        let NavEvent::Activated(entity) = *event else {
+            continue;
+        };
+        if let Ok(mut toggle) = toggles.get_mut(entity) {
+            toggle.on = !toggle.on;
+            changed_events.write(WidgetValueChanged {
+                entity,
+                value: WidgetValue::Toggle(toggle.on),
+            });
+        }
+    }
+}
+
+/// System to spawn prompt children for widgets with [`WidgetPrompts`].
+pub fn spawn_widget_prompts(
+    mut commands: Commands,
+    widgets: Query<(Entity, Has<ToggleWidget>), Added<WidgetPrompts>>,
+) {
+    for (entity, is_toggle) in &widgets {
+        let actions: &[GameAction] = if is_toggle {
+            &[GameAction::Confirm]
+        } else {
+            &[GameAction::Left, GameAction::Right]
+        };
+        commands.entity(entity).with_children(|parent| {
+            for action in actions {
+                parent.spawn(ActionPromptDisplay { action: *action });
+            }
+        });
+    }
+}
+
+/// System to keep action prompts on the current bindings.
+pub fn update_action_prompts(
+    action_map: Res<ActionMap>,
+    chords: Res<ChordBindings>,
+    mut prompts: Query<(Ref<ActionPromptDisplay>, &mut CompositeIconDisplay)>,
+) {
+    let rebound = action_map.is_changed() || chords.is_changed();
+    for (prompt, mut display) in &mut prompts {
+        if !rebound && !prompt.is_changed() {
+            continue;
+        }
+        let composite =
+            CompositePrompt::for_action(prompt.action, &action_map, &chords).unwrap_or_default();
+        if display.prompt != composite {
+            display.prompt = composite;
+        }
+    }
+}
+
+/// Plugin for registering widget types.
+pub(crate) fn register_widget_types(app: &mut App) {
+    app.register_type::<SliderWidget>()
+        .register_type::<StepperWidget>()
+        .register_type::<ToggleWidget>()
+        .add_message::<WidgetValueChanged>();
+}
+
+/// Add widget systems to the app.
+pub(crate) fn add_widget_systems(app: &mut App, schedules: &crate::plugin::ControllerSchedules) {
+    app.add_systems(
+        schedules.update,
+        (
+            adjust_focused_widget.before(crate::navigation::route_navigation_actions),
+            flip_activated_toggles.after(crate::navigation::resolve_nav_requests),
+            spawn_widget_prompts,
+            update_action_prompts,
+        )
+            .run_if(subsystem_enabled(Subsystem::Navigation)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slider_accelerates_and_clamps() {
+        let mut slider = SliderWidget::new(0.0, 1.0, 0.125).with_fast_step(0.5, 2);
+        assert!(slider.adjust(1, 0));
+        assert_eq!(slider.value, 0.125);
+        assert!(slider.adjust(1, 2));
+        assert_eq!(slider.value, 0.625);
+        assert!(slider.adjust(1, 3));
+        assert_eq!(slider.value, 1.0);
+        assert!(!slider.adjust(1, 4));
+        assert_eq!(slider.fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_stepper_wraps_only_when_enabled() {
+        let mut stepper = StepperWidget::new(["Low", "Medium", "High"]);
+        assert!(!stepper.step(-1));
+        assert!(stepper.step(1));
+        assert_eq!(stepper.selected(), Some("Medium"));
+
+        let mut stepper = stepper.with_index(2).with_wrap(true);
+        assert!(stepper.step(1));
+        assert_eq!(stepper.selected(), Some("Low"));
+        assert!(stepper.step(-1));
+        assert_eq!(stepper.index, 2);
+    }
+
+    #[test]
+    fn test_focused_widget_takes_left_right() {
+        let mut app = App::new();
+        app.init_resource::<NavFocus>()
+            .init_resource::<ActionState>()
+            .init_resource::<ActionRepeat>()
+            .add_message::<WidgetValueChanged>()
+            .add_message::<UiNavigationSound>()
+            .add_message::<crate::navigation::NavRequest>()
+            .add_message::<NavEvent>()
+            .add_systems(
+                Update,
+                (
+                    adjust_focused_widget,
+                    crate::navigation::resolve_nav_requests,
+                )
+                    .chain(),
+            );
+        let toggle = app.world_mut().spawn(ToggleWidget::new(false)).id();
+        assert!(
+            app.world()
+                .get::<Focusable>(toggle)
+                .is_some_and(|focusable| focusable.adjustable)
+        );
+
+        app.world_mut()
+            .write_message(crate::navigation::NavRequest::FocusOn(toggle));
+        app.update();
+        app.world_mut()
+            .resource_mut::<ActionState>()
+            .set_pressed(GameAction::Right, true);
+        app.update();
+
+        assert!(app.world().get::<ToggleWidget>(toggle).unwrap().on);
+        let changes = app.world().resource::<Messages<WidgetValueChanged>>();
+        let mut reader = changes.get_cursor();
+        let change = reader.read(changes).next().unwrap();
+        assert_eq!(change.value, WidgetValue::Toggle(true));
+    }
+}
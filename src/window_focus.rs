@@ -0,0 +1,305 @@
+//! Input handling when the window loses focus.
+//!
+//! Alt-tabbing away while a button is held would otherwise leave the action
+//! stuck and the controller rumbling. While the primary window is unfocused,
+//! every action is released, including each gamepad's
+//! [`GamepadActionState`], and rumble is stopped when focus is lost;
+//! [`Time<Virtual>`] can
+//! optionally be paused. After focus returns, input is ignored for a short
+//! grace period, and actions held since before then stay released until the
+//! player lets go.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_archie::window_focus::WindowFocusSettings;
+//!
+//! fn configure(mut settings: ResMut<WindowFocusSettings>) {
+//!     settings.auto_pause = true;
+//!     settings.resume_grace = 0.5;
+//! }
+//! ```
+
+use bevy::input::gamepad::GamepadRumbleRequest;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::HashSet;
+
+use crate::actions::{ActionState, GameAction, GamepadActionState};
+use crate::haptics::{RumbleController, RumbleOutput};
+use crate::subsystems::{Subsystem, subsystem_enabled};
+
+/// Settings for focus-loss behavior.
+#[derive(Debug, Clone, Resource)]
+pub struct WindowFocusSettings {
+    /// Release all actions while the window is unfocused.
+    pub release_actions: bool,
+    /// Stop rumble when the window loses focus.
+    pub stop_rumble: bool,
+    /// Pause [`Time<Virtual>`] while the window is unfocused.
+    pub auto_pause: bool,
+    /// Seconds to ignore input after focus returns.
+    pub resume_grace: f32,
+}
+
+impl Default for WindowFocusSettings {
+    fn default() -> Self {
+        Self {
+            release_actions: true,
+            stop_rumble: true,
+            auto_pause: false,
+            resume_grace: 0.2,
+        }
+    }
+}
+
+/// Resource tracking the primary window's focus.
+#[derive(Debug, Clone, Resource)]
+pub struct WindowFocusState {
+    /// Whether the window is focused.
+    focused: bool,
+    /// Seconds of grace left after focus returned.
+    grace_remaining: f32,
+    /// Actions held through the focus change, released until let go.
+    held: HashSet<GameAction>,
+    /// Whether virtual time was paused by focus loss.
+    paused_time: bool,
+}
+
+impl Default for WindowFocusState {
+    fn default() -> Self {
+        Self {
+            focused: true,
+            grace_remaining: 0.0,
+            held: HashSet::new(),
+            paused_time: false,
+        }
+    }
+}
+
+impl WindowFocusState {
+    /// Whether the window is focused.
+    #[must_use]
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Whether input is being ignored: unfocused or within the grace period.
+    #[must_use]
+    pub fn is_ignoring_input(&self) -> bool {
+        !self.focused || self.grace_remaining > 0.0
+    }
+
+    /// Record the window's focus, returning whether it changed.
+    pub fn set_focused(&mut self, focused: bool, settings: &WindowFocusSettings) -> bool {
+        if self.focused == focused {
+            return false;
+        }
+
+        self.focused = focused;
+        if focused {
+            self.grace_remaining = settings.resume_grace.max(0.0);
+        }
+        true
+    }
+
+    /// Advance the grace period by `delta` seconds.
+    pub fn tick(&mut self, delta: f32) {
+        if self.focused {
+            self.grace_remaining = (self.grace_remaining - delta).max(0.0);
+        }
+    }
+
+    /// Route an action's pressed state, returning whether to release it.
+    pub fn filter(&mut self, action: GameAction, pressed: bool) -> bool {
+        if !pressed {
+            self.held.remove(&action);
+            return false;
+        }
+
+        if self.is_ignoring_input() {
+            self.held.insert(action);
+            return true;
+        }
+
+        self.held.contains(&action)
+    }
+}
+
+/// Event fired when the primary window gains or loses focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Message)]
+pub struct InputFocusChanged {
+    /// Whether the window is now focused.
+    pub focused: bool,
+}
+
+/// System to track window focus and release actions while unfocused.
+#[expect(
+    clippy::too_many_arguments,
+    reason = "Bevy systems need access to multiple resources"
+)]
+pub fn track_window_focus(
+    settings: Res<WindowFocusSettings>,
+    mut focus: ResMut<WindowFocusState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut action_state: ResMut<ActionState>,
+    mut gamepads: Query<&mut GamepadActionState>,
+    mut events: MessageWriter<InputFocusChanged>,
+) {
+    let focused = windows.single().map_or(true, |window| window.focused);
+    focus.tick(real_time.delta_secs());
+
+    if focus.set_focused(focused, &settings) {
+        events.write(InputFocusChanged { focused });
+
+        if !focused && settings.auto_pause && !virtual_time.is_paused() {
+            virtual_time.pause();
+            focus.paused_time = true;
+        } else if focused && focus.paused_time {
+            virtual_time.unpause();
+            focus.paused_time = false;
+        }
+    }
+
+    if !settings.release_actions {
+        return;
+    }
+
+    for action in GameAction::all() {
+        if focus.filter(*action, action_state.pressed(*action)) {
+            action_state.suppress(*action, "window focus lost");
+            for mut gamepad in &mut gamepads {
+                gamepad.suppress(*action, "window focus lost");
+            }
+        }
+    }
+}
+
+/// System to stop rumble when the window loses focus.
+pub fn silence_rumble_while_unfocused(
+    settings: Res<WindowFocusSettings>,
+    mut focus_changes: MessageReader<InputFocusChanged>,
+    mut controllers: Query<&mut RumbleController>,
+    mut gamepads: Query<(Entity, Option<&mut RumbleOutput>), With<Gamepad>>,
+    mut rumble_requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    let lost_focus = focus_changes.read().any(|change| !change.focused);
+    if !settings.stop_rumble || !lost_focus {
+        return;
+    }
+
+    for mut controller in &mut controllers {
+        controller.stop();
+    }
+    for (gamepad, output) in &mut gamepads {
+        match output {
+            Some(mut output) => output.stop(),
+            None => {
+                rumble_requests.write(GamepadRumbleRequest::Stop { gamepad });
+            }
+        }
+    }
+}
+
+/// Plugin for registering window focus types.
+pub(crate) fn register_window_focus_types(app: &mut App) {
+    app.init_resource::<WindowFocusSettings>()
+        .init_resource::<WindowFocusState>()
+        .add_message::<InputFocusChanged>();
+}
+
+/// Add window focus systems to the app.
+pub(crate) fn add_window_focus_systems(
+    app: &mut App,
+    schedules: &crate::plugin::ControllerSchedules,
+) {
+    app.add_systems(
+        schedules.input,
+        track_window_focus
+            .after(crate::actions::update_action_state::<GameAction>)
+            .before(crate::safety::apply_safety_gates)
+            .before(crate::stick::update_stick_twirls)
+            .before(crate::repeat::update_action_repeat)
+            .run_if(subsystem_enabled(Subsystem::WindowFocus)),
+    )
+    .add_systems(
+        schedules.update,
+        silence_rumble_while_unfocused
+            .after(crate::haptics::update_rumble)
+            .after(crate::haptics::update_haptic_timelines)
+            .before(crate::haptics::send_rumble_output)
+            .run_if(subsystem_enabled(Subsystem::WindowFocus)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfocused_releases_actions() {
+        let settings = WindowFocusSettings::default();
+        let mut focus = WindowFocusState::default();
+        assert!(!focus.filter(GameAction::Primary, true));
+
+        assert!(focus.set_focused(false, &settings));
+        assert!(!focus.set_focused(false, &settings));
+        assert!(focus.filter(GameAction::Primary, true));
+        assert!(focus.filter(GameAction::Secondary, true));
+    }
+
+    #[test]
+    fn test_grace_period_after_refocus() {
+        let settings = WindowFocusSettings {
+            resume_grace: 0.25,
+            ..default()
+        };
+        let mut focus = WindowFocusState::default();
+        focus.set_focused(false, &settings);
+        focus.set_focused(true, &settings);
+
+        // New presses during the grace period are ignored
+        assert!(focus.filter(GameAction::Confirm, true));
+        focus.tick(0.125);
+        assert!(focus.is_ignoring_input());
+        focus.tick(0.125);
+        assert!(!focus.is_ignoring_input());
+
+        // Still held since the grace period, then released and pressed again
+        assert!(focus.filter(GameAction::Confirm, true));
+        assert!(!focus.filter(GameAction::Confirm, false));
+        assert!(!focus.filter(GameAction::Confirm, true));
+    }
+
+    #[test]
+    fn test_rumble_stops_once_on_focus_lost() {
+        let mut app = App::new();
+        app.init_resource::<WindowFocusSettings>()
+            .add_message::<InputFocusChanged>()
+            .add_message::<GamepadRumbleRequest>()
+            .add_systems(Update, silence_rumble_while_unfocused);
+        app.world_mut().spawn(Gamepad::default());
+        // Gamepads with a rumble output are stopped through it instead
+        app.world_mut()
+            .spawn((Gamepad::default(), RumbleOutput::default()));
+
+        let stops = |app: &App| {
+            app.world()
+                .resource::<Messages<GamepadRumbleRequest>>()
+                .iter_current_update_messages()
+                .count()
+        };
+
+        app.world_mut()
+            .write_message(InputFocusChanged { focused: false });
+        app.update();
+        assert_eq!(stops(&app), 1);
+
+        // Still unfocused, so the game can rumble again
+        app.update();
+        assert_eq!(stops(&app), 0);
+    }
+}
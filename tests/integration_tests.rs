@@ -9,6 +9,7 @@
 
 //! Integration tests for bevy_archie.
 
+use bevy::diagnostic::DiagnosticsStore;
 use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
 use bevy_archie::action_modifiers::ActionModifier;
@@ -17,6 +18,7 @@ use bevy_archie::haptics::{RumbleController, RumbleIntensity, RumblePattern};
 use bevy_archie::icons::{ButtonIcon, IconSize};
 use bevy_archie::input_buffer::InputBuffer;
 use bevy_archie::multiplayer::{ControllerOwnership, Player, PlayerId};
+use bevy_archie::navigation::{Focusable, NavDirection, NavEvent, NavFocus, NavRequest};
 use bevy_archie::prelude::*;
 use bevy_archie::profiles::ControllerModel;
 use bevy_archie::touchpad::TouchpadGesture;
@@ -37,6 +39,30 @@ fn test_plugin_initialization() {
     assert!(app.world().get_resource::<ActionState>().is_some());
 }
 
+#[test]
+fn test_plugin_registers_diagnostics() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let action_events = store
+        .get(&bevy_archie::diagnostics::ACTION_EVENTS)
+        .expect("action events diagnostic is registered");
+    assert_eq!(action_events.value(), Some(0.0));
+    assert!(
+        store
+            .get(&bevy_archie::diagnostics::ACTION_UPDATE_TIME)
+            .is_some()
+    );
+    let rumble_backlog = store
+        .get(&bevy_archie::diagnostics::RUMBLE_REQUEST_BACKLOG)
+        .expect("rumble backlog diagnostic is registered");
+    assert_eq!(rumble_backlog.value(), Some(0.0));
+}
+
 #[test]
 fn test_action_state_updates() {
     let mut app = App::new();
@@ -211,6 +237,48 @@ fn test_player_component() {
     assert_eq!(p2.id, PlayerId::new(1));
 }
 
+#[test]
+fn test_focus_navigation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+
+    let first = app.world_mut().spawn(Focusable::at(Vec2::ZERO)).id();
+    let second = app
+        .world_mut()
+        .spawn(Focusable::at(Vec2::new(0.0, 50.0)))
+        .id();
+
+    // With nothing focused, any move focuses the top-left entity.
+    app.world_mut()
+        .write_message(NavRequest::Move(NavDirection::Down));
+    app.update();
+    assert_eq!(app.world().resource::<NavFocus>().focused(), Some(first));
+
+    app.world_mut()
+        .write_message(NavRequest::Move(NavDirection::Down));
+    app.update();
+    assert_eq!(app.world().resource::<NavFocus>().focused(), Some(second));
+    assert!(app.world().get::<Focusable>(second).unwrap().is_focused());
+    assert!(!app.world().get::<Focusable>(first).unwrap().is_focused());
+
+    app.world_mut()
+        .write_message(NavRequest::Move(NavDirection::Down));
+    app.world_mut().write_message(NavRequest::Action);
+    app.update();
+
+    let events: Vec<NavEvent> = app
+        .world_mut()
+        .resource_mut::<Messages<NavEvent>>()
+        .drain()
+        .collect();
+    assert!(events.contains(&NavEvent::NoChanges {
+        from: Some(second),
+        request: NavRequest::Move(NavDirection::Down),
+    }));
+    assert!(events.contains(&NavEvent::Activated(second)));
+}
+
 #[test]
 fn test_motion_gesture_variants() {
     let gestures = [
@@ -292,3 +360,295 @@ fn test_input_buffer_capacity() {
     assert_eq!(buffer.inputs.len(), 0);
     assert!(buffer.inputs.is_empty());
 }
+
+#[test]
+fn test_disabled_subsystem_skips_systems() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+
+    let entity = app.world_mut().spawn(Focusable::at(Vec2::ZERO)).id();
+
+    app.world_mut().write_message(SetSubsystemEnabled {
+        subsystem: Subsystem::Navigation,
+        enabled: false,
+    });
+    app.world_mut().write_message(NavRequest::FocusOn(entity));
+    app.update();
+    assert!(
+        !app.world()
+            .resource::<EnabledSubsystems>()
+            .is_enabled(Subsystem::Navigation)
+    );
+    assert_eq!(app.world().resource::<NavFocus>().focused(), None);
+
+    app.world_mut().write_message(SetSubsystemEnabled {
+        subsystem: Subsystem::Navigation,
+        enabled: true,
+    });
+    app.world_mut().write_message(NavRequest::FocusOn(entity));
+    app.update();
+    assert_eq!(app.world().resource::<NavFocus>().focused(), Some(entity));
+}
+
+#[test]
+fn test_per_gamepad_action_states() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+
+    let first = app.world_mut().spawn(Gamepad::default()).id();
+    let second = app.world_mut().spawn(Gamepad::default()).id();
+    app.update();
+
+    app.world_mut()
+        .get_mut::<Gamepad>(second)
+        .unwrap()
+        .digital_mut()
+        .press(GamepadButton::West);
+    app.update();
+
+    let actions = |entity| {
+        app.world()
+            .get::<GamepadActionState>(entity)
+            .unwrap()
+            .actions()
+            .pressed(GameAction::Primary)
+    };
+    assert!(!actions(first));
+    assert!(actions(second));
+    assert!(
+        app.world()
+            .resource::<ActionState>()
+            .pressed(GameAction::Primary)
+    );
+}
+
+#[test]
+fn test_simulated_tap_flows_through_action_state() {
+    use bevy_archie::simulated_input::SimulatedInput;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<SimulatedInput>()
+        .tap(GameAction::Primary);
+    app.update();
+    assert!(
+        app.world()
+            .resource::<ActionState>()
+            .just_pressed(GameAction::Primary)
+    );
+
+    app.update();
+    let state = app.world().resource::<ActionState>();
+    assert!(!state.pressed(GameAction::Primary));
+    assert!(state.just_released(GameAction::Primary));
+}
+
+#[test]
+fn test_haptic_bindings_rumble_on_action_press() {
+    use bevy_archie::haptics::{HapticBindings, HapticFeedback};
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+    app.insert_resource(
+        HapticBindings::default().with_action(GameAction::Primary, HapticFeedback::light_tick()),
+    );
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.update();
+    assert!(app.world().get::<RumbleController>(gamepad).is_none());
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .digital_mut()
+        .press(GamepadButton::West);
+    app.update();
+
+    let controller = app.world().get::<RumbleController>(gamepad).unwrap();
+    assert_eq!(controller.pattern, Some(RumblePattern::DamageTap));
+}
+
+#[test]
+fn test_bindings_and_haptics_are_reflected() {
+    use bevy::reflect::structs::Struct;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.contains(std::any::TypeId::of::<RumbleController>()));
+    assert!(registry.contains(std::any::TypeId::of::<InputBuffer>()));
+    drop(registry);
+
+    let map = app.world().resource::<ActionMap>();
+    assert!(map.field("gamepad_bindings").is_some());
+    assert!(map.field("stick_direction_bindings").is_some());
+}
+
+#[test]
+fn test_controller_components_are_scene_serializable() {
+    use bevy::ecs::reflect::ReflectComponent;
+    use bevy_archie::touchpad::TouchpadData;
+    use bevy_archie::virtual_cursor::VirtualCursor;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    for type_id in [
+        std::any::TypeId::of::<VirtualCursor>(),
+        std::any::TypeId::of::<TouchpadData>(),
+        std::any::TypeId::of::<RumbleController>(),
+    ] {
+        assert!(
+            registry
+                .get_type_data::<ReflectComponent>(type_id)
+                .is_some()
+        );
+    }
+}
+
+#[test]
+fn test_touchscreen_two_finger_tap_emits_gesture() {
+    use bevy::input::touch::{TouchInput, TouchPhase};
+    use bevy::window::PrimaryWindow;
+    use bevy_archie::touchpad::{TouchscreenGestureEvent, TouchscreenGesturePlugin};
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin))
+        .add_plugins(TouchscreenGesturePlugin);
+    let window = app
+        .world_mut()
+        .spawn((Window::default(), PrimaryWindow))
+        .id();
+    app.update();
+
+    let touch = |id, phase, x| TouchInput {
+        phase,
+        position: Vec2::new(x, 300.0),
+        window,
+        force: None,
+        id,
+    };
+    app.world_mut()
+        .write_message(touch(0, TouchPhase::Started, 400.0));
+    app.world_mut()
+        .write_message(touch(1, TouchPhase::Started, 600.0));
+    app.update();
+
+    app.world_mut()
+        .write_message(touch(0, TouchPhase::Ended, 400.0));
+    app.world_mut()
+        .write_message(touch(1, TouchPhase::Ended, 600.0));
+    app.update();
+
+    let gestures: Vec<TouchscreenGestureEvent> = app
+        .world_mut()
+        .resource_mut::<Messages<TouchscreenGestureEvent>>()
+        .drain()
+        .collect();
+    assert_eq!(gestures.len(), 1);
+    assert_eq!(gestures[0].gesture, TouchpadGesture::TwoFingerTap);
+    assert!((gestures[0].position.x - 500.0).abs() < 0.01);
+}
+
+#[test]
+fn test_suspend_releases_held_actions_until_let_go() {
+    use bevy::window::AppLifecycle;
+    use bevy_archie::simulated_input::SimulatedInput;
+    use bevy_archie::suspend::SuspendSettings;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+    app.insert_resource(SuspendSettings {
+        resume_grace: 0.0,
+        ..default()
+    });
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<SimulatedInput>()
+        .press(GameAction::Primary);
+    app.update();
+    assert!(
+        app.world()
+            .resource::<ActionState>()
+            .pressed(GameAction::Primary)
+    );
+
+    app.world_mut().write_message(AppLifecycle::Suspended);
+    app.update();
+    assert!(
+        !app.world()
+            .resource::<ActionState>()
+            .pressed(GameAction::Primary)
+    );
+
+    app.world_mut().write_message(AppLifecycle::Running);
+    app.update();
+    let resumed: Vec<InputSystemResumed> = app
+        .world_mut()
+        .resource_mut::<Messages<InputSystemResumed>>()
+        .drain()
+        .collect();
+    assert_eq!(resumed.len(), 1);
+
+    // Still held from before the suspend
+    app.update();
+    assert!(
+        !app.world()
+            .resource::<ActionState>()
+            .pressed(GameAction::Primary)
+    );
+
+    app.world_mut()
+        .resource_mut::<SimulatedInput>()
+        .release(GameAction::Primary);
+    app.update();
+    app.world_mut()
+        .resource_mut::<SimulatedInput>()
+        .press(GameAction::Primary);
+    app.update();
+    assert!(
+        app.world()
+            .resource::<ActionState>()
+            .pressed(GameAction::Primary)
+    );
+}
+
+#[test]
+fn test_chord_binding_beats_single_button() {
+    use bevy_archie::chords::{ButtonChord, ChordBindings, ClashStrategy};
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin, StatesPlugin))
+        .add_plugins(ControllerPlugin::default());
+    let mut chords = ChordBindings::default().with_strategy(ClashStrategy::PrioritizeLongest);
+    chords.bind(
+        ButtonChord::from_gamepad_buttons(&[GamepadButton::LeftTrigger, GamepadButton::South]),
+        GameAction::Custom1,
+    );
+    app.insert_resource(chords);
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.update();
+
+    let mut pad = app.world_mut().get_mut::<Gamepad>(gamepad).unwrap();
+    pad.digital_mut().press(GamepadButton::LeftTrigger);
+    pad.digital_mut().press(GamepadButton::South);
+    app.update();
+
+    let state = app.world().resource::<ActionState>();
+    assert!(state.pressed(GameAction::Custom1));
+    assert!(!state.pressed(GameAction::Confirm));
+}